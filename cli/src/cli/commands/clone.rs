@@ -0,0 +1,77 @@
+use crate::cli::summary;
+use crate::container::{ContainerManager, DatabaseType, DockerClient};
+use crate::health::{checker_for, wait_for_healthy};
+use crate::progress::IndicatifProgress;
+use crate::{DBArenaError, Result};
+use console::style;
+use std::time::Duration;
+
+const DEFAULT_HEALTH_TIMEOUT: Duration = Duration::from_secs(60);
+
+pub async fn handle_clone(container: String, name: String, start: bool) -> Result<()> {
+    let docker_client = DockerClient::new()?;
+    docker_client.verify_connection().await?;
+
+    let manager = ContainerManager::new(docker_client.clone());
+
+    let source = manager
+        .find_container(&container)
+        .await?
+        .ok_or_else(|| DBArenaError::ContainerNotFound(container.clone()))?;
+
+    if manager.find_container_uncached(&name).await?.is_some() {
+        return Err(DBArenaError::NameInUse { name });
+    }
+
+    println!(
+        "{} Cloning {} to {}...",
+        style("→").cyan(),
+        style(&source.name).bold(),
+        style(&name).bold()
+    );
+
+    let cloned = manager.clone_container(&source, &name).await?;
+    println!("  {} Container cloned", style("✓").green());
+
+    if start {
+        let id = manager.start_container(&cloned.id).await?;
+        println!("  {} Container started", style("✓").green());
+
+        let database = DatabaseType::from_string(&cloned.database_type).ok_or_else(|| {
+            DBArenaError::InvalidConfig(format!(
+                "Unknown database type: {}",
+                cloned.database_type
+            ))
+        })?;
+        let checker = checker_for(database, docker_client.docker().clone());
+
+        wait_for_healthy(
+            &id,
+            checker.as_ref(),
+            DEFAULT_HEALTH_TIMEOUT,
+            &IndicatifProgress::new(),
+        )
+        .await?;
+    }
+
+    summary::record("cloned", 1);
+    println!("\n{}", style("Clone ready!").green().bold());
+    println!("  {}: {}", style("Name").bold(), cloned.name);
+    println!("  {}: {}", style("ID").bold(), &cloned.id[..12]);
+    println!(
+        "  {}: {}",
+        style("Port").bold(),
+        cloned
+            .host_port
+            .map(|p| p.to_string())
+            .unwrap_or_else(|| "N/A".to_string())
+    );
+    if !start {
+        println!(
+            "\n  Start it with: {}",
+            style(format!("dbarena start {}", cloned.name)).cyan()
+        );
+    }
+
+    Ok(())
+}
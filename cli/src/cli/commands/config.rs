@@ -1,4 +1,7 @@
-use crate::config::{load_or_default, validate_config};
+use crate::cli::output::OutputFormat;
+use crate::config::{
+    diff_configs, load_config_with, load_or_default, load_or_default_with, validate_config,
+};
 use crate::Result;
 use console::style;
 use std::path::PathBuf;
@@ -7,11 +10,12 @@ use std::path::PathBuf;
 pub async fn handle_config_validate(
     config_path: Option<PathBuf>,
     check_scripts: bool,
+    allow_missing_env: bool,
 ) -> Result<()> {
     println!("{}", style("Validating configuration...").bold().cyan());
 
     // Load config
-    let config = load_or_default(config_path.clone())?;
+    let config = load_or_default_with(config_path.clone(), allow_missing_env)?;
 
     // Validate config
     let validation_result = validate_config(&config)?;
@@ -270,3 +274,76 @@ env = { POSTGRES_DB = "myapp_prod", POSTGRES_PASSWORD = "CHANGE_ME" }
 
     Ok(())
 }
+
+/// Handle `config diff` command
+///
+/// Exits with an error when the configurations differ so the command can gate CI.
+pub async fn handle_config_diff(
+    a: PathBuf,
+    b: PathBuf,
+    format: OutputFormat,
+    allow_missing_env: bool,
+) -> Result<()> {
+    let before = load_config_with(&a, allow_missing_env)?;
+    let after = load_config_with(&b, allow_missing_env)?;
+    let changes = diff_configs(&before, &after)?;
+
+    if format.is_structured() {
+        let report = serde_json::json!({
+            "a": a.display().to_string(),
+            "b": b.display().to_string(),
+            "changes": changes
+                .iter()
+                .map(|change| {
+                    serde_json::json!({
+                        "path": change.path,
+                        "kind": change.kind(),
+                        "before": change.before,
+                        "after": change.after,
+                    })
+                })
+                .collect::<Vec<_>>(),
+        });
+        format.print(&report)?;
+    } else {
+        println!(
+            "{} {} → {}",
+            style("Configuration diff:").bold().cyan(),
+            style(a.display()).dim(),
+            style(b.display()).dim()
+        );
+        println!("{}", "─".repeat(60));
+
+        if changes.is_empty() {
+            println!("{} No differences", style("✓").green().bold());
+        }
+
+        for change in &changes {
+            match (&change.before, &change.after) {
+                (None, Some(after)) => {
+                    println!("  {} {} = {}", style("+").green(), change.path, after)
+                }
+                (Some(before), None) => {
+                    println!("  {} {} = {}", style("-").red(), change.path, before)
+                }
+                (Some(before), Some(after)) => println!(
+                    "  {} {}: {} → {}",
+                    style("~").yellow(),
+                    change.path,
+                    before,
+                    after
+                ),
+                (None, None) => {}
+            }
+        }
+    }
+
+    if !changes.is_empty() {
+        return Err(crate::DBArenaError::ConfigError(format!(
+            "configurations differ ({} change(s))",
+            changes.len()
+        )));
+    }
+
+    Ok(())
+}
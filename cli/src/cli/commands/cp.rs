@@ -0,0 +1,93 @@
+use crate::container::{ContainerManager, DockerClient};
+use crate::init::{copy_from_container, copy_path_to_container};
+use crate::{DBArenaError, Result};
+use console::style;
+use std::path::PathBuf;
+
+/// One side of a `cp`: a host path or `container:/path`
+#[derive(Debug, PartialEq, Eq)]
+enum Location {
+    Host(PathBuf),
+    Container { container: String, path: String },
+}
+
+/// Parse `container:/path` or a host path
+///
+/// As with `docker cp`, a host path containing `:` must be written with a
+/// `/` before the colon (e.g. `./a:b`).
+fn parse_location(arg: &str) -> Location {
+    match arg.split_once(':') {
+        Some((container, path)) if !container.is_empty() && !container.contains(['/', '\\']) => {
+            Location::Container {
+                container: container.to_string(),
+                path: if path.is_empty() { "/".to_string() } else { path.to_string() },
+            }
+        }
+        _ => Location::Host(PathBuf::from(arg)),
+    }
+}
+
+/// Handle the cp command: copy files or directories between a container and the host
+pub async fn handle_cp(source: String, dest: String) -> Result<()> {
+    let (container, container_path, local, download) =
+        match (parse_location(&source), parse_location(&dest)) {
+            (Location::Container { container, path }, Location::Host(local)) => {
+                (container, path, local, true)
+            }
+            (Location::Host(local), Location::Container { container, path }) => {
+                (container, path, local, false)
+            }
+            _ => {
+                return Err(DBArenaError::InvalidConfig(
+                    "cp copies between a container and the host: exactly one of SOURCE and \
+                     DEST must be 'container:/path'"
+                        .to_string(),
+                ))
+            }
+        };
+
+    let docker_client = DockerClient::new()?;
+    docker_client.verify_connection().await?;
+    let manager = ContainerManager::new(docker_client.clone());
+    let found = manager
+        .find_container(&container)
+        .await?
+        .ok_or_else(|| DBArenaError::ContainerNotFound(container.clone()))?;
+
+    if download {
+        copy_from_container(docker_client.docker(), &found.id, &container_path, &local).await?;
+    } else {
+        copy_path_to_container(docker_client.docker(), &found.id, &local, &container_path).await?;
+    }
+
+    println!(
+        "{} Copied {} to {}",
+        style("✓").green(),
+        style(&source).bold(),
+        style(&dest).bold()
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_location() {
+        assert_eq!(
+            parse_location("pg-main:/tmp/dump.sql"),
+            Location::Container {
+                container: "pg-main".to_string(),
+                path: "/tmp/dump.sql".to_string()
+            }
+        );
+        assert_eq!(parse_location("./dump.sql"), Location::Host(PathBuf::from("./dump.sql")));
+        assert_eq!(
+            parse_location("./odd:name.sql"),
+            Location::Host(PathBuf::from("./odd:name.sql"))
+        );
+        assert_eq!(parse_location(":/tmp"), Location::Host(PathBuf::from(":/tmp")));
+    }
+}
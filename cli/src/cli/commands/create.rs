@@ -1,10 +1,14 @@
-use crate::cli::interactive;
-use crate::config::{load_or_default, resolve_profile, get_database_env, merge_env_vars};
-use crate::container::{ContainerConfig, ContainerManager, DatabaseType, DockerClient};
-use crate::health::{
-    wait_for_healthy, MySQLHealthChecker, PostgresHealthChecker, SQLServerHealthChecker,
+use crate::cli::{interactive, summary};
+use crate::config::{load_or_default, parse_env_file};
+use crate::container::{
+    is_known_capability, parse_tmpfs, Container, ContainerManager, ContainerRegistry,
+    DatabaseType, DockerClient, RegistryEntry, Ulimit,
 };
-use crate::init::{execute_init_scripts, LogManager};
+use crate::health::RetryPolicy;
+use crate::init::expand_init_scripts;
+use crate::orchestrator::{create_database, CreateEvent, CreateObserver, CreateOptions};
+use crate::progress::{IndicatifProgress, Progress};
+use crate::util::bounded::for_each_concurrent;
 use crate::Result;
 use console::style;
 use indicatif::{ProgressBar, ProgressStyle};
@@ -13,9 +17,22 @@ use std::fs;
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
-use tracing::{error, info};
+use tracing::{error, info, warn};
 
-const DEFAULT_HEALTH_TIMEOUT: Duration = Duration::from_secs(60);
+/// Prints warnings and shows spinners for pulls and health waits
+struct TerminalObserver;
+
+impl CreateObserver for TerminalObserver {
+    fn event(&self, event: CreateEvent) {
+        if let CreateEvent::Warning(message) = event {
+            println!("{} {}", style("⚠").yellow(), message);
+        }
+    }
+
+    fn progress(&self) -> Box<dyn Progress> {
+        Box::new(IndicatifProgress::new())
+    }
+}
 
 #[allow(clippy::too_many_arguments)]
 pub async fn handle_create(
@@ -25,6 +42,8 @@ pub async fn handle_create(
     name: Option<String>,
     port: Option<u16>,
     persistent: bool,
+    tmpfs_args: Vec<String>,
+    ephemeral_data: bool,
     memory: Option<u64>,
     cpu_shares: Option<u64>,
     config_path: Option<PathBuf>,
@@ -33,16 +52,33 @@ pub async fn handle_create(
     env_file: Option<PathBuf>,
     init_scripts: Vec<PathBuf>,
     continue_on_error: bool,
+    atomic: bool,
+    recreate: bool,
+    reuse: bool,
+    user: Option<String>,
+    workdir: Option<String>,
+    cap_add: Vec<String>,
+    cap_drop: Vec<String>,
+    security_opt: Vec<String>,
+    ulimit_args: Vec<String>,
+    cmd: Vec<String>,
+    replace_cmd: bool,
     _keep_on_error: bool,
     _log_dir: Option<PathBuf>,
     _script_timeout: u64,
+    health_interval: Option<u64>,
+    health_max_interval: Option<u64>,
     _validate_only: bool,
+    concurrency: usize,
 ) -> Result<()> {
     info!("Starting create command");
 
     // Load configuration file
     let config = load_or_default(config_path)?;
 
+    // Expand directories and globs into an ordered script list
+    let init_scripts = expand_init_scripts(&init_scripts)?;
+
     // Helper function to parse KEY=VALUE env args
     let parse_env_args = |args: &[String]| -> Result<HashMap<String, String>> {
         let mut env_map = HashMap::new();
@@ -59,44 +95,21 @@ pub async fn handle_create(
         Ok(env_map)
     };
 
-    // Helper function to parse env file
-    let parse_env_file = |path: PathBuf| -> Result<HashMap<String, String>> {
-        let content = fs::read_to_string(&path).map_err(|e| {
-            crate::DBArenaError::ConfigError(format!(
-                "Failed to read env file '{}': {}",
-                path.display(),
-                e
-            ))
-        })?;
-
-        let mut env_map = HashMap::new();
-        for (line_num, line) in content.lines().enumerate() {
-            let line = line.trim();
-            // Skip empty lines and comments
-            if line.is_empty() || line.starts_with('#') {
-                continue;
-            }
-
-            let parts: Vec<&str> = line.splitn(2, '=').collect();
-            if parts.len() != 2 {
-                return Err(crate::DBArenaError::InvalidEnvVar(format!(
-                    "Invalid format in env file '{}' at line {}: '{}'. Expected KEY=VALUE",
-                    path.display(),
-                    line_num + 1,
-                    line
-                )));
-            }
-            env_map.insert(parts[0].to_string(), parts[1].to_string());
-        }
-        Ok(env_map)
-    };
-
     // Parse CLI env args
     let cli_env = parse_env_args(&env_args)?;
+    let ulimits = ulimit_args
+        .iter()
+        .map(|spec| Ulimit::parse(spec))
+        .collect::<Result<Vec<_>>>()?;
+    let tmpfs = tmpfs_args
+        .iter()
+        .map(|spec| parse_tmpfs(spec))
+        .collect::<Result<Vec<_>>>()?;
+    let health_policy = health_policy(health_interval, health_max_interval)?;
 
     // Load env file if specified
     let file_env = if let Some(env_file_path) = env_file {
-        parse_env_file(env_file_path)?
+        parse_env_file(&env_file_path)?
     } else {
         HashMap::new()
     };
@@ -156,6 +169,24 @@ pub async fn handle_create(
     // Use interactive profile or CLI profile
     let profile = interactive_profile.or(profile);
 
+    if ephemeral_data || !tmpfs.is_empty() {
+        println!(
+            "{} tmpfs mounts live in memory: their data is lost when the container stops",
+            style("⚠").yellow()
+        );
+    }
+
+    // Docker rejects unknown capabilities itself; flag likely typos up front
+    for cap in cap_add.iter().chain(&cap_drop) {
+        if !is_known_capability(cap) {
+            println!(
+                "{} Unknown capability '{}' (expected e.g. NET_ADMIN, SYS_NICE)",
+                style("⚠").yellow(),
+                cap
+            );
+        }
+    }
+
     // Initialize Docker client
     let pb = ProgressBar::new_spinner();
     pb.set_style(
@@ -187,76 +218,86 @@ pub async fn handle_create(
     }
     println!();
 
-    // Spawn tasks for all containers
-    let mut tasks = Vec::new();
-
-    for selection in selections {
+    // Everything but the database and version is shared across selections
+    let mut template = CreateOptions::new(DatabaseType::Postgres);
+    template.name = name;
+    template.port = port;
+    template.persistent = persistent;
+    template.tmpfs = tmpfs;
+    template.ephemeral_data = ephemeral_data;
+    template.memory = memory;
+    template.cpu_shares = cpu_shares;
+    template.profile = profile.clone();
+    template.file_env = file_env;
+    template.env = cli_env;
+    template.init_scripts = init_scripts.clone();
+    template.continue_on_error = continue_on_error;
+    template.atomic = atomic;
+    template.recreate = recreate;
+    template.reuse = reuse;
+    template.user = user;
+    template.workdir = workdir;
+    template.cap_add = cap_add;
+    template.cap_drop = cap_drop;
+    template.security_opt = security_opt;
+    template.ulimits = ulimits;
+    template.cmd = cmd;
+    template.replace_cmd = replace_cmd;
+    template.health_policy = health_policy;
+
+    // Spawn creation tasks, at most `concurrency` at a time, reporting each as it finishes
+    let results = for_each_concurrent(selections, concurrency, |selection| {
         let manager_clone = Arc::clone(&manager);
-        let name_clone = name.clone();
         let db_label = format!("{} ({})", selection.database.as_str(), selection.version);
         let display_name = format!("{}-{}", selection.database.as_str(), selection.version);
 
         let config_clone = config.clone();
-        let profile_clone = profile.clone();
-        let cli_env_clone = cli_env.clone();
-        let file_env_clone = file_env.clone();
-        let init_scripts_clone = init_scripts.clone();
-
-        let task = tokio::spawn(async move {
-            let result = create_single_database_simple(
-                &manager_clone,
-                selection.database,
-                selection.version.clone(),
-                name_clone,
-                port,
-                persistent,
-                memory,
-                cpu_shares,
-                config_clone,
-                profile_clone,
-                cli_env_clone,
-                file_env_clone,
-                init_scripts_clone,
-                continue_on_error,
-            )
-            .await;
-
-            (display_name, result)
-        });
-
-        tasks.push((task, db_label));
-    }
-
-    // Wait for all container creation tasks to complete and show results as they finish
-    let mut results = Vec::new();
-    for (task, db_label) in tasks {
-        match task.await {
-            Ok((display_name, result)) => {
-                match &result {
-                    Ok(_) => println!(
-                        "  {} {} - Ready",
-                        style("✓").green(),
-                        style(&display_name).cyan()
-                    ),
-                    Err(e) => println!(
-                        "  {} {} - Failed: {}",
-                        style("✗").red(),
-                        style(&display_name).cyan(),
-                        e
-                    ),
+        let mut options = template.clone();
+        options.database = selection.database;
+        options.version = Some(selection.version);
+
+        async move {
+            let task = tokio::spawn(async move {
+                create_database(&manager_clone, &config_clone, options, &TerminalObserver).await
+            });
+
+            let result = match task.await {
+                Ok(result) => result,
+                Err(e) => {
+                    error!("Task panicked for {}: {}", db_label, e);
+                    Err(crate::DBArenaError::Other(format!("Task failed: {}", e)))
                 }
-                results.push((db_label, result));
+            };
+            match &result {
+                Ok(_) => println!(
+                    "  {} {} - Ready",
+                    style("✓").green(),
+                    style(&display_name).cyan()
+                ),
+                Err(e) => println!(
+                    "  {} {} - Failed: {}",
+                    style("✗").red(),
+                    style(&display_name).cyan(),
+                    e
+                ),
             }
-            Err(e) => {
-                error!("Task panicked for {}: {}", db_label, e);
-                results.push((
-                    db_label,
-                    Err(crate::DBArenaError::Other(format!("Task failed: {}", e))),
-                ));
+            (db_label, result)
+        }
+    })
+    .await;
+
+    // Remember how each container was created; Docker labels don't carry this
+    let recorded_scripts: Vec<PathBuf> = init_scripts
+        .iter()
+        .map(|p| fs::canonicalize(p).unwrap_or_else(|_| p.clone()))
+        .collect();
+    for (_, result) in &results {
+        if let Ok(container) = result {
+            if let Err(e) = record_container(container, profile.clone(), &recorded_scripts) {
+                warn!("Failed to record {} in registry: {}", container.name, e);
             }
         }
     }
-
     let elapsed = start_time.elapsed();
 
     // Print summary
@@ -289,6 +330,9 @@ pub async fn handle_create(
         }
     }
 
+    summary::record("created", success_count);
+    summary::record("failed", failed_count);
+
     println!(
         "\n{} in {:.2}s",
         style(format!(
@@ -309,123 +353,42 @@ pub async fn handle_create(
     Ok(())
 }
 
-#[allow(clippy::too_many_arguments)]
-async fn create_single_database_simple(
-    manager: &ContainerManager,
-    database: DatabaseType,
-    version: String,
-    name: Option<String>,
-    port: Option<u16>,
-    persistent: bool,
-    memory: Option<u64>,
-    cpu_shares: Option<u64>,
-    db_config: crate::config::DBArenaConfig,
-    profile: Option<String>,
-    cli_env: HashMap<String, String>,
-    file_env: HashMap<String, String>,
-    init_scripts: Vec<PathBuf>,
-    continue_on_error: bool,
-) -> Result<()> {
-    // Build configuration
-    let mut config = ContainerConfig::new(database);
-    config = config.with_version(version);
-    if let Some(n) = name {
-        config = config.with_name(n);
-    }
-    if let Some(p) = port {
-        config = config.with_port(p);
-    }
-    config = config.with_persistent(persistent);
-    if let Some(m) = memory {
-        config = config.with_memory_limit(m);
-    }
-    if let Some(c) = cpu_shares {
-        config = config.with_cpu_shares(c);
-    }
-
-    // Build environment variables with proper precedence:
-    // 1. Database base env vars from config
-    // 2. Profile env vars (if profile specified)
-    // 3. Env file env vars
-    // 4. CLI env vars (highest precedence)
-    let mut layers = vec![get_database_env(&db_config, database)];
-
-    if let Some(profile_name) = profile {
-        let profile_env = resolve_profile(&db_config, &profile_name, database)?;
-        layers.push(profile_env);
+/// Health check backoff from --health-interval / --health-max-interval (milliseconds)
+fn health_policy(interval_ms: Option<u64>, max_interval_ms: Option<u64>) -> Result<RetryPolicy> {
+    let default = RetryPolicy::default();
+    let initial = interval_ms.map_or(default.initial_interval, Duration::from_millis);
+    // A long first interval raises the cap with it unless one was given
+    let max = max_interval_ms.map_or(default.max_interval.max(initial), Duration::from_millis);
+
+    if initial.is_zero() {
+        return Err(crate::DBArenaError::InvalidConfig(
+            "--health-interval must be greater than 0".to_string(),
+        ));
     }
-
-    layers.push(file_env);
-    layers.push(cli_env);
-
-    let env_vars = merge_env_vars(layers);
-
-    // Apply env vars and init scripts to config
-    config = config.with_env_vars(env_vars);
-    config = config.with_init_scripts(init_scripts);
-    config = config.with_continue_on_error(continue_on_error);
-
-    // Step 1: Ensure image is available
-    let image = database.docker_image(&config.version);
-    let docker = DockerClient::new()?;
-
-    if !docker.image_exists(&image).await? {
-        docker.pull_image(&image).await?;
+    if max < initial {
+        return Err(crate::DBArenaError::InvalidConfig(format!(
+            "--health-max-interval ({}ms) is shorter than --health-interval ({}ms)",
+            max.as_millis(),
+            initial.as_millis()
+        )));
     }
 
-    // Step 2: Create container
-    let container = manager.create_container(config.clone()).await?;
-
-    // Step 3: Start container
-    manager.start_container(&container.id).await?;
-
-    // Step 4: Wait for healthy
-    let checker: Box<dyn crate::health::HealthChecker> = match database {
-        DatabaseType::Postgres => Box::new(PostgresHealthChecker::new(docker.docker().clone())),
-        DatabaseType::MySQL => Box::new(MySQLHealthChecker::new(docker.docker().clone())),
-        DatabaseType::SQLServer => Box::new(SQLServerHealthChecker::new(docker.docker().clone())),
-    };
-
-    wait_for_healthy(&container.id, checker.as_ref(), DEFAULT_HEALTH_TIMEOUT).await?;
-
-    // Step 5: Execute initialization scripts (if any)
-    if !config.init_scripts.is_empty() {
-        let log_manager = LogManager::new(None)?;
-        let results = execute_init_scripts(
-            docker.docker(),
-            &container.id,
-            config.init_scripts.clone(),
-            database,
-            &config,
-            config.continue_on_error,
-            &log_manager,
-        )
-        .await?;
-
-        // Check if any scripts failed
-        let failed_scripts: Vec<_> = results.iter().filter(|r| !r.success).collect();
-        if !failed_scripts.is_empty() && !config.continue_on_error {
-            // Format error message with details
-            let error_details: Vec<String> = failed_scripts
-                .iter()
-                .map(|r| {
-                    format!(
-                        "  - {}: {}",
-                        r.script_path.display(),
-                        r.error
-                            .as_ref()
-                            .map(|e| e.to_string())
-                            .unwrap_or_else(|| "Unknown error".to_string())
-                    )
-                })
-                .collect();
-
-            return Err(crate::DBArenaError::InitScriptFailed(format!(
-                "Initialization scripts failed:\n{}",
-                error_details.join("\n")
-            )));
-        }
-    }
+    Ok(RetryPolicy::new(initial, max))
+}
 
-    Ok(())
+/// Record creation-time details that Docker labels don't carry
+fn record_container(
+    container: &Container,
+    profile: Option<String>,
+    init_scripts: &[PathBuf],
+) -> Result<()> {
+    ContainerRegistry::new()?.record(RegistryEntry {
+        name: container.name.clone(),
+        id: container.id.clone(),
+        database: container.database_type.clone(),
+        version: container.version.clone(),
+        profile,
+        init_scripts: init_scripts.to_vec(),
+        created_at: container.created_at,
+    })
 }
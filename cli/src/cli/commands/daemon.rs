@@ -0,0 +1,126 @@
+use crate::cli::DaemonCommands;
+use crate::container::DockerClient;
+use crate::daemon::{self, DaemonClient};
+use crate::{DBArenaError, Result};
+use console::style;
+use std::os::unix::process::CommandExt;
+use std::process::{Command, Stdio};
+use std::time::Duration;
+
+const STARTUP_TIMEOUT: Duration = Duration::from_secs(10);
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+pub async fn handle_daemon(command: DaemonCommands) -> Result<()> {
+    match command {
+        DaemonCommands::Start { foreground } => handle_daemon_start(foreground).await,
+        DaemonCommands::Stop => handle_daemon_stop().await,
+        DaemonCommands::Status => handle_daemon_status().await,
+    }
+}
+
+async fn handle_daemon_start(foreground: bool) -> Result<()> {
+    let path = daemon::socket_path();
+
+    if let Some(mut client) = DaemonClient::connect_to(&path).await {
+        let status = client.status().await?;
+        println!(
+            "{} Daemon already running (pid {})",
+            style("✓").green(),
+            status.pid
+        );
+        return Ok(());
+    }
+
+    if foreground {
+        println!(
+            "{} dbarena daemon listening on {}",
+            style("→").cyan(),
+            style(path.display()).bold()
+        );
+        println!("  Press Ctrl+C to stop");
+        return daemon::run(&path).await;
+    }
+
+    // Re-launch ourselves detached, in a new process group so the
+    // terminal's Ctrl+C doesn't reach it
+    let mut command = Command::new(std::env::current_exe()?);
+    let endpoint = DockerClient::new()?.endpoint().to_string();
+    if endpoint.contains("://") {
+        command.args(["--docker-host", &endpoint]);
+    }
+    command
+        .args(["daemon", "start", "--foreground"])
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .process_group(0);
+    let child = command.spawn()?;
+
+    let deadline = tokio::time::Instant::now() + STARTUP_TIMEOUT;
+    while tokio::time::Instant::now() < deadline {
+        if let Some(mut client) = DaemonClient::connect_to(&path).await {
+            let status = client.status().await?;
+            println!(
+                "{} Daemon started (pid {})",
+                style("✓").green(),
+                status.pid
+            );
+            println!("  Socket: {}", path.display());
+            return Ok(());
+        }
+        tokio::time::sleep(POLL_INTERVAL).await;
+    }
+
+    Err(DBArenaError::Other(format!(
+        "Daemon (pid {}) did not start listening on {} within {}s; run `dbarena daemon start --foreground` to see why",
+        child.id(),
+        path.display(),
+        STARTUP_TIMEOUT.as_secs()
+    )))
+}
+
+async fn handle_daemon_stop() -> Result<()> {
+    let path = daemon::socket_path();
+    let Some(mut client) = DaemonClient::connect_to(&path).await else {
+        println!("{} No daemon running", style("-").dim());
+        return Ok(());
+    };
+
+    client.shutdown().await?;
+
+    let deadline = tokio::time::Instant::now() + STARTUP_TIMEOUT;
+    while path.exists() && tokio::time::Instant::now() < deadline {
+        tokio::time::sleep(POLL_INTERVAL).await;
+    }
+
+    println!("{} Daemon stopped", style("✓").green());
+    Ok(())
+}
+
+async fn handle_daemon_status() -> Result<()> {
+    let path = daemon::socket_path();
+    let Some(mut client) = DaemonClient::connect_to(&path).await else {
+        println!("{} Daemon is not running", style("-").dim());
+        println!(
+            "\n  Start it with: {}",
+            style("dbarena daemon start").cyan()
+        );
+        return Ok(());
+    };
+
+    let status = client.status().await?;
+    let uptime = chrono::Utc::now().timestamp() - status.started_at;
+
+    println!("{} Daemon is running", style("✓").green());
+    println!("  {}: {}", style("PID").bold(), status.pid);
+    println!("  {}: {}", style("Socket").bold(), path.display());
+    println!("  {}: {}", style("Docker").bold(), status.endpoint);
+    println!("  {}: {}s", style("Uptime").bold(), uptime);
+    println!(
+        "  {}: {}",
+        style("Tracked containers").bold(),
+        status.tracked_containers
+    );
+
+    Ok(())
+}
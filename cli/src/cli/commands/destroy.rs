@@ -1,5 +1,6 @@
-use crate::cli::interactive;
-use crate::container::{ContainerManager, DockerClient};
+use crate::cli::{interactive, summary};
+use crate::container::{Container, ContainerManager, DockerClient};
+use crate::util::bounded::for_each_concurrent;
 use crate::{DBArenaError, Result};
 use console::style;
 use dialoguer;
@@ -13,6 +14,8 @@ pub async fn handle_destroy(
     all: bool,
     yes: bool,
     volumes: bool,
+    concurrency: usize,
+    dry_run: bool,
 ) -> Result<()> {
     let docker_client = DockerClient::new()?;
     docker_client.verify_connection().await?;
@@ -36,9 +39,13 @@ pub async fn handle_destroy(
         })?]
     };
 
+    if dry_run {
+        return print_destroy_plan(&manager, container_names, volumes).await;
+    }
+
     // If multiple containers, use multi-progress
     if container_names.len() > 1 {
-        destroy_multiple_with_progress(&manager, container_names, yes, volumes).await
+        destroy_multiple_with_progress(&manager, container_names, yes, volumes, concurrency).await
     } else {
         destroy_single(
             &manager,
@@ -50,6 +57,54 @@ pub async fn handle_destroy(
     }
 }
 
+/// Resolve the containers exactly as a real run would and list what would be removed
+async fn print_destroy_plan(
+    manager: &ContainerManager,
+    container_names: Vec<String>,
+    volumes: bool,
+) -> Result<()> {
+    let mut found: Vec<Container> = Vec::with_capacity(container_names.len());
+    for container_name in container_names {
+        found.push(
+            manager
+                .find_container(&container_name)
+                .await?
+                .ok_or_else(|| DBArenaError::ContainerNotFound(container_name.clone()))?,
+        );
+    }
+
+    if found.is_empty() {
+        println!("No containers to destroy.");
+        return Ok(());
+    }
+
+    println!(
+        "{} Would destroy {} container(s) (dry run, nothing removed):",
+        style("▸").cyan(),
+        found.len()
+    );
+    for container in &found {
+        let plan = manager.destroy_plan(&container.id, volumes).await?;
+        println!(
+            "  {} {} ({}, {})",
+            style("✗").red(),
+            style(&container.name).bold(),
+            &container.id[..12.min(container.id.len())],
+            container.database_type
+        );
+        for volume in &plan.removed_volumes {
+            println!("      {} volume {}", style("remove").red(), volume);
+        }
+        for volume in &plan.kept_volumes {
+            println!("      {}   volume {}", style("keep").dim(), volume);
+        }
+        if let Some(image) = &plan.clone_image {
+            println!("      {} image {}", style("remove").red(), image);
+        }
+    }
+    Ok(())
+}
+
 async fn destroy_single(
     manager: &ContainerManager,
     container_name: String,
@@ -90,6 +145,7 @@ async fn destroy_single(
     // Destroy the container
     manager.destroy_container(&found.id, volumes).await?;
     pb.finish_with_message(format!("{} {} destroyed", style("✓").green(), found.name));
+    summary::record("destroyed", 1);
 
     Ok(())
 }
@@ -99,6 +155,7 @@ async fn destroy_multiple_with_progress(
     container_names: Vec<String>,
     yes: bool,
     volumes: bool,
+    concurrency: usize,
 ) -> Result<()> {
     println!("\n{}", style("Destroying containers...").bold().red());
     println!("{}", "─".repeat(80));
@@ -163,42 +220,40 @@ async fn destroy_multiple_with_progress(
 
     println!();
 
-    // Destroy all confirmed containers sequentially with progress bars
-    let mut success_count = 0;
-    let mut failed_count = 0;
-
-    for container in confirmed_containers {
-        let container_id = container.id.clone();
-        let container_name = container.name.clone();
-
+    // Destroy confirmed containers concurrently with progress bars
+    let results = for_each_concurrent(&confirmed_containers, concurrency, |container| {
         let pb = multi_progress.add(ProgressBar::new_spinner());
         pb.set_style(
             ProgressStyle::default_spinner()
                 .template("{spinner:.red} {msg}")
                 .unwrap(),
         );
-        pb.set_message(format!("Destroying {:<20}", container_name));
+        pb.set_message(format!("Destroying {:<20}", container.name));
 
-        match manager.destroy_container(&container_id, volumes).await {
-            Ok(()) => {
-                pb.finish_with_message(format!(
+        async move {
+            let result = manager.destroy_container(&container.id, volumes).await;
+            match &result {
+                Ok(()) => pb.finish_with_message(format!(
                     "{} {:<20} destroyed",
                     style("✓").green(),
-                    container_name
-                ));
-                success_count += 1;
-            }
-            Err(e) => {
-                pb.finish_with_message(format!(
+                    container.name
+                )),
+                Err(e) => pb.finish_with_message(format!(
                     "{} {:<20} failed: {}",
                     style("✗").red(),
-                    container_name,
+                    container.name,
                     e
-                ));
-                failed_count += 1;
+                )),
             }
+            result
         }
-    }
+    })
+    .await;
+
+    let failed_count = results.iter().filter(|r| r.is_err()).count();
+    let success_count = results.len() - failed_count;
+    summary::record("destroyed", success_count);
+    summary::record("failed", failed_count);
 
     let elapsed = start_time.elapsed();
 
@@ -0,0 +1,55 @@
+use console::style;
+
+use crate::cli::commands::schema::read_schema;
+use crate::cli::target::Target;
+use crate::schema::{IntrospectOptions, SchemaDiff};
+use crate::{DBArenaError, Result};
+
+/// Handle the diff command: compare two containers' schemas
+///
+/// Returns whether they differ, for the caller to set the exit status.
+pub async fn handle_diff(
+    left: String,
+    right: String,
+    tables_only: bool,
+    data: bool,
+) -> Result<bool> {
+    let left = Target::resolve(Some(&left), None, None).await?;
+    let right = Target::resolve(Some(&right), None, None).await?;
+
+    let (left_type, right_type) = (left.params.db_type, right.params.db_type);
+    if left_type != right_type {
+        return Err(DBArenaError::InvalidConfig(format!(
+            "Cannot compare a {} database with a {} database",
+            left_type, right_type
+        )));
+    }
+    if !left_type.is_sql() {
+        return Err(DBArenaError::InvalidConfig(format!(
+            "Schema diff is not supported for NoSQL databases ({})",
+            left_type
+        )));
+    }
+
+    let options = IntrospectOptions {
+        tables_only,
+        row_counts: data,
+    };
+    let left_schema = read_schema(&left, options).await?;
+    let right_schema = read_schema(&right, options).await?;
+
+    let diff = SchemaDiff::between(&left_schema, &right_schema);
+    if diff.is_empty() {
+        println!(
+            "{} {} and {} match ({} table(s))",
+            style("✓").green(),
+            style(&left.name).bold(),
+            style(&right.name).bold(),
+            left_schema.tables.len()
+        );
+        return Ok(false);
+    }
+
+    diff.print(&left.name, &right.name);
+    Ok(true)
+}
@@ -1,5 +1,6 @@
 use console::style;
 
+use crate::cli::output::OutputFormat;
 use crate::docs::catalog::{normalize_db_name, DocCatalog, DocPackSummary};
 use crate::docs::installer::{install_pack, InstallOptions};
 use crate::docs::paths::{pack_index_dir, pack_dir};
@@ -9,7 +10,7 @@ use crate::docs::{parse_doc_id};
 use crate::error::{DBArenaError, Result};
 
 /// Handle docs list command
-pub async fn handle_docs_list(installed_only: bool, available_only: bool, json: bool) -> Result<()> {
+pub async fn handle_docs_list(installed_only: bool, available_only: bool, format: OutputFormat) -> Result<()> {
     let mut installed_only = installed_only;
     let mut available_only = available_only;
     if installed_only && available_only {
@@ -19,7 +20,7 @@ pub async fn handle_docs_list(installed_only: bool, available_only: bool, json:
     let available = DocCatalog::available();
     let installed = list_installed_manifests()?;
 
-    if json {
+    if format.is_structured() {
         let available_summaries: Vec<DocPackSummary> =
             available.iter().map(DocPackSummary::from).collect();
         let output = if installed_only {
@@ -29,7 +30,7 @@ pub async fn handle_docs_list(installed_only: bool, available_only: bool, json:
         } else {
             serde_json::json!({ "available": available_summaries, "installed": installed })
         };
-        println!("{}", serde_json::to_string_pretty(&output)?);
+        format.print(&output)?;
         return Ok(());
     }
 
@@ -97,7 +98,7 @@ pub async fn handle_docs_search(
     version: String,
     query: String,
     limit: usize,
-    json: bool,
+    format: OutputFormat,
 ) -> Result<()> {
     let db = normalize_db_name(&db);
     let index_dir = pack_index_dir(&db, &version);
@@ -108,14 +109,14 @@ pub async fn handle_docs_search(
         )));
     }
     let results = search_pack(&index_dir, &db, &version, &query, limit)?;
-    if json {
+    if format.is_structured() {
         let output = serde_json::json!({
             "query": query,
             "db": db,
             "version": version,
             "results": results,
         });
-        println!("{}", serde_json::to_string_pretty(&output)?);
+        format.print(&output)?;
         return Ok(());
     }
 
@@ -136,7 +137,7 @@ pub async fn handle_docs_search(
 }
 
 /// Handle docs show command
-pub async fn handle_docs_show(doc_id: String, max_chars: usize, json: bool) -> Result<()> {
+pub async fn handle_docs_show(doc_id: String, max_chars: usize, format: OutputFormat) -> Result<()> {
     let (db, version_slug) = parse_doc_id(&doc_id).ok_or_else(|| {
         DBArenaError::DocsError("Invalid doc_id format".to_string())
     })?;
@@ -154,8 +155,8 @@ pub async fn handle_docs_show(doc_id: String, max_chars: usize, json: bool) -> R
         chunk.body = chunk.body.chars().take(max_chars).collect::<String>() + "...";
     }
 
-    if json {
-        println!("{}", serde_json::to_string_pretty(&chunk)?);
+    if format.is_structured() {
+        format.print(&chunk)?;
         return Ok(());
     }
 
@@ -0,0 +1,266 @@
+use crate::container::models::ContainerStatus;
+use crate::container::{Container, ContainerManager, DockerClient};
+use crate::{DBArenaError, Result};
+use console::style;
+use std::time::Duration;
+
+/// How long a container may sit in `created` before it counts as a failed create
+/// rather than one still being set up
+const DEAD_AFTER_SECS: i64 = 60;
+
+/// How long to wait for Docker Desktop to answer after launching it
+const DOCKER_START_TIMEOUT: Duration = Duration::from_secs(90);
+
+/// Handle the doctor command: diagnose common setup problems and, with `fix`,
+/// repair them
+///
+/// Only dbarena's own containers are ever removed or stopped.
+pub async fn handle_doctor(fix: bool, yes: bool, port: Option<u16>) -> Result<()> {
+    let mut found = 0;
+    let mut fixed = 0;
+
+    // Docker first: nothing else can be checked without it
+    let docker_client = DockerClient::new()?;
+    if let Err(e) = docker_client.verify_connection().await {
+        found += 1;
+        println!("{} Docker is not reachable: {}", style("✗").red(), e);
+
+        let repaired = fix
+            && docker_client.is_local()
+            && confirm("Start Docker Desktop?", yes)?
+            && start_docker_desktop(&docker_client).await;
+        if !repaired {
+            return finish(found, fixed, fix);
+        }
+        fixed += 1;
+    }
+    println!(
+        "{} Docker is reachable at {}",
+        style("✓").green(),
+        docker_client.endpoint()
+    );
+
+    let manager = ContainerManager::new(docker_client.clone());
+    let containers = manager.list_containers_uncached(true).await?;
+
+    let now = chrono::Utc::now().timestamp();
+    let dead = dead_containers(&containers, now);
+    if dead.is_empty() {
+        println!("{} No failed dbarena containers", style("✓").green());
+    }
+    for container in dead {
+        found += 1;
+        println!(
+            "{} {} was created but never started",
+            style("✗").red(),
+            style(&container.name).bold()
+        );
+        if fix && confirm(&format!("Remove container '{}'?", container.name), yes)? {
+            manager.destroy_container(&container.id, false).await?;
+            println!("  {} Removed {}", style("✓").green(), container.name);
+            fixed += 1;
+        }
+    }
+
+    if let Some(port) = port {
+        // A remote daemon's ports can't be probed from here; only dbarena's
+        // own containers can be seen holding one
+        let local = docker_client.is_local();
+        if local && port_is_free(port) {
+            println!("{} Port {} is free", style("✓").green(), port);
+        } else if !local && port_holder(&containers, port).is_none() {
+            println!(
+                "{} No dbarena container holds port {} (other processes on {} aren't checked)",
+                style("✓").green(),
+                port,
+                docker_client.endpoint()
+            );
+        } else {
+            found += 1;
+            match port_holder(&containers, port) {
+                Some(holder) => {
+                    println!(
+                        "{} Port {} is held by dbarena container {}",
+                        style("✗").red(),
+                        port,
+                        style(&holder.name).bold()
+                    );
+                    let prompt = format!("Stop container '{}' to free port {}?", holder.name, port);
+                    if fix && confirm(&prompt, yes)? {
+                        manager.stop_container(&holder.id, None).await?;
+                        println!("  {} Stopped {}", style("✓").green(), holder.name);
+                        fixed += 1;
+                    }
+                }
+                None => println!(
+                    "{} Port {} is in use by a process outside dbarena; leaving it alone",
+                    style("✗").red(),
+                    port
+                ),
+            }
+        }
+    }
+
+    finish(found, fixed, fix)
+}
+
+/// Print the summary and fail when problems are left unfixed, so scripts
+/// can gate on `dbarena doctor`
+fn finish(found: usize, fixed: usize, fix: bool) -> Result<()> {
+    print_summary(found, fixed, fix);
+    if fixed < found {
+        return Err(DBArenaError::Other(format!(
+            "{} problem(s) remain",
+            found - fixed
+        )));
+    }
+    Ok(())
+}
+
+/// dbarena containers left in `created` for longer than a create takes
+fn dead_containers(containers: &[Container], now: i64) -> Vec<&Container> {
+    containers
+        .iter()
+        .filter(|c| c.status == ContainerStatus::Creating && now - c.created_at >= DEAD_AFTER_SECS)
+        .collect()
+}
+
+/// The running dbarena container publishing `port`, if any
+fn port_holder(containers: &[Container], port: u16) -> Option<&Container> {
+    containers.iter().find(|c| {
+        c.host_port == Some(port)
+            && matches!(
+                c.status,
+                ContainerStatus::Running | ContainerStatus::Healthy | ContainerStatus::Unhealthy
+            )
+    })
+}
+
+/// Whether `port` can be bound on this machine
+fn port_is_free(port: u16) -> bool {
+    std::net::TcpListener::bind(("0.0.0.0", port)).is_ok()
+}
+
+/// The command that launches Docker Desktop on this platform
+fn docker_desktop_command() -> Option<(&'static str, &'static [&'static str])> {
+    if cfg!(target_os = "macos") {
+        Some(("open", &["-a", "Docker"]))
+    } else if cfg!(target_os = "windows") {
+        Some((
+            "cmd",
+            &["/C", "start", "", r"C:\Program Files\Docker\Docker\Docker Desktop.exe"],
+        ))
+    } else if cfg!(target_os = "linux") {
+        Some(("systemctl", &["--user", "start", "docker-desktop"]))
+    } else {
+        None
+    }
+}
+
+/// Launch Docker Desktop and wait for the daemon to answer
+async fn start_docker_desktop(docker_client: &DockerClient) -> bool {
+    let Some((program, args)) = docker_desktop_command() else {
+        println!("  {} Don't know how to start Docker here", style("✗").red());
+        return false;
+    };
+
+    let launched = tokio::process::Command::new(program)
+        .args(args)
+        .status()
+        .await
+        .is_ok_and(|status| status.success());
+    if !launched {
+        println!(
+            "  {} Could not start Docker Desktop; start Docker manually",
+            style("✗").red()
+        );
+        return false;
+    }
+
+    println!("  {} Waiting for Docker to start...", style("→").cyan());
+    let deadline = tokio::time::Instant::now() + DOCKER_START_TIMEOUT;
+    while tokio::time::Instant::now() < deadline {
+        if docker_client.docker().ping().await.is_ok() {
+            return true;
+        }
+        tokio::time::sleep(Duration::from_secs(2)).await;
+    }
+    println!(
+        "  {} Docker did not answer within {}s",
+        style("✗").red(),
+        DOCKER_START_TIMEOUT.as_secs()
+    );
+    false
+}
+
+fn confirm(prompt: &str, yes: bool) -> Result<bool> {
+    if yes {
+        return Ok(true);
+    }
+    dialoguer::Confirm::new()
+        .with_prompt(prompt)
+        .interact()
+        .map_err(|e| DBArenaError::Other(format!("Failed to read input: {}", e)))
+}
+
+fn print_summary(found: usize, fixed: usize, fix: bool) {
+    println!();
+    if found == 0 {
+        println!("{} No problems found", style("✓").green().bold());
+    } else if fix {
+        println!("Fixed {} of {} problem(s)", fixed, found);
+    } else {
+        println!(
+            "{} problem(s) found; run {} to repair them",
+            found,
+            style("dbarena doctor --fix").bold()
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn container(name: &str, status: ContainerStatus, host_port: Option<u16>) -> Container {
+        Container {
+            id: format!("{}-id", name),
+            name: name.to_string(),
+            database_type: "postgres".to_string(),
+            version: "16".to_string(),
+            status,
+            port: 5432,
+            host_port,
+            persistent: false,
+            created_at: 1_000,
+        }
+    }
+
+    #[test]
+    fn test_dead_containers() {
+        let containers = vec![
+            container("failed", ContainerStatus::Creating, None),
+            container("running", ContainerStatus::Running, Some(5432)),
+            container("stopped", ContainerStatus::Exited, None),
+        ];
+
+        let dead = dead_containers(&containers, 1_000 + DEAD_AFTER_SECS);
+        assert_eq!(dead.len(), 1);
+        assert_eq!(dead[0].name, "failed");
+
+        // A create still in progress is left alone
+        assert!(dead_containers(&containers, 1_010).is_empty());
+    }
+
+    #[test]
+    fn test_port_holder() {
+        let containers = vec![
+            container("old", ContainerStatus::Exited, Some(5432)),
+            container("pg", ContainerStatus::Running, Some(5432)),
+            container("mysql", ContainerStatus::Running, Some(3306)),
+        ];
+
+        assert_eq!(port_holder(&containers, 5432).unwrap().name, "pg");
+        assert!(port_holder(&containers, 6379).is_none());
+    }
+}
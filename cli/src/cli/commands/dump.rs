@@ -0,0 +1,280 @@
+use crate::container::{
+    connection_params, ConnParams, ContainerManager, DatabaseType, DockerClient, SQLITE_DB_PATH,
+};
+use crate::monitoring::format_bytes;
+use crate::{DBArenaError, Result};
+use bollard::container::LogOutput;
+use bollard::exec::{CreateExecOptions, StartExecResults};
+use console::style;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use futures::StreamExt;
+use std::fs::{self, File};
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+/// Where the SQL Server backup is staged inside the container before streaming
+const SQLSERVER_BACKUP_PATH: &str = "/tmp/dbarena_dump.bak";
+
+/// Handle the dump command: write a logical backup of a container's database to a local file
+pub async fn handle_dump(container: String, output: PathBuf, format: String) -> Result<()> {
+    let docker_client = DockerClient::new()?;
+    docker_client.verify_connection().await?;
+
+    let manager = ContainerManager::new(docker_client.clone());
+    let found = manager
+        .find_container(&container)
+        .await?
+        .ok_or_else(|| DBArenaError::ContainerNotFound(container.clone()))?;
+
+    // Credentials come from the container's own environment
+    let docker = docker_client.docker();
+    let params = connection_params(docker, &found).await?;
+    let (cmd, exec_env) = build_dump_command(&params, &format)?;
+
+    println!(
+        "{} Dumping {} to {}...",
+        style("→").cyan(),
+        style(&found.name).bold(),
+        style(output.display()).bold()
+    );
+
+    let exec = docker
+        .create_exec(
+            &found.id,
+            CreateExecOptions {
+                cmd: Some(cmd),
+                env: Some(exec_env),
+                attach_stdout: Some(true),
+                attach_stderr: Some(true),
+                ..Default::default()
+            },
+        )
+        .await
+        .map_err(|e| DBArenaError::ContainerOperationFailed(format!("Failed to create exec: {}", e)))?;
+
+    // Write to a side file and only move it into place once the tool succeeded
+    let partial = partial_path(&output);
+    let gzip = output.extension().is_some_and(|ext| ext == "gz");
+    let mut file = DumpFile::create(&partial, gzip)?;
+    let mut stderr = String::new();
+
+    let streamed: Result<()> = async {
+        if let StartExecResults::Attached { output: mut stream, .. } =
+            docker.start_exec(&exec.id, None).await?
+        {
+            while let Some(chunk) = stream.next().await {
+                match chunk? {
+                    LogOutput::StdOut { message } | LogOutput::Console { message } => {
+                        file.write_all(&message)?
+                    }
+                    LogOutput::StdErr { message } => {
+                        stderr.push_str(&String::from_utf8_lossy(&message))
+                    }
+                    LogOutput::StdIn { .. } => {}
+                }
+            }
+        }
+        Ok(())
+    }
+    .await;
+
+    let exit_code = match streamed {
+        Ok(()) => docker.inspect_exec(&exec.id).await?.exit_code.unwrap_or(1),
+        Err(e) => {
+            let _ = fs::remove_file(&partial);
+            return Err(e);
+        }
+    };
+
+    if exit_code != 0 {
+        let _ = fs::remove_file(&partial);
+        return Err(DBArenaError::ContainerOperationFailed(format!(
+            "Dump failed with exit code {}: {}",
+            exit_code,
+            stderr.trim()
+        )));
+    }
+
+    file.finish()?;
+    fs::rename(&partial, &output)?;
+
+    let size = fs::metadata(&output)?.len();
+    println!(
+        "  {} Wrote {} ({})",
+        style("✓").green(),
+        output.display(),
+        format_bytes(size)
+    );
+
+    Ok(())
+}
+
+/// Dump command and extra exec environment for a database
+fn build_dump_command(params: &ConnParams, format: &str) -> Result<(Vec<String>, Vec<String>)> {
+    let db_type = params.db_type;
+    if format == "custom" && db_type != DatabaseType::Postgres {
+        return Err(DBArenaError::InvalidConfig(format!(
+            "--format custom is only supported for Postgres, not {}",
+            db_type
+        )));
+    }
+
+    match db_type {
+        DatabaseType::Postgres => {
+            let mut cmd = vec![
+                "pg_dump".to_string(),
+                "-U".to_string(),
+                params.user.clone(),
+                "-d".to_string(),
+                params.database.clone(),
+            ];
+            if format == "custom" {
+                cmd.push("-Fc".to_string());
+            }
+            let password = format!("PGPASSWORD={}", params.password);
+            Ok((cmd, vec![password]))
+        }
+        DatabaseType::MySQL => {
+            let mut cmd = vec![
+                "mysqldump".to_string(),
+                "-u".to_string(),
+                params.user.clone(),
+                "--single-transaction".to_string(),
+                "--routines".to_string(),
+            ];
+            if params.database.is_empty() {
+                cmd.push("--all-databases".to_string());
+            } else {
+                cmd.push(params.database.clone());
+            }
+            // MYSQL_PWD keeps the password off the command line
+            let password = format!("MYSQL_PWD={}", params.password);
+            Ok((cmd, vec![password]))
+        }
+        DatabaseType::SQLServer => {
+            // sqlcmd can't stream a dump, so back up inside the container and cat the file
+            let db = params.database.replace(']', "]]");
+            let backup = format!(
+                "BACKUP DATABASE [{}] TO DISK = N'{}' WITH INIT",
+                db, SQLSERVER_BACKUP_PATH
+            );
+            let script = format!(
+                "/opt/mssql-tools18/bin/sqlcmd -S localhost -U sa -P \"$SA_PASSWORD\" -C -b -Q \"{}\" >&2 \
+                 && cat {path}; status=$?; rm -f {path}; exit $status",
+                backup,
+                path = SQLSERVER_BACKUP_PATH
+            );
+            let password = format!("SA_PASSWORD={}", params.password);
+            Ok((vec!["sh".to_string(), "-c".to_string(), script], vec![password]))
+        }
+        DatabaseType::SQLite => Ok((
+            vec![
+                "sqlite3".to_string(),
+                SQLITE_DB_PATH.to_string(),
+                ".dump".to_string(),
+            ],
+            Vec::new(),
+        )),
+        // CockroachDB has no logical dump tool; BACKUP needs external storage
+        DatabaseType::Redis | DatabaseType::MongoDB | DatabaseType::CockroachDB => {
+            Err(DBArenaError::InvalidConfig(format!(
+                "Dumping is not supported for {}",
+                db_type
+            )))
+        }
+    }
+}
+
+/// `dump.sql.gz` -> `dump.sql.gz.partial`, next to the final file
+fn partial_path(output: &Path) -> PathBuf {
+    let mut name = output.as_os_str().to_owned();
+    name.push(".partial");
+    PathBuf::from(name)
+}
+
+/// Local dump file, gzip-compressed on the fly for `.gz` paths
+enum DumpFile {
+    Plain(BufWriter<File>),
+    Gzip(GzEncoder<BufWriter<File>>),
+}
+
+impl DumpFile {
+    fn create(path: &Path, gzip: bool) -> Result<Self> {
+        let file = BufWriter::new(File::create(path)?);
+        Ok(if gzip {
+            DumpFile::Gzip(GzEncoder::new(file, Compression::default()))
+        } else {
+            DumpFile::Plain(file)
+        })
+    }
+
+    fn write_all(&mut self, data: &[u8]) -> std::io::Result<()> {
+        match self {
+            DumpFile::Plain(file) => file.write_all(data),
+            DumpFile::Gzip(encoder) => encoder.write_all(data),
+        }
+    }
+
+    fn finish(self) -> std::io::Result<()> {
+        match self {
+            DumpFile::Plain(mut file) => file.flush(),
+            DumpFile::Gzip(encoder) => encoder.finish()?.flush(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::container::container_env;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_postgres_dump_uses_container_credentials() {
+        let env = container_env(vec![
+            "POSTGRES_USER=app".to_string(),
+            "POSTGRES_DB=shop".to_string(),
+            "PATH=/usr/bin".to_string(),
+        ]);
+        let params = ConnParams::from_env(DatabaseType::Postgres, env);
+        let (cmd, exec_env) = build_dump_command(&params, "custom").unwrap();
+        assert_eq!(cmd, ["pg_dump", "-U", "app", "-d", "shop", "-Fc"]);
+        assert_eq!(exec_env, ["PGPASSWORD=postgres"]);
+    }
+
+    #[test]
+    fn test_mysql_dump_defaults_to_all_databases() {
+        let params = ConnParams::from_env(DatabaseType::MySQL, HashMap::new());
+        let (cmd, exec_env) = build_dump_command(&params, "plain").unwrap();
+        assert_eq!(cmd.last().map(String::as_str), Some("--all-databases"));
+        assert_eq!(exec_env, ["MYSQL_PWD=mysql"]);
+        assert!(build_dump_command(&params, "custom").is_err());
+    }
+
+    #[test]
+    fn test_sqlite_dump() {
+        let params = ConnParams::from_env(DatabaseType::SQLite, HashMap::new());
+        let (cmd, exec_env) = build_dump_command(&params, "plain").unwrap();
+        assert_eq!(cmd, ["sqlite3", "/data/db.sqlite", ".dump"]);
+        assert!(exec_env.is_empty());
+    }
+
+    #[test]
+    fn test_gzip_dump_file() {
+        use std::io::Read;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("dump.sql.gz");
+        let mut file = DumpFile::create(&path, true).unwrap();
+        file.write_all(b"CREATE TABLE t (id int);\n").unwrap();
+        file.finish().unwrap();
+
+        let mut decoded = String::new();
+        flate2::read::GzDecoder::new(File::open(&path).unwrap())
+            .read_to_string(&mut decoded)
+            .unwrap();
+        assert_eq!(decoded, "CREATE TABLE t (id int);\n");
+        assert_eq!(partial_path(&path), dir.path().join("dump.sql.gz.partial"));
+    }
+}
@@ -1,10 +1,31 @@
+use crate::cli::summary;
 use crate::container::{ContainerManager, DockerClient};
+use crate::util::bounded::for_each_concurrent;
 use crate::{DBArenaError, Result};
 use bollard::exec::{CreateExecOptions, StartExecResults};
 use console::style;
 use futures::StreamExt;
+use std::fs::File;
 use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Where each container's command output goes besides (or instead of) the terminal
+#[derive(Clone, Copy)]
+struct OutputOptions<'a> {
+    /// Write each container's output to `<dir>/<container-name>.log`
+    dir: Option<&'a Path>,
+    /// Don't echo command output to the terminal
+    quiet: bool,
+}
 
+impl OutputOptions<'_> {
+    fn log_path(&self, container_name: &str) -> Option<PathBuf> {
+        self.dir.map(|dir| dir.join(format!("{}.log", container_name)))
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
 pub async fn handle_exec(
     containers: Vec<String>,
     all: bool,
@@ -12,6 +33,10 @@ pub async fn handle_exec(
     user: Option<String>,
     workdir: Option<String>,
     parallel: bool,
+    concurrency: usize,
+    output_dir: Option<PathBuf>,
+    quiet: bool,
+    timeout: Option<u64>,
     command: Vec<String>,
 ) -> Result<()> {
     if command.is_empty() {
@@ -42,11 +67,44 @@ pub async fn handle_exec(
     );
     println!();
 
-    if parallel && target_containers.len() > 1 {
-        execute_parallel(&docker_client, &target_containers, &command, user.as_deref(), workdir.as_deref()).await
+    if let Some(dir) = &output_dir {
+        std::fs::create_dir_all(dir)?;
+    }
+    let output = OutputOptions {
+        dir: output_dir.as_deref(),
+        quiet,
+    };
+
+    let result = if parallel && target_containers.len() > 1 {
+        execute_parallel(
+            &docker_client,
+            &target_containers,
+            &command,
+            user.as_deref(),
+            workdir.as_deref(),
+            concurrency,
+            output,
+            timeout,
+        )
+        .await
     } else {
-        execute_sequential(&docker_client, &target_containers, &command, user.as_deref(), workdir.as_deref()).await
+        execute_sequential(
+            &docker_client,
+            &target_containers,
+            &command,
+            user.as_deref(),
+            workdir.as_deref(),
+            output,
+            timeout,
+        )
+        .await
+    };
+
+    if let Some(dir) = &output_dir {
+        println!("Output saved to {}", style(dir.display()).cyan());
     }
+
+    result
 }
 
 async fn get_target_containers(
@@ -97,13 +155,28 @@ async fn execute_sequential(
     command: &[String],
     user: Option<&str>,
     workdir: Option<&str>,
+    output: OutputOptions<'_>,
+    timeout: Option<u64>,
 ) -> Result<()> {
     let mut successes = Vec::new();
     let mut failures = Vec::new();
 
     for (id, name) in containers {
         println!("{} {}", style("Container:").cyan(), style(name).bold());
-        match execute_single(docker_client, id, command, user, workdir).await {
+        let log_path = output.log_path(name);
+        let result = execute_single(
+            docker_client,
+            id,
+            command,
+            user,
+            workdir,
+            log_path.as_deref(),
+            output.quiet,
+            timeout,
+        )
+        .await;
+        print_log_path(log_path.as_deref());
+        match result {
             Ok(exit_code) => {
                 if exit_code == 0 {
                     println!("  {} Exit code: {}\n", style("✓").green(), exit_code);
@@ -124,46 +197,41 @@ async fn execute_sequential(
     Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn execute_parallel(
     docker_client: &DockerClient,
     containers: &[(String, String)],
     command: &[String],
     user: Option<&str>,
     workdir: Option<&str>,
+    concurrency: usize,
+    output: OutputOptions<'_>,
+    timeout: Option<u64>,
 ) -> Result<()> {
-    use futures::future::join_all;
-
-    let futures: Vec<_> = containers
-        .iter()
-        .map(|(id, name)| {
-            let docker = docker_client.clone();
-            let id = id.clone();
-            let name = name.clone();
-            let cmd = command.to_vec();
-            let user = user.map(|s| s.to_string());
-            let workdir = workdir.map(|s| s.to_string());
-
-            async move {
-                let result = execute_single(
-                    &docker,
-                    &id,
-                    &cmd,
-                    user.as_deref(),
-                    workdir.as_deref(),
-                )
-                .await;
-                (name, result)
-            }
-        })
-        .collect();
-
-    let results = join_all(futures).await;
+    // Each task writes its own log file, so output never interleaves within one
+    let results = for_each_concurrent(containers, concurrency, |(id, name)| async move {
+        let log_path = output.log_path(name);
+        let result = execute_single(
+            docker_client,
+            id,
+            command,
+            user,
+            workdir,
+            log_path.as_deref(),
+            output.quiet,
+            timeout,
+        )
+        .await;
+        (name.clone(), log_path, result)
+    })
+    .await;
 
     let mut successes = Vec::new();
     let mut failures = Vec::new();
 
-    for (name, result) in results {
+    for (name, log_path, result) in results {
         println!("{} {}", style("Container:").cyan(), style(&name).bold());
+        print_log_path(log_path.as_deref());
         match result {
             Ok(exit_code) => {
                 if exit_code == 0 {
@@ -185,8 +253,16 @@ async fn execute_parallel(
     Ok(())
 }
 
+fn print_log_path(log_path: Option<&Path>) {
+    if let Some(path) = log_path {
+        println!("  {} {}", style("Log:").dim(), path.display());
+    }
+}
+
 fn print_summary(successes: &[String], failures: &[(String, String)]) -> Result<()> {
     let total = successes.len() + failures.len();
+    summary::record("succeeded", successes.len());
+    summary::record("failed", failures.len());
 
     // Only show summary if executing on multiple containers
     if total <= 1 {
@@ -200,7 +276,7 @@ fn print_summary(successes: &[String], failures: &[(String, String)]) -> Result<
     if !successes.is_empty() {
         println!("{} {} container(s) succeeded:", style("✓").green(), successes.len());
         for name in successes {
-            println!("  • {}", style(name).green());
+            println!("  • {} - {}", style(name).green(), style("Exit code: 0").dim());
         }
         println!();
     }
@@ -228,20 +304,103 @@ fn print_summary(successes: &[String], failures: &[(String, String)]) -> Result<
     Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn execute_single(
     docker_client: &DockerClient,
     container_id: &str,
     command: &[String],
     user: Option<&str>,
     workdir: Option<&str>,
+    log_path: Option<&Path>,
+    quiet: bool,
+    timeout: Option<u64>,
+) -> Result<i64> {
+    let Some(secs) = timeout else {
+        return execute_streaming(
+            docker_client,
+            container_id,
+            command,
+            user,
+            workdir,
+            log_path,
+            quiet,
+            None,
+        )
+        .await;
+    };
+
+    let timed_out = || {
+        DBArenaError::ContainerOperationFailed(format!(
+            "Command exceeded the {}s timeout: {}",
+            secs,
+            command.join(" ")
+        ))
+    };
+    // Leaving the exec behind would keep it running in the container, so the
+    // container's own `timeout` stops it; waiting is capped too in case it can't
+    let wrapped = timeout_command(command, secs);
+    let run = execute_streaming(
+        docker_client,
+        container_id,
+        &wrapped,
+        user,
+        workdir,
+        log_path,
+        quiet,
+        timeout,
+    );
+    let grace = Duration::from_secs(secs) + TIMEOUT_GRACE;
+    match tokio::time::timeout(grace, run).await {
+        Ok(Ok(TIMEOUT_EXIT_CODE)) | Err(_) => Err(timed_out()),
+        Ok(result) => result,
+    }
+}
+
+/// Exit code of `timeout` when it had to stop the command
+const TIMEOUT_EXIT_CODE: i64 = 124;
+
+/// How long past `--timeout` to keep waiting for the container's `timeout` to
+/// stop the command
+const TIMEOUT_GRACE: Duration = Duration::from_secs(5);
+
+/// `command` run under `timeout`, which sends it SIGTERM after `secs`
+///
+/// Plain `timeout SECS` so BusyBox images (Alpine) take it as well as coreutils.
+fn timeout_command(command: &[String], secs: u64) -> Vec<String> {
+    let mut wrapped = vec!["timeout".to_string(), secs.to_string()];
+    wrapped.extend(command.iter().cloned());
+    wrapped
+}
+
+/// Environment that makes database clients started by the command cancel
+/// statements after `secs`: libpq's session options and sqlcmd's `-t` default
+fn statement_timeout_env(secs: u64) -> Vec<String> {
+    vec![
+        format!("PGOPTIONS=-c statement_timeout={}s", secs),
+        format!("SQLCMDSTATTIMEOUT={}", secs),
+    ]
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn execute_streaming(
+    docker_client: &DockerClient,
+    container_id: &str,
+    command: &[String],
+    user: Option<&str>,
+    workdir: Option<&str>,
+    log_path: Option<&Path>,
+    quiet: bool,
+    timeout: Option<u64>,
 ) -> Result<i64> {
     let docker = docker_client.docker();
+    let mut log = log_path.map(File::create).transpose()?;
 
     // Create exec instance
     let exec_config = CreateExecOptions {
         attach_stdout: Some(true),
         attach_stderr: Some(true),
         cmd: Some(command.to_vec()),
+        env: timeout.map(statement_timeout_env),
         user: user.map(|s| s.to_string()),
         working_dir: workdir.map(|s| s.to_string()),
         ..Default::default()
@@ -263,17 +422,24 @@ async fn execute_single(
             // Stream output
             while let Some(chunk) = output.next().await {
                 match chunk {
-                    Ok(bollard::container::LogOutput::StdOut { message }) => {
-                        std::io::stdout().write_all(&message)?;
-                        std::io::stdout().flush()?;
+                    Ok(bollard::container::LogOutput::StdOut { message })
+                    | Ok(bollard::container::LogOutput::Console { message }) => {
+                        if let Some(log) = log.as_mut() {
+                            log.write_all(&message)?;
+                        }
+                        if !quiet {
+                            std::io::stdout().write_all(&message)?;
+                            std::io::stdout().flush()?;
+                        }
                     }
                     Ok(bollard::container::LogOutput::StdErr { message }) => {
-                        std::io::stderr().write_all(&message)?;
-                        std::io::stderr().flush()?;
-                    }
-                    Ok(bollard::container::LogOutput::Console { message }) => {
-                        std::io::stdout().write_all(&message)?;
-                        std::io::stdout().flush()?;
+                        if let Some(log) = log.as_mut() {
+                            log.write_all(&message)?;
+                        }
+                        if !quiet {
+                            std::io::stderr().write_all(&message)?;
+                            std::io::stderr().flush()?;
+                        }
                     }
                     Ok(bollard::container::LogOutput::StdIn { .. }) => {}
                     Err(e) => {
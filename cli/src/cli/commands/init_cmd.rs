@@ -1,5 +1,5 @@
 use crate::container::{ContainerConfig, ContainerManager, DatabaseType, DockerClient};
-use crate::init::{execute_init_scripts, LogManager};
+use crate::init::{execute_init_scripts, read_init_script, LogManager};
 use crate::Result;
 use console::style;
 use std::path::PathBuf;
@@ -153,8 +153,13 @@ pub async fn handle_init_validate(script: PathBuf, database: String) -> Result<(
         crate::DBArenaError::InvalidConfig(format!("Unknown database type: {}", database))
     })?;
 
-    // Read script content
-    let content = std::fs::read_to_string(&script)?;
+    // Read script content; .gz scripts are checked decompressed
+    let content = String::from_utf8(read_init_script(&script)?).map_err(|_| {
+        crate::DBArenaError::InvalidConfig(format!(
+            "Script is not valid UTF-8: {}",
+            script.display()
+        ))
+    })?;
 
     // Basic validation
     let mut issues = Vec::new();
@@ -1,13 +1,21 @@
 use crate::cli::interactive;
-use crate::container::{ContainerManager, DockerClient};
+use crate::container::{
+    connection_details, connection_params, connection_uri, container_env, ConnectFormat,
+    ContainerManager, ContainerRegistry, DatabaseType, DockerClient,
+};
 use crate::{DBArenaError, Result};
 use console::style;
 
-pub async fn handle_inspect(container: Option<String>, interactive_mode: bool) -> Result<()> {
+pub async fn handle_inspect(
+    container: Option<String>,
+    interactive_mode: bool,
+    connect_format: Option<ConnectFormat>,
+    show_secrets: bool,
+) -> Result<()> {
     let docker_client = DockerClient::new()?;
     docker_client.verify_connection().await?;
 
-    let manager = ContainerManager::new(docker_client);
+    let manager = ContainerManager::new(docker_client.clone());
 
     // Get container name
     let container_name = if interactive_mode {
@@ -28,6 +36,26 @@ pub async fn handle_inspect(container: Option<String>, interactive_mode: bool) -
         .await?
         .ok_or_else(|| DBArenaError::ContainerNotFound(container_name.clone()))?;
 
+    // Just the connection details, for pasting into (or piping to) another tool
+    if let Some(format) = connect_format {
+        let port = found.host_port.ok_or_else(|| {
+            DBArenaError::InvalidConfig(format!("Container {} has no published port", found.name))
+        })?;
+        let params = connection_params(docker_client.docker(), &found).await?;
+        let host = docker_client.published_host();
+        println!(
+            "{}",
+            connection_details(params.db_type, &params.env, &host, port, format, show_secrets)?
+        );
+        if !show_secrets && !params.password.is_empty() {
+            eprintln!(
+                "{}",
+                style("Password masked; pass --show-secrets to include it").dim()
+            );
+        }
+        return Ok(());
+    }
+
     println!("\n{}", style("Container Details").bold());
     println!("{}", "─".repeat(50));
     println!("  {}: {}", style("Name").bold(), found.name);
@@ -55,6 +83,108 @@ pub async fn handle_inspect(container: Option<String>, interactive_mode: bool) -
         .unwrap_or_else(|| "Unknown".to_string());
     println!("  {}: {}", style("Created").bold(), created_date);
 
+    // User and working directory as set on the container (empty means image default)
+    let details = docker_client
+        .docker()
+        .inspect_container(&found.id, None)
+        .await?;
+    let runtime = details.config;
+    let setting = |value: Option<String>| {
+        value
+            .filter(|v| !v.is_empty())
+            .unwrap_or_else(|| "image default".to_string())
+    };
+    println!(
+        "  {}: {}",
+        style("User").bold(),
+        setting(runtime.as_ref().and_then(|c| c.user.clone()))
+    );
+    println!(
+        "  {}: {}",
+        style("Workdir").bold(),
+        setting(runtime.as_ref().and_then(|c| c.working_dir.clone()))
+    );
+
+    let ulimits: Vec<String> = details
+        .host_config
+        .and_then(|h| h.ulimits)
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|u| Some(format!("{}={}:{}", u.name?, u.soft?, u.hard?)))
+        .collect();
+    println!(
+        "  {}: {}",
+        style("Ulimits").bold(),
+        if ulimits.is_empty() {
+            "daemon default".to_string()
+        } else {
+            ulimits.join(", ")
+        }
+    );
+
+    // Ready-to-use connection strings, built from the container's credentials
+    let env = container_env(runtime.and_then(|c| c.env).unwrap_or_default());
+    if let (Some(db_type), Some(port)) = (
+        DatabaseType::from_string(&found.database_type),
+        found.host_port,
+    ) {
+        let host = docker_client.published_host();
+        if let Ok(uri) = connection_uri(db_type, &env, &host, port, false) {
+            println!("  {}: {}", style("URI").bold(), uri);
+        }
+        if let Ok(jdbc) = connection_uri(db_type, &env, &host, port, true) {
+            println!("  {}: {}", style("JDBC").bold(), jdbc);
+        }
+    }
+
+    // Creation-time details that only the local registry knows
+    let entry = ContainerRegistry::new()
+        .and_then(|registry| registry.get(&found.id))
+        .unwrap_or_else(|e| {
+            tracing::debug!("Container registry unavailable: {}", e);
+            None
+        });
+    if let Some(entry) = entry {
+        println!(
+            "  {}: {}",
+            style("Profile").bold(),
+            entry.profile.as_deref().unwrap_or("none")
+        );
+        if entry.init_scripts.is_empty() {
+            println!("  {}: none", style("Init Scripts").bold());
+        } else {
+            println!("  {}:", style("Init Scripts").bold());
+            for script in &entry.init_scripts {
+                println!("    - {}", script.display());
+            }
+        }
+    }
+
     println!();
     Ok(())
 }
+
+/// Handle the uri command: print just the connection string for a container
+pub async fn handle_uri(container: String, format: String) -> Result<()> {
+    let docker_client = DockerClient::new()?;
+    docker_client.verify_connection().await?;
+
+    let manager = ContainerManager::new(docker_client.clone());
+    let found = manager
+        .find_container(&container)
+        .await?
+        .ok_or_else(|| DBArenaError::ContainerNotFound(container.clone()))?;
+
+    let port = found.host_port.ok_or_else(|| {
+        DBArenaError::InvalidConfig(format!("Container {} has no published port", found.name))
+    })?;
+
+    let params = connection_params(docker_client.docker(), &found).await?;
+    let host = docker_client.published_host();
+    println!(
+        "{}",
+        connection_uri(params.db_type, &params.env, &host, port, format == "jdbc")?
+    );
+
+    Ok(())
+}
@@ -1,9 +1,240 @@
-use crate::container::{ContainerManager, DockerClient};
-use crate::Result;
+use crate::cli::output::OutputFormat;
+use crate::container::models::Container;
+use crate::container::{ContainerManager, ContainerRegistry, DockerClient};
+use crate::health::{probe_container, HealthStatus};
+use crate::{DBArenaError, Result};
 use console::style;
+use futures::future::join_all;
 use indicatif::{ProgressBar, ProgressStyle};
+use serde::Serialize;
+use std::time::Duration;
+
+const LIST_HEALTH_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Container entry for JSON output
+#[derive(Serialize)]
+struct ListEntry<'a> {
+    #[serde(flatten)]
+    container: &'a Container,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    health: Option<HealthStatus>,
+}
+
+/// Placeholders accepted by `--format`
+const FORMAT_FIELDS: &[&str] = &[
+    "Name",
+    "ID",
+    "DatabaseType",
+    "Version",
+    "Status",
+    "HostPort",
+    "Created",
+    "Health",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ListField {
+    Name,
+    Id,
+    DatabaseType,
+    Version,
+    Status,
+    HostPort,
+    Created,
+    Health,
+}
+
+impl ListField {
+    /// Match a placeholder name, ignoring case and underscores
+    /// (`{{.HostPort}}`, `{{.host_port}}` and `{{.Port}}` are equivalent)
+    fn parse(name: &str) -> Option<Self> {
+        match name.to_lowercase().replace('_', "").as_str() {
+            "name" => Some(ListField::Name),
+            "id" => Some(ListField::Id),
+            "databasetype" | "database" => Some(ListField::DatabaseType),
+            "version" => Some(ListField::Version),
+            "status" => Some(ListField::Status),
+            "hostport" | "port" => Some(ListField::HostPort),
+            "created" | "createdat" => Some(ListField::Created),
+            "health" => Some(ListField::Health),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum FormatSegment {
+    Literal(String),
+    Field(ListField),
+}
+
+/// Row template for `dbarena list --format`, e.g. `{{.Name}} {{.HostPort}}`
+#[derive(Debug, Clone)]
+pub struct ListFormat {
+    segments: Vec<FormatSegment>,
+}
+
+impl ListFormat {
+    pub fn parse(template: &str) -> Result<Self> {
+        let mut segments = Vec::new();
+        let mut rest = template;
+
+        while let Some(start) = rest.find("{{") {
+            if start > 0 {
+                segments.push(FormatSegment::Literal(rest[..start].to_string()));
+            }
+            let end = rest[start..].find("}}").ok_or_else(|| {
+                DBArenaError::InvalidConfig(format!(
+                    "Unclosed placeholder in --format: {}",
+                    &rest[start..]
+                ))
+            })? + start;
+
+            let inner = rest[start + 2..end].trim();
+            let name = inner.strip_prefix('.').unwrap_or(inner);
+            let field = ListField::parse(name).ok_or_else(|| {
+                DBArenaError::InvalidConfig(format!(
+                    "Unknown placeholder '{{{{{}}}}}' in --format. Valid placeholders: {}",
+                    inner,
+                    FORMAT_FIELDS
+                        .iter()
+                        .map(|f| format!("{{{{.{}}}}}", f))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ))
+            })?;
+            segments.push(FormatSegment::Field(field));
+            rest = &rest[end + 2..];
+        }
+
+        if !rest.is_empty() {
+            segments.push(FormatSegment::Literal(rest.to_string()));
+        }
+
+        Ok(Self { segments })
+    }
+
+    /// Whether the template references the health status
+    pub fn uses_health(&self) -> bool {
+        self.segments
+            .contains(&FormatSegment::Field(ListField::Health))
+    }
+
+    /// Render one container as plain, unstyled text
+    pub fn render(&self, container: &Container, health: Option<HealthStatus>) -> String {
+        let mut out = String::new();
+        for segment in &self.segments {
+            match segment {
+                FormatSegment::Literal(text) => out.push_str(text),
+                FormatSegment::Field(field) => out.push_str(&match field {
+                    ListField::Name => container.name.clone(),
+                    ListField::Id => container.id.chars().take(12).collect(),
+                    ListField::DatabaseType => container.database_type.clone(),
+                    ListField::Version => container.version.clone(),
+                    ListField::Status => container.status.to_string(),
+                    ListField::HostPort => container
+                        .host_port
+                        .map(|p| p.to_string())
+                        .unwrap_or_default(),
+                    ListField::Created => chrono::DateTime::from_timestamp(container.created_at, 0)
+                        .map(|t| t.to_rfc3339())
+                        .unwrap_or_default(),
+                    ListField::Health => health.map(|h| h.to_string()).unwrap_or_default(),
+                }),
+            }
+        }
+        out
+    }
+}
+
+/// Output mode selected with `--format`
+#[derive(Debug, Clone)]
+pub enum ListOutput {
+    Table,
+    Json,
+    Yaml,
+    Csv,
+    Template(ListFormat),
+}
+
+impl ListOutput {
+    /// Parse `table`, `json`, `yaml`, `csv`, or a `{{.Field}}` template
+    pub fn parse(format: &str) -> Result<Self> {
+        match format {
+            "table" => Ok(ListOutput::Table),
+            "json" => Ok(ListOutput::Json),
+            "yaml" => Ok(ListOutput::Yaml),
+            "csv" => Ok(ListOutput::Csv),
+            template if template.contains("{{") => {
+                Ok(ListOutput::Template(ListFormat::parse(template)?))
+            }
+            other => Err(DBArenaError::InvalidConfig(format!(
+                "Unknown format '{}'. Use table, json, yaml, csv, or a template such as '{{{{.Name}}}} {{{{.HostPort}}}}'",
+                other
+            ))),
+        }
+    }
+
+    /// Whether this output shows the health status
+    fn uses_health(&self) -> bool {
+        match self {
+            ListOutput::Table | ListOutput::Json | ListOutput::Yaml => true,
+            ListOutput::Csv => false,
+            ListOutput::Template(format) => format.uses_health(),
+        }
+    }
+}
+
+/// CSV header and row rendering for `--format csv`
+pub const CSV_HEADER: &str = "name,id,database,version,status,port,created";
+
+pub fn csv_row(container: &Container) -> String {
+    [
+        container.name.clone(),
+        container.id.clone(),
+        container.database_type.clone(),
+        container.version.clone(),
+        container.status.to_string(),
+        container
+            .host_port
+            .map(|p| p.to_string())
+            .unwrap_or_default(),
+        chrono::DateTime::from_timestamp(container.created_at, 0)
+            .map(|t| t.to_rfc3339())
+            .unwrap_or_default(),
+    ]
+    .iter()
+    .map(|field| csv_field(field))
+    .collect::<Vec<_>>()
+    .join(",")
+}
+
+/// Quote a CSV field if it contains a delimiter, quote, or newline
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+pub async fn handle_list(
+    all: bool,
+    no_health: bool,
+    json: bool,
+    yaml: bool,
+    format: Option<String>,
+) -> Result<()> {
+    // Validate the format before touching Docker; --json and --yaml are shorthand for
+    // --format json and --format yaml
+    let output = match format.as_deref() {
+        Some(format) => ListOutput::parse(format)?,
+        None if json => ListOutput::Json,
+        None if yaml => ListOutput::Yaml,
+        None => ListOutput::Table,
+    };
+    let no_health = no_health || !output.uses_health();
 
-pub async fn handle_list(all: bool) -> Result<()> {
     let pb = ProgressBar::new_spinner();
     pb.set_style(
         ProgressStyle::default_spinner()
@@ -13,12 +244,70 @@ pub async fn handle_list(all: bool) -> Result<()> {
     pb.set_message("Fetching containers...");
 
     let docker_client = DockerClient::new()?;
-    docker_client.verify_connection().await?;
+    let manager = ContainerManager::new(docker_client.clone());
+
+    // A running daemon answers without reconnecting or re-probing health
+    let (containers, health) = match list_via_daemon(all, docker_client.endpoint()).await {
+        Some((containers, _)) if no_health => {
+            let health = vec![None; containers.len()];
+            (containers, health)
+        }
+        Some(listed) => listed,
+        None => {
+            docker_client.verify_connection().await?;
 
-    let manager = ContainerManager::new(docker_client);
-    let containers = manager.list_containers(all).await?;
+            let docker = docker_client.docker().clone();
+            let containers = manager.list_containers(all).await?;
+
+            let health: Vec<Option<HealthStatus>> = if no_health {
+                vec![None; containers.len()]
+            } else {
+                pb.set_message("Checking database health...");
+                join_all(
+                    containers
+                        .iter()
+                        .map(|c| probe_container(&docker, c, LIST_HEALTH_TIMEOUT)),
+                )
+                .await
+            };
+            (containers, health)
+        }
+    };
     pb.finish_and_clear();
 
+    reconcile_registry(&manager, all, &containers).await;
+
+    match output {
+        ListOutput::Template(format) => {
+            for (container, health) in containers.iter().zip(health) {
+                println!("{}", format.render(container, health));
+            }
+            return Ok(());
+        }
+        ListOutput::Json | ListOutput::Yaml => {
+            let entries: Vec<ListEntry> = containers
+                .iter()
+                .zip(health)
+                .map(|(container, health)| ListEntry { container, health })
+                .collect();
+            let format = if matches!(output, ListOutput::Yaml) {
+                OutputFormat::Yaml
+            } else {
+                OutputFormat::Json
+            };
+            format.print(&entries)?;
+            return Ok(());
+        }
+        ListOutput::Csv => {
+            println!("{}", CSV_HEADER);
+            for container in &containers {
+                println!("{}", csv_row(container));
+            }
+            return Ok(());
+        }
+        ListOutput::Table => {}
+    }
+
     if containers.is_empty() {
         println!("No containers found.");
         println!(
@@ -39,18 +328,19 @@ pub async fn handle_list(all: bool) -> Result<()> {
         style("DBArena Containers").bold().cyan(),
         style(format!("({})", count_text)).dim()
     );
-    println!("{}", "─".repeat(80));
+    println!("{}", "─".repeat(92));
     println!(
-        "{:<20} {:<15} {:<12} {:<10} {:<15}",
+        "{:<20} {:<15} {:<12} {:<10} {:<12} {:<15}",
         style("NAME").bold(),
         style("DATABASE").bold(),
         style("VERSION").bold(),
         style("STATUS").bold(),
+        style("HEALTH").bold(),
         style("PORT").bold()
     );
-    println!("{}", "─".repeat(80));
+    println!("{}", "─".repeat(92));
 
-    for container in containers {
+    for (container, health) in containers.iter().zip(health) {
         let status_str = container.status.to_string();
         let status_display = match status_str.as_str() {
             "running" | "healthy" => style(&status_str).green(),
@@ -63,12 +353,20 @@ pub async fn handle_list(all: bool) -> Result<()> {
             .map(|p| p.to_string())
             .unwrap_or_else(|| "-".to_string());
 
+        let health_display = match health {
+            Some(HealthStatus::Healthy) => style("healthy").green(),
+            Some(HealthStatus::Starting) => style("starting").yellow(),
+            Some(HealthStatus::Unhealthy) => style("unhealthy").red(),
+            None => style("-").dim(),
+        };
+
         println!(
-            "{:<20} {:<15} {:<12} {:<10} {:<15}",
+            "{:<20} {:<15} {:<12} {:<10} {:<12} {:<15}",
             style(&container.name).cyan(),
             container.database_type,
             container.version,
             status_display,
+            health_display,
             port_display
         );
     }
@@ -76,3 +374,47 @@ pub async fn handle_list(all: bool) -> Result<()> {
     println!();
     Ok(())
 }
+
+/// Prune registry entries for containers that no longer exist in Docker
+async fn reconcile_registry(manager: &ContainerManager, all: bool, listed: &[Container]) {
+    // Pruning needs every container, not only the running ones
+    let existing = if all {
+        Ok(listed.to_vec())
+    } else {
+        manager.list_containers(true).await
+    };
+
+    let pruned = existing.and_then(|existing| ContainerRegistry::new()?.reconcile(&existing));
+    match pruned {
+        Ok(0) => {}
+        Ok(n) => tracing::debug!("Pruned {} stale registry entries", n),
+        Err(e) => tracing::debug!("Registry reconciliation skipped: {}", e),
+    }
+}
+
+/// List through the background daemon when one is running for the same Docker endpoint
+#[cfg(unix)]
+async fn list_via_daemon(
+    all: bool,
+    endpoint: &str,
+) -> Option<(Vec<Container>, Vec<Option<HealthStatus>>)> {
+    let mut client = crate::daemon::DaemonClient::connect().await?;
+    match client.list(all).await {
+        Ok((daemon_endpoint, states)) if daemon_endpoint == endpoint => {
+            Some(states.into_iter().map(|s| (s.container, s.health)).unzip())
+        }
+        Ok(_) => None,
+        Err(e) => {
+            tracing::debug!("Daemon list failed, falling back to Docker: {}", e);
+            None
+        }
+    }
+}
+
+#[cfg(not(unix))]
+async fn list_via_daemon(
+    _all: bool,
+    _endpoint: &str,
+) -> Option<(Vec<Container>, Vec<Option<HealthStatus>>)> {
+    None
+}
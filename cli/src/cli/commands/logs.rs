@@ -1,7 +1,9 @@
 use crate::cli::interactive;
 use crate::container::{ContainerManager, DockerClient};
+use crate::workload::sweep::parse_duration_secs;
 use crate::{DBArenaError, Result};
 use bollard::container::LogsOptions;
+use chrono::{DateTime, Utc};
 use futures::StreamExt;
 
 pub async fn handle_logs(
@@ -9,7 +11,10 @@ pub async fn handle_logs(
     interactive_mode: bool,
     follow: bool,
     tail: Option<usize>,
+    since: Option<String>,
 ) -> Result<()> {
+    let since = since.as_deref().map(parse_since).transpose()?;
+
     let docker_client = DockerClient::new()?;
     docker_client.verify_connection().await?;
 
@@ -38,9 +43,13 @@ pub async fn handle_logs(
         stdout: true,
         stderr: true,
         follow,
-        tail: tail
-            .map(|t| t.to_string())
-            .unwrap_or_else(|| "100".to_string()),
+        // With a cutoff, show everything after it unless --tail narrows it further
+        tail: match (tail, since) {
+            (Some(t), _) => t.to_string(),
+            (None, Some(_)) => "all".to_string(),
+            (None, None) => "100".to_string(),
+        },
+        since: since.unwrap_or(0),
         ..Default::default()
     };
 
@@ -53,3 +62,43 @@ pub async fn handle_logs(
 
     Ok(())
 }
+
+/// Parse --since as a relative duration ("30s", "5m", "1h") or an RFC 3339
+/// timestamp into a Unix timestamp
+fn parse_since(value: &str) -> Result<i64> {
+    parse_since_at(value, Utc::now())
+}
+
+fn parse_since_at(value: &str, now: DateTime<Utc>) -> Result<i64> {
+    if let Ok(timestamp) = DateTime::parse_from_rfc3339(value.trim()) {
+        return Ok(timestamp.timestamp());
+    }
+    let secs = parse_duration_secs(value).map_err(|_| {
+        DBArenaError::InvalidConfig(format!(
+            "Invalid --since '{}' (use a duration like 5m or a timestamp like 2024-01-01T12:00:00Z)",
+            value
+        ))
+    })?;
+    Ok(now.timestamp() - secs as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_since() {
+        let now = DateTime::parse_from_rfc3339("2024-06-01T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        assert_eq!(parse_since_at("5m", now).unwrap(), now.timestamp() - 300);
+        assert_eq!(parse_since_at("1h", now).unwrap(), now.timestamp() - 3600);
+        assert_eq!(
+            parse_since_at("2024-06-01T11:30:00+00:00", now).unwrap(),
+            now.timestamp() - 1800
+        );
+        assert!(parse_since_at("yesterday", now).is_err());
+        assert!(parse_since_at("0s", now).is_err());
+    }
+}
@@ -1,8 +1,15 @@
 // Command implementations will be added here
+pub mod clone;
 pub mod config;
+pub mod cp;
 pub mod create;
+#[cfg(unix)]
+pub mod daemon;
 pub mod destroy;
+pub mod diff;
 pub mod docs;
+pub mod doctor;
+pub mod dump;
 pub mod exec;
 pub mod init_cmd;
 pub mod inspect;
@@ -10,11 +17,17 @@ pub mod list;
 pub mod logs;
 pub mod network;
 pub mod query;
+pub mod replica;
+pub mod restore;
+pub mod schema;
 pub mod seed;
+pub mod serve;
 pub mod snapshot;
 pub mod start;
 pub mod stats;
 pub mod stop;
 pub mod template;
+pub mod wait;
+pub mod warm;
 pub mod volume;
 pub mod workload;
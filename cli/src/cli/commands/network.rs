@@ -1,5 +1,6 @@
+use crate::cli::output::OutputFormat;
 use crate::container::DockerClient;
-use crate::network::{NetworkConfig, NetworkDriver, NetworkManager};
+use crate::network::{NetworkConfig, NetworkDriver, NetworkManager, NetworkMember, NetworkTree};
 use crate::{DBArenaError, Result};
 use console::style;
 
@@ -60,16 +61,32 @@ pub async fn handle_network_create(
     Ok(())
 }
 
-pub async fn handle_network_list(all: bool, json: bool) -> Result<()> {
+pub async fn handle_network_list(
+    all: bool,
+    tree: bool,
+    managed_only: bool,
+    format: OutputFormat,
+) -> Result<()> {
     let docker_client = DockerClient::new()?;
     docker_client.verify_connection().await?;
 
     let manager = NetworkManager::new(docker_client);
     let networks = manager.list_networks(!all).await?;
 
-    if json {
-        println!("{}", serde_json::to_string_pretty(&networks)
-            .map_err(|e| DBArenaError::Other(format!("JSON serialization failed: {}", e)))?);
+    if tree {
+        let mut trees = Vec::with_capacity(networks.len());
+        for network in networks {
+            let mut containers = manager.list_members(&network.name).await?;
+            if managed_only {
+                containers.retain(|c| c.managed);
+            }
+            trees.push(NetworkTree { network, containers });
+        }
+        return print_network_trees(&trees, all, format);
+    }
+
+    if format.is_structured() {
+        format.print(&networks)?;
         return Ok(());
     }
 
@@ -103,16 +120,78 @@ pub async fn handle_network_list(all: bool, json: bool) -> Result<()> {
     Ok(())
 }
 
-pub async fn handle_network_inspect(name: String, json: bool) -> Result<()> {
+/// Print each network followed by its containers; containers dbarena doesn't manage
+/// are dimmed
+fn print_network_trees(trees: &[NetworkTree], all: bool, format: OutputFormat) -> Result<()> {
+    if format.is_structured() {
+        return format.print(trees);
+    }
+
+    if trees.is_empty() {
+        if all {
+            println!("No networks found.");
+        } else {
+            println!("No dbarena-managed networks found. Use --all to see all networks.");
+        }
+        return Ok(());
+    }
+
+    for (i, tree) in trees.iter().enumerate() {
+        if i > 0 {
+            println!();
+        }
+        let network = &tree.network;
+        match &network.subnet {
+            Some(subnet) => println!(
+                "{} ({}, {})",
+                style(&network.name).bold(),
+                network.driver,
+                subnet
+            ),
+            None => println!("{} ({})", style(&network.name).bold(), network.driver),
+        }
+
+        if tree.containers.is_empty() {
+            println!("  {}", style("(no containers)").dim());
+            continue;
+        }
+        let name_width = tree.containers.iter().map(|c| c.name.len()).max().unwrap_or(0);
+        for (j, member) in tree.containers.iter().enumerate() {
+            let branch = if j + 1 == tree.containers.len() { "└──" } else { "├──" };
+            let line = member_line(member, name_width);
+            if member.managed {
+                println!("{} {}", branch, line);
+            } else {
+                println!("{} {}", branch, style(line).dim());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// `name  ip  aliases: a, b`, with the name padded to `name_width`
+fn member_line(member: &NetworkMember, name_width: usize) -> String {
+    let mut line = format!(
+        "{:<name_width$}  {:<15}",
+        member.name,
+        member.ip_address.as_deref().unwrap_or("-")
+    );
+    if !member.aliases.is_empty() {
+        line.push_str(&format!("  aliases: {}", member.aliases.join(", ")));
+    }
+    line.trim_end().to_string()
+}
+
+pub async fn handle_network_inspect(name: String, format: OutputFormat) -> Result<()> {
     let docker_client = DockerClient::new()?;
     docker_client.verify_connection().await?;
 
     let manager = NetworkManager::new(docker_client);
     let network = manager.inspect_network(&name).await?;
 
-    if json {
-        println!("{}", serde_json::to_string_pretty(&network)
-            .map_err(|e| DBArenaError::Other(format!("JSON serialization failed: {}", e)))?);
+    if format.is_structured() {
+        format.print(&network)?;
         return Ok(());
     }
 
@@ -221,3 +300,27 @@ pub async fn handle_network_disconnect(network: String, container: String) -> Re
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_member_line() {
+        let mut member = NetworkMember {
+            id: "9f3c2a1b7d4e".to_string(),
+            name: "pg-main".to_string(),
+            ip_address: Some("172.18.0.2".to_string()),
+            aliases: vec!["db".to_string(), "postgres".to_string()],
+            managed: true,
+        };
+        assert_eq!(
+            member_line(&member, 10),
+            "pg-main     172.18.0.2       aliases: db, postgres"
+        );
+
+        member.ip_address = None;
+        member.aliases.clear();
+        assert_eq!(member_line(&member, 7), "pg-main  -");
+    }
+}
@@ -1,48 +1,61 @@
 use crate::cli::interactive;
-use crate::container::{ContainerManager, DatabaseType, DockerClient};
+use crate::cli::target::Target;
+use crate::container::{
+    run_on_host, ConnParams, ContainerManager, DatabaseType, DockerClient, SQLITE_DB_PATH,
+};
+use crate::util::resp::split_args;
+use crate::util::shell::shell_quote;
 use crate::{DBArenaError, Result};
 use bollard::exec::{CreateExecOptions, StartExecResults};
 use console::style;
 use futures::StreamExt;
 use std::path::PathBuf;
+use std::time::Duration;
 
+#[allow(clippy::too_many_arguments)]
 pub async fn handle_query(
     container: Option<String>,
+    url: Option<String>,
     interactive_mode: bool,
     script: Option<String>,
     file: Option<PathBuf>,
+    explain: Option<String>,
+    format: String,
+    timeout: Option<u64>,
 ) -> Result<()> {
-    let docker_client = DockerClient::new()?;
-    docker_client.verify_connection().await?;
-
-    let manager = ContainerManager::new(docker_client.clone());
-
-    // Get container name
-    let container_name = if interactive_mode {
-        let all_containers = manager.list_containers(false).await?;
-        interactive::select_container(all_containers, "query")?
+    // Get container name; an external database needs none
+    let container_name = if url.is_some() {
+        None
+    } else if interactive_mode {
+        let docker_client = DockerClient::new()?;
+        docker_client.verify_connection().await?;
+        let all_containers = ContainerManager::new(docker_client).list_containers(false).await?;
+        Some(interactive::select_container(all_containers, "query")?)
     } else {
-        container.ok_or_else(|| {
+        Some(container.ok_or_else(|| {
             DBArenaError::InvalidConfig(
-                "Container name required. Use --container <name> or -i for interactive mode.".to_string(),
+                "Container name required. Use --container <name>, --url <url> or -i for interactive mode.".to_string(),
             )
-        })?
+        })?)
     };
 
-    // Find the container
-    let found = manager
-        .find_container(&container_name)
-        .await?
-        .ok_or_else(|| DBArenaError::ContainerNotFound(container_name.clone()))?;
-
-    // Get database type
-    let db_type = DatabaseType::from_string(&found.database_type)
-        .ok_or_else(|| {
-            DBArenaError::InvalidConfig(format!("Unknown database type: {}", found.database_type))
-        })?;
+    // Connect with the credentials the container was created with, or the URL's
+    let target = Target::resolve(container_name.as_deref(), url.as_deref(), None).await?;
+    let docker = target.docker.docker();
+    let params = &target.params;
+    let db_type = params.db_type;
 
     // Determine what SQL to execute
-    let (sql_content, is_from_file) = if let Some(file_path) = file {
+    let explaining = explain.is_some();
+    let json = format == "json";
+    let (sql_content, is_from_file) = if let Some(sql) = explain {
+        (explain_statement(db_type, &sql)?, false)
+    } else if json {
+        let sql = script.ok_or_else(|| {
+            DBArenaError::InvalidConfig("--format json requires a --script query".to_string())
+        })?;
+        (json_statement(db_type, &sql)?, false)
+    } else if let Some(file_path) = file {
         // Read SQL from file
         let content = std::fs::read_to_string(&file_path).map_err(|e| {
             DBArenaError::InvalidConfig(format!("Failed to read file {:?}: {}", file_path, e))
@@ -52,97 +65,189 @@ pub async fn handle_query(
         (sql, false)
     } else {
         return Err(DBArenaError::InvalidConfig(
-            "Either --script or --file must be provided".to_string(),
+            "One of --script, --file or --explain must be provided".to_string(),
         ));
     };
 
-    println!(
+    // JSON output stays machine-readable: progress goes to stderr
+    let status = |line: String| {
+        if json {
+            eprintln!("{}", line)
+        } else {
+            println!("{}", line)
+        }
+    };
+
+    status(format!(
         "{} Executing {} on {}...",
         style("→").cyan(),
-        if is_from_file { "SQL file" } else { "query" },
-        style(&found.name).bold()
-    );
+        match (db_type.is_sql(), is_from_file) {
+            _ if explaining => "query plan",
+            (true, true) => "SQL file",
+            (true, false) => "query",
+            (false, true) => "command file",
+            (false, false) => "command",
+        },
+        style(&target.name).bold()
+    ));
 
     // Build command based on database type
-    let cmd = build_query_command(db_type, &sql_content, is_from_file);
-
-    // Execute the query
-    let docker = docker_client.docker();
-    let exec = docker
-        .create_exec(
-            &found.id,
-            CreateExecOptions {
-                cmd: Some(cmd),
-                attach_stdout: Some(true),
-                attach_stderr: Some(true),
-                ..Default::default()
-            },
-        )
-        .await
-        .map_err(|e| DBArenaError::ContainerOperationFailed(format!("Failed to create exec: {}", e)))?;
-
-    // Start exec and stream output
-    let start_exec = docker
-        .start_exec(&exec.id, None)
-        .await
-        .map_err(|e| DBArenaError::ContainerOperationFailed(format!("Failed to start exec: {}", e)))?;
-
-    println!();
-
-    let mut output = String::new();
-    match start_exec {
-        StartExecResults::Attached { output: mut stream, .. } => {
-            while let Some(chunk) = stream.next().await {
-                match chunk {
-                    Ok(bollard::container::LogOutput::StdOut { message }) => {
-                        let text = String::from_utf8_lossy(&message);
-                        print!("{}", text);
-                        output.push_str(&text);
-                    }
-                    Ok(bollard::container::LogOutput::StdErr { message }) => {
-                        let text = String::from_utf8_lossy(&message);
-                        eprint!("{}", text);
-                        output.push_str(&text);
-                    }
-                    Ok(bollard::container::LogOutput::Console { message }) => {
-                        let text = String::from_utf8_lossy(&message);
-                        print!("{}", text);
-                        output.push_str(&text);
-                    }
-                    Ok(bollard::container::LogOutput::StdIn { .. }) => {}
-                    Err(e) => {
-                        return Err(DBArenaError::ContainerOperationFailed(format!(
-                            "Error reading output: {}",
-                            e
-                        )));
+    let cmd = build_query_command(params, &sql_content, is_from_file, timeout, json)?;
+
+    // A plan or JSON result is held back and rendered once the client is done
+    let buffered = explaining || json;
+
+    status(String::new());
+
+    let run = async {
+        // An external database's client runs here and reports when it exits
+        if params.is_external() {
+            let result = run_on_host(params, &cmd).await?;
+            eprint!("{}", result.stderr);
+            let plan = if buffered {
+                result.stdout.clone()
+            } else {
+                print!("{}", result.stdout);
+                String::new()
+            };
+            return Ok((result.exit_code, result.stdout + &result.stderr, plan));
+        }
+
+        // Execute the query in the container
+        let exec = docker
+            .create_exec(
+                target.container_id(),
+                CreateExecOptions {
+                    cmd: Some(cmd.clone()),
+                    attach_stdout: Some(true),
+                    attach_stderr: Some(true),
+                    ..Default::default()
+                },
+            )
+            .await
+            .map_err(|e| {
+                DBArenaError::ContainerOperationFailed(format!("Failed to create exec: {}", e))
+            })?;
+
+        // Start exec and stream output
+        let start_exec = docker.start_exec(&exec.id, None).await.map_err(|e| {
+            DBArenaError::ContainerOperationFailed(format!("Failed to start exec: {}", e))
+        })?;
+
+        let mut output = String::new();
+        let mut plan = String::new();
+        match start_exec {
+            StartExecResults::Attached { output: mut stream, .. } => {
+                while let Some(chunk) = stream.next().await {
+                    match chunk {
+                        Ok(bollard::container::LogOutput::StdOut { message }) => {
+                            let text = String::from_utf8_lossy(&message);
+                            if buffered {
+                                plan.push_str(&text);
+                            } else {
+                                print!("{}", text);
+                            }
+                            output.push_str(&text);
+                        }
+                        Ok(bollard::container::LogOutput::StdErr { message }) => {
+                            let text = String::from_utf8_lossy(&message);
+                            eprint!("{}", text);
+                            output.push_str(&text);
+                        }
+                        Ok(bollard::container::LogOutput::Console { message }) => {
+                            let text = String::from_utf8_lossy(&message);
+                            if buffered {
+                                plan.push_str(&text);
+                            } else {
+                                print!("{}", text);
+                            }
+                            output.push_str(&text);
+                        }
+                        Ok(bollard::container::LogOutput::StdIn { .. }) => {}
+                        Err(e) => {
+                            return Err(DBArenaError::ContainerOperationFailed(format!(
+                                "Error reading output: {}",
+                                e
+                            )));
+                        }
                     }
                 }
             }
+            StartExecResults::Detached => {
+                return Err(DBArenaError::ContainerOperationFailed(
+                    "Unexpected detached exec".to_string(),
+                ));
+            }
         }
-        StartExecResults::Detached => {
-            return Err(DBArenaError::ContainerOperationFailed(
-                "Unexpected detached exec".to_string(),
-            ));
-        }
-    }
 
-    // Get exit code
-    let inspect = docker
-        .inspect_exec(&exec.id)
-        .await
-        .map_err(|e| DBArenaError::ContainerOperationFailed(format!("Failed to inspect exec: {}", e)))?;
+        // Get exit code
+        let inspect = docker.inspect_exec(&exec.id).await.map_err(|e| {
+            DBArenaError::ContainerOperationFailed(format!("Failed to inspect exec: {}", e))
+        })?;
 
-    let exit_code = inspect.exit_code.unwrap_or(0);
+        Ok((inspect.exit_code.unwrap_or(0), output, plan))
+    };
 
-    println!();
-    if exit_code == 0 {
+    let (exit_code, output, plan) = match timeout {
+        // The session timeout should fire first and name the statement; this
+        // only stops the CLI from waiting on a client that never returns
+        Some(secs) => {
+            let limit = Duration::from_secs(secs) + TIMEOUT_GRACE;
+            match tokio::time::timeout(limit, run).await {
+                Ok(result) => result?,
+                Err(_) => {
+                    status(String::new());
+                    status(format!(
+                        "{} Query did not finish within {}s",
+                        style("✗").red(),
+                        secs
+                    ));
+                    return Err(DBArenaError::ContainerOperationFailed(format!(
+                        "Query exceeded the {}s timeout: {}",
+                        secs,
+                        timed_out_statement(&sql_content, "", false)
+                    )));
+                }
+            }
+        }
+        None => run.await?,
+    };
+
+    status(String::new());
+    // Checked before the exit code: sqlcmd, and psql reading a script, exit 0
+    // after a statement fails
+    if let Some(secs) = timeout.filter(|_| is_timeout_error(&output)) {
+        status(format!(
+            "{} Statement exceeded the {}s timeout",
+            style("✗").red(),
+            secs
+        ));
+        return Err(DBArenaError::ContainerOperationFailed(format!(
+            "Statement exceeded the {}s timeout: {}",
+            secs,
+            timed_out_statement(
+                &sql_content,
+                &output,
+                statement_timeout_sql(db_type, secs).is_some()
+            )
+        )));
+    } else if exit_code == 0 && explaining {
+        println!("{}", render_plan(db_type, &plan));
+        println!();
+        println!("{} Query plan complete", style("✓").green());
+    } else if exit_code == 0 && json {
+        let rows = parse_json_output(db_type, &plan).map_err(|e| {
+            DBArenaError::ContainerOperationFailed(format!("{}: {}", e, output.trim()))
+        })?;
+        println!("{}", serde_json::to_string_pretty(&rows)?);
+    } else if exit_code == 0 {
         println!("{} Query executed successfully", style("✓").green());
     } else {
-        println!(
+        status(format!(
             "{} Query failed with exit code {}",
             style("✗").red(),
             exit_code
-        );
+        ));
         return Err(DBArenaError::ContainerOperationFailed(format!(
             "Query execution failed: {}",
             output
@@ -152,45 +257,691 @@ pub async fn handle_query(
     Ok(())
 }
 
-fn build_query_command(db_type: DatabaseType, sql: &str, is_file: bool) -> Vec<String> {
+/// Wrap a statement so the database reports its execution plan
+///
+/// The statement is executed, not just planned, so the plan carries real row
+/// counts and timings.
+fn explain_statement(db_type: DatabaseType, sql: &str) -> Result<String> {
+    let sql = sql.trim().trim_end_matches(';');
+    match db_type {
+        DatabaseType::Postgres => Ok(format!("EXPLAIN (ANALYZE, BUFFERS) {}", sql)),
+        DatabaseType::MySQL | DatabaseType::CockroachDB => Ok(format!("EXPLAIN ANALYZE {}", sql)),
+        DatabaseType::SQLServer => Ok(format!(
+            concat!(
+                "SET STATISTICS IO, TIME ON;\n",
+                "SET STATISTICS PROFILE ON;\n",
+                "{};\n",
+                "SET STATISTICS PROFILE OFF;"
+            ),
+            sql
+        )),
+        // SQLite can only describe the plan; the statement is not run
+        DatabaseType::SQLite => Ok(format!("EXPLAIN QUERY PLAN {}", sql)),
+        DatabaseType::Redis | DatabaseType::MongoDB => Err(DBArenaError::InvalidConfig(format!(
+            "--explain is only supported for SQL databases, not {}",
+            db_type
+        ))),
+    }
+}
+
+/// Wrap a query so the database itself renders the rows as JSON
+///
+/// Postgres and CockroachDB aggregate the rows with `json_agg` and SQL Server
+/// appends `FOR JSON`. MySQL's client has no JSON output, so its query runs
+/// as-is and the batch-mode table is converted by [`parse_json_output`];
+/// SQLite's query also runs as-is, under `sqlite3 -json`.
+fn json_statement(db_type: DatabaseType, sql: &str) -> Result<String> {
+    let sql = sql.trim().trim_end_matches(';');
     match db_type {
+        DatabaseType::Postgres | DatabaseType::CockroachDB => Ok(format!(
+            "SELECT coalesce(json_agg(dbarena_rows), '[]') FROM ({}) AS dbarena_rows",
+            sql
+        )),
+        DatabaseType::MySQL | DatabaseType::SQLite => Ok(sql.to_string()),
+        DatabaseType::SQLServer => Ok(format!(
+            "SET NOCOUNT ON; {} FOR JSON PATH, INCLUDE_NULL_VALUES",
+            sql
+        )),
+        DatabaseType::Redis | DatabaseType::MongoDB => Err(DBArenaError::InvalidConfig(format!(
+            "--format json is only supported for SQL databases, not {}",
+            db_type
+        ))),
+    }
+}
+
+/// Turn the client's output for a [`json_statement`] into a JSON array of rows
+fn parse_json_output(db_type: DatabaseType, output: &str) -> Result<serde_json::Value> {
+    let json = match db_type {
+        // psql -t -A prints the aggregate alone
+        DatabaseType::Postgres => output.trim().to_string(),
+        DatabaseType::CockroachDB => cockroach_raw_value(output)?,
+        DatabaseType::MySQL => return Ok(mysql_batch_rows(output)),
+        // FOR JSON splits long results across rows; no rows means no matches
+        DatabaseType::SQLServer => {
+            let joined: String = output.lines().map(|line| line.trim_end_matches('\r')).collect();
+            if joined.trim().is_empty() {
+                "[]".to_string()
+            } else {
+                joined
+            }
+        }
+        // sqlite3 -json prints nothing at all for an empty result
+        DatabaseType::SQLite => match output.trim() {
+            "" => "[]".to_string(),
+            json => json.to_string(),
+        },
+        DatabaseType::Redis | DatabaseType::MongoDB => {
+            return Err(DBArenaError::InvalidConfig(format!(
+                "--format json is only supported for SQL databases, not {}",
+                db_type
+            )))
+        }
+    };
+    serde_json::from_str(&json)
+        .map_err(|e| DBArenaError::Other(format!("Query did not return JSON ({})", e)))
+}
+
+/// The single value in `cockroach sql --format=raw` output
+///
+/// Raw output frames each value as `## <bytes>` followed by its exact bytes,
+/// with `#` comment lines around it.
+fn cockroach_raw_value(output: &str) -> Result<String> {
+    let start = output
+        .find("## ")
+        .ok_or_else(|| DBArenaError::Other("Query returned no value".to_string()))?;
+    let (len, rest) = output[start + 3..]
+        .split_once('\n')
+        .ok_or_else(|| DBArenaError::Other("Truncated query output".to_string()))?;
+    let len: usize = len
+        .trim()
+        .parse()
+        .map_err(|_| DBArenaError::Other(format!("Unexpected value length: {}", len)))?;
+    rest.get(..len)
+        .map(str::to_string)
+        .ok_or_else(|| DBArenaError::Other("Truncated query output".to_string()))
+}
+
+/// Rows of mysql batch output (a header line, then tab-separated rows) as
+/// JSON objects
+///
+/// Values stay strings since batch output doesn't carry types; `NULL` becomes
+/// null. No output means no rows.
+fn mysql_batch_rows(output: &str) -> serde_json::Value {
+    let mut lines = output.lines().filter(|line| !line.is_empty());
+    let header: Vec<String> = match lines.next() {
+        Some(header) => header.split('\t').map(unescape_mysql).collect(),
+        None => return serde_json::Value::Array(Vec::new()),
+    };
+
+    let rows = lines
+        .map(|line| {
+            let row = header
+                .iter()
+                .zip(line.split('\t'))
+                .map(|(column, value)| {
+                    let value = match value {
+                        "NULL" => serde_json::Value::Null,
+                        value => serde_json::Value::String(unescape_mysql(value)),
+                    };
+                    (column.clone(), value)
+                })
+                .collect();
+            serde_json::Value::Object(row)
+        })
+        .collect();
+    serde_json::Value::Array(rows)
+}
+
+/// Undo mysql's batch-mode escaping of `\0`, `\b`, `\n`, `\r`, `\t` and `\\`
+fn unescape_mysql(value: &str) -> String {
+    let mut unescaped = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('0') => unescaped.push('\0'),
+            Some('b') => unescaped.push('\u{8}'),
+            Some('n') => unescaped.push('\n'),
+            Some('r') => unescaped.push('\r'),
+            Some('t') => unescaped.push('\t'),
+            Some(other) => unescaped.push(other),
+            None => unescaped.push('\\'),
+        }
+    }
+    unescaped
+}
+
+/// Tidy a plan printed by the database client for the terminal
+///
+/// mysql's batch output puts the whole tree on one line under an `EXPLAIN`
+/// header with newlines escaped; other clients already print a readable plan.
+fn render_plan(db_type: DatabaseType, output: &str) -> String {
+    match db_type {
+        DatabaseType::MySQL => output
+            .lines()
+            .filter(|line| line.trim() != "EXPLAIN")
+            .map(|line| line.replace("\\n", "\n").replace("\\t", "    "))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        _ => output.trim_end().to_string(),
+    }
+}
+
+/// Extra time the client gets past `--timeout` before the CLI gives up on it
+const TIMEOUT_GRACE: Duration = Duration::from_secs(5);
+
+/// Session statement that cancels statements running longer than `secs`
+///
+/// MySQL's `max_execution_time` only applies to SELECT. SQL Server takes the
+/// timeout as a sqlcmd flag instead, and Redis, MongoDB and SQLite rely on
+/// the CLI-side timeout alone.
+fn statement_timeout_sql(db_type: DatabaseType, secs: u64) -> Option<String> {
+    match db_type {
+        DatabaseType::Postgres | DatabaseType::CockroachDB => {
+            Some(format!("SET statement_timeout = '{}s';", secs))
+        }
+        DatabaseType::MySQL => Some(format!("SET SESSION max_execution_time = {};", secs * 1000)),
+        DatabaseType::SQLServer
+        | DatabaseType::Redis
+        | DatabaseType::MongoDB
+        | DatabaseType::SQLite => None,
+    }
+}
+
+/// Whether client output reports a statement cancelled by its timeout
+fn is_timeout_error(output: &str) -> bool {
+    const MARKERS: &[&str] = &[
+        // Postgres and CockroachDB
+        "canceling statement due to statement timeout",
+        "query execution canceled due to statement timeout",
+        // MySQL (ER_QUERY_TIMEOUT)
+        "maximum statement execution time exceeded",
+        // sqlcmd -t
+        "Query timeout expired",
+    ];
+    MARKERS.iter().any(|marker| output.contains(marker))
+}
+
+/// The statement to name in a timeout error
+///
+/// Clients reading a script from stdin report the line that failed
+/// (`psql:<stdin>:3: ERROR: ...`, `ERROR 3024 (HY000) at line 3: ...`); that
+/// line is counted after the one-line timeout prefix. Otherwise the script
+/// itself is shown, shortened if it spans several lines.
+fn timed_out_statement(sql: &str, output: &str, prefixed: bool) -> String {
+    let line = output.lines().find_map(|line| {
+        let rest = match line.strip_prefix("psql:") {
+            Some(rest) => rest.split(':').nth(1),
+            None => line.split_once(" at line ").map(|(_, rest)| rest),
+        }?;
+        rest.split(':').next()?.trim().parse::<usize>().ok()
+    });
+    let statement = line
+        .and_then(|n| n.checked_sub(usize::from(prefixed) + 1))
+        .and_then(|index| sql.lines().nth(index))
+        .map(str::trim)
+        .filter(|statement| !statement.is_empty());
+    if let Some(statement) = statement {
+        return statement.to_string();
+    }
+
+    let sql = sql.trim();
+    match sql.lines().next() {
+        Some(first) if sql.lines().count() == 1 && first.len() <= 120 => first.to_string(),
+        Some(first) => format!("{}...", first.chars().take(80).collect::<String>()),
+        None => String::new(),
+    }
+}
+
+fn build_query_command(
+    params: &ConnParams,
+    sql: &str,
+    is_file: bool,
+    timeout: Option<u64>,
+    json: bool,
+) -> Result<Vec<String>> {
+    let db_type = params.db_type;
+    let session = timeout.and_then(|secs| statement_timeout_sql(db_type, secs));
+    // Scripts piped on stdin get the timeout as their first line
+    let script = match &session {
+        Some(set) => format!("{}\n{}", set, sql),
+        None => sql.to_string(),
+    };
+    // Host and port of an external database, for the `sh -c` forms
+    let endpoint: String = params
+        .endpoint_args()
+        .iter()
+        .map(|arg| format!(" {}", shell_quote(arg)))
+        .collect();
+    // Empty for an external database, whose client reads it from the environment
+    let password: String = params
+        .password_args()
+        .iter()
+        .map(|arg| format!(" {}", shell_quote(arg)))
+        .collect();
+
+    let cmd = match db_type {
         DatabaseType::Postgres => {
             if is_file {
                 // For file content, write to temp file in container and execute
                 vec![
                     "sh".to_string(),
                     "-c".to_string(),
-                    format!("echo '{}' | psql -U postgres -d postgres", sql.replace('\'', "'\\'''")),
+                    format!(
+                        "echo '{}' | psql -U {} -d {}{}",
+                        script.replace('\'', "'\\'''"),
+                        shell_quote(&params.user),
+                        shell_quote(&params.database),
+                        endpoint
+                    ),
                 ]
             } else {
                 // For inline script, use -c flag
-                vec![
+                let mut cmd = vec![
                     "psql".to_string(),
                     "-U".to_string(),
-                    "postgres".to_string(),
+                    params.user.clone(),
                     "-d".to_string(),
-                    "postgres".to_string(),
+                    params.database.clone(),
+                ];
+                cmd.extend(params.endpoint_args());
+                if json {
+                    // Only the value: no headers, padding or command tags
+                    cmd.extend(["-q", "-t", "-A"].map(String::from));
+                }
+                // A separate -c so the timeout is in effect before the script
+                // arrives; a SET inside the same message would start too late
+                if let Some(set) = session {
+                    cmd.extend(["-c".to_string(), set]);
+                }
+                cmd.extend(["-c".to_string(), sql.to_string()]);
+                cmd
+            }
+        }
+        DatabaseType::CockroachDB => {
+            // An external cluster is reached by URL, which names the database
+            let connect = match &params.endpoint {
+                Some(endpoint) => vec!["--url".to_string(), endpoint.url.clone()],
+                None => vec!["--insecure".to_string(), format!("--database={}", params.database)],
+            };
+            if is_file {
+                // cockroach sql reads statements from stdin when there's no -e
+                vec![
+                    "sh".to_string(),
                     "-c".to_string(),
-                    sql.to_string(),
+                    format!(
+                        "echo '{}' | cockroach sql {}",
+                        script.replace('\'', "'\\'''"),
+                        connect.iter().map(|arg| shell_quote(arg)).collect::<Vec<_>>().join(" ")
+                    ),
                 ]
+            } else {
+                let mut cmd = vec!["cockroach".to_string(), "sql".to_string()];
+                cmd.extend(connect);
+                if json {
+                    cmd.push("--format=raw".to_string());
+                }
+                if let Some(set) = session {
+                    cmd.extend(["-e".to_string(), set]);
+                }
+                cmd.extend(["-e".to_string(), sql.to_string()]);
+                cmd
             }
         }
         DatabaseType::MySQL => {
             vec![
                 "sh".to_string(),
                 "-c".to_string(),
-                format!("echo '{}' | mysql -u root -pmysql", sql.replace('\'', "'\\'''")),
+                format!(
+                    "echo '{}' | mysql -u {}{}{}{}",
+                    script.replace('\'', "'\\'''"),
+                    shell_quote(&params.user),
+                    password,
+                    endpoint,
+                    if params.database.is_empty() {
+                        String::new()
+                    } else {
+                        format!(" {}", shell_quote(&params.database))
+                    }
+                ),
             ]
         }
         DatabaseType::SQLServer => {
+            // On this host for an external database, so found on the PATH
+            let sqlcmd = if params.is_external() {
+                "sqlcmd"
+            } else {
+                "/opt/mssql-tools/bin/sqlcmd"
+            };
+            // sqlcmd cancels a batch that runs longer than -t seconds
+            let query_timeout = timeout.map(|secs| format!(" -t {}", secs)).unwrap_or_default();
+            if json {
+                // -Q rather than stdin, which would mix `1>` prompts into the
+                // output; no header, and no padding or truncation of the text
+                let mut cmd: Vec<String> = vec![
+                    sqlcmd.to_string(),
+                    "-S".to_string(),
+                    params.server(),
+                    "-U".to_string(),
+                    params.user.clone(),
+                ];
+                cmd.extend(params.password_args());
+                cmd.extend(["-d".to_string(), params.database.clone()]);
+                cmd.extend(["-h", "-1", "-y", "0"].map(String::from));
+                if let Some(secs) = timeout {
+                    cmd.extend(["-t".to_string(), secs.to_string()]);
+                }
+                cmd.extend(["-Q".to_string(), sql.to_string()]);
+                return Ok(cmd);
+            }
             vec![
                 "sh".to_string(),
                 "-c".to_string(),
                 format!(
-                    "echo '{}' | /opt/mssql-tools/bin/sqlcmd -S localhost -U sa -P 'YourStrong@Passw0rd'",
-                    sql.replace('\'', "'\\'''")
+                    "echo '{}' | {} -S {} -U {}{} -d {}{}",
+                    sql.replace('\'', "'\\'''"),
+                    sqlcmd,
+                    shell_quote(&params.server()),
+                    shell_quote(&params.user),
+                    password,
+                    shell_quote(&params.database),
+                    query_timeout
                 ),
             ]
         }
+        DatabaseType::SQLite => {
+            // -bail stops at the first error, like ON_ERROR_STOP
+            let mode = if json { "-json" } else { "-header -column" };
+            if is_file {
+                vec![
+                    "sh".to_string(),
+                    "-c".to_string(),
+                    format!(
+                        "echo '{}' | sqlite3 -bail {} {}",
+                        sql.replace('\'', "'\\'''"),
+                        mode,
+                        SQLITE_DB_PATH
+                    ),
+                ]
+            } else {
+                let mut cmd = vec!["sqlite3".to_string(), "-bail".to_string()];
+                cmd.extend(mode.split(' ').map(String::from));
+                cmd.extend([SQLITE_DB_PATH.to_string(), sql.to_string()]);
+                cmd
+            }
+        }
+        DatabaseType::Redis => {
+            if is_file {
+                // redis-cli reads one command per line from stdin
+                vec![
+                    "sh".to_string(),
+                    "-c".to_string(),
+                    format!(
+                        "echo '{}' | redis-cli{}",
+                        sql.replace('\'', "'\\'''"),
+                        endpoint
+                    ),
+                ]
+            } else {
+                // Redis has no SQL; run the script as a raw redis-cli command
+                let mut cmd = vec!["redis-cli".to_string()];
+                cmd.extend(params.endpoint_args());
+                cmd.extend(split_args(sql.trim())?);
+                cmd
+            }
+        }
+        DatabaseType::MongoDB => {
+            // mongosh evaluates inline scripts and file contents alike as JavaScript
+            let mut cmd = vec![
+                "mongosh".to_string(),
+                "--quiet".to_string(),
+                "-u".to_string(),
+                params.user.clone(),
+                "-p".to_string(),
+                params.password.clone(),
+                "--authenticationDatabase".to_string(),
+                "admin".to_string(),
+            ];
+            cmd.extend(params.endpoint_args());
+            if !params.database.is_empty() {
+                cmd.push(params.database.clone());
+            }
+            cmd.extend(["--eval".to_string(), sql.to_string()]);
+            cmd
+        }
+    };
+    Ok(cmd)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn params(db_type: DatabaseType) -> ConnParams {
+        ConnParams::from_env(db_type, HashMap::new())
+    }
+
+    #[test]
+    fn test_build_query_command_timeout() {
+        let cmd = build_query_command(
+            &params(DatabaseType::Postgres),
+            "SELECT 1",
+            false,
+            Some(5),
+            false,
+        ).unwrap();
+        assert_eq!(
+            cmd[5..],
+            ["-c", "SET statement_timeout = '5s';", "-c", "SELECT 1"]
+        );
+
+        let cmd = build_query_command(
+            &params(DatabaseType::MySQL),
+            "SELECT 1",
+            false,
+            Some(5),
+            false,
+        ).unwrap();
+        assert!(cmd[2].starts_with("echo 'SET SESSION max_execution_time = 5000;\nSELECT 1'"));
+
+        let cmd = build_query_command(
+            &params(DatabaseType::SQLServer),
+            "SELECT 1",
+            false,
+            Some(5),
+            false,
+        ).unwrap();
+        assert!(cmd[2].ends_with(" -t 5"));
+
+        let cmd = build_query_command(
+            &params(DatabaseType::Postgres),
+            "SELECT 1",
+            false,
+            None,
+            false,
+        ).unwrap();
+        assert_eq!(cmd[5..], ["-c", "SELECT 1"]);
+    }
+
+    #[test]
+    fn test_query_uses_container_credentials() {
+        let postgres = ConnParams::from_env(
+            DatabaseType::Postgres,
+            HashMap::from([
+                ("POSTGRES_USER".to_string(), "app".to_string()),
+                ("POSTGRES_DB".to_string(), "shop".to_string()),
+            ]),
+        );
+        let cmd = build_query_command(&postgres, "SELECT 1", false, None, false).unwrap();
+        assert_eq!(cmd[..5], ["psql", "-U", "app", "-d", "shop"]);
+
+        let mysql = ConnParams::from_env(
+            DatabaseType::MySQL,
+            HashMap::from([
+                ("MYSQL_ROOT_PASSWORD".to_string(), "s3cret".to_string()),
+                ("MYSQL_DATABASE".to_string(), "shop".to_string()),
+            ]),
+        );
+        let cmd = build_query_command(&mysql, "SELECT 1", false, None, false).unwrap();
+        assert!(cmd[2].ends_with("| mysql -u 'root' '-ps3cret' 'shop'"));
+    }
+
+    #[test]
+    fn test_explain_statement() {
+        assert_eq!(
+            explain_statement(DatabaseType::Postgres, "SELECT * FROM users;").unwrap(),
+            "EXPLAIN (ANALYZE, BUFFERS) SELECT * FROM users"
+        );
+        assert_eq!(
+            explain_statement(DatabaseType::MySQL, "SELECT 1").unwrap(),
+            "EXPLAIN ANALYZE SELECT 1"
+        );
+        let sqlserver = explain_statement(DatabaseType::SQLServer, "SELECT 1").unwrap();
+        assert!(sqlserver.starts_with("SET STATISTICS IO, TIME ON;"));
+        assert!(sqlserver.contains("SET STATISTICS PROFILE ON;\nSELECT 1;\n"));
+        assert!(explain_statement(DatabaseType::Redis, "GET key").is_err());
+    }
+
+    #[test]
+    fn test_sqlite_query() {
+        let cmd = build_query_command(
+            &params(DatabaseType::SQLite),
+            "SELECT 1",
+            false,
+            Some(5),
+            false,
+        ).unwrap();
+        assert_eq!(
+            cmd,
+            ["sqlite3", "-bail", "-header", "-column", "/data/db.sqlite", "SELECT 1"]
+        );
+
+        let cmd = build_query_command(&params(DatabaseType::SQLite), "SELECT 1", true, None, true).unwrap();
+        assert_eq!(cmd[2], "echo 'SELECT 1' | sqlite3 -bail -json /data/db.sqlite");
+
+        assert_eq!(
+            explain_statement(DatabaseType::SQLite, "SELECT 1;").unwrap(),
+            "EXPLAIN QUERY PLAN SELECT 1"
+        );
+        assert_eq!(
+            parse_json_output(DatabaseType::SQLite, "[{\"id\":1},\n{\"id\":2}]\n").unwrap(),
+            serde_json::json!([{"id": 1}, {"id": 2}])
+        );
+        assert_eq!(parse_json_output(DatabaseType::SQLite, "").unwrap(), serde_json::json!([]));
+    }
+
+    #[test]
+    fn test_redis_query_keeps_quoted_arguments() {
+        let cmd = build_query_command(
+            &params(DatabaseType::Redis),
+            r#"SET greeting "hello world""#,
+            false,
+            None,
+            false,
+        )
+        .unwrap();
+        assert_eq!(cmd, ["redis-cli", "SET", "greeting", "hello world"]);
+
+        let unbalanced = r#"SET greeting "hello"#;
+        assert!(build_query_command(&params(DatabaseType::Redis), unbalanced, false, None, false)
+            .is_err());
+    }
+
+    #[test]
+    fn test_render_mysql_plan() {
+        let output = "EXPLAIN\n-> Filter: (users.id > 1)  (rows=3)\\n    -> Table scan on users\n";
+        assert_eq!(
+            render_plan(DatabaseType::MySQL, output),
+            "-> Filter: (users.id > 1)  (rows=3)\n    -> Table scan on users"
+        );
+    }
+
+    #[test]
+    fn test_json_statement_and_flags() {
+        let sql = json_statement(DatabaseType::Postgres, "SELECT id FROM users;").unwrap();
+        assert_eq!(
+            sql,
+            concat!(
+                "SELECT coalesce(json_agg(dbarena_rows), '[]') ",
+                "FROM (SELECT id FROM users) AS dbarena_rows"
+            )
+        );
+        let cmd = build_query_command(&params(DatabaseType::Postgres), &sql, false, None, true).unwrap();
+        assert_eq!(cmd[5..8], ["-q", "-t", "-A"]);
+
+        let sql = json_statement(DatabaseType::SQLServer, "SELECT id FROM users").unwrap();
+        assert!(sql.ends_with("SELECT id FROM users FOR JSON PATH, INCLUDE_NULL_VALUES"));
+        let cmd = build_query_command(&params(DatabaseType::SQLServer), &sql, false, Some(5), true).unwrap();
+        assert_eq!(cmd[cmd.len() - 4..], ["-t", "5", "-Q", sql.as_str()]);
+
+        assert!(json_statement(DatabaseType::MongoDB, "db.users.find()").is_err());
+    }
+
+    #[test]
+    fn test_parse_postgres_json() {
+        let output = "[{\"id\":1,\"name\":\"ada\"}, \n {\"id\":2,\"name\":null}]\n";
+        let rows = parse_json_output(DatabaseType::Postgres, output).unwrap();
+        assert_eq!(rows[1]["id"], 2);
+        assert!(rows[1]["name"].is_null());
+
+        assert!(parse_json_output(DatabaseType::Postgres, "ERROR: oops").is_err());
+    }
+
+    #[test]
+    fn test_parse_cockroach_raw_json() {
+        let output = concat!(
+            "# 1 column\n",
+            "# row 1\n",
+            "## 27\n",
+            "[{\"id\": 1, \"note\": \"a\\nb\"}]\n",
+            "# 1 row\n",
+        );
+        let rows = parse_json_output(DatabaseType::CockroachDB, output).unwrap();
+        assert_eq!(rows[0]["note"], "a\nb");
+    }
+
+    #[test]
+    fn test_parse_mysql_batch_json() {
+        let output = "id\tname\tbio\n1\tada\tline one\\nline two\n2\tgrace\tNULL\n";
+        let rows = parse_json_output(DatabaseType::MySQL, output).unwrap();
+        assert_eq!(
+            rows,
+            serde_json::json!([
+                { "id": "1", "name": "ada", "bio": "line one\nline two" },
+                { "id": "2", "name": "grace", "bio": null },
+            ])
+        );
+        assert_eq!(parse_json_output(DatabaseType::MySQL, "").unwrap(), serde_json::json!([]));
+    }
+
+    #[test]
+    fn test_parse_sqlserver_json() {
+        // FOR JSON results longer than 2033 characters arrive split over lines
+        let output = "[{\"id\":1,\"name\":\"a\"},{\"id\":2,\"na\r\nme\":\"b\"}]\r\n";
+        let rows = parse_json_output(DatabaseType::SQLServer, output).unwrap();
+        assert_eq!(rows[1]["name"], "b");
+        assert_eq!(parse_json_output(DatabaseType::SQLServer, "").unwrap(), serde_json::json!([]));
+    }
+
+    #[test]
+    fn test_timed_out_statement() {
+        let sql = "SELECT 1;\nSELECT pg_sleep(60);\n";
+        let output = "psql:<stdin>:3: ERROR:  canceling statement due to statement timeout\n";
+        assert!(is_timeout_error(output));
+        assert_eq!(timed_out_statement(sql, output, true), "SELECT pg_sleep(60);");
+
+        let output = "ERROR 3024 (HY000) at line 2: Query execution was interrupted, \
+                      maximum statement execution time exceeded\n";
+        assert!(is_timeout_error(output));
+        assert_eq!(timed_out_statement(sql, output, true), "SELECT 1;");
+
+        // No line number: fall back to the script itself
+        assert_eq!(timed_out_statement("SELECT pg_sleep(60)", "", false), "SELECT pg_sleep(60)");
+        assert_eq!(timed_out_statement(sql, "", false), "SELECT 1;...");
+        assert!(!is_timeout_error("ERROR:  relation \"users\" does not exist"));
     }
 }
@@ -0,0 +1,112 @@
+use crate::cli::output::OutputFormat;
+use crate::cli::summary;
+use crate::container::{ContainerManager, DockerClient};
+use crate::monitoring::format_bytes;
+use crate::progress::IndicatifProgress;
+use crate::replication::ReplicaManager;
+use crate::{DBArenaError, Result};
+use console::style;
+
+/// Handle `replica create`: provision a streaming replica of a Postgres container
+pub async fn handle_replica_create(
+    primary: String,
+    name: Option<String>,
+    port: Option<u16>,
+) -> Result<()> {
+    let docker_client = DockerClient::new()?;
+    docker_client.verify_connection().await?;
+
+    let manager = ContainerManager::new(docker_client.clone());
+    let primary = manager
+        .find_container(&primary)
+        .await?
+        .ok_or_else(|| DBArenaError::ContainerNotFound(primary.clone()))?;
+    let name = name.unwrap_or_else(|| format!("{}-replica", primary.name));
+
+    println!(
+        "{} Creating replica {} of {}...",
+        style("→").cyan(),
+        style(&name).bold(),
+        style(&primary.name).bold()
+    );
+
+    let replicas = ReplicaManager::new(docker_client);
+    let replica = replicas
+        .create_replica(&primary, &name, port, &IndicatifProgress::new())
+        .await?;
+
+    summary::record("created", 1);
+    println!("\n{}", style("Replica ready!").green().bold());
+    println!("  {}: {}", style("Name").bold(), replica.name);
+    println!("  {}: {}", style("ID").bold(), &replica.id[..12]);
+    println!(
+        "  {}: {}",
+        style("Port").bold(),
+        replica
+            .host_port
+            .map(|p| p.to_string())
+            .unwrap_or_else(|| "N/A".to_string())
+    );
+    println!(
+        "  {}: {}",
+        style("Network").bold(),
+        ReplicaManager::network_name(&primary.name)
+    );
+    println!(
+        "\n  Check lag with: {}",
+        style(format!("dbarena replica status {}", primary.name)).cyan()
+    );
+
+    Ok(())
+}
+
+/// Handle `replica status`: show each replica's state and lag as seen by the primary
+pub async fn handle_replica_status(primary: String, format: OutputFormat) -> Result<()> {
+    let docker_client = DockerClient::new()?;
+    docker_client.verify_connection().await?;
+
+    let manager = ContainerManager::new(docker_client.clone());
+    let primary = manager
+        .find_container(&primary)
+        .await?
+        .ok_or_else(|| DBArenaError::ContainerNotFound(primary.clone()))?;
+
+    let statuses = ReplicaManager::new(docker_client).status(&primary).await?;
+
+    if format.is_structured() {
+        format.print(&statuses)?;
+        return Ok(());
+    }
+
+    if statuses.is_empty() {
+        println!(
+            "No replicas of {}. Create one with: dbarena replica create --primary {}",
+            primary.name, primary.name
+        );
+        return Ok(());
+    }
+
+    println!(
+        "{:<30} {:<14} {:<8} {:>12} {:>10}",
+        "REPLICA", "STATE", "SYNC", "LAG", "LAG (s)"
+    );
+    println!("{}", "─".repeat(78));
+    for status in statuses {
+        println!(
+            "{:<30} {:<14} {:<8} {:>12} {:>10}",
+            status.name,
+            status.state,
+            status.sync_state.as_deref().unwrap_or("-"),
+            status
+                .lag_bytes
+                .map(format_bytes)
+                .unwrap_or_else(|| "-".to_string()),
+            status
+                .lag_seconds
+                .map(|s| format!("{:.2}", s))
+                .unwrap_or_else(|| "-".to_string())
+        );
+    }
+
+    Ok(())
+}
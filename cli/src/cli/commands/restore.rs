@@ -0,0 +1,338 @@
+use crate::container::{
+    connection_params, ConnParams, ContainerManager, DatabaseType, DockerClient, SQLITE_DB_PATH,
+};
+use crate::init::{copy_file_to_container, parse_error, run_exec, run_exec_with_env};
+use crate::util::shell::shell_quote;
+use crate::{DBArenaError, Result};
+use console::style;
+use flate2::read::GzDecoder;
+use std::fs::File;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+/// Where dumps are staged inside the container (not /tmp, whose tmpfs blocks uploads)
+const RESTORE_DIR: &str = "/var/dbarena_restore";
+
+const GZIP_MAGIC: &[u8] = &[0x1f, 0x8b];
+/// pg_dump custom-format archives start with this
+const PG_CUSTOM_MAGIC: &[u8] = b"PGDMP";
+/// SQL Server backups (Microsoft Tape Format) start with this
+const MSSQL_BACKUP_MAGIC: &[u8] = b"TAPE";
+
+/// What a dump file contains, judged by its leading bytes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct DumpKind {
+    gzip: bool,
+    format: DumpFormat,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DumpFormat {
+    /// Plain SQL script
+    Sql,
+    /// pg_dump -Fc archive
+    PgCustom,
+    /// SQL Server BACKUP file
+    MssqlBackup,
+}
+
+/// Handle the restore command: load a logical dump into a container's database
+pub async fn handle_restore(container: String, input: PathBuf) -> Result<()> {
+    let kind = detect_dump_kind(&input)?;
+
+    let docker_client = DockerClient::new()?;
+    docker_client.verify_connection().await?;
+
+    let manager = ContainerManager::new(docker_client.clone());
+    let found = manager
+        .find_container(&container)
+        .await?
+        .ok_or_else(|| DBArenaError::ContainerNotFound(container.clone()))?;
+
+    let docker = docker_client.docker();
+    let params = connection_params(docker, &found).await?;
+    let db_type = params.db_type;
+
+    let file_name = input
+        .file_name()
+        .and_then(|n| n.to_str())
+        .ok_or_else(|| {
+            DBArenaError::InvalidConfig(format!("Invalid dump file name: {}", input.display()))
+        })?;
+    let staged = format!("{}/{}", RESTORE_DIR, file_name);
+    let (cmd, exec_env) = build_restore_command(&params, kind, &staged)?;
+
+    println!(
+        "{} Restoring {} into {}...",
+        style("→").cyan(),
+        style(input.display()).bold(),
+        style(&found.name).bold()
+    );
+
+    let mkdir = vec!["mkdir".to_string(), "-p".to_string(), RESTORE_DIR.to_string()];
+    run_exec(docker, &found.id, mkdir).await?;
+    copy_file_to_container(docker, &found.id, &input, RESTORE_DIR).await?;
+
+    let result = run_exec_with_env(docker, &found.id, cmd, exec_env).await;
+
+    // Clean up the staged copy whether or not the restore worked
+    let _ = run_exec(docker, &found.id, vec!["rm".to_string(), "-f".to_string(), staged]).await;
+
+    if let Err(e) = result {
+        let error = parse_error(&e.to_string(), &input, db_type);
+        println!("{} Restore failed", style("✗").red().bold());
+        println!();
+        println!("{}", style("Error Details:").red().bold());
+        println!("{}", "─".repeat(60));
+        println!("{}", error);
+        println!("{}", "─".repeat(60));
+        return Err(DBArenaError::ContainerOperationFailed(format!(
+            "Restore of {} failed{}",
+            input.display(),
+            error
+                .line_number
+                .map(|line| format!(" at line {}", line))
+                .unwrap_or_default()
+        )));
+    }
+
+    println!(
+        "  {} Restored {} into {}",
+        style("✓").green(),
+        input.display(),
+        found.name
+    );
+
+    Ok(())
+}
+
+/// Sniff gzip and the dump format from the first bytes of the file
+fn detect_dump_kind(path: &Path) -> Result<DumpKind> {
+    let mut head = Vec::new();
+    File::open(path)?.take(8).read_to_end(&mut head)?;
+    let gzip = head.starts_with(GZIP_MAGIC);
+
+    if gzip {
+        head.clear();
+        GzDecoder::new(File::open(path)?)
+            .take(8)
+            .read_to_end(&mut head)?;
+    }
+
+    let format = if head.starts_with(PG_CUSTOM_MAGIC) {
+        DumpFormat::PgCustom
+    } else if head.starts_with(MSSQL_BACKUP_MAGIC) {
+        DumpFormat::MssqlBackup
+    } else {
+        DumpFormat::Sql
+    };
+
+    Ok(DumpKind { gzip, format })
+}
+
+/// Restore command for a dump staged at `path` inside the container, and extra
+/// exec environment
+///
+/// Every tool stops at the first error so the failing line is the one reported.
+fn build_restore_command(
+    params: &ConnParams,
+    kind: DumpKind,
+    path: &str,
+) -> Result<(Vec<String>, Vec<String>)> {
+    let db_type = params.db_type;
+    // Decompress inside the container, feeding the tool on stdin
+    let source = if kind.gzip {
+        format!("gunzip -c {}", shell_quote(path))
+    } else {
+        format!("cat {}", shell_quote(path))
+    };
+
+    // Passwords go in the exec environment rather than the `sh -c` script,
+    // where they would show in the container's process list
+    let mut exec_env = Vec::new();
+    let script = match (db_type, kind.format) {
+        (DatabaseType::Postgres, DumpFormat::Sql) => format!(
+            "{} | psql -U {} -d {} -v ON_ERROR_STOP=1 -q -f -",
+            source,
+            shell_quote(&params.user),
+            shell_quote(&params.database)
+        ),
+        (DatabaseType::Postgres, DumpFormat::PgCustom) => format!(
+            "{} | pg_restore -U {} -d {} --exit-on-error --no-owner",
+            source,
+            shell_quote(&params.user),
+            shell_quote(&params.database)
+        ),
+        (DatabaseType::CockroachDB, DumpFormat::Sql) => {
+            // cockroach sql reads statements from stdin and stops at the first error
+            format!(
+                "{} | cockroach sql --insecure --database={}",
+                source,
+                shell_quote(&params.database)
+            )
+        }
+        (DatabaseType::SQLite, DumpFormat::Sql) => {
+            format!("{} | sqlite3 -bail {}", source, SQLITE_DB_PATH)
+        }
+        (DatabaseType::MySQL, DumpFormat::Sql) => {
+            exec_env.push(format!("MYSQL_PWD={}", params.password));
+            format!(
+                "{} | mysql -u {} {}",
+                source,
+                shell_quote(&params.user),
+                if params.database.is_empty() {
+                    String::new()
+                } else {
+                    shell_quote(&params.database)
+                }
+            )
+        }
+        (DatabaseType::SQLServer, DumpFormat::Sql | DumpFormat::MssqlBackup) => {
+            // sqlcmd reads the password from SQLCMDPASSWORD when there's no -P
+            exec_env.push(format!("SQLCMDPASSWORD={}", params.password));
+            let sqlcmd = format!(
+                "/opt/mssql-tools18/bin/sqlcmd -S localhost -U {} -C -b",
+                shell_quote(&params.user)
+            );
+            // sqlcmd and RESTORE both need a real file, so decompress next to the upload
+            let raw = format!("{}.raw", path);
+            let unpack = format!("{} > {}", source, shell_quote(&raw));
+            let run = if kind.format == DumpFormat::MssqlBackup {
+                let db = params.database.replace(']', "]]");
+                format!(
+                    "{} -Q {}",
+                    sqlcmd,
+                    shell_quote(&format!(
+                        "RESTORE DATABASE [{}] FROM DISK = N'{}' WITH REPLACE",
+                        db,
+                        raw.replace('\'', "''")
+                    ))
+                )
+            } else {
+                format!("{} -i {}", sqlcmd, shell_quote(&raw))
+            };
+            format!(
+                "{} && {}; status=$?; rm -f {}; exit $status",
+                unpack,
+                run,
+                shell_quote(&raw)
+            )
+        }
+        (DatabaseType::Redis | DatabaseType::MongoDB, _) => {
+            return Err(DBArenaError::InvalidConfig(format!(
+                "Restoring dumps is not supported for {}",
+                db_type
+            )))
+        }
+        (_, dump_format) => {
+            return Err(DBArenaError::InvalidConfig(format!(
+                "A {} file can't be restored into {}",
+                match dump_format {
+                    DumpFormat::PgCustom => "pg_dump custom-format",
+                    DumpFormat::MssqlBackup => "SQL Server backup",
+                    DumpFormat::Sql => "SQL",
+                },
+                db_type
+            )))
+        }
+    };
+
+    // pipefail so a failing gunzip/cat isn't masked by the tool's exit code;
+    // the Alpine-based SQLite image only has busybox sh, which supports it too
+    let shell = if db_type == DatabaseType::SQLite { "sh" } else { "bash" };
+    Ok((
+        vec![
+            shell.to_string(),
+            "-c".to_string(),
+            format!("set -o pipefail; {}", script),
+        ],
+        exec_env,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::container::container_env;
+    use flate2::write::GzEncoder;
+    use flate2::Compression;
+    use std::collections::HashMap;
+    use std::io::Write;
+
+    fn kind(gzip: bool, format: DumpFormat) -> DumpKind {
+        DumpKind { gzip, format }
+    }
+
+    #[test]
+    fn test_detect_dump_kind() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let plain = dir.path().join("d.sql");
+        std::fs::write(&plain, "CREATE TABLE t (id int);\n").unwrap();
+        assert_eq!(detect_dump_kind(&plain).unwrap(), kind(false, DumpFormat::Sql));
+
+        // Gzip is detected by content, not by the file name
+        let custom = dir.path().join("d.dump");
+        let mut encoder = GzEncoder::new(File::create(&custom).unwrap(), Compression::default());
+        encoder.write_all(b"PGDMP\x01\x0e\x00rest").unwrap();
+        encoder.finish().unwrap();
+        assert_eq!(detect_dump_kind(&custom).unwrap(), kind(true, DumpFormat::PgCustom));
+    }
+
+    #[test]
+    fn test_postgres_restore_command() {
+        let params = ConnParams::from_env(
+            DatabaseType::Postgres,
+            container_env(vec!["POSTGRES_USER=app".to_string()]),
+        );
+        let gz_sql = kind(true, DumpFormat::Sql);
+        let (cmd, exec_env) = build_restore_command(&params, gz_sql, "/var/r/d.sql.gz").unwrap();
+        assert_eq!(
+            cmd[2],
+            "set -o pipefail; gunzip -c '/var/r/d.sql.gz' | psql -U 'app' -d 'app' \
+             -v ON_ERROR_STOP=1 -q -f -"
+        );
+        assert!(exec_env.is_empty());
+    }
+
+    #[test]
+    fn test_restore_passwords_stay_out_of_the_script() {
+        let sql = kind(false, DumpFormat::Sql);
+        let mysql = ConnParams::from_env(
+            DatabaseType::MySQL,
+            container_env(vec!["MYSQL_ROOT_PASSWORD=s3cret".to_string()]),
+        );
+        let (cmd, exec_env) = build_restore_command(&mysql, sql, "/var/r/d.sql").unwrap();
+        assert_eq!(cmd[2], "set -o pipefail; cat '/var/r/d.sql' | mysql -u 'root' ");
+        assert_eq!(exec_env, ["MYSQL_PWD=s3cret"]);
+
+        let mssql = ConnParams::from_env(
+            DatabaseType::SQLServer,
+            container_env(vec!["SA_PASSWORD=Str0ng!".to_string()]),
+        );
+        let (cmd, exec_env) = build_restore_command(&mssql, sql, "/var/r/d.sql").unwrap();
+        assert!(!cmd[2].contains("Str0ng!"));
+        assert_eq!(exec_env, ["SQLCMDPASSWORD=Str0ng!"]);
+    }
+
+    #[test]
+    fn test_sqlite_restore_command() {
+        let sql = kind(false, DumpFormat::Sql);
+        let params = ConnParams::from_env(DatabaseType::SQLite, HashMap::new());
+        let (cmd, _) = build_restore_command(&params, sql, "/var/r/d.sql").unwrap();
+        assert_eq!(
+            cmd[2],
+            "set -o pipefail; cat '/var/r/d.sql' | sqlite3 -bail /data/db.sqlite"
+        );
+    }
+
+    #[test]
+    fn test_restore_rejects_mismatched_formats() {
+        let mysql = ConnParams::from_env(DatabaseType::MySQL, HashMap::new());
+        let custom = kind(false, DumpFormat::PgCustom);
+        assert!(build_restore_command(&mysql, custom, "/d").is_err());
+        let redis = ConnParams::from_env(DatabaseType::Redis, HashMap::new());
+        let sql = kind(false, DumpFormat::Sql);
+        assert!(build_restore_command(&redis, sql, "/d").is_err());
+    }
+}
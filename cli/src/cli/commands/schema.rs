@@ -0,0 +1,48 @@
+use std::sync::Arc;
+
+use crate::cli::output::OutputFormat;
+use crate::cli::target::Target;
+use crate::database_metrics::collector::DockerDatabaseMetricsCollector;
+use crate::schema::{introspect, DatabaseSchema, IntrospectOptions};
+use crate::workload::MetadataCollector;
+use crate::{DBArenaError, Result};
+
+/// Handle the schema command: print every table's columns, keys and indexes
+pub async fn handle_schema(
+    container: Option<String>,
+    url: Option<String>,
+    format: OutputFormat,
+) -> Result<()> {
+    let target = Target::resolve(container.as_deref(), url.as_deref(), None).await?;
+    let db_type = target.params.db_type;
+    if !db_type.is_sql() {
+        return Err(DBArenaError::InvalidConfig(format!(
+            "Schema introspection is not supported for NoSQL databases ({})",
+            db_type
+        )));
+    }
+
+    let schema = read_schema(&target, IntrospectOptions::default()).await?;
+    if format.is_structured() {
+        return format.print(&schema);
+    }
+
+    schema.print(&target.name);
+    Ok(())
+}
+
+/// Introspect a target's schema through the workload metadata queries
+pub(crate) async fn read_schema(
+    target: &Target,
+    options: IntrospectOptions,
+) -> Result<DatabaseSchema> {
+    let db_type = target.params.db_type;
+    let collector = DockerDatabaseMetricsCollector::new(Arc::new(target.docker.docker().clone()));
+    let mut metadata =
+        MetadataCollector::new(collector, target.container_id().to_string(), db_type)
+            .with_connection(target.params.clone());
+
+    introspect(&mut metadata, db_type, options).await.map_err(|e| {
+        DBArenaError::Other(format!("Failed to read schema of {}: {}", target.name, e))
+    })
+}
@@ -1,30 +1,81 @@
 use anyhow::anyhow;
 use console::style;
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::Instant;
+use tracing::debug;
 
-use crate::container::{ContainerManager, DatabaseType, DockerClient};
-use crate::seed::{SeedConfig, SeedStats, SeedingEngine, SizePreset};
+use crate::cli::commands::schema::read_schema;
+use crate::cli::summary;
+use crate::cli::target::Target;
+use crate::database_metrics::DockerDatabaseMetricsCollector;
+use crate::seed::config::SeedRules;
+use crate::schema::IntrospectOptions;
+use crate::seed::infer::{infer_rule, profile_kind, render_config, starter_rules};
+use crate::seed::{SeedConfig, SeedManifest, SeedStats, SeedingEngine, SizePreset};
+use crate::util::bounded::DEFAULT_CONCURRENCY;
+use crate::workload::{MetadataCache, MetadataCollector};
 use crate::{DBArenaError, Result};
 
+#[allow(clippy::too_many_arguments)]
 pub async fn handle_seed(
-    config_path: PathBuf,
-    container: String,
+    config_path: Option<PathBuf>,
+    infer: bool,
+    tables: Vec<String>,
+    container: Option<String>,
+    url: Option<String>,
     size: Option<String>,
     seed: Option<u64>,
     truncate: bool,
     _incremental: bool,
     rows_override: Option<String>,
+    manifest_path: Option<PathBuf>,
+    concurrency: usize,
+    parallel: usize,
+    dry_run: Option<usize>,
+    profile: Option<String>,
+    refresh_metadata: bool,
 ) -> Result<()> {
     let start = Instant::now();
 
     // Parse configuration
-    let config_content = std::fs::read_to_string(&config_path)?;
+    let (mut config, config_content) = match &config_path {
+        Some(path) => {
+            let content = std::fs::read_to_string(path)?;
+            let config: SeedConfig = toml::from_str(&content).map_err(|e| {
+                DBArenaError::ConfigError(format!("Failed to parse seed config: {}", e))
+            })?;
+            (config, content)
+        }
+        None => (
+            SeedConfig {
+                global_seed: None,
+                batch_size: 1000,
+                seed_rules: SeedRules::default(),
+            },
+            String::new(),
+        ),
+    };
 
-    let mut config: SeedConfig = toml::from_str(&config_content)
-        .map_err(|e| DBArenaError::ConfigError(format!("Failed to parse seed config: {}", e)))?;
+    // Fill in rules from the live schema; the inferred config is what gets recorded
+    let config_content = if infer {
+        let content = infer_config(
+            &config,
+            &tables,
+            container.as_deref(),
+            url.as_deref(),
+            profile.as_deref(),
+            refresh_metadata,
+        )
+        .await?;
+        config = toml::from_str(&content).map_err(|e| {
+            DBArenaError::ConfigError(format!("Failed to parse inferred seed config: {}", e))
+        })?;
+        content
+    } else {
+        config_content
+    };
 
     // Apply size preset if specified
     if let Some(size_str) = size {
@@ -50,27 +101,274 @@ pub async fn handle_seed(
             .map_err(|e| DBArenaError::InvalidConfig(e.to_string()))?;
     }
 
-    // Find container
-    let docker_client = DockerClient::new()?;
-    docker_client.verify_connection().await?;
+    run_seed(
+        &config,
+        &config_content,
+        container.as_deref(),
+        url.as_deref(),
+        seed,
+        truncate,
+        manifest_path.as_deref(),
+        concurrency,
+        parallel,
+        dry_run,
+        profile.as_deref(),
+        start,
+    )
+    .await
+}
+
+/// Infer a seed config for the config's tables plus `tables` from their live schema,
+/// print it, and return it as TOML
+async fn infer_config(
+    config: &SeedConfig,
+    tables: &[String],
+    container: Option<&str>,
+    url: Option<&str>,
+    profile: Option<&str>,
+    refresh_metadata: bool,
+) -> Result<String> {
+    let target = Target::resolve(container, url, profile).await?;
+    let db_type = target.params.db_type;
+    if !db_type.is_sql() {
+        return Err(DBArenaError::InvalidConfig(format!(
+            "Seeding is not supported for NoSQL databases ({})",
+            db_type
+        )));
+    }
+
+    let mut names: Vec<&str> = config
+        .seed_rules
+        .tables()
+        .iter()
+        .map(|rule| rule.name.as_str())
+        .collect();
+    for table in tables {
+        if !names.contains(&table.as_str()) {
+            names.push(table);
+        }
+    }
+    if names.is_empty() {
+        return Err(DBArenaError::InvalidConfig(
+            "--infer needs tables to infer: list them with --tables or in --config".to_string(),
+        ));
+    }
+
+    println!(
+        "{} Inferring generators from {} table(s)...",
+        style("▸").cyan(),
+        names.len()
+    );
+
+    let collector = DockerDatabaseMetricsCollector::new(Arc::new(target.docker.docker().clone()));
+    let mut metadata =
+        MetadataCollector::new(collector, target.container_id().to_string(), db_type)
+            .with_connection(target.params.clone());
+    if let Some(cache) = MetadataCache::for_target(target.container_id(), &target.params) {
+        metadata = metadata.with_disk_cache(cache.refreshing(refresh_metadata));
+        metadata.load_disk_cache().await;
+    }
+
+    let mut rules = Vec::with_capacity(names.len());
+    for name in names {
+        let table = metadata
+            .get_metadata(name)
+            .await
+            .map_err(|e| DBArenaError::Other(format!("Failed to read schema of {}: {}", name, e)))?
+            .clone();
+        let explicit = config.seed_rules.tables().iter().find(|rule| rule.name == name);
+
+        // Fit generators to the data already there; a column that can't be sampled
+        // keeps its type-based default
+        let mut profiles = HashMap::new();
+        for column in &table.columns {
+            let Some(kind) = profile_kind(column) else {
+                continue;
+            };
+            match metadata.profile_column(&table, &column.name, kind).await {
+                Ok(profile) => {
+                    profiles.insert(column.name.clone(), profile);
+                }
+                Err(e) => debug!("Not profiling {}.{}: {}", name, column.name, e),
+            }
+        }
+        rules.push(infer_rule(&table, explicit, &profiles));
+    }
+    metadata.save_disk_cache();
+
+    let content = render_config(config.global_seed, config.batch_size, &rules);
+    println!();
+    println!("{}", style("# Inferred seed config (save and edit to reuse)").dim());
+    print!("{}", content);
+    println!();
+
+    Ok(content)
+}
+
+/// Write a starter seed config for every table in the target's database
+pub async fn handle_generate_config(
+    path: PathBuf,
+    container: Option<String>,
+    url: Option<String>,
+    profile: Option<String>,
+) -> Result<()> {
+    let target = Target::resolve(container.as_deref(), url.as_deref(), profile.as_deref()).await?;
+    let db_type = target.params.db_type;
+    if !db_type.is_sql() {
+        return Err(DBArenaError::InvalidConfig(format!(
+            "Seeding is not supported for NoSQL databases ({})",
+            db_type
+        )));
+    }
+
+    println!(
+        "{} Reading schema of {}...",
+        style("▸").cyan(),
+        style(&target.name).green()
+    );
+    let schema = read_schema(&target, IntrospectOptions::default()).await?;
+    if schema.tables.is_empty() {
+        return Err(DBArenaError::InvalidConfig(format!(
+            "{} has no tables to generate a seed config for",
+            target.name
+        )));
+    }
+
+    let starter =
+        starter_rules(&schema.tables).map_err(|e| DBArenaError::Other(e.to_string()))?;
+    std::fs::write(&path, starter.render(1000))?;
+
+    let rules = &starter.rules;
+    let references = rules
+        .iter()
+        .flat_map(|rule| &rule.columns)
+        .filter(|column| column.generator == "foreign_key")
+        .count();
+    println!(
+        "{} Wrote seed config for {} table(s) ({} foreign key(s)): {}",
+        style("✓").green(),
+        rules.len(),
+        references,
+        style(path.display()).cyan()
+    );
+    for column in &starter.skipped {
+        println!(
+            "  {} {} references its own table and is NOT NULL; it has no rule",
+            style("⚠").yellow(),
+            style(column).bold()
+        );
+    }
+    println!(
+        "  Edit the generators and counts, then seed with --config {}",
+        path.display()
+    );
+
+    Ok(())
+}
+
+/// Regenerate a dataset from a seed manifest
+pub async fn handle_seed_reproduce(
+    manifest_path: PathBuf,
+    container: Option<String>,
+    truncate: bool,
+) -> Result<()> {
+    let start = Instant::now();
+
+    let manifest =
+        SeedManifest::load(&manifest_path).map_err(|e| DBArenaError::ConfigError(e.to_string()))?;
+    manifest
+        .verify()
+        .map_err(|e| DBArenaError::InvalidConfig(e.to_string()))?;
+
+    println!(
+        "{} Reproducing dataset from manifest: {} (dbarena {}, generated {})",
+        style("▸").cyan(),
+        style(manifest_path.display()).green(),
+        manifest.generator_version,
+        manifest.created_at
+    );
+
+    if manifest.generator_version != env!("CARGO_PKG_VERSION") {
+        println!(
+            "{} Manifest was generated by dbarena {} (running {}); generated values may differ",
+            style("⚠").yellow(),
+            manifest.generator_version,
+            env!("CARGO_PKG_VERSION")
+        );
+    }
+
+    let mut config: SeedConfig = toml::from_str(&manifest.config).map_err(|e| {
+        DBArenaError::ConfigError(format!("Failed to parse manifest seed config: {}", e))
+    })?;
 
-    let manager = ContainerManager::new(docker_client.clone());
-    let container_info = manager
-        .find_container(&container)
-        .await?
-        .ok_or_else(|| DBArenaError::ContainerNotFound(container.clone()))?;
+    // Recorded counts already include presets and overrides from the original run
+    let counts = manifest.table_counts();
+    for rule in config.seed_rules.tables_mut() {
+        if let Some(&count) = counts.get(&rule.name) {
+            rule.count = count;
+        }
+    }
+    config.batch_size = manifest.batch_size;
+
+    let container = container.unwrap_or_else(|| manifest.container.clone());
+
+    run_seed(
+        &config,
+        &manifest.config,
+        Some(container.as_str()),
+        None,
+        Some(manifest.global_seed),
+        truncate,
+        None,
+        DEFAULT_CONCURRENCY,
+        manifest.parallel,
+        None,
+        None,
+        start,
+    )
+    .await
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn run_seed(
+    config: &SeedConfig,
+    config_content: &str,
+    container: Option<&str>,
+    url: Option<&str>,
+    seed: Option<u64>,
+    truncate: bool,
+    manifest_path: Option<&Path>,
+    concurrency: usize,
+    parallel: usize,
+    dry_run: Option<usize>,
+    profile: Option<&str>,
+    start: Instant,
+) -> Result<()> {
+    let target = Target::resolve(container, url, profile).await?;
+    let db_type = target.params.db_type;
+
+    if !db_type.is_sql() {
+        return Err(DBArenaError::InvalidConfig(format!(
+            "Seeding is not supported for NoSQL databases ({})",
+            db_type
+        )));
+    }
 
     println!(
-        "{} Seeding container: {} ({})",
+        "{} {} {}: {} ({})",
         style("▸").cyan(),
-        style(&container_info.name).green(),
-        container_info.database_type
+        if dry_run.is_some() { "Dry run against" } else { "Seeding" },
+        target.kind(),
+        style(&target.name).green(),
+        db_type
     );
 
     // Truncate tables if requested
-    if truncate {
+    if truncate && dry_run.is_some() {
+        println!("{} Would truncate tables before seeding", style("▸").cyan());
+    } else if truncate {
         println!("{} Truncating tables...", style("▸").cyan());
-        truncate_tables(&manager, &container_info.id, &config)
+        truncate_tables(&target, config)
             .await
             .map_err(|e| DBArenaError::Other(e.to_string()))?;
     }
@@ -83,21 +381,20 @@ pub async fn handle_seed(
         style(seed_value).yellow()
     );
 
-    let db_type = DatabaseType::from_string(&container_info.database_type).ok_or_else(|| {
-        DBArenaError::InvalidConfig(format!(
-            "Unknown database type: {}",
-            container_info.database_type
-        ))
-    })?;
-
-    let docker = Arc::new(docker_client.docker().clone());
+    let docker = Arc::new(target.docker.docker().clone());
     let mut engine = SeedingEngine::new(
-        container_info.id.clone(),
+        target.container_id().to_string(),
         db_type,
         docker,
         seed_value,
         config.batch_size,
-    );
+    )
+    .with_concurrency(concurrency)
+    .with_parallel(parallel)
+    .with_connection(target.params.clone());
+    if let Some(preview) = dry_run {
+        engine = engine.with_dry_run(preview);
+    }
 
     // Seed all tables
     let rules: Vec<_> = config.seed_rules.tables().to_vec();
@@ -106,9 +403,41 @@ pub async fn handle_seed(
         .await
         .map_err(|e| DBArenaError::Other(e.to_string()))?;
 
+    // Nothing was written, so there is no dataset to record
+    if dry_run.is_some() {
+        print_dry_run(&engine, &stats);
+        return Ok(());
+    }
+
+    // New rows make cached row count estimates stale
+    if let Some(cache) = MetadataCache::for_target(target.container_id(), &target.params) {
+        cache.invalidate();
+    }
+
     // Print summary
     print_summary(&stats, start.elapsed());
 
+    // Record how the dataset was generated
+    if let Some(path) = manifest_path {
+        let manifest = SeedManifest::new(
+            config_content,
+            seed_value,
+            config.batch_size,
+            db_type.as_str(),
+            &target.name,
+            &rules,
+        )
+        .with_parallel(parallel);
+        manifest
+            .save(path)
+            .map_err(|e| DBArenaError::Other(e.to_string()))?;
+        println!(
+            "{} Wrote seed manifest: {}",
+            style("✓").green(),
+            style(path.display()).cyan()
+        );
+    }
+
     Ok(())
 }
 
@@ -152,8 +481,7 @@ fn apply_row_overrides(
 }
 
 async fn truncate_tables(
-    _manager: &ContainerManager,
-    _container_id: &str,
+    _target: &Target,
     config: &SeedConfig,
 ) -> std::result::Result<(), anyhow::Error> {
     for rule in config.seed_rules.tables() {
@@ -164,6 +492,39 @@ async fn truncate_tables(
     Ok(())
 }
 
+fn print_dry_run(engine: &SeedingEngine, stats: &[SeedStats]) {
+    println!();
+    println!("{}", style("═".repeat(70)).dim());
+    println!("{}", style("Dry Run (nothing was written)").yellow().bold());
+    println!("{}", style("═".repeat(70)).dim());
+
+    let mut total_rows = 0;
+    for stat in stats {
+        total_rows += stat.rows_inserted;
+        println!();
+        println!(
+            "  {} {}: {} rows planned",
+            style("▸").cyan(),
+            style(&stat.table).cyan(),
+            style(stat.rows_inserted).yellow()
+        );
+        for statement in engine.dry_run_statements(&stat.table) {
+            println!("    {}", statement);
+        }
+    }
+
+    println!();
+    println!("{}", style("─".repeat(70)).dim());
+    println!(
+        "  {} Total: {} rows planned across {} tables",
+        style("Σ").cyan().bold(),
+        style(total_rows).green().bold(),
+        stats.len()
+    );
+    println!("{}", style("═".repeat(70)).dim());
+    println!();
+}
+
 fn print_summary(stats: &[SeedStats], total_duration: std::time::Duration) {
     println!();
     println!("{}", style("═".repeat(70)).dim());
@@ -172,16 +533,24 @@ fn print_summary(stats: &[SeedStats], total_duration: std::time::Duration) {
     println!();
 
     let mut total_rows = 0;
+    summary::record("tables", stats.len());
 
     for stat in stats {
         total_rows += stat.rows_inserted;
+        summary::record("rows", stat.rows_inserted);
+        let workers = if stat.workers > 1 {
+            format!(" across {} workers", stat.workers)
+        } else {
+            String::new()
+        };
         println!(
-            "  {} {}: {} rows in {:.2}s ({:.0} rows/sec)",
+            "  {} {}: {} rows in {:.2}s ({:.0} rows/sec{})",
             style("✓").green(),
             style(&stat.table).cyan(),
             style(stat.rows_inserted).yellow(),
             stat.duration.as_secs_f64(),
-            stat.rows_per_second
+            stat.rows_per_second,
+            workers
         );
     }
 
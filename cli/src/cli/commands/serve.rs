@@ -0,0 +1,28 @@
+use crate::server;
+use crate::Result;
+use console::style;
+use std::net::{IpAddr, SocketAddr};
+
+pub async fn handle_serve(bind: IpAddr, port: u16) -> Result<()> {
+    let addr = SocketAddr::new(bind, port);
+
+    if !bind.is_loopback() {
+        println!(
+            "{} Binding to {} exposes container control to the network (no authentication)",
+            style("⚠").yellow(),
+            style(bind).bold()
+        );
+    }
+
+    println!(
+        "{} dbarena API listening on {}",
+        style("→").cyan(),
+        style(format!("http://{}/api/v1", addr)).bold()
+    );
+    println!("  Press Ctrl+C to stop");
+
+    server::serve(addr).await?;
+
+    println!("\n{}", style("Server stopped").dim());
+    Ok(())
+}
@@ -1,8 +1,11 @@
+use std::path::PathBuf;
 use std::sync::Arc;
-use bollard::Docker;
 use console::style;
 
+use crate::cli::output::OutputFormat;
+use crate::container::DockerClient;
 use crate::error::{DBArenaError, Result};
+use crate::snapshot::diff::{format_size, format_span};
 use crate::snapshot::SnapshotManager;
 
 /// Handle snapshot create command
@@ -10,9 +13,10 @@ pub async fn handle_snapshot_create(
     container: String,
     name: String,
     message: Option<String>,
+    base: Option<String>,
+    pause: bool,
 ) -> Result<()> {
-    let docker = Docker::connect_with_local_defaults()
-        .map_err(|_| DBArenaError::DockerNotAvailable)?;
+    let docker = DockerClient::new()?.docker().clone();
     let manager = SnapshotManager::new(Arc::new(docker));
 
     println!(
@@ -21,8 +25,9 @@ pub async fn handle_snapshot_create(
         style(&container).bold()
     );
 
-    // Create the snapshot (auto_pause = true by default)
-    let snapshot = manager.create(&container, name, message, true).await?;
+    let snapshot = manager
+        .create(&container, name, message, base.as_deref(), pause)
+        .await?;
 
     println!("  {} Snapshot created successfully", style("✓").green());
     println!();
@@ -30,9 +35,15 @@ pub async fn handle_snapshot_create(
     println!("  Name:     {}", snapshot.name);
     println!("  Image:    {}", snapshot.image_tag);
     println!("  Database: {}", snapshot.database_type);
+    if let Some(state) = snapshot.state {
+        println!("  State:    {}", state);
+    }
     if let Some(msg) = &snapshot.message {
         println!("  Message:  {}", msg);
     }
+    if let Some(base) = &base {
+        println!("  Base:     {}", base);
+    }
     println!(
         "  Created:  {}",
         chrono::DateTime::from_timestamp(snapshot.created_at, 0)
@@ -44,42 +55,57 @@ pub async fn handle_snapshot_create(
 }
 
 /// Handle snapshot list command
-pub async fn handle_snapshot_list(json: bool) -> Result<()> {
-    let docker = Docker::connect_with_local_defaults()
-        .map_err(|_| DBArenaError::DockerNotAvailable)?;
+pub async fn handle_snapshot_list(format: OutputFormat) -> Result<()> {
+    let docker = DockerClient::new()?.docker().clone();
     let manager = SnapshotManager::new(Arc::new(docker));
 
     let snapshots = manager.list().await?;
 
     if snapshots.is_empty() {
-        if !json {
+        if !format.is_structured() {
             println!("{}", style("No snapshots found.").yellow());
         } else {
-            println!("[]");
+            format.print(&snapshots)?;
         }
         return Ok(());
     }
 
-    if json {
-        println!("{}", serde_json::to_string_pretty(&snapshots)?);
+    if format.is_structured() {
+        format.print(&snapshots)?;
     } else {
         println!(
-            "{:<20} {:<30} {:<15} {:<20}",
-            "NAME", "ID", "DATABASE", "CREATED"
+            "{:<20} {:<30} {:<15} {:<20} {:<20} {:<10}",
+            "NAME", "ID", "DATABASE", "CREATED", "PARENT", "SIZE"
         );
-        println!("{}", "─".repeat(85));
+        println!("{}", "─".repeat(120));
 
-        for snapshot in snapshots {
+        for snapshot in &snapshots {
             let created = chrono::DateTime::from_timestamp(snapshot.created_at, 0)
                 .map(|dt| dt.format("%Y-%m-%d %H:%M:%S").to_string())
                 .unwrap_or_else(|| "Unknown".to_string());
+            let parent = match &snapshot.parent {
+                Some(parent_id) => snapshots
+                    .iter()
+                    .find(|s| &s.id == parent_id)
+                    .map(|parent| parent.name.clone())
+                    .unwrap_or_else(|| "(missing)".to_string()),
+                None => "-".to_string(),
+            };
+            // Layered snapshots show the delta over their base
+            let size = match manager.delta_size(snapshot, &snapshots).await {
+                Ok(Some(size)) if snapshot.parent.is_some() => format!("+{}", format_size(size)),
+                Ok(Some(size)) => format_size(size),
+                _ => "-".to_string(),
+            };
 
             println!(
-                "{:<20} {:<30} {:<15} {:<20}",
+                "{:<20} {:<30} {:<15} {:<20} {:<20} {:<10}",
                 truncate_string(&snapshot.name, 20),
                 truncate_string(&snapshot.id, 30),
                 snapshot.database_type,
-                created
+                created,
+                truncate_string(&parent, 20),
+                size
             );
         }
     }
@@ -93,8 +119,7 @@ pub async fn handle_snapshot_restore(
     name: Option<String>,
     port: Option<u16>,
 ) -> Result<()> {
-    let docker = Docker::connect_with_local_defaults()
-        .map_err(|_| DBArenaError::DockerNotAvailable)?;
+    let docker = DockerClient::new()?.docker().clone();
     let manager = SnapshotManager::new(Arc::new(docker));
 
     println!(
@@ -125,8 +150,7 @@ pub async fn handle_snapshot_restore(
 
 /// Handle snapshot delete command
 pub async fn handle_snapshot_delete(snapshot: String, yes: bool) -> Result<()> {
-    let docker = Docker::connect_with_local_defaults()
-        .map_err(|_| DBArenaError::DockerNotAvailable)?;
+    let docker = DockerClient::new()?.docker().clone();
     let manager = SnapshotManager::new(Arc::new(docker));
 
     // Get snapshot info first
@@ -162,15 +186,14 @@ pub async fn handle_snapshot_delete(snapshot: String, yes: bool) -> Result<()> {
 }
 
 /// Handle snapshot inspect command
-pub async fn handle_snapshot_inspect(snapshot: String, json: bool) -> Result<()> {
-    let docker = Docker::connect_with_local_defaults()
-        .map_err(|_| DBArenaError::DockerNotAvailable)?;
+pub async fn handle_snapshot_inspect(snapshot: String, format: OutputFormat) -> Result<()> {
+    let docker = DockerClient::new()?.docker().clone();
     let manager = SnapshotManager::new(Arc::new(docker));
 
     let snap = manager.inspect(&snapshot).await?;
 
-    if json {
-        println!("{}", serde_json::to_string_pretty(&snap)?);
+    if format.is_structured() {
+        format.print(&snap)?;
     } else {
         println!("{}", style("Snapshot Details").cyan().bold());
         println!("{}", "=".repeat(50));
@@ -189,6 +212,135 @@ pub async fn handle_snapshot_inspect(snapshot: String, json: bool) -> Result<()>
         if let Some(msg) = &snap.message {
             println!("  Message:          {}", msg);
         }
+        if let Some(parent) = &snap.parent {
+            println!("  Base Snapshot:    {}", parent);
+        }
+        println!(
+            "  State at Commit:  {}",
+            snap.state
+                .map(|state| state.to_string())
+                .unwrap_or_else(|| "unknown".to_string())
+        );
+    }
+
+    Ok(())
+}
+
+/// Handle snapshot diff command
+pub async fn handle_snapshot_diff(a: String, b: String, format: OutputFormat) -> Result<()> {
+    let docker = DockerClient::new()?.docker().clone();
+    let manager = SnapshotManager::new(Arc::new(docker));
+
+    let diff = manager.diff(&a, &b).await?;
+
+    if !diff.comparable {
+        eprintln!(
+            "{} Snapshots are from different databases ({} vs {}); they aren't comparable",
+            style("⚠").yellow(),
+            diff.a.database_type,
+            diff.b.database_type
+        );
+    }
+
+    if format.is_structured() {
+        format.print(&diff)?;
+        return Ok(());
+    }
+
+    println!(
+        "{:<10} {:<30} {:<30}",
+        "",
+        truncate_string(&diff.a.name, 30),
+        truncate_string(&diff.b.name, 30)
+    );
+    println!("{}", "─".repeat(72));
+    for field in &diff.fields {
+        let line = format!(
+            "{:<10} {:<30} {:<30}",
+            field.field,
+            truncate_string(&field.a, 30),
+            truncate_string(&field.b, 30)
+        );
+        if field.changed {
+            println!("{}", style(line).yellow());
+        } else {
+            println!("{}", line);
+        }
+    }
+
+    println!();
+    if let Some(delta) = diff.size_delta {
+        let sign = if delta >= 0 { "+" } else { "-" };
+        println!("  Size change:  {}{}", sign, format_size(delta.abs()));
+    }
+    println!("  Time between: {}", format_span(diff.created_delta_secs));
+
+    Ok(())
+}
+
+/// Handle snapshot export command
+pub async fn handle_snapshot_export(snapshot: String, path: PathBuf) -> Result<()> {
+    let docker = DockerClient::new()?.docker().clone();
+    let manager = SnapshotManager::new(Arc::new(docker));
+
+    println!(
+        "{} Exporting snapshot {} to {}...",
+        style("→").cyan(),
+        style(&snapshot).bold(),
+        style(path.display()).bold()
+    );
+
+    let snap = manager.export(&snapshot, &path).await?;
+
+    println!("  {} Snapshot exported successfully", style("✓").green());
+    println!();
+    println!("  Name:     {}", snap.name);
+    println!("  ID:       {}", snap.id);
+    if let Ok(metadata) = std::fs::metadata(&path) {
+        println!("  Size:     {}", format_size(metadata.len() as i64));
+    }
+    if let Some(parent) = &snap.parent {
+        println!(
+            "  {} Layered on base snapshot {}; export it too to restore on another machine",
+            style("⚠").yellow(),
+            parent
+        );
+    }
+
+    Ok(())
+}
+
+/// Handle snapshot import command
+pub async fn handle_snapshot_import(path: PathBuf) -> Result<()> {
+    let docker = DockerClient::new()?.docker().clone();
+    let manager = SnapshotManager::new(Arc::new(docker));
+
+    println!(
+        "{} Importing snapshot from {}...",
+        style("→").cyan(),
+        style(path.display()).bold()
+    );
+
+    let snap = manager.import(&path).await?;
+
+    println!("  {} Snapshot imported successfully", style("✓").green());
+    println!();
+    println!("  ID:       {}", snap.id);
+    println!("  Name:     {}", snap.name);
+    println!("  Image:    {}", snap.image_tag);
+    println!("  Database: {}", snap.database_type);
+    if let Some(msg) = &snap.message {
+        println!("  Message:  {}", msg);
+    }
+
+    if let Some(parent) = &snap.parent {
+        if manager.get(parent).await.is_err() {
+            println!(
+                "  {} Layered on base snapshot {}, which isn't here; import it before restoring",
+                style("⚠").yellow(),
+                parent
+            );
+        }
     }
 
     Ok(())
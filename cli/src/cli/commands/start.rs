@@ -1,9 +1,8 @@
-use crate::cli::interactive;
+use crate::cli::{interactive, summary};
 use crate::container::models::ContainerStatus;
 use crate::container::{ContainerManager, DatabaseType, DockerClient};
-use crate::health::{
-    wait_for_healthy, MySQLHealthChecker, PostgresHealthChecker, SQLServerHealthChecker,
-};
+use crate::health::{checker_for, wait_for_healthy};
+use crate::progress::IndicatifProgress;
 use crate::{DBArenaError, Result};
 use console::style;
 use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
@@ -11,7 +10,12 @@ use std::time::Duration;
 
 const DEFAULT_HEALTH_TIMEOUT: Duration = Duration::from_secs(60);
 
-pub async fn handle_start(container: Option<String>, interactive_mode: bool, all: bool) -> Result<()> {
+pub async fn handle_start(
+    container: Option<String>,
+    interactive_mode: bool,
+    all: bool,
+    concurrency: usize,
+) -> Result<()> {
     let docker_client = DockerClient::new()?;
     docker_client.verify_connection().await?;
 
@@ -19,7 +23,7 @@ pub async fn handle_start(container: Option<String>, interactive_mode: bool, all
 
     // Handle --all flag
     if all {
-        return handle_start_all(&manager).await;
+        return handle_start_all(&manager, concurrency).await;
     }
 
     // Get container name
@@ -53,31 +57,28 @@ pub async fn handle_start(container: Option<String>, interactive_mode: bool, all
     );
 
     // Start the container
-    manager.start_container(&found.id).await?;
+    let id = manager.start_container(&found.id).await?;
     println!("  {} Container started", style("✓").green());
 
     // Wait for healthy
     let database = DatabaseType::from_string(&found.database_type).unwrap();
-    let checker: Box<dyn crate::health::HealthChecker> = match database {
-        DatabaseType::Postgres => Box::new(PostgresHealthChecker::new(
-            DockerClient::new()?.docker().clone(),
-        )),
-        DatabaseType::MySQL => Box::new(MySQLHealthChecker::new(
-            DockerClient::new()?.docker().clone(),
-        )),
-        DatabaseType::SQLServer => Box::new(SQLServerHealthChecker::new(
-            DockerClient::new()?.docker().clone(),
-        )),
-    };
+    let checker = checker_for(database, DockerClient::new()?.docker().clone());
 
-    wait_for_healthy(&found.id, checker.as_ref(), DEFAULT_HEALTH_TIMEOUT).await?;
+    wait_for_healthy(
+        &id,
+        checker.as_ref(),
+        DEFAULT_HEALTH_TIMEOUT,
+        &IndicatifProgress::new(),
+    )
+    .await?;
 
     println!("\n{}", style("Container is ready!").green().bold());
+    summary::record("started", 1);
 
     Ok(())
 }
 
-async fn handle_start_all(manager: &ContainerManager) -> Result<()> {
+async fn handle_start_all(manager: &ContainerManager, concurrency: usize) -> Result<()> {
     // Get all stopped containers
     let all_containers = manager.list_containers(true).await?;
     let stopped_containers: Vec<_> = all_containers
@@ -119,7 +120,9 @@ async fn handle_start_all(manager: &ContainerManager) -> Result<()> {
     let container_ids: Vec<String> = stopped_containers.iter().map(|c| c.id.clone()).collect();
 
     // Start containers in parallel
-    let results = manager.start_containers_parallel(container_ids).await;
+    let results = manager
+        .start_containers_parallel(container_ids, concurrency)
+        .await;
 
     // Update progress bars based on results
     let mut success_count = 0;
@@ -147,6 +150,9 @@ async fn handle_start_all(manager: &ContainerManager) -> Result<()> {
         }
     }
 
+    summary::record("started", success_count);
+    summary::record("failed", failed_count);
+
     println!();
     println!(
         "{}",
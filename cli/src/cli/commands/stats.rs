@@ -1,16 +1,30 @@
+use std::collections::HashMap;
+use std::io::Write;
+use std::net::SocketAddr;
 use std::sync::Arc;
 use bollard::Docker;
 use tokio::time::{sleep, Duration};
 
+use crate::cli::output::OutputFormat;
 use crate::container::{ContainerManager, DockerClient};
 use crate::container::models::ContainerStatus;
 use crate::error::Result;
-use crate::monitoring::{DockerStatsCollector, MetricsCollector, StatsTui};
+use crate::monitoring::{
+    AlertThresholds, ContainerMetrics, DockerStatsCollector, MetricsCollector, StatsTui,
+};
 use crate::monitoring::{display_compact_header, display_metrics_compact, display_metrics_simple};
+use crate::monitoring::exporter::run_exporter;
 use crate::database_metrics::DockerDatabaseMetricsCollector;
 use crate::config::schema::MonitoringConfig;
 
+/// Prometheus scrapes every 15s by default; collecting faster only adds load
+const EXPORTER_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Gap between the two samples `--once` diffs to compute rates
+const ONCE_SAMPLE_INTERVAL: Duration = Duration::from_secs(1);
+
 /// Handle the stats command
+#[allow(clippy::too_many_arguments)]
 pub async fn handle_stats(
     docker: Arc<Docker>,
     container: Option<String>,
@@ -18,27 +32,67 @@ pub async fn handle_stats(
     tui: bool,
     multipane: bool,
     all: bool,
-    json: bool,
+    format: OutputFormat,
+    once: bool,
+    prometheus: bool,
+    port: u16,
+    alerts: AlertThresholds,
 ) -> Result<()> {
     let collector = DockerStatsCollector::new(docker.clone());
 
+    // Exporter mode covers every running container
+    if prometheus {
+        let db_collector = DockerDatabaseMetricsCollector::new(docker.clone());
+        let manager = ContainerManager::new(DockerClient::new()?);
+        let addr = SocketAddr::from(([0, 0, 0, 0], port));
+
+        println!(
+            "{} Prometheus metrics at {}",
+            console::style("→").cyan(),
+            console::style(format!("http://{}/metrics", addr)).bold()
+        );
+        println!("  Press Ctrl+C to stop");
+
+        return run_exporter(&collector, &db_collector, &manager, addr, EXPORTER_INTERVAL).await;
+    }
+
     // Use default monitoring config interval (500ms for responsive updates)
     // TODO: Load from config file when --config flag is added to stats command
     let monitoring_config = MonitoringConfig::default();
     let interval_ms = monitoring_config.interval_ms;
 
+    // Streaming JSON Lines or YAML documents takes precedence over any TUI
+    if follow && format.is_structured() {
+        let container_id = if all {
+            None
+        } else {
+            Some(get_container_id(container).await?)
+        };
+        return stream_samples(&collector, container_id.as_deref(), interval_ms, format).await;
+    }
+
+    if once {
+        let container_id = if all {
+            None
+        } else {
+            Some(get_container_id(container).await?)
+        };
+        return print_once(&collector, container_id.as_deref(), format).await;
+    }
+
     // Multi-pane mode
     if multipane {
         let db_collector = DockerDatabaseMetricsCollector::new(docker.clone());
-        let mut tui_app = StatsTui::new(interval_ms)?;
+        let mut tui_app = StatsTui::new(interval_ms)?.with_alerts(alerts);
         return tui_app.run_multipane(&collector, &db_collector, container).await;
     }
 
     if all {
-        handle_stats_all(&collector, tui, json, interval_ms).await
+        handle_stats_all(&collector, tui, format, interval_ms, alerts).await
     } else {
         let container_id = get_container_id(container).await?;
-        handle_stats_single(&collector, &container_id, follow, tui, json, interval_ms).await
+        handle_stats_single(&collector, &container_id, follow, tui, format, interval_ms, alerts)
+            .await
     }
 }
 
@@ -88,12 +142,13 @@ async fn handle_stats_single(
     container_id: &str,
     follow: bool,
     tui: bool,
-    json: bool,
+    format: OutputFormat,
     interval_ms: u64,
+    alerts: AlertThresholds,
 ) -> Result<()> {
     if tui {
         // Launch TUI with configurable interval in milliseconds
-        let mut tui_app = StatsTui::new(interval_ms)?;
+        let mut tui_app = StatsTui::new(interval_ms)?.with_alerts(alerts);
         tui_app.run_single(collector, container_id).await?;
     } else if follow {
         // Continuous text output
@@ -108,11 +163,7 @@ async fn handle_stats_single(
 
             // Clear screen and display
             print!("\x1B[2J\x1B[1;1H"); // ANSI escape codes to clear screen
-            if json {
-                println!("{}", serde_json::to_string_pretty(&metrics)?);
-            } else {
-                display_metrics_simple(&metrics);
-            }
+            display_metrics_simple(&metrics);
 
             previous_metrics = Some(metrics);
             sleep(Duration::from_millis(interval_ms)).await;
@@ -120,8 +171,8 @@ async fn handle_stats_single(
     } else {
         // One-time output
         let metrics = collector.collect(container_id).await?;
-        if json {
-            println!("{}", serde_json::to_string_pretty(&metrics)?);
+        if format.is_structured() {
+            format.print(&metrics)?;
         } else {
             display_metrics_simple(&metrics);
         }
@@ -134,19 +185,21 @@ async fn handle_stats_single(
 async fn handle_stats_all(
     collector: &DockerStatsCollector,
     tui: bool,
-    json: bool,
+    format: OutputFormat,
     interval_ms: u64,
+    alerts: AlertThresholds,
 ) -> Result<()> {
     if tui {
         // Launch multi-container TUI dashboard
-        let mut tui_app = StatsTui::new(interval_ms)?;
-        tui_app.run_multi(collector).await?;
+        let manager = ContainerManager::new(DockerClient::new()?);
+        let mut tui_app = StatsTui::new(interval_ms)?.with_alerts(alerts);
+        tui_app.run_multi(collector, &manager).await?;
         return Ok(());
     }
 
-    if json {
+    if format.is_structured() {
         let metrics = collector.collect_all().await?;
-        println!("{}", serde_json::to_string_pretty(&metrics)?);
+        format.print(&metrics)?;
     } else {
         let metrics = collector.collect_all().await?;
         display_compact_header();
@@ -157,3 +210,94 @@ async fn handle_stats_all(
 
     Ok(())
 }
+
+/// One container's metrics, or every running container's when `container_id` is None
+async fn collect_samples(
+    collector: &DockerStatsCollector,
+    container_id: Option<&str>,
+) -> Result<Vec<ContainerMetrics>> {
+    match container_id {
+        Some(id) => Ok(vec![collector.collect(id).await?]),
+        None => collector.collect_all().await,
+    }
+}
+
+/// Print one snapshot whose rates come from two samples `ONCE_SAMPLE_INTERVAL` apart
+///
+/// A single sample has nothing to diff against, so its rates would all be zero.
+async fn print_once(
+    collector: &DockerStatsCollector,
+    container_id: Option<&str>,
+    format: OutputFormat,
+) -> Result<()> {
+    let first: HashMap<String, ContainerMetrics> = collect_samples(collector, container_id)
+        .await?
+        .into_iter()
+        .map(|m| (m.container_id.clone(), m))
+        .collect();
+    sleep(ONCE_SAMPLE_INTERVAL).await;
+
+    let mut metrics = collect_samples(collector, container_id).await?;
+    for m in &mut metrics {
+        if let Some(prev) = first.get(&m.container_id) {
+            m.calculate_rates(prev);
+        }
+    }
+
+    match (container_id, format.is_structured()) {
+        (Some(_), true) => format.print(&metrics[0])?,
+        (None, true) => format.print(&metrics)?,
+        (Some(_), false) => display_metrics_simple(&metrics[0]),
+        (None, false) => {
+            display_compact_header();
+            for m in &metrics {
+                display_metrics_compact(m);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Stream metrics as JSON Lines, or YAML documents separated by `---`: one per
+/// container per interval
+///
+/// Streams a single container when `container_id` is set, otherwise every
+/// running container. Stops quietly when the reader goes away (e.g. `| head`).
+async fn stream_samples(
+    collector: &DockerStatsCollector,
+    container_id: Option<&str>,
+    interval_ms: u64,
+    format: OutputFormat,
+) -> Result<()> {
+    let mut previous: HashMap<String, ContainerMetrics> = HashMap::new();
+
+    loop {
+        let samples = collect_samples(collector, container_id).await?;
+
+        // Scoped so the stdout lock is released before the next await
+        {
+            let mut stdout = std::io::stdout().lock();
+            for mut metrics in samples {
+                if let Some(prev) = previous.get(&metrics.container_id) {
+                    metrics.calculate_rates(prev);
+                }
+
+                let sample = match format {
+                    OutputFormat::Yaml => format!("---\n{}", format.render(&metrics)?),
+                    _ => serde_json::to_string(&metrics)?,
+                };
+                if let Err(e) = writeln!(stdout, "{}", sample).and_then(|_| stdout.flush()) {
+                    if e.kind() == std::io::ErrorKind::BrokenPipe {
+                        return Ok(());
+                    }
+                    return Err(e.into());
+                }
+
+                previous.insert(metrics.container_id.clone(), metrics);
+            }
+        }
+
+        sleep(Duration::from_millis(interval_ms)).await;
+    }
+}
@@ -1,4 +1,4 @@
-use crate::cli::interactive;
+use crate::cli::{interactive, summary};
 use crate::container::models::ContainerStatus;
 use crate::container::{ContainerManager, DockerClient};
 use crate::{DBArenaError, Result};
@@ -11,6 +11,7 @@ pub async fn handle_stop(
     interactive_mode: bool,
     all: bool,
     timeout: u64,
+    concurrency: usize,
 ) -> Result<()> {
     let docker_client = DockerClient::new()?;
     docker_client.verify_connection().await?;
@@ -19,7 +20,7 @@ pub async fn handle_stop(
 
     // Handle --all flag
     if all {
-        return handle_stop_all(&manager, timeout).await;
+        return handle_stop_all(&manager, timeout, concurrency).await;
     }
 
     // Get container name
@@ -60,11 +61,16 @@ pub async fn handle_stop(
     // Stop the container
     manager.stop_container(&found.id, Some(timeout)).await?;
     println!("{} Container stopped", style("✓").green());
+    summary::record("stopped", 1);
 
     Ok(())
 }
 
-async fn handle_stop_all(manager: &ContainerManager, timeout: u64) -> Result<()> {
+async fn handle_stop_all(
+    manager: &ContainerManager,
+    timeout: u64,
+    concurrency: usize,
+) -> Result<()> {
     // Get all running containers
     let all_containers = manager.list_containers(false).await?;
     let running_containers: Vec<_> = all_containers
@@ -106,7 +112,9 @@ async fn handle_stop_all(manager: &ContainerManager, timeout: u64) -> Result<()>
     let container_ids: Vec<String> = running_containers.iter().map(|c| c.id.clone()).collect();
 
     // Stop containers in parallel
-    let results = manager.stop_containers_parallel(container_ids, timeout).await;
+    let results = manager
+        .stop_containers_parallel(container_ids, timeout, concurrency)
+        .await;
 
     // Update progress bars based on results
     let mut success_count = 0;
@@ -134,6 +142,9 @@ async fn handle_stop_all(manager: &ContainerManager, timeout: u64) -> Result<()>
         }
     }
 
+    summary::record("stopped", success_count);
+    summary::record("failed", failed_count);
+
     println!();
     println!(
         "{}",
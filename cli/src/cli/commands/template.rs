@@ -1,3 +1,4 @@
+use crate::cli::output::OutputFormat;
 use crate::config::{Template, TemplateManager};
 use crate::container::{ContainerConfig, ContainerManager, DockerClient, VolumeMount, VolumeMountType};
 use crate::{DBArenaError, Result};
@@ -71,6 +72,7 @@ pub async fn handle_template_save(
         name: Some(found.name.clone()),
         database: db_type,
         version: found.version.clone(),
+        image: None,
         port: host_port,
         persistent,
         memory_limit,
@@ -78,7 +80,18 @@ pub async fn handle_template_save(
         env_vars,
         init_scripts: init_scripts.iter().map(PathBuf::from).collect(),
         continue_on_error: false,
+        atomic_init: false,
         volumes,
+        user: None,
+        workdir: None,
+        cap_add: Vec::new(),
+        cap_drop: Vec::new(),
+        security_opt: Vec::new(),
+        ulimits: Vec::new(),
+        tmpfs: HashMap::new(),
+        command: None,
+        command_args: Vec::new(),
+        labels: HashMap::new(),
     };
 
     let mut template = Template::from_container_config(name.clone(), description.clone(), &config);
@@ -98,16 +111,12 @@ pub async fn handle_template_save(
     Ok(())
 }
 
-pub async fn handle_template_list(json: bool) -> Result<()> {
+pub async fn handle_template_list(format: OutputFormat) -> Result<()> {
     let template_manager = TemplateManager::new()?;
     let templates = template_manager.list()?;
 
-    if json {
-        println!(
-            "{}",
-            serde_json::to_string_pretty(&templates)
-                .map_err(|e| DBArenaError::Other(format!("JSON serialization failed: {}", e)))?
-        );
+    if format.is_structured() {
+        format.print(&templates)?;
         return Ok(());
     }
 
@@ -212,16 +221,12 @@ pub async fn handle_template_import(path: PathBuf) -> Result<()> {
     Ok(())
 }
 
-pub async fn handle_template_inspect(name: String, json: bool) -> Result<()> {
+pub async fn handle_template_inspect(name: String, format: OutputFormat) -> Result<()> {
     let template_manager = TemplateManager::new()?;
     let template = template_manager.load(&name)?;
 
-    if json {
-        println!(
-            "{}",
-            serde_json::to_string_pretty(&template)
-                .map_err(|e| DBArenaError::Other(format!("JSON serialization failed: {}", e)))?
-        );
+    if format.is_structured() {
+        format.print(&template)?;
         return Ok(());
     }
 
@@ -1,17 +1,16 @@
 use std::sync::Arc;
-use bollard::Docker;
 use console::style;
 
+use crate::cli::output::OutputFormat;
 use crate::error::{DBArenaError, Result};
-use crate::container::{VolumeManager, VolumeConfig};
+use crate::container::{DockerClient, VolumeManager, VolumeConfig};
 
 /// Handle volume create command
 pub async fn handle_volume_create(
     name: String,
     mount_path: Option<String>,
 ) -> Result<()> {
-    let docker = Docker::connect_with_local_defaults()
-        .map_err(|_| DBArenaError::DockerNotAvailable)?;
+    let docker = DockerClient::new()?.docker().clone();
     let manager = VolumeManager::new(Arc::new(docker));
 
     let config = VolumeConfig::new(
@@ -35,28 +34,27 @@ pub async fn handle_volume_create(
 }
 
 /// Handle volume list command
-pub async fn handle_volume_list(all: bool, json: bool) -> Result<()> {
-    let docker = Docker::connect_with_local_defaults()
-        .map_err(|_| DBArenaError::DockerNotAvailable)?;
+pub async fn handle_volume_list(all: bool, format: OutputFormat) -> Result<()> {
+    let docker = DockerClient::new()?.docker().clone();
     let manager = VolumeManager::new(Arc::new(docker));
 
     let volumes = manager.list(!all).await?; // If not --all, show only managed
 
     if volumes.is_empty() {
-        if !json {
+        if !format.is_structured() {
             if all {
                 println!("{}", style("No volumes found.").yellow());
             } else {
                 println!("{}", style("No dbarena-managed volumes found. Use --all to see all volumes.").yellow());
             }
         } else {
-            println!("[]");
+            format.print(&volumes)?;
         }
         return Ok(());
     }
 
-    if json {
-        println!("{}", serde_json::to_string_pretty(&volumes)?);
+    if format.is_structured() {
+        format.print(&volumes)?;
     } else {
         println!(
             "{:<30} {:<15} {:<50}",
@@ -79,8 +77,7 @@ pub async fn handle_volume_list(all: bool, json: bool) -> Result<()> {
 
 /// Handle volume delete command
 pub async fn handle_volume_delete(name: String, force: bool, yes: bool) -> Result<()> {
-    let docker = Docker::connect_with_local_defaults()
-        .map_err(|_| DBArenaError::DockerNotAvailable)?;
+    let docker = DockerClient::new()?.docker().clone();
     let manager = VolumeManager::new(Arc::new(docker));
 
     if !yes {
@@ -113,15 +110,14 @@ pub async fn handle_volume_delete(name: String, force: bool, yes: bool) -> Resul
 }
 
 /// Handle volume inspect command
-pub async fn handle_volume_inspect(name: String, json: bool) -> Result<()> {
-    let docker = Docker::connect_with_local_defaults()
-        .map_err(|_| DBArenaError::DockerNotAvailable)?;
+pub async fn handle_volume_inspect(name: String, format: OutputFormat) -> Result<()> {
+    let docker = DockerClient::new()?.docker().clone();
     let manager = VolumeManager::new(Arc::new(docker));
 
     let details = manager.inspect(&name).await?;
 
-    if json {
-        println!("{}", serde_json::to_string_pretty(&details)?);
+    if format.is_structured() {
+        format.print(&details)?;
     } else {
         println!("{}", style("Volume Details").cyan().bold());
         println!("{}", "=".repeat(50));
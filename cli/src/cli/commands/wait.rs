@@ -0,0 +1,237 @@
+use crate::container::{Container, ContainerManager, DatabaseType, DockerClient};
+use crate::health::{check_once, checker_for, HealthChecker, HealthStatus};
+use crate::{DBArenaError, Result};
+use console::style;
+use futures::future::join_all;
+use indicatif::{ProgressBar, ProgressStyle};
+use std::time::{Duration, Instant};
+
+#[cfg(unix)]
+use crate::daemon::DaemonClient;
+
+/// Stand-in on platforms without the daemon; never constructed
+#[cfg(not(unix))]
+enum DaemonClient {}
+
+/// Upper bound on a single health probe
+const PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Block until the containers are healthy (or, with `any`, until one is)
+///
+/// Fails with `HealthCheckTimeout` if that doesn't happen within `timeout`
+/// seconds. With `quiet`, nothing is printed. Probes go through the daemon
+/// when one is running for the same Docker endpoint.
+pub async fn handle_wait(
+    containers: Vec<String>,
+    timeout: u64,
+    interval_ms: u64,
+    any: bool,
+    quiet: bool,
+) -> Result<()> {
+    if containers.is_empty() {
+        return Err(DBArenaError::InvalidConfig(
+            "At least one container name or ID is required".to_string(),
+        ));
+    }
+
+    let docker_client = DockerClient::new()?;
+    let mut daemon = connect_daemon(docker_client.endpoint()).await;
+
+    let resolved = match daemon.as_mut() {
+        Some(client) => match probe_via_daemon(client, &containers).await {
+            Ok(states) => Some(states),
+            Err(e) => {
+                tracing::debug!("Daemon probe failed, falling back to Docker: {}", e);
+                None
+            }
+        },
+        None => None,
+    };
+    let resolved: Vec<Container> = match resolved {
+        Some(states) => {
+            let found: Vec<Container> = states.into_iter().map(|(c, _)| c).collect();
+            let mut resolved = Vec::with_capacity(containers.len());
+            for name in &containers {
+                let container = found
+                    .iter()
+                    .find(|c| c.name == *name || c.id.starts_with(name.as_str()))
+                    .ok_or_else(|| DBArenaError::ContainerNotFound(name.clone()))?;
+                resolved.push(container.clone());
+            }
+            resolved
+        }
+        None => {
+            daemon = None;
+            docker_client.verify_connection().await?;
+            let manager = ContainerManager::new(docker_client.clone());
+            let mut resolved = Vec::with_capacity(containers.len());
+            for name in &containers {
+                resolved.push(
+                    manager
+                        .find_container(name)
+                        .await?
+                        .ok_or_else(|| DBArenaError::ContainerNotFound(name.clone()))?,
+                );
+            }
+            resolved
+        }
+    };
+
+    let mut pending: Vec<(Container, Box<dyn HealthChecker>)> = Vec::new();
+    for container in resolved {
+        let database = DatabaseType::from_string(&container.database_type).ok_or_else(|| {
+            DBArenaError::InvalidConfig(format!(
+                "Unknown database type: {}",
+                container.database_type
+            ))
+        })?;
+        let checker = checker_for(database, docker_client.docker().clone());
+        pending.push((container, checker));
+    }
+
+    let pb = if quiet {
+        ProgressBar::hidden()
+    } else {
+        let pb = ProgressBar::new_spinner();
+        pb.set_style(
+            ProgressStyle::default_spinner()
+                .template("{spinner:.green} {msg}")
+                .unwrap(),
+        );
+        pb.enable_steady_tick(Duration::from_millis(100));
+        pb
+    };
+
+    let total = pending.len();
+    let timeout = Duration::from_secs(timeout);
+    let interval = Duration::from_millis(interval_ms);
+    let start = Instant::now();
+
+    loop {
+        let mut statuses = None;
+        if let Some(client) = daemon.as_mut() {
+            let ids: Vec<String> = pending.iter().map(|(c, _)| c.id.clone()).collect();
+            match probe_via_daemon(client, &ids).await {
+                Ok(states) => {
+                    statuses = Some(
+                        pending
+                            .iter()
+                            .map(|(container, _)| {
+                                states
+                                    .iter()
+                                    .find(|(c, _)| c.id == container.id)
+                                    .and_then(|(_, health)| *health)
+                                    .unwrap_or(HealthStatus::Starting)
+                            })
+                            .collect(),
+                    );
+                }
+                Err(e) => {
+                    tracing::debug!("Daemon probe failed, falling back to Docker: {}", e);
+                    daemon = None;
+                    docker_client.verify_connection().await?;
+                }
+            }
+        }
+        let statuses: Vec<HealthStatus> = match statuses {
+            Some(statuses) => statuses,
+            None => {
+                join_all(pending.iter().map(|(container, checker)| {
+                    check_once(&container.id, checker.as_ref(), PROBE_TIMEOUT)
+                }))
+                .await
+            }
+        };
+
+        let mut still_pending = Vec::with_capacity(pending.len());
+        for ((container, checker), status) in pending.into_iter().zip(statuses) {
+            if status == HealthStatus::Healthy {
+                pb.println(format!(
+                    "{} {} is healthy ({:.1}s)",
+                    style("✓").green(),
+                    style(&container.name).cyan(),
+                    start.elapsed().as_secs_f64()
+                ));
+                if any {
+                    pb.finish_and_clear();
+                    return Ok(());
+                }
+            } else {
+                still_pending.push((container, checker));
+            }
+        }
+        pending = still_pending;
+
+        if pending.is_empty() {
+            pb.finish_and_clear();
+            return Ok(());
+        }
+
+        if start.elapsed() >= timeout {
+            pb.finish_and_clear();
+            if !quiet {
+                let names: Vec<_> = pending.iter().map(|(c, _)| c.name.as_str()).collect();
+                eprintln!(
+                    "{} Still not healthy: {}",
+                    style("✗").red(),
+                    names.join(", ")
+                );
+            }
+            return Err(DBArenaError::HealthCheckTimeout(timeout.as_secs()));
+        }
+
+        pb.set_message(if any {
+            format!(
+                "Waiting for any of {} container(s) to become healthy... ({}s)",
+                total,
+                start.elapsed().as_secs()
+            )
+        } else {
+            format!(
+                "Waiting for {} of {} container(s) to become healthy... ({}s)",
+                pending.len(),
+                total,
+                start.elapsed().as_secs()
+            )
+        });
+
+        tokio::time::sleep(interval).await;
+    }
+}
+
+/// Connect to the daemon if one is running for `endpoint`
+#[cfg(unix)]
+async fn connect_daemon(endpoint: &str) -> Option<DaemonClient> {
+    let mut client = DaemonClient::connect().await?;
+    match client.status().await {
+        Ok(status) if status.endpoint == endpoint => Some(client),
+        Ok(_) => None,
+        Err(e) => {
+            tracing::debug!("Daemon status failed, falling back to Docker: {}", e);
+            None
+        }
+    }
+}
+
+#[cfg(not(unix))]
+async fn connect_daemon(_endpoint: &str) -> Option<DaemonClient> {
+    None
+}
+
+/// Probe `containers` through the daemon, pairing each found container with its health
+#[cfg(unix)]
+async fn probe_via_daemon(
+    client: &mut DaemonClient,
+    containers: &[String],
+) -> Result<Vec<(Container, Option<HealthStatus>)>> {
+    let (_, states) = client.probe(containers).await?;
+    Ok(states.into_iter().map(|s| (s.container, s.health)).collect())
+}
+
+#[cfg(not(unix))]
+async fn probe_via_daemon(
+    client: &mut DaemonClient,
+    _containers: &[String],
+) -> Result<Vec<(Container, Option<HealthStatus>)>> {
+    match *client {}
+}
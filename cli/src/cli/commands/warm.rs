@@ -0,0 +1,183 @@
+use crate::container::{connection_params, ConnParams, ContainerManager, DatabaseType, DockerClient};
+use crate::init::run_exec;
+use crate::monitoring::format_bytes;
+use crate::util::shell::shell_quote;
+use crate::{DBArenaError, Result};
+use console::style;
+
+/// Handle the warm command: load tables into the database's buffer cache
+pub async fn handle_warm(container: String, tables: Vec<String>) -> Result<()> {
+    let docker_client = DockerClient::new()?;
+    docker_client.verify_connection().await?;
+
+    let manager = ContainerManager::new(docker_client.clone());
+    let found = manager
+        .find_container(&container)
+        .await?
+        .ok_or_else(|| DBArenaError::ContainerNotFound(container.clone()))?;
+
+    // Credentials come from the container's own environment
+    let docker = docker_client.docker();
+    let params = connection_params(docker, &found).await?;
+
+    println!(
+        "{} Warming {} table(s) on {}...",
+        style("→").cyan(),
+        tables.len(),
+        style(&found.name).bold()
+    );
+
+    let mut total = 0;
+    for table in &tables {
+        let cmd = build_warm_command(&params, table)?;
+        let output = run_exec(docker, &found.id, cmd).await.map_err(|e| {
+            DBArenaError::ContainerOperationFailed(format!("Failed to warm {}: {}", table, e))
+        })?;
+
+        let bytes = parse_bytes_warmed(&output);
+        total += bytes;
+        println!(
+            "  {} {} {}",
+            style("✓").green(),
+            table,
+            style(format_bytes(bytes)).dim()
+        );
+    }
+
+    println!(
+        "{} Warmed {} into cache",
+        style("✓").green(),
+        style(format_bytes(total)).bold()
+    );
+
+    Ok(())
+}
+
+/// Build a command that reads a table into cache and prints the bytes it covered
+///
+/// Postgres loads the table with pg_prewarm; MySQL and SQL Server read it with a
+/// full scan and report the size of the clustered data. The client and its
+/// connection flags come from [`ConnParams::client_command`].
+fn build_warm_command(params: &ConnParams, table: &str) -> Result<Vec<String>> {
+    let literal = table.replace('\'', "''");
+
+    match params.db_type {
+        DatabaseType::Postgres => {
+            let mut command = params.client_command()?;
+            command.extend([
+                "-q".to_string(),
+                "-t".to_string(),
+                "-A".to_string(),
+                "-v".to_string(),
+                "ON_ERROR_STOP=1".to_string(),
+                "-c".to_string(),
+                "CREATE EXTENSION IF NOT EXISTS pg_prewarm".to_string(),
+                "-c".to_string(),
+                format!(
+                    "SELECT pg_prewarm('{}') * current_setting('block_size')::bigint",
+                    literal
+                ),
+            ]);
+            Ok(command)
+        }
+        DatabaseType::MySQL => {
+            // Two client runs so the scanned rows can go to /dev/null
+            let client = params
+                .client_command()?
+                .iter()
+                .map(|arg| shell_quote(arg))
+                .collect::<Vec<_>>()
+                .join(" ");
+            let scan = format!("SELECT * FROM `{}`", table.replace('`', "``"));
+            let size = format!(
+                "SELECT DATA_LENGTH FROM information_schema.TABLES \
+                 WHERE TABLE_SCHEMA = DATABASE() AND TABLE_NAME = '{}'",
+                literal
+            );
+            Ok(vec![
+                "sh".to_string(),
+                "-c".to_string(),
+                format!(
+                    "{client} -N -B -e {} > /dev/null && {client} -N -B -e {}",
+                    shell_quote(&scan),
+                    shell_quote(&size),
+                    client = client
+                ),
+            ])
+        }
+        DatabaseType::SQLServer => {
+            // INDEX(0) forces a scan of the heap or clustered index
+            let query = format!(
+                "SET NOCOUNT ON; \
+                 SELECT COUNT_BIG(*) FROM [{}] WITH (INDEX(0)); \
+                 SELECT SUM(used_page_count) * 8192 FROM sys.dm_db_partition_stats \
+                 WHERE object_id = OBJECT_ID('{}') AND index_id IN (0, 1)",
+                table.replace(']', "]]"),
+                literal
+            );
+            let mut command = params.client_command()?;
+            command.extend([
+                "-b".to_string(),
+                "-h".to_string(),
+                "-1".to_string(),
+                "-W".to_string(),
+                "-Q".to_string(),
+                query,
+            ]);
+            Ok(command)
+        }
+        // SQLite has no buffer pool of its own; it reads through the OS page cache
+        DatabaseType::Redis
+        | DatabaseType::MongoDB
+        | DatabaseType::CockroachDB
+        | DatabaseType::SQLite => {
+            Err(DBArenaError::InvalidConfig(format!(
+                "Warming the cache is not supported for {}",
+                params.db_type
+            )))
+        }
+    }
+}
+
+/// The byte count is the last line the warm command prints
+fn parse_bytes_warmed(output: &str) -> u64 {
+    output
+        .lines()
+        .rev()
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .and_then(|line| line.parse().ok())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_build_warm_command() {
+        let params = |db_type| ConnParams::from_env(db_type, HashMap::new());
+
+        let cmd = build_warm_command(&params(DatabaseType::Postgres), "users").unwrap();
+        assert_eq!(cmd[0], "psql");
+        assert!(cmd.last().unwrap().starts_with("SELECT pg_prewarm('users')"));
+
+        let cmd = build_warm_command(&params(DatabaseType::MySQL), "orders").unwrap();
+        assert!(cmd[2].contains("'SELECT * FROM `orders`' > /dev/null"));
+        assert!(cmd[2].starts_with("'mysql' '-uroot' '-pmysql' -N -B -e"));
+
+        let cmd = build_warm_command(&params(DatabaseType::SQLServer), "orders").unwrap();
+        assert_eq!(cmd[0], "/opt/mssql-tools18/bin/sqlcmd");
+        assert!(cmd.last().unwrap().contains("FROM [orders] WITH (INDEX(0))"));
+
+        assert!(build_warm_command(&params(DatabaseType::Redis), "users").is_err());
+    }
+
+    #[test]
+    fn test_parse_bytes_warmed() {
+        assert_eq!(parse_bytes_warmed("81920\n"), 81920);
+        assert_eq!(parse_bytes_warmed("1000\n\n16384\n"), 16384);
+        assert_eq!(parse_bytes_warmed(""), 0);
+    }
+}
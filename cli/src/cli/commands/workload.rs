@@ -4,46 +4,510 @@ use std::sync::Arc;
 use std::time::Duration;
 use tokio::time::{interval, sleep};
 
-use crate::container::{ContainerManager, DatabaseType, DockerClient};
-use crate::workload::{print_summary, WorkloadConfig, WorkloadEngine, WorkloadPattern, WorkloadProgressDisplay};
+use crate::cli::output::OutputFormat;
+use crate::cli::target::Target;
+use crate::progress::{NoProgress, Progress};
+use crate::snapshot::{ScheduledSnapshot, SnapshotManager, SnapshotSchedule};
+use crate::workload::sweep::{parse_duration_secs, sweep_targets};
+use crate::workload::{
+    load_queries_file, print_summary, print_sweep, CustomQuery, MetadataCache, SweepFormat,
+    SweepStep, WorkloadConfig, WorkloadEngine, WorkloadPattern, WorkloadProgressDisplay,
+    WorkloadReport,
+};
 use crate::{DBArenaError, Result};
 
+#[allow(clippy::too_many_arguments)]
 pub async fn handle_workload_run(
-    container: String,
+    container: Option<String>,
+    url: Option<String>,
     pattern: Option<String>,
     config: Option<PathBuf>,
+    queries_file: Option<PathBuf>,
     connections: Option<usize>,
     tps: Option<usize>,
+    start_tps: Option<usize>,
+    end_tps: Option<usize>,
+    ramp: Option<String>,
+    base_tps: Option<usize>,
+    peak_tps: Option<usize>,
+    period: Option<String>,
+    duty_cycle: Option<f64>,
+    seed: Option<u64>,
+    warmup_seconds: Option<u64>,
     duration: Option<u64>,
     transaction_count: Option<u64>,
+    stats_out: Option<PathBuf>,
+    histogram: bool,
+    histogram_out: Option<PathBuf>,
+    format: OutputFormat,
+    profile: Option<String>,
+    refresh_metadata: bool,
+    snapshot_interval: Option<u64>,
+    max_snapshots: Option<usize>,
 ) -> Result<()> {
-    println!("{}", style("Starting workload...").cyan().bold());
-    println!();
+    if snapshot_interval == Some(0) {
+        return Err(DBArenaError::InvalidConfig(
+            "--snapshot-interval must be greater than 0".to_string(),
+        ));
+    }
+    if max_snapshots == Some(0) {
+        return Err(DBArenaError::InvalidConfig(
+            "--max-snapshots must be greater than 0".to_string(),
+        ));
+    }
+
+    // With --json or --yaml, stdout carries only the final report
+    let quiet = format.is_structured();
+    if !quiet {
+        println!("{}", style("Starting workload...").cyan().bold());
+        println!();
+    }
+
+    let ramp = ramp_pattern(start_tps, end_tps, ramp.as_deref())?;
+    let spike = spike_pattern(base_tps, peak_tps, period.as_deref(), duty_cycle)?;
+    let custom_queries = queries_file
+        .map(|path| load_queries_file(&path))
+        .transpose()
+        .map_err(|e| DBArenaError::ConfigError(format!("{:#}", e)))?;
+    let (target, mut workload_config) = prepare_workload(
+        container,
+        url,
+        profile,
+        pattern,
+        config,
+        custom_queries,
+        connections,
+        tps,
+        ramp,
+        spike,
+        duration,
+        transaction_count,
+        quiet,
+    )
+    .await?;
+
+    // Always run with a known seed so any run can be replayed
+    if seed.is_some() {
+        workload_config.seed = seed;
+    }
+    workload_config.seed.get_or_insert_with(rand::random);
+    if warmup_seconds.is_some() {
+        workload_config.warmup_seconds = warmup_seconds;
+    }
+
+    let schedule = match (snapshot_interval, &target.container) {
+        (None, _) => None,
+        (Some(_), None) => {
+            return Err(DBArenaError::InvalidConfig(
+                "--snapshot-interval needs a dbarena container, not a --url".to_string(),
+            ));
+        }
+        (Some(secs), Some(container)) => Some(SnapshotSchedule::new(
+            Arc::new(SnapshotManager::new(Arc::new(target.docker.docker().clone()))),
+            container.id.clone(),
+            container.name.clone(),
+            Duration::from_secs(secs),
+            max_snapshots,
+        )),
+    };
+
+    if !quiet {
+        print_run_settings(&workload_config);
+    }
+
+    // Create workload engine
+    let docker = Arc::new(target.docker.docker().clone());
+    let engine = WorkloadEngine::new(
+        target.container_id().to_string(),
+        target.params.db_type,
+        workload_config.clone(),
+        docker,
+    )
+    .with_connection(target.params.clone())
+    .with_metadata_cache(
+        MetadataCache::for_target(target.container_id(), &target.params)
+            .map(|cache| cache.refreshing(refresh_metadata)),
+    );
+
+    // Start workload in background
+    let stats_ref = engine.stats().clone();
+    let progress: Box<dyn Progress> = if quiet {
+        Box::new(NoProgress)
+    } else {
+        Box::new(
+            WorkloadProgressDisplay::new(
+                stats_ref.clone(),
+                workload_config.target_tps,
+                workload_config.duration_seconds.map(Duration::from_secs),
+                workload_config.transaction_count,
+            )
+            .with_target_schedule(workload_config.clone()),
+        )
+    };
+    let mut stats_writer = match &stats_out {
+        Some(path) => {
+            if !quiet {
+                println!("{} Streaming stats to {}", style("▸").cyan(), style(path.display()).yellow());
+            }
+            Some(stats_ref.write_jsonl(path)?)
+        }
+        None => None,
+    };
+    let mut engine_handle = tokio::spawn(async move {
+        engine.run().await
+    });
+    let schedule = schedule.map(SnapshotSchedule::spawn);
+
+    // Show live progress
+    progress.start("Running workload", workload_config.transaction_count);
+    let mut progress_interval = interval(Duration::from_secs(1));
+    loop {
+        tokio::select! {
+            _ = progress_interval.tick() => {
+                progress.update(stats_ref.total(), None);
+                if let Some(writer) = stats_writer.as_mut() {
+                    writer.sample(&stats_ref)?;
+                }
+            }
+            result = &mut engine_handle => {
+                // Stop the schedule whether or not the workload succeeded
+                let snapshots = match schedule {
+                    Some(schedule) => schedule.stop().await,
+                    None => Vec::new(),
+                };
+                match result {
+                    Ok(Ok(final_stats)) => {
+                        if let Some(writer) = stats_writer.take() {
+                            writer.finish(&final_stats)?;
+                        }
+                        if let Some(path) = &histogram_out {
+                            final_stats.latency_distribution().write(path)?;
+                        }
+
+                        let pattern_name = workload_config.pattern
+                            .map(|p| p.as_str().to_string())
+                            .unwrap_or_else(|| workload_config.name.clone());
 
-    // Find container
-    let docker_client = DockerClient::new()?;
-    docker_client.verify_connection().await?;
+                        progress.finish("Workload complete");
 
-    let manager = ContainerManager::new(docker_client.clone());
-    let container_info = manager
-        .find_container(&container)
-        .await?
-        .ok_or_else(|| DBArenaError::ContainerNotFound(container.clone()))?;
+                        if quiet {
+                            let report = WorkloadReport::from_snapshot(&pattern_name, final_stats.snapshot())
+                                .with_seed(workload_config.seed)
+                                .with_snapshots(snapshots);
+                            format.print(&report)?;
+                            return Ok(());
+                        }
 
+                        // Print final summary
+                        print_summary(&final_stats, &pattern_name, histogram);
+                        if let Some(path) = &histogram_out {
+                            println!(
+                                "{} Wrote latency histogram: {}",
+                                style("✓").green(),
+                                style(path.display()).cyan()
+                            );
+                        }
+                        print_snapshots(&snapshots);
+                        return Ok(());
+                    }
+                    Ok(Err(e)) => {
+                        if !quiet {
+                            print_snapshots(&snapshots);
+                        }
+                        return Err(DBArenaError::Other(format!("Workload failed: {}", e)));
+                    }
+                    Err(e) => {
+                        if !quiet {
+                            print_snapshots(&snapshots);
+                        }
+                        return Err(DBArenaError::Other(format!("Workload task failed: {}", e)));
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn print_snapshots(snapshots: &[ScheduledSnapshot]) {
+    if snapshots.is_empty() {
+        return;
+    }
+    println!();
+    println!("{}", style("Snapshots:").bold());
+    for snapshot in snapshots {
+        let note = if snapshot.kept { "" } else { " (rotated out)" };
+        println!(
+            "  {} {} at {:.0}s{}",
+            style("▸").cyan(),
+            style(&snapshot.name).cyan(),
+            snapshot.elapsed_secs,
+            style(note).dim()
+        );
+    }
+}
+
+fn print_run_settings(workload_config: &WorkloadConfig) {
     println!(
-        "{} Container: {} ({})",
+        "{} Workers: {}",
         style("▸").cyan(),
-        style(&container_info.name).green(),
-        container_info.database_type
+        style(workload_config.connections).yellow()
     );
+    if let Some(WorkloadPattern::RampUp {
+        start_tps,
+        end_tps,
+        ramp_seconds,
+    }) = workload_config.pattern
+    {
+        println!(
+            "{} Target TPS: {} → {} over {}s",
+            style("▸").cyan(),
+            style(start_tps).yellow(),
+            style(end_tps).yellow(),
+            style(ramp_seconds).yellow()
+        );
+    } else if let Some(WorkloadPattern::Spike {
+        base_tps,
+        peak_tps,
+        period_seconds,
+        duty_cycle,
+    }) = workload_config.pattern
+    {
+        println!(
+            "{} Target TPS: {}, {} for the last {}% of every {}s",
+            style("▸").cyan(),
+            style(base_tps).yellow(),
+            style(peak_tps).yellow(),
+            style(format!("{:.0}", duty_cycle * 100.0)).yellow(),
+            style(period_seconds).yellow()
+        );
+    } else {
+        println!(
+            "{} Target TPS: {}",
+            style("▸").cyan(),
+            style(workload_config.target_tps).yellow()
+        );
+    }
+    match &workload_config.custom_queries {
+        Some(queries) => println!(
+            "{} Queries: {}",
+            style("▸").cyan(),
+            style(queries.iter().map(|q| q.name.as_str()).collect::<Vec<_>>().join(", "))
+                .yellow()
+        ),
+        None => println!(
+            "{} Tables: {}",
+            style("▸").cyan(),
+            style(workload_config.tables.join(", ")).yellow()
+        ),
+    }
+
+    if let Some(w) = workload_config.warmup_seconds.filter(|w| *w > 0) {
+        println!("{} Warmup: {}s (not measured)", style("▸").cyan(), style(w).yellow());
+    }
+    if let Some(d) = workload_config.duration_seconds {
+        println!("{} Duration: {}s", style("▸").cyan(), style(d).yellow());
+    }
+    if let Some(c) = workload_config.transaction_count {
+        println!("{} Target transactions: {}", style("▸").cyan(), style(c).yellow());
+    }
+    if let Some(seed) = workload_config.seed {
+        println!("{} Seed: {}", style("▸").cyan(), style(seed).yellow());
+    }
+
+    println!();
+}
+
+/// Run workload phases at increasing target TPS and report where latency knees upward
+#[allow(clippy::too_many_arguments)]
+pub async fn handle_workload_sweep(
+    container: Option<String>,
+    url: Option<String>,
+    pattern: Option<String>,
+    config: Option<PathBuf>,
+    connections: Option<usize>,
+    from: usize,
+    to: usize,
+    step: usize,
+    duration: String,
+    format: SweepFormat,
+    profile: Option<String>,
+    refresh_metadata: bool,
+) -> Result<()> {
+    let targets =
+        sweep_targets(from, to, step).map_err(|e| DBArenaError::InvalidConfig(e.to_string()))?;
+    let phase_secs =
+        parse_duration_secs(&duration).map_err(|e| DBArenaError::InvalidConfig(e.to_string()))?;
+
+    let quiet = format != SweepFormat::Table;
+    let (target, base_config) = prepare_workload(
+        container,
+        url,
+        profile,
+        pattern,
+        config,
+        None,
+        connections,
+        Some(from),
+        None,
+        None,
+        Some(phase_secs),
+        None,
+        quiet,
+    )
+    .await?;
+
+    // Progress goes to stderr so csv/json output stays pipe-friendly
+    eprintln!(
+        "{} Sweeping {} phase(s) from {} to {} TPS, {}s each",
+        style("▸").cyan(),
+        targets.len(),
+        from,
+        to,
+        phase_secs
+    );
+
+    let docker = Arc::new(target.docker.docker().clone());
+    let mut steps = Vec::with_capacity(targets.len());
+
+    for (i, tps) in targets.iter().enumerate() {
+        let mut phase_config = base_config.clone();
+        phase_config.target_tps = *tps;
+        phase_config.duration_seconds = Some(phase_secs);
+        phase_config.transaction_count = None;
+
+        eprintln!(
+            "{} Phase {}/{}: target {} TPS",
+            style("→").cyan(),
+            i + 1,
+            targets.len(),
+            tps
+        );
+
+        let engine = WorkloadEngine::new(
+            target.container_id().to_string(),
+            target.params.db_type,
+            phase_config,
+            docker.clone(),
+        )
+        .with_connection(target.params.clone())
+        // Later phases reuse what the first one introspected
+        .with_metadata_cache(
+            MetadataCache::for_target(target.container_id(), &target.params)
+                .map(|cache| cache.refreshing(refresh_metadata && i == 0)),
+        );
+        let stats = engine
+            .run()
+            .await
+            .map_err(|e| DBArenaError::Other(format!("Workload failed at {} TPS: {}", tps, e)))?;
+
+        let result = SweepStep::from_snapshot(*tps, &stats.snapshot());
+        eprintln!(
+            "  {} achieved {:.1} TPS, p99 {}",
+            style("✓").green(),
+            result.achieved_tps,
+            result
+                .p99_us
+                .map(|p| format!("{:.2}ms", p as f64 / 1000.0))
+                .unwrap_or_else(|| "-".to_string())
+        );
+        steps.push(result);
+    }
+
+    print_sweep(&steps, format).map_err(|e| DBArenaError::Other(e.to_string()))?;
+
+    Ok(())
+}
+
+/// Build the ramp-up pattern from `--start-tps`, `--end-tps` and `--ramp`,
+/// which must be given together
+fn ramp_pattern(
+    start_tps: Option<usize>,
+    end_tps: Option<usize>,
+    ramp: Option<&str>,
+) -> Result<Option<WorkloadPattern>> {
+    match (start_tps, end_tps, ramp) {
+        (None, None, None) => Ok(None),
+        (Some(start_tps), Some(end_tps), Some(ramp)) => {
+            let ramp_seconds =
+                parse_duration_secs(ramp).map_err(|e| DBArenaError::InvalidConfig(e.to_string()))?;
+            Ok(Some(WorkloadPattern::RampUp {
+                start_tps,
+                end_tps,
+                ramp_seconds,
+            }))
+        }
+        _ => Err(DBArenaError::InvalidConfig(
+            "--start-tps, --end-tps and --ramp must be used together".to_string(),
+        )),
+    }
+}
+
+/// Build the spike pattern from `--base-tps`, `--peak-tps`, `--period` and
+/// the optional `--duty-cycle`
+fn spike_pattern(
+    base_tps: Option<usize>,
+    peak_tps: Option<usize>,
+    period: Option<&str>,
+    duty_cycle: Option<f64>,
+) -> Result<Option<WorkloadPattern>> {
+    match (base_tps, peak_tps, period) {
+        (None, None, None) if duty_cycle.is_none() => Ok(None),
+        (Some(base_tps), Some(peak_tps), Some(period)) => {
+            let period_seconds = parse_duration_secs(period)
+                .map_err(|e| DBArenaError::InvalidConfig(e.to_string()))?;
+            Ok(Some(WorkloadPattern::Spike {
+                base_tps,
+                peak_tps,
+                period_seconds,
+                duty_cycle: duty_cycle.unwrap_or(0.5),
+            }))
+        }
+        _ => Err(DBArenaError::InvalidConfig(
+            "--base-tps, --peak-tps and --period must be used together".to_string(),
+        )),
+    }
+}
+
+/// Resolve the target container or `--url` database and build the workload
+/// config from a file or pattern plus CLI overrides. `ramp` and `spike` are
+/// the patterns `--pattern ramp` and `--pattern spike` run, and
+/// `custom_queries` those of `--queries-file`, run in place of generated
+/// operations. `quiet` suppresses the informational output.
+#[allow(clippy::too_many_arguments)]
+async fn prepare_workload(
+    container: Option<String>,
+    url: Option<String>,
+    profile: Option<String>,
+    pattern: Option<String>,
+    config: Option<PathBuf>,
+    custom_queries: Option<Vec<CustomQuery>>,
+    connections: Option<usize>,
+    tps: Option<usize>,
+    mut ramp: Option<WorkloadPattern>,
+    mut spike: Option<WorkloadPattern>,
+    duration: Option<u64>,
+    transaction_count: Option<u64>,
+    quiet: bool,
+) -> Result<(Target, WorkloadConfig)> {
+    let target = Target::resolve(container.as_deref(), url.as_deref(), profile.as_deref()).await?;
+    let db_type = target.params.db_type;
 
-    // Parse database type
-    let db_type = DatabaseType::from_string(&container_info.database_type).ok_or_else(|| {
-        DBArenaError::InvalidConfig(format!(
-            "Unknown database type: {}",
-            container_info.database_type
-        ))
-    })?;
+    if !quiet {
+        println!(
+            "{} {}: {} ({})",
+            style("▸").cyan(),
+            if target.container.is_some() { "Container" } else { "Database" },
+            style(&target.name).green(),
+            db_type
+        );
+    }
+
+    if !db_type.is_sql() {
+        return Err(DBArenaError::InvalidConfig(format!(
+            "Workloads are not supported for NoSQL databases ({})",
+            db_type
+        )));
+    }
 
     // Load or create workload config
     let mut workload_config = if let Some(config_path) = config {
@@ -53,16 +517,37 @@ pub async fn handle_workload_run(
             .map_err(|e| DBArenaError::ConfigError(format!("Failed to parse workload config: {}", e)))?
     } else if let Some(pattern_str) = pattern {
         // Use built-in pattern
-        let pattern = WorkloadPattern::from_str(&pattern_str).ok_or_else(|| {
-            DBArenaError::InvalidConfig(format!(
-                "Unknown pattern: {}. Available: oltp, ecommerce, olap, reporting, time_series, social_media, iot, read_heavy, write_heavy, balanced",
-                pattern_str
-            ))
-        })?;
-
-        println!("{} Pattern: {}", style("▸").cyan(), style(pattern.as_str()).yellow());
-        println!("  {}", style(pattern.description()).dim());
-        println!();
+        let pattern = if is_ramp_pattern(&pattern_str) {
+            ramp.take().ok_or_else(|| {
+                DBArenaError::InvalidConfig(
+                    "--pattern ramp requires --start-tps, --end-tps and --ramp".to_string(),
+                )
+            })?
+        } else if is_spike_pattern(&pattern_str) {
+            spike.take().ok_or_else(|| {
+                DBArenaError::InvalidConfig(
+                    "--pattern spike requires --base-tps, --peak-tps and --period".to_string(),
+                )
+            })?
+        } else {
+            WorkloadPattern::from_str(&pattern_str).ok_or_else(|| {
+                DBArenaError::InvalidConfig(format!(
+                    "Unknown pattern: {}. Available: oltp, ecommerce, olap, reporting, time_series, social_media, iot, read_heavy, write_heavy, balanced, ramp, spike",
+                    pattern_str
+                ))
+            })?
+        };
+        let target_tps = match pattern {
+            WorkloadPattern::RampUp { end_tps, .. } => end_tps,
+            WorkloadPattern::Spike { peak_tps, .. } => peak_tps,
+            _ => tps.unwrap_or(100),
+        };
+
+        if !quiet {
+            println!("{} Pattern: {}", style("▸").cyan(), style(pattern.as_str()).yellow());
+            println!("  {}", style(pattern.description()).dim());
+            println!();
+        }
 
         // Create config from pattern
         WorkloadConfig {
@@ -71,16 +556,49 @@ pub async fn handle_workload_run(
             custom_operations: None,
             custom_queries: None,
             tables: Vec::new(), // Will need to be specified
+            table_weights: None,
+            connections: connections.unwrap_or(10),
+            target_tps,
+            duration_seconds: duration,
+            transaction_count,
+            seed: None,
+            warmup_seconds: None,
+        }
+    } else if custom_queries.is_some() {
+        WorkloadConfig {
+            name: "Custom Queries".to_string(),
+            pattern: None,
+            custom_operations: None,
+            custom_queries: None,
+            tables: Vec::new(),
+            table_weights: None,
             connections: connections.unwrap_or(10),
             target_tps: tps.unwrap_or(100),
             duration_seconds: duration,
             transaction_count,
+            seed: None,
+            warmup_seconds: None,
         }
     } else {
         return Err(DBArenaError::InvalidConfig(
-            "Must specify either --pattern or --config".to_string(),
+            "Must specify --pattern, --config or --queries-file".to_string(),
         ));
     };
+    if custom_queries.is_some() {
+        workload_config.custom_queries = custom_queries;
+    }
+
+    if ramp.is_some() {
+        return Err(DBArenaError::InvalidConfig(
+            "--start-tps, --end-tps and --ramp only apply to --pattern ramp".to_string(),
+        ));
+    }
+    if spike.is_some() {
+        return Err(DBArenaError::InvalidConfig(
+            "--base-tps, --peak-tps, --period and --duty-cycle only apply to --pattern spike"
+                .to_string(),
+        ));
+    }
 
     // Override with CLI parameters
     if let Some(c) = connections {
@@ -96,94 +614,28 @@ pub async fn handle_workload_run(
         workload_config.transaction_count = transaction_count;
     }
 
-    // Validate config
-    if workload_config.tables.is_empty() {
+    // Validate config; custom queries name their own tables
+    if workload_config.tables.is_empty() && workload_config.custom_queries.is_none() {
         return Err(DBArenaError::InvalidConfig(
             "No tables specified. Use --tables or provide a config file with tables".to_string(),
         ));
     }
+    workload_config
+        .validate()
+        .map_err(|e| DBArenaError::InvalidConfig(e.to_string()))?;
 
     if workload_config.duration_seconds.is_none() && workload_config.transaction_count.is_none() {
         // Default to 60 seconds
         workload_config.duration_seconds = Some(60);
     }
 
-    println!(
-        "{} Workers: {}",
-        style("▸").cyan(),
-        style(workload_config.connections).yellow()
-    );
-    println!(
-        "{} Target TPS: {}",
-        style("▸").cyan(),
-        style(workload_config.target_tps).yellow()
-    );
-    println!(
-        "{} Tables: {}",
-        style("▸").cyan(),
-        style(workload_config.tables.join(", ")).yellow()
-    );
-
-    if let Some(d) = workload_config.duration_seconds {
-        println!("{} Duration: {}s", style("▸").cyan(), style(d).yellow());
-    }
-    if let Some(c) = workload_config.transaction_count {
-        println!("{} Target transactions: {}", style("▸").cyan(), style(c).yellow());
-    }
-
-    println!();
-
-    // Create workload engine
-    let docker = Arc::new(docker_client.docker().clone());
-    let engine = WorkloadEngine::new(
-        container_info.id.clone(),
-        db_type,
-        workload_config.clone(),
-        docker,
-    );
-
-    // Create progress display
-    let progress = WorkloadProgressDisplay::new(
-        workload_config.target_tps,
-        workload_config.duration_seconds.map(Duration::from_secs),
-        workload_config.transaction_count,
-    );
-
-    // Start workload in background
-    let stats_ref = engine.stats().clone();
-    let mut engine_handle = tokio::spawn(async move {
-        engine.run().await
-    });
-
-    // Show live progress
-    let mut progress_interval = interval(Duration::from_secs(1));
-    loop {
-        tokio::select! {
-            _ = progress_interval.tick() => {
-                progress.render(&stats_ref);
-            }
-            result = &mut engine_handle => {
-                match result {
-                    Ok(Ok(final_stats)) => {
-                        // Clear progress display
-                        print!("\x1B[2J\x1B[1;1H");
+    Ok((target, workload_config))
+}
 
-                        // Print final summary
-                        let pattern_name = workload_config.pattern
-                            .map(|p| p.as_str().to_string())
-                            .unwrap_or_else(|| workload_config.name.clone());
+fn is_ramp_pattern(pattern: &str) -> bool {
+    matches!(pattern.to_lowercase().as_str(), "ramp" | "ramp-up" | "ramp_up" | "rampup")
+}
 
-                        print_summary(&final_stats, &pattern_name);
-                        return Ok(());
-                    }
-                    Ok(Err(e)) => {
-                        return Err(DBArenaError::Other(format!("Workload failed: {}", e)));
-                    }
-                    Err(e) => {
-                        return Err(DBArenaError::Other(format!("Workload task failed: {}", e)));
-                    }
-                }
-            }
-        }
-    }
+fn is_spike_pattern(pattern: &str) -> bool {
+    matches!(pattern.to_lowercase().as_str(), "spike" | "burst")
 }
@@ -81,7 +81,15 @@ pub fn select_databases() -> Result<Vec<DatabaseSelection>> {
     println!("{}", "─".repeat(50));
 
     // Step 1: Select which databases to create
-    let databases = vec!["PostgreSQL", "MySQL", "SQL Server"];
+    let databases = vec![
+        "PostgreSQL",
+        "MySQL",
+        "SQL Server",
+        "Redis",
+        "MongoDB",
+        "CockroachDB",
+        "SQLite",
+    ];
 
     let selections = MultiSelect::with_theme(&ColorfulTheme::default())
         .with_prompt("Select databases to create (use Space to select, Enter to confirm)")
@@ -137,6 +145,31 @@ pub fn select_databases() -> Result<Vec<DatabaseSelection>> {
                     ],
                 )
             }
+            3 => {
+                // Redis
+                (
+                    DatabaseType::Redis,
+                    vec!["7 (latest)", "6", "Custom version"],
+                )
+            }
+            4 => {
+                // MongoDB
+                (
+                    DatabaseType::MongoDB,
+                    vec!["7 (latest)", "6", "5", "Custom version"],
+                )
+            }
+            5 => {
+                // CockroachDB
+                (
+                    DatabaseType::CockroachDB,
+                    vec!["latest-v24.1 (latest)", "latest-v23.2", "Custom version"],
+                )
+            }
+            6 => {
+                // SQLite
+                (DatabaseType::SQLite, vec!["latest", "Custom version"])
+            }
             _ => unreachable!(),
         };
 
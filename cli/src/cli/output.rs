@@ -0,0 +1,100 @@
+//! Machine-readable output for commands with `--json` and `--yaml`
+
+use serde::Serialize;
+
+use crate::Result;
+
+/// How a command prints its result
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutputFormat {
+    /// Human-readable tables and text
+    #[default]
+    Text,
+    Json,
+    Yaml,
+}
+
+impl OutputFormat {
+    /// Format picked by a command's `--json` and `--yaml` flags (clap keeps them exclusive)
+    pub fn from_flags(json: bool, yaml: bool) -> Self {
+        if yaml {
+            OutputFormat::Yaml
+        } else if json {
+            OutputFormat::Json
+        } else {
+            OutputFormat::Text
+        }
+    }
+
+    /// Whether the output is JSON or YAML rather than text
+    pub fn is_structured(self) -> bool {
+        self != OutputFormat::Text
+    }
+
+    /// Serialize `value` in this format (pretty-printed JSON for `Text`)
+    pub fn render<T: Serialize + ?Sized>(self, value: &T) -> Result<String> {
+        match self {
+            OutputFormat::Yaml => {
+                let yaml = serde_yaml::to_string(value)?;
+                Ok(yaml.trim_end().to_string())
+            }
+            OutputFormat::Json | OutputFormat::Text => Ok(serde_json::to_string_pretty(value)?),
+        }
+    }
+
+    /// Print `value` in this format
+    pub fn print<T: Serialize + ?Sized>(self, value: &T) -> Result<()> {
+        println!("{}", self.render(value)?);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    #[derive(Serialize)]
+    struct Item {
+        name: String,
+        subnet: Option<String>,
+        labels: BTreeMap<String, String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        parent: Option<String>,
+    }
+
+    #[test]
+    fn test_flags() {
+        assert_eq!(OutputFormat::from_flags(false, false), OutputFormat::Text);
+        assert_eq!(OutputFormat::from_flags(true, false), OutputFormat::Json);
+        assert_eq!(OutputFormat::from_flags(false, true), OutputFormat::Yaml);
+        assert!(!OutputFormat::Text.is_structured());
+    }
+
+    #[test]
+    fn test_yaml_renders_nested_maps_and_options() {
+        let items = vec![Item {
+            name: "dbarena-net".to_string(),
+            subnet: None,
+            labels: BTreeMap::from([("dbarena.managed".to_string(), "true".to_string())]),
+            parent: None,
+        }];
+
+        let yaml = OutputFormat::Yaml.render(&items).unwrap();
+        assert_eq!(
+            yaml,
+            "- name: dbarena-net\n  subnet: null\n  labels:\n    dbarena.managed: 'true'"
+        );
+
+        // Same structure as the JSON output
+        let from_yaml: serde_json::Value = serde_yaml::from_str(&yaml).unwrap();
+        let json = OutputFormat::Json.render(&items).unwrap();
+        assert_eq!(from_yaml, serde_json::from_str::<serde_json::Value>(&json).unwrap());
+    }
+
+    #[test]
+    fn test_yaml_empty_list() {
+        let empty: Vec<Item> = Vec::new();
+        assert_eq!(OutputFormat::Yaml.render(&empty).unwrap(), "[]");
+    }
+}
@@ -0,0 +1,82 @@
+//! End-of-run summary line printed with `--summary`
+//!
+//! Handlers record counts as they finish work (`record("created", 2)`); main
+//! prints one greppable line to stderr once the command returns, e.g.
+//! `dbarena: created 2, failed 0 in 12.4s`.
+
+use std::sync::Mutex;
+use std::time::Duration;
+
+static COUNTS: Mutex<Vec<(&'static str, usize)>> = Mutex::new(Vec::new());
+
+/// Add `count` to the tally for `label`, keeping labels in first-recorded order
+pub fn record(label: &'static str, count: usize) {
+    let mut counts = COUNTS.lock().unwrap_or_else(|e| e.into_inner());
+    match counts.iter_mut().find(|(l, _)| *l == label) {
+        Some((_, total)) => *total += count,
+        None => counts.push((label, count)),
+    }
+}
+
+/// Take the counts recorded so far
+pub fn take() -> Vec<(&'static str, usize)> {
+    std::mem::take(&mut *COUNTS.lock().unwrap_or_else(|e| e.into_inner()))
+}
+
+/// Format the summary line
+///
+/// Without recorded counts the line reports the command and whether it
+/// succeeded, e.g. `dbarena: list ok in 0.3s`.
+pub fn format_line(
+    command: &str,
+    counts: &[(&str, usize)],
+    elapsed: Duration,
+    succeeded: bool,
+) -> String {
+    let outcome = if counts.is_empty() {
+        format!("{} {}", command, if succeeded { "ok" } else { "failed" })
+    } else {
+        counts
+            .iter()
+            .map(|(label, count)| format!("{} {}", label, count))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+
+    format!("dbarena: {} in {:.1}s", outcome, elapsed.as_secs_f64())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_line() {
+        assert_eq!(
+            format_line(
+                "create",
+                &[("created", 2), ("failed", 0)],
+                Duration::from_millis(12_400),
+                true
+            ),
+            "dbarena: created 2, failed 0 in 12.4s"
+        );
+        assert_eq!(
+            format_line("list", &[], Duration::from_millis(300), true),
+            "dbarena: list ok in 0.3s"
+        );
+        assert_eq!(
+            format_line("query", &[], Duration::from_secs(1), false),
+            "dbarena: query failed in 1.0s"
+        );
+    }
+
+    #[test]
+    fn test_record_accumulates() {
+        record("started", 1);
+        record("failed", 0);
+        record("started", 2);
+        assert_eq!(take(), vec![("started", 3), ("failed", 0)]);
+        assert!(take().is_empty());
+    }
+}
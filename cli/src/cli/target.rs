@@ -0,0 +1,88 @@
+//! The database `seed`, `workload` and `query` run against: a dbarena
+//! container, or an external database given by `--url`
+
+use crate::config::{load_or_default, resolve_profile};
+use crate::container::{connection_params, ConnParams, Container, ContainerManager, DockerClient};
+use crate::{DBArenaError, Result};
+
+pub struct Target {
+    pub docker: DockerClient,
+    /// `None` for an external database
+    pub container: Option<Container>,
+    /// Container name, or an external database's `host:port`
+    pub name: String,
+    pub params: ConnParams,
+}
+
+impl Target {
+    /// Find the container and read its credentials, layering `profile`'s
+    /// variables on top, or parse `url` for an external database
+    ///
+    /// Docker isn't contacted for a URL: its client commands run on this host.
+    pub async fn resolve(
+        container: Option<&str>,
+        url: Option<&str>,
+        profile: Option<&str>,
+    ) -> Result<Self> {
+        let docker = DockerClient::new()?;
+
+        if let Some(url) = url {
+            if profile.is_some() {
+                return Err(DBArenaError::InvalidConfig(
+                    "--profile overrides a container's credentials; put them in the --url instead"
+                        .to_string(),
+                ));
+            }
+            let params = ConnParams::from_url(url)?;
+            let name = params
+                .endpoint
+                .as_ref()
+                .map(|endpoint| endpoint.to_string())
+                .unwrap_or_default();
+            return Ok(Self {
+                docker,
+                container: None,
+                name,
+                params,
+            });
+        }
+
+        let container = container.ok_or_else(|| {
+            DBArenaError::InvalidConfig("A container name or --url is required".to_string())
+        })?;
+        docker.verify_connection().await?;
+
+        let found = ContainerManager::new(docker.clone())
+            .find_container(container)
+            .await?
+            .ok_or_else(|| DBArenaError::ContainerNotFound(container.to_string()))?;
+
+        // Connect with the credentials the container was created with
+        let mut params = connection_params(docker.docker(), &found).await?;
+        if let Some(profile) = profile {
+            let overrides = resolve_profile(&load_or_default(None)?, profile, params.db_type)?;
+            params = params.with_overrides(overrides);
+        }
+
+        Ok(Self {
+            docker,
+            name: found.name.clone(),
+            container: Some(found),
+            params,
+        })
+    }
+
+    /// ID to `docker exec` in; empty for an external database
+    pub fn container_id(&self) -> &str {
+        self.container.as_ref().map_or("", |container| &container.id)
+    }
+
+    /// "container" or "database", for messages naming the target
+    pub fn kind(&self) -> &'static str {
+        if self.container.is_some() {
+            "container"
+        } else {
+            "database"
+        }
+    }
+}
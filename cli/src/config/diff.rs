@@ -0,0 +1,175 @@
+use super::merger::merge_configs;
+use super::schema::DBArenaConfig;
+use crate::error::Result;
+use serde::Serialize;
+use serde_json::Value;
+
+/// A single setting that differs between two configurations
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ConfigChange {
+    /// Dotted path to the setting, e.g. `databases.postgres.env.POSTGRES_USER`
+    pub path: String,
+    /// Value in the first configuration (`None` if unset)
+    pub before: Option<Value>,
+    /// Value in the second configuration (`None` if unset)
+    pub after: Option<Value>,
+}
+
+impl ConfigChange {
+    /// `added`, `removed` or `changed`
+    pub fn kind(&self) -> &'static str {
+        match (&self.before, &self.after) {
+            (None, Some(_)) => "added",
+            (Some(_), None) => "removed",
+            _ => "changed",
+        }
+    }
+}
+
+/// Compare two configurations setting by setting
+///
+/// Both sides are first normalized through [`merge_configs`] so that unset
+/// sections and their defaults compare equal, and map ordering (env tables,
+/// profiles, databases) never shows up as a change. Init script lists are
+/// compared as a whole since their order is significant.
+pub fn diff_configs(a: &DBArenaConfig, b: &DBArenaConfig) -> Result<Vec<ConfigChange>> {
+    let before = normalize(a)?;
+    let after = normalize(b)?;
+
+    let mut changes = Vec::new();
+    diff_values(String::new(), Some(&before), Some(&after), &mut changes);
+    Ok(changes)
+}
+
+fn normalize(config: &DBArenaConfig) -> Result<Value> {
+    let merged = merge_configs(DBArenaConfig::default(), config.clone());
+    Ok(serde_json::to_value(merged)?)
+}
+
+fn diff_values(path: String, a: Option<&Value>, b: Option<&Value>, out: &mut Vec<ConfigChange>) {
+    // An explicit null is the same as an unset value
+    let a = a.filter(|v| !v.is_null());
+    let b = b.filter(|v| !v.is_null());
+
+    match (a, b) {
+        (Some(Value::Object(a)), Some(Value::Object(b))) => {
+            let mut keys: Vec<&String> = a.keys().chain(b.keys()).collect();
+            keys.sort();
+            keys.dedup();
+            for key in keys {
+                let child = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", path, key)
+                };
+                diff_values(child, a.get(key), b.get(key), out);
+            }
+        }
+        (a, b) if a != b => out.push(ConfigChange {
+            path,
+            before: a.cloned(),
+            after: b.cloned(),
+        }),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{load_config_from_string, ConfigFormat};
+
+    fn parse(toml: &str) -> DBArenaConfig {
+        load_config_from_string(toml, ConfigFormat::Toml).unwrap()
+    }
+
+    #[test]
+    fn test_identical_configs_have_no_changes() {
+        let a = parse(
+            r#"
+            [databases.postgres.env]
+            POSTGRES_USER = "app"
+            POSTGRES_DB = "main"
+            "#,
+        );
+        let b = parse(
+            r#"
+            [databases.postgres.env]
+            POSTGRES_DB = "main"
+            POSTGRES_USER = "app"
+            "#,
+        );
+
+        assert!(diff_configs(&a, &b).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_empty_sections_match_missing_sections() {
+        let a = parse("[databases.postgres]\n");
+        let b = parse("[databases.postgres.env]\n[profiles]\n");
+
+        assert!(diff_configs(&a, &b).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_reports_added_removed_and_changed() {
+        let a = parse(
+            r#"
+            [defaults]
+            memory_mb = 512
+
+            [profiles.ci]
+            env = { LOG_LEVEL = "warn" }
+
+            [databases.postgres]
+            default_version = "15"
+            "#,
+        );
+        let b = parse(
+            r#"
+            [defaults]
+            memory_mb = 1024
+
+            [databases.postgres]
+            default_version = "16"
+            env = { POSTGRES_USER = "app" }
+            "#,
+        );
+
+        let changes = diff_configs(&a, &b).unwrap();
+        let summary: Vec<(&str, &str)> =
+            changes.iter().map(|c| (c.path.as_str(), c.kind())).collect();
+
+        assert_eq!(
+            summary,
+            vec![
+                ("databases.postgres.default_version", "changed"),
+                ("databases.postgres.env.POSTGRES_USER", "added"),
+                ("defaults.memory_mb", "changed"),
+                ("profiles.ci", "removed"),
+            ]
+        );
+        assert_eq!(changes[2].before, Some(Value::from(512)));
+        assert_eq!(changes[2].after, Some(Value::from(1024)));
+    }
+
+    #[test]
+    fn test_init_script_order_is_significant() {
+        let a = parse(
+            r#"
+            [databases.postgres]
+            init_scripts = ["schema.sql", "seed.sql"]
+            "#,
+        );
+        let b = parse(
+            r#"
+            [databases.postgres]
+            init_scripts = ["seed.sql", "schema.sql"]
+            "#,
+        );
+
+        let changes = diff_configs(&a, &b).unwrap();
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].path, "databases.postgres.init_scripts");
+    }
+}
@@ -0,0 +1,122 @@
+//! `${VAR}` interpolation of config env values
+//!
+//! Applied to every `env` table (global profiles, databases and database
+//! profiles) after the file is parsed, so secrets can stay out of the file:
+//!
+//! ```toml
+//! [databases.postgres.env]
+//! POSTGRES_PASSWORD = "${PG_PASS}"
+//! POSTGRES_DB = "${PG_DB:-app}"
+//! ```
+//!
+//! A referenced variable that is unset is an error unless the `${VAR:-default}`
+//! form is used. `$${literal}` produces a literal `${literal}`, and `\$` a
+//! literal `$`. The syntax is shared with env files ([`env_file::interpolate`]).
+
+use super::env_file::{self, MissingVar};
+use super::schema::DBArenaConfig;
+use crate::{DBArenaError, Result};
+use std::collections::HashMap;
+
+/// Resolve `${VAR}` references in all env values of `config`
+///
+/// With `allow_missing`, references to unset variables are left as written
+/// instead of failing, so configs can be linted without every secret present.
+pub fn interpolate_config(config: &mut DBArenaConfig, allow_missing: bool) -> Result<()> {
+    for (name, profile) in &mut config.profiles {
+        interpolate_env(&mut profile.env, &format!("profiles.{}", name), allow_missing)?;
+    }
+
+    for (db, db_config) in &mut config.databases {
+        interpolate_env(&mut db_config.env, &format!("databases.{}", db), allow_missing)?;
+        for (name, profile) in &mut db_config.profiles {
+            interpolate_env(
+                &mut profile.env,
+                &format!("databases.{}.profiles.{}", db, name),
+                allow_missing,
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+fn interpolate_env(
+    env: &mut HashMap<String, String>,
+    section: &str,
+    allow_missing: bool,
+) -> Result<()> {
+    for (key, value) in env.iter_mut() {
+        *value = interpolate(value, allow_missing).map_err(|reason| {
+            DBArenaError::ConfigError(format!("{}.env.{}: {}", section, key, reason))
+        })?;
+    }
+    Ok(())
+}
+
+/// Expand `${VAR}` and `${VAR:-default}` in a single value from the process
+/// environment
+pub fn interpolate(value: &str, allow_missing: bool) -> std::result::Result<String, String> {
+    let missing = if allow_missing {
+        MissingVar::Keep
+    } else {
+        MissingVar::Error
+    };
+    env_file::interpolate(value, |name| std::env::var(name).ok(), missing)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_interpolate() {
+        std::env::set_var("DBARENA_INTERP_TEST_PASS", "s3cret");
+        std::env::remove_var("DBARENA_INTERP_TEST_UNSET");
+
+        assert_eq!(
+            interpolate("pw=${DBARENA_INTERP_TEST_PASS}", false).unwrap(),
+            "pw=s3cret"
+        );
+        assert_eq!(
+            interpolate("${DBARENA_INTERP_TEST_UNSET:-app}", false).unwrap(),
+            "app"
+        );
+        assert_eq!(interpolate("$${literal} costs $5", false).unwrap(), "${literal} costs $5");
+        assert_eq!(interpolate(r"\${literal}", false).unwrap(), "${literal}");
+
+        let err = interpolate("${DBARENA_INTERP_TEST_UNSET}", false).unwrap_err();
+        assert!(err.contains("DBARENA_INTERP_TEST_UNSET"), "{}", err);
+        assert_eq!(
+            interpolate("x${DBARENA_INTERP_TEST_UNSET}y", true).unwrap(),
+            "x${DBARENA_INTERP_TEST_UNSET}y"
+        );
+        assert!(interpolate("${UNCLOSED", true).is_err());
+    }
+
+    #[test]
+    fn test_interpolate_config_covers_profiles() {
+        std::env::set_var("DBARENA_INTERP_TEST_DB", "myapp_dev");
+        let toml = r#"
+            [databases.postgres.env]
+            POSTGRES_PASSWORD = "${DBARENA_INTERP_TEST_MISSING_PW}"
+
+            [databases.postgres.profiles.dev]
+            env = { POSTGRES_DB = "${DBARENA_INTERP_TEST_DB}" }
+        "#;
+        let mut config: DBArenaConfig = toml::from_str(toml).unwrap();
+
+        let err = interpolate_config(&mut config.clone(), false).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("databases.postgres.env.POSTGRES_PASSWORD"));
+
+        interpolate_config(&mut config, true).unwrap();
+        let postgres = &config.databases["postgres"];
+        assert_eq!(postgres.profiles["dev"].env["POSTGRES_DB"], "myapp_dev");
+        assert_eq!(
+            postgres.env["POSTGRES_PASSWORD"],
+            "${DBARENA_INTERP_TEST_MISSING_PW}"
+        );
+    }
+}
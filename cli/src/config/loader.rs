@@ -1,3 +1,4 @@
+use super::interpolate::interpolate_config;
 use super::schema::DBArenaConfig;
 use crate::error::{DBArenaError, Result};
 use std::fs;
@@ -65,6 +66,15 @@ pub fn find_config_file() -> Result<Option<PathBuf>> {
 
 /// Load configuration from a file
 pub fn load_config(path: impl AsRef<Path>) -> Result<DBArenaConfig> {
+    load_config_with(path, false)
+}
+
+/// Load configuration from a file, optionally leaving `${VAR}` references to
+/// unset variables unresolved instead of failing
+pub fn load_config_with(
+    path: impl AsRef<Path>,
+    allow_missing_env: bool,
+) -> Result<DBArenaConfig> {
     let path = path.as_ref();
 
     // Read file contents
@@ -84,18 +94,29 @@ pub fn load_config(path: impl AsRef<Path>) -> Result<DBArenaConfig> {
         ))
     })?;
 
-    // Parse based on format
-    load_config_from_string(&content, format).map_err(|e| {
-        DBArenaError::ConfigError(format!(
-            "Failed to parse config file '{}': {}",
-            path.display(),
-            e
-        ))
-    })
+    // Parse based on format, then resolve ${VAR} references in env values
+    parse_config(&content, format)
+        .and_then(|mut config| {
+            interpolate_config(&mut config, allow_missing_env)?;
+            Ok(config)
+        })
+        .map_err(|e| {
+            DBArenaError::ConfigError(format!(
+                "Failed to parse config file '{}': {}",
+                path.display(),
+                e
+            ))
+        })
 }
 
 /// Load configuration from a string with specified format
 pub fn load_config_from_string(content: &str, format: ConfigFormat) -> Result<DBArenaConfig> {
+    let mut config = parse_config(content, format)?;
+    interpolate_config(&mut config, false)?;
+    Ok(config)
+}
+
+fn parse_config(content: &str, format: ConfigFormat) -> Result<DBArenaConfig> {
     match format {
         ConfigFormat::Toml => {
             toml::from_str(content).map_err(|e| DBArenaError::ConfigError(format!("TOML parse error: {}", e)))
@@ -112,13 +133,21 @@ pub fn load_config_from_string(content: &str, format: ConfigFormat) -> Result<DB
 /// Otherwise, searches for config file using find_config_file().
 /// Returns default config if no file is found.
 pub fn load_or_default(explicit_path: Option<PathBuf>) -> Result<DBArenaConfig> {
+    load_or_default_with(explicit_path, false)
+}
+
+/// `load_or_default` that can tolerate unset `${VAR}` references (see `load_config_with`)
+pub fn load_or_default_with(
+    explicit_path: Option<PathBuf>,
+    allow_missing_env: bool,
+) -> Result<DBArenaConfig> {
     if let Some(path) = explicit_path {
         // Explicit path provided - must exist
-        load_config(path)
+        load_config_with(path, allow_missing_env)
     } else {
         // Search for config file
         if let Some(path) = find_config_file()? {
-            load_config(path)
+            load_config_with(path, allow_missing_env)
         } else {
             // No config file found - use defaults
             Ok(DBArenaConfig::default())
@@ -1,4 +1,4 @@
-use super::schema::{DBArenaConfig, DatabaseConfig, DefaultsConfig, ProfileConfig};
+use super::schema::{DBArenaConfig, DatabaseConfig, DefaultsConfig, DockerConfig, ProfileConfig};
 use std::collections::HashMap;
 
 /// Merge two configurations with override taking precedence
@@ -16,6 +16,13 @@ pub fn merge_configs(base: DBArenaConfig, override_config: DBArenaConfig) -> DBA
         databases: merge_databases(base.databases, override_config.databases),
         monitoring: override_config.monitoring, // Override completely replaces
         snapshots: override_config.snapshots,   // Override completely replaces
+        docker: DockerConfig {
+            host: override_config.docker.host.or(base.docker.host),
+            list_cache_ms: override_config
+                .docker
+                .list_cache_ms
+                .or(base.docker.list_cache_ms),
+        },
     }
 }
 
@@ -73,8 +80,12 @@ fn merge_database_config(base: DatabaseConfig, override_config: DatabaseConfig)
     let mut bind_mounts = base.bind_mounts;
     bind_mounts.extend(override_config.bind_mounts);
 
+    let mut tuning = base.tuning;
+    tuning.extend(override_config.tuning);
+
     DatabaseConfig {
         default_version: override_config.default_version.or(base.default_version),
+        image: override_config.image.or(base.image),
         env,
         profiles,
         init_scripts,
@@ -82,6 +93,7 @@ fn merge_database_config(base: DatabaseConfig, override_config: DatabaseConfig)
         volume_path: override_config.volume_path.or(base.volume_path),
         volumes,
         bind_mounts,
+        tuning,
     }
 }
 
@@ -178,6 +190,7 @@ mod tests {
     fn test_merge_database_config() {
         let base = DatabaseConfig {
             default_version: Some("14".to_string()),
+            image: None,
             env: [("POSTGRES_DB".to_string(), "basedb".to_string())]
                 .iter()
                 .cloned()
@@ -188,10 +201,12 @@ mod tests {
             volume_path: None,
             volumes: vec![],
             bind_mounts: vec![],
+            tuning: HashMap::new(),
         };
 
         let override_config = DatabaseConfig {
             default_version: Some("16".to_string()),
+            image: None,
             env: [
                 ("POSTGRES_DB".to_string(), "overridedb".to_string()),
                 ("POSTGRES_USER".to_string(), "user".to_string()),
@@ -205,6 +220,7 @@ mod tests {
             volume_path: None,
             volumes: vec![],
             bind_mounts: vec![],
+            tuning: HashMap::new(),
         };
 
         let merged = merge_database_config(base, override_config);
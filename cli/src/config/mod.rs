@@ -25,18 +25,42 @@
 //!
 //! [databases.postgres.profiles.dev]
 //! env = { POSTGRES_DB = "myapp_dev" }
+//!
+//! [databases.postgres.tuning]
+//! shared_buffers = "512MB"
+//!
+//! [docker]
+//! host = "tcp://build-host:2376"
 //! ```
+//!
+//! Env values may reference the process environment with `${VAR}` or
+//! `${VAR:-default}`; see [`interpolate`].
 
+pub mod diff;
+pub mod env_file;
+pub mod interpolate;
 pub mod loader;
 pub mod merger;
 pub mod profile;
 pub mod schema;
 pub mod template;
+pub mod tuning;
 pub mod validator;
 
-pub use loader::{find_config_file, load_config, load_config_from_string, load_or_default, ConfigFormat};
+pub use diff::{diff_configs, ConfigChange};
+pub use env_file::parse_env_file;
+pub use loader::{
+    find_config_file, load_config, load_config_from_string, load_config_with, load_or_default,
+    load_or_default_with, ConfigFormat,
+};
 pub use merger::{apply_cli_overrides, merge_configs, merge_env_vars};
-pub use profile::{get_database_env, list_profiles, resolve_profile};
-pub use schema::{DBArenaConfig, DatabaseConfig, DefaultsConfig, InitScript, ProfileConfig};
+pub use profile::{
+    get_database_env, get_database_image, get_database_tuning, list_profiles, resolve_profile,
+};
+pub use schema::{
+    DBArenaConfig, DatabaseConfig, DefaultsConfig, DockerConfig, InitScript, ProfileConfig,
+    TuningValue,
+};
 pub use template::{Template, TemplateConfig, TemplateManager};
+pub use tuning::{known_parameters, tuning_settings, TuningSettings};
 pub use validator::{validate_config, validate_init_script_paths, ValidationResult};
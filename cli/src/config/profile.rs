@@ -1,4 +1,4 @@
-use super::schema::DBArenaConfig;
+use super::schema::{DBArenaConfig, TuningValue};
 use crate::container::DatabaseType;
 use crate::error::{DBArenaError, Result};
 use std::collections::HashMap;
@@ -77,6 +77,28 @@ pub fn get_database_env(config: &DBArenaConfig, db_type: DatabaseType) -> HashMa
         .unwrap_or_default()
 }
 
+/// Get the custom image configured for a database type, if any
+pub fn get_database_image(config: &DBArenaConfig, db_type: DatabaseType) -> Option<String> {
+    let db_key = db_type.to_string().to_lowercase();
+    config
+        .databases
+        .get(&db_key)
+        .and_then(|cfg| cfg.image.clone())
+}
+
+/// Get the tuning parameters configured for a database type
+pub fn get_database_tuning(
+    config: &DBArenaConfig,
+    db_type: DatabaseType,
+) -> HashMap<String, TuningValue> {
+    let db_key = db_type.to_string().to_lowercase();
+    config
+        .databases
+        .get(&db_key)
+        .map(|cfg| cfg.tuning.clone())
+        .unwrap_or_default()
+}
+
 /// Suggest a profile name based on simple string distance
 fn suggest_profile_name(target: &str, available: &[String]) -> Option<String> {
     if available.is_empty() {
@@ -26,6 +26,10 @@ pub struct DBArenaConfig {
     /// Snapshot configuration
     #[serde(default)]
     pub snapshots: SnapshotsConfig,
+
+    /// Docker daemon connection settings
+    #[serde(default)]
+    pub docker: DockerConfig,
 }
 
 /// Default settings for all containers
@@ -55,6 +59,11 @@ pub struct DatabaseConfig {
     /// Default version to use for this database type
     pub default_version: Option<String>,
 
+    /// Image to use instead of the official one, e.g. a mirror such as
+    /// "registry.internal/postgres"; the version is appended as the tag
+    /// unless the image already has one
+    pub image: Option<String>,
+
     /// Base environment variables for this database
     #[serde(default)]
     pub env: HashMap<String, String>,
@@ -80,6 +89,33 @@ pub struct DatabaseConfig {
     /// Bind mounts to create
     #[serde(default)]
     pub bind_mounts: Vec<BindMountSpec>,
+
+    /// Server tuning parameters, e.g. shared_buffers = "512MB" (see `config::tuning`)
+    #[serde(default)]
+    pub tuning: HashMap<String, TuningValue>,
+}
+
+/// Value of a tuning parameter as written in the config file
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum TuningValue {
+    Bool(bool),
+    Integer(i64),
+    Float(f64),
+    Text(String),
+}
+
+impl TuningValue {
+    /// The value as the engine expects it, spelling booleans its way
+    pub fn render(&self, on: &str, off: &str) -> String {
+        match self {
+            TuningValue::Bool(true) => on.to_string(),
+            TuningValue::Bool(false) => off.to_string(),
+            TuningValue::Integer(i) => i.to_string(),
+            TuningValue::Float(f) => f.to_string(),
+            TuningValue::Text(s) => s.clone(),
+        }
+    }
 }
 
 /// Volume specification
@@ -139,6 +175,17 @@ impl Default for MonitoringConfig {
     }
 }
 
+/// Docker daemon connection configuration
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+pub struct DockerConfig {
+    /// Docker host URL (overrides DOCKER_HOST, e.g. "tcp://build-host:2376")
+    pub host: Option<String>,
+
+    /// How long container listings are cached in-process, in milliseconds
+    /// (0 disables the cache)
+    pub list_cache_ms: Option<u64>,
+}
+
 /// Snapshot configuration
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct SnapshotsConfig {
@@ -288,4 +335,16 @@ mod tests {
         assert_eq!(scripts[0].path(), "./schema.sql");
         assert!(scripts[0].continue_on_error());
     }
+
+    #[test]
+    fn test_parse_docker_config() {
+        let toml = r#"
+            [docker]
+            host = "tcp://build-host:2376"
+        "#;
+
+        let config: DBArenaConfig = toml::from_str(toml).unwrap();
+        assert_eq!(config.docker.host.as_deref(), Some("tcp://build-host:2376"));
+        assert!(DBArenaConfig::default().docker.host.is_none());
+    }
 }
@@ -83,6 +83,7 @@ impl Template {
                 .version
                 .clone()
                 .unwrap_or_else(|| "latest".to_string()),
+            image: None,
             port: self.config.port,
             persistent: self.config.persistent.unwrap_or(false),
             memory_limit: self.config.memory_limit,
@@ -95,7 +96,18 @@ impl Template {
                 .map(PathBuf::from)
                 .collect(),
             continue_on_error: false,
+            atomic_init: false,
             volumes: self.config.volumes.clone(),
+            user: None,
+            workdir: None,
+            cap_add: Vec::new(),
+            cap_drop: Vec::new(),
+            security_opt: Vec::new(),
+            ulimits: Vec::new(),
+            tmpfs: HashMap::new(),
+            command: None,
+            command_args: Vec::new(),
+            labels: HashMap::new(),
         })
     }
 }
@@ -0,0 +1,243 @@
+//! Database tuning parameters from `[databases.<db>.tuning]`
+//!
+//! Well-known server settings are translated into what each engine accepts
+//! at startup: `-c name=value` for Postgres, `--name=value` for MySQL,
+//! `--name value` for Redis, MongoDB and CockroachDB, and mssql-conf
+//! environment variables for SQL Server.
+//!
+//! ```toml
+//! [databases.postgres.tuning]
+//! shared_buffers = "512MB"
+//! max_connections = 200
+//!
+//! [databases.mysql.tuning]
+//! innodb_buffer_pool_size = "1G"
+//! ```
+
+use super::schema::TuningValue;
+use crate::container::DatabaseType;
+use std::collections::HashMap;
+
+const POSTGRES_PARAMETERS: &[&str] = &[
+    "shared_buffers",
+    "work_mem",
+    "maintenance_work_mem",
+    "effective_cache_size",
+    "max_connections",
+    "max_wal_size",
+    "min_wal_size",
+    "wal_buffers",
+    "checkpoint_timeout",
+    "checkpoint_completion_target",
+    "random_page_cost",
+    "effective_io_concurrency",
+    "synchronous_commit",
+    "fsync",
+    "full_page_writes",
+    "max_worker_processes",
+    "max_parallel_workers",
+    "max_parallel_workers_per_gather",
+    "default_statistics_target",
+];
+
+const MYSQL_PARAMETERS: &[&str] = &[
+    "innodb_buffer_pool_size",
+    "innodb_log_file_size",
+    "innodb_flush_log_at_trx_commit",
+    "innodb_flush_method",
+    "innodb_io_capacity",
+    "max_connections",
+    "sort_buffer_size",
+    "join_buffer_size",
+    "tmp_table_size",
+    "max_heap_table_size",
+    "table_open_cache",
+    "sync_binlog",
+];
+
+/// Redis takes its config directives with dashes (`maxmemory-policy`)
+const REDIS_PARAMETERS: &[&str] = &[
+    "maxmemory",
+    "maxmemory_policy",
+    "appendonly",
+    "appendfsync",
+    "save",
+    "io_threads",
+];
+
+/// Parameter -> mongod flag
+const MONGODB_PARAMETERS: &[(&str, &str)] = &[
+    ("wiredtiger_cache_size_gb", "--wiredTigerCacheSizeGB"),
+    ("slow_op_threshold_ms", "--slowms"),
+];
+
+/// Parameter -> `cockroach start-single-node` flag
+const COCKROACHDB_PARAMETERS: &[(&str, &str)] = &[
+    ("cache", "--cache"),
+    ("max_sql_memory", "--max-sql-memory"),
+];
+
+/// Parameter -> mssql-conf environment variable
+const SQLSERVER_PARAMETERS: &[(&str, &str)] = &[
+    ("memory_limit_mb", "MSSQL_MEMORY_LIMIT_MB"),
+    ("lcid", "MSSQL_LCID"),
+    ("collation", "MSSQL_COLLATION"),
+];
+
+/// Startup arguments and environment for a set of tuning parameters
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TuningSettings {
+    /// Appended to the image's default command
+    pub args: Vec<String>,
+    /// Added to the container's environment
+    pub env: HashMap<String, String>,
+    /// Parameters this engine doesn't know; left out of `args` and `env`
+    pub unknown: Vec<String>,
+}
+
+/// Tuning parameter names dbarena knows for a database
+pub fn known_parameters(db_type: DatabaseType) -> Vec<&'static str> {
+    match db_type {
+        DatabaseType::Postgres => POSTGRES_PARAMETERS.to_vec(),
+        DatabaseType::MySQL => MYSQL_PARAMETERS.to_vec(),
+        DatabaseType::Redis => REDIS_PARAMETERS.to_vec(),
+        DatabaseType::MongoDB => MONGODB_PARAMETERS.iter().map(|(name, _)| *name).collect(),
+        DatabaseType::SQLServer => SQLSERVER_PARAMETERS.iter().map(|(name, _)| *name).collect(),
+        DatabaseType::CockroachDB => {
+            COCKROACHDB_PARAMETERS.iter().map(|(name, _)| *name).collect()
+        }
+        // No server to start with flags; PRAGMAs only last for one connection
+        DatabaseType::SQLite => Vec::new(),
+    }
+}
+
+/// Translate tuning parameters into the engine's startup arguments and environment
+///
+/// Parameters are applied in name order so the resulting command is stable.
+pub fn tuning_settings(
+    db_type: DatabaseType,
+    tuning: &HashMap<String, TuningValue>,
+) -> TuningSettings {
+    let mut names: Vec<&String> = tuning.keys().collect();
+    names.sort();
+
+    let mut settings = TuningSettings::default();
+    for name in names {
+        let value = &tuning[name];
+        match db_type {
+            DatabaseType::Postgres if POSTGRES_PARAMETERS.contains(&name.as_str()) => {
+                settings.args.push("-c".to_string());
+                settings
+                    .args
+                    .push(format!("{}={}", name, value.render("on", "off")));
+            }
+            DatabaseType::MySQL if MYSQL_PARAMETERS.contains(&name.as_str()) => {
+                settings
+                    .args
+                    .push(format!("--{}={}", name, value.render("ON", "OFF")));
+            }
+            DatabaseType::Redis if REDIS_PARAMETERS.contains(&name.as_str()) => {
+                settings.args.push(format!("--{}", name.replace('_', "-")));
+                settings.args.push(value.render("yes", "no"));
+            }
+            DatabaseType::MongoDB => match lookup(MONGODB_PARAMETERS, name) {
+                Some(flag) => {
+                    settings.args.push(flag.to_string());
+                    settings.args.push(value.render("true", "false"));
+                }
+                None => settings.unknown.push(name.clone()),
+            },
+            DatabaseType::CockroachDB => match lookup(COCKROACHDB_PARAMETERS, name) {
+                Some(flag) => {
+                    settings.args.push(flag.to_string());
+                    settings.args.push(value.render("true", "false"));
+                }
+                None => settings.unknown.push(name.clone()),
+            },
+            DatabaseType::SQLServer => match lookup(SQLSERVER_PARAMETERS, name) {
+                Some(var) => {
+                    settings
+                        .env
+                        .insert(var.to_string(), value.render("true", "false"));
+                }
+                None => settings.unknown.push(name.clone()),
+            },
+            _ => settings.unknown.push(name.clone()),
+        }
+    }
+
+    settings
+}
+
+fn lookup(table: &[(&str, &'static str)], name: &str) -> Option<&'static str> {
+    table
+        .iter()
+        .find(|(parameter, _)| *parameter == name)
+        .map(|(_, target)| *target)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tuning(entries: &[(&str, TuningValue)]) -> HashMap<String, TuningValue> {
+        entries
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn test_postgres_tuning_flags() {
+        let settings = tuning_settings(
+            DatabaseType::Postgres,
+            &tuning(&[
+                ("shared_buffers", TuningValue::Text("512MB".to_string())),
+                ("max_connections", TuningValue::Integer(200)),
+                ("fsync", TuningValue::Bool(false)),
+                ("shared_bufers", TuningValue::Text("1GB".to_string())),
+            ]),
+        );
+        assert_eq!(
+            settings.args,
+            [
+                "-c",
+                "fsync=off",
+                "-c",
+                "max_connections=200",
+                "-c",
+                "shared_buffers=512MB"
+            ]
+        );
+        assert_eq!(settings.unknown, ["shared_bufers"]);
+    }
+
+    #[test]
+    fn test_mysql_and_redis_tuning_flags() {
+        let mysql = tuning_settings(
+            DatabaseType::MySQL,
+            &tuning(&[("innodb_buffer_pool_size", TuningValue::Text("1G".to_string()))]),
+        );
+        assert_eq!(mysql.args, ["--innodb_buffer_pool_size=1G"]);
+
+        let redis = tuning_settings(
+            DatabaseType::Redis,
+            &tuning(&[("maxmemory_policy", TuningValue::Text("allkeys-lru".to_string()))]),
+        );
+        assert_eq!(redis.args, ["--maxmemory-policy", "allkeys-lru"]);
+    }
+
+    #[test]
+    fn test_sqlserver_tuning_uses_env() {
+        let settings = tuning_settings(
+            DatabaseType::SQLServer,
+            &tuning(&[
+                ("memory_limit_mb", TuningValue::Integer(2048)),
+                ("max_connections", TuningValue::Integer(10)),
+            ]),
+        );
+        assert!(settings.args.is_empty());
+        assert_eq!(settings.env["MSSQL_MEMORY_LIMIT_MB"], "2048");
+        assert_eq!(settings.unknown, ["max_connections"]);
+    }
+}
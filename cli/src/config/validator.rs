@@ -1,4 +1,6 @@
 use super::schema::{DBArenaConfig, InitScript};
+use super::tuning::{known_parameters, tuning_settings};
+use crate::container::DatabaseType;
 use crate::error::{DBArenaError, Result};
 use std::collections::HashMap;
 use std::path::Path;
@@ -109,6 +111,19 @@ fn validate_databases(config: &DBArenaConfig, result: &mut ValidationResult) {
             }
         }
 
+        // Unknown tuning parameters are skipped at create time, so flag them here
+        if let Some(db_type) = DatabaseType::from_string(db_name) {
+            for name in tuning_settings(db_type, &db_config.tuning).unknown {
+                result.add_warning(format!(
+                    "{}.tuning.{} is not a known {} parameter and will be ignored (known: {})",
+                    prefix,
+                    name,
+                    db_type,
+                    known_parameters(db_type).join(", ")
+                ));
+            }
+        }
+
         // Validate init scripts (paths will be validated later when actually used)
         for (idx, script) in db_config.init_scripts.iter().enumerate() {
             validate_init_script(script, &format!("{}.init_scripts[{}]", prefix, idx), result);
@@ -263,6 +278,19 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_validate_unknown_tuning_parameter() {
+        let toml = r#"
+            [databases.postgres.tuning]
+            shared_buffers = "512MB"
+            innodb_buffer_pool_size = "1G"
+        "#;
+        let config: DBArenaConfig = toml::from_str(toml).unwrap();
+        let result = validate_config(&config).unwrap();
+        assert_eq!(result.warnings.len(), 1);
+        assert!(result.warnings[0].contains("databases.postgres.tuning.innodb_buffer_pool_size"));
+    }
+
     #[test]
     fn test_validate_valid_config() {
         let toml = r#"
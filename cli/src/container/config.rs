@@ -3,11 +3,15 @@ use std::collections::HashMap;
 use std::path::PathBuf;
 
 use super::volume::VolumeMount;
+use crate::{DBArenaError, Result};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ContainerConfig {
     pub database: DatabaseType,
     pub version: String,
+    /// Image overriding the official one for this database (see `image_ref`)
+    #[serde(default)]
+    pub image: Option<String>,
     pub name: Option<String>,
     pub port: Option<u16>,
     pub persistent: bool,
@@ -22,17 +26,207 @@ pub struct ContainerConfig {
     /// Continue creating container even if init scripts fail
     #[serde(default)]
     pub continue_on_error: bool,
+    /// Run all init scripts in one transaction, rolling back on the first failure
+    #[serde(default)]
+    pub atomic_init: bool,
     /// Volume mounts for the container
     #[serde(default)]
     pub volumes: Vec<VolumeMount>,
+    /// User the container runs as (`user`, `uid` or `uid:gid`); image default if unset
+    #[serde(default)]
+    pub user: Option<String>,
+    /// Working directory inside the container; image default if unset
+    #[serde(default)]
+    pub workdir: Option<String>,
+    /// Linux capabilities to add, e.g. `NET_ADMIN` for network chaos
+    #[serde(default)]
+    pub cap_add: Vec<String>,
+    /// Linux capabilities to drop
+    #[serde(default)]
+    pub cap_drop: Vec<String>,
+    /// Docker security options, e.g. `seccomp=unconfined`
+    #[serde(default)]
+    pub security_opt: Vec<String>,
+    /// Resource limits overriding the database's defaults (see `effective_ulimits`)
+    #[serde(default)]
+    pub ulimits: Vec<Ulimit>,
+    /// In-memory mounts (container path -> mount options); contents are lost on stop
+    #[serde(default)]
+    pub tmpfs: HashMap<String, String>,
+    /// Command overriding the image's default `CMD`
+    #[serde(default)]
+    pub command: Option<Vec<String>>,
+    /// Arguments appended to the image's default `CMD` (ignored when `command` is set)
+    #[serde(default)]
+    pub command_args: Vec<String>,
+    /// Extra labels recorded on the container alongside dbarena's own
+    #[serde(default)]
+    pub labels: HashMap<String, String>,
+}
+
+/// Capability names Docker accepts for `--cap-add`/`--cap-drop` (without the `CAP_` prefix)
+pub const KNOWN_CAPABILITIES: &[&str] = &[
+    "ALL",
+    "AUDIT_CONTROL",
+    "AUDIT_READ",
+    "AUDIT_WRITE",
+    "BLOCK_SUSPEND",
+    "BPF",
+    "CHECKPOINT_RESTORE",
+    "CHOWN",
+    "DAC_OVERRIDE",
+    "DAC_READ_SEARCH",
+    "FOWNER",
+    "FSETID",
+    "IPC_LOCK",
+    "IPC_OWNER",
+    "KILL",
+    "LEASE",
+    "LINUX_IMMUTABLE",
+    "MAC_ADMIN",
+    "MAC_OVERRIDE",
+    "MKNOD",
+    "NET_ADMIN",
+    "NET_BIND_SERVICE",
+    "NET_BROADCAST",
+    "NET_RAW",
+    "PERFMON",
+    "SETFCAP",
+    "SETGID",
+    "SETPCAP",
+    "SETUID",
+    "SYS_ADMIN",
+    "SYS_BOOT",
+    "SYS_CHROOT",
+    "SYS_MODULE",
+    "SYS_NICE",
+    "SYS_PACCT",
+    "SYS_PTRACE",
+    "SYS_RAWIO",
+    "SYS_RESOURCE",
+    "SYS_TIME",
+    "SYS_TTY_CONFIG",
+    "SYSLOG",
+    "WAKE_ALARM",
+];
+
+/// Resource names Docker accepts for `--ulimit`
+const ULIMIT_NAMES: &[&str] = &[
+    "core",
+    "cpu",
+    "data",
+    "fsize",
+    "locks",
+    "memlock",
+    "msgqueue",
+    "nice",
+    "nofile",
+    "nproc",
+    "rss",
+    "rtprio",
+    "rttime",
+    "sigpending",
+    "stack",
+];
+
+/// A resource limit for the container's processes, e.g. `nofile=65536:65536`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Ulimit {
+    pub name: String,
+    /// Soft limit; -1 is unlimited
+    pub soft: i64,
+    /// Hard limit; -1 is unlimited
+    pub hard: i64,
+}
+
+impl Ulimit {
+    pub fn new(name: &str, soft: i64, hard: i64) -> Self {
+        Self {
+            name: name.to_string(),
+            soft,
+            hard,
+        }
+    }
+
+    /// Parse `name=soft[:hard]`; a single value sets both limits and
+    /// `unlimited` (or -1) lifts the limit
+    pub fn parse(spec: &str) -> Result<Self> {
+        let invalid = |reason: &str| {
+            DBArenaError::InvalidConfig(format!("Invalid ulimit '{}': {}", spec, reason))
+        };
+
+        let (name, limits) = spec
+            .split_once('=')
+            .ok_or_else(|| invalid("expected name=soft[:hard], e.g. nofile=65536:65536"))?;
+        if !ULIMIT_NAMES.contains(&name) {
+            return Err(invalid(&format!(
+                "unknown resource, expected one of {}",
+                ULIMIT_NAMES.join(", ")
+            )));
+        }
+
+        let value = |v: &str| match v {
+            "unlimited" | "-1" => Ok(-1),
+            _ => v
+                .parse::<i64>()
+                .ok()
+                .filter(|n| *n >= 0)
+                .ok_or_else(|| invalid(&format!("'{}' is not a limit", v))),
+        };
+        let (soft, hard) = match limits.split_once(':') {
+            Some((soft, hard)) => (value(soft)?, value(hard)?),
+            None => (value(limits)?, value(limits)?),
+        };
+        if hard != -1 && (soft == -1 || soft > hard) {
+            return Err(invalid("soft limit is above the hard limit"));
+        }
+
+        Ok(Self::new(name, soft, hard))
+    }
 }
 
+impl std::fmt::Display for Ulimit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}={}:{}", self.name, self.soft, self.hard)
+    }
+}
+
+/// Parse a `--tmpfs` spec, `path[:options]` as with `docker run --tmpfs`
+///
+/// Returns the container path and its mount options (`rw` when none are given).
+pub fn parse_tmpfs(spec: &str) -> Result<(String, String)> {
+    let (path, options) = spec.split_once(':').unwrap_or((spec, "rw"));
+    if !path.starts_with('/') {
+        return Err(DBArenaError::InvalidConfig(format!(
+            "Invalid tmpfs '{}': the path must be absolute, e.g. /var/lib/postgresql/data:size=1g",
+            spec
+        )));
+    }
+    let options = if options.is_empty() { "rw" } else { options };
+    Ok((path.to_string(), options.to_string()))
+}
+
+/// Whether `name` is a known capability, case-insensitive and with or without `CAP_`
+pub fn is_known_capability(name: &str) -> bool {
+    let name = name.to_uppercase();
+    let name = name.strip_prefix("CAP_").unwrap_or(&name);
+    KNOWN_CAPABILITIES.contains(&name)
+}
+
+/// Database file of a SQLite container, kept in its managed data volume
+pub const SQLITE_DB_PATH: &str = "/data/db.sqlite";
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "lowercase")]
 pub enum DatabaseType {
     Postgres,
     MySQL,
     SQLServer,
+    Redis,
+    MongoDB,
+    CockroachDB,
+    /// Embedded database: a file in a volume, queried with `sqlite3`, no server
+    SQLite,
 }
 
 impl DatabaseType {
@@ -41,6 +235,10 @@ impl DatabaseType {
             "postgres" | "postgresql" | "pg" => Some(DatabaseType::Postgres),
             "mysql" | "mariadb" => Some(DatabaseType::MySQL),
             "sqlserver" | "mssql" | "sql-server" => Some(DatabaseType::SQLServer),
+            "redis" => Some(DatabaseType::Redis),
+            "mongodb" | "mongo" => Some(DatabaseType::MongoDB),
+            "cockroachdb" | "cockroach" | "crdb" => Some(DatabaseType::CockroachDB),
+            "sqlite" | "sqlite3" => Some(DatabaseType::SQLite),
             _ => None,
         }
     }
@@ -50,6 +248,10 @@ impl DatabaseType {
             DatabaseType::Postgres => "16",
             DatabaseType::MySQL => "8.0",
             DatabaseType::SQLServer => "2022-latest",
+            DatabaseType::Redis => "7",
+            DatabaseType::MongoDB => "7",
+            DatabaseType::CockroachDB => "latest-v24.1",
+            DatabaseType::SQLite => "latest",
         }
     }
 
@@ -60,14 +262,23 @@ impl DatabaseType {
             DatabaseType::SQLServer => {
                 format!("mcr.microsoft.com/mssql/server:{}", version)
             }
+            DatabaseType::Redis => format!("redis:{}", version),
+            DatabaseType::MongoDB => format!("mongo:{}", version),
+            DatabaseType::CockroachDB => format!("cockroachdb/cockroach:{}", version),
+            DatabaseType::SQLite => format!("keinos/sqlite3:{}", version),
         }
     }
 
+    /// Port the server listens on; 0 for SQLite, which has no server
     pub fn default_port(&self) -> u16 {
         match self {
             DatabaseType::Postgres => 5432,
             DatabaseType::MySQL => 3306,
             DatabaseType::SQLServer => 1433,
+            DatabaseType::Redis => 6379,
+            DatabaseType::MongoDB => 27017,
+            DatabaseType::CockroachDB => 26257,
+            DatabaseType::SQLite => 0,
         }
     }
 
@@ -76,8 +287,88 @@ impl DatabaseType {
             DatabaseType::Postgres => "postgres",
             DatabaseType::MySQL => "mysql",
             DatabaseType::SQLServer => "sqlserver",
+            DatabaseType::Redis => "redis",
+            DatabaseType::MongoDB => "mongodb",
+            DatabaseType::CockroachDB => "cockroachdb",
+            DatabaseType::SQLite => "sqlite",
+        }
+    }
+
+    /// Directory the official image keeps its data in
+    pub fn data_dir(&self) -> &'static str {
+        match self {
+            DatabaseType::Postgres => "/var/lib/postgresql/data",
+            DatabaseType::MySQL => "/var/lib/mysql",
+            DatabaseType::SQLServer => "/var/opt/mssql",
+            DatabaseType::Redis => "/data",
+            DatabaseType::MongoDB => "/data/db",
+            DatabaseType::CockroachDB => "/cockroach/cockroach-data",
+            DatabaseType::SQLite => "/data",
+        }
+    }
+
+    /// Command to run when the image has no usable default
+    ///
+    /// The CockroachDB image only wraps the `cockroach` binary, so it has to
+    /// be told to start a node. The SQLite image has no server at all: the
+    /// container creates the database file and then idles so `sqlite3` can be
+    /// exec'd against it.
+    pub fn default_command(&self) -> Option<Vec<String>> {
+        match self {
+            DatabaseType::CockroachDB => Some(vec![
+                "start-single-node".to_string(),
+                "--insecure".to_string(),
+            ]),
+            DatabaseType::SQLite => Some(vec![
+                "sh".to_string(),
+                "-c".to_string(),
+                format!(
+                    "sqlite3 {} 'PRAGMA journal_mode=WAL;' > /dev/null && \
+                     trap 'exit 0' TERM INT; while :; do sleep 1; done",
+                    SQLITE_DB_PATH
+                ),
+            ]),
+            _ => None,
+        }
+    }
+
+    /// User to run as when none is configured
+    ///
+    /// The SQLite image defaults to an unprivileged user that can't write to
+    /// the root-owned data volume.
+    pub fn default_user(&self) -> Option<&'static str> {
+        match self {
+            DatabaseType::SQLite => Some("root"),
+            _ => None,
         }
     }
+
+    /// Ulimits every container of this type gets unless overridden
+    ///
+    /// Docker's default open-file limit is easy to exhaust with many
+    /// connections or tables; MongoDB also wants a high process limit.
+    pub fn default_ulimits(&self) -> Vec<Ulimit> {
+        match self {
+            DatabaseType::MongoDB => vec![
+                Ulimit::new("nofile", 64000, 64000),
+                Ulimit::new("nproc", 64000, 64000),
+            ],
+            _ => vec![Ulimit::new("nofile", 65536, 65536)],
+        }
+    }
+
+    /// Whether the database speaks SQL (Redis and MongoDB do not)
+    pub fn is_sql(&self) -> bool {
+        !matches!(self, DatabaseType::Redis | DatabaseType::MongoDB)
+    }
+
+    /// Whether the database runs embedded rather than as a network server
+    ///
+    /// Embedded databases (SQLite) publish no port, so connection strings,
+    /// networks, replicas and server metrics don't apply to them.
+    pub fn is_embedded(&self) -> bool {
+        matches!(self, DatabaseType::SQLite)
+    }
 }
 
 impl std::fmt::Display for DatabaseType {
@@ -91,6 +382,7 @@ impl ContainerConfig {
         Self {
             database,
             version: database.default_version().to_string(),
+            image: None,
             name: None,
             port: None,
             persistent: false,
@@ -99,7 +391,18 @@ impl ContainerConfig {
             env_vars: HashMap::new(),
             init_scripts: Vec::new(),
             continue_on_error: false,
+            atomic_init: false,
             volumes: Vec::new(),
+            user: None,
+            workdir: None,
+            cap_add: Vec::new(),
+            cap_drop: Vec::new(),
+            security_opt: Vec::new(),
+            ulimits: Vec::new(),
+            tmpfs: HashMap::new(),
+            command: None,
+            command_args: Vec::new(),
+            labels: HashMap::new(),
         }
     }
 
@@ -108,6 +411,29 @@ impl ContainerConfig {
         self
     }
 
+    pub fn with_image(mut self, image: String) -> Self {
+        self.image = Some(image);
+        self
+    }
+
+    /// Full image reference to run
+    ///
+    /// A custom image gets `:<version>` appended unless it already carries a
+    /// tag or digest; otherwise the official image for the database is used.
+    pub fn image_ref(&self) -> String {
+        match &self.image {
+            Some(image) => {
+                let last_segment = image.rsplit('/').next().unwrap_or(image);
+                if last_segment.contains(':') || image.contains('@') {
+                    image.clone()
+                } else {
+                    format!("{}:{}", image, self.version)
+                }
+            }
+            None => self.database.docker_image(&self.version),
+        }
+    }
+
     pub fn with_name(mut self, name: String) -> Self {
         self.name = Some(name);
         self
@@ -158,6 +484,11 @@ impl ContainerConfig {
         self
     }
 
+    pub fn with_atomic_init(mut self, atomic_init: bool) -> Self {
+        self.atomic_init = atomic_init;
+        self
+    }
+
     pub fn with_volumes(mut self, volumes: Vec<VolumeMount>) -> Self {
         self.volumes = volumes;
         self
@@ -167,4 +498,66 @@ impl ContainerConfig {
         self.volumes.push(volume);
         self
     }
+
+    pub fn with_user(mut self, user: String) -> Self {
+        self.user = Some(user);
+        self
+    }
+
+    pub fn with_workdir(mut self, workdir: String) -> Self {
+        self.workdir = Some(workdir);
+        self
+    }
+
+    pub fn with_cap_add(mut self, caps: Vec<String>) -> Self {
+        self.cap_add = caps;
+        self
+    }
+
+    pub fn with_cap_drop(mut self, caps: Vec<String>) -> Self {
+        self.cap_drop = caps;
+        self
+    }
+
+    pub fn with_security_opt(mut self, opts: Vec<String>) -> Self {
+        self.security_opt = opts;
+        self
+    }
+
+    pub fn with_ulimits(mut self, ulimits: Vec<Ulimit>) -> Self {
+        self.ulimits = ulimits;
+        self
+    }
+
+    /// The database's default ulimits with `ulimits` applied on top, by name
+    pub fn effective_ulimits(&self) -> Vec<Ulimit> {
+        let mut ulimits = self.database.default_ulimits();
+        for ulimit in &self.ulimits {
+            match ulimits.iter_mut().find(|u| u.name == ulimit.name) {
+                Some(existing) => *existing = ulimit.clone(),
+                None => ulimits.push(ulimit.clone()),
+            }
+        }
+        ulimits
+    }
+
+    pub fn with_tmpfs(mut self, path: String, options: String) -> Self {
+        self.tmpfs.insert(path, options);
+        self
+    }
+
+    pub fn with_command(mut self, command: Vec<String>) -> Self {
+        self.command = Some(command);
+        self
+    }
+
+    pub fn with_command_args(mut self, args: Vec<String>) -> Self {
+        self.command_args = args;
+        self
+    }
+
+    pub fn with_label(mut self, key: String, value: String) -> Self {
+        self.labels.insert(key, value);
+        self
+    }
 }
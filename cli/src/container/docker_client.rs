@@ -1,28 +1,200 @@
+use super::pull::{classify_pull_error, LayerTracker, PullFailure, PullOptions};
+use crate::progress::{NoProgress, Progress};
+use crate::util::backoff::Backoff;
 use crate::{DBArenaError, Result};
 use bollard::image::{CreateImageOptions, ListImagesOptions};
 use bollard::models::ImageSummary;
 use bollard::Docker;
 use futures::StreamExt;
-use tracing::{debug, info};
+use std::path::PathBuf;
+use std::sync::OnceLock;
+use std::time::Duration;
+use tracing::{debug, info, warn};
+
+/// Timeout for Docker API requests, in seconds (bollard's default)
+const DEFAULT_TIMEOUT_SECS: u64 = 120;
+
+/// Endpoint description used when no host is configured
+const LOCAL_ENDPOINT: &str = "local default socket";
+
+/// Delay before the first connection retry; doubled for each following one
+const DEFAULT_RETRY_DELAY: Duration = Duration::from_millis(500);
+
+/// Host set via `--docker-host` or the `[docker]` config section
+static HOST_OVERRIDE: OnceLock<String> = OnceLock::new();
+
+/// Connection attempts set via `--docker-retry`
+static CONNECT_ATTEMPTS: OnceLock<u32> = OnceLock::new();
 
 #[derive(Clone)]
 pub struct DockerClient {
     docker: Docker,
+    endpoint: String,
 }
 
 impl DockerClient {
     pub fn new() -> Result<Self> {
-        let docker =
-            Docker::connect_with_local_defaults().map_err(|_| DBArenaError::DockerNotAvailable)?;
-        Ok(Self { docker })
+        Self::from_env()
+    }
+
+    /// Connect using the configured host override, then `DOCKER_HOST`
+    /// (with `DOCKER_TLS_VERIFY` / `DOCKER_CERT_PATH`), then local defaults
+    pub fn from_env() -> Result<Self> {
+        let host = HOST_OVERRIDE.get().cloned().or_else(|| {
+            std::env::var("DOCKER_HOST")
+                .ok()
+                .filter(|h| !h.trim().is_empty())
+        });
+
+        match host {
+            Some(host) => Self::connect(&host),
+            None => {
+                let docker = Docker::connect_with_local_defaults()
+                    .map_err(|e| connection_error(LOCAL_ENDPOINT, e))?;
+                Ok(Self {
+                    docker,
+                    endpoint: LOCAL_ENDPOINT.to_string(),
+                })
+            }
+        }
+    }
+
+    /// Connect and ping the daemon, retrying with exponential backoff and jitter
+    ///
+    /// For daemons that are still starting (e.g. fresh CI runners). Fails with
+    /// `DockerNotAvailable` once `max_attempts` pings have failed; use `new`
+    /// to fail fast.
+    pub async fn new_with_retry(max_attempts: u32, base_delay: Duration) -> Result<Self> {
+        let client = Self::from_env()?;
+        client.ping_with_retry(max_attempts, base_delay).await?;
+        Ok(client)
+    }
+
+    /// Connect to an explicit Docker host (unix://, tcp://, http://, https://)
+    pub fn connect(host: &str) -> Result<Self> {
+        debug!("Connecting to Docker at {}", host);
+
+        let docker = if let Some(path) = host.strip_prefix("unix://") {
+            Docker::connect_with_unix(path, DEFAULT_TIMEOUT_SECS, bollard::API_DEFAULT_VERSION)
+        } else if host.starts_with("tcp://")
+            || host.starts_with("http://")
+            || host.starts_with("https://")
+        {
+            if tls_enabled(host) {
+                let cert_path = cert_path();
+                Docker::connect_with_ssl(
+                    host,
+                    &cert_path.join("key.pem"),
+                    &cert_path.join("cert.pem"),
+                    &cert_path.join("ca.pem"),
+                    DEFAULT_TIMEOUT_SECS,
+                    bollard::API_DEFAULT_VERSION,
+                )
+            } else {
+                Docker::connect_with_http(host, DEFAULT_TIMEOUT_SECS, bollard::API_DEFAULT_VERSION)
+            }
+        } else {
+            return Err(DBArenaError::DockerConnectionFailed(
+                host.to_string(),
+                "unsupported scheme (use unix://, tcp://, http://, or https://)".to_string(),
+            ));
+        }
+        .map_err(|e| connection_error(host, e))?;
+
+        Ok(Self {
+            docker,
+            endpoint: host.to_string(),
+        })
+    }
+
+    /// Set the host used by all clients created afterwards
+    ///
+    /// Takes precedence over `DOCKER_HOST`. Only the first call has an effect.
+    pub fn set_default_host(host: String) {
+        let _ = HOST_OVERRIDE.set(host);
     }
 
+    /// Set how many times `verify_connection` pings before giving up
+    ///
+    /// Only the first call has an effect.
+    pub fn set_default_retry(attempts: u32) {
+        let _ = CONNECT_ATTEMPTS.set(attempts);
+    }
+
+    /// The endpoint this client connects to
+    pub fn endpoint(&self) -> &str {
+        &self.endpoint
+    }
+
+    /// Whether the daemon runs on this machine, so its published ports are local
+    pub fn is_local(&self) -> bool {
+        self.endpoint == LOCAL_ENDPOINT || self.endpoint.starts_with("unix://")
+    }
+
+    /// Host that published container ports are reached on: `localhost` for a
+    /// local daemon, otherwise the endpoint's host (`build-host` for
+    /// `tcp://build-host:2376`)
+    pub fn published_host(&self) -> String {
+        if self.is_local() {
+            return "localhost".to_string();
+        }
+        let authority = self
+            .endpoint
+            .split_once("://")
+            .map_or(self.endpoint.as_str(), |(_, rest)| rest);
+        let authority = authority.split('/').next().unwrap_or(authority);
+        match authority.rsplit_once(':') {
+            Some((host, port)) if !host.is_empty() && port.bytes().all(|b| b.is_ascii_digit()) => {
+                host.to_string()
+            }
+            _ => authority.to_string(),
+        }
+    }
+
+    /// Ping the daemon, retrying if `--docker-retry` asked for more than one attempt
     pub async fn verify_connection(&self) -> Result<()> {
-        self.docker
-            .ping()
-            .await
-            .map_err(|_| DBArenaError::DockerNotAvailable)?;
-        Ok(())
+        match CONNECT_ATTEMPTS.get() {
+            Some(&attempts) if attempts > 1 => {
+                self.ping_with_retry(attempts, DEFAULT_RETRY_DELAY).await
+            }
+            _ => {
+                self.docker
+                    .ping()
+                    .await
+                    .map_err(|e| connection_error(&self.endpoint, e))?;
+                Ok(())
+            }
+        }
+    }
+
+    async fn ping_with_retry(&self, max_attempts: u32, base_delay: Duration) -> Result<()> {
+        let max_attempts = max_attempts.max(1);
+
+        for attempt in 1..=max_attempts {
+            match self.docker.ping().await {
+                Ok(_) => return Ok(()),
+                Err(e) if attempt < max_attempts => {
+                    let delay = retry_delay(base_delay, attempt);
+                    warn!(
+                        "Docker at {} not reachable (attempt {}/{}): {}; retrying in {:.1}s",
+                        self.endpoint,
+                        attempt,
+                        max_attempts,
+                        e,
+                        delay.as_secs_f64()
+                    );
+                    tokio::time::sleep(delay).await;
+                }
+                Err(e) => {
+                    warn!(
+                        "Giving up on Docker at {} after {} attempts: {}",
+                        self.endpoint, max_attempts, e
+                    );
+                }
+            }
+        }
+
+        Err(DBArenaError::DockerNotAvailable)
     }
 
     pub fn docker(&self) -> &Docker {
@@ -41,8 +213,94 @@ impl DockerClient {
     }
 
     pub async fn pull_image(&self, image_name: &str) -> Result<()> {
+        self.pull_image_with(image_name, &PullOptions::default(), &NoProgress)
+            .await
+    }
+
+    /// Pull an image, retrying transient failures
+    ///
+    /// Layers finished by an earlier attempt are kept by the daemon, so a retry
+    /// only downloads what is missing. A missing image or tag fails immediately
+    /// with `ImageNotFound`.
+    pub async fn pull_image_with(
+        &self,
+        image_name: &str,
+        options: &PullOptions,
+        progress: &dyn Progress,
+    ) -> Result<()> {
         info!("Pulling image: {}", image_name);
+        progress.start(&format!("Pulling {}...", image_name), None);
+
+        let mut layers = LayerTracker::new();
+        let attempts = options.attempts.max(1);
+        let mut attempt = 1;
+
+        loop {
+            let result = self.pull_attempt(image_name, &mut layers, progress).await;
+            let (status_code, message) = match result {
+                Ok(()) => {
+                    progress.finish(&format!("Pulled {} ({})", image_name, layers.summary()));
+                    info!("Successfully pulled image: {}", image_name);
+                    return Ok(());
+                }
+                Err(failure) => failure,
+            };
+
+            match classify_pull_error(status_code, &message) {
+                PullFailure::NotFound => {
+                    progress.finish(&format!("Image {} not found", image_name));
+                    return Err(DBArenaError::ImageNotFound(format!(
+                        "{} ({})",
+                        image_name, message
+                    )));
+                }
+                PullFailure::Rejected => {
+                    progress.finish(&format!("Pull of {} was rejected", image_name));
+                    return Err(DBArenaError::ImagePullFailed(format!(
+                        "{}: {}",
+                        image_name, message
+                    )));
+                }
+                PullFailure::Transient if attempt >= attempts => {
+                    progress.finish(&format!("Failed to pull {}", image_name));
+                    return Err(DBArenaError::ImagePullFailed(format!(
+                        "{} after {} attempt(s), network or registry error: {}",
+                        image_name, attempt, message
+                    )));
+                }
+                PullFailure::Transient => {
+                    let delay = options.delay_for(attempt);
+                    warn!(
+                        "Pull of {} failed (attempt {}/{}): {}; retrying in {}s",
+                        image_name,
+                        attempt,
+                        attempts,
+                        message,
+                        delay.as_secs()
+                    );
+                    progress.update(
+                        layers.completed() as u64,
+                        Some(&format!(
+                            "Pull interrupted ({}); resuming in {}s with {}",
+                            message,
+                            delay.as_secs(),
+                            layers.summary()
+                        )),
+                    );
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+            }
+        }
+    }
 
+    /// Run one pull, returning the HTTP status (if any) and message on failure
+    async fn pull_attempt(
+        &self,
+        image_name: &str,
+        layers: &mut LayerTracker,
+        progress: &dyn Progress,
+    ) -> std::result::Result<(), (Option<u16>, String)> {
         let options = Some(CreateImageOptions {
             from_image: image_name,
             ..Default::default()
@@ -53,18 +311,26 @@ impl DockerClient {
         while let Some(result) = stream.next().await {
             match result {
                 Ok(info) => {
-                    if let Some(status) = info.status {
-                        debug!("Image pull: {}", status);
-                    }
                     if let Some(error) = info.error {
-                        return Err(DBArenaError::ImagePullFailed(error));
+                        return Err((None, error));
+                    }
+                    if let Some(status) = &info.status {
+                        debug!("Image pull: {}", status);
                     }
+                    layers.observe(&info);
+                    progress.update(
+                        layers.completed() as u64,
+                        Some(&format!("Pulling {}: {}", image_name, layers.summary())),
+                    );
                 }
-                Err(e) => return Err(DBArenaError::DockerError(e)),
+                Err(bollard::errors::Error::DockerResponseServerError {
+                    status_code,
+                    message,
+                }) => return Err((Some(status_code), message)),
+                Err(e) => return Err((None, e.to_string())),
             }
         }
 
-        info!("Successfully pulled image: {}", image_name);
         Ok(())
     }
 
@@ -83,6 +349,38 @@ impl DockerClient {
     }
 }
 
+/// Backoff before retry number `attempt` (1-based): `base * 2^(attempt-1)`,
+/// capped at `base * 1024`, with ±25% jitter so parallel runners don't ping in
+/// lockstep
+fn retry_delay(base: Duration, attempt: u32) -> Duration {
+    Backoff::exponential(base)
+        .with_max(base * 1024)
+        .with_jitter(0.25)
+        .jittered_delay(attempt)
+}
+
+/// Whether to use TLS for a TCP host
+fn tls_enabled(host: &str) -> bool {
+    host.starts_with("https://")
+        || std::env::var("DOCKER_TLS_VERIFY")
+            .map(|v| !v.is_empty() && v != "0")
+            .unwrap_or(false)
+}
+
+/// Directory holding key.pem, cert.pem, and ca.pem (`DOCKER_CERT_PATH` or ~/.docker)
+fn cert_path() -> PathBuf {
+    std::env::var("DOCKER_CERT_PATH")
+        .ok()
+        .filter(|p| !p.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| dirs::home_dir().map(|home| home.join(".docker")))
+        .unwrap_or_else(|| PathBuf::from(".docker"))
+}
+
+fn connection_error(endpoint: &str, error: bollard::errors::Error) -> DBArenaError {
+    DBArenaError::DockerConnectionFailed(endpoint.to_string(), error.to_string())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -109,4 +407,54 @@ mod tests {
         }
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn test_connect_rejects_unknown_scheme() {
+        let err = DockerClient::connect("ftp://example.com:2375").err().unwrap();
+        assert!(err.to_string().contains("ftp://example.com:2375"));
+    }
+
+    #[test]
+    fn test_is_local() {
+        assert!(DockerClient::connect("unix:///var/run/docker.sock")
+            .unwrap()
+            .is_local());
+        assert!(!DockerClient::connect("tcp://10.0.0.5:2375")
+            .unwrap()
+            .is_local());
+    }
+
+    #[test]
+    fn test_published_host() {
+        let host = |endpoint: &str| DockerClient::connect(endpoint).unwrap().published_host();
+        assert_eq!(host("unix:///var/run/docker.sock"), "localhost");
+        assert_eq!(host("tcp://build-host:2376"), "build-host");
+        assert_eq!(host("http://10.0.0.5"), "10.0.0.5");
+        assert_eq!(host("tcp://[fd00::5]:2375"), "[fd00::5]");
+    }
+
+    #[test]
+    fn test_retry_delay_backs_off_with_jitter() {
+        let base = Duration::from_millis(100);
+        for attempt in 1..=4 {
+            let backoff = base * 2u32.pow(attempt - 1);
+            let delay = retry_delay(base, attempt);
+            assert!(delay >= backoff.mul_f64(0.75) && delay <= backoff.mul_f64(1.25));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_new_with_retry_gives_up() {
+        let client = DockerClient::connect("tcp://127.0.0.1:1").unwrap();
+        let err = client
+            .ping_with_retry(2, Duration::from_millis(1))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, DBArenaError::DockerNotAvailable));
+    }
+
+    #[test]
+    fn test_tls_enabled_for_https() {
+        assert!(tls_enabled("https://docker.example.com:2376"));
+    }
 }
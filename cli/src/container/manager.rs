@@ -1,30 +1,146 @@
-use super::{Container, ContainerConfig, DockerClient};
+use super::{Container, ContainerConfig, DatabaseType, DockerClient, VolumeConfig, VolumeManager, VolumeMount};
 use crate::container::models::ContainerStatus;
+use crate::util::bounded::for_each_concurrent;
 use crate::Result;
 use bollard::container::{
-    Config, CreateContainerOptions, ListContainersOptions, RemoveContainerOptions,
-    StartContainerOptions, StopContainerOptions,
+    Config, CreateContainerOptions, ListContainersOptions, NetworkingConfig,
+    RemoveContainerOptions, RenameContainerOptions, StartContainerOptions, StopContainerOptions,
+    WaitContainerOptions,
 };
-use bollard::models::{ContainerSummary, HostConfig, PortBinding};
+use bollard::image::CommitContainerOptions;
+use bollard::models::{
+    ContainerInspectResponse, ContainerSummary, EndpointSettings, HostConfig, Mount, MountPoint,
+    MountPointTypeEnum, MountTypeEnum, PortBinding, ResourcesUlimits,
+};
+use bollard::network::ConnectNetworkOptions;
 use std::collections::HashMap;
-use tracing::{debug, info};
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+use tracing::{debug, info, warn};
 
 const DBARENA_LABEL: &str = "dbarena.managed";
+const CLONED_FROM_LABEL: &str = "dbarena.cloned_from";
+const CLONE_IMAGE_LABEL: &str = "dbarena.clone_image";
+const CLONE_IMAGE_REPO: &str = "dbarena-clone";
+const REBIND_IMAGE_REPO: &str = "dbarena-rebind";
+const HOST_PORT_LABEL: &str = "dbarena.host_port";
+const PERSISTENT_LABEL: &str = "dbarena.persistent";
+
+/// How long a container listing is reused before Docker is asked again
+pub const DEFAULT_LIST_CACHE_TTL: Duration = Duration::from_secs(2);
+
+/// TTL set via the `[docker]` config section
+static LIST_CACHE_TTL: OnceLock<Duration> = OnceLock::new();
+
+/// Cached listings, one slot for running-only and one for all containers
+#[derive(Default)]
+struct ListCache {
+    running: Option<(Instant, Vec<Container>)>,
+    all: Option<(Instant, Vec<Container>)>,
+}
+
+impl ListCache {
+    fn slot(&mut self, all: bool) -> &mut Option<(Instant, Vec<Container>)> {
+        if all {
+            &mut self.all
+        } else {
+            &mut self.running
+        }
+    }
+}
+
+/// What [`ContainerManager::destroy_container`] would remove, for `destroy --dry-run`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DestroyPlan {
+    /// Anonymous volumes removed along with the container (only when removing volumes)
+    pub removed_volumes: Vec<String>,
+    /// Volumes left behind: named volumes always, anonymous ones unless removing volumes
+    pub kept_volumes: Vec<String>,
+    /// Temporary image a clone runs from, removed along with it
+    pub clone_image: Option<String>,
+}
+
+impl DestroyPlan {
+    fn new(mounts: &[MountPoint], remove_volumes: bool, clone_image: Option<String>) -> Self {
+        let mut plan = Self {
+            clone_image,
+            ..Default::default()
+        };
+        for mount in mounts {
+            if mount.typ != Some(MountPointTypeEnum::VOLUME) {
+                continue;
+            }
+            let Some(name) = mount.name.clone() else {
+                continue;
+            };
+            // Docker's `v` option only removes anonymous volumes
+            if remove_volumes && !is_named_volume(Some(&name)) {
+                plan.removed_volumes.push(name);
+            } else {
+                plan.kept_volumes.push(name);
+            }
+        }
+        plan
+    }
+}
 
 pub struct ContainerManager {
     client: DockerClient,
+    cache_ttl: Duration,
+    cache: Mutex<ListCache>,
 }
 
 impl ContainerManager {
     pub fn new(client: DockerClient) -> Self {
-        Self { client }
+        Self {
+            client,
+            cache_ttl: LIST_CACHE_TTL
+                .get()
+                .copied()
+                .unwrap_or(DEFAULT_LIST_CACHE_TTL),
+            cache: Mutex::new(ListCache::default()),
+        }
+    }
+
+    /// Docker connection this manager works through
+    pub fn client(&self) -> &DockerClient {
+        &self.client
+    }
+
+    /// Set the listing cache TTL used by managers created after this call
+    ///
+    /// Only the first call takes effect.
+    pub fn set_default_cache_ttl(ttl: Duration) {
+        let _ = LIST_CACHE_TTL.set(ttl);
+    }
+
+    /// Override the listing cache TTL for this manager; zero disables caching
+    pub fn with_cache_ttl(mut self, ttl: Duration) -> Self {
+        self.cache_ttl = ttl;
+        self
+    }
+
+    /// Drop cached listings so the next lookup goes to Docker
+    ///
+    /// Called by every lifecycle method on this manager; changes made through
+    /// other managers or processes are picked up once the TTL expires.
+    pub fn invalidate_cache(&self) {
+        *self.cache.lock().unwrap() = ListCache::default();
     }
 
     pub async fn create_container(&self, config: ContainerConfig) -> Result<Container> {
         let name = self.generate_container_name(&config);
-        let port = config.port.unwrap_or_else(|| self.find_available_port());
 
-        let image = config.database.docker_image(&config.version);
+        // Docker would reject the name only after the image pull; fail early instead
+        if config.name.is_some() && self.name_in_use(&name).await? {
+            return Err(crate::DBArenaError::NameInUse { name });
+        }
+
+        // Embedded databases have no server, so nothing to publish
+        let port = (!config.database.is_embedded())
+            .then(|| config.port.unwrap_or_else(|| self.find_available_port()));
+
+        let image = config.image_ref();
 
         // Ensure the image exists
         self.client.ensure_image(&image).await?;
@@ -34,18 +150,19 @@ impl ContainerManager {
 
         // Build port bindings
         let container_port = config.database.default_port();
-        let mut port_bindings = HashMap::new();
-        port_bindings.insert(
-            format!("{}/tcp", container_port),
-            Some(vec![PortBinding {
-                host_ip: Some("0.0.0.0".to_string()),
-                host_port: Some(port.to_string()),
-            }]),
-        );
+        let port_bindings = port.map(|port| {
+            HashMap::from([(
+                format!("{}/tcp", container_port),
+                Some(vec![PortBinding {
+                    host_ip: Some("0.0.0.0".to_string()),
+                    host_port: Some(port.to_string()),
+                }]),
+            )])
+        });
 
         // Build host config with resource limits
         let mut host_config = HostConfig {
-            port_bindings: Some(port_bindings),
+            port_bindings,
             tmpfs: Some(HashMap::from([(
                 "/tmp".to_string(),
                 "rw,noexec,nosuid,size=256m".to_string(),
@@ -53,6 +170,21 @@ impl ContainerManager {
             ..Default::default()
         };
 
+        if let Some(tmpfs) = host_config.tmpfs.as_mut() {
+            tmpfs.extend(config.tmpfs.clone());
+        }
+
+        // The SQLite image declares no VOLUME, so give its data dir a managed
+        // (anonymous) one like the server images get
+        let data_dir = config.database.data_dir();
+        if config.database.is_embedded() && !config.tmpfs.contains_key(data_dir) {
+            host_config.mounts = Some(vec![Mount {
+                target: Some(data_dir.to_string()),
+                typ: Some(MountTypeEnum::VOLUME),
+                ..Default::default()
+            }]);
+        }
+
         if let Some(memory) = config.memory_limit {
             host_config.memory = Some(memory as i64);
         }
@@ -61,14 +193,42 @@ impl ContainerManager {
             host_config.cpu_shares = Some(cpu_shares as i64);
         }
 
+        if !config.cap_add.is_empty() {
+            host_config.cap_add = Some(config.cap_add.clone());
+        }
+        if !config.cap_drop.is_empty() {
+            host_config.cap_drop = Some(config.cap_drop.clone());
+        }
+        if !config.security_opt.is_empty() {
+            host_config.security_opt = Some(config.security_opt.clone());
+        }
+
+        host_config.ulimits = Some(
+            config
+                .effective_ulimits()
+                .into_iter()
+                .map(|ulimit| ResourcesUlimits {
+                    name: Some(ulimit.name),
+                    soft: Some(ulimit.soft),
+                    hard: Some(ulimit.hard),
+                })
+                .collect(),
+        );
+
         // Build labels
-        let mut labels = HashMap::new();
+        let mut labels = config.labels.clone();
         labels.insert(DBARENA_LABEL.to_string(), "true".to_string());
         labels.insert(
             "dbarena.database".to_string(),
             config.database.as_str().to_string(),
         );
         labels.insert("dbarena.version".to_string(), config.version.clone());
+        if let Some(port) = port {
+            labels.insert(HOST_PORT_LABEL.to_string(), port.to_string());
+        }
+        if config.persistent {
+            labels.insert(PERSISTENT_LABEL.to_string(), "true".to_string());
+        }
         if !config.init_scripts.is_empty() {
             let scripts: Vec<String> = config
                 .init_scripts
@@ -85,12 +245,40 @@ impl ContainerManager {
             );
         }
 
+        // Extra arguments extend the image's own command, e.g. postgres -c ...
+        let cmd = match (&config.command, config.database.default_command()) {
+            (Some(command), _) => Some(command.clone()),
+            (None, Some(mut cmd)) => {
+                cmd.extend(config.command_args.iter().cloned());
+                Some(cmd)
+            }
+            (None, None) if !config.command_args.is_empty() => {
+                let mut cmd = self
+                    .client
+                    .docker()
+                    .inspect_image(&image)
+                    .await?
+                    .config
+                    .and_then(|c| c.cmd)
+                    .unwrap_or_default();
+                cmd.extend(config.command_args.iter().cloned());
+                Some(cmd)
+            }
+            (None, None) => None,
+        };
+
         // Create container configuration
         let container_config = Config {
             image: Some(image.clone()),
             env: Some(env),
             labels: Some(labels),
             host_config: Some(host_config),
+            user: config
+                .user
+                .clone()
+                .or_else(|| config.database.default_user().map(String::from)),
+            working_dir: config.workdir.clone(),
+            cmd,
             ..Default::default()
         };
 
@@ -105,6 +293,7 @@ impl ContainerManager {
             .docker()
             .create_container(Some(options), container_config)
             .await?;
+        self.invalidate_cache();
 
         debug!("Container created with ID: {}", response.id);
 
@@ -115,19 +304,342 @@ impl ContainerManager {
             version: config.version,
             status: ContainerStatus::Creating,
             port: container_port,
-            host_port: Some(port),
+            host_port: port,
             persistent: config.persistent,
             created_at: chrono::Utc::now().timestamp(),
         })
     }
 
-    pub async fn start_container(&self, id: &str) -> Result<()> {
+    /// Start a container on the host port it was created with
+    ///
+    /// Returns the ID of the started container. This differs from `id` when the
+    /// port binding had drifted and the container was re-created to restore it.
+    pub async fn start_container(&self, id: &str) -> Result<String> {
         info!("Starting container: {}", id);
-        self.client
+        let id = self.restore_port_binding(id).await;
+        // Re-creating for a drifted port changes the ID even if starting fails
+        self.invalidate_cache();
+        let id = id?;
+        let result = self
+            .client
             .docker()
-            .start_container(id, None::<StartContainerOptions<String>>)
+            .start_container(&id, None::<StartContainerOptions<String>>)
+            .await;
+        self.invalidate_cache();
+        result?;
+        Ok(id)
+    }
+
+    /// Make sure a stopped container will come back on its original host port
+    ///
+    /// Fails if another container or process holds the port, and re-creates the
+    /// container with the recorded binding if Docker's binding no longer matches.
+    async fn restore_port_binding(&self, id: &str) -> Result<String> {
+        let inspect = self.client.docker().inspect_container(id, None).await?;
+        let container_id = inspect.id.clone().unwrap_or_else(|| id.to_string());
+
+        if inspect.state.as_ref().and_then(|s| s.running) == Some(true) {
+            return Ok(container_id);
+        }
+
+        let labels = inspect
+            .config
+            .as_ref()
+            .and_then(|c| c.labels.clone())
+            .unwrap_or_default();
+        let Some(database) = labels
+            .get("dbarena.database")
+            .and_then(|d| DatabaseType::from_string(d))
+        else {
+            return Ok(container_id);
+        };
+
+        let binding_key = format!("{}/tcp", database.default_port());
+        let binding = inspect
+            .host_config
+            .as_ref()
+            .and_then(|h| h.port_bindings.as_ref())
+            .and_then(|bindings| bindings.get(&binding_key).cloned().flatten())
+            .and_then(|bindings| bindings.into_iter().next());
+        let bound_port = binding
+            .as_ref()
+            .and_then(|b| b.host_port.as_deref())
+            .and_then(|p| p.parse::<u16>().ok());
+        // An empty host IP means every interface
+        let host_ip = binding
+            .and_then(|b| b.host_ip)
+            .filter(|ip| !ip.is_empty())
+            .unwrap_or_else(|| "0.0.0.0".to_string());
+        let recorded_port = labels
+            .get(HOST_PORT_LABEL)
+            .and_then(|p| p.parse::<u16>().ok());
+
+        // Containers created before the label existed keep their current binding
+        let Some(port) = recorded_port.or(bound_port) else {
+            return Ok(container_id);
+        };
+
+        self.ensure_port_free(&host_ip, port, &container_id).await?;
+
+        if bound_port == Some(port) {
+            return Ok(container_id);
+        }
+
+        info!(
+            "Port binding for {} drifted ({:?} != {}), re-creating container",
+            container_id, bound_port, port
+        );
+        self.recreate_with_port(inspect, &binding_key, &host_ip, port)
+            .await
+    }
+
+    /// Fail with the owner of `host_ip:port` if something other than `own_id` holds it
+    async fn ensure_port_free(&self, host_ip: &str, port: u16, own_id: &str) -> Result<()> {
+        // Ports on a remote daemon can't be probed from here
+        if !self.client.is_local() {
+            return Ok(());
+        }
+        let Ok(ip) = host_ip.parse::<std::net::IpAddr>() else {
+            return Ok(());
+        };
+        if std::net::TcpListener::bind((ip, port)).is_ok() {
+            return Ok(());
+        }
+
+        let running = self
+            .client
+            .docker()
+            .list_containers(Some(ListContainersOptions::<String>::default()))
             .await?;
-        Ok(())
+        let owner = running
+            .iter()
+            .filter(|c| c.id.as_deref() != Some(own_id))
+            .find(|c| {
+                c.ports
+                    .as_ref()
+                    .is_some_and(|ports| ports.iter().any(|p| p.public_port == Some(port)))
+            })
+            .map(|c| {
+                let name = c
+                    .names
+                    .as_ref()
+                    .and_then(|names| names.first())
+                    .map(|n| n.trim_start_matches('/').to_string())
+                    .unwrap_or_else(|| c.id.clone().unwrap_or_default());
+                format!("container '{}'", name)
+            })
+            .or_else(|| port_owner_pid(port).map(|pid| format!("process {}", pid)))
+            .unwrap_or_else(|| "another process".to_string());
+
+        Err(crate::DBArenaError::PortOccupied(port, owner))
+    }
+
+    /// Re-create a stopped container with `host_ip:port` bound, keeping its
+    /// data, configuration and networks
+    ///
+    /// The old container is committed to an image the new one runs from, so
+    /// files outside volumes survive (SQL Server's image declares no volume),
+    /// and its whole config (healthcheck, stop signal, hostname, ...) is
+    /// copied. Every volume, including anonymous ones, and bind mount is
+    /// re-attached, and the container rejoins each network it was on with its
+    /// aliases. If the new container can't be set up, the old one is restored
+    /// under its name.
+    async fn recreate_with_port(
+        &self,
+        inspect: ContainerInspectResponse,
+        binding_key: &str,
+        host_ip: &str,
+        port: u16,
+    ) -> Result<String> {
+        let docker = self.client.docker();
+        let old_id = inspect.id.clone().unwrap_or_default();
+        let name = inspect
+            .name
+            .as_deref()
+            .unwrap_or_default()
+            .trim_start_matches('/')
+            .to_string();
+        let config = inspect.config.unwrap_or_default();
+        let mut host_config = inspect.host_config.unwrap_or_default();
+        let previous_image = config
+            .labels
+            .as_ref()
+            .and_then(|labels| labels.get(CLONE_IMAGE_LABEL).cloned());
+
+        let mounts: Vec<_> = inspect
+            .mounts
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|m| {
+                let read_only = !m.rw.unwrap_or(true);
+                match m.typ {
+                    Some(MountPointTypeEnum::VOLUME) => Some(
+                        VolumeMount::volume(m.name?, m.destination?, read_only).to_docker_mount(),
+                    ),
+                    Some(MountPointTypeEnum::BIND) => Some(
+                        VolumeMount::bind(m.source?, m.destination?, read_only).to_docker_mount(),
+                    ),
+                    _ => None,
+                }
+            })
+            .collect();
+        host_config.binds = None;
+        host_config.mounts = if mounts.is_empty() { None } else { Some(mounts) };
+
+        let mut port_bindings = host_config.port_bindings.take().unwrap_or_default();
+        port_bindings.insert(
+            binding_key.to_string(),
+            Some(vec![PortBinding {
+                host_ip: Some(host_ip.to_string()),
+                host_port: Some(port.to_string()),
+            }]),
+        );
+        host_config.port_bindings = Some(port_bindings);
+
+        // Docker only takes one network at creation; the rest are joined after
+        let mut networks: Vec<(String, EndpointSettings)> = inspect
+            .network_settings
+            .and_then(|settings| settings.networks)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(network, endpoint)| {
+                let aliases = endpoint.aliases.map(|aliases| {
+                    aliases
+                        .into_iter()
+                        .filter(|alias| !old_id.starts_with(alias.as_str()))
+                        .collect()
+                });
+                let settings = EndpointSettings {
+                    aliases,
+                    links: endpoint.links,
+                    ipam_config: endpoint.ipam_config,
+                    driver_opts: endpoint.driver_opts,
+                    ..Default::default()
+                };
+                (network, settings)
+            })
+            .collect();
+        let primary = host_config
+            .network_mode
+            .as_deref()
+            .and_then(|mode| networks.iter().position(|(network, _)| network == mode))
+            .map(|index| networks.remove(index));
+
+        // Keep what the database wrote outside its volumes; the image goes
+        // away with the container like a clone's
+        let short_id = &old_id[..old_id.len().min(12)];
+        let image_tag = format!("{}:{}", REBIND_IMAGE_REPO, short_id);
+        docker
+            .commit_container(
+                CommitContainerOptions {
+                    container: old_id.clone(),
+                    repo: REBIND_IMAGE_REPO.to_string(),
+                    tag: short_id.to_string(),
+                    comment: format!("dbarena re-bind of {}", name),
+                    author: "dbarena".to_string(),
+                    pause: false,
+                    changes: None,
+                },
+                Config::<String>::default(),
+            )
+            .await?;
+
+        let mut labels = config.labels.clone().unwrap_or_default();
+        labels.insert(HOST_PORT_LABEL.to_string(), port.to_string());
+        labels.insert(CLONE_IMAGE_LABEL.to_string(), image_tag.clone());
+
+        let container_config = Config {
+            image: Some(image_tag.clone()),
+            labels: Some(labels),
+            host_config: Some(host_config),
+            networking_config: primary.map(|(network, settings)| NetworkingConfig {
+                endpoints_config: HashMap::from([(network, settings)]),
+            }),
+            ..Config::from(config)
+        };
+
+        // Park the old container under another name so its name can be reused
+        let parked = format!("{}-rebind-{}", name, short_id);
+        let options = RenameContainerOptions {
+            name: parked.clone(),
+        };
+        if let Err(e) = docker.rename_container(&old_id, options).await {
+            self.remove_image_quietly(&image_tag).await;
+            return Err(e.into());
+        }
+
+        let mut new_id = None;
+        let created = async {
+            let response = docker
+                .create_container(
+                    Some(CreateContainerOptions {
+                        name: name.clone(),
+                        ..Default::default()
+                    }),
+                    container_config,
+                )
+                .await?;
+            new_id = Some(response.id.clone());
+
+            for (network, endpoint_config) in networks {
+                let options = ConnectNetworkOptions {
+                    container: response.id.as_str(),
+                    endpoint_config,
+                };
+                docker.connect_network(&network, options).await?;
+            }
+            Ok::<_, crate::DBArenaError>(response.id)
+        }
+        .await;
+
+        match created {
+            Ok(id) => {
+                docker
+                    .remove_container(
+                        &old_id,
+                        Some(RemoveContainerOptions {
+                            force: true,
+                            ..Default::default()
+                        }),
+                    )
+                    .await?;
+                // The new image holds the old one's layers; drop its tag
+                if let Some(image) = previous_image {
+                    self.remove_image_quietly(&image).await;
+                }
+                debug!("Re-created {} as {}", old_id, id);
+                Ok(id)
+            }
+            Err(e) => {
+                if let Some(id) = new_id {
+                    let options = RemoveContainerOptions {
+                        force: true,
+                        ..Default::default()
+                    };
+                    if let Err(e) = docker.remove_container(&id, Some(options)).await {
+                        warn!("Failed to remove half-created container {}: {}", id, e);
+                    }
+                }
+                if let Err(rename_error) = docker
+                    .rename_container(&old_id, RenameContainerOptions { name: name.clone() })
+                    .await
+                {
+                    warn!(
+                        "Container {} is still named '{}' and must be renamed to '{}': {}",
+                        old_id, parked, name, rename_error
+                    );
+                }
+                self.remove_image_quietly(&image_tag).await;
+                Err(e)
+            }
+        }
+    }
+
+    /// Remove a temporary image, logging rather than failing if it can't be
+    async fn remove_image_quietly(&self, image: &str) {
+        if let Err(e) = self.client.docker().remove_image(image, None, None).await {
+            debug!("Failed to remove image {}: {}", image, e);
+        }
     }
 
     pub async fn stop_container(&self, id: &str, timeout: Option<u64>) -> Result<()> {
@@ -135,28 +647,398 @@ impl ContainerManager {
         let options = StopContainerOptions {
             t: timeout.unwrap_or(10) as i64,
         };
-        self.client
-            .docker()
-            .stop_container(id, Some(options))
-            .await?;
+        let result = self.client.docker().stop_container(id, Some(options)).await;
+        self.invalidate_cache();
+        result?;
         Ok(())
     }
 
     pub async fn destroy_container(&self, id: &str, remove_volumes: bool) -> Result<()> {
         info!("Destroying container: {}", id);
+
+        // Clones run from a temporary image that should go away with them
+        let clone_image = self
+            .client
+            .docker()
+            .inspect_container(id, None)
+            .await
+            .ok()
+            .and_then(|inspect| inspect.config)
+            .and_then(|config| config.labels)
+            .and_then(|labels| labels.get(CLONE_IMAGE_LABEL).cloned());
+
         let options = RemoveContainerOptions {
             v: remove_volumes,
             force: true,
             ..Default::default()
         };
-        self.client
-            .docker()
-            .remove_container(id, Some(options))
-            .await?;
+        let result = self.client.docker().remove_container(id, Some(options)).await;
+        self.invalidate_cache();
+        result?;
+
+        if let Some(image) = clone_image {
+            if let Err(e) = self.client.docker().remove_image(&image, None, None).await {
+                debug!("Failed to remove clone image {}: {}", image, e);
+            }
+        }
         Ok(())
     }
 
+    /// Report what destroying a container would remove, without removing anything
+    pub async fn destroy_plan(&self, id: &str, remove_volumes: bool) -> Result<DestroyPlan> {
+        let inspect = self.client.docker().inspect_container(id, None).await?;
+        let clone_image = inspect
+            .config
+            .as_ref()
+            .and_then(|config| config.labels.as_ref())
+            .and_then(|labels| labels.get(CLONE_IMAGE_LABEL).cloned());
+
+        Ok(DestroyPlan::new(
+            &inspect.mounts.unwrap_or_default(),
+            remove_volumes,
+            clone_image,
+        ))
+    }
+
+    /// Clone a container, including its data, into a new stopped container
+    ///
+    /// The source is committed to a temporary image and the contents of its
+    /// volumes are copied across. Named volumes are replaced with new named
+    /// volumes so the clone never shares storage with the source. If any step
+    /// fails, the image, volumes and container created so far are removed.
+    pub async fn clone_container(&self, source: &Container, new_name: &str) -> Result<Container> {
+        let docker = self.client.docker();
+
+        let inspect = docker.inspect_container(&source.id, None).await?;
+        let source_config = inspect.config.unwrap_or_default();
+        let source_host_config = inspect.host_config.unwrap_or_default();
+        let mounts = inspect.mounts.unwrap_or_default();
+
+        let database = DatabaseType::from_string(&source.database_type).ok_or_else(|| {
+            crate::DBArenaError::InvalidConfig(format!(
+                "Unknown database type: {}",
+                source.database_type
+            ))
+        })?;
+
+        // Step 1: Commit the source to a temporary image
+        let image_tag = format!("{}:{}", CLONE_IMAGE_REPO, new_name.to_lowercase());
+        info!("Committing {} to {}", source.name, image_tag);
+        docker
+            .commit_container(
+                CommitContainerOptions {
+                    container: source.id.clone(),
+                    repo: CLONE_IMAGE_REPO.to_string(),
+                    tag: new_name.to_lowercase(),
+                    comment: format!("dbarena clone of {}", source.name),
+                    author: "dbarena".to_string(),
+                    pause: true,
+                    changes: None,
+                },
+                Config::<String>::default(),
+            )
+            .await?;
+
+        let volume_manager = VolumeManager::new(std::sync::Arc::new(docker.clone()));
+        let mut created_volumes = Vec::new();
+        let mut clone_id = None;
+        let cloned = async {
+            // Step 2: Give the clone its own volumes
+            let mut new_mounts = Vec::new();
+            let mut volume_paths = Vec::new();
+            for (index, mount) in mounts.iter().enumerate() {
+                let Some(target) = mount.destination.clone() else {
+                    continue;
+                };
+                match mount.typ {
+                    Some(MountPointTypeEnum::VOLUME) => {
+                        volume_paths.push(target.clone());
+                        // Anonymous volumes are recreated from the image automatically
+                        if source.persistent || is_named_volume(mount.name.as_deref()) {
+                            let volume_name = if index == 0 {
+                                format!("{}-data", new_name)
+                            } else {
+                                format!("{}-data-{}", new_name, index)
+                            };
+                            volume_manager
+                                .create(VolumeConfig::new(volume_name.clone(), target.clone()))
+                                .await?;
+                            created_volumes.push(volume_name.clone());
+                            new_mounts.push(
+                                VolumeMount::volume(volume_name, target, !mount.rw.unwrap_or(true))
+                                    .to_docker_mount(),
+                            );
+                        } else if database.is_embedded() {
+                            // Not declared by the image, so it has to be asked for again
+                            new_mounts.push(Mount {
+                                target: Some(target),
+                                typ: Some(MountTypeEnum::VOLUME),
+                                ..Default::default()
+                            });
+                        }
+                    }
+                    Some(MountPointTypeEnum::BIND) => {
+                        if let Some(host_path) = mount.source.clone() {
+                            new_mounts.push(
+                                VolumeMount::bind(host_path, target, !mount.rw.unwrap_or(true))
+                                    .to_docker_mount(),
+                            );
+                        }
+                    }
+                    _ => {}
+                }
+            }
+
+            // Step 3: Create the new container with a fresh port
+            let port = (!database.is_embedded()).then(|| self.find_available_port());
+            let container_port = database.default_port();
+            let port_bindings = port.map(|port| {
+                HashMap::from([(
+                    format!("{}/tcp", container_port),
+                    Some(vec![PortBinding {
+                        host_ip: Some("0.0.0.0".to_string()),
+                        host_port: Some(port.to_string()),
+                    }]),
+                )])
+            });
+
+            let host_config = HostConfig {
+                port_bindings,
+                tmpfs: source_host_config.tmpfs,
+                memory: source_host_config.memory,
+                cpu_shares: source_host_config.cpu_shares,
+                cap_add: source_host_config.cap_add,
+                cap_drop: source_host_config.cap_drop,
+                security_opt: source_host_config.security_opt,
+                ulimits: source_host_config.ulimits,
+                mounts: if new_mounts.is_empty() {
+                    None
+                } else {
+                    Some(new_mounts)
+                },
+                ..Default::default()
+            };
+
+            let mut labels: HashMap<String, String> = source_config
+                .labels
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|(key, _)| key.starts_with("dbarena."))
+                .collect();
+            labels.insert(DBARENA_LABEL.to_string(), "true".to_string());
+            labels.insert(
+                "dbarena.database".to_string(),
+                database.as_str().to_string(),
+            );
+            labels.insert("dbarena.version".to_string(), source.version.clone());
+            labels.insert(CLONED_FROM_LABEL.to_string(), source.name.clone());
+            labels.insert(CLONE_IMAGE_LABEL.to_string(), image_tag.clone());
+            if let Some(port) = port {
+                labels.insert(HOST_PORT_LABEL.to_string(), port.to_string());
+            }
+
+            let container_config = Config {
+                image: Some(image_tag.clone()),
+                env: source_config.env,
+                labels: Some(labels),
+                host_config: Some(host_config),
+                ..Default::default()
+            };
+
+            info!("Creating clone container: {}", new_name);
+            let response = docker
+                .create_container(
+                    Some(CreateContainerOptions {
+                        name: new_name.to_string(),
+                        ..Default::default()
+                    }),
+                    container_config,
+                )
+                .await?;
+            clone_id = Some(response.id.clone());
+            self.invalidate_cache();
+
+            // Step 4: Copy volume contents (commit does not capture volumes)
+            self.copy_volumes_between_containers(
+                &image_tag,
+                &source.id,
+                &response.id,
+                &volume_paths,
+            )
+            .await?;
+
+            Ok::<_, crate::DBArenaError>((response.id, port))
+        }
+        .await;
+
+        let (id, port) = match cloned {
+            Ok(cloned) => cloned,
+            Err(e) => {
+                self.discard_clone(clone_id.as_deref(), &created_volumes, &image_tag)
+                    .await;
+                return Err(e);
+            }
+        };
+
+        debug!("Clone created with ID: {}", id);
+
+        Ok(Container {
+            id,
+            name: new_name.to_string(),
+            database_type: database.as_str().to_string(),
+            version: source.version.clone(),
+            status: ContainerStatus::Creating,
+            port: database.default_port(),
+            host_port: port,
+            persistent: source.persistent,
+            created_at: chrono::Utc::now().timestamp(),
+        })
+    }
+
+    /// Copy the volumes mounted at `paths` in one container into the volumes at
+    /// the same paths in another
+    ///
+    /// A short-lived helper container from `image` mounts both sets of volumes
+    /// and copies between them, so the data streams inside Docker rather than
+    /// passing through this process.
+    async fn copy_volumes_between_containers(
+        &self,
+        image: &str,
+        from_id: &str,
+        to_id: &str,
+        paths: &[String],
+    ) -> Result<()> {
+        use futures::StreamExt;
+
+        if paths.is_empty() {
+            return Ok(());
+        }
+        let docker = self.client.docker();
+
+        let volume_names = |inspect: ContainerInspectResponse| -> HashMap<String, String> {
+            inspect
+                .mounts
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|mount| mount.typ == Some(MountPointTypeEnum::VOLUME))
+                .filter_map(|mount| Some((mount.destination?, mount.name?)))
+                .collect()
+        };
+        let from = volume_names(docker.inspect_container(from_id, None).await?);
+        let to = volume_names(docker.inspect_container(to_id, None).await?);
+
+        let mut binds = Vec::new();
+        let mut copies = Vec::new();
+        for (index, path) in paths.iter().enumerate() {
+            let (Some(source), Some(target)) = (from.get(path), to.get(path)) else {
+                return Err(crate::DBArenaError::ContainerOperationFailed(format!(
+                    "No volume at {} to copy",
+                    path
+                )));
+            };
+            debug!("Copying {} ({} -> {})", path, source, target);
+            binds.push(format!("{}:/dbarena-clone/from/{}:ro", source, index));
+            binds.push(format!("{}:/dbarena-clone/to/{}", target, index));
+            copies.push(format!(
+                "cp -a /dbarena-clone/from/{0}/. /dbarena-clone/to/{0}/",
+                index
+            ));
+        }
+
+        let helper = docker
+            .create_container(
+                None::<CreateContainerOptions<String>>,
+                Config {
+                    image: Some(image.to_string()),
+                    entrypoint: Some(vec!["sh".to_string(), "-c".to_string()]),
+                    cmd: Some(vec![copies.join(" && ")]),
+                    // Root, so ownership and modes survive the copy
+                    user: Some("0:0".to_string()),
+                    host_config: Some(HostConfig {
+                        binds: Some(binds),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                },
+            )
+            .await?;
+
+        let copied = async {
+            docker
+                .start_container(&helper.id, None::<StartContainerOptions<String>>)
+                .await?;
+            let mut wait = docker.wait_container(&helper.id, None::<WaitContainerOptions<String>>);
+            match wait.next().await {
+                Some(Ok(response)) if response.status_code == 0 => Ok(()),
+                Some(Ok(response)) => Err(crate::DBArenaError::ContainerOperationFailed(format!(
+                    "Copying volume data exited with status {}",
+                    response.status_code
+                ))),
+                Some(Err(e)) => Err(e.into()),
+                None => Err(crate::DBArenaError::ContainerOperationFailed(
+                    "Copying volume data ended without a status".to_string(),
+                )),
+            }
+        }
+        .await;
+
+        let _ = docker
+            .remove_container(
+                &helper.id,
+                Some(RemoveContainerOptions {
+                    force: true,
+                    ..Default::default()
+                }),
+            )
+            .await;
+        copied
+    }
+
+    /// Remove what a failed clone left behind: its container (with anonymous
+    /// volumes), the named volumes created for it and the committed image
+    async fn discard_clone(&self, container_id: Option<&str>, volumes: &[String], image: &str) {
+        let docker = self.client.docker();
+        if let Some(id) = container_id {
+            let options = RemoveContainerOptions {
+                v: true,
+                force: true,
+                ..Default::default()
+            };
+            if let Err(e) = docker.remove_container(id, Some(options)).await {
+                warn!("Failed to remove partial clone {}: {}", id, e);
+            }
+            self.invalidate_cache();
+        }
+
+        let volume_manager = VolumeManager::new(std::sync::Arc::new(docker.clone()));
+        for volume in volumes {
+            if let Err(e) = volume_manager.delete(volume, true).await {
+                warn!("Failed to remove volume {} of partial clone: {}", volume, e);
+            }
+        }
+
+        let _ = docker.remove_image(image, None, None).await;
+    }
+
+    /// List dbarena containers, reusing a listing younger than the cache TTL
     pub async fn list_containers(&self, all: bool) -> Result<Vec<Container>> {
+        if !self.cache_ttl.is_zero() {
+            if let Some((fetched, containers)) = self.cache.lock().unwrap().slot(all) {
+                if fetched.elapsed() < self.cache_ttl {
+                    return Ok(containers.clone());
+                }
+            }
+        }
+
+        let containers = self.list_containers_uncached(all).await?;
+        if !self.cache_ttl.is_zero() {
+            *self.cache.lock().unwrap().slot(all) = Some((Instant::now(), containers.clone()));
+        }
+        Ok(containers)
+    }
+
+    /// List dbarena containers straight from Docker, bypassing the cache
+    pub async fn list_containers_uncached(&self, all: bool) -> Result<Vec<Container>> {
         let mut filters = HashMap::new();
         filters.insert("label".to_string(), vec![format!("{}=true", DBARENA_LABEL)]);
 
@@ -176,9 +1058,27 @@ impl ContainerManager {
 
     pub async fn find_container(&self, name_or_id: &str) -> Result<Option<Container>> {
         let containers = self.list_containers(true).await?;
-        Ok(containers
-            .into_iter()
-            .find(|c| c.name == name_or_id || c.id.starts_with(name_or_id)))
+        Ok(match_container(containers, name_or_id))
+    }
+
+    /// Whether any container, managed by dbarena or not, already has `name`
+    pub async fn name_in_use(&self, name: &str) -> Result<bool> {
+        match self.client.docker().inspect_container(name, None).await {
+            // An ID prefix also resolves, so compare the actual name
+            Ok(inspect) => {
+                Ok(inspect.name.as_deref().map(|n| n.trim_start_matches('/')) == Some(name))
+            }
+            Err(bollard::errors::Error::DockerResponseServerError {
+                status_code: 404, ..
+            }) => Ok(false),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Find a container straight from Docker, bypassing the cache
+    pub async fn find_container_uncached(&self, name_or_id: &str) -> Result<Option<Container>> {
+        let containers = self.list_containers_uncached(true).await?;
+        Ok(match_container(containers, name_or_id))
     }
 
     fn generate_container_name(&self, config: &ContainerConfig) -> String {
@@ -218,6 +1118,15 @@ impl ContainerManager {
                 ("ACCEPT_EULA".to_string(), "Y".to_string()),
                 ("SA_PASSWORD".to_string(), "YourStrong@Passw0rd".to_string()),
             ]),
+            crate::container::DatabaseType::Redis => HashMap::new(),
+            crate::container::DatabaseType::MongoDB => HashMap::from([
+                ("MONGO_INITDB_ROOT_USERNAME".to_string(), "root".to_string()),
+                ("MONGO_INITDB_ROOT_PASSWORD".to_string(), "mongo".to_string()),
+                ("MONGO_INITDB_DATABASE".to_string(), "testdb".to_string()),
+            ]),
+            // Insecure single-node clusters only have the passwordless root user
+            crate::container::DatabaseType::CockroachDB => HashMap::new(),
+            crate::container::DatabaseType::SQLite => HashMap::new(),
         };
 
         // Override with custom environment variables from config
@@ -262,6 +1171,15 @@ impl ContainerManager {
             .map(|p| (p.private_port, p.public_port))
             .unwrap_or((0, None));
 
+        // Containers created before the label existed count as persistent if
+        // they mount a named volume
+        let persistent = match labels.get(PERSISTENT_LABEL) {
+            Some(value) => value == "true",
+            None => summary.mounts.as_deref().unwrap_or_default().iter().any(|m| {
+                m.typ == Some(MountPointTypeEnum::VOLUME) && is_named_volume(m.name.as_deref())
+            }),
+        };
+
         Container {
             id: summary.id.unwrap_or_default(),
             name,
@@ -270,45 +1188,78 @@ impl ContainerManager {
             status,
             port,
             host_port,
-            persistent: false,
+            persistent,
             created_at: summary.created.unwrap_or(0),
         }
     }
 
     // Bulk/Parallel Operations
 
-    /// Start multiple containers in parallel
-    pub async fn start_containers_parallel(&self, ids: Vec<String>) -> Vec<Result<()>> {
-        use futures::future::join_all;
-        let futures: Vec<_> = ids.iter().map(|id| {
-            let id = id.clone();
-            async move { self.start_container(&id).await }
-        }).collect();
-        join_all(futures).await
+    /// Start multiple containers, at most `concurrency` at a time
+    pub async fn start_containers_parallel(
+        &self,
+        ids: Vec<String>,
+        concurrency: usize,
+    ) -> Vec<Result<()>> {
+        for_each_concurrent(ids, concurrency, |id| async move {
+            self.start_container(&id).await.map(|_| ())
+        })
+        .await
     }
 
-    /// Stop multiple containers in parallel
-    pub async fn stop_containers_parallel(&self, ids: Vec<String>, timeout: u64) -> Vec<Result<()>> {
-        use futures::future::join_all;
-        let futures: Vec<_> = ids.iter().map(|id| {
-            let id = id.clone();
-            async move { self.stop_container(&id, Some(timeout)).await }
-        }).collect();
-        join_all(futures).await
+    /// Stop multiple containers, at most `concurrency` at a time
+    pub async fn stop_containers_parallel(
+        &self,
+        ids: Vec<String>,
+        timeout: u64,
+        concurrency: usize,
+    ) -> Vec<Result<()>> {
+        for_each_concurrent(ids, concurrency, |id| async move {
+            self.stop_container(&id, Some(timeout)).await
+        })
+        .await
     }
 
-    /// Destroy multiple containers in parallel
+    /// Destroy multiple containers, at most `concurrency` at a time
     pub async fn destroy_containers_parallel(
         &self,
         ids: Vec<String>,
         remove_volumes: bool,
+        concurrency: usize,
     ) -> Vec<Result<()>> {
-        use futures::future::join_all;
-        let futures: Vec<_> = ids.iter().map(|id| {
-            let id = id.clone();
-            async move { self.destroy_container(&id, remove_volumes).await }
-        }).collect();
-        join_all(futures).await
+        for_each_concurrent(ids, concurrency, |id| async move {
+            self.destroy_container(&id, remove_volumes).await
+        })
+        .await
+    }
+}
+
+/// Best-effort lookup of the PID listening on a local TCP port
+fn port_owner_pid(port: u16) -> Option<u32> {
+    let output = std::process::Command::new("lsof")
+        .args(["-nP", &format!("-iTCP:{}", port), "-sTCP:LISTEN", "-t"])
+        .output()
+        .ok()?;
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .next()?
+        .trim()
+        .parse()
+        .ok()
+}
+
+/// Match by exact name or ID prefix
+fn match_container(containers: Vec<Container>, name_or_id: &str) -> Option<Container> {
+    containers
+        .into_iter()
+        .find(|c| c.name == name_or_id || c.id.starts_with(name_or_id))
+}
+
+/// Docker names anonymous volumes with a 64-character hex ID
+fn is_named_volume(name: Option<&str>) -> bool {
+    match name {
+        Some(name) => !(name.len() == 64 && name.chars().all(|c| c.is_ascii_hexdigit())),
+        None => false,
     }
 }
 
@@ -331,4 +1282,94 @@ mod tests {
         let name = manager.generate_container_name(&config_with_name);
         assert_eq!(name, "my-custom-name");
     }
+
+    #[test]
+    fn test_is_named_volume() {
+        assert!(is_named_volume(Some("mydb-data")));
+        assert!(!is_named_volume(Some(&"a1".repeat(32))));
+        assert!(!is_named_volume(None));
+    }
+
+    #[test]
+    fn test_convert_container_persistent() {
+        let manager = ContainerManager::new(DockerClient::new().unwrap());
+        let summary = |labels: &[(&str, &str)], volume: &str| ContainerSummary {
+            labels: Some(
+                labels
+                    .iter()
+                    .map(|(k, v)| (k.to_string(), v.to_string()))
+                    .collect(),
+            ),
+            mounts: Some(vec![MountPoint {
+                typ: Some(MountPointTypeEnum::VOLUME),
+                name: Some(volume.to_string()),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        };
+        let anonymous = "a1".repeat(32);
+
+        let labelled = summary(&[(PERSISTENT_LABEL, "true")], &anonymous);
+        assert!(manager.convert_container(labelled).persistent);
+        // Older containers have no label; a named volume gives them away
+        assert!(manager.convert_container(summary(&[], "mydb-data")).persistent);
+        assert!(!manager.convert_container(summary(&[], &anonymous)).persistent);
+    }
+
+    #[test]
+    fn test_destroy_plan_volumes() {
+        let volume = |name: &str| MountPoint {
+            typ: Some(MountPointTypeEnum::VOLUME),
+            name: Some(name.to_string()),
+            ..Default::default()
+        };
+        let anonymous = "a1".repeat(32);
+        let mounts = vec![
+            volume("mydb-data"),
+            volume(&anonymous),
+            MountPoint {
+                typ: Some(MountPointTypeEnum::BIND),
+                source: Some("/tmp/init".to_string()),
+                ..Default::default()
+            },
+        ];
+
+        let plan = DestroyPlan::new(&mounts, true, Some("dbarena-clone:abc".to_string()));
+        assert_eq!(plan.removed_volumes, vec![anonymous.clone()]);
+        assert_eq!(plan.kept_volumes, vec!["mydb-data".to_string()]);
+        assert_eq!(plan.clone_image.as_deref(), Some("dbarena-clone:abc"));
+
+        let plan = DestroyPlan::new(&mounts, false, None);
+        assert!(plan.removed_volumes.is_empty());
+        assert_eq!(plan.kept_volumes, vec!["mydb-data".to_string(), anonymous]);
+    }
+
+    fn cached_container(name: &str) -> Container {
+        Container {
+            id: format!("{}-id", name),
+            name: name.to_string(),
+            database_type: "postgres".to_string(),
+            version: "16".to_string(),
+            status: ContainerStatus::Running,
+            port: 5432,
+            host_port: Some(5432),
+            persistent: false,
+            created_at: 0,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_list_cache_hit_and_invalidate() {
+        let client = DockerClient::new().expect("Failed to create Docker client");
+        let manager = ContainerManager::new(client).with_cache_ttl(Duration::from_secs(60));
+
+        manager.cache.lock().unwrap().all = Some((Instant::now(), vec![cached_container("pg")]));
+
+        // Served from the cache without touching Docker
+        let found = manager.find_container("pg").await.unwrap();
+        assert_eq!(found.map(|c| c.id), Some("pg-id".to_string()));
+
+        manager.invalidate_cache();
+        assert!(manager.cache.lock().unwrap().all.is_none());
+    }
 }
@@ -1,11 +1,25 @@
 pub mod config;
+pub mod connection;
 pub mod docker_client;
+pub mod external;
 pub mod manager;
 pub mod models;
+pub mod pull;
+pub mod registry;
 pub mod volume;
 
-pub use config::{ContainerConfig, DatabaseType};
+pub use config::{
+    is_known_capability, parse_tmpfs, ContainerConfig, DatabaseType, Ulimit, KNOWN_CAPABILITIES,
+    SQLITE_DB_PATH,
+};
+pub use connection::{
+    client_command, connection_details, connection_params, connection_uri, container_env,
+    ConnParams, ConnectFormat, MASKED_PASSWORD,
+};
 pub use docker_client::DockerClient;
-pub use manager::ContainerManager;
+pub use external::{run_on_host, Endpoint, HostOutput};
+pub use manager::{ContainerManager, DestroyPlan};
 pub use models::Container;
+pub use pull::PullOptions;
+pub use registry::{ContainerRegistry, RegistryEntry};
 pub use volume::{VolumeConfig, VolumeMount, VolumeManager, VolumeMountType};
@@ -0,0 +1,237 @@
+//! Image pull retry policy and per-layer progress tracking
+//!
+//! Docker keeps layers that finished downloading, so retrying a failed pull
+//! resumes from the layers that are still missing.
+
+use crate::util::backoff::Backoff;
+use bollard::models::CreateImageInfo;
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+/// How image pulls are retried
+#[derive(Debug, Clone)]
+pub struct PullOptions {
+    /// Total attempts, including the first
+    pub attempts: u32,
+    /// Delay before the first retry; doubled for each following retry
+    pub retry_delay: Duration,
+}
+
+impl Default for PullOptions {
+    fn default() -> Self {
+        Self {
+            attempts: 3,
+            retry_delay: Duration::from_secs(2),
+        }
+    }
+}
+
+impl PullOptions {
+    /// Delay before retry number `retry` (1-based)
+    pub fn delay_for(&self, retry: u32) -> Duration {
+        Backoff::exponential(self.retry_delay).delay(retry)
+    }
+}
+
+/// Why a pull attempt failed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PullFailure {
+    /// The image or tag does not exist; retrying won't help
+    NotFound,
+    /// Authentication or other registry refusal; retrying won't help
+    Rejected,
+    /// Network or daemon hiccup worth retrying
+    Transient,
+}
+
+/// Classify a pull error from its HTTP status (when known) and message
+pub fn classify_pull_error(status_code: Option<u16>, message: &str) -> PullFailure {
+    let message = message.to_lowercase();
+
+    if status_code == Some(404)
+        || message.contains("manifest unknown")
+        || (message.contains("manifest for") && message.contains("not found"))
+        || message.contains("repository does not exist")
+        || message.contains("pull access denied")
+    {
+        return PullFailure::NotFound;
+    }
+
+    if matches!(status_code, Some(401) | Some(403))
+        || message.contains("unauthorized")
+        || message.contains("authentication required")
+        || message.contains("denied")
+    {
+        return PullFailure::Rejected;
+    }
+
+    PullFailure::Transient
+}
+
+#[derive(Debug, Clone, Default)]
+struct LayerState {
+    status: String,
+    current: u64,
+    total: u64,
+}
+
+/// Per-layer state built from the daemon's pull progress messages
+///
+/// Kept across retries so a resumed pull can report what was already done.
+#[derive(Debug, Default)]
+pub struct LayerTracker {
+    layers: BTreeMap<String, LayerState>,
+}
+
+impl LayerTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a progress message; messages that aren't about a layer are ignored
+    pub fn observe(&mut self, info: &CreateImageInfo) {
+        let (Some(id), Some(status)) = (&info.id, &info.status) else {
+            return;
+        };
+        if !is_layer_status(status) {
+            return;
+        }
+
+        let layer = self.layers.entry(id.clone()).or_default();
+        layer.status = status.clone();
+        if let Some(detail) = &info.progress_detail {
+            if let Some(total) = detail.total.filter(|t| *t > 0) {
+                layer.total = total as u64;
+            }
+            if let Some(current) = detail.current.filter(|c| *c >= 0) {
+                layer.current = current as u64;
+            }
+        }
+        if is_done(&layer.status) {
+            layer.current = layer.total;
+        }
+    }
+
+    pub fn total(&self) -> usize {
+        self.layers.len()
+    }
+
+    /// Layers that are downloaded and extracted (including cached ones)
+    pub fn completed(&self) -> usize {
+        self.layers.values().filter(|l| is_done(&l.status)).count()
+    }
+
+    /// Layers that were already present locally
+    pub fn cached(&self) -> usize {
+        self.layers
+            .values()
+            .filter(|l| l.status == "Already exists")
+            .count()
+    }
+
+    /// One-line summary, e.g. "3/7 layers (2 cached), 45.2/120.0 MB downloading"
+    pub fn summary(&self) -> String {
+        let mut summary = format!(
+            "{}/{} layers ({} cached)",
+            self.completed(),
+            self.total(),
+            self.cached()
+        );
+
+        let active: Vec<_> = self
+            .layers
+            .values()
+            .filter(|l| !is_done(&l.status) && l.total > 0)
+            .collect();
+        if !active.is_empty() {
+            let current: u64 = active.iter().map(|l| l.current).sum();
+            let total: u64 = active.iter().map(|l| l.total).sum();
+            summary.push_str(&format!(
+                ", {:.1}/{:.1} MB downloading",
+                current as f64 / 1_048_576.0,
+                total as f64 / 1_048_576.0
+            ));
+        }
+
+        summary
+    }
+}
+
+fn is_layer_status(status: &str) -> bool {
+    matches!(
+        status,
+        "Pulling fs layer"
+            | "Waiting"
+            | "Downloading"
+            | "Verifying Checksum"
+            | "Download complete"
+            | "Extracting"
+            | "Pull complete"
+            | "Already exists"
+            | "Retrying"
+    ) || status.starts_with("Retrying in")
+}
+
+fn is_done(status: &str) -> bool {
+    matches!(status, "Pull complete" | "Already exists")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bollard::models::ProgressDetail;
+
+    fn info(id: &str, status: &str, current: Option<i64>, total: Option<i64>) -> CreateImageInfo {
+        CreateImageInfo {
+            id: Some(id.to_string()),
+            status: Some(status.to_string()),
+            progress_detail: Some(ProgressDetail { current, total }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_layer_tracker() {
+        let mut tracker = LayerTracker::new();
+        tracker.observe(&info("16", "Pulling from library/postgres", None, None));
+        tracker.observe(&info("a1", "Already exists", None, None));
+        tracker.observe(&info("b2", "Downloading", Some(1_048_576), Some(4_194_304)));
+        tracker.observe(&info("c3", "Pull complete", None, None));
+
+        assert_eq!(tracker.total(), 3);
+        assert_eq!(tracker.completed(), 2);
+        assert_eq!(tracker.cached(), 1);
+        assert_eq!(tracker.summary(), "2/3 layers (1 cached), 1.0/4.0 MB downloading");
+
+        tracker.observe(&info("b2", "Pull complete", None, None));
+        assert_eq!(tracker.summary(), "3/3 layers (1 cached)");
+    }
+
+    #[test]
+    fn test_classify_pull_error() {
+        assert_eq!(
+            classify_pull_error(None, "manifest for postgres:99 not found: manifest unknown"),
+            PullFailure::NotFound
+        );
+        assert_eq!(classify_pull_error(Some(404), "no such image"), PullFailure::NotFound);
+        assert_eq!(
+            classify_pull_error(None, "unauthorized: authentication required"),
+            PullFailure::Rejected
+        );
+        assert_eq!(
+            classify_pull_error(None, "net/http: TLS handshake timeout"),
+            PullFailure::Transient
+        );
+        assert_eq!(
+            classify_pull_error(Some(500), "read: connection reset by peer"),
+            PullFailure::Transient
+        );
+    }
+
+    #[test]
+    fn test_retry_backoff() {
+        let options = PullOptions::default();
+        assert_eq!(options.delay_for(1), Duration::from_secs(2));
+        assert_eq!(options.delay_for(2), Duration::from_secs(4));
+    }
+}
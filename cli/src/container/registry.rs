@@ -0,0 +1,191 @@
+//! Local record of containers created by dbarena
+//!
+//! Docker labels only carry what was set on the container itself, so details
+//! such as the config profile and init scripts used at creation time are kept
+//! here. Entries are pruned once their container no longer exists in Docker.
+
+use super::Container;
+use crate::{DBArenaError, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs;
+use std::path::PathBuf;
+
+/// Metadata recorded when a container is created
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RegistryEntry {
+    pub name: String,
+    pub id: String,
+    pub database: String,
+    pub version: String,
+    #[serde(default)]
+    pub profile: Option<String>,
+    #[serde(default)]
+    pub init_scripts: Vec<PathBuf>,
+    pub created_at: i64,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct RegistryFile {
+    #[serde(default)]
+    containers: Vec<RegistryEntry>,
+}
+
+/// Container registry backed by `~/.config/dbarena/containers.json`
+pub struct ContainerRegistry {
+    path: PathBuf,
+}
+
+impl ContainerRegistry {
+    pub fn new() -> Result<Self> {
+        let config_dir = dirs::config_dir().ok_or_else(|| {
+            DBArenaError::InvalidConfig("Could not determine config directory".to_string())
+        })?;
+        Ok(Self::with_path(
+            config_dir.join("dbarena").join("containers.json"),
+        ))
+    }
+
+    pub fn with_path(path: PathBuf) -> Self {
+        Self { path }
+    }
+
+    fn load(&self) -> Result<RegistryFile> {
+        if !self.path.exists() {
+            return Ok(RegistryFile::default());
+        }
+
+        let content = fs::read_to_string(&self.path).map_err(|e| {
+            DBArenaError::Other(format!(
+                "Failed to read container registry {}: {}",
+                self.path.display(),
+                e
+            ))
+        })?;
+        serde_json::from_str(&content).map_err(|e| {
+            DBArenaError::Other(format!(
+                "Failed to parse container registry {}: {}",
+                self.path.display(),
+                e
+            ))
+        })
+    }
+
+    fn save(&self, file: &RegistryFile) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        // Write to a sibling file and rename so readers never see a partial file
+        let tmp = self.path.with_extension("json.tmp");
+        fs::write(&tmp, serde_json::to_string_pretty(file)?)?;
+        fs::rename(&tmp, &self.path).map_err(|e| {
+            DBArenaError::Other(format!(
+                "Failed to write container registry {}: {}",
+                self.path.display(),
+                e
+            ))
+        })
+    }
+
+    /// Record a container, replacing any entry with the same name
+    pub fn record(&self, entry: RegistryEntry) -> Result<()> {
+        let mut file = self.load()?;
+        file.containers.retain(|e| e.name != entry.name);
+        file.containers.push(entry);
+        self.save(&file)
+    }
+
+    /// Look up an entry by name or ID prefix
+    pub fn get(&self, name_or_id: &str) -> Result<Option<RegistryEntry>> {
+        Ok(self
+            .load()?
+            .containers
+            .into_iter()
+            .find(|e| e.name == name_or_id || e.id.starts_with(name_or_id)))
+    }
+
+    pub fn entries(&self) -> Result<Vec<RegistryEntry>> {
+        Ok(self.load()?.containers)
+    }
+
+    /// Drop entries whose container is not in `existing`, which must be a
+    /// listing of all containers (not only running ones)
+    ///
+    /// Returns the number of entries pruned.
+    pub fn reconcile(&self, existing: &[Container]) -> Result<usize> {
+        let mut file = self.load()?;
+        let ids: HashSet<&str> = existing.iter().map(|c| c.id.as_str()).collect();
+
+        let before = file.containers.len();
+        file.containers.retain(|e| ids.contains(e.id.as_str()));
+        let pruned = before - file.containers.len();
+
+        if pruned > 0 {
+            self.save(&file)?;
+        }
+        Ok(pruned)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::container::models::ContainerStatus;
+
+    fn entry(name: &str) -> RegistryEntry {
+        RegistryEntry {
+            name: name.to_string(),
+            id: format!("{}-id", name),
+            database: "postgres".to_string(),
+            version: "16".to_string(),
+            profile: Some("dev".to_string()),
+            init_scripts: vec![PathBuf::from("schema.sql")],
+            created_at: 0,
+        }
+    }
+
+    fn container(id: &str) -> Container {
+        Container {
+            id: id.to_string(),
+            name: "pg".to_string(),
+            database_type: "postgres".to_string(),
+            version: "16".to_string(),
+            status: ContainerStatus::Stopped,
+            port: 5432,
+            host_port: None,
+            persistent: false,
+            created_at: 0,
+        }
+    }
+
+    #[test]
+    fn test_record_get_and_reconcile() {
+        let dir = tempfile::tempdir().unwrap();
+        let registry = ContainerRegistry::with_path(dir.path().join("containers.json"));
+
+        registry.record(entry("pg")).unwrap();
+        registry.record(entry("mysql")).unwrap();
+        assert_eq!(registry.get("pg").unwrap(), Some(entry("pg")));
+        assert_eq!(registry.get("mysql-i").unwrap(), Some(entry("mysql")));
+
+        // Re-recording a name replaces the old entry
+        let mut updated = entry("pg");
+        updated.profile = None;
+        registry.record(updated.clone()).unwrap();
+        assert_eq!(registry.entries().unwrap().len(), 2);
+        assert_eq!(registry.get("pg").unwrap(), Some(updated));
+
+        assert_eq!(registry.reconcile(&[container("pg-id")]).unwrap(), 1);
+        assert!(registry.get("mysql").unwrap().is_none());
+        assert!(registry.get("pg").unwrap().is_some());
+    }
+
+    #[test]
+    fn test_missing_file_is_empty() {
+        let dir = tempfile::tempdir().unwrap();
+        let registry = ContainerRegistry::with_path(dir.path().join("containers.json"));
+        assert!(registry.entries().unwrap().is_empty());
+        assert_eq!(registry.reconcile(&[]).unwrap(), 0);
+    }
+}
@@ -0,0 +1,423 @@
+//! Background daemon serving container state over a Unix socket
+//!
+//! The daemon holds a single Docker connection and keeps database health
+//! warm in the background, so tight loops of `dbarena list` and `dbarena
+//! wait` calls don't each reconnect to Docker and re-probe every container.
+//! Requests and responses are single lines of JSON.
+
+use futures::future::join_all;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::os::unix::fs::{DirBuilderExt, PermissionsExt};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::unix::{OwnedReadHalf, OwnedWriteHalf};
+use tokio::net::{UnixListener, UnixStream};
+use tokio::sync::{Notify, RwLock};
+use tracing::{debug, info, warn};
+
+use crate::container::models::Container;
+use crate::container::{ContainerManager, DockerClient};
+use crate::health::{probe_container, HealthStatus};
+use crate::{DBArenaError, Result};
+
+const HEALTH_REFRESH_INTERVAL: Duration = Duration::from_secs(2);
+const HEALTH_TIMEOUT: Duration = Duration::from_secs(2);
+const CONNECT_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// Path of the daemon socket; `DBARENA_SOCKET` overrides the default
+///
+/// The default is in a per-user directory: the runtime or local data
+/// directory, or failing both a temp directory named after the user.
+pub fn socket_path() -> PathBuf {
+    if let Ok(path) = std::env::var("DBARENA_SOCKET") {
+        return PathBuf::from(path);
+    }
+
+    let dir = match dirs::runtime_dir().or_else(dirs::data_local_dir) {
+        Some(dir) => dir.join("dbarena"),
+        None => {
+            let user = std::env::var("USER")
+                .or_else(|_| std::env::var("LOGNAME"))
+                .unwrap_or_else(|_| "default".to_string());
+            std::env::temp_dir().join(format!("dbarena-{}", user))
+        }
+    };
+    dir.join("dbarena.sock")
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum DaemonRequest {
+    Status,
+    List { all: bool },
+    /// Probe the named containers' health now rather than from the cache
+    Probe { containers: Vec<String> },
+    Shutdown,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DaemonStatus {
+    pub pid: u32,
+    /// Docker endpoint the daemon is connected to
+    pub endpoint: String,
+    pub started_at: i64,
+    /// Containers with cached health
+    pub tracked_containers: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContainerState {
+    pub container: Container,
+    pub health: Option<HealthStatus>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum DaemonResponse {
+    Status(DaemonStatus),
+    Containers {
+        endpoint: String,
+        containers: Vec<ContainerState>,
+    },
+    Ok,
+    Error {
+        message: String,
+    },
+}
+
+struct Daemon {
+    docker: DockerClient,
+    manager: ContainerManager,
+    started_at: i64,
+    health: RwLock<HashMap<String, HealthStatus>>,
+    shutdown: Notify,
+}
+
+impl Daemon {
+    fn new(docker: DockerClient) -> Self {
+        Self {
+            manager: ContainerManager::new(docker.clone()),
+            docker,
+            started_at: chrono::Utc::now().timestamp(),
+            health: RwLock::new(HashMap::new()),
+            shutdown: Notify::new(),
+        }
+    }
+
+    /// Re-probe every running container and replace the health cache
+    async fn refresh_health(&self) -> Result<()> {
+        let containers = self.manager.list_containers_uncached(false).await?;
+        let docker = self.docker.docker();
+        let probes = join_all(
+            containers
+                .iter()
+                .map(|c| probe_container(docker, c, HEALTH_TIMEOUT)),
+        )
+        .await;
+
+        let health = containers
+            .into_iter()
+            .zip(probes)
+            .filter_map(|(container, health)| health.map(|h| (container.id, h)))
+            .collect();
+        *self.health.write().await = health;
+        Ok(())
+    }
+
+    async fn handle(&self, request: DaemonRequest) -> DaemonResponse {
+        match request {
+            DaemonRequest::Status => DaemonResponse::Status(DaemonStatus {
+                pid: std::process::id(),
+                endpoint: self.docker.endpoint().to_string(),
+                started_at: self.started_at,
+                tracked_containers: self.health.read().await.len(),
+            }),
+            DaemonRequest::List { all } => match self.list(all).await {
+                Ok(containers) => DaemonResponse::Containers {
+                    endpoint: self.docker.endpoint().to_string(),
+                    containers,
+                },
+                Err(e) => DaemonResponse::Error {
+                    message: e.to_string(),
+                },
+            },
+            DaemonRequest::Probe { containers } => match self.probe(&containers).await {
+                Ok(containers) => DaemonResponse::Containers {
+                    endpoint: self.docker.endpoint().to_string(),
+                    containers,
+                },
+                Err(e) => DaemonResponse::Error {
+                    message: e.to_string(),
+                },
+            },
+            DaemonRequest::Shutdown => {
+                self.shutdown.notify_one();
+                DaemonResponse::Ok
+            }
+        }
+    }
+
+    /// Find each of `names` (name or ID prefix) and probe its health; names that
+    /// match no container are left out
+    async fn probe(&self, names: &[String]) -> Result<Vec<ContainerState>> {
+        let containers = self.manager.list_containers_uncached(true).await?;
+        let docker = self.docker.docker();
+
+        Ok(join_all(
+            names
+                .iter()
+                .filter_map(|name| {
+                    containers
+                        .iter()
+                        .find(|c| c.name == *name || c.id.starts_with(name.as_str()))
+                        .cloned()
+                })
+                .map(|container| async move {
+                    let health = probe_container(docker, &container, HEALTH_TIMEOUT).await;
+                    ContainerState { container, health }
+                }),
+        )
+        .await)
+    }
+
+    /// List containers fresh from Docker, attaching cached health where available
+    async fn list(&self, all: bool) -> Result<Vec<ContainerState>> {
+        let containers = self.manager.list_containers_uncached(all).await?;
+        let cached = self.health.read().await.clone();
+        let cached = &cached;
+        let docker = self.docker.docker();
+
+        Ok(join_all(containers.into_iter().map(|container| async move {
+            let health = match cached.get(&container.id) {
+                Some(health) => Some(*health),
+                None => probe_container(docker, &container, HEALTH_TIMEOUT).await,
+            };
+            ContainerState { container, health }
+        }))
+        .await)
+    }
+
+    async fn serve_connection(&self, stream: UnixStream) -> Result<()> {
+        let (reader, mut writer) = stream.into_split();
+        let mut lines = BufReader::new(reader).lines();
+
+        while let Some(line) = lines.next_line().await? {
+            let response = match serde_json::from_str::<DaemonRequest>(&line) {
+                Ok(request) => self.handle(request).await,
+                Err(e) => DaemonResponse::Error {
+                    message: format!("Invalid request: {}", e),
+                },
+            };
+
+            let mut out = serde_json::to_string(&response)?;
+            out.push('\n');
+            writer.write_all(out.as_bytes()).await?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Run the daemon on `path` until it receives a shutdown request or Ctrl+C
+pub async fn run(path: &Path) -> Result<()> {
+    let docker = DockerClient::new()?;
+    docker.verify_connection().await?;
+
+    // Only the user running the daemon may reach it: a new socket directory
+    // is private, and the socket itself is made owner-only once bound
+    if let Some(parent) = path.parent() {
+        std::fs::DirBuilder::new()
+            .recursive(true)
+            .mode(0o700)
+            .create(parent)?;
+    }
+    if path.exists() {
+        if UnixStream::connect(path).await.is_ok() {
+            return Err(DBArenaError::Other(format!(
+                "A daemon is already listening on {}",
+                path.display()
+            )));
+        }
+        // Left behind by a daemon that didn't shut down cleanly
+        std::fs::remove_file(path)?;
+    }
+
+    let listener = UnixListener::bind(path)?;
+    std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o600))?;
+    let daemon = Arc::new(Daemon::new(docker));
+    info!("Daemon listening on {}", path.display());
+
+    let refresher = {
+        let daemon = daemon.clone();
+        tokio::spawn(async move {
+            loop {
+                if let Err(e) = daemon.refresh_health().await {
+                    warn!("Health refresh failed: {}", e);
+                }
+                tokio::time::sleep(HEALTH_REFRESH_INTERVAL).await;
+            }
+        })
+    };
+
+    loop {
+        tokio::select! {
+            accepted = listener.accept() => match accepted {
+                Ok((stream, _)) => {
+                    let daemon = daemon.clone();
+                    tokio::spawn(async move {
+                        if let Err(e) = daemon.serve_connection(stream).await {
+                            debug!("Daemon connection closed with error: {}", e);
+                        }
+                    });
+                }
+                Err(e) => warn!("Failed to accept daemon connection: {}", e),
+            },
+            _ = daemon.shutdown.notified() => break,
+            _ = tokio::signal::ctrl_c() => break,
+        }
+    }
+
+    refresher.abort();
+    let _ = std::fs::remove_file(path);
+    info!("Daemon stopped");
+    Ok(())
+}
+
+/// Client side of the daemon socket
+pub struct DaemonClient {
+    reader: BufReader<OwnedReadHalf>,
+    writer: OwnedWriteHalf,
+}
+
+impl DaemonClient {
+    /// Connect to the daemon, or `None` if none is listening
+    pub async fn connect() -> Option<Self> {
+        Self::connect_to(&socket_path()).await
+    }
+
+    pub async fn connect_to(path: &Path) -> Option<Self> {
+        let stream = tokio::time::timeout(CONNECT_TIMEOUT, UnixStream::connect(path))
+            .await
+            .ok()?
+            .ok()?;
+        Some(Self::from_stream(stream))
+    }
+
+    pub fn from_stream(stream: UnixStream) -> Self {
+        let (reader, writer) = stream.into_split();
+        Self {
+            reader: BufReader::new(reader),
+            writer,
+        }
+    }
+
+    pub async fn request(&mut self, request: &DaemonRequest) -> Result<DaemonResponse> {
+        let mut line = serde_json::to_string(request)?;
+        line.push('\n');
+        self.writer.write_all(line.as_bytes()).await?;
+
+        let mut response = String::new();
+        if self.reader.read_line(&mut response).await? == 0 {
+            return Err(DBArenaError::Other(
+                "Daemon closed the connection".to_string(),
+            ));
+        }
+        Ok(serde_json::from_str(&response)?)
+    }
+
+    pub async fn status(&mut self) -> Result<DaemonStatus> {
+        match self.request(&DaemonRequest::Status).await? {
+            DaemonResponse::Status(status) => Ok(status),
+            other => Err(unexpected(other)),
+        }
+    }
+
+    /// List containers, returning the daemon's Docker endpoint alongside them
+    pub async fn list(&mut self, all: bool) -> Result<(String, Vec<ContainerState>)> {
+        match self.request(&DaemonRequest::List { all }).await? {
+            DaemonResponse::Containers {
+                endpoint,
+                containers,
+            } => Ok((endpoint, containers)),
+            other => Err(unexpected(other)),
+        }
+    }
+
+    /// Find and probe containers by name or ID prefix, returning the daemon's
+    /// Docker endpoint alongside those that exist
+    pub async fn probe(&mut self, containers: &[String]) -> Result<(String, Vec<ContainerState>)> {
+        let request = DaemonRequest::Probe {
+            containers: containers.to_vec(),
+        };
+        match self.request(&request).await? {
+            DaemonResponse::Containers {
+                endpoint,
+                containers,
+            } => Ok((endpoint, containers)),
+            other => Err(unexpected(other)),
+        }
+    }
+
+    pub async fn shutdown(&mut self) -> Result<()> {
+        match self.request(&DaemonRequest::Shutdown).await? {
+            DaemonResponse::Ok => Ok(()),
+            other => Err(unexpected(other)),
+        }
+    }
+}
+
+fn unexpected(response: DaemonResponse) -> DBArenaError {
+    match response {
+        DaemonResponse::Error { message } => DBArenaError::Other(message),
+        other => DBArenaError::Other(format!("Unexpected daemon response: {:?}", other)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_request_wire_format() {
+        assert_eq!(
+            serde_json::to_string(&DaemonRequest::List { all: true }).unwrap(),
+            r#"{"op":"list","all":true}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&DaemonRequest::Probe {
+                containers: vec!["pg".to_string()]
+            })
+            .unwrap(),
+            r#"{"op":"probe","containers":["pg"]}"#
+        );
+        assert!(matches!(
+            serde_json::from_str::<DaemonRequest>(r#"{"op":"shutdown"}"#).unwrap(),
+            DaemonRequest::Shutdown
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_status_and_shutdown_over_socket() {
+        let (server, client) = UnixStream::pair().unwrap();
+        let daemon = Arc::new(Daemon::new(DockerClient::new().unwrap()));
+
+        let serving = {
+            let daemon = daemon.clone();
+            tokio::spawn(async move { daemon.serve_connection(server).await })
+        };
+
+        let mut client = DaemonClient::from_stream(client);
+        let status = client.status().await.unwrap();
+        assert_eq!(status.pid, std::process::id());
+        assert_eq!(status.tracked_containers, 0);
+
+        client.shutdown().await.unwrap();
+        daemon.shutdown.notified().await;
+
+        drop(client);
+        serving.await.unwrap().unwrap();
+    }
+}
@@ -0,0 +1,114 @@
+use std::collections::HashMap;
+
+use crate::container::DatabaseType;
+use crate::error::Result;
+
+use super::collector::DockerDatabaseMetricsCollector;
+use super::models::DatabaseMetrics;
+
+/// Node metrics read on every sample; counters are cumulative since node start
+const NODE_METRICS: &[&str] = &[
+    "sql.conns",
+    "sql.query.count",
+    "sql.select.count",
+    "sql.insert.count",
+    "sql.update.count",
+    "sql.delete.count",
+    "sql.txn.commit.count",
+    "sql.txn.rollback.count",
+];
+
+/// Collect CockroachDB metrics from `crdb_internal.node_metrics` via Docker exec
+///
+/// Rates are the change in each cumulative counter since the previous sample.
+pub async fn collect_metrics(
+    collector: &DockerDatabaseMetricsCollector,
+    container_id: &str,
+) -> Result<DatabaseMetrics> {
+    let mut metrics = DatabaseMetrics::new(container_id.to_string(), DatabaseType::CockroachDB);
+
+    let previous = collector.get_previous_sample(container_id).await;
+    let time_delta = if let Some(ref prev) = previous {
+        ((metrics.timestamp - prev.timestamp) as f64).max(1.0)
+    } else {
+        1.0
+    };
+
+    let names: Vec<String> = NODE_METRICS.iter().map(|name| format!("'{}'", name)).collect();
+    let query = format!(
+        "SELECT name, value FROM crdb_internal.node_metrics WHERE name IN ({})",
+        names.join(", ")
+    );
+
+    if let Ok(output) = collector
+        .exec_query(
+            container_id,
+            vec!["cockroach", "sql", "--insecure", "--format=tsv", "-e", &query],
+        )
+        .await
+    {
+        let values = parse_node_metrics(&output);
+        let get = |name: &str| values.get(name).copied().unwrap_or(0);
+
+        metrics.active_connections = get("sql.conns");
+
+        // Rate of a counter since the previous sample, stored for the next one
+        let mut rate = |name: &str| {
+            let key = format!("cumulative_{}", name);
+            let current = get(name);
+            let before = previous
+                .as_ref()
+                .and_then(|prev| prev.extras.get(&key))
+                .and_then(|v| v.as_u64());
+            metrics.extras.insert(key, current.into());
+            before.map_or(0, |before| current.saturating_sub(before))
+        };
+
+        let queries = rate("sql.query.count");
+        let selects = rate("sql.select.count");
+        let inserts = rate("sql.insert.count");
+        let updates = rate("sql.update.count");
+        let deletes = rate("sql.delete.count");
+        let commits = rate("sql.txn.commit.count");
+        let rollbacks = rate("sql.txn.rollback.count");
+
+        metrics.queries_per_second = queries as f64 / time_delta;
+        metrics.query_breakdown.select_count = selects;
+        metrics.query_breakdown.insert_count = inserts;
+        metrics.query_breakdown.update_count = updates;
+        metrics.query_breakdown.delete_count = deletes;
+        metrics.commits_per_second = commits as f64 / time_delta;
+        metrics.rollbacks_per_second = rollbacks as f64 / time_delta;
+        metrics.transactions_per_second = (commits + rollbacks) as f64 / time_delta;
+    }
+
+    Ok(metrics)
+}
+
+/// Parse `name<TAB>value` rows, skipping the header and non-numeric values
+pub fn parse_node_metrics(output: &str) -> HashMap<String, u64> {
+    output
+        .lines()
+        .filter_map(|line| line.trim().split_once('\t'))
+        .filter_map(|(name, value)| {
+            // Counters come back as floats, e.g. 42 or 4.2e+06
+            let value = value.trim().parse::<f64>().ok()?;
+            Some((name.trim().to_string(), value as u64))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_node_metrics() {
+        let output = "name\tvalue\nsql.conns\t3\nsql.query.count\t4.2e+06\n";
+        let values = parse_node_metrics(output);
+
+        assert_eq!(values.get("sql.conns"), Some(&3));
+        assert_eq!(values.get("sql.query.count"), Some(&4_200_000));
+        assert!(!values.contains_key("name"));
+    }
+}
@@ -4,11 +4,11 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
-use crate::container::DatabaseType;
+use crate::container::{run_on_host, ConnParams, DatabaseType};
 use crate::error::Result;
 
 use super::models::DatabaseMetrics;
-use super::{mysql, postgres, sqlserver};
+use super::{cockroachdb, mongodb, mysql, postgres, redis, sqlserver};
 
 /// Trait for collecting database-specific metrics
 #[async_trait]
@@ -72,6 +72,22 @@ impl DockerDatabaseMetricsCollector {
         Ok(output)
     }
 
+    /// Run a client command built from `params`: in the container, or on this
+    /// host when `params` point at an external database
+    pub async fn exec_client(
+        &self,
+        container_id: &str,
+        params: &ConnParams,
+        command: &[String],
+    ) -> Result<String> {
+        if params.is_external() {
+            let output = run_on_host(params, command).await?;
+            return Ok(output.stdout + &output.stderr);
+        }
+        self.exec_query(container_id, command.iter().map(String::as_str).collect())
+            .await
+    }
+
     /// Store the current sample for rate calculation
     pub async fn store_sample(&self, metrics: &DatabaseMetrics) {
         let mut samples = self.previous_samples.lock().await;
@@ -92,6 +108,14 @@ impl DatabaseMetricsCollector for DockerDatabaseMetricsCollector {
             DatabaseType::Postgres => postgres::collect_metrics(self, container_id).await?,
             DatabaseType::MySQL => mysql::collect_metrics(self, container_id).await?,
             DatabaseType::SQLServer => sqlserver::collect_metrics(self, container_id).await?,
+            DatabaseType::Redis => redis::collect_metrics(self, container_id).await?,
+            DatabaseType::MongoDB => mongodb::collect_metrics(self, container_id).await?,
+            DatabaseType::CockroachDB => cockroachdb::collect_metrics(self, container_id).await?,
+            DatabaseType::SQLite => {
+                return Err(crate::DBArenaError::Other(
+                    "Database metrics are not available for SQLite (no server)".to_string(),
+                ))
+            }
         };
 
         // Store this sample for next iteration's rate calculation
@@ -100,8 +124,9 @@ impl DatabaseMetricsCollector for DockerDatabaseMetricsCollector {
         Ok(metrics)
     }
 
-    async fn supports_database_type(&self, _db_type: DatabaseType) -> bool {
-        // We support all three database types
-        true
+    async fn supports_database_type(&self, db_type: DatabaseType) -> bool {
+        // Every server database is supported (Redis, MongoDB and CockroachDB with
+        // simplified metric sets); embedded SQLite has no server to ask
+        !db_type.is_embedded()
     }
 }
@@ -2,13 +2,25 @@
 //!
 //! This module provides collectors for gathering database-specific metrics
 //! (connections, queries, transactions, cache hit ratios, replication lag, etc.)
-//! from PostgreSQL, MySQL, and SQL Server containers using Docker exec.
+//! from PostgreSQL, MySQL, SQL Server, Redis, MongoDB, and CockroachDB containers
+//! using Docker exec.
 
+mod cockroachdb;
 pub mod collector;
 pub mod models;
+mod mongodb;
 mod mysql;
 mod postgres;
+mod redis;
 mod sqlserver;
 
 pub use collector::{DatabaseMetricsCollector, DockerDatabaseMetricsCollector};
 pub use models::{DatabaseMetrics, QueryBreakdown};
+
+// Parsers for the clients' raw output, so it can be checked without Docker
+pub use cockroachdb::parse_node_metrics;
+pub use mongodb::{parse_server_status, ServerStatus};
+pub use mysql::parse_status_rows;
+pub use postgres::{parse_connection_states, parse_database_stats, PgDatabaseStats};
+pub use redis::parse_info;
+pub use sqlserver::parse_performance_counters;
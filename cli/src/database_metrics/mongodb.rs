@@ -0,0 +1,146 @@
+use serde::Deserialize;
+
+use crate::container::DatabaseType;
+use crate::error::Result;
+
+use super::collector::DockerDatabaseMetricsCollector;
+use super::models::{DatabaseMetrics, QueryBreakdown};
+
+/// Summarise `serverStatus` as flat JSON; counters are Longs, so coerce them to numbers
+const SERVER_STATUS_SCRIPT: &str = "const s = db.serverStatus(); \
+     const o = s.opcounters; \
+     print(JSON.stringify({ \
+       current: s.connections.current, \
+       available: s.connections.available, \
+       query: Number(o.query), \
+       insert: Number(o.insert), \
+       update: Number(o.update), \
+       delete: Number(o.delete), \
+       resident_mb: s.mem.resident, \
+       set_name: s.repl ? s.repl.setName : null \
+     }))";
+
+/// Connection, opcounter and memory figures from `serverStatus`
+#[derive(Debug, Deserialize)]
+pub struct ServerStatus {
+    pub current: u64,
+    pub available: u64,
+    pub query: u64,
+    pub insert: u64,
+    pub update: u64,
+    pub delete: u64,
+    pub resident_mb: Option<u64>,
+    pub set_name: Option<String>,
+}
+
+/// Collect MongoDB metrics from `serverStatus` using mongosh via Docker exec
+///
+/// Opcounters map onto the query breakdown (query → SELECT); MongoDB has no
+/// SQL transactions, so transaction rates stay at zero.
+pub async fn collect_metrics(
+    collector: &DockerDatabaseMetricsCollector,
+    container_id: &str,
+) -> Result<DatabaseMetrics> {
+    let mut metrics = DatabaseMetrics::new(container_id.to_string(), DatabaseType::MongoDB);
+
+    // Get previous sample for rate calculation
+    let previous = collector.get_previous_sample(container_id).await;
+    let time_delta = if let Some(ref prev) = previous {
+        (metrics.timestamp - prev.timestamp) as f64
+    } else {
+        1.0
+    };
+
+    if let Ok(output) = collector
+        .exec_query(
+            container_id,
+            vec![
+                "mongosh",
+                "--quiet",
+                "-u",
+                "root",
+                "-p",
+                "mongo",
+                "--authenticationDatabase",
+                "admin",
+                "--eval",
+                SERVER_STATUS_SCRIPT,
+            ],
+        )
+        .await
+    {
+        if let Some(status) = parse_server_status(&output) {
+            metrics.active_connections = status.current;
+            metrics.max_connections = Some(status.current + status.available);
+
+            if let Some(ref prev) = previous {
+                if time_delta > 0.0 {
+                    let delta = |key: &str, value: u64| {
+                        value.saturating_sub(
+                            prev.extras.get(key).and_then(|v| v.as_u64()).unwrap_or(0),
+                        )
+                    };
+
+                    metrics.query_breakdown = QueryBreakdown {
+                        select_count: delta("cumulative_query", status.query),
+                        insert_count: delta("cumulative_insert", status.insert),
+                        update_count: delta("cumulative_update", status.update),
+                        delete_count: delta("cumulative_delete", status.delete),
+                    };
+                    metrics.queries_per_second =
+                        metrics.query_breakdown.total() as f64 / time_delta;
+                }
+            }
+
+            metrics
+                .extras
+                .insert("cumulative_query".to_string(), status.query.into());
+            metrics
+                .extras
+                .insert("cumulative_insert".to_string(), status.insert.into());
+            metrics
+                .extras
+                .insert("cumulative_update".to_string(), status.update.into());
+            metrics
+                .extras
+                .insert("cumulative_delete".to_string(), status.delete.into());
+            if let Some(resident_mb) = status.resident_mb {
+                metrics
+                    .extras
+                    .insert("resident_memory_mb".to_string(), resident_mb.into());
+            }
+
+            metrics.replication_status =
+                Some(status.set_name.unwrap_or_else(|| "standalone".to_string()));
+        }
+    }
+
+    Ok(metrics)
+}
+
+/// Find the status JSON in mongosh output, skipping any warnings it prints first
+pub fn parse_server_status(output: &str) -> Option<ServerStatus> {
+    output
+        .lines()
+        .rev()
+        .map(str::trim)
+        .find(|line| line.starts_with('{'))
+        .and_then(|line| serde_json::from_str(line).ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_server_status() {
+        let output = "Warning: something\n{\"current\":4,\"available\":838856,\"query\":10,\"insert\":3,\"update\":1,\"delete\":0,\"resident_mb\":92,\"set_name\":null}\n";
+        let status = parse_server_status(output).unwrap();
+
+        assert_eq!(status.current, 4);
+        assert_eq!(status.query, 10);
+        assert_eq!(status.resident_mb, Some(92));
+        assert!(status.set_name.is_none());
+        assert!(parse_server_status("MongoServerError: auth failed").is_none());
+    }
+}
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use crate::container::DatabaseType;
 use crate::error::Result;
 
@@ -35,26 +37,15 @@ pub async fn collect_metrics(
         )
         .await
     {
-        for line in conn_output.lines() {
-            let parts: Vec<&str> = line.split_whitespace().collect();
-            if parts.len() >= 2 {
-                match parts[0] {
-                    "Threads_connected" => {
-                        if let Ok(count) = parts[1].parse::<u64>() {
-                            metrics.active_connections = count;
-                        }
-                    }
-                    "Max_used_connections" => {
-                        // Store for reference
-                        if let Ok(max_used) = parts[1].parse::<u64>() {
-                            metrics
-                                .extras
-                                .insert("max_used_connections".to_string(), max_used.into());
-                        }
-                    }
-                    _ => {}
-                }
-            }
+        let status = parse_status_rows(&conn_output);
+        if let Some(&count) = status.get("Threads_connected") {
+            metrics.active_connections = count;
+        }
+        if let Some(&max_used) = status.get("Max_used_connections") {
+            // Store for reference
+            metrics
+                .extras
+                .insert("max_used_connections".to_string(), max_used.into());
         }
     }
 
@@ -74,13 +65,8 @@ pub async fn collect_metrics(
         )
         .await
     {
-        for line in max_conn_output.lines() {
-            let parts: Vec<&str> = line.split_whitespace().collect();
-            if parts.len() >= 2 && parts[0] == "max_connections" {
-                if let Ok(max_conn) = parts[1].parse::<u64>() {
-                    metrics.max_connections = Some(max_conn);
-                }
-            }
+        if let Some(&max_conn) = parse_status_rows(&max_conn_output).get("max_connections") {
+            metrics.max_connections = Some(max_conn);
         }
     }
 
@@ -100,28 +86,14 @@ pub async fn collect_metrics(
         )
         .await
     {
-        let mut select_count = 0u64;
-        let mut insert_count = 0u64;
-        let mut update_count = 0u64;
-        let mut delete_count = 0u64;
-        let mut commits = 0u64;
-        let mut rollbacks = 0u64;
-
-        for line in query_output.lines() {
-            let parts: Vec<&str> = line.split_whitespace().collect();
-            if parts.len() >= 2 {
-                let value = parts[1].parse::<u64>().unwrap_or(0);
-                match parts[0] {
-                    "Com_select" => select_count = value,
-                    "Com_insert" => insert_count = value,
-                    "Com_update" => update_count = value,
-                    "Com_delete" => delete_count = value,
-                    "Com_commit" => commits = value,
-                    "Com_rollback" => rollbacks = value,
-                    _ => {}
-                }
-            }
-        }
+        let status = parse_status_rows(&query_output);
+        let counter = |name: &str| status.get(name).copied().unwrap_or(0);
+        let select_count = counter("Com_select");
+        let insert_count = counter("Com_insert");
+        let update_count = counter("Com_update");
+        let delete_count = counter("Com_delete");
+        let commits = counter("Com_commit");
+        let rollbacks = counter("Com_rollback");
 
         // Calculate rates if we have previous sample
         if let Some(ref prev) = previous {
@@ -215,20 +187,12 @@ pub async fn collect_metrics(
         )
         .await
     {
-        let mut read_requests = 0u64;
-        let mut disk_reads = 0u64;
-
-        for line in buffer_output.lines() {
-            let parts: Vec<&str> = line.split_whitespace().collect();
-            if parts.len() >= 2 {
-                let value = parts[1].parse::<u64>().unwrap_or(0);
-                match parts[0] {
-                    "Innodb_buffer_pool_read_requests" => read_requests = value,
-                    "Innodb_buffer_pool_reads" => disk_reads = value,
-                    _ => {}
-                }
-            }
-        }
+        let status = parse_status_rows(&buffer_output);
+        let read_requests = status
+            .get("Innodb_buffer_pool_read_requests")
+            .copied()
+            .unwrap_or(0);
+        let disk_reads = status.get("Innodb_buffer_pool_reads").copied().unwrap_or(0);
 
         if read_requests > 0 {
             let cache_hits = read_requests.saturating_sub(disk_reads);
@@ -271,3 +235,17 @@ pub async fn collect_metrics(
 
     Ok(metrics)
 }
+
+/// Parse `SHOW STATUS`/`SHOW VARIABLES` rows (`mysql -N`: name, whitespace,
+/// value), skipping rows whose value isn't a number
+pub fn parse_status_rows(output: &str) -> HashMap<String, u64> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.split_whitespace();
+            let name = parts.next()?;
+            let value = parts.next()?.parse().ok()?;
+            Some((name.to_string(), value))
+        })
+        .collect()
+}
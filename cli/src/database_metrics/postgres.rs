@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use crate::container::DatabaseType;
 use crate::error::Result;
 
@@ -39,22 +41,15 @@ pub async fn collect_metrics(
         )
         .await
     {
-        for line in conn_output.lines() {
-            let line = line.trim();
-            if line.is_empty() {
-                continue;
-            }
-            let parts: Vec<&str> = line.split(',').collect();
-            if parts.len() == 2 {
-                let state = parts[0].to_string();
-                if let Ok(count) = parts[1].parse::<u64>() {
-                    metrics.connections_by_state.insert(state.clone(), count);
-                    if state == "active" || state == "idle" || state == "idle in transaction" {
-                        metrics.active_connections += count;
-                    }
-                }
-            }
-        }
+        let states = parse_connection_states(&conn_output);
+        metrics.active_connections = states
+            .iter()
+            .filter(|(state, _)| {
+                matches!(state.as_str(), "active" | "idle" | "idle in transaction")
+            })
+            .map(|(_, count)| count)
+            .sum();
+        metrics.connections_by_state = states;
     }
 
     // Query 2: Max connections
@@ -100,18 +95,16 @@ pub async fn collect_metrics(
         )
         .await
     {
-        let line = stats_output.trim();
-        let parts: Vec<&str> = line.split(',').collect();
-        if parts.len() >= 8 {
-            // Parse cumulative counters
-            let commits = parts[1].parse::<u64>().unwrap_or(0);
-            let rollbacks = parts[2].parse::<u64>().unwrap_or(0);
-            let rows_returned = parts[3].parse::<u64>().unwrap_or(0);
-            let rows_fetched = parts[4].parse::<u64>().unwrap_or(0);
-            let rows_inserted = parts[5].parse::<u64>().unwrap_or(0);
-            let rows_updated = parts[6].parse::<u64>().unwrap_or(0);
-            let rows_deleted = parts[7].parse::<u64>().unwrap_or(0);
-
+        if let Some(PgDatabaseStats {
+            commits,
+            rollbacks,
+            rows_returned,
+            rows_fetched,
+            rows_inserted,
+            rows_updated,
+            rows_deleted,
+        }) = parse_database_stats(&stats_output)
+        {
             // Calculate rates if we have a previous sample
             if let Some(ref prev) = previous {
                 let commits_delta = commits.saturating_sub(
@@ -261,3 +254,44 @@ pub async fn collect_metrics(
 
     Ok(metrics)
 }
+
+/// Cumulative counters of the current database from `pg_stat_database`
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct PgDatabaseStats {
+    pub commits: u64,
+    pub rollbacks: u64,
+    pub rows_returned: u64,
+    pub rows_fetched: u64,
+    pub rows_inserted: u64,
+    pub rows_updated: u64,
+    pub rows_deleted: u64,
+}
+
+/// Parse the `state,count` rows of the `pg_stat_activity` query
+pub fn parse_connection_states(output: &str) -> HashMap<String, u64> {
+    output
+        .lines()
+        .filter_map(|line| line.trim().rsplit_once(','))
+        .filter_map(|(state, count)| Some((state.to_string(), count.parse().ok()?)))
+        .collect()
+}
+
+/// Parse the comma-separated `pg_stat_database` row (numbackends,
+/// xact_commit, xact_rollback, tup_returned, tup_fetched, tup_inserted,
+/// tup_updated, tup_deleted)
+pub fn parse_database_stats(output: &str) -> Option<PgDatabaseStats> {
+    let parts: Vec<&str> = output.trim().split(',').collect();
+    if parts.len() < 8 {
+        return None;
+    }
+    let counter = |i: usize| parts[i].trim().parse::<u64>().unwrap_or(0);
+    Some(PgDatabaseStats {
+        commits: counter(1),
+        rollbacks: counter(2),
+        rows_returned: counter(3),
+        rows_fetched: counter(4),
+        rows_inserted: counter(5),
+        rows_updated: counter(6),
+        rows_deleted: counter(7),
+    })
+}
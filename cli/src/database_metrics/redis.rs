@@ -0,0 +1,90 @@
+use std::collections::HashMap;
+
+use crate::container::DatabaseType;
+use crate::error::Result;
+
+use super::collector::DockerDatabaseMetricsCollector;
+use super::models::DatabaseMetrics;
+
+/// Collect Redis metrics from `INFO` using redis-cli via Docker exec
+///
+/// Redis has no SQL, so only connections, ops/sec, and keyspace hit ratio
+/// are populated; memory usage is reported in extras.
+pub async fn collect_metrics(
+    collector: &DockerDatabaseMetricsCollector,
+    container_id: &str,
+) -> Result<DatabaseMetrics> {
+    let mut metrics = DatabaseMetrics::new(container_id.to_string(), DatabaseType::Redis);
+
+    if let Ok(info_output) = collector
+        .exec_query(container_id, vec!["redis-cli", "INFO"])
+        .await
+    {
+        let info = parse_info(&info_output);
+        let get_u64 = |key: &str| info.get(key).and_then(|v| v.parse::<u64>().ok());
+
+        if let Some(clients) = get_u64("connected_clients") {
+            metrics.active_connections = clients;
+        }
+        metrics.max_connections = get_u64("maxclients");
+
+        if let Some(ops) = info
+            .get("instantaneous_ops_per_sec")
+            .and_then(|v| v.parse::<f64>().ok())
+        {
+            metrics.queries_per_second = ops;
+        }
+
+        if let (Some(hits), Some(misses)) = (get_u64("keyspace_hits"), get_u64("keyspace_misses")) {
+            let total = hits + misses;
+            if total > 0 {
+                metrics.cache_hit_ratio = Some((hits as f64 / total as f64) * 100.0);
+            }
+        }
+
+        if let Some(used_memory) = get_u64("used_memory") {
+            metrics
+                .extras
+                .insert("used_memory".to_string(), used_memory.into());
+        }
+        if let Some(human) = info.get("used_memory_human") {
+            metrics
+                .extras
+                .insert("used_memory_human".to_string(), human.clone().into());
+        }
+
+        if let Some(role) = info.get("role") {
+            metrics.replication_status = Some(role.clone());
+        }
+    }
+
+    Ok(metrics)
+}
+
+/// Parse `INFO` output into key/value pairs, skipping section headers
+pub fn parse_info(output: &str) -> HashMap<String, String> {
+    output
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| {
+            line.split_once(':')
+                .map(|(k, v)| (k.to_string(), v.trim().to_string()))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_info() {
+        let output = "# Clients\r\nconnected_clients:3\r\nmaxclients:10000\r\n\r\n# Memory\r\nused_memory:1048576\r\nused_memory_human:1.00M\r\n";
+        let info = parse_info(output);
+
+        assert_eq!(info.get("connected_clients").map(String::as_str), Some("3"));
+        assert_eq!(info.get("used_memory_human").map(String::as_str), Some("1.00M"));
+        assert!(!info.contains_key("# Clients"));
+    }
+}
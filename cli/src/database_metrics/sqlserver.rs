@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use crate::container::DatabaseType;
 use crate::error::Result;
 
@@ -73,22 +75,9 @@ pub async fn collect_metrics(
     ]);
 
     if let Ok(perf_output) = collector.exec_query(container_id, query_cmd).await {
-        let mut batch_requests = 0u64;
-        let mut transactions = 0u64;
-
-        for line in perf_output.lines() {
-            let parts: Vec<&str> = line.split(',').collect();
-            if parts.len() >= 2 {
-                let counter_name = parts[0].trim();
-                if let Ok(value) = parts[1].trim().parse::<u64>() {
-                    match counter_name {
-                        "Batch Requests/sec" => batch_requests = value,
-                        "Transactions/sec" => transactions = value,
-                        _ => {}
-                    }
-                }
-            }
-        }
+        let counters = parse_performance_counters(&perf_output);
+        let batch_requests = counters.get("Batch Requests/sec").copied().unwrap_or(0);
+        let transactions = counters.get("Transactions/sec").copied().unwrap_or(0);
 
         // Calculate rates if we have previous sample
         if let Some(ref prev) = previous {
@@ -180,3 +169,13 @@ pub async fn collect_metrics(
 
     Ok(metrics)
 }
+
+/// Parse `counter_name,cntr_value` rows of `sqlcmd -h -1 -W -s ,` output,
+/// skipping the `(N rows affected)` footer
+pub fn parse_performance_counters(output: &str) -> HashMap<String, u64> {
+    output
+        .lines()
+        .filter_map(|line| line.split_once(','))
+        .filter_map(|(name, value)| Some((name.trim().to_string(), value.trim().parse().ok()?)))
+        .collect()
+}
@@ -5,6 +5,9 @@ pub enum DBArenaError {
     #[error("Docker daemon not running or not accessible")]
     DockerNotAvailable,
 
+    #[error("Failed to connect to Docker at {0}: {1}")]
+    DockerConnectionFailed(String, String),
+
     #[error("Docker operation failed: {0}")]
     DockerError(#[from] bollard::errors::Error),
 
@@ -17,9 +20,18 @@ pub enum DBArenaError {
     #[error("Port {0} is already in use")]
     PortInUse(u16),
 
+    #[error("Port {0} is already in use by {1}")]
+    PortOccupied(u16, String),
+
+    #[error("A container named '{name}' already exists. Use --recreate to replace it, --reuse to use it as-is, or choose a different --name")]
+    NameInUse { name: String },
+
     #[error("Invalid configuration: {0}")]
     InvalidConfig(String),
 
+    #[error("Image not found: {0}. Check that the version tag exists")]
+    ImageNotFound(String),
+
     #[error("Image pull failed: {0}")]
     ImagePullFailed(String),
 
@@ -32,6 +44,9 @@ pub enum DBArenaError {
     #[error("JSON error: {0}")]
     JsonError(#[from] serde_json::Error),
 
+    #[error("YAML error: {0}")]
+    YamlError(#[from] serde_yaml::Error),
+
     #[error("Configuration error: {0}")]
     ConfigError(String),
 
@@ -233,3 +233,199 @@ impl HealthChecker for SQLServerHealthChecker {
         }
     }
 }
+
+pub struct RedisHealthChecker {
+    docker: Docker,
+}
+
+impl RedisHealthChecker {
+    pub fn new(docker: Docker) -> Self {
+        Self { docker }
+    }
+}
+
+#[async_trait]
+impl HealthChecker for RedisHealthChecker {
+    async fn check(&self, container_id: &str) -> Result<bool> {
+        debug!("Checking Redis health for container {}", container_id);
+
+        let exec = self
+            .docker
+            .create_exec(
+                container_id,
+                CreateExecOptions {
+                    cmd: Some(vec!["redis-cli", "ping"]),
+                    attach_stdout: Some(true),
+                    attach_stderr: Some(true),
+                    ..Default::default()
+                },
+            )
+            .await?;
+
+        if let StartExecResults::Attached { mut output, .. } =
+            self.docker.start_exec(&exec.id, None).await?
+        {
+            let mut stdout = String::new();
+            while let Some(Ok(msg)) = output.next().await {
+                stdout.push_str(&msg.to_string());
+            }
+
+            let is_healthy = stdout.contains("PONG");
+            debug!("Redis health check result: {}", is_healthy);
+            Ok(is_healthy)
+        } else {
+            Ok(false)
+        }
+    }
+}
+
+pub struct MongoDBHealthChecker {
+    docker: Docker,
+}
+
+impl MongoDBHealthChecker {
+    pub fn new(docker: Docker) -> Self {
+        Self { docker }
+    }
+}
+
+#[async_trait]
+impl HealthChecker for MongoDBHealthChecker {
+    async fn check(&self, container_id: &str) -> Result<bool> {
+        debug!("Checking MongoDB health for container {}", container_id);
+
+        // ping needs no authentication, so this works before users are set up
+        let exec = self
+            .docker
+            .create_exec(
+                container_id,
+                CreateExecOptions {
+                    cmd: Some(vec![
+                        "mongosh",
+                        "--quiet",
+                        "--eval",
+                        "db.adminCommand('ping').ok",
+                    ]),
+                    attach_stdout: Some(true),
+                    attach_stderr: Some(true),
+                    ..Default::default()
+                },
+            )
+            .await?;
+
+        if let StartExecResults::Attached { mut output, .. } =
+            self.docker.start_exec(&exec.id, None).await?
+        {
+            let mut stdout = String::new();
+            while let Some(Ok(msg)) = output.next().await {
+                stdout.push_str(&msg.to_string());
+            }
+
+            let is_healthy = stdout.trim() == "1";
+            debug!("MongoDB health check result: {}", is_healthy);
+            Ok(is_healthy)
+        } else {
+            Ok(false)
+        }
+    }
+}
+
+pub struct CockroachDBHealthChecker {
+    docker: Docker,
+}
+
+impl CockroachDBHealthChecker {
+    pub fn new(docker: Docker) -> Self {
+        Self { docker }
+    }
+}
+
+#[async_trait]
+impl HealthChecker for CockroachDBHealthChecker {
+    async fn check(&self, container_id: &str) -> Result<bool> {
+        debug!("Checking CockroachDB health for container {}", container_id);
+
+        // ready=1 only succeeds once the node is live and accepting SQL clients
+        let exec = self
+            .docker
+            .create_exec(
+                container_id,
+                CreateExecOptions {
+                    cmd: Some(vec![
+                        "curl",
+                        "--silent",
+                        "--fail",
+                        "http://localhost:8080/health?ready=1",
+                    ]),
+                    attach_stdout: Some(true),
+                    attach_stderr: Some(true),
+                    ..Default::default()
+                },
+            )
+            .await?;
+
+        if let StartExecResults::Attached { mut output, .. } =
+            self.docker.start_exec(&exec.id, None).await?
+        {
+            while let Some(Ok(_)) = output.next().await {}
+
+            let inspect = self.docker.inspect_exec(&exec.id).await?;
+            let is_healthy = inspect.exit_code == Some(0);
+            debug!("CockroachDB health check result: {}", is_healthy);
+            Ok(is_healthy)
+        } else {
+            Ok(false)
+        }
+    }
+}
+
+pub struct SQLiteHealthChecker {
+    docker: Docker,
+}
+
+impl SQLiteHealthChecker {
+    pub fn new(docker: Docker) -> Self {
+        Self { docker }
+    }
+}
+
+#[async_trait]
+impl HealthChecker for SQLiteHealthChecker {
+    async fn check(&self, container_id: &str) -> Result<bool> {
+        debug!("Checking SQLite health for container {}", container_id);
+
+        // There is no server; healthy means the database file opens cleanly
+        let exec = self
+            .docker
+            .create_exec(
+                container_id,
+                CreateExecOptions {
+                    cmd: Some(vec![
+                        "sqlite3",
+                        crate::container::SQLITE_DB_PATH,
+                        "PRAGMA quick_check;",
+                    ]),
+                    attach_stdout: Some(true),
+                    attach_stderr: Some(true),
+                    ..Default::default()
+                },
+            )
+            .await?;
+
+        if let StartExecResults::Attached { mut output, .. } =
+            self.docker.start_exec(&exec.id, None).await?
+        {
+            let mut stdout = String::new();
+            while let Some(Ok(msg)) = output.next().await {
+                stdout.push_str(&msg.to_string());
+            }
+
+            let inspect = self.docker.inspect_exec(&exec.id).await?;
+            let is_healthy = inspect.exit_code == Some(0) && stdout.trim() == "ok";
+            debug!("SQLite health check result: {}", is_healthy);
+            Ok(is_healthy)
+        } else {
+            Ok(false)
+        }
+    }
+}
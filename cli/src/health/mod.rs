@@ -2,44 +2,192 @@ mod checker;
 mod implementations;
 
 pub use checker::HealthChecker;
-pub use implementations::{MySQLHealthChecker, PostgresHealthChecker, SQLServerHealthChecker};
+pub use implementations::{
+    CockroachDBHealthChecker, MongoDBHealthChecker, MySQLHealthChecker, PostgresHealthChecker,
+    RedisHealthChecker, SQLServerHealthChecker, SQLiteHealthChecker,
+};
 
+use crate::container::models::{Container, ContainerStatus};
+use crate::container::DatabaseType;
+use crate::progress::Progress;
+use crate::util::backoff::Backoff;
 use crate::{DBArenaError, Result};
-use indicatif::{ProgressBar, ProgressStyle};
+use bollard::Docker;
+use serde::{Deserialize, Serialize};
 use std::time::{Duration, Instant};
 use tokio::time::sleep;
 use tracing::{debug, info};
 
-const HEALTH_CHECK_INTERVAL: Duration = Duration::from_millis(250);
+/// Share of each retry interval added or removed at random
+const RETRY_JITTER: f64 = 0.2;
 
+/// Backoff between health checks of a database that isn't ready yet
+///
+/// Intervals start at `initial_interval` and grow by `multiplier` after each
+/// failed check up to `max_interval`, with jitter so containers started
+/// together don't probe in lockstep.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RetryPolicy {
+    pub initial_interval: Duration,
+    pub max_interval: Duration,
+    pub multiplier: f64,
+}
+
+impl Default for RetryPolicy {
+    /// 250ms at first, as before backoff, reaching 2s after about six checks
+    fn default() -> Self {
+        Self {
+            initial_interval: Duration::from_millis(250),
+            max_interval: Duration::from_secs(2),
+            multiplier: 1.5,
+        }
+    }
+}
+
+impl RetryPolicy {
+    pub fn new(initial_interval: Duration, max_interval: Duration) -> Self {
+        Self {
+            initial_interval,
+            max_interval,
+            ..Self::default()
+        }
+    }
+
+    pub fn with_multiplier(mut self, multiplier: f64) -> Self {
+        self.multiplier = multiplier;
+        self
+    }
+
+    fn backoff(&self) -> Backoff {
+        Backoff::exponential(self.initial_interval)
+            .with_max(self.max_interval)
+            .with_multiplier(self.multiplier)
+            .with_jitter(RETRY_JITTER)
+    }
+
+    /// Interval after the `attempt`-th failed check (1-based), before jitter
+    pub fn interval(&self, attempt: u32) -> Duration {
+        self.backoff().delay(attempt)
+    }
+
+    /// `interval` with jitter applied, still capped at `max_interval`
+    fn jittered_interval(&self, attempt: u32) -> Duration {
+        self.backoff().jittered_delay(attempt)
+    }
+}
+
+/// Result of a single health probe
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HealthStatus {
+    /// Database is accepting connections
+    Healthy,
+    /// Database responded but is not ready yet
+    Starting,
+    /// Probe failed or timed out
+    Unhealthy,
+}
+
+impl std::fmt::Display for HealthStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HealthStatus::Healthy => write!(f, "healthy"),
+            HealthStatus::Starting => write!(f, "starting"),
+            HealthStatus::Unhealthy => write!(f, "unhealthy"),
+        }
+    }
+}
+
+/// Build the health checker for a database type
+pub fn checker_for(database: DatabaseType, docker: Docker) -> Box<dyn HealthChecker> {
+    match database {
+        DatabaseType::Postgres => Box::new(PostgresHealthChecker::new(docker)),
+        DatabaseType::MySQL => Box::new(MySQLHealthChecker::new(docker)),
+        DatabaseType::SQLServer => Box::new(SQLServerHealthChecker::new(docker)),
+        DatabaseType::Redis => Box::new(RedisHealthChecker::new(docker)),
+        DatabaseType::MongoDB => Box::new(MongoDBHealthChecker::new(docker)),
+        DatabaseType::CockroachDB => Box::new(CockroachDBHealthChecker::new(docker)),
+        DatabaseType::SQLite => Box::new(SQLiteHealthChecker::new(docker)),
+    }
+}
+
+/// Run a health check once, giving up after `limit`
+pub async fn check_once(
+    container_id: &str,
+    checker: &dyn HealthChecker,
+    limit: Duration,
+) -> HealthStatus {
+    match tokio::time::timeout(limit, checker.check(container_id)).await {
+        Ok(Ok(true)) => HealthStatus::Healthy,
+        Ok(Ok(false)) => HealthStatus::Starting,
+        Ok(Err(e)) => {
+            debug!("Health check error for {}: {}", container_id, e);
+            HealthStatus::Unhealthy
+        }
+        Err(_) => {
+            debug!("Health check for {} timed out", container_id);
+            HealthStatus::Unhealthy
+        }
+    }
+}
+
+/// Probe a container's database once; containers that aren't running are skipped
+pub async fn probe_container(
+    docker: &Docker,
+    container: &Container,
+    limit: Duration,
+) -> Option<HealthStatus> {
+    if !matches!(
+        container.status,
+        ContainerStatus::Running | ContainerStatus::Healthy | ContainerStatus::Unhealthy
+    ) {
+        return None;
+    }
+
+    let database = DatabaseType::from_string(&container.database_type)?;
+    let checker = checker_for(database, docker.clone());
+
+    Some(check_once(&container.id, checker.as_ref(), limit).await)
+}
+
+/// Poll `checker` until the database is healthy or `timeout` elapses
 pub async fn wait_for_healthy(
     container_id: &str,
     checker: &dyn HealthChecker,
     timeout: Duration,
+    progress: &dyn Progress,
+) -> Result<()> {
+    wait_for_healthy_with(container_id, checker, timeout, &RetryPolicy::default(), progress).await
+}
+
+/// Like `wait_for_healthy`, backing off between checks according to `policy`
+pub async fn wait_for_healthy_with(
+    container_id: &str,
+    checker: &dyn HealthChecker,
+    timeout: Duration,
+    policy: &RetryPolicy,
+    progress: &dyn Progress,
 ) -> Result<()> {
     info!("Waiting for container {} to become healthy", container_id);
 
-    let pb = ProgressBar::new_spinner();
-    pb.set_style(
-        ProgressStyle::default_spinner()
-            .template("{spinner:.green} {msg}")
-            .unwrap(),
-    );
-    pb.set_message("Waiting for database to be ready...");
+    progress.start("Waiting for database to be ready...", None);
 
     let start = Instant::now();
     let mut attempts = 0;
 
     loop {
         attempts += 1;
-        pb.set_message(format!(
-            "Waiting for database to be ready... (attempt {})",
-            attempts
-        ));
+        progress.update(
+            attempts,
+            Some(&format!(
+                "Waiting for database to be ready... (attempt {})",
+                attempts
+            )),
+        );
 
         match checker.check(container_id).await {
             Ok(true) => {
-                pb.finish_with_message("Database is healthy and ready!");
+                progress.finish("Database is healthy and ready!");
                 info!(
                     "Container {} is healthy after {:.2}s",
                     container_id,
@@ -55,11 +203,98 @@ pub async fn wait_for_healthy(
             }
         }
 
-        if start.elapsed() >= timeout {
-            pb.finish_with_message("Timeout waiting for database");
+        let elapsed = start.elapsed();
+        if elapsed >= timeout {
+            progress.finish("Timeout waiting for database");
             return Err(DBArenaError::HealthCheckTimeout(timeout.as_secs()));
         }
 
-        sleep(HEALTH_CHECK_INTERVAL).await;
+        // Don't sleep past the deadline; one last check happens at the timeout
+        sleep(policy.jittered_interval(attempts as u32).min(timeout - elapsed)).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+
+    struct FixedChecker(Option<bool>);
+
+    #[async_trait]
+    impl HealthChecker for FixedChecker {
+        async fn check(&self, _container_id: &str) -> Result<bool> {
+            match self.0 {
+                Some(healthy) => Ok(healthy),
+                None => Err(DBArenaError::Other("exec failed".to_string())),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_check_once_statuses() {
+        let limit = Duration::from_secs(1);
+        assert_eq!(check_once("c", &FixedChecker(Some(true)), limit).await, HealthStatus::Healthy);
+        assert_eq!(check_once("c", &FixedChecker(Some(false)), limit).await, HealthStatus::Starting);
+        assert_eq!(check_once("c", &FixedChecker(None), limit).await, HealthStatus::Unhealthy);
+        assert_eq!(serde_json::to_string(&HealthStatus::Starting).unwrap(), "\"starting\"");
+    }
+
+    #[test]
+    fn test_retry_policy_backoff() {
+        let policy = RetryPolicy::new(Duration::from_millis(100), Duration::from_millis(1000))
+            .with_multiplier(2.0);
+        assert_eq!(policy.interval(1), Duration::from_millis(100));
+        assert_eq!(policy.interval(2), Duration::from_millis(200));
+        assert_eq!(policy.interval(4), Duration::from_millis(800));
+        assert_eq!(policy.interval(5), Duration::from_millis(1000));
+        assert_eq!(policy.interval(500), Duration::from_millis(1000));
+
+        for attempt in 1..10 {
+            let interval = policy.jittered_interval(attempt);
+            assert!(interval <= policy.max_interval);
+            assert!(interval >= policy.interval(attempt).mul_f64(1.0 - RETRY_JITTER));
+        }
+
+        assert_eq!(RetryPolicy::default().interval(1), Duration::from_millis(250));
+    }
+
+    #[derive(Default)]
+    struct RecordingProgress(std::sync::Mutex<Vec<String>>);
+
+    impl Progress for RecordingProgress {
+        fn start(&self, message: &str, _total: Option<u64>) {
+            self.0.lock().unwrap().push(format!("start: {}", message));
+        }
+        fn update(&self, position: u64, _message: Option<&str>) {
+            self.0.lock().unwrap().push(format!("update: {}", position));
+        }
+        fn finish(&self, message: &str) {
+            self.0.lock().unwrap().push(format!("finish: {}", message));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_healthy_reports_progress() {
+        let progress = RecordingProgress::default();
+        wait_for_healthy("c", &FixedChecker(Some(true)), Duration::from_secs(1), &progress)
+            .await
+            .unwrap();
+        assert_eq!(
+            *progress.0.lock().unwrap(),
+            vec![
+                "start: Waiting for database to be ready...",
+                "update: 1",
+                "finish: Database is healthy and ready!",
+            ]
+        );
+
+        let progress = RecordingProgress::default();
+        let result = wait_for_healthy("c", &FixedChecker(Some(false)), Duration::ZERO, &progress).await;
+        assert!(matches!(result, Err(DBArenaError::HealthCheckTimeout(0))));
+        assert_eq!(
+            progress.0.lock().unwrap().last().map(String::as_str),
+            Some("finish: Timeout waiting for database")
+        );
     }
 }
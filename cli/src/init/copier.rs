@@ -1,7 +1,12 @@
-use crate::Result;
+use crate::{DBArenaError, Result};
+use bollard::container::DownloadFromContainerOptions;
 use bollard::Docker;
-use std::path::Path;
-use tar::{Builder, Header};
+use flate2::read::GzDecoder;
+use futures::StreamExt;
+use std::fs;
+use std::io::Read;
+use std::path::{Component, Path, PathBuf};
+use tar::{Archive, Builder, Header};
 
 /// Copy a file to a container using Docker's tar upload API
 pub async fn copy_file_to_container(
@@ -67,12 +72,82 @@ pub async fn copy_file_to_container(
     Ok(())
 }
 
-/// Copy multiple files to a container
+/// Contents of an init script, decompressed when the file name ends in `.gz`
+pub fn read_init_script(path: &Path) -> Result<Vec<u8>> {
+    let read_error = |e: std::io::Error| {
+        DBArenaError::InitScriptNotFound(format!(
+            "Failed to read init script '{}': {}",
+            path.display(),
+            e
+        ))
+    };
+
+    let content = fs::read(path).map_err(read_error)?;
+    if !is_gzipped(path) {
+        return Ok(content);
+    }
+
+    let mut decompressed = Vec::new();
+    GzDecoder::new(&content[..])
+        .read_to_end(&mut decompressed)
+        .map_err(|e| {
+            DBArenaError::InitScriptFailed(format!(
+                "Failed to decompress init script '{}': {}",
+                path.display(),
+                e
+            ))
+        })?;
+    Ok(decompressed)
+}
+
+/// File name an init script is copied into the container under: compressed
+/// scripts lose their `.gz` since they arrive decompressed
+pub fn init_script_name(path: &Path) -> Option<String> {
+    let name = path.file_name()?.to_str()?;
+    let name = if is_gzipped(path) {
+        &name[..name.len() - ".gz".len()]
+    } else {
+        name
+    };
+    Some(name.to_string())
+}
+
+fn is_gzipped(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("gz"))
+}
+
+/// Copy init scripts into a directory in the container
+///
+/// `.gz` scripts are decompressed on the host, so the database client reads
+/// plain SQL and reports line numbers in the decompressed text.
 pub async fn copy_files_to_container(
     docker: &Docker,
     container_id: &str,
     local_paths: &[&Path],
     container_dir: &str,
+) -> Result<()> {
+    let mut scripts = Vec::with_capacity(local_paths.len());
+    for local_path in local_paths {
+        let file_content = read_init_script(local_path)?;
+
+        let file_name = init_script_name(local_path).ok_or_else(|| {
+            crate::DBArenaError::InitScriptNotFound(format!(
+                "Invalid file name: {}",
+                local_path.display()
+            ))
+        })?;
+        scripts.push((file_name, file_content));
+    }
+
+    copy_contents_to_container(docker, container_id, &scripts, container_dir).await
+}
+
+/// Write `(file name, content)` pairs into a directory in the container
+pub async fn copy_contents_to_container(
+    docker: &Docker,
+    container_id: &str,
+    files: &[(String, Vec<u8>)],
+    container_dir: &str,
 ) -> Result<()> {
     // Parse the container directory to get parent and subdirectory name
     let parent_dir = std::path::Path::new(container_dir)
@@ -92,25 +167,7 @@ pub async fn copy_files_to_container(
     {
         let mut ar = Builder::new(&mut tar_data);
 
-        for local_path in local_paths {
-            let file_content = std::fs::read(local_path).map_err(|e| {
-                crate::DBArenaError::InitScriptNotFound(format!(
-                    "Failed to read init script '{}': {}",
-                    local_path.display(),
-                    e
-                ))
-            })?;
-
-            let file_name = local_path
-                .file_name()
-                .and_then(|n| n.to_str())
-                .ok_or_else(|| {
-                    crate::DBArenaError::InitScriptNotFound(format!(
-                        "Invalid file name: {}",
-                        local_path.display()
-                    ))
-                })?;
-
+        for (file_name, file_content) in files {
             // Add file with subdirectory path (e.g., "dbarena_init/script.sql")
             let tar_path = format!("{}/{}", dir_name, file_name);
 
@@ -146,6 +203,137 @@ pub async fn copy_files_to_container(
     Ok(())
 }
 
+/// Copy a local file or directory into a container, like `docker cp`
+///
+/// If `container_dest` is an existing directory the copy is placed inside it,
+/// otherwise it is written to `container_dest`. Directories are copied
+/// recursively and file modes are preserved.
+pub async fn copy_path_to_container(
+    docker: &Docker,
+    container_id: &str,
+    local_path: &Path,
+    container_dest: &str,
+) -> Result<()> {
+    let source = fs::canonicalize(local_path).map_err(|e| {
+        DBArenaError::Other(format!("Cannot read '{}': {}", local_path.display(), e))
+    })?;
+    let source_name = file_name(&source)?;
+
+    let into_dir = archive_path(&source, &source_name)?;
+    let first_try = upload(docker, container_id, container_dest, into_dir).await;
+    if first_try.is_ok() || container_dest.ends_with('/') {
+        return first_try;
+    }
+
+    // Not an existing directory: write to the destination path itself
+    let dest = Path::new(container_dest);
+    let parent = dest
+        .parent()
+        .and_then(|p| p.to_str())
+        .filter(|p| !p.is_empty())
+        .unwrap_or(".");
+    let dest_name = file_name(dest)?;
+    upload(docker, container_id, parent, archive_path(&source, &dest_name)?).await
+}
+
+/// Copy a file or directory out of a container, like `docker cp`
+///
+/// If `local_dest` is an existing directory the copy is placed inside it,
+/// otherwise it is written to `local_dest`. Directories are copied
+/// recursively and file modes are preserved.
+pub async fn copy_from_container(
+    docker: &Docker,
+    container_id: &str,
+    container_path: &str,
+    local_dest: &Path,
+) -> Result<()> {
+    let mut stream = docker.download_from_container(
+        container_id,
+        Some(DownloadFromContainerOptions {
+            path: container_path.to_string(),
+        }),
+    );
+
+    let mut tar_data = Vec::new();
+    while let Some(chunk) = stream.next().await {
+        tar_data.extend_from_slice(&chunk?);
+    }
+
+    extract_archive(&tar_data, local_dest)
+}
+
+/// Unpack a `docker cp`-style archive whose entries all sit under one top-level name
+fn extract_archive(tar_data: &[u8], local_dest: &Path) -> Result<()> {
+    let into_dir = local_dest.is_dir();
+    let mut archive = Archive::new(tar_data);
+
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        let path = entry.path()?.into_owned();
+        if path
+            .components()
+            .any(|c| !matches!(c, Component::Normal(_) | Component::CurDir))
+        {
+            return Err(DBArenaError::Other(format!(
+                "Refusing to extract unsafe path '{}'",
+                path.display()
+            )));
+        }
+
+        let target = if into_dir {
+            local_dest.join(&path)
+        } else {
+            // The top-level name is replaced by the destination
+            let rest: PathBuf = path.components().skip(1).collect();
+            if rest.as_os_str().is_empty() {
+                local_dest.to_path_buf()
+            } else {
+                local_dest.join(rest)
+            }
+        };
+
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        entry.set_preserve_permissions(true);
+        entry.unpack(&target)?;
+    }
+
+    Ok(())
+}
+
+/// Tar `path` (recursively for directories) under the top-level `name`
+fn archive_path(path: &Path, name: &str) -> Result<Vec<u8>> {
+    let mut ar = Builder::new(Vec::new());
+    ar.follow_symlinks(false);
+    if path.is_dir() {
+        ar.append_dir_all(name, path)?;
+    } else {
+        ar.append_path_with_name(path, name)?;
+    }
+    Ok(ar.into_inner()?)
+}
+
+async fn upload(docker: &Docker, container_id: &str, dir: &str, tar_data: Vec<u8>) -> Result<()> {
+    docker
+        .upload_to_container(
+            container_id,
+            Some(bollard::container::UploadToContainerOptions {
+                path: dir.to_string(),
+                ..Default::default()
+            }),
+            tar_data.into(),
+        )
+        .await?;
+    Ok(())
+}
+
+fn file_name(path: &Path) -> Result<String> {
+    path.file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .ok_or_else(|| DBArenaError::Other(format!("Invalid path: {}", path.display())))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -170,4 +358,78 @@ mod tests {
 
         assert!(!tar_data.is_empty());
     }
+
+    #[test]
+    fn test_archive_roundtrip_preserves_layout_and_modes() {
+        let src = tempfile::tempdir().unwrap();
+        let dump = src.path().join("dump");
+        fs::create_dir_all(dump.join("nested")).unwrap();
+        fs::write(dump.join("schema.sql"), "CREATE TABLE t (id int);").unwrap();
+        fs::write(dump.join("nested/run.sh"), "#!/bin/sh").unwrap();
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            fs::set_permissions(dump.join("nested/run.sh"), fs::Permissions::from_mode(0o755))
+                .unwrap();
+        }
+        let tar_data = archive_path(&dump, "dump").unwrap();
+
+        // Into an existing directory: keeps the top-level name
+        let into = tempfile::tempdir().unwrap();
+        extract_archive(&tar_data, into.path()).unwrap();
+        assert!(into.path().join("dump/schema.sql").is_file());
+
+        // To a new path: the top-level name becomes the destination
+        let renamed = into.path().join("restored");
+        extract_archive(&tar_data, &renamed).unwrap();
+        assert_eq!(
+            fs::read_to_string(renamed.join("schema.sql")).unwrap(),
+            "CREATE TABLE t (id int);"
+        );
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs::metadata(renamed.join("nested/run.sh")).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o755);
+        }
+    }
+
+    #[test]
+    fn test_read_gzipped_init_script() {
+        use flate2::write::GzEncoder;
+        use flate2::Compression;
+        use std::io::Write;
+
+        let dir = tempfile::tempdir().unwrap();
+        let plain = dir.path().join("01_schema.sql");
+        fs::write(&plain, "CREATE TABLE t (id int);\n").unwrap();
+
+        let gzipped = dir.path().join("02_data.sql.gz");
+        let file = fs::File::create(&gzipped).unwrap();
+        let mut encoder = GzEncoder::new(file, Compression::default());
+        encoder.write_all(b"INSERT INTO t VALUES (1);\n").unwrap();
+        encoder.finish().unwrap();
+
+        assert_eq!(read_init_script(&plain).unwrap(), b"CREATE TABLE t (id int);\n");
+        assert_eq!(read_init_script(&gzipped).unwrap(), b"INSERT INTO t VALUES (1);\n");
+        assert_eq!(init_script_name(&plain).unwrap(), "01_schema.sql");
+        assert_eq!(init_script_name(&gzipped).unwrap(), "02_data.sql");
+
+        // Not actually gzip
+        let fake = dir.path().join("03_broken.sql.gz");
+        fs::write(&fake, "SELECT 1;").unwrap();
+        assert!(read_init_script(&fake).is_err());
+    }
+
+    #[test]
+    fn test_single_file_to_new_name() {
+        let src = tempfile::tempdir().unwrap();
+        let file = src.path().join("dump.sql");
+        fs::write(&file, "SELECT 1;").unwrap();
+        let tar_data = archive_path(&file, "dump.sql").unwrap();
+
+        let dest = src.path().join("copy.sql");
+        extract_archive(&tar_data, &dest).unwrap();
+        assert_eq!(fs::read_to_string(dest).unwrap(), "SELECT 1;");
+    }
 }
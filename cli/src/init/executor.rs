@@ -1,12 +1,16 @@
-use super::copier::copy_files_to_container;
+use super::copier::{
+    copy_contents_to_container, copy_files_to_container, init_script_name, read_init_script,
+};
 use super::logs::{ExecutionMetadata, LogManager, ScriptMetadata};
-use crate::container::{ContainerConfig, DatabaseType};
-use crate::Result;
+use crate::container::{ContainerConfig, DatabaseType, SQLITE_DB_PATH};
+use crate::util::resp;
+use crate::{DBArenaError, Result};
 use bollard::Docker;
 use bollard::exec::{CreateExecOptions, StartExecResults};
 use futures::StreamExt;
 use std::path::{Path, PathBuf};
 use std::time::{Duration, Instant};
+use tracing::warn;
 
 /// Result of executing a single script
 #[derive(Debug, Clone)]
@@ -45,6 +49,9 @@ impl std::fmt::Display for ScriptError {
 }
 
 /// Execute initialization scripts in a container
+///
+/// With `db_config.atomic_init`, databases that support it run every script
+/// in one session and transaction, so a failure leaves nothing behind.
 pub async fn execute_init_scripts(
     docker: &Docker,
     container_id: &str,
@@ -61,84 +68,107 @@ pub async fn execute_init_scripts(
     // Create log session
     let session = log_manager.create_session(container_id)?;
     let start_time = Instant::now();
-    let mut results = Vec::new();
-    let mut success_count = 0;
-    let mut failure_count = 0;
 
     // Copy all scripts to container
     // Note: Use /var instead of /tmp because tmpfs mounts prevent docker cp/upload
     let container_script_dir = "/var/dbarena_init";
-    let script_refs: Vec<&Path> = scripts.iter().map(|p| p.as_path()).collect();
-    copy_files_to_container(docker, container_id, &script_refs, container_script_dir).await?;
-
-    // Execute each script
-    for script_path in scripts {
-        let script_name = script_path
-            .file_name()
-            .and_then(|n| n.to_str())
-            .unwrap_or("unknown");
-
-        let container_script_path = format!("{}/{}", container_script_dir, script_name);
-
-        let exec_start = Instant::now();
-        let result = execute_single_script(
-            docker,
-            container_id,
-            &script_path,
-            &container_script_path,
-            db_type,
-            db_config,
-            continue_on_error,
-        )
-        .await;
-
-        let duration = exec_start.elapsed();
-
-        match result {
-            Ok((output, statements)) => {
-                // Write log
-                let _log_file = log_manager.write_script_log(&session, &script_path, &output)?;
-
-                results.push(ScriptResult {
-                    script_path: script_path.clone(),
-                    success: true,
-                    output,
-                    error: None,
-                    duration,
-                    statements_executed: statements,
-                });
-                success_count += 1;
-            }
-            Err(e) => {
-                let error_message = e.to_string();
-                let output = error_message.clone();
+    if db_type == DatabaseType::Redis {
+        let converted = redis_pipe_scripts(&scripts)?;
+        copy_contents_to_container(docker, container_id, &converted, container_script_dir).await?;
+    } else {
+        let script_refs: Vec<&Path> = scripts.iter().map(|p| p.as_path()).collect();
+        copy_files_to_container(docker, container_id, &script_refs, container_script_dir).await?;
+    }
 
-                // Write error log
-                let _log_file = log_manager.write_script_log(&session, &script_path, &output)?;
+    let container_paths: Vec<String> = scripts
+        .iter()
+        .map(|script_path| {
+            let script_name =
+                init_script_name(script_path).unwrap_or_else(|| "unknown".to_string());
+            format!("{}/{}", container_script_dir, script_name)
+        })
+        .collect();
+
+    let atomic_cmd = if db_config.atomic_init {
+        let cmd = build_atomic_command(db_type, &container_paths, db_config);
+        match db_type {
+            DatabaseType::Postgres | DatabaseType::SQLite => {}
+            DatabaseType::MySQL => warn!(
+                "MySQL commits DDL implicitly; --atomic can only roll back data changes"
+            ),
+            _ => warn!(
+                "--atomic is not supported for {}; running scripts one at a time",
+                db_type
+            ),
+        }
+        cmd
+    } else {
+        None
+    };
 
-                // Parse error
-                let script_error = parse_error(&error_message, &script_path, db_type);
+    let results = match atomic_cmd {
+        Some(cmd) => {
+            let exec_start = Instant::now();
+            let result = run_exec(docker, container_id, cmd).await;
+            atomic_results(&scripts, &container_paths, result, exec_start.elapsed(), db_type)
+        }
+        None => {
+            let mut results = Vec::new();
+            for (script_path, container_script_path) in scripts.iter().zip(&container_paths) {
+                let exec_start = Instant::now();
+                let result = execute_single_script(
+                    docker,
+                    container_id,
+                    script_path,
+                    container_script_path,
+                    db_type,
+                    db_config,
+                    continue_on_error,
+                )
+                .await;
+
+                let script_result = match result {
+                    Ok((output, statements)) => ScriptResult {
+                        script_path: script_path.clone(),
+                        success: true,
+                        output,
+                        error: None,
+                        duration: exec_start.elapsed(),
+                        statements_executed: statements,
+                    },
+                    Err(e) => {
+                        let error_message = e.to_string();
+                        ScriptResult {
+                            script_path: script_path.clone(),
+                            success: false,
+                            output: error_message.clone(),
+                            error: Some(parse_error(&error_message, script_path, db_type)),
+                            duration: exec_start.elapsed(),
+                            statements_executed: 0,
+                        }
+                    }
+                };
 
-                results.push(ScriptResult {
-                    script_path: script_path.clone(),
-                    success: false,
-                    output,
-                    error: Some(script_error),
-                    duration,
-                    statements_executed: 0,
-                });
-                failure_count += 1;
+                let failed = !script_result.success;
+                results.push(script_result);
 
                 // Stop if not continuing on error
-                if !continue_on_error {
+                if failed && !continue_on_error {
                     break;
                 }
             }
+            results
         }
+    };
+
+    // Write logs
+    for result in &results {
+        let _log_file = log_manager.write_script_log(&session, &result.script_path, &result.output)?;
     }
 
     // Write metadata
     let total_duration = start_time.elapsed();
+    let success_count = results.iter().filter(|r| r.success).count();
     let metadata = ExecutionMetadata {
         scripts: results
             .iter()
@@ -157,13 +187,108 @@ pub async fn execute_init_scripts(
             .collect(),
         total_duration,
         success_count,
-        failure_count,
+        failure_count: results.len() - success_count,
     };
     log_manager.write_metadata(&session, &metadata)?;
 
     Ok(results)
 }
 
+/// Turn the outcome of one atomic run into a result per script
+///
+/// The scripts share one session, so the time is split evenly between them.
+/// On failure nothing was committed: the script named in the error output gets
+/// the parsed error and every other script is reported as rolled back.
+fn atomic_results(
+    scripts: &[PathBuf],
+    container_paths: &[String],
+    result: Result<String>,
+    duration: Duration,
+    db_type: DatabaseType,
+) -> Vec<ScriptResult> {
+    let duration = duration / scripts.len() as u32;
+
+    match result {
+        Ok(output) => {
+            let statements = count_statements(&output);
+            scripts
+                .iter()
+                .enumerate()
+                .map(|(i, script_path)| ScriptResult {
+                    script_path: script_path.clone(),
+                    success: true,
+                    output: output.clone(),
+                    error: None,
+                    duration,
+                    // Statement counts can't be attributed to scripts; report them once
+                    statements_executed: if i == 0 { statements } else { 0 },
+                })
+                .collect()
+        }
+        Err(e) => {
+            let error_message = e.to_string();
+            let failed = failed_script_index(&error_message, container_paths);
+            let failed_name = failed
+                .and_then(|i| scripts[i].file_name())
+                .map(|n| n.to_string_lossy().into_owned());
+
+            scripts
+                .iter()
+                .enumerate()
+                .map(|(i, script_path)| {
+                    let error = match (&failed_name, failed == Some(i)) {
+                        (Some(name), false) => ScriptError {
+                            error_message: format!("Rolled back because {} failed", name),
+                            ..parse_error("", script_path, db_type)
+                        },
+                        _ => parse_error(&error_message, script_path, db_type),
+                    };
+                    ScriptResult {
+                        script_path: script_path.clone(),
+                        success: false,
+                        output: error_message.clone(),
+                        error: Some(error),
+                        duration,
+                        statements_executed: 0,
+                    }
+                })
+                .collect()
+        }
+    }
+}
+
+/// Index of the script named on the first error line of `output`, e.g.
+/// `psql:/var/dbarena_init/02_seed.sql:3: ERROR:  ...`
+fn failed_script_index(output: &str, container_paths: &[String]) -> Option<usize> {
+    output
+        .lines()
+        .filter(|line| line.contains("ERROR"))
+        .find_map(|line| container_paths.iter().position(|p| line.contains(p.as_str())))
+}
+
+/// Redis init scripts as RESP, keyed by the name they are copied under
+///
+/// Scripts are redis-cli command files, one command per line, with quoting
+/// handled as redis-cli does.
+fn redis_pipe_scripts(scripts: &[PathBuf]) -> Result<Vec<(String, Vec<u8>)>> {
+    scripts
+        .iter()
+        .map(|script| {
+            let name = init_script_name(script).ok_or_else(|| {
+                DBArenaError::InitScriptNotFound(format!(
+                    "Invalid file name: {}",
+                    script.display()
+                ))
+            })?;
+            let content = read_init_script(script)?;
+            let encoded = resp::encode_script(&String::from_utf8_lossy(&content)).map_err(|e| {
+                DBArenaError::InitScriptFailed(format!("{}: {}", script.display(), e))
+            })?;
+            Ok((name, encoded.into_bytes()))
+        })
+        .collect()
+}
+
 /// Execute a single SQL script
 async fn execute_single_script(
     docker: &Docker,
@@ -176,13 +301,33 @@ async fn execute_single_script(
 ) -> Result<(String, usize)> {
     // Build command based on database type
     let cmd = build_exec_command(db_type, container_path, db_config, continue_on_error);
+    let output = run_exec(docker, container_id, cmd).await?;
+
+    // Count statements (rough estimate)
+    let statements = count_statements(&output);
+
+    Ok((output, statements))
+}
+
+/// Run a command in the container, failing on a non-zero exit code
+pub async fn run_exec(docker: &Docker, container_id: &str, cmd: Vec<String>) -> Result<String> {
+    run_exec_with_env(docker, container_id, cmd, Vec::new()).await
+}
 
+/// [`run_exec`] with extra `KEY=VALUE` variables in the command's environment
+pub async fn run_exec_with_env(
+    docker: &Docker,
+    container_id: &str,
+    cmd: Vec<String>,
+    env: Vec<String>,
+) -> Result<String> {
     // Create exec instance
     let exec = docker
         .create_exec(
             container_id,
             CreateExecOptions {
                 cmd: Some(cmd),
+                env: (!env.is_empty()).then_some(env),
                 attach_stdout: Some(true),
                 attach_stderr: Some(true),
                 ..Default::default()
@@ -221,43 +366,83 @@ async fn execute_single_script(
         )));
     }
 
-    // Count statements (rough estimate)
-    let statements = count_statements(&output);
-
-    Ok((output, statements))
+    Ok(output)
 }
 
-/// Build the exec command for running a script
-fn build_exec_command(db_type: DatabaseType, script_path: &str, config: &ContainerConfig, _continue_on_error: bool) -> Vec<String> {
+/// Build a command that runs all scripts in one session and transaction
+///
+/// Returns `None` for databases without transactional script execution.
+fn build_atomic_command(
+    db_type: DatabaseType,
+    script_paths: &[String],
+    config: &ContainerConfig,
+) -> Option<Vec<String>> {
+    let files: Vec<String> = script_paths.iter().map(|p| format!("'{}'", p)).collect();
+    let wrapped = |begin: &str, client: String| {
+        vec![
+            "sh".to_string(),
+            "-c".to_string(),
+            format!(
+                "{{ echo '{}'; cat {}; echo 'COMMIT;'; }} | {}",
+                begin,
+                files.join(" "),
+                client
+            ),
+        ]
+    };
+
     match db_type {
         DatabaseType::Postgres => {
-            let user = config
-                .env_vars
-                .get("POSTGRES_USER")
-                .map(|s| s.as_str())
-                .unwrap_or("postgres");
-            let db = config
-                .env_vars
-                .get("POSTGRES_DB")
-                .map(|s| s.as_str())
-                .unwrap_or("postgres");
-
-            let mut cmd = vec![
-                "psql".to_string(),
-                "-U".to_string(),
-                user.to_string(),
-                "-d".to_string(),
-                db.to_string(),
-            ];
-
-            cmd.push("-v".to_string());
-            cmd.push("ON_ERROR_STOP=1".to_string());
+            // One psql session with ON_ERROR_STOP and --single-transaction wraps
+            // every -f file in BEGIN/COMMIT and rolls back on the first error
+            let mut cmd = psql_command(config);
+            cmd.push("--single-transaction".to_string());
+            for path in script_paths {
+                cmd.push("-f".to_string());
+                cmd.push(path.clone());
+            }
+            Some(cmd)
+        }
+        // The client stops at the first error and disconnects without
+        // COMMIT, which rolls back data changes (MySQL DDL commits implicitly)
+        DatabaseType::MySQL | DatabaseType::CockroachDB => {
+            Some(wrapped("START TRANSACTION;", stdin_client(db_type, config)?))
+        }
+        // -bail exits at the first error without COMMIT, so SQLite rolls
+        // everything back, DDL included
+        DatabaseType::SQLite => Some(wrapped("BEGIN;", stdin_client(db_type, config)?)),
+        DatabaseType::SQLServer | DatabaseType::Redis | DatabaseType::MongoDB => None,
+    }
+}
 
-            cmd.push("-f".to_string());
-            cmd.push(script_path.to_string());
+/// psql stopping at the first error, before any script arguments
+fn psql_command(config: &ContainerConfig) -> Vec<String> {
+    let user = config
+        .env_vars
+        .get("POSTGRES_USER")
+        .map(|s| s.as_str())
+        .unwrap_or("postgres");
+    let db = config
+        .env_vars
+        .get("POSTGRES_DB")
+        .map(|s| s.as_str())
+        .unwrap_or("postgres");
+
+    vec![
+        "psql".to_string(),
+        "-U".to_string(),
+        user.to_string(),
+        "-d".to_string(),
+        db.to_string(),
+        "-v".to_string(),
+        "ON_ERROR_STOP=1".to_string(),
+    ]
+}
 
-            cmd
-        }
+/// Shell command line of the client that reads a script on stdin, for the
+/// databases whose scripts run through `sh -c`
+fn stdin_client(db_type: DatabaseType, config: &ContainerConfig) -> Option<String> {
+    match db_type {
         DatabaseType::MySQL => {
             let password = config
                 .env_vars
@@ -270,14 +455,35 @@ fn build_exec_command(db_type: DatabaseType, script_path: &str, config: &Contain
                 .map(|s| s.as_str())
                 .unwrap_or("testdb");
 
-            vec![
-                "sh".to_string(),
-                "-c".to_string(),
-                format!(
-                    "mysql -u root -p{} {} < {}",
-                    password, db, script_path
-                ),
-            ]
+            Some(format!("mysql -u root -p{} {}", password, db))
+        }
+        DatabaseType::CockroachDB => {
+            // Non-interactive cockroach sql stops at the first failing statement
+            let db = config
+                .env_vars
+                .get("COCKROACH_DATABASE")
+                .map(|s| s.as_str())
+                .unwrap_or("defaultdb");
+
+            Some(format!("cockroach sql --insecure --database={}", db))
+        }
+        // -bail stops at the first failing statement, like ON_ERROR_STOP
+        DatabaseType::SQLite => Some(format!("sqlite3 -bail {}", SQLITE_DB_PATH)),
+        // Scripts are converted to RESP on the host; --pipe exits non-zero
+        // when any command fails, unlike reading commands from stdin
+        DatabaseType::Redis => Some("redis-cli --pipe".to_string()),
+        DatabaseType::Postgres | DatabaseType::SQLServer | DatabaseType::MongoDB => None,
+    }
+}
+
+/// Build the exec command for running a script
+fn build_exec_command(db_type: DatabaseType, script_path: &str, config: &ContainerConfig, _continue_on_error: bool) -> Vec<String> {
+    match db_type {
+        DatabaseType::Postgres => {
+            let mut cmd = psql_command(config);
+            cmd.push("-f".to_string());
+            cmd.push(script_path.to_string());
+            cmd
         }
         DatabaseType::SQLServer => {
             let password = config
@@ -299,11 +505,65 @@ fn build_exec_command(db_type: DatabaseType, script_path: &str, config: &Contain
                 script_path.to_string(),
             ]
         }
+        DatabaseType::MySQL
+        | DatabaseType::CockroachDB
+        | DatabaseType::SQLite
+        | DatabaseType::Redis => {
+            let client = stdin_client(db_type, config).expect("client reading stdin");
+            vec![
+                "sh".to_string(),
+                "-c".to_string(),
+                format!("{} < {}", client, script_path),
+            ]
+        }
+        DatabaseType::MongoDB => {
+            let user = config
+                .env_vars
+                .get("MONGO_INITDB_ROOT_USERNAME")
+                .map(|s| s.as_str())
+                .unwrap_or("root");
+            let password = config
+                .env_vars
+                .get("MONGO_INITDB_ROOT_PASSWORD")
+                .map(|s| s.as_str())
+                .unwrap_or("mongo");
+            let db = config
+                .env_vars
+                .get("MONGO_INITDB_DATABASE")
+                .map(|s| s.as_str())
+                .unwrap_or("testdb");
+
+            let mut cmd = vec![
+                "mongosh".to_string(),
+                "--quiet".to_string(),
+                "-u".to_string(),
+                user.to_string(),
+                "-p".to_string(),
+                password.to_string(),
+                "--authenticationDatabase".to_string(),
+                "admin".to_string(),
+                db.to_string(),
+            ];
+
+            if script_path.ends_with(".js") {
+                cmd.push("--file".to_string());
+                cmd.push(script_path.to_string());
+                cmd
+            } else {
+                // Anything else is fed to the shell line by line, like typing it in
+                let shell = cmd.join(" ");
+                vec![
+                    "sh".to_string(),
+                    "-c".to_string(),
+                    format!("{} < {}", shell, script_path),
+                ]
+            }
+        }
     }
 }
 
 /// Parse error message and extract useful information
-fn parse_error(error_msg: &str, script_path: &Path, db_type: DatabaseType) -> ScriptError {
+pub fn parse_error(error_msg: &str, script_path: &Path, db_type: DatabaseType) -> ScriptError {
     let mut script_error = ScriptError {
         script_path: script_path.to_path_buf(),
         line_number: None,
@@ -317,9 +577,12 @@ fn parse_error(error_msg: &str, script_path: &Path, db_type: DatabaseType) -> Sc
     match db_type {
         DatabaseType::Postgres => {
             // Parse PostgreSQL errors
-            // Format: ERROR:  syntax error at or near "INSRT" at character 1
+            // Format: psql:/path/to/script.sql:12: ERROR:  syntax error at or near "INSRT"
             // LINE 1: INSRT INTO users ...
-            if let Some(line_pos) = error_msg.find("LINE ") {
+            // The psql prefix gives the line in the file; LINE is relative to the statement
+            if let Some(line_num) = psql_error_line(error_msg) {
+                script_error.line_number = Some(line_num);
+            } else if let Some(line_pos) = error_msg.find("LINE ") {
                 if let Some(line_str) = error_msg[line_pos..].split(':').next() {
                     if let Some(num_str) = line_str.split_whitespace().nth(1) {
                         if let Ok(line_num) = num_str.parse::<usize>() {
@@ -372,13 +635,79 @@ fn parse_error(error_msg: &str, script_path: &Path, db_type: DatabaseType) -> Sc
                 }
             }
         }
+        DatabaseType::CockroachDB => {
+            // CockroachDB errors look like:
+            // ERROR: at or near "insrt": syntax error
+            // SQLSTATE: 42601
+            if let Some(code) = error_msg
+                .split("SQLSTATE: ")
+                .nth(1)
+                .and_then(|rest| rest.split_whitespace().next())
+            {
+                script_error.database_error_code = Some(code.to_string());
+            }
+        }
+        DatabaseType::SQLite => {
+            // sqlite3 errors look like:
+            // Parse error near line 3: near "INSRT": syntax error
+            // Runtime error near line 5: UNIQUE constraint failed: users.email (19)
+            if let Some(line_pos) = error_msg.find("near line ") {
+                if let Some(num_str) = error_msg[line_pos + 10..].split(':').next() {
+                    if let Ok(line_num) = num_str.trim().parse::<usize>() {
+                        script_error.line_number = Some(line_num);
+                    }
+                }
+            }
+
+            if error_msg.contains("\"INSRT\"") {
+                script_error.suggestion = Some("Did you mean 'INSERT'?".to_string());
+            } else if error_msg.contains("\"SLECT\"") {
+                script_error.suggestion = Some("Did you mean 'SELECT'?".to_string());
+            }
+        }
+        DatabaseType::Redis => {
+            // redis-cli --pipe prints failed replies as: ERR unknown command 'SETT'
+            if let Some(code) = error_msg
+                .lines()
+                .filter_map(|line| line.split_whitespace().next())
+                .find(|word| word.len() > 1 && word.chars().all(|c| c.is_ascii_uppercase()))
+            {
+                script_error.database_error_code = Some(code.to_string());
+            }
+        }
+        DatabaseType::MongoDB => {
+            // mongosh errors look like: MongoServerError: E11000 duplicate key error
+            // or, for script syntax errors: SyntaxError: Unexpected token (3:4)
+            if let Some(name) = error_msg
+                .split_whitespace()
+                .find_map(|word| word.strip_suffix(':').filter(|w| w.ends_with("Error")))
+            {
+                script_error.database_error_code = Some(name.to_string());
+            }
+
+            if let Some(position) = error_msg.rfind('(') {
+                if let Some((line, _)) = error_msg[position + 1..].split_once(':') {
+                    if let Ok(line_num) = line.parse::<usize>() {
+                        script_error.line_number = Some(line_num);
+                    }
+                }
+            }
+        }
     }
 
     script_error
 }
 
+/// Line number from the first `psql:<file>:<line>: ERROR:` line of psql output
+fn psql_error_line(output: &str) -> Option<usize> {
+    output.lines().find_map(|line| {
+        let (location, _) = line.strip_prefix("psql:")?.split_once(": ERROR:")?;
+        location.rsplit_once(':')?.1.parse().ok()
+    })
+}
+
 /// Count SQL statements in output (rough estimate)
-fn count_statements(output: &str) -> usize {
+pub fn count_statements(output: &str) -> usize {
     // Simple heuristic: count common success indicators
     let create_count = output.matches("CREATE").count();
     let insert_count = output.matches("INSERT").count();
@@ -403,6 +732,14 @@ LINE 1: INSRT INTO users (name) VALUES ('test');
         assert_eq!(error.suggestion, Some("Did you mean 'INSERT'?".to_string()));
     }
 
+    #[test]
+    fn test_parse_psql_file_line() {
+        let error_msg = r#"psql:<stdin>:42: ERROR:  relation "users" does not exist
+LINE 1: INSERT INTO users (name) VALUES ('test');"#;
+        let error = parse_error(error_msg, Path::new("dump.sql"), DatabaseType::Postgres);
+        assert_eq!(error.line_number, Some(42));
+    }
+
     #[test]
     fn test_parse_mysql_error() {
         let error_msg = "ERROR 1064 (42000) at line 5: You have an error in your SQL syntax";
@@ -411,6 +748,67 @@ LINE 1: INSRT INTO users (name) VALUES ('test');
         assert_eq!(error.database_error_code, Some("1064".to_string()));
     }
 
+    #[test]
+    fn test_parse_cockroachdb_error() {
+        let error_msg = "ERROR: at or near \"insrt\": syntax error\nSQLSTATE: 42601\n";
+        let error = parse_error(error_msg, Path::new("/tmp/test.sql"), DatabaseType::CockroachDB);
+        assert_eq!(error.database_error_code, Some("42601".to_string()));
+    }
+
+    #[test]
+    fn test_parse_mongodb_error() {
+        let error_msg = "SyntaxError: Unexpected token (3:4)";
+        let error = parse_error(error_msg, Path::new("/tmp/init.js"), DatabaseType::MongoDB);
+        assert_eq!(error.line_number, Some(3));
+        assert_eq!(error.database_error_code, Some("SyntaxError".to_string()));
+    }
+
+    #[test]
+    fn test_redis_scripts_run_through_pipe() {
+        let config = ContainerConfig::new(DatabaseType::Redis);
+        let cmd = build_exec_command(DatabaseType::Redis, "/var/dbarena_init/seed.redis", &config, false);
+        assert_eq!(cmd[2], "redis-cli --pipe < /var/dbarena_init/seed.redis");
+
+        let output = "All data transferred. Waiting for the last reply...\n\
+                      ERR unknown command 'SETT', with args beginning with: 'k' 'v'\n\
+                      Last reply received from server.\n\
+                      errors: 1, replies: 2\n";
+        let error = parse_error(output, Path::new("seed.redis"), DatabaseType::Redis);
+        assert_eq!(error.database_error_code.as_deref(), Some("ERR"));
+    }
+
+    #[test]
+    fn test_build_mongodb_command() {
+        let config = ContainerConfig::new(DatabaseType::MongoDB);
+        let cmd = build_exec_command(DatabaseType::MongoDB, "/tmp/init.js", &config, false);
+        assert_eq!(cmd[0], "mongosh");
+        assert!(cmd.contains(&"--file".to_string()));
+        assert_eq!(cmd.last(), Some(&"/tmp/init.js".to_string()));
+    }
+
+    #[test]
+    fn test_sqlite_commands() {
+        let config = ContainerConfig::new(DatabaseType::SQLite);
+        let cmd = build_exec_command(DatabaseType::SQLite, "/tmp/init.sql", &config, false);
+        assert_eq!(cmd[2], "sqlite3 -bail /data/db.sqlite < /tmp/init.sql");
+
+        let paths = vec!["/tmp/a.sql".to_string(), "/tmp/b.sql".to_string()];
+        let atomic = build_atomic_command(DatabaseType::SQLite, &paths, &config).unwrap();
+        assert_eq!(
+            atomic[2],
+            "{ echo 'BEGIN;'; cat '/tmp/a.sql' '/tmp/b.sql'; echo 'COMMIT;'; } \
+             | sqlite3 -bail /data/db.sqlite"
+        );
+    }
+
+    #[test]
+    fn test_parse_sqlite_error() {
+        let error_msg = "Parse error near line 3: near \"INSRT\": syntax error";
+        let error = parse_error(error_msg, Path::new("/tmp/test.sql"), DatabaseType::SQLite);
+        assert_eq!(error.line_number, Some(3));
+        assert_eq!(error.suggestion, Some("Did you mean 'INSERT'?".to_string()));
+    }
+
     #[test]
     fn test_count_statements() {
         let output = "CREATE TABLE users;\nINSERT INTO users VALUES (1);\nINSERT INTO users VALUES (2);";
@@ -430,4 +828,58 @@ LINE 1: INSRT INTO users (name) VALUES ('test');
         let cmd_continue = build_exec_command(DatabaseType::Postgres, "/tmp/test.sql", &config, true);
         assert!(cmd_continue.contains(&"ON_ERROR_STOP=1".to_string()));
     }
+
+    #[test]
+    fn test_build_atomic_command() {
+        let config = ContainerConfig::new(DatabaseType::Postgres);
+        let paths = vec![
+            "/var/dbarena_init/a.sql".to_string(),
+            "/var/dbarena_init/b.sql".to_string(),
+        ];
+
+        let cmd = build_atomic_command(DatabaseType::Postgres, &paths, &config).unwrap();
+        assert!(cmd.contains(&"--single-transaction".to_string()));
+        assert!(cmd.contains(&"ON_ERROR_STOP=1".to_string()));
+        assert_eq!(cmd.iter().filter(|a| *a == "-f").count(), 2);
+        assert_eq!(cmd.last(), Some(&"/var/dbarena_init/b.sql".to_string()));
+
+        let config = ContainerConfig::new(DatabaseType::MySQL);
+        let cmd = build_atomic_command(DatabaseType::MySQL, &paths, &config).unwrap();
+        let script = cmd.last().unwrap();
+        assert!(script.starts_with("{ echo 'START TRANSACTION;'; cat '/var/dbarena_init/a.sql' '/var/dbarena_init/b.sql'; echo 'COMMIT;'; } | mysql"));
+        assert!(!script.contains(" < "));
+
+        let config = ContainerConfig::new(DatabaseType::Redis);
+        assert!(build_atomic_command(DatabaseType::Redis, &paths, &config).is_none());
+    }
+
+    #[test]
+    fn test_atomic_failure_rolls_back_every_script() {
+        let scripts = vec![PathBuf::from("01_schema.sql"), PathBuf::from("02_seed.sql")];
+        let paths = vec![
+            "/var/dbarena_init/01_schema.sql".to_string(),
+            "/var/dbarena_init/02_seed.sql".to_string(),
+        ];
+        let output = "psql:/var/dbarena_init/01_schema.sql:4: NOTICE:  table exists\n\
+                      psql:/var/dbarena_init/02_seed.sql:3: ERROR:  syntax error at or near \"INSRT\"";
+        assert_eq!(failed_script_index(output, &paths), Some(1));
+
+        let results = atomic_results(
+            &scripts,
+            &paths,
+            Err(crate::DBArenaError::InitScriptFailed(output.to_string())),
+            Duration::from_secs(2),
+            DatabaseType::Postgres,
+        );
+        assert!(results.iter().all(|r| !r.success));
+        assert_eq!(
+            results[0].error.as_ref().unwrap().error_message,
+            "Rolled back because 02_seed.sql failed"
+        );
+        assert_eq!(
+            results[1].error.as_ref().unwrap().suggestion.as_deref(),
+            Some("Did you mean 'INSERT'?")
+        );
+        assert_eq!(results[0].duration, Duration::from_secs(1));
+    }
 }
@@ -0,0 +1,177 @@
+//! Expansion of `--init-script` arguments into an ordered list of files
+//!
+//! An argument may be a file, a directory (its files are used) or a glob such
+//! as `./sql/*.sql`. Directory and glob matches are sorted by file name with
+//! numeric awareness, so `10_x.sql` runs after `2_x.sql`; explicit files keep
+//! their position on the command line.
+
+use crate::{DBArenaError, Result};
+use std::cmp::Ordering;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Expand files, directories and globs into the scripts to run, in order
+pub fn expand_init_scripts(args: &[PathBuf]) -> Result<Vec<PathBuf>> {
+    let mut scripts = Vec::new();
+
+    for arg in args {
+        let pattern = arg.to_string_lossy();
+        if is_glob(&pattern) {
+            let options = glob::MatchOptions {
+                require_literal_leading_dot: true,
+                ..Default::default()
+            };
+            let mut matches = glob::glob_with(&pattern, options)
+                .map_err(|e| {
+                    DBArenaError::InvalidConfig(format!(
+                        "Invalid init script pattern '{}': {}",
+                        pattern, e
+                    ))
+                })?
+                .filter_map(|entry| entry.ok())
+                .filter(|path| path.is_file())
+                .collect::<Vec<_>>();
+            if matches.is_empty() {
+                return Err(DBArenaError::InitScriptNotFound(format!(
+                    "pattern '{}' matched no files",
+                    pattern
+                )));
+            }
+            sort_naturally(&mut matches);
+            scripts.extend(matches);
+        } else if arg.is_dir() {
+            let mut files = scripts_in_dir(arg)?;
+            if files.is_empty() {
+                return Err(DBArenaError::InitScriptNotFound(format!(
+                    "directory '{}' contains no scripts",
+                    arg.display()
+                )));
+            }
+            sort_naturally(&mut files);
+            scripts.extend(files);
+        } else {
+            // Plain files are checked when the scripts are copied
+            scripts.push(arg.clone());
+        }
+    }
+
+    Ok(scripts)
+}
+
+fn is_glob(pattern: &str) -> bool {
+    pattern.contains(['*', '?', '['])
+}
+
+/// Regular, non-hidden files directly inside `dir`
+fn scripts_in_dir(dir: &Path) -> Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let hidden = path
+            .file_name()
+            .map(|n| n.to_string_lossy().starts_with('.'))
+            .unwrap_or(true);
+        if path.is_file() && !hidden {
+            files.push(path);
+        }
+    }
+    Ok(files)
+}
+
+fn sort_naturally(paths: &mut [PathBuf]) {
+    paths.sort_by(|a, b| {
+        let name = |p: &PathBuf| {
+            p.file_name()
+                .map(|n| n.to_string_lossy().into_owned())
+                .unwrap_or_default()
+        };
+        natural_cmp(&name(a), &name(b)).then_with(|| a.cmp(b))
+    });
+}
+
+/// Compare strings treating runs of digits as numbers ("2_x" < "10_x")
+pub fn natural_cmp(a: &str, b: &str) -> Ordering {
+    let (mut a, mut b) = (a, b);
+    loop {
+        match (a.chars().next(), b.chars().next()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(x), Some(y)) if x.is_ascii_digit() && y.is_ascii_digit() => {
+                let (num_a, rest_a) = split_digits(a);
+                let (num_b, rest_b) = split_digits(b);
+                let trimmed_a = num_a.trim_start_matches('0');
+                let trimmed_b = num_b.trim_start_matches('0');
+                let ordering = trimmed_a
+                    .len()
+                    .cmp(&trimmed_b.len())
+                    .then_with(|| trimmed_a.cmp(trimmed_b))
+                    .then_with(|| num_a.len().cmp(&num_b.len()));
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+                a = rest_a;
+                b = rest_b;
+            }
+            (Some(x), Some(y)) => {
+                if x != y {
+                    return x.cmp(&y);
+                }
+                a = &a[x.len_utf8()..];
+                b = &b[y.len_utf8()..];
+            }
+        }
+    }
+}
+
+fn split_digits(s: &str) -> (&str, &str) {
+    let end = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+    s.split_at(end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_natural_cmp() {
+        assert_eq!(natural_cmp("2_x.sql", "10_x.sql"), Ordering::Less);
+        assert_eq!(natural_cmp("01_schema.sql", "02_seed.sql"), Ordering::Less);
+        assert_eq!(natural_cmp("a10", "a9"), Ordering::Greater);
+        assert_eq!(natural_cmp("same.sql", "same.sql"), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_expand_globs_dirs_and_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let sql = dir.path().join("sql");
+        fs::create_dir(&sql).unwrap();
+        for name in ["10_indexes.sql", "2_seed.sql", "1_schema.sql", ".hidden.sql"] {
+            fs::write(sql.join(name), "").unwrap();
+        }
+        let extra = dir.path().join("extra.sql");
+        fs::write(&extra, "").unwrap();
+
+        let names = |paths: Vec<PathBuf>| -> Vec<String> {
+            paths
+                .iter()
+                .map(|p| p.file_name().unwrap().to_string_lossy().into_owned())
+                .collect()
+        };
+
+        let from_glob = expand_init_scripts(&[extra.clone(), sql.join("*.sql")]).unwrap();
+        assert_eq!(
+            names(from_glob),
+            ["extra.sql", "1_schema.sql", "2_seed.sql", "10_indexes.sql"]
+        );
+
+        let from_dir = expand_init_scripts(&[sql.clone(), extra]).unwrap();
+        assert_eq!(
+            names(from_dir),
+            ["1_schema.sql", "2_seed.sql", "10_indexes.sql", "extra.sql"]
+        );
+
+        let err = expand_init_scripts(&[sql.join("*.js")]).unwrap_err();
+        assert!(err.to_string().contains("matched no files"));
+    }
+}
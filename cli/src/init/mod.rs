@@ -5,8 +5,15 @@
 
 pub mod copier;
 pub mod executor;
+pub mod expand;
 pub mod logs;
 
-pub use copier::{copy_file_to_container, copy_files_to_container};
-pub use executor::{execute_init_scripts, ScriptError, ScriptResult};
+pub use copier::{
+    copy_file_to_container, copy_files_to_container, copy_from_container, copy_path_to_container,
+    init_script_name, read_init_script,
+};
+pub use executor::{
+    count_statements, execute_init_scripts, parse_error, run_exec, run_exec_with_env, ScriptError, ScriptResult,
+};
+pub use expand::expand_init_scripts;
 pub use logs::{ExecutionMetadata, LogEntry, LogManager, LogSession, ScriptMetadata};
@@ -1,6 +1,8 @@
 pub mod cli;
 pub mod config;
 pub mod container;
+#[cfg(unix)]
+pub mod daemon;
 pub mod database_metrics;
 pub mod docs;
 pub mod error;
@@ -8,8 +10,14 @@ pub mod health;
 pub mod init;
 pub mod monitoring;
 pub mod network;
+pub mod orchestrator;
+pub mod progress;
+pub mod replication;
+pub mod schema;
 pub mod seed;
+pub mod server;
 pub mod snapshot;
+pub mod util;
 pub mod workload;
 
 pub use error::{DBArenaError, Result};
@@ -1,7 +1,10 @@
-use clap::Parser;
-use dbarena::cli::commands::{config, create, destroy, docs, exec, init_cmd, inspect, list, logs, network, query, seed, snapshot, start, stats, stop, template, volume, workload};
+use clap::{CommandFactory, FromArgMatches};
+use dbarena::cli::commands::{clone, config, cp, create, destroy, diff, docs, doctor, dump, exec, init_cmd, inspect, list, logs, network, query, replica, restore, schema, seed, serve, snapshot, start, stats, stop, template, volume, wait, warm, workload};
 use dbarena::cli::interactive::{show_main_menu, MainMenuChoice};
-use dbarena::cli::{Cli, Commands, ConfigCommands, DocsCommands, InitCommands, NetworkCommands, SnapshotCommands, TemplateCommands, VolumeCommands};
+use dbarena::cli::output::OutputFormat;
+use dbarena::cli::summary;
+use dbarena::cli::{Cli, Commands, ConfigCommands, DaemonCommands, DocsCommands, InitCommands, NetworkCommands, ReplicaCommands, SeedCommands, SnapshotCommands, TemplateCommands, VolumeCommands, WorkloadCommands};
+use dbarena::util::bounded::DEFAULT_CONCURRENCY;
 use tracing_subscriber::{fmt, prelude::*, EnvFilter};
 
 #[tokio::main]
@@ -19,7 +22,11 @@ async fn main() -> anyhow::Result<()> {
 }
 
 async fn run() -> anyhow::Result<()> {
-    let cli = Cli::parse();
+    let matches = Cli::command().get_matches();
+    let cli = Cli::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
+    let command_name = matches.subcommand_name().unwrap_or("menu").to_string();
+    let show_summary = cli.summary && !cli.json;
+    let start = std::time::Instant::now();
 
     // Setup logging
     let filter = match cli.verbose {
@@ -35,6 +42,22 @@ async fn run() -> anyhow::Result<()> {
         .with(EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(filter)))
         .init();
 
+    // Docker host: --docker-host flag, then [docker] host in config, then DOCKER_HOST
+    let docker_config = dbarena::config::load_or_default(None)
+        .map(|config| config.docker)
+        .unwrap_or_default();
+    if let Some(host) = cli.docker_host.clone().or(docker_config.host) {
+        dbarena::container::DockerClient::set_default_host(host);
+    }
+    if let Some(attempts) = cli.docker_retry {
+        dbarena::container::DockerClient::set_default_retry(attempts);
+    }
+    if let Some(ms) = docker_config.list_cache_ms {
+        dbarena::container::ContainerManager::set_default_cache_ttl(
+            std::time::Duration::from_millis(ms),
+        );
+    }
+
     // If no command specified, show main menu
     let command = if let Some(cmd) = cli.command {
         cmd
@@ -48,6 +71,8 @@ async fn run() -> anyhow::Result<()> {
                 name: None,
                 port: None,
                 persistent: false,
+                tmpfs: vec![],
+                ephemeral_data: false,
                 memory: None,
                 cpu_shares: None,
                 config: None,
@@ -56,22 +81,44 @@ async fn run() -> anyhow::Result<()> {
                 env_file: None,
                 init_script: vec![],
                 continue_on_error: false,
+                atomic: false,
+                recreate: false,
+                reuse: false,
+                user: None,
+                workdir: None,
+                cap_add: vec![],
+                cap_drop: vec![],
+                security_opt: vec![],
+                ulimit: vec![],
+                replace_cmd: false,
+                cmd: vec![],
                 keep_on_error: false,
                 log_dir: None,
                 script_timeout: 30,
+                health_interval: None,
+                health_max_interval: None,
                 validate_only: false,
+                concurrency: DEFAULT_CONCURRENCY,
+            },
+            MainMenuChoice::List => Commands::List {
+                all: false,
+                no_health: false,
+                json: false,
+                yaml: false,
+                format: None,
             },
-            MainMenuChoice::List => Commands::List { all: false },
             MainMenuChoice::Start => Commands::Start {
                 container: None,
                 interactive: true,
                 all: false,
+                concurrency: DEFAULT_CONCURRENCY,
             },
             MainMenuChoice::Stop => Commands::Stop {
                 container: None,
                 interactive: true,
                 all: false,
                 timeout: 10,
+                concurrency: DEFAULT_CONCURRENCY,
             },
             MainMenuChoice::Restart => Commands::Restart {
                 container: None,
@@ -83,16 +130,21 @@ async fn run() -> anyhow::Result<()> {
                 all: false,
                 yes: false,
                 volumes: false,
+                concurrency: DEFAULT_CONCURRENCY,
+                dry_run: false,
             },
             MainMenuChoice::Inspect => Commands::Inspect {
                 container: None,
                 interactive: true,
+                connect_format: None,
+                show_secrets: false,
             },
             MainMenuChoice::Logs => Commands::Logs {
                 container: None,
                 interactive: true,
                 follow: false,
                 tail: None,
+                since: None,
             },
             MainMenuChoice::Exit => {
                 println!("\n{}", console::style("Goodbye! 👋").cyan());
@@ -101,6 +153,10 @@ async fn run() -> anyhow::Result<()> {
         }
     };
 
+    // Set by commands that fail without an error to print (diff finding
+    // differences, wait --quiet), so --summary still runs before exiting 1
+    let mut silent_failure = false;
+
     // Handle commands
     let result = match command {
         Commands::Create {
@@ -110,6 +166,8 @@ async fn run() -> anyhow::Result<()> {
             name,
             port,
             persistent,
+            tmpfs,
+            ephemeral_data,
             memory,
             cpu_shares,
             config,
@@ -118,10 +176,24 @@ async fn run() -> anyhow::Result<()> {
             env_file,
             init_script,
             continue_on_error,
+            atomic,
+            recreate,
+            reuse,
+            user,
+            workdir,
+            cap_add,
+            cap_drop,
+            security_opt,
+            ulimit,
+            replace_cmd,
+            cmd,
             keep_on_error,
             log_dir,
             script_timeout,
+            health_interval,
+            health_max_interval,
             validate_only,
+            concurrency,
         } => {
             create::handle_create(
                 databases,
@@ -130,6 +202,8 @@ async fn run() -> anyhow::Result<()> {
                 name,
                 port,
                 persistent,
+                tmpfs,
+                ephemeral_data,
                 memory,
                 cpu_shares,
                 config,
@@ -138,10 +212,24 @@ async fn run() -> anyhow::Result<()> {
                 env_file,
                 init_script,
                 continue_on_error,
+                atomic,
+                recreate,
+                reuse,
+                user,
+                workdir,
+                cap_add,
+                cap_drop,
+                security_opt,
+                ulimit,
+                cmd,
+                replace_cmd,
                 keep_on_error,
                 log_dir,
                 script_timeout,
+                health_interval,
+                health_max_interval,
                 validate_only,
+                concurrency,
             )
             .await
         }
@@ -149,48 +237,115 @@ async fn run() -> anyhow::Result<()> {
             container,
             interactive,
             all,
-        } => start::handle_start(container, interactive, all).await,
+            concurrency,
+        } => start::handle_start(container, interactive, all, concurrency).await,
         Commands::Stop {
             container,
             interactive,
             all,
             timeout,
-        } => stop::handle_stop(container, interactive, all, timeout).await,
+            concurrency,
+        } => stop::handle_stop(container, interactive, all, timeout, concurrency).await,
         Commands::Restart {
             container,
             interactive,
         } => {
             // Restart is stop + start
-            stop::handle_stop(container.clone(), interactive, false, 10).await?;
-            start::handle_start(container, interactive, false).await
+            stop::handle_stop(container.clone(), interactive, false, 10, DEFAULT_CONCURRENCY)
+                .await?;
+            start::handle_start(container, interactive, false, DEFAULT_CONCURRENCY).await
         }
+        Commands::Wait {
+            containers,
+            timeout,
+            interval,
+            any,
+            quiet,
+        } => {
+            match wait::handle_wait(containers, timeout, interval, any, quiet).await {
+                Err(_) if quiet => {
+                    silent_failure = true;
+                    Ok(())
+                }
+                result => result,
+            }
+        }
+        Commands::Cp { source, dest } => cp::handle_cp(source, dest).await,
+        Commands::Doctor { fix, yes, port } => doctor::handle_doctor(fix, yes, port).await,
+        Commands::Schema {
+            container,
+            url,
+            json,
+            yaml,
+        } => schema::handle_schema(container, url, OutputFormat::from_flags(json, yaml)).await,
+        Commands::Diff {
+            left,
+            right,
+            tables_only,
+            data,
+        } => diff::handle_diff(left, right, tables_only, data)
+            .await
+            .map(|differs| silent_failure = differs),
+        Commands::Dump {
+            container,
+            output,
+            format,
+        } => dump::handle_dump(container, output, format).await,
+        Commands::Restore { container, input } => restore::handle_restore(container, input).await,
+        Commands::Warm { container, tables } => warm::handle_warm(container, tables).await,
         Commands::Destroy {
             container,
             interactive,
             all,
             yes,
             volumes,
-        } => destroy::handle_destroy(container, interactive, all, yes, volumes).await,
-        Commands::List { all } => list::handle_list(all).await,
+            concurrency,
+            dry_run,
+        } => {
+            destroy::handle_destroy(container, interactive, all, yes, volumes, concurrency, dry_run)
+                .await
+        }
+        Commands::List {
+            all,
+            no_health,
+            json,
+            yaml,
+            format,
+        } => list::handle_list(all, no_health, json, yaml, format).await,
+        Commands::Clone {
+            container,
+            name,
+            start,
+        } => clone::handle_clone(container, name, start).await,
         Commands::Inspect {
             container,
             interactive,
-        } => inspect::handle_inspect(container, interactive).await,
+            connect_format,
+            show_secrets,
+        } => inspect::handle_inspect(container, interactive, connect_format, show_secrets).await,
+        Commands::Uri { container, format } => inspect::handle_uri(container, format).await,
         Commands::Logs {
             container,
             interactive,
             follow,
             tail,
-        } => logs::handle_logs(container, interactive, follow, tail).await,
+            since,
+        } => logs::handle_logs(container, interactive, follow, tail, since).await,
         Commands::Config(config_cmd) => match config_cmd {
             ConfigCommands::Validate {
                 config: config_path,
                 check_scripts,
-            } => config::handle_config_validate(config_path, check_scripts).await,
+                allow_missing_env,
+            } => {
+                config::handle_config_validate(config_path, check_scripts, allow_missing_env).await
+            }
             ConfigCommands::Show { config: config_path, profile } => {
                 config::handle_config_show(config_path, profile).await
             }
             ConfigCommands::Init => config::handle_config_init().await,
+            ConfigCommands::Diff { a, b, json, yaml, allow_missing_env } => {
+                config::handle_config_diff(a, b, OutputFormat::from_flags(json, yaml), allow_missing_env).await
+            }
         },
         Commands::Init(init_command) => match init_command {
             InitCommands::Test { script, container } => {
@@ -203,12 +358,26 @@ async fn run() -> anyhow::Result<()> {
         Commands::Query {
             container,
             container_flag,
+            url,
             interactive,
             script,
             file,
+            explain,
+            format,
+            timeout,
         } => {
             let resolved_container = container_flag.or(container);
-            query::handle_query(resolved_container, interactive, script, file).await
+            query::handle_query(
+                resolved_container,
+                url,
+                interactive,
+                script,
+                file,
+                explain,
+                format,
+                timeout,
+            )
+            .await
         }
         Commands::Exec {
             containers,
@@ -217,8 +386,27 @@ async fn run() -> anyhow::Result<()> {
             user,
             workdir,
             parallel,
+            concurrency,
+            output_dir,
+            quiet,
+            timeout,
             command,
-        } => exec::handle_exec(containers, all, filter, user, workdir, parallel, command).await,
+        } => {
+            exec::handle_exec(
+                containers,
+                all,
+                filter,
+                user,
+                workdir,
+                parallel,
+                concurrency,
+                output_dir,
+                quiet,
+                timeout,
+                command,
+            )
+            .await
+        }
         Commands::Stats {
             container,
             follow,
@@ -226,25 +414,40 @@ async fn run() -> anyhow::Result<()> {
             multipane,
             all,
             json,
+            yaml,
+            once,
+            prometheus,
+            port,
+            alert_cpu,
+            alert_mem,
         } => {
-            use bollard::Docker;
             use std::sync::Arc;
 
-            let docker = Docker::connect_with_local_defaults()
-                .map_err(|_| dbarena::error::DBArenaError::DockerNotAvailable)?;
-            let docker = Arc::new(docker);
+            let docker = dbarena::container::DockerClient::new()?;
+            let docker = Arc::new(docker.docker().clone());
+            let alerts = dbarena::monitoring::AlertThresholds {
+                cpu_percent: alert_cpu,
+                memory_percent: alert_mem,
+            };
+
+            let format = OutputFormat::from_flags(json, yaml);
 
-            stats::handle_stats(docker, container, follow, tui, multipane, all, json).await
+            stats::handle_stats(
+                docker, container, follow, tui, multipane, all, format, once, prometheus, port,
+                alerts,
+            )
+            .await
         }
         Commands::Snapshot(snapshot_cmd) => match snapshot_cmd {
-            SnapshotCommands::Create { container, container_flag, name, message } => {
+            SnapshotCommands::Create { container, container_flag, name, message, base, pause } => {
                 let resolved_container = container_flag
                     .or(container)
                     .ok_or_else(|| anyhow::anyhow!("Container name or ID is required"))?;
-                snapshot::handle_snapshot_create(resolved_container, name, message).await
+                snapshot::handle_snapshot_create(resolved_container, name, message, base, pause)
+                    .await
             }
-            SnapshotCommands::List { json } => {
-                snapshot::handle_snapshot_list(json).await
+            SnapshotCommands::List { json, yaml } => {
+                snapshot::handle_snapshot_list(OutputFormat::from_flags(json, yaml)).await
             }
             SnapshotCommands::Restore { snapshot, snapshot_flag, name, port } => {
                 let resolved_snapshot = snapshot_flag
@@ -258,36 +461,65 @@ async fn run() -> anyhow::Result<()> {
                     .ok_or_else(|| anyhow::anyhow!("Snapshot ID or name is required"))?;
                 snapshot::handle_snapshot_delete(resolved_snapshot, yes).await
             }
-            SnapshotCommands::Inspect { snapshot, snapshot_flag, json } => {
+            SnapshotCommands::Inspect { snapshot, snapshot_flag, json, yaml } => {
                 let resolved_snapshot = snapshot_flag
                     .or(snapshot)
                     .ok_or_else(|| anyhow::anyhow!("Snapshot ID or name is required"))?;
-                snapshot::handle_snapshot_inspect(resolved_snapshot, json).await
+                snapshot::handle_snapshot_inspect(resolved_snapshot, OutputFormat::from_flags(json, yaml)).await
+            }
+            SnapshotCommands::Diff { a, b, json, yaml } => {
+                snapshot::handle_snapshot_diff(a, b, OutputFormat::from_flags(json, yaml)).await
+            }
+            SnapshotCommands::Export { snapshot, path } => {
+                snapshot::handle_snapshot_export(snapshot, path).await
+            }
+            SnapshotCommands::Import { path } => {
+                snapshot::handle_snapshot_import(path).await
             }
         },
         Commands::Volume(volume_cmd) => match volume_cmd {
             VolumeCommands::Create { name, mount_path } => {
                 volume::handle_volume_create(name, mount_path).await
             }
-            VolumeCommands::List { all, json } => {
-                volume::handle_volume_list(all, json).await
+            VolumeCommands::List { all, json, yaml } => {
+                volume::handle_volume_list(all, OutputFormat::from_flags(json, yaml)).await
             }
             VolumeCommands::Delete { name, force, yes } => {
                 volume::handle_volume_delete(name, force, yes).await
             }
-            VolumeCommands::Inspect { name, json } => {
-                volume::handle_volume_inspect(name, json).await
+            VolumeCommands::Inspect { name, json, yaml } => {
+                volume::handle_volume_inspect(name, OutputFormat::from_flags(json, yaml)).await
+            }
+        },
+        Commands::Replica(replica_cmd) => match replica_cmd {
+            ReplicaCommands::Create { primary, name, port } => {
+                replica::handle_replica_create(primary, name, port).await
+            }
+            ReplicaCommands::Status { primary, json, yaml } => {
+                replica::handle_replica_status(primary, OutputFormat::from_flags(json, yaml)).await
             }
         },
         Commands::Network(network_cmd) => match network_cmd {
             NetworkCommands::Create { name, driver, subnet, gateway, internal } => {
                 network::handle_network_create(name, driver, subnet, gateway, internal).await
             }
-            NetworkCommands::List { all, json } => {
-                network::handle_network_list(all, json).await
+            NetworkCommands::List {
+                all,
+                tree,
+                managed_only,
+                json,
+                yaml,
+            } => {
+                network::handle_network_list(
+                    all,
+                    tree,
+                    managed_only,
+                    OutputFormat::from_flags(json, yaml),
+                )
+                .await
             }
-            NetworkCommands::Inspect { name, json } => {
-                network::handle_network_inspect(name, json).await
+            NetworkCommands::Inspect { name, json, yaml } => {
+                network::handle_network_inspect(name, OutputFormat::from_flags(json, yaml)).await
             }
             NetworkCommands::Delete { name, yes } => {
                 network::handle_network_delete(name, yes).await
@@ -300,48 +532,166 @@ async fn run() -> anyhow::Result<()> {
             }
         },
         Commands::Seed {
+            command: Some(SeedCommands::Reproduce {
+                manifest,
+                container,
+                truncate,
+            }),
+            ..
+        } => seed::handle_seed_reproduce(manifest, container, truncate).await,
+        Commands::Seed {
+            command: None,
+            generate_config: Some(path),
             container,
+            url,
+            profile,
+            ..
+        } => seed::handle_generate_config(path, container, url, profile).await,
+        Commands::Seed {
+            command: None,
+            container,
+            url,
             config,
             size,
             seed: seed_value,
             truncate,
             incremental,
             rows,
+            manifest,
+            concurrency,
+            parallel,
+            dry_run,
+            preview,
+            profile,
+            infer,
+            tables,
+            refresh_metadata,
+            generate_config: None,
         } => {
+            if config.is_none() && !infer {
+                return Err(anyhow::anyhow!(
+                    "Seed configuration file is required (--config) unless --infer is set"
+                ));
+            }
             seed::handle_seed(
                 config,
+                infer,
+                tables,
                 container,
+                url,
                 size,
                 seed_value,
                 truncate,
                 incremental,
                 rows,
+                manifest,
+                concurrency,
+                parallel,
+                dry_run.then_some(preview),
+                profile,
+                refresh_metadata,
+            )
+            .await
+        }
+        Commands::Daemon(daemon_cmd) => handle_daemon(daemon_cmd).await,
+        Commands::Serve { port, bind } => serve::handle_serve(bind, port).await,
+        Commands::Workload {
+            command:
+                Some(WorkloadCommands::Sweep {
+                    container,
+                    url,
+                    pattern,
+                    config,
+                    connections,
+                    from,
+                    to,
+                    step,
+                    duration,
+                    format,
+                    profile,
+                    refresh_metadata,
+                }),
+            ..
+        } => {
+            workload::handle_workload_sweep(
+                container,
+                url,
+                pattern,
+                config,
+                connections,
+                from,
+                to,
+                step,
+                duration,
+                format,
+                profile,
+                refresh_metadata,
             )
             .await
         }
         Commands::Workload {
+            command: None,
             container,
+            url,
             pattern,
             config,
+            queries_file,
             connections,
             tps,
+            start_tps,
+            end_tps,
+            ramp,
+            base_tps,
+            peak_tps,
+            period,
+            duty_cycle,
+            seed,
+            warmup_seconds,
             duration,
             transactions,
+            stats_out,
+            histogram,
+            histogram_out,
+            json,
+            yaml,
+            profile,
+            refresh_metadata,
+            snapshot_interval,
+            max_snapshots,
         } => {
             workload::handle_workload_run(
                 container,
+                url,
                 pattern,
                 config,
+                queries_file,
                 connections,
                 tps,
+                start_tps,
+                end_tps,
+                ramp,
+                base_tps,
+                peak_tps,
+                period,
+                duty_cycle,
+                seed,
+                warmup_seconds,
                 duration,
                 transactions,
+                stats_out,
+                histogram,
+                histogram_out,
+                OutputFormat::from_flags(json, yaml),
+                profile,
+                refresh_metadata,
+                snapshot_interval,
+                max_snapshots,
             )
             .await
         }
         Commands::Docs(docs_cmd) => match docs_cmd {
-            DocsCommands::List { installed, available, json } => {
-                docs::handle_docs_list(installed, available, json).await
+            DocsCommands::List { installed, available, json, yaml } => {
+                docs::handle_docs_list(installed, available, OutputFormat::from_flags(json, yaml)).await
             }
             DocsCommands::Install {
                 db,
@@ -358,12 +708,14 @@ async fn run() -> anyhow::Result<()> {
                 query,
                 limit,
                 json,
-            } => docs::handle_docs_search(db, version, query, limit, json).await,
+                yaml,
+            } => docs::handle_docs_search(db, version, query, limit, OutputFormat::from_flags(json, yaml)).await,
             DocsCommands::Show {
                 doc_id,
                 max_chars,
                 json,
-            } => docs::handle_docs_show(doc_id, max_chars, json).await,
+                yaml,
+            } => docs::handle_docs_show(doc_id, max_chars, OutputFormat::from_flags(json, yaml)).await,
             DocsCommands::Remove { db, version, yes } => {
                 docs::handle_docs_remove(db, version, yes).await
             }
@@ -372,8 +724,8 @@ async fn run() -> anyhow::Result<()> {
             TemplateCommands::Save { container, name, description } => {
                 template::handle_template_save(container, name, description).await
             }
-            TemplateCommands::List { json } => {
-                template::handle_template_list(json).await
+            TemplateCommands::List { json, yaml } => {
+                template::handle_template_list(OutputFormat::from_flags(json, yaml)).await
             }
             TemplateCommands::Delete { name, yes } => {
                 template::handle_template_delete(name, yes).await
@@ -384,16 +736,43 @@ async fn run() -> anyhow::Result<()> {
             TemplateCommands::Import { path } => {
                 template::handle_template_import(path).await
             }
-            TemplateCommands::Inspect { name, json } => {
-                template::handle_template_inspect(name, json).await
+            TemplateCommands::Inspect { name, json, yaml } => {
+                template::handle_template_inspect(name, OutputFormat::from_flags(json, yaml)).await
             }
         },
     };
 
-    if let Err(e) = result {
+    if let Err(e) = &result {
         eprintln!("Error: {}", e);
+    }
+
+    if show_summary {
+        eprintln!(
+            "{}",
+            summary::format_line(
+                &command_name,
+                &summary::take(),
+                start.elapsed(),
+                result.is_ok() && !silent_failure
+            )
+        );
+    }
+
+    if result.is_err() || silent_failure {
         std::process::exit(1);
     }
 
     Ok(())
 }
+
+#[cfg(unix)]
+async fn handle_daemon(command: DaemonCommands) -> dbarena::Result<()> {
+    dbarena::cli::commands::daemon::handle_daemon(command).await
+}
+
+#[cfg(not(unix))]
+async fn handle_daemon(_command: DaemonCommands) -> dbarena::Result<()> {
+    Err(dbarena::DBArenaError::Other(
+        "The daemon requires Unix domain sockets and is unavailable on this platform".to_string(),
+    ))
+}
@@ -0,0 +1,242 @@
+use std::collections::{HashSet, VecDeque};
+
+use super::metrics::ContainerMetrics;
+
+/// Alert lines kept for the TUI's alerts log
+const ALERT_LOG_SIZE: usize = 50;
+
+/// Usage levels (percent) that raise an alert in the stats TUI
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct AlertThresholds {
+    pub cpu_percent: Option<f64>,
+    pub memory_percent: Option<f64>,
+}
+
+impl AlertThresholds {
+    pub fn is_empty(&self) -> bool {
+        self.cpu_percent.is_none() && self.memory_percent.is_none()
+    }
+}
+
+/// Resource an alert threshold applies to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AlertResource {
+    Cpu,
+    Memory,
+}
+
+impl AlertResource {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            AlertResource::Cpu => "CPU",
+            AlertResource::Memory => "Memory",
+        }
+    }
+}
+
+/// A container crossing a threshold in either direction
+#[derive(Debug, Clone, PartialEq)]
+pub struct AlertTransition {
+    pub container_name: String,
+    pub resource: AlertResource,
+    pub value: f64,
+    pub threshold: f64,
+    /// `true` when entering the alert state, `false` when recovering
+    pub raised: bool,
+}
+
+impl AlertTransition {
+    pub fn message(&self) -> String {
+        if self.raised {
+            format!(
+                "{} {} at {:.1}% (threshold {:.0}%)",
+                self.container_name,
+                self.resource.as_str(),
+                self.value,
+                self.threshold
+            )
+        } else {
+            format!(
+                "{} {} back to {:.1}% (threshold {:.0}%)",
+                self.container_name,
+                self.resource.as_str(),
+                self.value,
+                self.threshold
+            )
+        }
+    }
+}
+
+/// Tracks which containers are over their thresholds
+///
+/// Only transitions into and out of the alert state are reported and logged,
+/// so a container that stays over a threshold produces one line, not one per
+/// sample.
+#[derive(Debug, Default)]
+pub struct AlertTracker {
+    thresholds: AlertThresholds,
+    active: HashSet<(String, AlertResource)>,
+    log: VecDeque<String>,
+}
+
+impl AlertTracker {
+    pub fn new(thresholds: AlertThresholds) -> Self {
+        Self {
+            thresholds,
+            ..Self::default()
+        }
+    }
+
+    /// Whether any threshold is configured
+    pub fn is_enabled(&self) -> bool {
+        !self.thresholds.is_empty()
+    }
+
+    /// Compare a sample against the thresholds, logging and returning any
+    /// transitions
+    pub fn check(&mut self, metrics: &ContainerMetrics) -> Vec<AlertTransition> {
+        let checks = [
+            (AlertResource::Cpu, self.thresholds.cpu_percent, metrics.cpu.usage_percent),
+            (AlertResource::Memory, self.thresholds.memory_percent, metrics.memory.percent),
+        ];
+
+        let mut transitions = Vec::new();
+        for (resource, threshold, value) in checks {
+            let Some(threshold) = threshold else {
+                continue;
+            };
+
+            let key = (metrics.container_id.clone(), resource);
+            let over = value >= threshold;
+            let changed = if over {
+                self.active.insert(key)
+            } else {
+                self.active.remove(&key)
+            };
+
+            if changed {
+                transitions.push(AlertTransition {
+                    container_name: metrics.container_name.clone(),
+                    resource,
+                    value,
+                    threshold,
+                    raised: over,
+                });
+            }
+        }
+
+        for transition in &transitions {
+            let marker = if transition.raised { "ALERT" } else { "OK" };
+            self.log.push_back(format!(
+                "{} {:<5} {}",
+                chrono::Local::now().format("%H:%M:%S"),
+                marker,
+                transition.message()
+            ));
+            while self.log.len() > ALERT_LOG_SIZE {
+                self.log.pop_front();
+            }
+        }
+
+        transitions
+    }
+
+    /// Whether a container is currently over the threshold for `resource`
+    pub fn is_active(&self, container_id: &str, resource: AlertResource) -> bool {
+        self.active.contains(&(container_id.to_string(), resource))
+    }
+
+    /// Logged transitions, oldest first
+    pub fn log(&self) -> &VecDeque<String> {
+        &self.log
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::monitoring::metrics::{BlockIoMetrics, CpuMetrics, MemoryMetrics, NetworkMetrics};
+
+    fn sample(cpu: f64, memory: f64) -> ContainerMetrics {
+        ContainerMetrics {
+            container_id: "abc123".to_string(),
+            container_name: "db".to_string(),
+            timestamp: 0,
+            cpu: CpuMetrics {
+                usage_percent: cpu,
+                num_cores: 4,
+                total_usage: None,
+                system_usage: None,
+            },
+            memory: MemoryMetrics {
+                usage: 0,
+                limit: 0,
+                percent: memory,
+            },
+            network: NetworkMetrics {
+                rx_bytes: 0,
+                tx_bytes: 0,
+                rx_rate: 0.0,
+                tx_rate: 0.0,
+            },
+            block_io: BlockIoMetrics {
+                read_bytes: 0,
+                write_bytes: 0,
+                read_rate: 0.0,
+                write_rate: 0.0,
+            },
+            pids: 0,
+        }
+    }
+
+    #[test]
+    fn test_logs_only_transitions() {
+        let mut tracker = AlertTracker::new(AlertThresholds {
+            cpu_percent: None,
+            memory_percent: Some(85.0),
+        });
+
+        assert!(tracker.check(&sample(99.0, 50.0)).is_empty());
+
+        let raised = tracker.check(&sample(10.0, 90.0));
+        assert_eq!(raised.len(), 1);
+        assert!(raised[0].raised);
+        assert_eq!(raised[0].resource, AlertResource::Memory);
+        assert!(tracker.is_active("abc123", AlertResource::Memory));
+
+        // Staying over the threshold doesn't log again
+        assert!(tracker.check(&sample(10.0, 95.0)).is_empty());
+
+        let cleared = tracker.check(&sample(10.0, 40.0));
+        assert_eq!(cleared.len(), 1);
+        assert!(!cleared[0].raised);
+        assert!(!tracker.is_active("abc123", AlertResource::Memory));
+
+        assert_eq!(tracker.log().len(), 2);
+        assert!(tracker.log()[0].contains("ALERT db Memory at 90.0% (threshold 85%)"));
+        assert!(tracker.log()[1].contains("OK    db Memory back to 40.0%"));
+    }
+
+    #[test]
+    fn test_tracks_resources_separately() {
+        let mut tracker = AlertTracker::new(AlertThresholds {
+            cpu_percent: Some(90.0),
+            memory_percent: Some(85.0),
+        });
+
+        let transitions = tracker.check(&sample(95.0, 90.0));
+        assert_eq!(transitions.len(), 2);
+
+        let transitions = tracker.check(&sample(50.0, 90.0));
+        assert_eq!(transitions.len(), 1);
+        assert_eq!(transitions[0].resource, AlertResource::Cpu);
+        assert!(tracker.is_active("abc123", AlertResource::Memory));
+    }
+
+    #[test]
+    fn test_disabled_without_thresholds() {
+        let mut tracker = AlertTracker::new(AlertThresholds::default());
+        assert!(!tracker.is_enabled());
+        assert!(tracker.check(&sample(100.0, 100.0)).is_empty());
+    }
+}
@@ -0,0 +1,275 @@
+//! Prometheus exporter for container and database metrics
+//!
+//! Collects on a fixed interval and serves the latest snapshot at `/metrics`
+//! in the Prometheus text exposition format.
+
+use axum::extract::State;
+use axum::http::header;
+use axum::response::IntoResponse;
+use axum::routing::get;
+use axum::Router;
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::RwLock;
+use tracing::{debug, info, warn};
+
+use super::collector::MetricsCollector;
+use super::metrics::ContainerMetrics;
+use crate::container::{ContainerManager, DatabaseType};
+use crate::database_metrics::{DatabaseMetrics, DatabaseMetricsCollector};
+use crate::Result;
+
+const CONTENT_TYPE: &str = "text/plain; version=0.0.4";
+
+type ContainerValue = fn(&ContainerMetrics) -> f64;
+type DatabaseValue = fn(&DatabaseMetrics) -> Option<f64>;
+
+/// Container series: name, type, help, value
+const CONTAINER_SERIES: &[(&str, &str, &str, ContainerValue)] = &[
+    ("dbarena_cpu_usage_percent", "gauge", "CPU usage percentage", |m| m.cpu.usage_percent),
+    ("dbarena_memory_usage_bytes", "gauge", "Memory usage in bytes", |m| m.memory.usage as f64),
+    ("dbarena_memory_limit_bytes", "gauge", "Memory limit in bytes", |m| m.memory.limit as f64),
+    ("dbarena_memory_usage_percent", "gauge", "Memory usage percentage", |m| m.memory.percent),
+    ("dbarena_network_rx_bytes_total", "counter", "Bytes received", |m| m.network.rx_bytes as f64),
+    ("dbarena_network_tx_bytes_total", "counter", "Bytes transmitted", |m| m.network.tx_bytes as f64),
+    ("dbarena_network_rx_bytes_per_second", "gauge", "Receive rate in bytes/sec", |m| m.network.rx_rate),
+    ("dbarena_network_tx_bytes_per_second", "gauge", "Transmit rate in bytes/sec", |m| m.network.tx_rate),
+    ("dbarena_block_read_bytes_total", "counter", "Bytes read from disk", |m| m.block_io.read_bytes as f64),
+    ("dbarena_block_write_bytes_total", "counter", "Bytes written to disk", |m| m.block_io.write_bytes as f64),
+    ("dbarena_pids", "gauge", "Number of processes/threads", |m| m.pids as f64),
+];
+
+/// Database series: name, type, help, value (absent values are skipped)
+const DATABASE_SERIES: &[(&str, &str, &str, DatabaseValue)] = &[
+    ("dbarena_db_active_connections", "gauge", "Active database connections", |m| Some(m.active_connections as f64)),
+    ("dbarena_db_max_connections", "gauge", "Maximum database connections", |m| m.max_connections.map(|v| v as f64)),
+    ("dbarena_db_queries_per_second", "gauge", "Queries per second", |m| Some(m.queries_per_second)),
+    ("dbarena_db_transactions_per_second", "gauge", "Transactions per second", |m| Some(m.transactions_per_second)),
+    ("dbarena_db_cache_hit_ratio_percent", "gauge", "Cache hit ratio percentage", |m| m.cache_hit_ratio),
+    ("dbarena_db_replication_lag_bytes", "gauge", "Replication lag in bytes", |m| m.replication_lag_bytes.map(|v| v as f64)),
+];
+
+/// One container's metrics; `database` is absent if its collector failed
+pub struct ExportSample {
+    pub container: ContainerMetrics,
+    pub database: Option<DatabaseMetrics>,
+}
+
+/// Render samples in the Prometheus text exposition format
+pub fn render(samples: &[ExportSample]) -> String {
+    let mut out = String::new();
+
+    for (name, kind, help, value) in CONTAINER_SERIES {
+        if samples.is_empty() {
+            break;
+        }
+        let _ = writeln!(out, "# HELP {} {}", name, help);
+        let _ = writeln!(out, "# TYPE {} {}", name, kind);
+        for sample in samples {
+            let _ = writeln!(
+                out,
+                "{}{{container=\"{}\"}} {}",
+                name,
+                escape_label(&sample.container.container_name),
+                value(&sample.container)
+            );
+        }
+    }
+
+    for (name, kind, help, value) in DATABASE_SERIES {
+        let series: Vec<_> = samples
+            .iter()
+            .filter_map(|s| {
+                let db = s.database.as_ref()?;
+                Some((&s.container.container_name, db.database_type, value(db)?))
+            })
+            .collect();
+        if series.is_empty() {
+            continue;
+        }
+
+        let _ = writeln!(out, "# HELP {} {}", name, help);
+        let _ = writeln!(out, "# TYPE {} {}", name, kind);
+        for (container, db, value) in series {
+            let _ = writeln!(
+                out,
+                "{}{{container=\"{}\",db=\"{}\"}} {}",
+                name,
+                escape_label(container),
+                db,
+                value
+            );
+        }
+    }
+
+    out
+}
+
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// Collect every running dbarena container, computing rates against `previous`
+async fn collect_samples(
+    collector: &dyn MetricsCollector,
+    db_collector: &dyn DatabaseMetricsCollector,
+    manager: &ContainerManager,
+    previous: &mut HashMap<String, ContainerMetrics>,
+) -> Result<Vec<ExportSample>> {
+    let containers = manager.list_containers_uncached(false).await?;
+    let mut samples = Vec::with_capacity(containers.len());
+
+    for container in containers {
+        let mut metrics = match collector.collect(&container.id).await {
+            Ok(metrics) => metrics,
+            Err(e) => {
+                debug!("Skipping {}: {}", container.name, e);
+                continue;
+            }
+        };
+        if let Some(prev) = previous.get(&container.id) {
+            metrics.calculate_rates(prev);
+        }
+        previous.insert(container.id.clone(), metrics.clone());
+
+        let database = match DatabaseType::from_string(&container.database_type) {
+            Some(db_type) => match db_collector.collect(&container.id, db_type).await {
+                Ok(db_metrics) => Some(db_metrics),
+                Err(e) => {
+                    debug!("Skipping database series for {}: {}", container.name, e);
+                    None
+                }
+            },
+            None => None,
+        };
+
+        samples.push(ExportSample {
+            container: metrics,
+            database,
+        });
+    }
+
+    // Forget containers that have gone away
+    previous.retain(|id, _| samples.iter().any(|s| &s.container.container_id == id));
+
+    Ok(samples)
+}
+
+async fn metrics_handler(State(body): State<Arc<RwLock<String>>>) -> impl IntoResponse {
+    ([(header::CONTENT_TYPE, CONTENT_TYPE)], body.read().await.clone())
+}
+
+/// Serve `/metrics` on `addr`, refreshing every `interval`, until Ctrl+C
+pub async fn run_exporter(
+    collector: &dyn MetricsCollector,
+    db_collector: &dyn DatabaseMetricsCollector,
+    manager: &ContainerManager,
+    addr: SocketAddr,
+    interval: Duration,
+) -> Result<()> {
+    let body = Arc::new(RwLock::new(String::new()));
+    let app = Router::new()
+        .route("/metrics", get(metrics_handler))
+        .with_state(body.clone());
+
+    let listener = tokio::net::TcpListener::bind(addr).await?;
+    info!("Prometheus exporter listening on {}", addr);
+
+    let server = async {
+        axum::serve(listener, app)
+            .with_graceful_shutdown(async {
+                let _ = tokio::signal::ctrl_c().await;
+            })
+            .await
+    };
+
+    let collect = async {
+        let mut previous = HashMap::new();
+        loop {
+            match collect_samples(collector, db_collector, manager, &mut previous).await {
+                Ok(samples) => *body.write().await = render(&samples),
+                // Keep serving the last good snapshot
+                Err(e) => warn!("Metrics collection failed: {}", e),
+            }
+            tokio::time::sleep(interval).await;
+        }
+    };
+
+    tokio::select! {
+        result = server => result?,
+        _ = collect => {}
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::monitoring::{BlockIoMetrics, CpuMetrics, MemoryMetrics, NetworkMetrics};
+
+    fn container_metrics(name: &str) -> ContainerMetrics {
+        ContainerMetrics {
+            container_id: format!("{}-id", name),
+            container_name: name.to_string(),
+            timestamp: 0,
+            cpu: CpuMetrics {
+                usage_percent: 12.5,
+                num_cores: 4,
+                total_usage: None,
+                system_usage: None,
+            },
+            memory: MemoryMetrics {
+                usage: 1024,
+                limit: 2048,
+                percent: 50.0,
+            },
+            network: NetworkMetrics {
+                rx_bytes: 10,
+                tx_bytes: 20,
+                rx_rate: 1.0,
+                tx_rate: 2.0,
+            },
+            block_io: BlockIoMetrics {
+                read_bytes: 0,
+                write_bytes: 0,
+                read_rate: 0.0,
+                write_rate: 0.0,
+            },
+            pids: 7,
+        }
+    }
+
+    #[test]
+    fn test_render_exposition_format() {
+        let mut db = DatabaseMetrics::new("pg-id".to_string(), DatabaseType::Postgres);
+        db.active_connections = 3;
+
+        let samples = vec![
+            ExportSample {
+                container: container_metrics("pg"),
+                database: Some(db),
+            },
+            // Database collector failed: container series only
+            ExportSample {
+                container: container_metrics("my\"sql"),
+                database: None,
+            },
+        ];
+        let output = render(&samples);
+
+        assert!(output.contains("# TYPE dbarena_cpu_usage_percent gauge\n"));
+        assert!(output.contains("dbarena_cpu_usage_percent{container=\"pg\"} 12.5\n"));
+        assert!(output.contains("dbarena_pids{container=\"my\\\"sql\"} 7\n"));
+        assert!(output.contains("dbarena_db_active_connections{container=\"pg\",db=\"postgres\"} 3\n"));
+        assert!(!output.contains("db=\"mysql\""));
+        // Absent values produce no series at all
+        assert!(!output.contains("dbarena_db_replication_lag_bytes"));
+    }
+}
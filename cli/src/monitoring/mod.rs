@@ -1,15 +1,19 @@
 //! Performance monitoring module
 //!
 //! Provides real-time CPU, memory, network, and I/O metrics collection
-//! for Docker containers with both simple text output and interactive TUI.
+//! for Docker containers with simple text output, an interactive TUI, and a
+//! Prometheus exporter.
 
+pub mod alerts;
 pub mod metrics;
 pub mod collector;
 pub mod docker_stats;
 pub mod display;
+pub mod exporter;
 pub mod logs;
 pub mod tui;
 
+pub use alerts::{AlertResource, AlertThresholds, AlertTracker, AlertTransition};
 pub use metrics::{ContainerMetrics, CpuMetrics, MemoryMetrics, NetworkMetrics, BlockIoMetrics};
 pub use collector::MetricsCollector;
 pub use docker_stats::DockerStatsCollector;
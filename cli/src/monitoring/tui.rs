@@ -1,14 +1,14 @@
 use std::collections::VecDeque;
 use std::io;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use ratatui::{
     backend::CrosstermBackend,
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Gauge, List, ListItem, Paragraph, Row, Table, Sparkline},
+    widgets::{Block, Borders, Clear, Gauge, List, ListItem, Paragraph, Row, Table, Sparkline},
     Frame, Terminal,
 };
 use crossterm::{
@@ -18,8 +18,10 @@ use crossterm::{
 };
 use futures::StreamExt;
 
+use crate::container::ContainerManager;
 use crate::error::Result;
 use crate::database_metrics::{DatabaseMetrics, DatabaseMetricsCollector};
+use super::alerts::{AlertResource, AlertThresholds, AlertTracker};
 use super::collector::MetricsCollector;
 use super::logs::LogStreamer;
 use super::metrics::ContainerMetrics;
@@ -27,6 +29,10 @@ use super::display::{format_bytes, format_rate};
 
 const HISTORY_SIZE: usize = 60;
 const LOG_BUFFER_SIZE: usize = 100;
+/// Alert lines shown in the single-container view's alerts log
+const ALERT_LINES_SHOWN: u16 = 4;
+/// How long the result of a stop/restart stays in the list view's status line
+const STATUS_DURATION: Duration = Duration::from_secs(5);
 
 /// View mode for multi-container TUI
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -36,6 +42,29 @@ enum ViewMode {
     MultiPane,
 }
 
+/// Action on the selected container in the list view, run after confirmation
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ContainerAction {
+    Stop,
+    Restart,
+}
+
+impl ContainerAction {
+    fn verb(self) -> &'static str {
+        match self {
+            ContainerAction::Stop => "Stop",
+            ContainerAction::Restart => "Restart",
+        }
+    }
+
+    fn past_tense(self) -> &'static str {
+        match self {
+            ContainerAction::Stop => "stopped",
+            ContainerAction::Restart => "restarted",
+        }
+    }
+}
+
 /// Active pane in multi-pane view
 #[derive(Debug, Clone, Copy, PartialEq)]
 enum PaneType {
@@ -62,6 +91,9 @@ pub struct StatsTui {
     show_logs: bool,
     log_scroll_offset: usize,
     selected_container_index: usize,
+
+    // Resource thresholds crossed so far
+    alerts: AlertTracker,
 }
 
 impl StatsTui {
@@ -94,9 +126,17 @@ impl StatsTui {
             show_logs: true,
             log_scroll_offset: 0,
             selected_container_index: 0,
+            alerts: AlertTracker::default(),
         })
     }
 
+    /// Flash the CPU/memory gauge and log a line when a container crosses
+    /// one of these thresholds
+    pub fn with_alerts(mut self, thresholds: AlertThresholds) -> Self {
+        self.alerts = AlertTracker::new(thresholds);
+        self
+    }
+
     /// Run the TUI for a single container
     pub async fn run_single(
         &mut self,
@@ -117,6 +157,7 @@ impl StatsTui {
                         if let Some(prev) = &previous_metrics {
                             metrics.calculate_rates(prev);
                         }
+                        self.alerts.check(&metrics);
 
                         previous_metrics = Some(metrics.clone());
                         self.metrics_history.push_back(metrics);
@@ -139,9 +180,10 @@ impl StatsTui {
             let show_help = self.show_help;
             let metrics_history = &self.metrics_history;
             let paused = self.paused;
+            let alerts = &self.alerts;
 
             self.terminal
-                .draw(|f| render_single_frame(f, metrics_history, paused, show_help, false))
+                .draw(|f| render_single_frame(f, metrics_history, paused, show_help, false, alerts))
                 .map_err(|e| crate::error::DBArenaError::MonitoringError(format!("Failed to draw: {}", e)))?;
 
             // Handle input with short timeout for responsive controls
@@ -185,12 +227,20 @@ impl StatsTui {
     }
 
     /// Run the TUI for multiple containers
+    ///
+    /// In the list view, `K` stops and `R` restarts the selected container
+    /// through `manager` once confirmed.
     pub async fn run_multi(
         &mut self,
         collector: &impl MetricsCollector,
+        manager: &ContainerManager,
     ) -> Result<()> {
         let mut all_metrics: Vec<ContainerMetrics> = Vec::new();
         let mut selected_index = 0;
+        // Action awaiting y/n, with the container's ID and name
+        let mut pending: Option<(ContainerAction, String, String)> = None;
+        // Outcome of the last action: message, success, when it happened
+        let mut status: Option<(String, bool, Instant)> = None;
         let mut last_collection = tokio::time::Instant::now();
         let mut previous_metrics: Option<ContainerMetrics> = None;
         let collection_interval = self.collection_interval;
@@ -207,6 +257,9 @@ impl StatsTui {
                         // Collect all containers
                         match collector.collect_all().await {
                             Ok(metrics) => {
+                                for m in &metrics {
+                                    self.alerts.check(m);
+                                }
                                 all_metrics = metrics;
                                 last_collection = now;
                             }
@@ -225,6 +278,7 @@ impl StatsTui {
                                     if let Some(prev) = &previous_metrics {
                                         metrics.calculate_rates(prev);
                                     }
+                                    self.alerts.check(&metrics);
                                     previous_metrics = Some(metrics.clone());
                                     self.metrics_history.push_back(metrics);
 
@@ -251,19 +305,30 @@ impl StatsTui {
 
             // Render TUI based on view mode
             let show_help = self.show_help;
+            if status.as_ref().is_some_and(|(_, _, at)| at.elapsed() >= STATUS_DURATION) {
+                status = None;
+            }
             match self.view_mode {
                 ViewMode::List => {
                     let paused = self.paused;
                     let all_metrics_ref = &all_metrics;
+                    let prompt = pending
+                        .as_ref()
+                        .map(|(action, _, name)| format!("{} {}? (y/n)", action.verb(), name));
+                    let status_line = status.as_ref().map(|(message, ok, _)| (message.as_str(), *ok));
                     self.terminal.draw(|f| {
-                        render_multi_frame(f, all_metrics_ref, selected_index, paused);
+                        render_multi_frame(f, all_metrics_ref, selected_index, paused, status_line);
+                        if let Some(prompt) = &prompt {
+                            render_confirm(f, prompt);
+                        }
                     }).map_err(|e| crate::error::DBArenaError::MonitoringError(format!("Failed to draw TUI: {}", e)))?;
                 }
                 ViewMode::Detail => {
                     let paused = self.paused;
                     let metrics_history = &self.metrics_history;
+                    let alerts = &self.alerts;
                     self.terminal.draw(|f| {
-                        render_single_frame(f, metrics_history, paused, show_help, true);
+                        render_single_frame(f, metrics_history, paused, show_help, true, alerts);
                     }).map_err(|e| crate::error::DBArenaError::MonitoringError(format!("Failed to draw TUI: {}", e)))?;
                 }
                 ViewMode::MultiPane => {
@@ -278,9 +343,42 @@ impl StatsTui {
                 if let Event::Key(key) = event::read()
                     .map_err(|e| crate::error::DBArenaError::MonitoringError(format!("Failed to read event: {}", e)))? {
                     match self.view_mode {
+                        // Any key but y answers the confirmation with no
+                        ViewMode::List if pending.is_some() => {
+                            let (action, id, name) = pending.take().unwrap();
+                            if matches!(key.code, KeyCode::Char('y') | KeyCode::Char('Y')) {
+                                let result = match action {
+                                    ContainerAction::Stop => manager.stop_container(&id, None).await,
+                                    ContainerAction::Restart => match manager.stop_container(&id, None).await {
+                                        Ok(()) => manager.start_container(&id).await.map(|_| ()),
+                                        Err(e) => Err(e),
+                                    },
+                                };
+                                status = Some(match result {
+                                    Ok(()) => (format!("{} {}", name, action.past_tense()), true, Instant::now()),
+                                    Err(e) => (format!("Failed to {} {}: {}", action.verb().to_lowercase(), name, e), false, Instant::now()),
+                                });
+                                // Pick up the change on the next collection
+                                last_collection = tokio::time::Instant::now() - collection_interval;
+                            }
+                        }
                         ViewMode::List => {
                             match key.code {
                                 KeyCode::Char('q') => break,
+                                KeyCode::Char('K') | KeyCode::Char('R') => {
+                                    let action = if key.code == KeyCode::Char('K') {
+                                        ContainerAction::Stop
+                                    } else {
+                                        ContainerAction::Restart
+                                    };
+                                    if let Some(metrics) = all_metrics.get(selected_index) {
+                                        pending = Some((
+                                            action,
+                                            metrics.container_id.clone(),
+                                            metrics.container_name.clone(),
+                                        ));
+                                    }
+                                }
                                 KeyCode::Esc => break,
                                 KeyCode::Char('f') => {
                                     self.paused = !self.paused;
@@ -365,7 +463,6 @@ impl StatsTui {
         container_id: Option<String>,
     ) -> Result<()> {
         use crate::container::DatabaseType;
-        use bollard::Docker;
 
         self.view_mode = ViewMode::MultiPane;
 
@@ -375,7 +472,7 @@ impl StatsTui {
         let collection_interval = self.collection_interval;
 
         // Get Docker client for log streaming
-        let docker = Arc::new(Docker::connect_with_local_defaults()?);
+        let docker = Arc::new(crate::container::DockerClient::new()?.docker().clone());
         let log_streamer = LogStreamer::new(docker.clone());
         let mut log_stream: Option<futures::stream::BoxStream<String>> = None;
 
@@ -415,6 +512,7 @@ impl StatsTui {
                             if let Some(prev) = &previous_resource_metrics {
                                 metrics.calculate_rates(prev);
                             }
+                            self.alerts.check(&metrics);
                             previous_resource_metrics = Some(metrics.clone());
                             self.metrics_history.push_back(metrics);
 
@@ -425,9 +523,21 @@ impl StatsTui {
                         Err(_) => {}
                     }
 
-                    // Collect database metrics (try to detect database type)
-                    // For now, try each database type and use the first that succeeds
-                    for db_type in &[DatabaseType::Postgres, DatabaseType::MySQL, DatabaseType::SQLServer] {
+                    // Collect database metrics, preferring the type recorded in the
+                    // container's labels and falling back to trying each SQL type
+                    let labeled_type = docker
+                        .inspect_container(cid, None)
+                        .await
+                        .ok()
+                        .and_then(|info| info.config)
+                        .and_then(|config| config.labels)
+                        .and_then(|labels| labels.get("dbarena.database").cloned())
+                        .and_then(|db| DatabaseType::from_string(&db));
+                    let candidates = match labeled_type {
+                        Some(db_type) => vec![db_type],
+                        None => vec![DatabaseType::Postgres, DatabaseType::MySQL, DatabaseType::SQLServer],
+                    };
+                    for db_type in &candidates {
                         match db_collector.collect(cid, *db_type).await {
                             Ok(db_metrics) => {
                                 self.database_metrics_history.push_back(db_metrics);
@@ -493,6 +603,7 @@ impl StatsTui {
             let selected_idx = self.selected_container_index;
             let log_lines = &self.log_lines;
             let log_scroll = self.log_scroll_offset;
+            let alerts = &self.alerts;
 
             self.terminal.draw(|f| {
                 render_multipane_frame(
@@ -506,6 +617,7 @@ impl StatsTui {
                     active_pane,
                     show_logs,
                     paused,
+                    alerts,
                 );
             }).map_err(|e| crate::error::DBArenaError::MonitoringError(format!("Failed to draw: {}", e)))?;
 
@@ -609,6 +721,7 @@ fn render_single_frame(
     paused: bool,
     show_help: bool,
     in_multi_mode: bool,
+    alerts: &AlertTracker,
 ) {
     if show_help {
         render_help(f);
@@ -627,7 +740,8 @@ fn render_single_frame(
         }
     };
 
-    // Main layout
+    // Main layout; the alerts log only appears when thresholds are set
+    let alerts_height = if alerts.is_enabled() { ALERT_LINES_SHOWN + 2 } else { 0 };
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
@@ -636,6 +750,7 @@ fn render_single_frame(
             Constraint::Length(8),  // CPU chart
             Constraint::Length(8),  // Memory chart
             Constraint::Length(8),  // I/O stats (5 data rows + header + borders)
+            Constraint::Length(alerts_height), // Alerts log
             Constraint::Length(3),  // Footer
         ])
         .split(f.size());
@@ -644,7 +759,7 @@ fn render_single_frame(
     render_header(f, chunks[0], current, paused);
 
     // Gauges
-    render_gauges(f, chunks[1], current);
+    render_gauges(f, chunks[1], current, alerts);
 
     // CPU history chart
     render_cpu_chart(f, chunks[2], metrics_history);
@@ -655,8 +770,13 @@ fn render_single_frame(
     // I/O stats
     render_io_stats(f, chunks[4], current);
 
+    // Alerts
+    if alerts.is_enabled() {
+        render_alerts(f, chunks[5], alerts);
+    }
+
     // Footer
-    render_footer(f, chunks[5], in_multi_mode);
+    render_footer(f, chunks[6], in_multi_mode);
 }
 
 fn render_header(f: &mut Frame, area: Rect, metrics: &ContainerMetrics, paused: bool) {
@@ -682,7 +802,7 @@ fn render_header(f: &mut Frame, area: Rect, metrics: &ContainerMetrics, paused:
     f.render_widget(paragraph, area);
 }
 
-fn render_gauges(f: &mut Frame, area: Rect, metrics: &ContainerMetrics) {
+fn render_gauges(f: &mut Frame, area: Rect, metrics: &ContainerMetrics, alerts: &AlertTracker) {
         let chunks = Layout::default()
             .direction(Direction::Horizontal)
             .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
@@ -691,35 +811,39 @@ fn render_gauges(f: &mut Frame, area: Rect, metrics: &ContainerMetrics) {
         // CPU gauge
         // Clamp ratio to 0.0-1.0 for display, but show actual percentage in label
         let cpu_ratio = (metrics.cpu.usage_percent / 100.0).min(1.0).max(0.0);
+        let cpu_style = if alerts.is_active(&metrics.container_id, AlertResource::Cpu) {
+            alert_style()
+        } else {
+            Style::default().fg(if metrics.cpu.usage_percent > 90.0 {
+                Color::Red
+            } else if metrics.cpu.usage_percent > 75.0 {
+                Color::Yellow
+            } else {
+                Color::Green
+            })
+        };
         let cpu_gauge = Gauge::default()
             .block(Block::default().title("CPU Usage").borders(Borders::ALL))
-            .gauge_style(
-                Style::default()
-                    .fg(if metrics.cpu.usage_percent > 90.0 {
-                        Color::Red
-                    } else if metrics.cpu.usage_percent > 75.0 {
-                        Color::Yellow
-                    } else {
-                        Color::Green
-                    })
-            )
+            .gauge_style(cpu_style)
             .ratio(cpu_ratio)
             .label(format!("{:.1}%", metrics.cpu.usage_percent));
         f.render_widget(cpu_gauge, chunks[0]);
 
         // Memory gauge
+        let memory_style = if alerts.is_active(&metrics.container_id, AlertResource::Memory) {
+            alert_style()
+        } else {
+            Style::default().fg(if metrics.memory.percent > 90.0 {
+                Color::Red
+            } else if metrics.memory.percent > 75.0 {
+                Color::Yellow
+            } else {
+                Color::Green
+            })
+        };
         let memory_gauge = Gauge::default()
             .block(Block::default().title("Memory Usage").borders(Borders::ALL))
-            .gauge_style(
-                Style::default()
-                    .fg(if metrics.memory.percent > 90.0 {
-                        Color::Red
-                    } else if metrics.memory.percent > 75.0 {
-                        Color::Yellow
-                    } else {
-                        Color::Green
-                    })
-            )
+            .gauge_style(memory_style)
             .ratio(metrics.memory.percent / 100.0)
             .label(format!(
                 "{} / {} ({:.1}%)",
@@ -730,6 +854,32 @@ fn render_gauges(f: &mut Frame, area: Rect, metrics: &ContainerMetrics) {
         f.render_widget(memory_gauge, chunks[1]);
 }
 
+/// Flashing red for a resource over its alert threshold
+fn alert_style() -> Style {
+    Style::default()
+        .fg(Color::Red)
+        .add_modifier(Modifier::BOLD | Modifier::SLOW_BLINK)
+}
+
+/// Most recent threshold crossings, newest last
+fn render_alerts(f: &mut Frame, area: Rect, alerts: &AlertTracker) {
+    let log = alerts.log();
+    let items: Vec<ListItem> = if log.is_empty() {
+        vec![ListItem::new("No thresholds crossed").style(Style::default().fg(Color::DarkGray))]
+    } else {
+        log.iter()
+            .skip(log.len().saturating_sub(ALERT_LINES_SHOWN as usize))
+            .map(|line| {
+                let color = if line.contains(" ALERT ") { Color::Red } else { Color::Green };
+                ListItem::new(line.as_str()).style(Style::default().fg(color))
+            })
+            .collect()
+    };
+
+    let list = List::new(items).block(Block::default().title("Alerts").borders(Borders::ALL));
+    f.render_widget(list, area);
+}
+
 fn render_cpu_chart(f: &mut Frame, area: Rect, metrics_history: &VecDeque<ContainerMetrics>) {
     // Clamp CPU values to 0-100 range for chart display
     let data: Vec<u64> = metrics_history
@@ -819,6 +969,7 @@ fn render_multi_frame(
     all_metrics: &[ContainerMetrics],
     selected_index: usize,
     paused: bool,
+    status: Option<(&str, bool)>,
 ) {
     if all_metrics.is_empty() {
         let block = Block::default()
@@ -894,18 +1045,47 @@ fn render_multi_frame(
 
     f.render_widget(table, chunks[1]);
 
-    // Footer with instructions
-    let footer = Paragraph::new(Line::from(vec![
-        Span::raw("[↑/↓: navigate]  "),
-        Span::raw("[Space/Enter: details]  "),
-        Span::raw("[f: freeze]  "),
-        Span::raw("[r: refresh]  "),
-        Span::raw("[q: quit]"),
-    ]))
-    .style(Style::default().fg(Color::Cyan));
+    // Footer with instructions, replaced for a while by the last action's outcome
+    let footer = match status {
+        Some((message, ok)) => Paragraph::new(message)
+            .style(Style::default().fg(if ok { Color::Green } else { Color::Red })),
+        None => Paragraph::new(Line::from(vec![
+            Span::raw("[↑/↓: navigate]  "),
+            Span::raw("[Space/Enter: details]  "),
+            Span::raw("[K: stop]  "),
+            Span::raw("[R: restart]  "),
+            Span::raw("[f: freeze]  "),
+            Span::raw("[r: refresh]  "),
+            Span::raw("[q: quit]"),
+        ]))
+        .style(Style::default().fg(Color::Cyan)),
+    };
     f.render_widget(footer, chunks[2]);
 }
 
+/// Yes/no prompt drawn over the middle of the screen
+fn render_confirm(f: &mut Frame, prompt: &str) {
+    let area = f.size();
+    let width = (prompt.chars().count() as u16 + 6).min(area.width);
+    let height = 3.min(area.height);
+    let popup = Rect::new(
+        area.x + (area.width - width) / 2,
+        area.y + (area.height - height) / 2,
+        width,
+        height,
+    );
+
+    let confirm = Paragraph::new(Span::styled(
+        prompt,
+        Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+    ))
+    .alignment(ratatui::layout::Alignment::Center)
+    .block(Block::default().title(" Confirm ").borders(Borders::ALL));
+
+    f.render_widget(Clear, popup);
+    f.render_widget(confirm, popup);
+}
+
 fn render_help(f: &mut Frame) {
     let help_text = vec![
         Line::from(""),
@@ -923,6 +1103,14 @@ fn render_help(f: &mut Frame) {
             Span::styled("  r", Style::default().fg(Color::Yellow)),
             Span::raw("      Reset history data"),
         ]),
+        Line::from(vec![
+            Span::styled("  K", Style::default().fg(Color::Yellow)),
+            Span::raw("      Stop the selected container (container list, asks first)"),
+        ]),
+        Line::from(vec![
+            Span::styled("  R", Style::default().fg(Color::Yellow)),
+            Span::raw("      Restart the selected container (container list, asks first)"),
+        ]),
         Line::from(vec![
             Span::styled("  h, ?", Style::default().fg(Color::Yellow)),
             Span::raw("    Show/hide this help"),
@@ -954,6 +1142,7 @@ fn render_multipane_frame(
     active_pane: PaneType,
     show_logs: bool,
     paused: bool,
+    alerts: &AlertTracker,
 ) {
     // Main layout: left sidebar (20%) | right content (80%)
     let main_chunks = Layout::default()
@@ -990,6 +1179,7 @@ fn render_multipane_frame(
         resource_metrics,
         active_pane == PaneType::Resource,
         paused,
+        alerts,
     );
 
     // Render database metrics
@@ -1059,6 +1249,7 @@ fn render_resource_metrics_pane(
     metrics: Option<&ContainerMetrics>,
     is_active: bool,
     paused: bool,
+    alerts: &AlertTracker,
 ) {
     let border_style = if is_active {
         Style::default().fg(Color::Green)
@@ -1072,17 +1263,23 @@ fn render_resource_metrics_pane(
         let cpu_ratio = (m.cpu.usage_percent / 100.0).min(1.0).max(0.0);
         let mem_ratio = (m.memory.percent / 100.0).min(1.0).max(0.0);
 
-        let content = vec![
+        let usage_style = |resource: AlertResource, percent: f64| {
+            if alerts.is_active(&m.container_id, resource) {
+                alert_style()
+            } else if percent > 80.0 {
+                Style::default().fg(Color::Red)
+            } else {
+                Style::default().fg(Color::Green)
+            }
+        };
+
+        let mut content = vec![
             Line::from(""),
             Line::from(vec![
                 Span::raw("CPU: "),
                 Span::styled(
                     format!("{:.1}%", m.cpu.usage_percent),
-                    Style::default().fg(if m.cpu.usage_percent > 80.0 {
-                        Color::Red
-                    } else {
-                        Color::Green
-                    }),
+                    usage_style(AlertResource::Cpu, m.cpu.usage_percent),
                 ),
             ]),
             Line::from(gauge_bar(cpu_ratio, 30)),
@@ -1096,11 +1293,7 @@ fn render_resource_metrics_pane(
                         format_bytes(m.memory.limit),
                         m.memory.percent
                     ),
-                    Style::default().fg(if m.memory.percent > 80.0 {
-                        Color::Red
-                    } else {
-                        Color::Green
-                    }),
+                    usage_style(AlertResource::Memory, m.memory.percent),
                 ),
             ]),
             Line::from(gauge_bar(mem_ratio, 30)),
@@ -1117,6 +1310,13 @@ fn render_resource_metrics_pane(
             )),
         ];
 
+        // Latest threshold crossing, if any
+        if let Some(line) = alerts.log().back() {
+            let color = if line.contains(" ALERT ") { Color::Red } else { Color::Green };
+            content.push(Line::from(""));
+            content.push(Line::from(Span::styled(line.clone(), Style::default().fg(color))));
+        }
+
         let paragraph = Paragraph::new(content).block(
             Block::default()
                 .title(format!(" Resource Metrics{} ", status))
@@ -1167,29 +1367,94 @@ fn render_database_metrics_pane(
 
         let repl_status = m.replication_status.as_ref().map(|s| s.as_str()).unwrap_or("None");
 
-        let content = vec![
-            Line::from(""),
-            Line::from(vec![
-                Span::raw("Connections: "),
-                Span::styled(conn_usage, Style::default().fg(Color::Cyan)),
-            ]),
-            Line::from(""),
-            Line::from(format!("QPS: {:.2}", m.queries_per_second)),
-            Line::from(format!("TPS: {:.2}", m.transactions_per_second)),
-            Line::from(""),
-            Line::from(vec![
-                Span::raw("Cache Hit: "),
-                Span::styled(cache_hit, Style::default().fg(Color::Green)),
-            ]),
-            Line::from(""),
-            Line::from(format!("SELECT: {} | INSERT: {}", m.query_breakdown.select_count, m.query_breakdown.insert_count)),
-            Line::from(format!("UPDATE: {} | DELETE: {}", m.query_breakdown.update_count, m.query_breakdown.delete_count)),
-            Line::from(""),
-            Line::from(vec![
-                Span::raw("Replication: "),
-                Span::styled(repl_status, Style::default().fg(Color::Yellow)),
-            ]),
-        ];
+        let content = if m.database_type == crate::container::DatabaseType::Redis {
+            // Redis reports a simplified metric set gathered from INFO
+            let used_memory = m
+                .extras
+                .get("used_memory_human")
+                .and_then(|v| v.as_str())
+                .unwrap_or("N/A")
+                .to_string();
+
+            vec![
+                Line::from(""),
+                Line::from(vec![
+                    Span::raw("Clients: "),
+                    Span::styled(conn_usage, Style::default().fg(Color::Cyan)),
+                ]),
+                Line::from(""),
+                Line::from(format!("Ops/sec: {:.2}", m.queries_per_second)),
+                Line::from(vec![
+                    Span::raw("Used Memory: "),
+                    Span::styled(used_memory, Style::default().fg(Color::Magenta)),
+                ]),
+                Line::from(""),
+                Line::from(vec![
+                    Span::raw("Keyspace Hit: "),
+                    Span::styled(cache_hit, Style::default().fg(Color::Green)),
+                ]),
+                Line::from(""),
+                Line::from(vec![
+                    Span::raw("Role: "),
+                    Span::styled(repl_status, Style::default().fg(Color::Yellow)),
+                ]),
+            ]
+        } else if m.database_type == crate::container::DatabaseType::MongoDB {
+            // MongoDB reports opcounters and resident memory from serverStatus;
+            // it has no SQL transactions or buffer cache ratio to show
+            let resident = m
+                .extras
+                .get("resident_memory_mb")
+                .and_then(|v| v.as_u64())
+                .map(|mb| format!("{} MB", mb))
+                .unwrap_or_else(|| "N/A".to_string());
+
+            vec![
+                Line::from(""),
+                Line::from(vec![
+                    Span::raw("Connections: "),
+                    Span::styled(conn_usage, Style::default().fg(Color::Cyan)),
+                ]),
+                Line::from(""),
+                Line::from(format!("Ops/sec: {:.2}", m.queries_per_second)),
+                Line::from(vec![
+                    Span::raw("Resident Memory: "),
+                    Span::styled(resident, Style::default().fg(Color::Magenta)),
+                ]),
+                Line::from(""),
+                Line::from(format!("QUERY: {} | INSERT: {}", m.query_breakdown.select_count, m.query_breakdown.insert_count)),
+                Line::from(format!("UPDATE: {} | DELETE: {}", m.query_breakdown.update_count, m.query_breakdown.delete_count)),
+                Line::from(""),
+                Line::from(vec![
+                    Span::raw("Replica Set: "),
+                    Span::styled(repl_status, Style::default().fg(Color::Yellow)),
+                ]),
+            ]
+        } else {
+            vec![
+                Line::from(""),
+                Line::from(vec![
+                    Span::raw("Connections: "),
+                    Span::styled(conn_usage, Style::default().fg(Color::Cyan)),
+                ]),
+                Line::from(""),
+                Line::from(format!("QPS: {:.2}", m.queries_per_second)),
+                Line::from(format!("TPS: {:.2}", m.transactions_per_second)),
+                Line::from(""),
+                Line::from(vec![
+                    Span::raw("Cache Hit: "),
+                    Span::styled(cache_hit, Style::default().fg(Color::Green)),
+                ]),
+                Line::from(""),
+                Line::from(format!("SELECT: {} | INSERT: {}", m.query_breakdown.select_count, m.query_breakdown.insert_count)),
+                Line::from(format!("UPDATE: {} | DELETE: {}", m.query_breakdown.update_count, m.query_breakdown.delete_count)),
+                Line::from(""),
+                Line::from(vec![
+                    Span::raw("Replication: "),
+                    Span::styled(repl_status, Style::default().fg(Color::Yellow)),
+                ]),
+            ]
+        };
 
         let paragraph = Paragraph::new(content).block(
             Block::default()
@@ -1,4 +1,4 @@
-use super::models::{Network, NetworkConfig};
+use super::models::{Network, NetworkConfig, NetworkMember};
 use crate::container::DockerClient;
 use crate::{DBArenaError, Result};
 use bollard::network::{CreateNetworkOptions, InspectNetworkOptions, ListNetworksOptions};
@@ -6,6 +6,7 @@ use std::collections::HashMap;
 use tracing::{debug, info};
 
 const DBARENA_NETWORK_LABEL: &str = "dbarena.network";
+const DBARENA_CONTAINER_LABEL: &str = "dbarena.managed";
 
 pub struct NetworkManager {
     client: DockerClient,
@@ -159,6 +160,75 @@ impl NetworkManager {
         })
     }
 
+    /// List the containers attached to a network, sorted by name
+    ///
+    /// Each container is inspected for its aliases on the network and whether dbarena
+    /// manages it.
+    pub async fn list_members(&self, name: &str) -> Result<Vec<NetworkMember>> {
+        let options = InspectNetworkOptions {
+            verbose: false,
+            scope: "local",
+        };
+
+        let network = self
+            .client
+            .docker()
+            .inspect_network(name, Some(options))
+            .await
+            .map_err(|e| {
+                DBArenaError::ContainerOperationFailed(format!(
+                    "Failed to inspect network {}: {}",
+                    name, e
+                ))
+            })?;
+        let network_name = network.name.unwrap_or_else(|| name.to_string());
+
+        let mut members = Vec::new();
+        for (id, endpoint) in network.containers.unwrap_or_default() {
+            let container = self
+                .client
+                .docker()
+                .inspect_container(&id, None)
+                .await
+                .map_err(|e| {
+                    DBArenaError::ContainerOperationFailed(format!(
+                        "Failed to inspect container {}: {}",
+                        id, e
+                    ))
+                })?;
+
+            let container_name = endpoint
+                .name
+                .unwrap_or_else(|| id.chars().take(12).collect());
+            let managed = container
+                .config
+                .and_then(|config| config.labels)
+                .is_some_and(|labels| {
+                    labels.get(DBARENA_CONTAINER_LABEL).map(String::as_str) == Some("true")
+                });
+            let aliases = container
+                .network_settings
+                .and_then(|settings| settings.networks)
+                .and_then(|mut networks| networks.remove(&network_name))
+                .and_then(|settings| settings.aliases)
+                .unwrap_or_default();
+
+            members.push(NetworkMember {
+                aliases: user_aliases(aliases, &id, &container_name),
+                ip_address: endpoint
+                    .ipv4_address
+                    .filter(|ip| !ip.is_empty())
+                    .map(|ip| strip_prefix_length(&ip).to_string()),
+                id,
+                name: container_name,
+                managed,
+            });
+        }
+
+        members.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(members)
+    }
+
     /// Delete a network
     pub async fn delete_network(&self, name: &str) -> Result<()> {
         info!("Deleting network: {}", name);
@@ -240,3 +310,40 @@ impl NetworkManager {
         Ok(())
     }
 }
+
+/// Drop the aliases Docker adds on its own: the container's name and short ID
+fn user_aliases(aliases: Vec<String>, id: &str, name: &str) -> Vec<String> {
+    aliases
+        .into_iter()
+        .filter(|alias| alias != name && !id.starts_with(alias.as_str()))
+        .collect()
+}
+
+/// `172.18.0.2/16` -> `172.18.0.2`
+fn strip_prefix_length(address: &str) -> &str {
+    address.split('/').next().unwrap_or(address)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_user_aliases() {
+        let aliases = vec![
+            "9f3c2a1b7d4e".to_string(),
+            "pg-main".to_string(),
+            "db".to_string(),
+        ];
+        assert_eq!(
+            user_aliases(aliases, "9f3c2a1b7d4e5f60718293a4b5c6d7e8", "pg-main"),
+            vec!["db"]
+        );
+    }
+
+    #[test]
+    fn test_strip_prefix_length() {
+        assert_eq!(strip_prefix_length("172.18.0.2/16"), "172.18.0.2");
+        assert_eq!(strip_prefix_length("172.18.0.2"), "172.18.0.2");
+    }
+}
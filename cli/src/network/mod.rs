@@ -2,4 +2,4 @@ pub mod manager;
 pub mod models;
 
 pub use manager::NetworkManager;
-pub use models::{Network, NetworkConfig, NetworkDriver};
+pub use models::{Network, NetworkConfig, NetworkDriver, NetworkMember, NetworkTree};
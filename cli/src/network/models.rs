@@ -41,6 +41,26 @@ pub struct Network {
     pub labels: HashMap<String, String>,
 }
 
+/// A container attached to a network
+#[derive(Debug, Clone, Serialize)]
+pub struct NetworkMember {
+    pub id: String,
+    pub name: String,
+    /// Address on this network, without the prefix length
+    pub ip_address: Option<String>,
+    pub aliases: Vec<String>,
+    /// Whether dbarena created the container
+    pub managed: bool,
+}
+
+/// A network with the containers attached to it
+#[derive(Debug, Clone, Serialize)]
+pub struct NetworkTree {
+    #[serde(flatten)]
+    pub network: Network,
+    pub containers: Vec<NetworkMember>,
+}
+
 /// Network creation/update configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NetworkConfig {
@@ -0,0 +1,424 @@
+//! Library entry point for creating databases
+//!
+//! [`create_database`] runs the same steps as `dbarena create` for one
+//! container: config, profile and env merging, tuning, image pull, health wait
+//! and init scripts. Nothing is printed; each step is reported to a
+//! [`CreateObserver`], which can be a plain closure:
+//!
+//! ```no_run
+//! # async fn example() -> dbarena::Result<()> {
+//! use dbarena::config::DBArenaConfig;
+//! use dbarena::container::{ContainerManager, DatabaseType, DockerClient};
+//! use dbarena::orchestrator::{create_database, CreateEvent, CreateOptions};
+//!
+//! let manager = ContainerManager::new(DockerClient::new()?);
+//! let mut options = CreateOptions::new(DatabaseType::Postgres);
+//! options.name = Some("test-db".to_string());
+//!
+//! let container = create_database(&manager, &DBArenaConfig::default(), options, &|event| {
+//!     if let CreateEvent::Warning(message) = event {
+//!         eprintln!("warning: {}", message);
+//!     }
+//! })
+//! .await?;
+//! println!("{} is ready on port {:?}", container.name, container.host_port);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::config::{
+    get_database_env, get_database_image, get_database_tuning, merge_env_vars, resolve_profile,
+    tuning_settings, DBArenaConfig,
+};
+use crate::container::models::ContainerStatus;
+use crate::container::{
+    Container, ContainerConfig, ContainerManager, DatabaseType, PullOptions, Ulimit,
+};
+use crate::health::{checker_for, wait_for_healthy_with, RetryPolicy};
+use crate::init::{execute_init_scripts, LogManager};
+use crate::progress::{NoProgress, Progress};
+use crate::{DBArenaError, Result};
+
+/// How long a new container has to pass its health check
+pub const DEFAULT_HEALTH_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// A step of [`create_database`], reported as it happens
+#[derive(Debug, Clone, PartialEq)]
+pub enum CreateEvent {
+    /// Something worth surfacing that doesn't stop creation, e.g. an unknown
+    /// tuning parameter
+    Warning(String),
+    /// An existing container with the requested name is being reused
+    Reusing { name: String },
+    /// An existing container with the requested name is being destroyed first
+    Replacing { name: String },
+    PullingImage { image: String },
+    Creating,
+    Starting,
+    WaitingForHealthy,
+    RunningInitScripts { count: usize },
+}
+
+/// Receives the steps of [`create_database`]
+///
+/// Any `Fn(CreateEvent)` closure is an observer. Implement the trait directly
+/// to also show progress for image pulls and health waits.
+pub trait CreateObserver: Send + Sync {
+    fn event(&self, event: CreateEvent);
+
+    /// Progress reporter for a single pull or health wait
+    fn progress(&self) -> Box<dyn Progress> {
+        Box::new(NoProgress)
+    }
+}
+
+impl<F: Fn(CreateEvent) + Send + Sync> CreateObserver for F {
+    fn event(&self, event: CreateEvent) {
+        self(event)
+    }
+}
+
+/// Everything `dbarena create` takes for a single container
+#[derive(Debug, Clone)]
+pub struct CreateOptions {
+    pub database: DatabaseType,
+    /// Defaults to the database's default version
+    pub version: Option<String>,
+    /// Generated from the database and version when unset
+    pub name: Option<String>,
+    pub port: Option<u16>,
+    pub persistent: bool,
+    /// Extra tmpfs mounts as `(path, options)`
+    pub tmpfs: Vec<(String, String)>,
+    /// Keep the data directory on a tmpfs
+    pub ephemeral_data: bool,
+    pub memory: Option<u64>,
+    pub cpu_shares: Option<u64>,
+    /// Config profile layered over the database's config env
+    pub profile: Option<String>,
+    /// Variables from an env file, layered over the profile
+    pub file_env: HashMap<String, String>,
+    /// Variables with the highest precedence, layered last
+    pub env: HashMap<String, String>,
+    pub init_scripts: Vec<PathBuf>,
+    pub continue_on_error: bool,
+    pub atomic: bool,
+    /// Destroy an existing container with the same name first
+    pub recreate: bool,
+    /// Start and return an existing container with the same name
+    pub reuse: bool,
+    pub user: Option<String>,
+    pub workdir: Option<String>,
+    pub cap_add: Vec<String>,
+    pub cap_drop: Vec<String>,
+    pub security_opt: Vec<String>,
+    pub ulimits: Vec<Ulimit>,
+    /// Arguments appended to the image's command, after config tuning
+    pub cmd: Vec<String>,
+    /// Replace the image's command with `cmd` instead, skipping tuning flags
+    pub replace_cmd: bool,
+    pub health_policy: RetryPolicy,
+    pub health_timeout: Duration,
+}
+
+impl CreateOptions {
+    pub fn new(database: DatabaseType) -> Self {
+        Self {
+            database,
+            version: None,
+            name: None,
+            port: None,
+            persistent: false,
+            tmpfs: Vec::new(),
+            ephemeral_data: false,
+            memory: None,
+            cpu_shares: None,
+            profile: None,
+            file_env: HashMap::new(),
+            env: HashMap::new(),
+            init_scripts: Vec::new(),
+            continue_on_error: false,
+            atomic: false,
+            recreate: false,
+            reuse: false,
+            user: None,
+            workdir: None,
+            cap_add: Vec::new(),
+            cap_drop: Vec::new(),
+            security_opt: Vec::new(),
+            ulimits: Vec::new(),
+            cmd: Vec::new(),
+            replace_cmd: false,
+            health_policy: RetryPolicy::default(),
+            health_timeout: DEFAULT_HEALTH_TIMEOUT,
+        }
+    }
+}
+
+/// Build the container config for `options` against the dbarena config
+///
+/// Environment precedence, lowest first: the database's config env and tuning,
+/// the profile, the env file, then `options.env`.
+pub fn container_config(
+    config: &DBArenaConfig,
+    options: CreateOptions,
+    observer: &dyn CreateObserver,
+) -> Result<ContainerConfig> {
+    let database = options.database;
+    let mut container_config = ContainerConfig::new(database);
+    if let Some(version) = options.version {
+        container_config = container_config.with_version(version);
+    }
+    if let Some(image) = get_database_image(config, database) {
+        container_config = container_config.with_image(image);
+    }
+    if let Some(name) = options.name {
+        container_config = container_config.with_name(name);
+    }
+    if let Some(port) = options.port {
+        container_config = container_config.with_port(port);
+    }
+    container_config = container_config.with_persistent(options.persistent);
+    if options.ephemeral_data {
+        container_config =
+            container_config.with_tmpfs(database.data_dir().to_string(), "rw".to_string());
+    }
+    for (path, mount_options) in options.tmpfs {
+        container_config = container_config.with_tmpfs(path, mount_options);
+    }
+    if let Some(memory) = options.memory {
+        container_config = container_config.with_memory_limit(memory);
+    }
+    if let Some(cpu_shares) = options.cpu_shares {
+        container_config = container_config.with_cpu_shares(cpu_shares);
+    }
+    if let Some(user) = options.user {
+        container_config = container_config.with_user(user);
+    }
+    if let Some(workdir) = options.workdir {
+        container_config = container_config.with_workdir(workdir);
+    }
+    container_config = container_config
+        .with_cap_add(options.cap_add)
+        .with_cap_drop(options.cap_drop)
+        .with_security_opt(options.security_opt)
+        .with_ulimits(options.ulimits);
+
+    // Config tuning goes first so flags after -- can override it
+    let tuning = tuning_settings(database, &get_database_tuning(config, database));
+    for name in &tuning.unknown {
+        observer.event(CreateEvent::Warning(format!(
+            "Ignoring unknown {} tuning parameter: {}",
+            database, name
+        )));
+    }
+    if options.replace_cmd {
+        container_config = container_config.with_command(options.cmd);
+    } else {
+        let mut args = tuning.args;
+        args.extend(options.cmd);
+        container_config = container_config.with_command_args(args);
+    }
+
+    let mut base_env = get_database_env(config, database);
+    base_env.extend(tuning.env);
+    let mut layers = vec![base_env];
+    if let Some(profile) = &options.profile {
+        layers.push(resolve_profile(config, profile, database)?);
+    }
+    layers.push(options.file_env);
+    layers.push(options.env);
+
+    Ok(container_config
+        .with_env_vars(merge_env_vars(layers))
+        .with_init_scripts(options.init_scripts)
+        .with_continue_on_error(options.continue_on_error)
+        .with_atomic_init(options.atomic))
+}
+
+/// Create, start and initialize a database container, returning once it is
+/// healthy and its init scripts have run
+pub async fn create_database(
+    manager: &ContainerManager,
+    config: &DBArenaConfig,
+    options: CreateOptions,
+    observer: &dyn CreateObserver,
+) -> Result<Container> {
+    let database = options.database;
+    let (reuse, recreate) = (options.reuse, options.recreate);
+    let (health_policy, health_timeout) = (options.health_policy, options.health_timeout);
+    let container_config = container_config(config, options, observer)?;
+
+    let docker = manager.client();
+
+    // Deal with an existing container of the same name before pulling anything
+    if let Some(name) = &container_config.name {
+        let existing = manager
+            .find_container_uncached(name)
+            .await?
+            .filter(|c| &c.name == name);
+        if let Some(mut existing) = existing {
+            if reuse {
+                if existing.database_type != database.as_str() {
+                    return Err(DBArenaError::InvalidConfig(format!(
+                        "Cannot reuse '{}': it is a {} container, not {}",
+                        name, existing.database_type, database
+                    )));
+                }
+                observer.event(CreateEvent::Reusing { name: name.clone() });
+                if !matches!(
+                    existing.status,
+                    ContainerStatus::Running | ContainerStatus::Healthy | ContainerStatus::Unhealthy
+                ) {
+                    observer.event(CreateEvent::Starting);
+                    existing.id = manager.start_container(&existing.id).await?;
+                }
+                observer.event(CreateEvent::WaitingForHealthy);
+                let checker = checker_for(database, docker.docker().clone());
+                wait_for_healthy_with(
+                    &existing.id,
+                    checker.as_ref(),
+                    health_timeout,
+                    &health_policy,
+                    observer.progress().as_ref(),
+                )
+                .await?;
+                return Ok(existing);
+            } else if recreate {
+                observer.event(CreateEvent::Replacing { name: name.clone() });
+                manager.destroy_container(&existing.id, false).await?;
+            } else {
+                return Err(DBArenaError::NameInUse { name: name.clone() });
+            }
+        }
+    }
+
+    // Step 1: Ensure image is available
+    let image = container_config.image_ref();
+    if !docker.image_exists(&image).await? {
+        observer.event(CreateEvent::PullingImage {
+            image: image.clone(),
+        });
+        docker
+            .pull_image_with(&image, &PullOptions::default(), observer.progress().as_ref())
+            .await?;
+    }
+
+    // Step 2: Create container
+    observer.event(CreateEvent::Creating);
+    let mut container = manager.create_container(container_config.clone()).await?;
+
+    // Step 3: Start container
+    observer.event(CreateEvent::Starting);
+    container.id = manager.start_container(&container.id).await?;
+
+    // Step 4: Wait for healthy
+    observer.event(CreateEvent::WaitingForHealthy);
+    let checker = checker_for(database, docker.docker().clone());
+    wait_for_healthy_with(
+        &container.id,
+        checker.as_ref(),
+        health_timeout,
+        &health_policy,
+        observer.progress().as_ref(),
+    )
+    .await?;
+
+    // Step 5: Execute initialization scripts (if any)
+    if !container_config.init_scripts.is_empty() {
+        observer.event(CreateEvent::RunningInitScripts {
+            count: container_config.init_scripts.len(),
+        });
+        let log_manager = LogManager::new(None)?;
+        let results = execute_init_scripts(
+            docker.docker(),
+            &container.id,
+            container_config.init_scripts.clone(),
+            database,
+            &container_config,
+            container_config.continue_on_error,
+            &log_manager,
+        )
+        .await?;
+
+        // Check if any scripts failed
+        let failed_scripts: Vec<_> = results.iter().filter(|r| !r.success).collect();
+        if !failed_scripts.is_empty() && !container_config.continue_on_error {
+            let error_details: Vec<String> = failed_scripts
+                .iter()
+                .map(|r| {
+                    format!(
+                        "  - {}: {}",
+                        r.script_path.display(),
+                        r.error
+                            .as_ref()
+                            .map(|e| e.to_string())
+                            .unwrap_or_else(|| "Unknown error".to_string())
+                    )
+                })
+                .collect();
+
+            return Err(DBArenaError::InitScriptFailed(format!(
+                "Initialization scripts failed:\n{}",
+                error_details.join("\n")
+            )));
+        }
+    }
+
+    Ok(container)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[test]
+    fn test_container_config_env_precedence() {
+        let toml = r#"
+            [databases.postgres.env]
+            POSTGRES_USER = "app"
+            POSTGRES_DB = "main"
+
+            [databases.postgres.profiles.ci]
+            env = { POSTGRES_DB = "ci", POSTGRES_PASSWORD = "ci" }
+        "#;
+        let config: DBArenaConfig = toml::from_str(toml).unwrap();
+
+        let mut options = CreateOptions::new(DatabaseType::Postgres);
+        options.profile = Some("ci".to_string());
+        options.file_env = HashMap::from([("POSTGRES_PASSWORD".to_string(), "file".to_string())]);
+        options.env = HashMap::from([("POSTGRES_USER".to_string(), "cli".to_string())]);
+
+        let container_config = container_config(&config, options, &|_| {}).unwrap();
+        let env = &container_config.env_vars;
+        assert_eq!(env.get("POSTGRES_USER"), Some(&"cli".to_string()));
+        assert_eq!(env.get("POSTGRES_DB"), Some(&"ci".to_string()));
+        assert_eq!(env.get("POSTGRES_PASSWORD"), Some(&"file".to_string()));
+    }
+
+    #[test]
+    fn test_unknown_tuning_is_reported() {
+        let toml = r#"
+            [databases.postgres.tuning]
+            not_a_setting = 1
+        "#;
+        let config: DBArenaConfig = toml::from_str(toml).unwrap();
+        let events = Mutex::new(Vec::new());
+
+        container_config(
+            &config,
+            CreateOptions::new(DatabaseType::Postgres),
+            &|event| events.lock().unwrap().push(event),
+        )
+        .unwrap();
+
+        let events = events.into_inner().unwrap();
+        assert_eq!(events.len(), 1);
+        assert!(matches!(&events[0], CreateEvent::Warning(m) if m.contains("not_a_setting")));
+    }
+}
@@ -0,0 +1,115 @@
+//! Progress reporting decoupled from how it is presented
+//!
+//! Long-running operations (health waits, seeding, workloads) report through
+//! the [`Progress`] trait. The CLI passes an [`IndicatifProgress`]; the API
+//! server, quiet/JSON modes and library callers pass [`NoProgress`].
+
+use indicatif::{ProgressBar, ProgressStyle};
+use std::time::Duration;
+
+/// Receiver of progress for a single long-running task
+pub trait Progress: Send + Sync {
+    /// Begin reporting; `total` is the amount of work when it is known up front
+    fn start(&self, message: &str, total: Option<u64>);
+
+    /// Report how much work is done, optionally replacing the message
+    fn update(&self, position: u64, message: Option<&str>);
+
+    /// Stop reporting with a closing message
+    fn finish(&self, message: &str);
+}
+
+/// Discards all progress
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NoProgress;
+
+impl Progress for NoProgress {
+    fn start(&self, _message: &str, _total: Option<u64>) {}
+    fn update(&self, _position: u64, _message: Option<&str>) {}
+    fn finish(&self, _message: &str) {}
+}
+
+/// Terminal spinner (unknown total) or bar (known total) drawn with indicatif
+pub struct IndicatifProgress {
+    bar: ProgressBar,
+    unit: Option<&'static str>,
+}
+
+impl IndicatifProgress {
+    pub fn new() -> Self {
+        Self {
+            bar: ProgressBar::new_spinner(),
+            unit: None,
+        }
+    }
+
+    /// Label shown after the position on bars, e.g. "rows"
+    pub fn with_unit(mut self, unit: &'static str) -> Self {
+        self.unit = Some(unit);
+        self
+    }
+}
+
+impl Default for IndicatifProgress {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Progress for IndicatifProgress {
+    fn start(&self, message: &str, total: Option<u64>) {
+        match total {
+            Some(total) => {
+                let template = format!(
+                    "{{spinner:.green}} [{{elapsed_precise}}] [{{wide_bar:.cyan/blue}}] {{pos}}/{{len}}{} ({{per_sec}}) {{msg}}",
+                    self.unit.map(|u| format!(" {}", u)).unwrap_or_default()
+                );
+                self.bar.set_length(total);
+                self.bar.set_style(
+                    ProgressStyle::default_bar()
+                        .template(&template)
+                        .unwrap()
+                        .progress_chars("#>-"),
+                );
+            }
+            None => {
+                self.bar.set_style(
+                    ProgressStyle::default_spinner()
+                        .template("{spinner:.green} {msg}")
+                        .unwrap(),
+                );
+                self.bar.enable_steady_tick(Duration::from_millis(100));
+            }
+        }
+        self.bar.set_message(message.to_string());
+    }
+
+    fn update(&self, position: u64, message: Option<&str>) {
+        self.bar.set_position(position);
+        if let Some(message) = message {
+            self.bar.set_message(message.to_string());
+        }
+    }
+
+    fn finish(&self, message: &str) {
+        self.bar.finish_with_message(message.to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reporters_are_object_safe() {
+        let reporters: Vec<Box<dyn Progress>> = vec![
+            Box::new(NoProgress),
+            Box::new(IndicatifProgress::new().with_unit("rows")),
+        ];
+        for progress in &reporters {
+            progress.start("working", Some(2));
+            progress.update(1, Some("halfway"));
+            progress.finish("done");
+        }
+    }
+}
@@ -0,0 +1,310 @@
+use super::models::{
+    parse_replication_rows, slot_name, ReplicaStatus, REPLICATION_NETWORK_LABEL,
+    REPLICATION_SLOT_LABEL, REPLICA_OF_LABEL,
+};
+use crate::container::{
+    container_env, Container, ContainerConfig, ContainerManager, DatabaseType, DockerClient,
+};
+use crate::database_metrics::DockerDatabaseMetricsCollector;
+use crate::health::{checker_for, wait_for_healthy};
+use crate::init::run_exec;
+use crate::network::{NetworkConfig, NetworkManager};
+use crate::progress::Progress;
+use crate::util::shell::shell_quote;
+use crate::{DBArenaError, Result};
+use bollard::container::ListContainersOptions;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::info;
+
+/// A replica starts with a full base backup, so allow more than a plain start
+const REPLICA_HEALTH_TIMEOUT: Duration = Duration::from_secs(180);
+
+/// Sets up and inspects Postgres streaming replicas
+pub struct ReplicaManager {
+    client: DockerClient,
+    containers: ContainerManager,
+    networks: NetworkManager,
+}
+
+impl ReplicaManager {
+    pub fn new(client: DockerClient) -> Self {
+        Self {
+            containers: ContainerManager::new(client.clone()),
+            networks: NetworkManager::new(client.clone()),
+            client,
+        }
+    }
+
+    /// Name of the network a primary shares with its replicas
+    pub fn network_name(primary: &str) -> String {
+        format!("dbarena-repl-{}", primary)
+    }
+
+    /// Provision `name` as a streaming replica of `primary` and wait until it is ready
+    ///
+    /// The replica is cloned from the primary with `pg_basebackup -R` on its first
+    /// start, using a physical replication slot named after it, so the primary
+    /// keeps WAL the replica has not received yet.
+    pub async fn create_replica(
+        &self,
+        primary: &Container,
+        name: &str,
+        port: Option<u16>,
+        progress: &dyn Progress,
+    ) -> Result<Container> {
+        if DatabaseType::from_string(&primary.database_type) != Some(DatabaseType::Postgres) {
+            return Err(DBArenaError::InvalidConfig(format!(
+                "Replicas are only supported for Postgres, not {}",
+                primary.database_type
+            )));
+        }
+
+        let docker = self.client.docker();
+        let inspect = docker.inspect_container(&primary.id, None).await?;
+        if inspect.state.as_ref().and_then(|s| s.running) != Some(true) {
+            return Err(DBArenaError::ContainerOperationFailed(format!(
+                "Primary {} is not running",
+                primary.name
+            )));
+        }
+        let env = container_env(
+            inspect
+                .config
+                .as_ref()
+                .and_then(|c| c.env.clone())
+                .unwrap_or_default(),
+        );
+        let user = env
+            .get("POSTGRES_USER")
+            .cloned()
+            .unwrap_or_else(|| "postgres".to_string());
+        let slot = slot_name(name);
+
+        info!("Preparing {} for streaming replication", primary.name);
+        self.prepare_primary(&primary.id, &user, &slot).await?;
+
+        let network = Self::network_name(&primary.name);
+        self.ensure_network(&network).await?;
+        let attached = inspect
+            .network_settings
+            .and_then(|s| s.networks)
+            .is_some_and(|networks| networks.contains_key(&network));
+        if !attached {
+            self.networks
+                .connect_container(&network, &primary.id, Some(vec![primary.name.clone()]))
+                .await?;
+        }
+
+        // The replica carries the primary's credentials so health checks and
+        // connection strings work the same on both
+        let replica_env: HashMap<String, String> = env
+            .iter()
+            .filter(|(key, _)| key.starts_with("POSTGRES_"))
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect();
+
+        let mut config = ContainerConfig::new(DatabaseType::Postgres)
+            .with_version(primary.version.clone())
+            .with_name(name.to_string())
+            .with_env_vars(replica_env)
+            .with_command(replica_command(&primary.name, &user, &slot))
+            .with_label(REPLICA_OF_LABEL.to_string(), primary.name.clone())
+            .with_label(REPLICATION_SLOT_LABEL.to_string(), slot)
+            .with_label(REPLICATION_NETWORK_LABEL.to_string(), network.clone());
+        // Same image as the primary: a base backup only works across identical majors
+        if let Some(image) = inspect.config.and_then(|c| c.image) {
+            config = config.with_image(image);
+        }
+        if let Some(port) = port {
+            config = config.with_port(port);
+        }
+        // pg_basebackup authenticates over the network
+        if let Some(password) = env.get("POSTGRES_PASSWORD") {
+            config = config.with_env_var("PGPASSWORD".to_string(), password.clone());
+        }
+
+        let replica = self.containers.create_container(config).await?;
+        self.networks
+            .connect_container(&network, &replica.id, Some(vec![replica.name.clone()]))
+            .await?;
+        let id = self.containers.start_container(&replica.id).await?;
+
+        let checker = checker_for(DatabaseType::Postgres, docker.clone());
+        wait_for_healthy(&id, checker.as_ref(), REPLICA_HEALTH_TIMEOUT, progress).await?;
+
+        Ok(Container { id, ..replica })
+    }
+
+    /// Let replicas connect and drop a stale slot left by an earlier replica of the same name
+    async fn prepare_primary(&self, primary_id: &str, user: &str, slot: &str) -> Result<()> {
+        let docker = self.client.docker();
+
+        // The image only allows replication connections from localhost
+        let hba = shell_quote("host replication all all md5");
+        let allow = format!(
+            "grep -qx {hba} \"$PGDATA/pg_hba.conf\" || echo {hba} >> \"$PGDATA/pg_hba.conf\"",
+            hba = hba
+        );
+        run_exec(docker, primary_id, vec!["sh".to_string(), "-c".to_string(), allow]).await?;
+
+        let sql = format!(
+            "SELECT pg_reload_conf(); \
+             SELECT pg_drop_replication_slot(slot_name) FROM pg_replication_slots \
+             WHERE slot_name = '{}' AND NOT active;",
+            slot
+        );
+        run_exec(docker, primary_id, psql(user, &sql)).await?;
+        Ok(())
+    }
+
+    async fn ensure_network(&self, network: &str) -> Result<()> {
+        if self.networks.inspect_network(network).await.is_err() {
+            self.networks
+                .create_network(NetworkConfig::new(network.to_string()))
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// Replicas created for `primary`, by their labels
+    pub async fn list_replicas(&self, primary: &Container) -> Result<Vec<(String, String)>> {
+        let mut filters = HashMap::new();
+        filters.insert(
+            "label".to_string(),
+            vec![format!("{}={}", REPLICA_OF_LABEL, primary.name)],
+        );
+        let containers = self
+            .client
+            .docker()
+            .list_containers(Some(ListContainersOptions {
+                all: true,
+                filters,
+                ..Default::default()
+            }))
+            .await?;
+
+        Ok(containers
+            .into_iter()
+            .filter_map(|c| {
+                let name = c.names?.first()?.trim_start_matches('/').to_string();
+                let slot = c.labels?.get(REPLICATION_SLOT_LABEL)?.clone();
+                Some((name, slot))
+            })
+            .collect())
+    }
+
+    /// Replication state of every replica of `primary`, from `pg_stat_replication`
+    ///
+    /// Replicas that exist but have no walsender on the primary are reported as
+    /// `disconnected`; walsenders without a dbarena replica are listed by their
+    /// application name.
+    pub async fn status(&self, primary: &Container) -> Result<Vec<ReplicaStatus>> {
+        let env = container_env(
+            self.client
+                .docker()
+                .inspect_container(&primary.id, None)
+                .await?
+                .config
+                .and_then(|c| c.env)
+                .unwrap_or_default(),
+        );
+        let user = env
+            .get("POSTGRES_USER")
+            .cloned()
+            .unwrap_or_else(|| "postgres".to_string());
+
+        let collector =
+            DockerDatabaseMetricsCollector::new(Arc::new(self.client.docker().clone()));
+        let output = collector
+            .exec_query(
+                &primary.id,
+                vec![
+                    "psql",
+                    "-U",
+                    user.as_str(),
+                    "-d",
+                    "postgres",
+                    "-t",
+                    "-A",
+                    "-F",
+                    ",",
+                    "-c",
+                    "SELECT application_name, state, sync_state, \
+                     pg_wal_lsn_diff(pg_current_wal_lsn(), replay_lsn), \
+                     EXTRACT(EPOCH FROM replay_lag) FROM pg_stat_replication;",
+                ],
+            )
+            .await?;
+        let mut connected = parse_replication_rows(&output);
+
+        let mut statuses = Vec::new();
+        for (name, slot) in self.list_replicas(primary).await? {
+            match connected.iter().position(|(app, _)| *app == slot) {
+                Some(index) => {
+                    let (_, status) = connected.remove(index);
+                    statuses.push(ReplicaStatus { name, ..status });
+                }
+                None => statuses.push(ReplicaStatus {
+                    name,
+                    state: "disconnected".to_string(),
+                    sync_state: None,
+                    lag_bytes: None,
+                    lag_seconds: None,
+                }),
+            }
+        }
+        statuses.extend(connected.into_iter().map(|(_, status)| status));
+
+        Ok(statuses)
+    }
+}
+
+/// Replica entrypoint: take a base backup on first start, then run Postgres as usual
+fn replica_command(primary: &str, user: &str, slot: &str) -> Vec<String> {
+    let script = format!(
+        "set -e\n\
+         if [ ! -s \"$PGDATA/PG_VERSION\" ]; then\n\
+         mkdir -p \"$PGDATA\" && chown postgres:postgres \"$PGDATA\" && chmod 700 \"$PGDATA\"\n\
+         gosu postgres pg_basebackup -h {primary} -U {user} -D \"$PGDATA\" \
+         -R -X stream -C -S {slot}\n\
+         echo \"cluster_name = '{slot}'\" >> \"$PGDATA/postgresql.auto.conf\"\n\
+         fi\n\
+         exec docker-entrypoint.sh postgres",
+        primary = shell_quote(primary),
+        user = shell_quote(user),
+        slot = slot
+    );
+    vec!["bash".to_string(), "-c".to_string(), script]
+}
+
+fn psql(user: &str, sql: &str) -> Vec<String> {
+    vec![
+        "psql".to_string(),
+        "-U".to_string(),
+        user.to_string(),
+        "-d".to_string(),
+        "postgres".to_string(),
+        "-v".to_string(),
+        "ON_ERROR_STOP=1".to_string(),
+        "-c".to_string(),
+        sql.to_string(),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_replica_command_takes_base_backup_once() {
+        let cmd = replica_command("pg-main", "app", "pg_main_replica");
+        assert_eq!(cmd[0], "bash");
+        let script = &cmd[2];
+        assert!(script.contains("if [ ! -s \"$PGDATA/PG_VERSION\" ]"));
+        assert!(script.contains("pg_basebackup -h 'pg-main' -U 'app'"));
+        assert!(script.contains("-C -S pg_main_replica"));
+        assert!(script.ends_with("exec docker-entrypoint.sh postgres"));
+    }
+}
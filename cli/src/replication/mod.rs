@@ -0,0 +1,12 @@
+//! Postgres streaming replication topologies
+//!
+//! A replica is a second Postgres container cloned from its primary with
+//! `pg_basebackup` and kept in sync over a network the two share. The
+//! relationship lives in the replica's labels, so `replica status` can find
+//! every replica of a primary without any local state.
+
+pub mod manager;
+pub mod models;
+
+pub use manager::ReplicaManager;
+pub use models::{ReplicaStatus, REPLICA_OF_LABEL};
@@ -0,0 +1,88 @@
+use serde::Serialize;
+
+/// Label on a replica naming its primary container
+pub const REPLICA_OF_LABEL: &str = "dbarena.replica_of";
+/// Label on a replica naming its physical replication slot on the primary
+pub const REPLICATION_SLOT_LABEL: &str = "dbarena.replication.slot";
+/// Label on a replica naming the network it shares with the primary
+pub const REPLICATION_NETWORK_LABEL: &str = "dbarena.replication.network";
+
+/// One replica's state as seen from the primary
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ReplicaStatus {
+    /// Replica container name
+    pub name: String,
+    /// Walsender state (`streaming`, `catchup`, ...) or `disconnected`
+    pub state: String,
+    /// `async`, `sync`, ...; None when disconnected
+    pub sync_state: Option<String>,
+    /// WAL the replica has not replayed yet, in bytes
+    pub lag_bytes: Option<u64>,
+    /// Replay lag as reported by the primary, in seconds
+    pub lag_seconds: Option<f64>,
+}
+
+/// Replication slot / `cluster_name` for a replica container name
+///
+/// Slot names may only contain lower-case letters, digits and underscores.
+pub fn slot_name(replica: &str) -> String {
+    replica
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+/// Parse `psql -t -A -F ,` rows of
+/// `application_name, state, sync_state, lag_bytes, lag_seconds`
+/// from `pg_stat_replication`, keyed by application name
+pub fn parse_replication_rows(output: &str) -> Vec<(String, ReplicaStatus)> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let parts: Vec<&str> = line.trim().split(',').collect();
+            if parts.len() != 5 {
+                return None;
+            }
+            Some((
+                parts[0].to_string(),
+                ReplicaStatus {
+                    name: parts[0].to_string(),
+                    state: parts[1].to_string(),
+                    sync_state: Some(parts[2].to_string()).filter(|s| !s.is_empty()),
+                    lag_bytes: parts[3].parse().ok(),
+                    lag_seconds: parts[4].parse().ok(),
+                },
+            ))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slot_name() {
+        assert_eq!(slot_name("pg-main-Replica.1"), "pg_main_replica_1");
+    }
+
+    #[test]
+    fn test_parse_replication_rows() {
+        let output = "pg_main_replica,streaming,async,1024,0.25\n\
+                      other,catchup,async,,\n\
+                      garbage line\n";
+        let rows = parse_replication_rows(output);
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].0, "pg_main_replica");
+        assert_eq!(rows[0].1.state, "streaming");
+        assert_eq!(rows[0].1.lag_bytes, Some(1024));
+        assert_eq!(rows[0].1.lag_seconds, Some(0.25));
+        assert_eq!(rows[1].1.lag_bytes, None);
+    }
+}
@@ -0,0 +1,236 @@
+use console::style;
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+use super::{DatabaseSchema, TableSchema};
+
+/// A line present on only one side of a table's diff
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "side", content = "text", rename_all = "lowercase")]
+pub enum DiffLine {
+    Left(String),
+    Right(String),
+}
+
+/// Differences in one table
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TableDiff {
+    pub table: String,
+    pub lines: Vec<DiffLine>,
+}
+
+/// Structural differences between two schemas, table by table
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SchemaDiff {
+    pub tables: Vec<TableDiff>,
+}
+
+impl SchemaDiff {
+    /// Compare two schemas. Indexes and foreign keys are matched by their columns rather
+    /// than their names, which SQL Server generates per database.
+    pub fn between(left: &DatabaseSchema, right: &DatabaseSchema) -> Self {
+        let left_tables: BTreeMap<&str, &TableSchema> =
+            left.tables.iter().map(|t| (t.name.as_str(), t)).collect();
+        let right_tables: BTreeMap<&str, &TableSchema> =
+            right.tables.iter().map(|t| (t.name.as_str(), t)).collect();
+
+        let mut names: Vec<&str> = left_tables.keys().chain(right_tables.keys()).copied().collect();
+        names.sort_unstable();
+        names.dedup();
+
+        let mut tables = Vec::new();
+        for name in names {
+            let lines = match (left_tables.get(name), right_tables.get(name)) {
+                (Some(_), None) => vec![DiffLine::Left(format!("table {}", name))],
+                (None, Some(_)) => vec![DiffLine::Right(format!("table {}", name))],
+                (Some(l), Some(r)) => diff_entries(&entries(l), &entries(r)),
+                (None, None) => unreachable!(),
+            };
+            if !lines.is_empty() {
+                tables.push(TableDiff {
+                    table: name.to_string(),
+                    lines,
+                });
+            }
+        }
+
+        Self { tables }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tables.is_empty()
+    }
+
+    /// Print as a colorized unified diff
+    pub fn print(&self, left: &str, right: &str) {
+        println!("{}", style(format!("--- {}", left)).red().bold());
+        println!("{}", style(format!("+++ {}", right)).green().bold());
+        for table in &self.tables {
+            println!("{}", style(format!("@@ {} @@", table.table)).cyan());
+            for line in &table.lines {
+                match line {
+                    DiffLine::Left(text) => println!("{}", style(format!("-{}", text)).red()),
+                    DiffLine::Right(text) => println!("{}", style(format!("+{}", text)).green()),
+                }
+            }
+        }
+    }
+}
+
+/// A table's structure as `key => description` lines; lines with the same key are
+/// compared, lines whose key is missing on the other side are added or removed
+fn entries(table: &TableSchema) -> BTreeMap<String, String> {
+    let mut entries = BTreeMap::new();
+    for column in &table.columns {
+        let mut line = format!("column {} {}", column.name, column.data_type);
+        if !column.is_nullable {
+            line.push_str(" NOT NULL");
+        }
+        if column.is_primary_key {
+            line.push_str(" PRIMARY KEY");
+        }
+        entries.insert(format!("column {}", column.name), line);
+    }
+    for index in &table.indexes {
+        let columns = index.columns.join(", ");
+        let kind = if index.is_unique { "unique index" } else { "index" };
+        entries.insert(
+            format!("index ({})", columns),
+            format!("{} ({})", kind, columns),
+        );
+    }
+    for fk in &table.foreign_keys {
+        let target = if fk.referenced_column.is_empty() {
+            fk.referenced_table.clone()
+        } else {
+            format!("{}({})", fk.referenced_table, fk.referenced_column)
+        };
+        entries.insert(
+            format!("foreign key {}", fk.column),
+            format!("foreign key {} -> {}", fk.column, target),
+        );
+    }
+    if let Some(count) = table.row_count {
+        entries.insert("rows".to_string(), format!("rows {}", count));
+    }
+    entries
+}
+
+fn diff_entries(left: &BTreeMap<String, String>, right: &BTreeMap<String, String>) -> Vec<DiffLine> {
+    let mut keys: Vec<&String> = left.keys().chain(right.keys()).collect();
+    keys.sort_unstable();
+    keys.dedup();
+
+    let mut lines = Vec::new();
+    for key in keys {
+        match (left.get(key), right.get(key)) {
+            (Some(l), Some(r)) if l == r => {}
+            (l, r) => {
+                lines.extend(l.map(|l| DiffLine::Left(l.clone())));
+                lines.extend(r.map(|r| DiffLine::Right(r.clone())));
+            }
+        }
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::container::DatabaseType;
+    use crate::workload::{ColumnMetadata, ForeignKeyMetadata, IndexMetadata};
+
+    fn column(name: &str, data_type: &str, nullable: bool) -> ColumnMetadata {
+        ColumnMetadata {
+            name: name.to_string(),
+            data_type: data_type.to_string(),
+            is_nullable: nullable,
+            is_primary_key: name == "id",
+        }
+    }
+
+    fn table(name: &str, columns: Vec<ColumnMetadata>) -> TableSchema {
+        TableSchema {
+            name: name.to_string(),
+            columns,
+            indexes: vec![IndexMetadata {
+                name: format!("PK__{}__3213E83F", name),
+                columns: vec!["id".to_string()],
+                is_unique: true,
+            }],
+            foreign_keys: Vec::new(),
+            row_count: None,
+        }
+    }
+
+    fn schema(tables: Vec<TableSchema>) -> DatabaseSchema {
+        DatabaseSchema {
+            database_type: DatabaseType::Postgres,
+            tables,
+        }
+    }
+
+    #[test]
+    fn test_identical_schemas() {
+        let a = schema(vec![table("users", vec![column("id", "integer", false)])]);
+        let mut b = a.clone();
+        // Generated index names don't count as a difference
+        b.tables[0].indexes[0].name = "PK__users__8A2B11C0".to_string();
+        assert!(SchemaDiff::between(&a, &b).is_empty());
+    }
+
+    #[test]
+    fn test_column_and_table_changes() {
+        let a = schema(vec![
+            table("orders", vec![column("id", "integer", false)]),
+            table(
+                "users",
+                vec![column("id", "integer", false), column("email", "varchar", false)],
+            ),
+        ]);
+        let mut users = table(
+            "users",
+            vec![
+                column("id", "integer", false),
+                column("email", "text", true),
+                column("name", "text", true),
+            ],
+        );
+        users.foreign_keys.push(ForeignKeyMetadata {
+            column: "org_id".to_string(),
+            referenced_table: "orgs".to_string(),
+            referenced_column: "id".to_string(),
+        });
+        let b = schema(vec![users]);
+
+        let diff = SchemaDiff::between(&a, &b);
+        assert_eq!(diff.tables.len(), 2);
+        assert_eq!(diff.tables[0].table, "orders");
+        assert_eq!(diff.tables[0].lines, vec![DiffLine::Left("table orders".to_string())]);
+        assert_eq!(
+            diff.tables[1].lines,
+            vec![
+                DiffLine::Left("column email varchar NOT NULL".to_string()),
+                DiffLine::Right("column email text".to_string()),
+                DiffLine::Right("column name text".to_string()),
+                DiffLine::Right("foreign key org_id -> orgs(id)".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_row_counts() {
+        let mut a = schema(vec![table("users", Vec::new())]);
+        let mut b = a.clone();
+        a.tables[0].row_count = Some(100);
+        b.tables[0].row_count = Some(120);
+        let diff = SchemaDiff::between(&a, &b);
+        assert_eq!(
+            diff.tables[0].lines,
+            vec![
+                DiffLine::Left("rows 100".to_string()),
+                DiffLine::Right("rows 120".to_string()),
+            ]
+        );
+    }
+}
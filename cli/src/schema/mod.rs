@@ -0,0 +1,182 @@
+//! Structure of a live database: tables, columns, indexes and foreign keys
+//!
+//! Read through the workload [`MetadataCollector`], so it works wherever seeding and
+//! workloads do.
+
+pub mod diff;
+
+use anyhow::Result;
+use console::style;
+use serde::Serialize;
+
+use crate::container::DatabaseType;
+use crate::workload::{ColumnMetadata, ForeignKeyMetadata, IndexMetadata, MetadataCollector};
+
+pub use diff::{DiffLine, SchemaDiff, TableDiff};
+
+/// What to read for each table
+#[derive(Debug, Clone, Copy, Default)]
+pub struct IntrospectOptions {
+    /// Table names only: skip columns, indexes and foreign keys
+    pub tables_only: bool,
+    /// Count each table's rows exactly
+    pub row_counts: bool,
+}
+
+/// One table's structure
+#[derive(Debug, Clone, Serialize)]
+pub struct TableSchema {
+    pub name: String,
+    pub columns: Vec<ColumnMetadata>,
+    pub indexes: Vec<IndexMetadata>,
+    pub foreign_keys: Vec<ForeignKeyMetadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub row_count: Option<u64>,
+}
+
+impl TableSchema {
+    /// One line per column, then its indexes and foreign keys
+    pub fn describe(&self) -> Vec<String> {
+        let name_width = self.columns.iter().map(|c| c.name.len()).max().unwrap_or(0);
+        let type_width = self.columns.iter().map(|c| c.data_type.len()).max().unwrap_or(0);
+
+        let mut lines = Vec::new();
+        for column in &self.columns {
+            let mut line = format!(
+                "{:<name_width$}  {:<type_width$}",
+                column.name, column.data_type
+            );
+            if !column.is_nullable {
+                line.push_str("  NOT NULL");
+            }
+            if column.is_primary_key {
+                line.push_str("  PRIMARY KEY");
+            }
+            lines.push(line.trim_end().to_string());
+        }
+        for index in &self.indexes {
+            let unique = if index.is_unique { " unique" } else { "" };
+            lines.push(format!("index {} ({}){}", index.name, index.columns.join(", "), unique));
+        }
+        for fk in &self.foreign_keys {
+            if fk.referenced_column.is_empty() {
+                lines.push(format!("foreign key {} -> {}", fk.column, fk.referenced_table));
+            } else {
+                lines.push(format!(
+                    "foreign key {} -> {}({})",
+                    fk.column, fk.referenced_table, fk.referenced_column
+                ));
+            }
+        }
+        if let Some(count) = self.row_count {
+            lines.push(format!("{} rows", count));
+        }
+        lines
+    }
+}
+
+/// Every table in a database, sorted by name
+#[derive(Debug, Clone, Serialize)]
+pub struct DatabaseSchema {
+    pub database_type: DatabaseType,
+    pub tables: Vec<TableSchema>,
+}
+
+impl DatabaseSchema {
+    /// Print each table and its [`describe`](TableSchema::describe) lines
+    pub fn print(&self, name: &str) {
+        println!(
+            "{} {} ({}, {} table(s))",
+            style("Schema of").bold(),
+            style(name).cyan().bold(),
+            self.database_type,
+            self.tables.len()
+        );
+        for table in &self.tables {
+            println!();
+            println!("{}", style(&table.name).bold());
+            for line in table.describe() {
+                println!("  {}", line);
+            }
+        }
+    }
+}
+
+/// Read the schema of the database `metadata` is connected to
+pub async fn introspect(
+    metadata: &mut MetadataCollector,
+    db_type: DatabaseType,
+    options: IntrospectOptions,
+) -> Result<DatabaseSchema> {
+    let names = metadata.list_tables().await?;
+
+    let mut tables = Vec::with_capacity(names.len());
+    for name in names {
+        let mut table = TableSchema {
+            name: name.clone(),
+            columns: Vec::new(),
+            indexes: Vec::new(),
+            foreign_keys: Vec::new(),
+            row_count: None,
+        };
+        if !options.tables_only {
+            table.columns = metadata.get_metadata(&name).await?.columns.clone();
+            table.indexes = metadata.get_indexes(&name).await?;
+            table.foreign_keys = metadata.get_foreign_keys(&name).await?;
+        }
+        if options.row_counts {
+            table.row_count = Some(metadata.count_rows(&name).await?);
+        }
+        tables.push(table);
+    }
+
+    Ok(DatabaseSchema {
+        database_type: db_type,
+        tables,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_describe_table() {
+        let column = |name: &str, data_type: &str, nullable: bool| ColumnMetadata {
+            name: name.to_string(),
+            data_type: data_type.to_string(),
+            is_nullable: nullable,
+            is_primary_key: name == "id",
+        };
+        let table = TableSchema {
+            name: "orders".to_string(),
+            columns: vec![
+                column("id", "integer", false),
+                column("user_id", "integer", false),
+                column("note", "text", true),
+            ],
+            indexes: vec![IndexMetadata {
+                name: "orders_user_id_idx".to_string(),
+                columns: vec!["user_id".to_string()],
+                is_unique: false,
+            }],
+            foreign_keys: vec![ForeignKeyMetadata {
+                column: "user_id".to_string(),
+                referenced_table: "users".to_string(),
+                referenced_column: "id".to_string(),
+            }],
+            row_count: None,
+        };
+
+        assert_eq!(
+            table.describe(),
+            vec![
+                "id       integer  NOT NULL  PRIMARY KEY",
+                "user_id  integer  NOT NULL",
+                "note     text",
+                "index orders_user_id_idx (user_id)",
+                "foreign key user_id -> users(id)",
+            ]
+        );
+    }
+}
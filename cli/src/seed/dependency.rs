@@ -32,11 +32,15 @@ impl DependencyResolver {
     }
 
     /// Resolve the seeding order using topological sort
-    /// Returns a Vec of "levels" where tables in the same level can be seeded in parallel
+    /// Returns a Vec of "levels" where tables in the same level can be seeded in parallel,
+    /// each listed in the order of `tables`
     pub fn resolve_order(&self, tables: &[String]) -> Result<Vec<Vec<String>>> {
         // Check for cycles first
-        if self.has_cycle(tables)? {
-            return Err(anyhow!("Circular dependency detected in table relationships"));
+        if let Some(cycle) = self.find_cycle(tables) {
+            return Err(anyhow!(
+                "Circular dependency detected in table relationships: {}",
+                cycle.join(" -> ")
+            ));
         }
 
         // Build the graph with only requested tables
@@ -93,6 +97,11 @@ impl DependencyResolver {
             }
         }
 
+        // Keep each level in declared order rather than hash order
+        for level in &mut levels {
+            level.sort_by_key(|table| tables.iter().position(|t| t == table));
+        }
+
         // Verify all tables were processed
         let total_processed: usize = levels.iter().map(|level| level.len()).sum();
         if total_processed != tables.len() {
@@ -104,49 +113,63 @@ impl DependencyResolver {
 
     /// Check if there's a cycle in the dependency graph
     pub fn has_cycle(&self, tables: &[String]) -> Result<bool> {
+        Ok(self.find_cycle(tables).is_some())
+    }
+
+    /// Find a cycle in the dependency graph, returned as the path of tables
+    /// that forms it (the first table is repeated at the end)
+    pub fn find_cycle(&self, tables: &[String]) -> Option<Vec<String>> {
         let mut visited = HashSet::new();
-        let mut rec_stack = HashSet::new();
+        let mut path = Vec::new();
 
         for table in tables {
             if !visited.contains(table) {
-                if self.has_cycle_util(table, tables, &mut visited, &mut rec_stack) {
-                    return Ok(true);
+                if let Some(cycle) = self.find_cycle_util(table, tables, &mut visited, &mut path) {
+                    return Some(cycle);
                 }
             }
         }
 
-        Ok(false)
+        None
     }
 
-    fn has_cycle_util(
+    fn find_cycle_util(
         &self,
         table: &str,
         tables: &[String],
         visited: &mut HashSet<String>,
-        rec_stack: &mut HashSet<String>,
-    ) -> bool {
+        path: &mut Vec<String>,
+    ) -> Option<Vec<String>> {
         visited.insert(table.to_string());
-        rec_stack.insert(table.to_string());
+        path.push(table.to_string());
 
         if let Some(deps) = self.dependencies.get(table) {
+            // Sort for a stable cycle description
+            let mut deps: Vec<&String> = deps.iter().collect();
+            deps.sort();
+
             for dep in deps {
                 // Only consider dependencies within our table set
                 if !tables.contains(dep) {
                     continue;
                 }
 
+                if let Some(start) = path.iter().position(|t| t == dep) {
+                    let mut cycle = path[start..].to_vec();
+                    cycle.push(dep.clone());
+                    return Some(cycle);
+                }
+
                 if !visited.contains(dep) {
-                    if self.has_cycle_util(dep, tables, visited, rec_stack) {
-                        return true;
+                    if let Some(cycle) = self.find_cycle_util(dep, tables, visited, path) {
+                        return Some(cycle);
                     }
-                } else if rec_stack.contains(dep) {
-                    return true;
                 }
             }
         }
 
-        rec_stack.remove(table);
-        false
+        path.pop();
+        None
     }
 }
 
@@ -211,6 +234,24 @@ mod tests {
         assert!(order[1].contains(&"reviews".to_string()));
     }
 
+    #[test]
+    fn test_levels_follow_declared_order() {
+        let tables: Vec<String> = ["zebra", "apple", "mango", "kiwi", "banana", "cherry"]
+            .iter()
+            .map(|t| t.to_string())
+            .collect();
+        let mut resolver = DependencyResolver::new();
+        for table in &tables {
+            resolver.add_table(table.clone());
+        }
+        resolver.add_dependency("banana".to_string(), "zebra".to_string());
+        resolver.add_dependency("apple".to_string(), "zebra".to_string());
+
+        let order = resolver.resolve_order(&tables).unwrap();
+        assert_eq!(order[0], vec!["zebra", "mango", "kiwi", "cherry"]);
+        assert_eq!(order[1], vec!["apple", "banana"]);
+    }
+
     #[test]
     fn test_cycle_detection() {
         let mut resolver = DependencyResolver::new();
@@ -232,6 +273,29 @@ mod tests {
             .contains("Circular dependency"));
     }
 
+    #[test]
+    fn test_cycle_error_lists_tables() {
+        let mut resolver = DependencyResolver::new();
+
+        resolver.add_dependency("orders".to_string(), "users".to_string());
+        resolver.add_dependency("users".to_string(), "orders".to_string());
+        resolver.add_table("products".to_string());
+
+        let tables = vec![
+            "orders".to_string(),
+            "users".to_string(),
+            "products".to_string(),
+        ];
+
+        assert_eq!(
+            resolver.find_cycle(&tables).unwrap(),
+            vec!["orders", "users", "orders"]
+        );
+
+        let err = resolver.resolve_order(&tables).unwrap_err().to_string();
+        assert!(err.contains("orders -> users -> orders"));
+    }
+
     #[test]
     fn test_self_referential_table() {
         let mut resolver = DependencyResolver::new();
@@ -1,21 +1,28 @@
 use anyhow::{anyhow, Result};
 use bollard::Docker;
-use futures::future::join_all;
-use indicatif::{ProgressBar, ProgressStyle};
 use rand::SeedableRng;
 use rand_chacha::ChaCha8Rng;
-use std::collections::HashMap;
-use std::sync::Arc;
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
 use std::time::Instant;
 
-use crate::container::DatabaseType;
+use crate::container::{ConnParams, DatabaseType, SQLITE_DB_PATH};
 use crate::database_metrics::collector::DockerDatabaseMetricsCollector;
-use crate::seed::config::SeedRule;
+use crate::progress::{IndicatifProgress, NoProgress, Progress};
+use crate::seed::config::{ColumnRule, SeedRule};
 use crate::seed::dependency::DependencyResolver;
 use crate::seed::foreign_key::ForeignKeyResolver;
-use crate::seed::generator::{create_generator, DataGenerator, ForeignKeyInfo};
+use crate::seed::generator::{
+    create_generator, with_null_probability, CsvGenerator, CsvSources, DataGenerator,
+    BinaryFormat, ForeignKeyInfo, GeoFormat, UniqueGenerator, WorkerShare,
+};
 use crate::seed::models::{Row, SeedStats};
-use crate::seed::sql_builder::build_batch_insert;
+use crate::seed::sql_builder::build_batch_insert_with_expressions;
+use crate::util::bounded::{collect_errors, for_each_concurrent, DEFAULT_CONCURRENCY};
+
+/// Generators of a table's columns, by column name
+type ColumnGenerators = HashMap<String, Box<dyn DataGenerator>>;
 
 /// Main seeding engine
 pub struct SeedingEngine {
@@ -25,8 +32,55 @@ pub struct SeedingEngine {
     rng: ChaCha8Rng,
     seed: u64,
     batch_size: usize,
+    /// Maximum number of tables seeded at once within a dependency level
+    concurrency: usize,
+    /// Concurrent exec sessions inserting batches into a single table
+    parallel: usize,
+    /// Draw a progress bar per table while seeding
+    show_progress: bool,
     collector: DockerDatabaseMetricsCollector,
     fk_resolver: Arc<ForeignKeyResolver>,
+    /// Columns referenced by foreign keys elsewhere (table -> columns) whose
+    /// generated values are cached for dependent tables
+    referenced_columns: Arc<HashMap<String, HashSet<String>>>,
+    /// CSV files read by `csv` columns, loaded once per run
+    csv_sources: Arc<CsvSources>,
+    /// Capture INSERTs instead of executing them
+    dry_run: Option<Arc<DryRun>>,
+    /// Credentials, and for an external database its address
+    conn: Arc<ConnParams>,
+}
+
+/// INSERT statements a dry run would have executed, first few rows per table
+struct DryRun {
+    preview: usize,
+    statements: Mutex<HashMap<String, Vec<String>>>,
+}
+
+impl DryRun {
+    /// Keep single-row INSERTs for the table's first `preview` rows
+    fn capture(
+        &self,
+        db_type: DatabaseType,
+        table: &str,
+        columns: &[String],
+        rows: &[Row],
+        expression_columns: &HashSet<String>,
+    ) -> Result<()> {
+        let mut statements = self.statements.lock().unwrap();
+        let captured = statements.entry(table.to_string()).or_default();
+        let wanted = self.preview.saturating_sub(captured.len());
+        for row in rows.iter().take(wanted) {
+            captured.push(build_batch_insert_with_expressions(
+                db_type,
+                table,
+                columns,
+                std::slice::from_ref(row),
+                expression_columns,
+            )?);
+        }
+        Ok(())
+    }
 }
 
 impl SeedingEngine {
@@ -52,28 +106,113 @@ impl SeedingEngine {
             rng,
             seed,
             batch_size,
+            concurrency: DEFAULT_CONCURRENCY,
+            parallel: 1,
+            show_progress: true,
             collector,
             fk_resolver,
+            referenced_columns: Arc::new(HashMap::new()),
+            csv_sources: Arc::new(CsvSources::default()),
+            dry_run: None,
+            conn: Arc::new(ConnParams::from_env(db_type, HashMap::new())),
         }
     }
 
+    /// Connect with these parameters instead of the image defaults
+    ///
+    /// Typically the running container's own (see
+    /// [`crate::container::connection_params`]), or an external database's
+    /// from [`ConnParams::from_url`], whose statements then run through the
+    /// native client on this host.
+    pub fn with_connection(mut self, conn: ConnParams) -> Self {
+        self.fk_resolver = Arc::new(
+            ForeignKeyResolver::new(
+                self.docker_client.clone(),
+                self.container_id.clone(),
+                self.db_type,
+            )
+            .with_connection(conn.clone()),
+        );
+        self.conn = Arc::new(conn);
+        self
+    }
+
+    /// Generate every row but only keep INSERTs for the first `preview` rows
+    /// of each table instead of executing them
+    ///
+    /// The database is still read for column types and existing foreign key
+    /// values. A reference to a table with no rows that this run doesn't seed
+    /// either gets a `<fk:table.column>` placeholder.
+    pub fn with_dry_run(mut self, preview: usize) -> Self {
+        self.dry_run = Some(Arc::new(DryRun {
+            preview,
+            statements: Mutex::new(HashMap::new()),
+        }));
+        self
+    }
+
+    /// INSERTs captured for a table by a dry run, in generation order
+    pub fn dry_run_statements(&self, table: &str) -> Vec<String> {
+        self.dry_run
+            .as_ref()
+            .and_then(|dry_run| dry_run.statements.lock().unwrap().get(table).cloned())
+            .unwrap_or_default()
+    }
+
+    /// Limit how many tables in the same dependency level are seeded at once
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency;
+        self
+    }
+
+    /// Split each table's batches across `parallel` concurrent exec sessions
+    ///
+    /// Each worker draws from its own RNG derived from the seed and its index,
+    /// with generators of its own: sequences continue from the rows of the
+    /// workers before it and `unique` values are split between workers by
+    /// hash. A run is reproducible for a given seed and worker count.
+    pub fn with_parallel(mut self, parallel: usize) -> Self {
+        self.parallel = parallel.max(1);
+        self
+    }
+
+    /// Enable or disable terminal progress bars (on by default)
+    pub fn with_progress(mut self, show_progress: bool) -> Self {
+        self.show_progress = show_progress;
+        self
+    }
+
     /// Seed multiple tables with dependency resolution and parallel execution
     pub async fn seed_all(&mut self, rules: &[SeedRule]) -> Result<Vec<SeedStats>> {
+        if !self.db_type.is_sql() {
+            return Err(anyhow!(
+                "Seeding is not supported for NoSQL databases ({})",
+                self.db_type
+            ));
+        }
+
         // Build dependency resolver
         let mut dep_resolver = DependencyResolver::new();
 
+        let mut referenced: HashMap<String, HashSet<String>> = HashMap::new();
+
         // Analyze foreign key dependencies
         for rule in rules {
             dep_resolver.add_table(rule.name.clone());
 
             for col_rule in &rule.columns {
                 if col_rule.generator == "foreign_key" {
-                    if let Ok(fk_info) = ForeignKeyInfo::from_options(&col_rule.options) {
-                        dep_resolver.add_dependency(rule.name.clone(), fk_info.table.clone());
-                    }
+                    let fk_info = ForeignKeyInfo::from_options(&col_rule.options)
+                        .map_err(|e| anyhow!("{}.{}: {}", rule.name, col_rule.name, e))?;
+                    dep_resolver.add_dependency(rule.name.clone(), fk_info.table.clone());
+                    referenced
+                        .entry(fk_info.table)
+                        .or_default()
+                        .insert(fk_info.column);
                 }
             }
         }
+        self.referenced_columns = Arc::new(referenced);
 
         // Resolve seeding order
         let table_names: Vec<String> = rules.iter().map(|r| r.name.clone()).collect();
@@ -99,9 +238,7 @@ impl SeedingEngine {
             }
 
             // Seed tables in parallel
-            let mut futures = Vec::new();
-
-            for table_name in level_tables {
+            let level_stats = for_each_concurrent(level_tables, self.concurrency, |table_name| {
                 let rule = rules
                     .iter()
                     .find(|r| &r.name == table_name)
@@ -115,20 +252,22 @@ impl SeedingEngine {
                     self.docker_client.clone(),
                     self.seed,
                     self.batch_size,
-                );
+                )
+                .with_parallel(self.parallel)
+                .with_progress(self.show_progress);
 
-                // Share the FK resolver
+                // Share the FK resolver, the set of keys to cache and any dry run
                 engine.fk_resolver = self.fk_resolver.clone();
+                engine.referenced_columns = self.referenced_columns.clone();
+                engine.csv_sources = self.csv_sources.clone();
+                engine.dry_run = self.dry_run.clone();
+                engine.conn = self.conn.clone();
 
-                futures.push(async move { engine.seed_table(&rule).await });
-            }
-
-            // Wait for all tables in this level to complete
-            let level_stats = join_all(futures).await;
+                async move { engine.seed_table(&rule).await }
+            })
+            .await;
 
-            for stat in level_stats {
-                all_stats.push(stat?);
-            }
+            all_stats.extend(collect_errors(level_stats)?);
         }
 
         Ok(all_stats)
@@ -139,9 +278,19 @@ impl SeedingEngine {
         for col_rule in &rule.columns {
             if col_rule.generator == "foreign_key" {
                 if let Ok(fk_info) = ForeignKeyInfo::from_options(&col_rule.options) {
-                    self.fk_resolver
+                    let loaded = self
+                        .fk_resolver
                         .load_ids(&fk_info.table, &fk_info.column)
-                        .await?;
+                        .await;
+                    match loaded {
+                        Err(_) if self.dry_run.is_some() => {
+                            let placeholder = format!("<fk:{}.{}>", fk_info.table, fk_info.column);
+                            self.fk_resolver
+                                .record_ids(&fk_info.table, &fk_info.column, vec![placeholder])
+                                .await;
+                        }
+                        loaded => loaded?,
+                    }
                 }
             }
         }
@@ -152,18 +301,113 @@ impl SeedingEngine {
     pub async fn seed_table(&mut self, rule: &SeedRule) -> Result<SeedStats> {
         let start = Instant::now();
 
-        // Create progress bar
-        let pb = ProgressBar::new(rule.count as u64);
-        pb.set_style(
-            ProgressStyle::default_bar()
-                .template("{spinner:.green} [{elapsed_precise}] [{wide_bar:.cyan/blue}] {pos}/{len} rows ({per_sec})")
-                .unwrap()
-                .progress_chars("#>-"),
+        let progress: Box<dyn Progress> = if self.show_progress {
+            Box::new(IndicatifProgress::new().with_unit("rows"))
+        } else {
+            Box::new(NoProgress)
+        };
+        progress.start(
+            &format!("Seeding table: {}", rule.name),
+            Some(rule.count as u64),
         );
-        pb.set_message(format!("Seeding table: {}", rule.name));
 
-        // Build generators for each column
-        let mut generators: HashMap<String, Box<dyn DataGenerator>> = HashMap::new();
+        // geo_point columns without an explicit format follow the target column type
+        let column_types = if rule.columns.iter().any(|c| has_auto_format(c, "geo_point")) {
+            self.fetch_column_types(&rule.name).await?
+        } else {
+            HashMap::new()
+        };
+        let (generators, fk_columns) = self.build_generators(rule, &column_types)?;
+
+        let column_names: Vec<String> = rule.columns.iter().map(|c| c.name.clone()).collect();
+        let expression_columns: HashSet<String> = generators
+            .iter()
+            .filter(|(_, gen)| gen.is_sql_expression())
+            .map(|(name, _)| name.clone())
+            .collect();
+
+        // Generate and insert data in batches, split across workers if asked
+        let inserted = AtomicUsize::new(0);
+        let batches = rule.count.div_ceil(self.batch_size.max(1));
+        let workers = self.parallel.min(batches).max(1);
+
+        if workers == 1 {
+            self.insert_rows(
+                rule,
+                &column_names,
+                &generators,
+                &fk_columns,
+                &expression_columns,
+                rule.count,
+                progress.as_ref(),
+                &inserted,
+            )
+            .await?;
+        } else {
+            // Each worker gets generators of its own, confined to its share of
+            // the rows, so what it generates depends only on its own RNG
+            let rows = split_rows(rule.count, workers, self.batch_size);
+            let mut shares = Vec::with_capacity(workers);
+            let mut offset = 0;
+            for (index, rows) in rows.into_iter().enumerate() {
+                let worker = self.worker(index);
+                let (generators, _) = worker.build_generators(rule, &column_types)?;
+                let share = WorkerShare {
+                    index,
+                    workers,
+                    offset,
+                };
+                for generator in generators.values() {
+                    generator.assign_share(share);
+                }
+                shares.push((worker, generators, rows));
+                offset += rows;
+            }
+
+            let (column_names, fk_columns, expression_columns, progress, inserted) = (
+                &column_names,
+                &fk_columns,
+                &expression_columns,
+                progress.as_ref(),
+                &inserted,
+            );
+            let results = for_each_concurrent(shares, workers, |(mut worker, generators, rows)| {
+                async move {
+                    worker
+                        .insert_rows(
+                            rule,
+                            column_names,
+                            &generators,
+                            fk_columns,
+                            expression_columns,
+                            rows,
+                            progress,
+                            inserted,
+                        )
+                        .await
+                }
+            })
+            .await;
+            collect_errors(results)?;
+        }
+
+        progress.finish(&format!("Completed seeding table: {}", rule.name));
+
+        let duration = start.elapsed();
+        Ok(SeedStats::new(rule.name.clone(), inserted.into_inner(), duration).with_workers(workers))
+    }
+
+    /// Build the generators for a table's columns, and the foreign key columns
+    /// filled in separately
+    ///
+    /// `unique` generators remember every value they hand out for as long as
+    /// the table is being seeded.
+    fn build_generators(
+        &self,
+        rule: &SeedRule,
+        column_types: &HashMap<String, String>,
+    ) -> Result<(ColumnGenerators, HashMap<String, ForeignKeyInfo>)> {
+        let mut generators = ColumnGenerators::new();
         let mut fk_columns: HashMap<String, ForeignKeyInfo> = HashMap::new();
 
         for col_rule in &rule.columns {
@@ -171,38 +415,107 @@ impl SeedingEngine {
                 // Handle FK separately since it needs async
                 let fk_info = ForeignKeyInfo::from_options(&col_rule.options)?;
                 fk_columns.insert(col_rule.name.clone(), fk_info);
+            } else if col_rule.generator == "csv" {
+                let generator =
+                    CsvGenerator::from_options(&col_rule.options, &self.csv_sources, &rule.name)
+                        .map_err(|e| anyhow!("{}.{}: {}", rule.name, col_rule.name, e))?;
+                let generator = with_null_probability(Box::new(generator), &col_rule.options)?;
+                generators.insert(
+                    col_rule.name.clone(),
+                    unique_generator(rule, col_rule, generator)?,
+                );
+            } else if has_auto_format(col_rule, "geo_point") {
+                // geo_point columns without an explicit format follow the target column type
+                let mut options = col_rule.options.clone();
+                if let Some(column_type) = column_types.get(&col_rule.name) {
+                    let format = GeoFormat::for_column_type(self.db_type, column_type);
+                    options.insert("format".to_string(), toml::Value::String(format.as_str().to_string()));
+                }
+                let generator = create_generator(&col_rule.generator, &options)?;
+                generators.insert(
+                    col_rule.name.clone(),
+                    unique_generator(rule, col_rule, generator)?,
+                );
+            } else if has_auto_format(col_rule, "bytes") {
+                // Binary literals take a different form in each database
+                let mut options = col_rule.options.clone();
+                let format = BinaryFormat::for_database(self.db_type);
+                options.insert("format".to_string(), toml::Value::String(format.as_str().to_string()));
+                let generator = create_generator(&col_rule.generator, &options)?;
+                generators.insert(
+                    col_rule.name.clone(),
+                    unique_generator(rule, col_rule, generator)?,
+                );
             } else {
                 let generator = create_generator(&col_rule.generator, &col_rule.options)?;
-                generators.insert(col_rule.name.clone(), generator);
+                generators.insert(
+                    col_rule.name.clone(),
+                    unique_generator(rule, col_rule, generator)?,
+                );
             }
         }
 
-        let column_names: Vec<String> = rule.columns.iter().map(|c| c.name.clone()).collect();
+        Ok((generators, fk_columns))
+    }
 
-        // Generate and insert data in batches
-        let mut total_inserted = 0;
-        let mut remaining = rule.count;
+    /// Engine for one worker of a parallel table seed, with its own RNG
+    fn worker(&self, index: usize) -> Self {
+        let mut engine = Self::new(
+            self.container_id.clone(),
+            self.db_type,
+            self.docker_client.clone(),
+            worker_seed(self.seed, index),
+            self.batch_size,
+        );
+        engine.fk_resolver = self.fk_resolver.clone();
+        engine.referenced_columns = self.referenced_columns.clone();
+        engine.csv_sources = Arc::new(self.csv_sources.for_worker());
+        engine.dry_run = self.dry_run.clone();
+        engine.conn = self.conn.clone();
+        engine
+    }
+
+    /// Generate and insert `count` rows of a table in batches
+    #[allow(clippy::too_many_arguments)]
+    async fn insert_rows(
+        &mut self,
+        rule: &SeedRule,
+        column_names: &[String],
+        generators: &HashMap<String, Box<dyn DataGenerator>>,
+        fk_columns: &HashMap<String, ForeignKeyInfo>,
+        expression_columns: &HashSet<String>,
+        count: usize,
+        progress: &dyn Progress,
+        inserted: &AtomicUsize,
+    ) -> Result<()> {
+        let mut remaining = count;
 
         while remaining > 0 {
             let batch_count = remaining.min(self.batch_size);
 
             // Generate batch
             let batch = self
-                .generate_batch_with_fk(&column_names, &generators, &fk_columns, batch_count)
+                .generate_batch_with_fk(column_names, generators, fk_columns, batch_count)
                 .await?;
 
             // Insert batch
-            self.insert_batch(&rule.name, &column_names, &batch).await?;
+            self.insert_batch(&rule.name, column_names, &batch, expression_columns)
+                .await?;
+
+            // Cache generated keys that dependent tables reference
+            if let Some(referenced) = self.referenced_columns.get(&rule.name) {
+                for column in referenced.iter().filter(|c| generators.contains_key(*c)) {
+                    let ids = batch.iter().filter_map(|row| row.get(column).cloned()).collect();
+                    self.fk_resolver.record_ids(&rule.name, column, ids).await;
+                }
+            }
 
-            total_inserted += batch_count;
             remaining -= batch_count;
-            pb.set_position(total_inserted as u64);
+            let total = inserted.fetch_add(batch_count, Ordering::Relaxed) + batch_count;
+            progress.update(total as u64, None);
         }
 
-        pb.finish_with_message(format!("Completed seeding table: {}", rule.name));
-
-        let duration = start.elapsed();
-        Ok(SeedStats::new(rule.name.clone(), total_inserted, duration))
+        Ok(())
     }
 
     /// Generate a batch of rows with FK support
@@ -221,18 +534,23 @@ impl SeedingEngine {
             for col_name in columns {
                 let value = if let Some(fk_info) = fk_columns.get(col_name) {
                     // Generate FK value
-                    self.fk_resolver
-                        .random_id(&fk_info.table, &fk_info.column, &mut self.rng)
-                        .await?
+                    Some(
+                        self.fk_resolver
+                            .random_id(&fk_info.table, &fk_info.column, &mut self.rng)
+                            .await?,
+                    )
                 } else {
                     // Generate regular value
                     let generator = generators
                         .get(col_name)
                         .ok_or_else(|| anyhow!("Generator not found for column: {}", col_name))?;
-                    generator.generate(&mut self.rng)?
+                    generator.generate_nullable(&mut self.rng)?
                 };
 
-                row.insert(col_name.clone(), value);
+                // Columns left out of the row are inserted as NULL
+                if let Some(value) = value {
+                    row.insert(col_name.clone(), value);
+                }
             }
 
             rows.push(row);
@@ -247,13 +565,24 @@ impl SeedingEngine {
         table: &str,
         columns: &[String],
         rows: &[Row],
+        expression_columns: &HashSet<String>,
     ) -> Result<()> {
         if rows.is_empty() {
             return Ok(());
         }
 
+        if let Some(dry_run) = &self.dry_run {
+            return dry_run.capture(self.db_type, table, columns, rows, expression_columns);
+        }
+
         // Build INSERT statement
-        let sql = build_batch_insert(self.db_type, table, columns, rows)?;
+        let sql = build_batch_insert_with_expressions(
+            self.db_type,
+            table,
+            columns,
+            rows,
+            expression_columns,
+        )?;
 
         // Execute SQL
         self.execute_sql(&sql).await?;
@@ -261,46 +590,71 @@ impl SeedingEngine {
         Ok(())
     }
 
-    /// Execute SQL via Docker exec
-    async fn execute_sql(&self, sql: &str) -> Result<()> {
-        let command = match self.db_type {
-            DatabaseType::Postgres => {
-                vec![
-                    "psql",
-                    "-U",
-                    "postgres",
-                    "-c",
-                    sql,
-                ]
+    /// Look up the declared type of each column in a table
+    async fn fetch_column_types(&self, table: &str) -> Result<HashMap<String, String>> {
+        let escaped = table.replace('\'', "''");
+        let query = match self.db_type {
+            DatabaseType::Postgres | DatabaseType::CockroachDB => format!(
+                "SELECT column_name || '|' || udt_name FROM information_schema.columns WHERE table_name = '{}'",
+                escaped
+            ),
+            DatabaseType::MySQL => format!(
+                "SELECT CONCAT(column_name, '|', data_type) FROM information_schema.columns WHERE table_schema = DATABASE() AND table_name = '{}'",
+                escaped
+            ),
+            DatabaseType::SQLServer => format!(
+                "SET NOCOUNT ON; SELECT column_name + '|' + data_type FROM information_schema.columns WHERE table_name = '{}'",
+                escaped
+            ),
+            DatabaseType::SQLite => format!(
+                "SELECT name || '|' || lower(type) FROM pragma_table_info('{}')",
+                escaped
+            ),
+            DatabaseType::Redis | DatabaseType::MongoDB => {
+                return Err(anyhow!("Seeding is not supported for NoSQL databases"));
             }
-            DatabaseType::MySQL => {
-                vec![
-                    "mysql",
-                    "-uroot",
-                    "-proot",
-                    "-e",
-                    sql,
-                ]
-            }
-            DatabaseType::SQLServer => {
-                vec![
-                    "/opt/mssql-tools18/bin/sqlcmd",
-                    "-S",
-                    "localhost",
-                    "-U",
-                    "sa",
-                    "-P",
-                    "YourStrong@Passw0rd",
-                    "-C",
-                    "-Q",
-                    sql,
-                ]
+        };
+
+        let mut command = self.conn.client_command()?;
+        let options: &[&str] = match self.db_type {
+            DatabaseType::Postgres => &["-t", "-A", "-c"],
+            // The tsv header line has no '|' and is skipped when parsing
+            DatabaseType::CockroachDB => &["--format=tsv", "-e"],
+            DatabaseType::MySQL => &["-N", "-B", "-e"],
+            DatabaseType::SQLServer => &["-h", "-1", "-W", "-Q"],
+            DatabaseType::SQLite => &[SQLITE_DB_PATH],
+            DatabaseType::Redis | DatabaseType::MongoDB => unreachable!("rejected above"),
+        };
+        command.extend(options.iter().map(|s| s.to_string()));
+        command.push(query);
+
+        let output = self
+            .collector
+            .exec_client(&self.container_id, &self.conn, &command)
+            .await?;
+
+        Ok(parse_column_types(&output))
+    }
+
+    /// Execute SQL through the database's client
+    async fn execute_sql(&self, sql: &str) -> Result<()> {
+        let mut command = self.conn.client_command()?;
+        let options: &[&str] = match self.db_type {
+            DatabaseType::Postgres => &["-c"],
+            DatabaseType::MySQL | DatabaseType::CockroachDB => &["-e"],
+            DatabaseType::SQLServer => &["-Q"],
+            // Parallel workers share one file; wait out each other's write locks
+            DatabaseType::SQLite => &["-bail", "-cmd", ".timeout 30000", SQLITE_DB_PATH],
+            DatabaseType::Redis | DatabaseType::MongoDB => {
+                return Err(anyhow!("Seeding is not supported for NoSQL databases"));
             }
         };
+        command.extend(options.iter().map(|s| s.to_string()));
+        command.push(sql.to_string());
 
         let output = self
             .collector
-            .exec_query(&self.container_id, command)
+            .exec_client(&self.container_id, &self.conn, &command)
             .await?;
 
         // Check for errors in output
@@ -312,9 +666,77 @@ impl SeedingEngine {
     }
 }
 
+/// Whether a `generator` column leaves its format to the target database
+fn has_auto_format(column: &ColumnRule, generator: &str) -> bool {
+    column.generator == generator
+        && matches!(
+            column.options.get("format").and_then(|v| v.as_str()),
+            None | Some("auto")
+        )
+}
+
+/// RNG seed for worker `index` of a parallel table seed
+fn worker_seed(seed: u64, index: usize) -> u64 {
+    seed.wrapping_add((index as u64 + 1).wrapping_mul(0x9E37_79B9_7F4A_7C15))
+}
+
+/// Split `count` rows into `workers` shares of whole batches (the last batch may be short)
+fn split_rows(count: usize, workers: usize, batch_size: usize) -> Vec<usize> {
+    let batch_size = batch_size.max(1);
+    let batches = count.div_ceil(batch_size);
+    (0..workers)
+        .map(|worker| {
+            let first = batches * worker / workers;
+            let last = batches * (worker + 1) / workers;
+            (last * batch_size).min(count) - (first * batch_size).min(count)
+        })
+        .collect()
+}
+
+/// Apply a column's `unique` option to its generator
+fn unique_generator(
+    rule: &SeedRule,
+    col_rule: &ColumnRule,
+    generator: Box<dyn DataGenerator>,
+) -> Result<Box<dyn DataGenerator>> {
+    let column = format!("{}.{}", rule.name, col_rule.name);
+    UniqueGenerator::from_options(generator, &column, &col_rule.options)
+}
+
+/// Parse `column|type` lines into a column type map
+fn parse_column_types(output: &str) -> HashMap<String, String> {
+    output
+        .lines()
+        .filter_map(|line| line.trim().split_once('|'))
+        .map(|(name, ty)| (name.trim().to_string(), ty.trim().to_string()))
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+
+    #[test]
+    fn test_split_rows() {
+        assert_eq!(split_rows(1000, 4, 100), vec![200, 300, 200, 300]);
+        assert_eq!(split_rows(1050, 2, 100), vec![500, 550]);
+        assert_eq!(split_rows(10, 3, 100), vec![0, 0, 10]);
+        assert_eq!(split_rows(1050, 3, 100).iter().sum::<usize>(), 1050);
+    }
+
+    #[test]
+    fn test_worker_seeds_are_distinct_and_stable() {
+        assert_eq!(worker_seed(42, 0), worker_seed(42, 0));
+        assert_ne!(worker_seed(42, 0), worker_seed(42, 1));
+        assert_ne!(worker_seed(42, 0), 42);
+    }
+
+    #[test]
+    fn test_parse_column_types() {
+        let types = parse_column_types("id|int4\nlocation|geometry\n\n");
+        assert_eq!(types.len(), 2);
+        assert_eq!(types.get("location").map(|s| s.as_str()), Some("geometry"));
+    }
     use crate::seed::generator::SequentialGenerator;
 
     #[tokio::test]
@@ -347,6 +769,56 @@ mod tests {
         assert_eq!(batch[2].get("id").unwrap(), "3");
     }
 
+    #[tokio::test]
+    async fn test_same_config_and_seed_produce_identical_sql() {
+        let config: crate::seed::SeedConfig = toml::from_str(
+            r#"
+            [[seed_rules.tables]]
+            name = "customers"
+            count = 50
+            columns = [
+                { name = "id", generator = "sequential", start = 1 },
+                { name = "email", generator = "email" },
+                { name = "tier", generator = "enum", values = ["free", "pro", "team"] },
+                { name = "credit", generator = "random_decimal", min = 0.0, max = 500.0 },
+                { name = "note", generator = "template", template = "cust-{random_int:1:99}" },
+                { name = "active", generator = "boolean" },
+            ]
+
+            [[seed_rules.tables]]
+            name = "products"
+            count = 20
+            columns = [
+                { name = "sku", generator = "sequential", start = 1000 },
+                { name = "price", generator = "random_int", min = 1, max = 999 },
+            ]
+            "#,
+        )
+        .unwrap();
+        let rules = config.seed_rules.tables();
+
+        async fn dry_run_sql(rules: &[SeedRule]) -> String {
+            let docker = Arc::new(Docker::connect_with_local_defaults().unwrap());
+            let mut engine =
+                SeedingEngine::new("test".to_string(), DatabaseType::Postgres, docker, 42, 7)
+                    .with_progress(false)
+                    .with_dry_run(usize::MAX);
+            engine.seed_all(rules).await.unwrap();
+            rules
+                .iter()
+                .flat_map(|rule| engine.dry_run_statements(&rule.name))
+                .collect::<Vec<_>>()
+                .join("\n")
+        }
+
+        let first = dry_run_sql(rules).await;
+        assert!(first.starts_with("INSERT INTO \"customers\" (\"id\", \"email\", \"tier\""));
+        assert_eq!(first.lines().count(), 70);
+        for _ in 0..3 {
+            assert_eq!(dry_run_sql(rules).await, first);
+        }
+    }
+
     #[tokio::test]
     async fn test_deterministic_generation() {
         use crate::seed::generator::RandomIntGenerator;
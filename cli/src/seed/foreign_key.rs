@@ -6,7 +6,7 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
-use crate::container::DatabaseType;
+use crate::container::{ConnParams, DatabaseType, SQLITE_DB_PATH};
 use crate::database_metrics::collector::DockerDatabaseMetricsCollector;
 
 /// Resolves foreign key values by querying and caching existing data
@@ -15,6 +15,7 @@ pub struct ForeignKeyResolver {
     collector: DockerDatabaseMetricsCollector,
     container_id: String,
     db_type: DatabaseType,
+    conn: ConnParams,
 }
 
 impl ForeignKeyResolver {
@@ -28,9 +29,16 @@ impl ForeignKeyResolver {
             collector: DockerDatabaseMetricsCollector::new(docker_client),
             container_id,
             db_type,
+            conn: ConnParams::from_env(db_type, HashMap::new()),
         }
     }
 
+    /// Connect with these parameters instead of the image defaults
+    pub fn with_connection(mut self, conn: ConnParams) -> Self {
+        self.conn = conn;
+        self
+    }
+
     /// Load all IDs from a referenced table and cache them
     pub async fn load_ids(&self, table: &str, column: &str) -> Result<()> {
         let key = format!("{}.{}", table, column);
@@ -60,6 +68,14 @@ impl ForeignKeyResolver {
         Ok(())
     }
 
+    /// Cache IDs that were generated while seeding a referenced table,
+    /// so dependent tables can draw from them without querying the database
+    pub async fn record_ids(&self, table: &str, column: &str, ids: Vec<String>) {
+        let key = format!("{}.{}", table, column);
+        let mut cache = self.cache.lock().await;
+        cache.entry(key).or_default().extend(ids);
+    }
+
     /// Get a random ID from the cache
     pub async fn random_id(
         &self,
@@ -84,42 +100,30 @@ impl ForeignKeyResolver {
 
     /// Fetch IDs from database
     async fn fetch_ids(&self, table: &str, column: &str) -> Result<Vec<String>> {
-        let query = self.build_select_query(table, column);
-
-        let command = match self.db_type {
-            DatabaseType::Postgres => {
-                vec!["psql", "-U", "postgres", "-t", "-A", "-c", &query]
-            }
-            DatabaseType::MySQL => {
-                vec!["mysql", "-uroot", "-proot", "-N", "-B", "-e", &query]
-            }
-            DatabaseType::SQLServer => {
-                vec![
-                    "/opt/mssql-tools18/bin/sqlcmd",
-                    "-S",
-                    "localhost",
-                    "-U",
-                    "sa",
-                    "-P",
-                    "YourStrong@Passw0rd",
-                    "-C",
-                    "-h",
-                    "-1",
-                    "-W",
-                    "-Q",
-                    &query,
-                ]
-            }
+        let query = self.build_select_query(table, column)?;
+
+        let mut command = self.conn.client_command()?;
+        let options: &[&str] = match self.db_type {
+            DatabaseType::Postgres => &["-t", "-A", "-c"],
+            DatabaseType::CockroachDB => &["--format=tsv", "-e"],
+            DatabaseType::MySQL => &["-N", "-B", "-e"],
+            DatabaseType::SQLServer => &["-h", "-1", "-W", "-Q"],
+            DatabaseType::SQLite => &[SQLITE_DB_PATH],
+            DatabaseType::Redis | DatabaseType::MongoDB => return Err(self.unsupported()),
         };
+        command.extend(options.iter().map(|s| s.to_string()));
+        command.push(query);
 
         let output = self
             .collector
-            .exec_query(&self.container_id, command)
+            .exec_client(&self.container_id, &self.conn, &command)
             .await?;
 
-        // Parse output into IDs
+        // Parse output into IDs; cockroach sql always prints a header row
+        let header_lines = usize::from(self.db_type == DatabaseType::CockroachDB);
         let ids: Vec<String> = output
             .lines()
+            .skip(header_lines)
             .filter(|line| !line.trim().is_empty())
             .map(|line| line.trim().to_string())
             .collect();
@@ -128,9 +132,9 @@ impl ForeignKeyResolver {
     }
 
     /// Build SELECT query for fetching IDs
-    fn build_select_query(&self, table: &str, column: &str) -> String {
-        match self.db_type {
-            DatabaseType::Postgres => {
+    fn build_select_query(&self, table: &str, column: &str) -> Result<String> {
+        let query = match self.db_type {
+            DatabaseType::Postgres | DatabaseType::CockroachDB | DatabaseType::SQLite => {
                 format!("SELECT \"{}\" FROM \"{}\"", column, table)
             }
             DatabaseType::MySQL => {
@@ -139,7 +143,25 @@ impl ForeignKeyResolver {
             DatabaseType::SQLServer => {
                 format!("SELECT [{}] FROM [{}]", column, table)
             }
-        }
+            DatabaseType::Redis | DatabaseType::MongoDB => return Err(self.unsupported()),
+        };
+        Ok(query)
+    }
+
+    /// Error for databases without tables to draw foreign key values from
+    fn unsupported(&self) -> anyhow::Error {
+        anyhow!(
+            "Foreign keys are not supported for NoSQL databases ({})",
+            self.db_type
+        )
+    }
+
+    /// Every ID of a table.column, loading them first if needed
+    pub async fn ids(&self, table: &str, column: &str) -> Result<Vec<String>> {
+        self.load_ids(table, column).await?;
+        let key = format!("{}.{}", table, column);
+        let cache = self.cache.lock().await;
+        Ok(cache.get(&key).cloned().unwrap_or_default())
     }
 
     /// Get the number of cached IDs for a table.column
@@ -183,6 +205,27 @@ mod tests {
         assert!(id == "1" || id == "2" || id == "3");
     }
 
+    #[tokio::test]
+    async fn test_record_ids() {
+        let docker = Arc::new(Docker::connect_with_local_defaults().unwrap());
+        let resolver = ForeignKeyResolver::new(
+            docker,
+            "test".to_string(),
+            DatabaseType::Postgres,
+        );
+
+        resolver
+            .record_ids("users", "id", vec!["1".to_string(), "2".to_string()])
+            .await;
+        resolver
+            .record_ids("users", "id", vec!["3".to_string()])
+            .await;
+        assert_eq!(resolver.cached_count("users", "id").await, 3);
+
+        // Already cached, so no database query is made
+        resolver.load_ids("users", "id").await.unwrap();
+    }
+
     #[tokio::test]
     async fn test_random_id_not_loaded() {
         let docker = Arc::new(Docker::connect_with_local_defaults().unwrap());
@@ -212,7 +255,7 @@ mod tests {
             DatabaseType::Postgres,
         );
         assert_eq!(
-            pg_resolver.build_select_query("users", "id"),
+            pg_resolver.build_select_query("users", "id").unwrap(),
             "SELECT \"id\" FROM \"users\""
         );
 
@@ -222,7 +265,7 @@ mod tests {
             DatabaseType::MySQL,
         );
         assert_eq!(
-            mysql_resolver.build_select_query("users", "id"),
+            mysql_resolver.build_select_query("users", "id").unwrap(),
             "SELECT `id` FROM `users`"
         );
 
@@ -232,8 +275,14 @@ mod tests {
             DatabaseType::SQLServer,
         );
         assert_eq!(
-            mssql_resolver.build_select_query("users", "id"),
+            mssql_resolver.build_select_query("users", "id").unwrap(),
             "SELECT [id] FROM [users]"
         );
+
+        let docker = Arc::new(Docker::connect_with_local_defaults().unwrap());
+        let redis_resolver =
+            ForeignKeyResolver::new(docker, "test".to_string(), DatabaseType::Redis);
+        let err = redis_resolver.build_select_query("users", "id").unwrap_err();
+        assert!(err.to_string().contains("not supported for NoSQL"));
     }
 }
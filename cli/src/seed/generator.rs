@@ -6,9 +6,10 @@ use fake::faker::phone_number::en::PhoneNumber;
 use fake::Fake;
 use rand::{Rng, SeedableRng};
 use rand_chacha::ChaCha8Rng;
-use std::collections::HashMap;
-use std::sync::atomic::{AtomicUsize, Ordering};
-use std::sync::Arc;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
 
 use crate::seed::foreign_key::ForeignKeyResolver;
 
@@ -20,12 +21,59 @@ pub enum DataType {
     String,
     Boolean,
     Timestamp,
+    Array,
+    Composite,
+    GeoPoint,
+    Json,
+    Binary,
 }
 
 /// Core trait for data generation
 pub trait DataGenerator: Send + Sync {
     fn generate(&self, rng: &mut ChaCha8Rng) -> Result<String>;
     fn data_type(&self) -> DataType;
+
+    /// Whether generated values are SQL expressions to insert verbatim
+    fn is_sql_expression(&self) -> bool {
+        false
+    }
+
+    /// Generate a value for a column, or `None` for SQL `NULL`
+    fn generate_nullable(&self, rng: &mut ChaCha8Rng) -> Result<Option<String>> {
+        self.generate(rng).map(Some)
+    }
+
+    /// Whether the generator never repeats a value, so `unique` needs no tracking
+    fn is_unique(&self) -> bool {
+        false
+    }
+
+    /// Confine a fresh generator to one worker's share of a parallel table seed
+    ///
+    /// Sequences start at the share's row offset and `unique` values are split
+    /// between workers, so workers never collide and each worker's values
+    /// depend only on its own RNG.
+    fn assign_share(&self, _share: WorkerShare) {}
+}
+
+/// One worker's part of a table seeded by several workers at once
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WorkerShare {
+    pub index: usize,
+    pub workers: usize,
+    /// Rows generated by the workers before this one
+    pub offset: usize,
+}
+
+impl WorkerShare {
+    /// Whether `value` belongs to this worker when unique values are split by hash
+    fn owns(&self, value: &str) -> bool {
+        // FNV-1a: stable across runs and Rust versions, unlike the std hasher
+        let hash = value.bytes().fold(0xcbf2_9ce4_8422_2325u64, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+        });
+        hash % self.workers as u64 == self.index as u64
+    }
 }
 
 /// Sequential integer generator (thread-safe)
@@ -60,6 +108,14 @@ impl DataGenerator for SequentialGenerator {
     fn data_type(&self) -> DataType {
         DataType::Integer
     }
+
+    fn is_unique(&self) -> bool {
+        true
+    }
+
+    fn assign_share(&self, share: WorkerShare) {
+        self.counter.store(self.start + share.offset, Ordering::SeqCst);
+    }
 }
 
 /// Random integer generator
@@ -437,6 +493,84 @@ impl DataGenerator for TemplateGenerator {
     }
 }
 
+/// UUID version produced by `UuidGenerator`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UuidVersion {
+    /// Fully random
+    V4,
+    /// Millisecond timestamp prefix, so keys sort in insertion order
+    V7,
+}
+
+/// UUID generator drawing its random bits from the seeded RNG
+///
+/// v7 timestamps start at `start` (default 2024-01-01) and advance one
+/// millisecond per value rather than following the clock, so seeded runs
+/// reproduce the same keys.
+pub struct UuidGenerator {
+    version: UuidVersion,
+    start_millis: u64,
+    counter: Arc<AtomicU64>,
+}
+
+impl UuidGenerator {
+    pub fn new(version: UuidVersion, start: DateTime<Utc>) -> Self {
+        Self {
+            version,
+            start_millis: start.timestamp_millis().max(0) as u64,
+            counter: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    pub fn from_options(options: &HashMap<String, toml::Value>) -> Result<Self> {
+        let version = match options.get("version").and_then(|v| v.as_str()).unwrap_or("v4") {
+            "v4" => UuidVersion::V4,
+            "v7" => UuidVersion::V7,
+            other => return Err(anyhow!("Unknown UUID version: {} (use v4 or v7)", other)),
+        };
+        let start = parse_date(
+            options
+                .get("start")
+                .and_then(|v| v.as_str())
+                .unwrap_or("2024-01-01"),
+        )?;
+        Ok(Self::new(version, start))
+    }
+}
+
+impl DataGenerator for UuidGenerator {
+    fn generate(&self, rng: &mut ChaCha8Rng) -> Result<String> {
+        let mut bytes: [u8; 16] = rng.gen();
+
+        if self.version == UuidVersion::V7 {
+            let millis = self.start_millis + self.counter.fetch_add(1, Ordering::SeqCst);
+            bytes[..6].copy_from_slice(&millis.to_be_bytes()[2..]);
+        }
+        let version = match self.version {
+            UuidVersion::V4 => 0x40,
+            UuidVersion::V7 => 0x70,
+        };
+        bytes[6] = (bytes[6] & 0x0f) | version;
+        // RFC 4122 variant
+        bytes[8] = (bytes[8] & 0x3f) | 0x80;
+
+        Ok(uuid::Uuid::from_bytes(bytes).to_string())
+    }
+
+    fn data_type(&self) -> DataType {
+        DataType::String
+    }
+
+    /// v7 values carry a counter; v4 collisions are astronomically unlikely
+    fn is_unique(&self) -> bool {
+        true
+    }
+
+    fn assign_share(&self, share: WorkerShare) {
+        self.counter.store(share.offset as u64, Ordering::SeqCst);
+    }
+}
+
 /// Enum generator for selecting from predefined values
 pub struct EnumGenerator {
     values: Vec<String>,
@@ -475,6 +609,540 @@ impl DataGenerator for EnumGenerator {
     }
 }
 
+/// Build a nested generator from a `{ generator = "...", options = { ... } }` table
+fn nested_generator(value: &toml::Value, context: &str) -> Result<Box<dyn DataGenerator>> {
+    let table = value
+        .as_table()
+        .ok_or_else(|| anyhow!("{} must be a table with a generator", context))?;
+    let generator = table
+        .get("generator")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| anyhow!("generator required in {}", context))?;
+    let options: HashMap<String, toml::Value> = table
+        .get("options")
+        .and_then(|v| v.as_table())
+        .map(|t| t.iter().map(|(k, v)| (k.clone(), v.clone())).collect())
+        .unwrap_or_default();
+
+    create_generator(generator, &options)
+}
+
+/// Postgres array generator producing `{v1,v2,...}` literals
+pub struct ArrayGenerator {
+    element: Box<dyn DataGenerator>,
+    min_length: usize,
+    max_length: usize,
+}
+
+impl ArrayGenerator {
+    pub fn new(element: Box<dyn DataGenerator>, min_length: usize, max_length: usize) -> Self {
+        Self {
+            element,
+            min_length,
+            max_length,
+        }
+    }
+
+    pub fn from_options(options: &HashMap<String, toml::Value>) -> Result<Self> {
+        let element = nested_generator(
+            options
+                .get("element")
+                .ok_or_else(|| anyhow!("element required for array generator"))?,
+            "array element",
+        )?;
+
+        let length = |key: &str, default: usize| match options.get(key) {
+            None => Ok(default),
+            Some(value) => value
+                .as_integer()
+                .and_then(|n| usize::try_from(n).ok())
+                .ok_or_else(|| anyhow!("array {} must be a non-negative integer", key)),
+        };
+        let min_length = length("min_length", 1)?;
+        let max_length = length("max_length", 5)?;
+
+        if min_length > max_length {
+            return Err(anyhow!("min_length cannot be greater than max_length"));
+        }
+
+        Ok(Self::new(element, min_length, max_length))
+    }
+}
+
+impl DataGenerator for ArrayGenerator {
+    fn generate(&self, rng: &mut ChaCha8Rng) -> Result<String> {
+        let len = rng.gen_range(self.min_length..=self.max_length);
+        let elements = (0..len)
+            .map(|_| {
+                self.element
+                    .generate(rng)
+                    .map(|v| quote_array_element(&v))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(format!("{{{}}}", elements.join(",")))
+    }
+
+    fn data_type(&self) -> DataType {
+        DataType::Array
+    }
+}
+
+/// Quote an element for Postgres array literal syntax
+fn quote_array_element(value: &str) -> String {
+    let needs_quotes = value.is_empty()
+        || value.eq_ignore_ascii_case("null")
+        || value
+            .chars()
+            .any(|c| matches!(c, '{' | '}' | ',' | '"' | '\\') || c.is_whitespace());
+
+    if needs_quotes {
+        format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Postgres composite type generator producing `(f1,f2,...)` row literals
+pub struct CompositeGenerator {
+    fields: Vec<Box<dyn DataGenerator>>,
+}
+
+impl CompositeGenerator {
+    pub fn new(fields: Vec<Box<dyn DataGenerator>>) -> Self {
+        Self { fields }
+    }
+
+    pub fn from_options(options: &HashMap<String, toml::Value>) -> Result<Self> {
+        let fields = options
+            .get("fields")
+            .and_then(|v| v.as_array())
+            .ok_or_else(|| anyhow!("fields array required for composite generator"))?
+            .iter()
+            .map(|f| nested_generator(f, "composite field"))
+            .collect::<Result<Vec<_>>>()?;
+
+        if fields.is_empty() {
+            return Err(anyhow!("composite fields cannot be empty"));
+        }
+
+        Ok(Self::new(fields))
+    }
+}
+
+impl DataGenerator for CompositeGenerator {
+    fn generate(&self, rng: &mut ChaCha8Rng) -> Result<String> {
+        let fields = self
+            .fields
+            .iter()
+            .map(|f| f.generate(rng).map(|v| quote_composite_field(&v)))
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(format!("({})", fields.join(",")))
+    }
+
+    fn data_type(&self) -> DataType {
+        DataType::Composite
+    }
+}
+
+/// Quote a field for Postgres composite (row) literal syntax
+fn quote_composite_field(value: &str) -> String {
+    let needs_quotes = value.is_empty()
+        || value
+            .chars()
+            .any(|c| matches!(c, '(' | ')' | ',' | '"' | '\\') || c.is_whitespace());
+
+    if needs_quotes {
+        format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// JSON object generator assembling fields from other generators
+///
+/// Fields are generated in name order, so output is deterministic under the
+/// global seed. Numbers and booleans stay JSON numbers and booleans, and
+/// nested tables without a `generator` key become nested objects.
+pub struct JsonGenerator {
+    fields: Vec<(String, Box<dyn DataGenerator>)>,
+}
+
+impl JsonGenerator {
+    pub fn new(fields: Vec<(String, Box<dyn DataGenerator>)>) -> Self {
+        Self { fields }
+    }
+
+    pub fn from_options(options: &HashMap<String, toml::Value>) -> Result<Self> {
+        let fields = options
+            .get("fields")
+            .and_then(|v| v.as_table())
+            .ok_or_else(|| anyhow!("fields table required for json generator"))?;
+        Self::from_fields(fields)
+    }
+
+    fn from_fields(fields: &toml::Table) -> Result<Self> {
+        if fields.is_empty() {
+            return Err(anyhow!("json fields cannot be empty"));
+        }
+
+        let mut generators = fields
+            .iter()
+            .map(|(name, spec)| {
+                let context = format!("json field '{}'", name);
+                let generator: Box<dyn DataGenerator> = match spec {
+                    toml::Value::String(spec) => shorthand_generator(spec)?,
+                    toml::Value::Table(table) if !table.contains_key("generator") => {
+                        Box::new(Self::from_fields(table)?)
+                    }
+                    other => nested_generator(other, &context)?,
+                };
+                Ok((name.clone(), generator))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        generators.sort_by(|a, b| a.0.cmp(&b.0));
+
+        Ok(Self::new(generators))
+    }
+}
+
+impl DataGenerator for JsonGenerator {
+    fn generate(&self, rng: &mut ChaCha8Rng) -> Result<String> {
+        let mut object = serde_json::Map::new();
+        for (name, generator) in &self.fields {
+            let value = match generator.generate_nullable(rng)? {
+                Some(value) => json_value(generator.data_type(), value),
+                None => serde_json::Value::Null,
+            };
+            object.insert(name.clone(), value);
+        }
+
+        Ok(serde_json::Value::Object(object).to_string())
+    }
+
+    fn data_type(&self) -> DataType {
+        DataType::Json
+    }
+}
+
+/// Convert a generated value to JSON according to its generator's type
+fn json_value(data_type: DataType, value: String) -> serde_json::Value {
+    let typed = match data_type {
+        DataType::Integer => value.parse::<i64>().ok().map(serde_json::Value::from),
+        DataType::Decimal => value
+            .parse::<f64>()
+            .ok()
+            .and_then(serde_json::Number::from_f64)
+            .map(serde_json::Value::Number),
+        DataType::Boolean => value.parse::<bool>().ok().map(serde_json::Value::Bool),
+        DataType::Json => serde_json::from_str(&value).ok(),
+        _ => None,
+    };
+    typed.unwrap_or(serde_json::Value::String(value))
+}
+
+/// Build a generator from `"name"` or `"name:arg:..."` shorthand
+///
+/// Arguments are `min:max` for `random_int`/`random_decimal`, the true
+/// probability for `boolean`, the values for `enum` and the type for `name`.
+fn shorthand_generator(spec: &str) -> Result<Box<dyn DataGenerator>> {
+    let mut parts = spec.split(':');
+    let generator = parts.next().unwrap_or_default();
+    let args: Vec<&str> = parts.collect();
+
+    let parse_int = |s: &str| {
+        s.parse::<i64>()
+            .map(toml::Value::Integer)
+            .map_err(|_| anyhow!("Invalid integer '{}' in '{}'", s, spec))
+    };
+    let parse_float = |s: &str| {
+        s.parse::<f64>()
+            .map(toml::Value::Float)
+            .map_err(|_| anyhow!("Invalid number '{}' in '{}'", s, spec))
+    };
+
+    let mut options = HashMap::new();
+    match (generator, args.as_slice()) {
+        (_, []) => {}
+        ("random_int", [min, max]) => {
+            options.insert("min".to_string(), parse_int(min)?);
+            options.insert("max".to_string(), parse_int(max)?);
+        }
+        ("random_decimal", [min, max]) => {
+            options.insert("min".to_string(), parse_float(min)?);
+            options.insert("max".to_string(), parse_float(max)?);
+        }
+        ("boolean", [probability]) => {
+            options.insert("true_probability".to_string(), parse_float(probability)?);
+        }
+        ("name", [name_type]) => {
+            options.insert("type".to_string(), toml::Value::String(name_type.to_string()));
+        }
+        ("enum", values) => {
+            let values = values
+                .iter()
+                .map(|v| toml::Value::String(v.to_string()))
+                .collect();
+            options.insert("values".to_string(), toml::Value::Array(values));
+        }
+        _ => {
+            return Err(anyhow!(
+                "Unsupported arguments in '{}'; use a {{ generator, options }} table",
+                spec
+            ))
+        }
+    }
+
+    create_generator(generator, &options)
+}
+
+/// Output representation for geographic points
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GeoFormat {
+    /// `(lat,lng)` pair
+    Pair,
+    /// WKT `POINT(lng lat)`
+    Wkt,
+    /// GeoJSON point object
+    GeoJson,
+    /// PostGIS `ST_SetSRID(ST_MakePoint(lng,lat),4326)` expression
+    PostGis,
+    /// MySQL `ST_GeomFromText('POINT(lng lat)')` expression
+    MySql,
+}
+
+impl GeoFormat {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "pair" => Some(GeoFormat::Pair),
+            "wkt" => Some(GeoFormat::Wkt),
+            "geojson" => Some(GeoFormat::GeoJson),
+            "postgis" => Some(GeoFormat::PostGis),
+            "mysql" => Some(GeoFormat::MySql),
+            _ => None,
+        }
+    }
+
+    /// Choose a representation from the target column's database type
+    pub fn for_column_type(db_type: crate::container::DatabaseType, column_type: &str) -> Self {
+        use crate::container::DatabaseType;
+
+        // CockroachDB implements the PostGIS spatial functions
+        let postgis = matches!(db_type, DatabaseType::Postgres | DatabaseType::CockroachDB);
+        match column_type.to_lowercase().as_str() {
+            "geometry" | "geography" if postgis => GeoFormat::PostGis,
+            // MySQL spatial columns only accept geometry values, not text
+            "point" | "geometry" if db_type == DatabaseType::MySQL => GeoFormat::MySql,
+            "point" => GeoFormat::Pair,
+            "json" | "jsonb" => GeoFormat::GeoJson,
+            _ => GeoFormat::Wkt,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GeoFormat::Pair => "pair",
+            GeoFormat::Wkt => "wkt",
+            GeoFormat::GeoJson => "geojson",
+            GeoFormat::PostGis => "postgis",
+            GeoFormat::MySql => "mysql",
+        }
+    }
+}
+
+/// Geographic point generator within a bounding box
+pub struct GeoPointGenerator {
+    min_lat: f64,
+    max_lat: f64,
+    min_lng: f64,
+    max_lng: f64,
+    precision: usize,
+    format: GeoFormat,
+}
+
+impl GeoPointGenerator {
+    pub fn new(min_lat: f64, max_lat: f64, min_lng: f64, max_lng: f64, format: GeoFormat) -> Self {
+        Self {
+            min_lat,
+            max_lat,
+            min_lng,
+            max_lng,
+            precision: 6,
+            format,
+        }
+    }
+
+    pub fn from_options(options: &HashMap<String, toml::Value>) -> Result<Self> {
+        let get_f64 = |key: &str, default: f64| {
+            options
+                .get(key)
+                .and_then(|v| v.as_float().or_else(|| v.as_integer().map(|i| i as f64)))
+                .unwrap_or(default)
+        };
+
+        let min_lat = get_f64("min_lat", -90.0);
+        let max_lat = get_f64("max_lat", 90.0);
+        let min_lng = get_f64("min_lng", -180.0);
+        let max_lng = get_f64("max_lng", 180.0);
+
+        if min_lat > max_lat || min_lng > max_lng {
+            return Err(anyhow!("geo_point bounding box minimums cannot exceed maximums"));
+        }
+        if min_lat < -90.0 || max_lat > 90.0 || min_lng < -180.0 || max_lng > 180.0 {
+            return Err(anyhow!(
+                "geo_point bounding box must be within latitude -90..90 and longitude -180..180"
+            ));
+        }
+
+        // "auto" is resolved by the seeding engine from the column type
+        let format = match options.get("format").and_then(|v| v.as_str()) {
+            None | Some("auto") => GeoFormat::Wkt,
+            Some(f) => GeoFormat::parse(f).ok_or_else(|| {
+                anyhow!(
+                    "Invalid geo_point format: {} (use auto, pair, wkt, geojson, postgis, or mysql)",
+                    f
+                )
+            })?,
+        };
+
+        let precision = options
+            .get("precision")
+            .and_then(|v| v.as_integer())
+            .unwrap_or(6) as usize;
+
+        Ok(Self {
+            precision,
+            ..Self::new(min_lat, max_lat, min_lng, max_lng, format)
+        })
+    }
+}
+
+impl DataGenerator for GeoPointGenerator {
+    fn generate(&self, rng: &mut ChaCha8Rng) -> Result<String> {
+        let lat = rng.gen_range(self.min_lat..=self.max_lat);
+        let lng = rng.gen_range(self.min_lng..=self.max_lng);
+        let p = self.precision;
+
+        Ok(match self.format {
+            GeoFormat::Pair => format!("({:.p$},{:.p$})", lat, lng),
+            GeoFormat::Wkt => format!("POINT({:.p$} {:.p$})", lng, lat),
+            GeoFormat::GeoJson => format!(
+                "{{\"type\":\"Point\",\"coordinates\":[{:.p$},{:.p$}]}}",
+                lng, lat
+            ),
+            GeoFormat::PostGis => {
+                format!("ST_SetSRID(ST_MakePoint({:.p$},{:.p$}),4326)", lng, lat)
+            }
+            GeoFormat::MySql => format!("ST_GeomFromText('POINT({:.p$} {:.p$})')", lng, lat),
+        })
+    }
+
+    fn data_type(&self) -> DataType {
+        DataType::GeoPoint
+    }
+
+    fn is_sql_expression(&self) -> bool {
+        matches!(self.format, GeoFormat::PostGis | GeoFormat::MySql)
+    }
+}
+
+/// How binary values are written as SQL literals
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BinaryFormat {
+    /// PostgreSQL/CockroachDB `'\x0a1b'::bytea`
+    Bytea,
+    /// SQL Server `0x0a1b`
+    ZeroX,
+    /// MySQL/SQLite `X'0a1b'`
+    HexString,
+}
+
+impl BinaryFormat {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "bytea" => Some(BinaryFormat::Bytea),
+            "0x" => Some(BinaryFormat::ZeroX),
+            "x" => Some(BinaryFormat::HexString),
+            _ => None,
+        }
+    }
+
+    /// The literal form `db_type` reads into its binary columns
+    pub fn for_database(db_type: crate::container::DatabaseType) -> Self {
+        use crate::container::DatabaseType;
+
+        match db_type {
+            DatabaseType::Postgres | DatabaseType::CockroachDB => BinaryFormat::Bytea,
+            DatabaseType::SQLServer => BinaryFormat::ZeroX,
+            _ => BinaryFormat::HexString,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BinaryFormat::Bytea => "bytea",
+            BinaryFormat::ZeroX => "0x",
+            BinaryFormat::HexString => "x",
+        }
+    }
+}
+
+/// Random binary values of a fixed length, for `bytea`, `BLOB` and `varbinary` columns
+pub struct BytesGenerator {
+    length: usize,
+    format: BinaryFormat,
+}
+
+impl BytesGenerator {
+    pub fn new(length: usize, format: BinaryFormat) -> Self {
+        Self { length, format }
+    }
+
+    pub fn from_options(options: &HashMap<String, toml::Value>) -> Result<Self> {
+        let length = match options.get("length") {
+            None => 16,
+            Some(value) => value
+                .as_integer()
+                .filter(|n| *n >= 0)
+                .ok_or_else(|| anyhow!("bytes length must be a non-negative integer"))?
+                as usize,
+        };
+
+        // "auto" is resolved by the seeding engine from the database type
+        let format = match options.get("format").and_then(|v| v.as_str()) {
+            None | Some("auto") => BinaryFormat::HexString,
+            Some(f) => BinaryFormat::parse(f).ok_or_else(|| {
+                anyhow!("Invalid bytes format: {} (use auto, bytea, 0x, or x)", f)
+            })?,
+        };
+
+        Ok(Self::new(length, format))
+    }
+}
+
+impl DataGenerator for BytesGenerator {
+    fn generate(&self, rng: &mut ChaCha8Rng) -> Result<String> {
+        let hex: String = (0..self.length)
+            .map(|_| format!("{:02x}", rng.gen::<u8>()))
+            .collect();
+
+        Ok(match self.format {
+            BinaryFormat::Bytea => format!("'\\x{}'::bytea", hex),
+            BinaryFormat::ZeroX => format!("0x{}", hex),
+            BinaryFormat::HexString => format!("X'{}'", hex),
+        })
+    }
+
+    fn data_type(&self) -> DataType {
+        DataType::Binary
+    }
+
+    fn is_sql_expression(&self) -> bool {
+        true
+    }
+}
+
 /// Foreign key generator using ForeignKeyResolver
 pub struct ForeignKeyGenerator {
     resolver: Arc<ForeignKeyResolver>,
@@ -529,10 +1197,544 @@ impl DataGenerator for ForeignKeyGenerator {
     }
 }
 
+/// How a `csv` generator walks the rows of its file
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CsvMode {
+    Random,
+    Sequential,
+}
+
+/// Values of one CSV column, keyed by (path, column)
+type CsvColumns = HashMap<(PathBuf, String), Arc<Vec<String>>>;
+
+/// Columns read by `csv` generators and the row cursors of named sources
+///
+/// One instance is shared by every table in a seed run, so each file column is
+/// read from disk once.
+#[derive(Default)]
+pub struct CsvSources {
+    columns: Arc<Mutex<CsvColumns>>,
+    /// (table, source) -> cursor shared by that source's columns
+    cursors: Mutex<HashMap<(String, String), Arc<RowCursor>>>,
+}
+
+impl CsvSources {
+    /// Sources for one worker of a parallel table seed: the files already read
+    /// are shared, but named sources get cursors of their own
+    pub fn for_worker(&self) -> Self {
+        Self {
+            columns: self.columns.clone(),
+            cursors: Mutex::default(),
+        }
+    }
+
+    /// Values of a CSV column, reading the file the first time it's asked for
+    pub fn column(&self, path: &Path, column: &str) -> Result<Arc<Vec<String>>> {
+        let key = (path.to_path_buf(), column.to_string());
+        let mut columns = self.columns.lock().unwrap();
+        if let Some(values) = columns.get(&key) {
+            return Ok(values.clone());
+        }
+
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| anyhow!("failed to read CSV file {}: {}", path.display(), e))?;
+        let values = Arc::new(
+            csv_column(&content, column).map_err(|e| anyhow!("{}: {}", path.display(), e))?,
+        );
+        columns.insert(key, values.clone());
+        Ok(values)
+    }
+
+    /// The cursor of a named source in a table, created by its first column
+    fn cursor(
+        &self,
+        table: &str,
+        source: &str,
+        path: &Path,
+        rows: usize,
+        mode: CsvMode,
+        looped: bool,
+    ) -> Result<Arc<RowCursor>> {
+        let mut cursors = self.cursors.lock().unwrap();
+        let cursor = cursors
+            .entry((table.to_string(), source.to_string()))
+            .or_insert_with(|| Arc::new(RowCursor::new(path, rows, mode, looped)));
+
+        if cursor.path != path || cursor.mode != mode || cursor.looped != looped {
+            return Err(anyhow!(
+                "csv source '{}' in table {} must use the same path, mode and loop on every column",
+                source,
+                table
+            ));
+        }
+        cursor.state.lock().unwrap().members += 1;
+        Ok(cursor.clone())
+    }
+}
+
+/// Picks the row each draw of a `csv` generator reads
+///
+/// Columns sharing a source share one cursor: the first column to make its
+/// n-th draw picks the row and the others read the same row for theirs.
+struct RowCursor {
+    path: PathBuf,
+    rows: usize,
+    mode: CsvMode,
+    looped: bool,
+    state: Mutex<CursorState>,
+}
+
+#[derive(Default)]
+struct CursorState {
+    /// Columns sharing this cursor through a source (0 for a lone column)
+    members: usize,
+    /// Next row in sequential mode
+    next: usize,
+    /// Rows not yet used in random mode without `loop`
+    unused: Option<Vec<usize>>,
+    /// Draw number -> (row, columns still to read it)
+    pending: HashMap<usize, (usize, usize)>,
+    /// Worker whose rows this cursor picks from, when seeding in parallel
+    share: Option<WorkerShare>,
+}
+
+impl RowCursor {
+    fn new(path: &Path, rows: usize, mode: CsvMode, looped: bool) -> Self {
+        Self {
+            path: path.to_path_buf(),
+            rows,
+            mode,
+            looped,
+            state: Mutex::new(CursorState::default()),
+        }
+    }
+
+    /// Start sequential reads at the share's offset and keep random reads
+    /// without `loop` to the rows no other worker uses
+    fn assign_share(&self, share: WorkerShare) {
+        let mut state = self.state.lock().unwrap();
+        // Every column of a source passes the same share; apply it once
+        if state.share.is_none() {
+            state.share = Some(share);
+            state.next = share.offset;
+        }
+    }
+
+    /// Row for a column's `draw`-th value
+    fn row(&self, draw: usize, rng: &mut ChaCha8Rng) -> Result<usize> {
+        let mut state = self.state.lock().unwrap();
+        if let Some((row, readers)) = state.pending.get_mut(&draw) {
+            let row = *row;
+            *readers -= 1;
+            if *readers == 0 {
+                state.pending.remove(&draw);
+            }
+            return Ok(row);
+        }
+
+        let row = self.next_row(&mut state, rng)?;
+        if state.members > 1 {
+            let readers = state.members - 1;
+            state.pending.insert(draw, (row, readers));
+        }
+        Ok(row)
+    }
+
+    fn next_row(&self, state: &mut CursorState, rng: &mut ChaCha8Rng) -> Result<usize> {
+        let exhausted = || {
+            anyhow!(
+                "CSV file {} ran out of rows after {}; set loop = true to reuse them",
+                self.path.display(),
+                self.rows
+            )
+        };
+        match (self.mode, self.looped) {
+            (CsvMode::Random, true) => Ok(rng.gen_range(0..self.rows)),
+            (CsvMode::Random, false) => {
+                // Each row at most once, in random order
+                let share = state.share;
+                let unused = state.unused.get_or_insert_with(|| {
+                    (0..self.rows)
+                        .filter(|row| share.is_none_or(|s| row % s.workers == s.index))
+                        .collect()
+                });
+                if unused.is_empty() {
+                    return Err(exhausted());
+                }
+                let index = rng.gen_range(0..unused.len());
+                Ok(unused.swap_remove(index))
+            }
+            (CsvMode::Sequential, looped) => {
+                if state.next >= self.rows && !looped {
+                    return Err(exhausted());
+                }
+                let row = state.next % self.rows;
+                state.next += 1;
+                Ok(row)
+            }
+        }
+    }
+}
+
+/// Values from one column of a local CSV file
+///
+/// Rows are picked at random (seeded) or in file order; `loop = false` stops
+/// with an error instead of reusing rows. Columns with the same `source` in a
+/// table read from the same row, e.g. a city and its country.
+pub struct CsvGenerator {
+    values: Arc<Vec<String>>,
+    cursor: Arc<RowCursor>,
+    /// Draws made by this column, to line up with the rest of its source
+    draws: AtomicUsize,
+}
+
+impl CsvGenerator {
+    pub fn from_options(
+        options: &HashMap<String, toml::Value>,
+        sources: &CsvSources,
+        table: &str,
+    ) -> Result<Self> {
+        let path = options
+            .get("path")
+            .and_then(|v| v.as_str())
+            .map(PathBuf::from)
+            .ok_or_else(|| anyhow!("path required for csv generator"))?;
+        let column = options
+            .get("column")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| anyhow!("column required for csv generator"))?;
+        let mode = match options.get("mode").and_then(|v| v.as_str()).unwrap_or("random") {
+            "random" => CsvMode::Random,
+            "sequential" => CsvMode::Sequential,
+            other => {
+                return Err(anyhow!(
+                    "Invalid csv mode: {} (use random or sequential)",
+                    other
+                ))
+            }
+        };
+        let looped = match options.get("loop") {
+            None => true,
+            Some(value) => value
+                .as_bool()
+                .ok_or_else(|| anyhow!("loop must be true or false"))?,
+        };
+
+        let values = sources.column(&path, column)?;
+        if values.is_empty() {
+            return Err(anyhow!("CSV file {} has no rows", path.display()));
+        }
+
+        let cursor = match options.get("source") {
+            None => Arc::new(RowCursor::new(&path, values.len(), mode, looped)),
+            Some(source) => {
+                let source = source
+                    .as_str()
+                    .ok_or_else(|| anyhow!("csv source must be a string"))?;
+                // Skipped or repeated draws would put the column on another row
+                if options.contains_key("null_probability") || options.contains_key("unique") {
+                    return Err(anyhow!(
+                        "csv source '{}' can't be combined with null_probability or unique",
+                        source
+                    ));
+                }
+                sources.cursor(table, source, &path, values.len(), mode, looped)?
+            }
+        };
+
+        Ok(Self {
+            values,
+            cursor,
+            draws: AtomicUsize::new(0),
+        })
+    }
+}
+
+impl DataGenerator for CsvGenerator {
+    fn generate(&self, rng: &mut ChaCha8Rng) -> Result<String> {
+        let draw = self.draws.fetch_add(1, Ordering::SeqCst);
+        let row = self.cursor.row(draw, rng)?;
+        Ok(self.values[row].clone())
+    }
+
+    fn data_type(&self) -> DataType {
+        DataType::String
+    }
+
+    fn assign_share(&self, share: WorkerShare) {
+        self.cursor.assign_share(share);
+    }
+}
+
+/// Parse CSV content and return the values of the column with the given header
+///
+/// Fields may be quoted with `"`; quoted fields can hold commas, newlines and
+/// doubled `""` quotes.
+fn csv_column(content: &str, column: &str) -> Result<Vec<String>> {
+    let mut records = parse_csv(content).into_iter();
+    let header = records.next().ok_or_else(|| anyhow!("CSV file is empty"))?;
+    let index = header
+        .iter()
+        .position(|name| name.trim() == column)
+        .ok_or_else(|| {
+            anyhow!(
+                "CSV file has no column '{}' (columns: {})",
+                column,
+                header.join(", ")
+            )
+        })?;
+
+    records
+        .enumerate()
+        .map(|(i, record)| {
+            record.get(index).cloned().ok_or_else(|| {
+                // Records are counted from the line after the header
+                anyhow!("CSV record {} has no value for column '{}'", i + 2, column)
+            })
+        })
+        .collect()
+}
+
+fn parse_csv(content: &str) -> Vec<Vec<String>> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = content.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => in_quotes = !in_quotes,
+            ',' if !in_quotes => record.push(std::mem::take(&mut field)),
+            '\r' if !in_quotes => {}
+            '\n' if !in_quotes => {
+                record.push(std::mem::take(&mut field));
+                records.push(std::mem::take(&mut record));
+            }
+            c => field.push(c),
+        }
+    }
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push(record);
+    }
+
+    // Blank lines hold no record
+    records.retain(|record| !(record.len() == 1 && record[0].is_empty()));
+    records
+}
+
+/// Wraps a generator so a fraction of its values are SQL `NULL`
+///
+/// The null decision is drawn from the same RNG as the value, so a fixed
+/// seed reproduces the same null placement.
+pub struct NullableGenerator {
+    inner: Box<dyn DataGenerator>,
+    null_probability: f64,
+}
+
+impl NullableGenerator {
+    pub fn new(inner: Box<dyn DataGenerator>, null_probability: f64) -> Self {
+        Self {
+            inner,
+            null_probability,
+        }
+    }
+}
+
+impl DataGenerator for NullableGenerator {
+    /// Always a value; nulls only come from `generate_nullable`
+    fn generate(&self, rng: &mut ChaCha8Rng) -> Result<String> {
+        self.inner.generate(rng)
+    }
+
+    fn data_type(&self) -> DataType {
+        self.inner.data_type()
+    }
+
+    fn is_sql_expression(&self) -> bool {
+        self.inner.is_sql_expression()
+    }
+
+    fn generate_nullable(&self, rng: &mut ChaCha8Rng) -> Result<Option<String>> {
+        if rng.gen::<f64>() < self.null_probability {
+            return Ok(None);
+        }
+        self.inner.generate(rng).map(Some)
+    }
+
+    fn is_unique(&self) -> bool {
+        self.inner.is_unique()
+    }
+
+    fn assign_share(&self, share: WorkerShare) {
+        self.inner.assign_share(share);
+    }
+}
+
+/// Default number of draws for a `unique` column before giving up on a value
+pub const DEFAULT_UNIQUE_MAX_ATTEMPTS: usize = 1000;
+
+/// Wraps a generator to never emit the same value twice
+///
+/// Every value handed out is remembered; a repeat is redrawn up to
+/// `max_attempts` times before the column's keyspace is considered exhausted.
+/// `NULL`s are not tracked since they never conflict with a unique constraint.
+pub struct UniqueGenerator {
+    inner: Box<dyn DataGenerator>,
+    column: String,
+    max_attempts: usize,
+    seen: Mutex<HashSet<String>>,
+    /// Set when seeding in parallel; values of other workers are redrawn
+    share: OnceLock<WorkerShare>,
+}
+
+impl UniqueGenerator {
+    pub fn new(inner: Box<dyn DataGenerator>, column: String, max_attempts: usize) -> Self {
+        Self {
+            inner,
+            column,
+            max_attempts,
+            seen: Mutex::new(HashSet::new()),
+            share: OnceLock::new(),
+        }
+    }
+
+    /// Wrap `inner` when the column sets `unique = true`
+    ///
+    /// `unique_max_attempts` overrides how often a repeated value is redrawn.
+    /// Generators that never repeat are returned as-is.
+    pub fn from_options(
+        inner: Box<dyn DataGenerator>,
+        column: &str,
+        options: &HashMap<String, toml::Value>,
+    ) -> Result<Box<dyn DataGenerator>> {
+        let unique = match options.get("unique") {
+            None => false,
+            Some(value) => value
+                .as_bool()
+                .ok_or_else(|| anyhow!("unique must be true or false"))?,
+        };
+        let max_attempts = match options.get("unique_max_attempts") {
+            None => DEFAULT_UNIQUE_MAX_ATTEMPTS,
+            Some(value) => value
+                .as_integer()
+                .and_then(|n| usize::try_from(n).ok())
+                .filter(|n| *n > 0)
+                .ok_or_else(|| anyhow!("unique_max_attempts must be a positive integer"))?,
+        };
+
+        if !unique || inner.is_unique() {
+            return Ok(inner);
+        }
+        Ok(Box::new(Self::new(inner, column.to_string(), max_attempts)))
+    }
+
+    /// Draw until `draw` yields a value not handed out before
+    fn draw_unique(
+        &self,
+        rng: &mut ChaCha8Rng,
+        draw: impl Fn(&mut ChaCha8Rng) -> Result<Option<String>>,
+    ) -> Result<Option<String>> {
+        // Only one draw in `workers` falls in this worker's share
+        let share = self.share.get();
+        let attempts = self.max_attempts * share.map_or(1, |s| s.workers);
+        for _ in 0..attempts {
+            let value = match draw(rng)? {
+                Some(value) => value,
+                None => return Ok(None),
+            };
+            if share.is_some_and(|s| !s.owns(&value)) {
+                continue;
+            }
+            let mut seen = self.seen.lock().unwrap();
+            if seen.insert(value.clone()) {
+                return Ok(Some(value));
+            }
+        }
+
+        let generated = self.seen.lock().unwrap().len();
+        Err(anyhow!(
+            "cannot generate {} unique values for {}: no new value in {} attempts, \
+             the generator's range is exhausted",
+            generated + 1,
+            self.column,
+            self.max_attempts
+        ))
+    }
+}
+
+impl DataGenerator for UniqueGenerator {
+    fn generate(&self, rng: &mut ChaCha8Rng) -> Result<String> {
+        self.draw_unique(rng, |rng| self.inner.generate(rng).map(Some))?
+            .ok_or_else(|| anyhow!("{} generated no value", self.column))
+    }
+
+    fn data_type(&self) -> DataType {
+        self.inner.data_type()
+    }
+
+    fn is_sql_expression(&self) -> bool {
+        self.inner.is_sql_expression()
+    }
+
+    fn generate_nullable(&self, rng: &mut ChaCha8Rng) -> Result<Option<String>> {
+        self.draw_unique(rng, |rng| self.inner.generate_nullable(rng))
+    }
+
+    fn is_unique(&self) -> bool {
+        true
+    }
+
+    fn assign_share(&self, share: WorkerShare) {
+        let _ = self.share.set(share);
+        self.inner.assign_share(share);
+    }
+}
+
 /// Factory function to create generators from config
+///
+/// Any generator accepts `null_probability` (0.0-1.0) to emit `NULL` for
+/// that fraction of rows.
 pub fn create_generator(
     generator_type: &str,
     options: &HashMap<String, toml::Value>,
+) -> Result<Box<dyn DataGenerator>> {
+    with_null_probability(create_base_generator(generator_type, options)?, options)
+}
+
+/// Wrap `generator` in a [`NullableGenerator`] when the column sets `null_probability`
+pub fn with_null_probability(
+    generator: Box<dyn DataGenerator>,
+    options: &HashMap<String, toml::Value>,
+) -> Result<Box<dyn DataGenerator>> {
+    let null_probability = match options.get("null_probability") {
+        None => return Ok(generator),
+        Some(value) => value
+            .as_float()
+            .or_else(|| value.as_integer().map(|i| i as f64))
+            .ok_or_else(|| anyhow!("null_probability must be a number"))?,
+    };
+    if !(0.0..=1.0).contains(&null_probability) {
+        return Err(anyhow!(
+            "null_probability must be between 0 and 1, got {}",
+            null_probability
+        ));
+    }
+    if null_probability == 0.0 {
+        return Ok(generator);
+    }
+
+    Ok(Box::new(NullableGenerator::new(generator, null_probability)))
+}
+
+fn create_base_generator(
+    generator_type: &str,
+    options: &HashMap<String, toml::Value>,
 ) -> Result<Box<dyn DataGenerator>> {
     match generator_type {
         "sequential" => Ok(Box::new(SequentialGenerator::from_options(options)?)),
@@ -546,6 +1748,14 @@ pub fn create_generator(
         "address" => Ok(Box::new(AddressGenerator::from_options(options)?)),
         "template" => Ok(Box::new(TemplateGenerator::from_options(options)?)),
         "enum" => Ok(Box::new(EnumGenerator::from_options(options)?)),
+        "uuid" => Ok(Box::new(UuidGenerator::from_options(options)?)),
+        "array" => Ok(Box::new(ArrayGenerator::from_options(options)?)),
+        "composite" => Ok(Box::new(CompositeGenerator::from_options(options)?)),
+        "geo_point" => Ok(Box::new(GeoPointGenerator::from_options(options)?)),
+        "json" => Ok(Box::new(JsonGenerator::from_options(options)?)),
+        "bytes" => Ok(Box::new(BytesGenerator::from_options(options)?)),
+        // csv reads files shared across the run, so the engine builds it
+        "csv" => Err(anyhow!("csv generator can only be used for a table column")),
         // Note: foreign_key is handled separately in the engine
         // because it requires async context
         _ => Err(anyhow!("Unknown generator type: {}", generator_type)),
@@ -719,6 +1929,47 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_uuid_generator_deterministic() {
+        let mut options = HashMap::new();
+        options.insert("version".to_string(), toml::Value::String("v4".to_string()));
+        let gen = create_generator("uuid", &options).unwrap();
+        assert_eq!(gen.data_type(), DataType::String);
+
+        let mut rng1 = ChaCha8Rng::seed_from_u64(7);
+        let mut rng2 = ChaCha8Rng::seed_from_u64(7);
+        let first: Vec<String> = (0..10).map(|_| gen.generate(&mut rng1).unwrap()).collect();
+        let second: Vec<String> = (0..10).map(|_| gen.generate(&mut rng2).unwrap()).collect();
+        assert_eq!(first, second);
+
+        let parsed = uuid::Uuid::parse_str(&first[0]).unwrap();
+        assert_eq!(parsed.get_version_num(), 4);
+        assert_eq!(parsed.get_variant(), uuid::Variant::RFC4122);
+    }
+
+    #[test]
+    fn test_uuid_v7_is_sortable() {
+        let start = parse_date("2024-06-01").unwrap();
+        let mut rng = test_rng();
+
+        let gen = UuidGenerator::new(UuidVersion::V7, start);
+        let ids: Vec<String> = (0..100).map(|_| gen.generate(&mut rng).unwrap()).collect();
+        let mut sorted = ids.clone();
+        sorted.sort();
+        assert_eq!(ids, sorted);
+
+        let first = uuid::Uuid::parse_str(&ids[0]).unwrap();
+        assert_eq!(first.get_version_num(), 7);
+        let mut millis = [0u8; 8];
+        millis[2..].copy_from_slice(&first.as_bytes()[..6]);
+        assert_eq!(u64::from_be_bytes(millis), start.timestamp_millis() as u64);
+
+        // Each generator restarts the sequence, so a fresh one reproduces the keys
+        let again = UuidGenerator::new(UuidVersion::V7, start);
+        let mut rng = test_rng();
+        assert_eq!(again.generate(&mut rng).unwrap(), ids[0]);
+    }
+
     #[test]
     fn test_deterministic_seeding() {
         let gen = RandomIntGenerator::new(1, 100);
@@ -770,4 +2021,425 @@ mod tests {
 
         assert_eq!(gen.generate(&mut rng).unwrap(), "100");
     }
+
+    #[test]
+    fn test_null_probability() {
+        let options: HashMap<String, toml::Value> =
+            toml::from_str("min = 1\nmax = 10\nnull_probability = 0.1").unwrap();
+        let gen = create_generator("random_int", &options).unwrap();
+
+        let mut rng = test_rng();
+        let values: Vec<Option<String>> = (0..1000)
+            .map(|_| gen.generate_nullable(&mut rng).unwrap())
+            .collect();
+        let nulls = values.iter().filter(|v| v.is_none()).count();
+        assert!(nulls > 60 && nulls < 140, "got {} nulls", nulls);
+
+        // Same seed, same null placement
+        let mut rng = test_rng();
+        let again: Vec<Option<String>> = (0..1000)
+            .map(|_| gen.generate_nullable(&mut rng).unwrap())
+            .collect();
+        assert_eq!(values, again);
+
+        let mut options = HashMap::new();
+        options.insert("null_probability".to_string(), toml::Value::Float(1.5));
+        assert!(create_generator("email", &options).is_err());
+    }
+
+    #[test]
+    fn test_unique_generator() {
+        let options: HashMap<String, toml::Value> =
+            toml::from_str("min = 1\nmax = 10\nunique = true\nunique_max_attempts = 500")
+                .unwrap();
+        let inner = create_generator("random_int", &options).unwrap();
+        let gen = UniqueGenerator::from_options(inner, "t.code", &options).unwrap();
+
+        let mut rng = test_rng();
+        let mut values: Vec<i64> = (0..10)
+            .map(|_| gen.generate(&mut rng).unwrap().parse().unwrap())
+            .collect();
+        values.sort();
+        assert_eq!(values, (1..=10).collect::<Vec<_>>());
+
+        // An 11th value can't exist in a range of ten
+        let err = gen.generate(&mut rng).unwrap_err().to_string();
+        assert!(err.contains("cannot generate 11 unique values for t.code"), "{}", err);
+    }
+
+    #[test]
+    fn test_worker_shares_do_not_overlap() {
+        let share = |index| WorkerShare {
+            index,
+            workers: 2,
+            offset: index * 100,
+        };
+
+        // Sequences continue from the rows of the workers before
+        let gen = SequentialGenerator::new(1);
+        gen.assign_share(share(1));
+        assert_eq!(gen.generate(&mut test_rng()).unwrap(), "101");
+
+        // Unique values are split between workers, and each worker's values
+        // depend only on its own RNG
+        let options: HashMap<String, toml::Value> =
+            toml::from_str("min = 1\nmax = 1000\nunique = true").unwrap();
+        let draw = |index| {
+            let inner = create_generator("random_int", &options).unwrap();
+            let gen = UniqueGenerator::from_options(inner, "t.code", &options).unwrap();
+            gen.assign_share(share(index));
+            let mut rng = test_rng();
+            (0..100)
+                .map(|_| gen.generate(&mut rng).unwrap())
+                .collect::<HashSet<_>>()
+        };
+        let (first, second) = (draw(0), draw(1));
+        assert_eq!(first.len(), 100);
+        assert!(first.is_disjoint(&second));
+        assert_eq!(first, draw(0));
+    }
+
+    #[test]
+    fn test_unique_skips_generators_that_never_repeat() {
+        let mut options = HashMap::new();
+        options.insert("unique".to_string(), toml::Value::Boolean(true));
+
+        let sequential = create_generator("sequential", &options).unwrap();
+        let gen = UniqueGenerator::from_options(sequential, "t.id", &options).unwrap();
+        assert!(gen.is_unique());
+
+        let random = create_generator("random_int", &options).unwrap();
+        assert!(!random.is_unique());
+
+        options.insert("unique".to_string(), toml::Value::String("yes".to_string()));
+        let email = create_generator("email", &options).unwrap();
+        assert!(UniqueGenerator::from_options(email, "t.email", &options).is_err());
+    }
+
+    #[test]
+    fn test_array_generator_int_elements() {
+        let options: HashMap<String, toml::Value> = toml::from_str(
+            r#"
+            min_length = 2
+            max_length = 4
+            element = { generator = "random_int", options = { min = 1, max = 100 } }
+            "#,
+        )
+        .unwrap();
+
+        let gen = create_generator("array", &options).unwrap();
+        let mut rng = test_rng();
+
+        for _ in 0..50 {
+            let val = gen.generate(&mut rng).unwrap();
+            assert!(val.starts_with('{') && val.ends_with('}'));
+
+            let elements: Vec<i64> = val[1..val.len() - 1]
+                .split(',')
+                .map(|e| e.parse().unwrap())
+                .collect();
+            assert!(elements.len() >= 2 && elements.len() <= 4);
+            assert!(elements.iter().all(|&e| (1..=100).contains(&e)));
+        }
+        assert_eq!(gen.data_type(), DataType::Array);
+    }
+
+    #[test]
+    fn test_array_generator_rejects_negative_lengths() {
+        for bounds in ["min_length = -1", "min_length = -3\nmax_length = -1", "max_length = -2"] {
+            let options: HashMap<String, toml::Value> = toml::from_str(&format!(
+                "{}\nelement = {{ generator = \"random_int\" }}",
+                bounds
+            ))
+            .unwrap();
+            assert!(create_generator("array", &options).is_err(), "{}", bounds);
+        }
+    }
+
+    #[test]
+    fn test_array_element_quoting() {
+        assert_eq!(quote_array_element("abc"), "abc");
+        assert_eq!(quote_array_element("a b"), "\"a b\"");
+        assert_eq!(quote_array_element("a,b"), "\"a,b\"");
+        assert_eq!(quote_array_element("say \"hi\""), "\"say \\\"hi\\\"\"");
+        assert_eq!(quote_array_element("NULL"), "\"NULL\"");
+        assert_eq!(quote_array_element(""), "\"\"");
+    }
+
+    #[test]
+    fn test_composite_generator() {
+        let options: HashMap<String, toml::Value> = toml::from_str(
+            r#"
+            fields = [
+                { generator = "enum", options = { values = ["New York"] } },
+                { generator = "sequential", options = { start = 7 } },
+            ]
+            "#,
+        )
+        .unwrap();
+
+        let gen = create_generator("composite", &options).unwrap();
+        let mut rng = test_rng();
+
+        assert_eq!(gen.generate(&mut rng).unwrap(), "(\"New York\",7)");
+        assert_eq!(gen.data_type(), DataType::Composite);
+    }
+
+    #[test]
+    fn test_json_generator() {
+        let options: HashMap<String, toml::Value> = toml::from_str(
+            r#"
+            [fields]
+            name = "name:first"
+            age = "random_int:18:80"
+            active = "boolean"
+            tier = "enum:free:pro"
+
+            [fields.address]
+            city = "enum:Paris:Oslo"
+            zip = { generator = "template", options = { template = "{random_int:10000:99999}" } }
+            "#,
+        )
+        .unwrap();
+        let gen = create_generator("json", &options).unwrap();
+        assert_eq!(gen.data_type(), DataType::Json);
+
+        let mut rng = test_rng();
+        let value: serde_json::Value =
+            serde_json::from_str(&gen.generate(&mut rng).unwrap()).unwrap();
+        let age = value["age"].as_i64().unwrap();
+        assert!((18..=80).contains(&age));
+        assert!(value["active"].is_boolean());
+        assert!(value["name"].is_string());
+        assert!(["free", "pro"].contains(&value["tier"].as_str().unwrap()));
+        assert!(["Paris", "Oslo"].contains(&value["address"]["city"].as_str().unwrap()));
+        assert_eq!(value["address"]["zip"].as_str().unwrap().len(), 5);
+
+        // Deterministic under the same seed
+        let mut rng1 = ChaCha8Rng::seed_from_u64(7);
+        let mut rng2 = ChaCha8Rng::seed_from_u64(7);
+        assert_eq!(gen.generate(&mut rng1).unwrap(), gen.generate(&mut rng2).unwrap());
+    }
+
+    #[test]
+    fn test_json_generator_escapes_strings() {
+        let mut options = HashMap::new();
+        options.insert(
+            "values".to_string(),
+            toml::Value::Array(vec![toml::Value::String("say \"hi\"\n".to_string())]),
+        );
+        let quote = Box::new(EnumGenerator::from_options(&options).unwrap());
+        let gen = JsonGenerator::new(vec![("quote".to_string(), quote)]);
+
+        let json = gen.generate(&mut test_rng()).unwrap();
+        assert_eq!(json, r#"{"quote":"say \"hi\"\n"}"#);
+        assert!(shorthand_generator("email:1").is_err());
+    }
+
+    #[test]
+    fn test_geo_point_generator_bounding_box() {
+        let gen = GeoPointGenerator::new(40.0, 41.0, -74.5, -73.5, GeoFormat::Pair);
+        let mut rng = test_rng();
+
+        for _ in 0..100 {
+            let val = gen.generate(&mut rng).unwrap();
+            let inner = val.trim_start_matches('(').trim_end_matches(')');
+            let (lat, lng) = inner.split_once(',').unwrap();
+            let lat: f64 = lat.parse().unwrap();
+            let lng: f64 = lng.parse().unwrap();
+            assert!((40.0..=41.0).contains(&lat));
+            assert!((-74.5..=-73.5).contains(&lng));
+        }
+        assert!(!gen.is_sql_expression());
+    }
+
+    #[test]
+    fn test_geo_point_formats() {
+        let mut rng = test_rng();
+
+        let wkt = GeoPointGenerator::new(10.0, 10.0, 20.0, 20.0, GeoFormat::Wkt);
+        assert_eq!(wkt.generate(&mut rng).unwrap(), "POINT(20.000000 10.000000)");
+
+        let geojson = GeoPointGenerator::new(10.0, 10.0, 20.0, 20.0, GeoFormat::GeoJson);
+        assert_eq!(
+            geojson.generate(&mut rng).unwrap(),
+            "{\"type\":\"Point\",\"coordinates\":[20.000000,10.000000]}"
+        );
+
+        let postgis = GeoPointGenerator::new(10.0, 10.0, 20.0, 20.0, GeoFormat::PostGis);
+        assert_eq!(
+            postgis.generate(&mut rng).unwrap(),
+            "ST_SetSRID(ST_MakePoint(20.000000,10.000000),4326)"
+        );
+        assert!(postgis.is_sql_expression());
+
+        let mysql = GeoPointGenerator::new(10.0, 10.0, 20.0, 20.0, GeoFormat::MySql);
+        assert_eq!(
+            mysql.generate(&mut rng).unwrap(),
+            "ST_GeomFromText('POINT(20.000000 10.000000)')"
+        );
+        assert!(mysql.is_sql_expression());
+    }
+
+    #[test]
+    fn test_bytes_literals_per_dialect() {
+        use crate::container::DatabaseType;
+
+        let mut rng = test_rng();
+        let cases = [
+            (DatabaseType::Postgres, "'\\x", "'::bytea"),
+            (DatabaseType::CockroachDB, "'\\x", "'::bytea"),
+            (DatabaseType::SQLServer, "0x", ""),
+            (DatabaseType::MySQL, "X'", "'"),
+            (DatabaseType::SQLite, "X'", "'"),
+        ];
+        for (db_type, prefix, suffix) in cases {
+            let gen = BytesGenerator::new(20, BinaryFormat::for_database(db_type));
+            let literal = gen.generate(&mut rng).unwrap();
+            let hex = literal
+                .strip_prefix(prefix)
+                .and_then(|rest| rest.strip_suffix(suffix))
+                .unwrap_or_else(|| panic!("{}: unexpected literal {}", db_type, literal));
+            assert_eq!(hex.len(), 40, "{}: {}", db_type, literal);
+            assert!(hex.chars().all(|c| c.is_ascii_hexdigit()), "{}: {}", db_type, literal);
+        }
+
+        // Empty values are still valid literals
+        let empty = BytesGenerator::new(0, BinaryFormat::ZeroX);
+        assert_eq!(empty.generate(&mut rng).unwrap(), "0x");
+    }
+
+    #[test]
+    fn test_bytes_options() {
+        let mut options = HashMap::new();
+        let gen = BytesGenerator::from_options(&options).unwrap();
+        assert_eq!(gen.length, 16);
+        assert!(gen.is_sql_expression());
+
+        options.insert("length".to_string(), toml::Value::Integer(32));
+        options.insert("format".to_string(), toml::Value::String("bytea".to_string()));
+        let gen = BytesGenerator::from_options(&options).unwrap();
+        assert_eq!((gen.length, gen.format), (32, BinaryFormat::Bytea));
+
+        options.insert("format".to_string(), toml::Value::String("base64".to_string()));
+        assert!(BytesGenerator::from_options(&options).is_err());
+        options.insert("length".to_string(), toml::Value::Integer(-1));
+        assert!(BytesGenerator::from_options(&options).is_err());
+    }
+
+    #[test]
+    fn test_geo_format_for_column_type() {
+        use crate::container::DatabaseType;
+
+        assert_eq!(GeoFormat::for_column_type(DatabaseType::Postgres, "geometry"), GeoFormat::PostGis);
+        assert_eq!(GeoFormat::for_column_type(DatabaseType::Postgres, "jsonb"), GeoFormat::GeoJson);
+        assert_eq!(GeoFormat::for_column_type(DatabaseType::Postgres, "point"), GeoFormat::Pair);
+        assert_eq!(GeoFormat::for_column_type(DatabaseType::Postgres, "text"), GeoFormat::Wkt);
+        assert_eq!(GeoFormat::for_column_type(DatabaseType::MySQL, "geometry"), GeoFormat::MySql);
+        assert_eq!(GeoFormat::for_column_type(DatabaseType::MySQL, "point"), GeoFormat::MySql);
+        assert_eq!(GeoFormat::for_column_type(DatabaseType::MySQL, "text"), GeoFormat::Wkt);
+    }
+
+    #[test]
+    fn test_geo_point_deterministic() {
+        let gen = GeoPointGenerator::new(-10.0, 10.0, -10.0, 10.0, GeoFormat::Wkt);
+        let mut rng1 = ChaCha8Rng::seed_from_u64(7);
+        let mut rng2 = ChaCha8Rng::seed_from_u64(7);
+
+        assert_eq!(gen.generate(&mut rng1).unwrap(), gen.generate(&mut rng2).unwrap());
+    }
+
+    fn csv_options(toml: &str) -> HashMap<String, toml::Value> {
+        toml::from_str(toml).unwrap()
+    }
+
+    #[test]
+    fn test_csv_column() {
+        let content = concat!(
+            "city,country\n",
+            "Paris,France\n",
+            "\"Washington, D.C.\",USA\r\n",
+            "\n",
+            "\"Say \"\"hi\"\"\",X\n",
+        );
+        assert_eq!(
+            csv_column(content, "city").unwrap(),
+            ["Paris", "Washington, D.C.", "Say \"hi\""]
+        );
+        assert_eq!(csv_column(content, "country").unwrap(), ["France", "USA", "X"]);
+
+        let err = csv_column(content, "population").unwrap_err().to_string();
+        assert!(err.contains("no column 'population' (columns: city, country)"));
+        assert!(csv_column("", "city").is_err());
+    }
+
+    #[test]
+    fn test_csv_generator_modes() {
+        let file = tempfile::NamedTempFile::new().unwrap();
+        std::fs::write(file.path(), "sku\nA\nB\nC\n").unwrap();
+        let path = file.path().display().to_string().replace('\\', "/");
+        let sources = CsvSources::default();
+        let mut rng = test_rng();
+
+        let options = csv_options(&format!(
+            "path = '{}'\ncolumn = 'sku'\nmode = 'sequential'",
+            path
+        ));
+        let gen = CsvGenerator::from_options(&options, &sources, "products").unwrap();
+        let values: Vec<String> = (0..4).map(|_| gen.generate(&mut rng).unwrap()).collect();
+        assert_eq!(values, ["A", "B", "C", "A"]);
+
+        // Without loop, random mode uses every row once
+        let options = csv_options(&format!("path = '{}'\ncolumn = 'sku'\nloop = false", path));
+        let gen = CsvGenerator::from_options(&options, &sources, "products").unwrap();
+        let mut values: Vec<String> = (0..3).map(|_| gen.generate(&mut rng).unwrap()).collect();
+        values.sort();
+        assert_eq!(values, ["A", "B", "C"]);
+        assert!(gen.generate(&mut rng).is_err());
+
+        let options = csv_options(&format!("path = '{}'\ncolumn = 'name'", path));
+        assert!(CsvGenerator::from_options(&options, &sources, "products").is_err());
+
+        // A worker's sequential reads start at its offset
+        let options = csv_options(&format!(
+            "path = '{}'\ncolumn = 'sku'\nmode = 'sequential'",
+            path
+        ));
+        let gen = CsvGenerator::from_options(&options, &sources, "products").unwrap();
+        gen.assign_share(WorkerShare {
+            index: 1,
+            workers: 2,
+            offset: 2,
+        });
+        assert_eq!(gen.generate(&mut rng).unwrap(), "C");
+    }
+
+    #[test]
+    fn test_csv_source_shares_rows() {
+        let file = tempfile::NamedTempFile::new().unwrap();
+        let rows: String = (0..50).map(|i| format!("city{0},country{0}\n", i)).collect();
+        std::fs::write(file.path(), format!("city,country\n{}", rows)).unwrap();
+        let path = file.path().display().to_string().replace('\\', "/");
+        let sources = CsvSources::default();
+
+        let options = |column: &str| {
+            csv_options(&format!(
+                "path = '{}'\ncolumn = '{}'\nsource = 'places'",
+                path, column
+            ))
+        };
+        let city = CsvGenerator::from_options(&options("city"), &sources, "users").unwrap();
+        let country = CsvGenerator::from_options(&options("country"), &sources, "users").unwrap();
+
+        let mut rng = test_rng();
+        for _ in 0..20 {
+            let city = city.generate(&mut rng).unwrap();
+            let country = country.generate(&mut rng).unwrap();
+            assert_eq!(city.replace("city", ""), country.replace("country", ""));
+        }
+
+        let mut nullable = options("city");
+        nullable.insert("null_probability".to_string(), toml::Value::Float(0.5));
+        assert!(CsvGenerator::from_options(&nullable, &sources, "users").is_err());
+    }
 }
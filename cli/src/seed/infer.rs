@@ -0,0 +1,683 @@
+//! Infer seed rules from the schema of existing tables
+//!
+//! Each column gets a generator picked from its type, refined by its name for
+//! text columns (an `email` column gets the email generator, and so on).
+//! Rules from a seed config take precedence over inferred ones, column by column.
+//!
+//! Where the table already has data, a sample of each column
+//! ([`ColumnProfile`]) narrows the generator to match it: integer, decimal and
+//! timestamp ranges, a few repeated text values as an enum, and the share of NULLs.
+//!
+//! [`starter_rules`] goes further for a whole schema: foreign key columns reference
+//! the tables they point at, and tables come in an order they can be seeded in.
+
+use anyhow::{anyhow, Result};
+use chrono::NaiveDate;
+use std::collections::HashMap;
+
+use super::config::{ColumnRule, SeedRule};
+use super::dependency::DependencyResolver;
+use crate::schema::TableSchema;
+use crate::workload::{ColumnMetadata, ColumnProfile, ProfileKind, TableMetadata};
+
+/// Row count for inferred tables that have no rule in the config
+pub const DEFAULT_INFERRED_COUNT: usize = 1000;
+
+/// Pick a generator for a column from its type and name
+pub fn infer_column(column: &ColumnMetadata) -> ColumnRule {
+    let name = column.name.to_lowercase();
+    let data_type = column.data_type.to_lowercase();
+    let base_type = data_type.split(['(', ' ']).next().unwrap_or_default();
+
+    let (generator, options): (&str, Vec<(&str, toml::Value)>) = if is_integer(base_type) {
+        if column.is_primary_key {
+            ("sequential", vec![("start", toml::Value::Integer(1))])
+        } else {
+            let max = if matches!(base_type, "tinyint" | "smallint" | "int2") {
+                100
+            } else {
+                1000
+            };
+            (
+                "random_int",
+                vec![("min", toml::Value::Integer(1)), ("max", toml::Value::Integer(max))],
+            )
+        }
+    } else if is_binary(base_type) {
+        // Hashes and tokens fit a sized binary(n) column; larger blobs get a sample
+        let length = data_type
+            .split_once('(')
+            .and_then(|(_, rest)| rest.trim_end_matches(')').trim().parse::<i64>().ok())
+            .filter(|n| (1..=1024).contains(n))
+            .unwrap_or(16);
+        ("bytes", vec![("length", toml::Value::Integer(length))])
+    } else if matches!(base_type, "uuid" | "uniqueidentifier") {
+        ("uuid", vec![])
+    } else if matches!(base_type, "bool" | "boolean" | "bit") {
+        ("boolean", vec![])
+    } else if matches!(
+        base_type,
+        "numeric" | "decimal" | "real" | "double" | "float" | "float4" | "float8" | "money"
+    ) {
+        (
+            "random_decimal",
+            vec![
+                ("min", toml::Value::Float(0.0)),
+                ("max", toml::Value::Float(1000.0)),
+                ("precision", toml::Value::Integer(2)),
+            ],
+        )
+    } else if base_type.starts_with("timestamp")
+        || matches!(base_type, "datetime" | "datetime2" | "datetimeoffset" | "date")
+    {
+        ("timestamp", vec![("type", toml::Value::String("now".to_string()))])
+    } else if matches!(base_type, "json" | "jsonb") {
+        ("template", vec![("template", toml::Value::String("{}".to_string()))])
+    } else if name.contains("email") {
+        ("email", vec![])
+    } else if name.contains("phone") {
+        ("phone", vec![])
+    } else if name.contains("first_name") || name == "firstname" {
+        ("name", vec![("type", toml::Value::String("first".to_string()))])
+    } else if name.contains("last_name") || name == "lastname" {
+        ("name", vec![("type", toml::Value::String("last".to_string()))])
+    } else if name.contains("name") {
+        ("name", vec![("type", toml::Value::String("full".to_string()))])
+    } else if name.contains("address") || name.contains("street") {
+        ("address", vec![])
+    } else {
+        // Any other text (or unrecognized type): a distinguishable string per row
+        let template = format!("{}_{{random_int:1:100000}}", column.name);
+        ("template", vec![("template", toml::Value::String(template))])
+    };
+
+    ColumnRule {
+        name: column.name.clone(),
+        generator: generator.to_string(),
+        options: options
+            .into_iter()
+            .map(|(key, value)| (key.to_string(), value))
+            .collect(),
+    }
+}
+
+/// What to sample from a column to refine its inferred generator, if anything
+pub fn profile_kind(column: &ColumnMetadata) -> Option<ProfileKind> {
+    let rule = infer_column(column);
+    match rule.generator.as_str() {
+        "sequential" | "random_int" | "random_decimal" => Some(ProfileKind::Numeric),
+        "timestamp" => Some(ProfileKind::Temporal),
+        // JSON columns get a fixed `{}` template; other templates stand in for text
+        "template" if rule.options["template"].as_str() != Some("{}") => Some(ProfileKind::Values),
+        "name" | "address" => Some(ProfileKind::Values),
+        _ if column.is_nullable => Some(ProfileKind::Values),
+        _ => None,
+    }
+}
+
+/// Pick a generator for a column from its type and name, fitted to a sample of its
+/// existing values when there is one
+pub fn infer_column_from(column: &ColumnMetadata, profile: Option<&ColumnProfile>) -> ColumnRule {
+    let mut rule = infer_column(column);
+    let Some(profile) = profile.filter(|p| p.non_null > 0) else {
+        return rule;
+    };
+
+    match rule.generator.as_str() {
+        "sequential" => {
+            // Continue after the existing keys so new rows don't collide with them
+            if let Some(max) = profile.max.as_deref().and_then(|v| v.parse::<i64>().ok()) {
+                rule.options.insert("start".to_string(), toml::Value::Integer(max + 1));
+            }
+        }
+        "random_int" => {
+            let min = profile.min.as_deref().and_then(|v| v.parse::<i64>().ok());
+            let max = profile.max.as_deref().and_then(|v| v.parse::<i64>().ok());
+            if let (Some(min), Some(max)) = (min, max) {
+                rule.options.insert("min".to_string(), toml::Value::Integer(min));
+                rule.options.insert("max".to_string(), toml::Value::Integer(max));
+            }
+        }
+        "random_decimal" => {
+            let min = profile.min.as_deref().and_then(|v| v.parse::<f64>().ok());
+            let max = profile.max.as_deref().and_then(|v| v.parse::<f64>().ok());
+            if let (Some(min), Some(max)) = (min, max) {
+                rule.options.insert("min".to_string(), toml::Value::Float(min));
+                rule.options.insert("max".to_string(), toml::Value::Float(max));
+            }
+        }
+        "timestamp" => {
+            let min = profile.min.as_deref().and_then(parse_day);
+            let max = profile.max.as_deref().and_then(parse_day);
+            if let (Some(start), Some(last)) = (min, max) {
+                // Through the end of the last day seen, and never an empty range
+                let end = last.succ_opt().unwrap_or(last);
+                rule.options = HashMap::from([
+                    ("type".to_string(), toml::Value::String("range".to_string())),
+                    ("start".to_string(), toml::Value::String(start.to_string())),
+                    ("end".to_string(), toml::Value::String(end.to_string())),
+                ]);
+            }
+        }
+        "template" | "name" | "address" | "email" | "phone" => {
+            // A few values repeated across many rows are categories, not free text
+            let categorical = !profile.values.is_empty()
+                && profile.non_null >= 2 * profile.values.len() as u64;
+            if categorical {
+                rule.generator = "enum".to_string();
+                rule.options = HashMap::from([(
+                    "values".to_string(),
+                    toml::Value::Array(
+                        profile
+                            .values
+                            .iter()
+                            .map(|v| toml::Value::String(v.clone()))
+                            .collect(),
+                    ),
+                )]);
+            }
+        }
+        _ => {}
+    }
+
+    if column.is_nullable && !column.is_primary_key {
+        let null_fraction = (profile.null_fraction() * 100.0).round() / 100.0;
+        if null_fraction > 0.0 {
+            rule.options.insert(
+                "null_probability".to_string(),
+                toml::Value::Float(null_fraction),
+            );
+        }
+    }
+
+    rule
+}
+
+/// The date of a timestamp as the database prints it (`2024-05-01 12:00:00`,
+/// `2024-05-01T12:00:00`, ...)
+fn parse_day(value: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(value.get(..10)?, "%Y-%m-%d").ok()
+}
+
+fn is_binary(base_type: &str) -> bool {
+    matches!(
+        base_type,
+        "bytea"
+            | "bytes"
+            | "blob"
+            | "tinyblob"
+            | "mediumblob"
+            | "longblob"
+            | "binary"
+            | "varbinary"
+            | "image"
+    )
+}
+
+fn is_integer(base_type: &str) -> bool {
+    matches!(
+        base_type,
+        "int"
+            | "integer"
+            | "tinyint"
+            | "smallint"
+            | "mediumint"
+            | "bigint"
+            | "int2"
+            | "int4"
+            | "int8"
+            | "serial"
+            | "bigserial"
+    )
+}
+
+/// Seed rule for a table: inferred columns, fitted to their `profiles` where sampled,
+/// with `explicit`'s columns (and count) taking precedence
+pub fn infer_rule(
+    metadata: &TableMetadata,
+    explicit: Option<&SeedRule>,
+    profiles: &HashMap<String, ColumnProfile>,
+) -> SeedRule {
+    let explicit_columns: HashMap<&str, &ColumnRule> = explicit
+        .map(|rule| rule.columns.iter().map(|c| (c.name.as_str(), c)).collect())
+        .unwrap_or_default();
+
+    let mut columns: Vec<ColumnRule> = metadata
+        .columns
+        .iter()
+        .map(|column| match explicit_columns.get(column.name.as_str()) {
+            Some(rule) => (*rule).clone(),
+            None => infer_column_from(column, profiles.get(&column.name)),
+        })
+        .collect();
+
+    // Explicit rules for columns the table doesn't report are kept, so typos surface on insert
+    if let Some(rule) = explicit {
+        for column in &rule.columns {
+            if !metadata.columns.iter().any(|c| c.name == column.name) {
+                columns.push(column.clone());
+            }
+        }
+    }
+
+    SeedRule {
+        name: metadata.name.clone(),
+        count: explicit.map_or(DEFAULT_INFERRED_COUNT, |rule| rule.count),
+        columns,
+    }
+}
+
+/// Seed rules for every table in a schema, ordered so each table comes after the
+/// tables its foreign keys reference
+///
+/// Columns get [`infer_column`]'s generators, except foreign keys, which pick from the
+/// referenced column. Primary keys stay sequential even when they are also foreign keys,
+/// so they remain unique. A table referencing itself can't draw from its own rows while
+/// they are being inserted, so a nullable self-reference is left NULL and a NOT NULL one
+/// is left out of the rules and listed in [`StarterRules::skipped`].
+pub fn starter_rules(tables: &[TableSchema]) -> Result<StarterRules> {
+    let mut resolver = DependencyResolver::new();
+    let mut rules = HashMap::with_capacity(tables.len());
+    let mut skipped = Vec::new();
+
+    for table in tables {
+        resolver.add_table(table.name.clone());
+
+        let mut columns = Vec::with_capacity(table.columns.len());
+        for column in &table.columns {
+            let mut rule = infer_column(column);
+            let fk = table.foreign_keys.iter().find(|fk| fk.column == column.name);
+            let referenced = fk.and_then(|fk| {
+                tables
+                    .iter()
+                    .find(|t| t.name == fk.referenced_table)
+                    .map(|t| (fk, t))
+            });
+
+            if let Some((fk, target)) = referenced.filter(|_| !column.is_primary_key) {
+                if target.name == table.name && column.is_nullable {
+                    rule.options
+                        .insert("null_probability".to_string(), toml::Value::Float(1.0));
+                } else if target.name == table.name {
+                    skipped.push(format!("{}.{}", table.name, column.name));
+                    continue;
+                } else {
+                    resolver.add_dependency(table.name.clone(), target.name.clone());
+                    // SQLite reports references to the primary key without a column
+                    let referenced_column = if fk.referenced_column.is_empty() {
+                        target
+                            .columns
+                            .iter()
+                            .find(|c| c.is_primary_key)
+                            .map_or("id", |c| c.name.as_str())
+                    } else {
+                        fk.referenced_column.as_str()
+                    };
+                    rule.generator = "foreign_key".to_string();
+                    rule.options = HashMap::from([(
+                        "references".to_string(),
+                        toml::Value::Table(toml::map::Map::from_iter([
+                            ("table".to_string(), toml::Value::String(target.name.clone())),
+                            (
+                                "column".to_string(),
+                                toml::Value::String(referenced_column.to_string()),
+                            ),
+                        ])),
+                    )]);
+                }
+            }
+            columns.push(rule);
+        }
+
+        rules.insert(
+            table.name.clone(),
+            SeedRule {
+                name: table.name.clone(),
+                count: DEFAULT_INFERRED_COUNT,
+                columns,
+            },
+        );
+    }
+
+    let names: Vec<String> = tables.iter().map(|t| t.name.clone()).collect();
+    let levels = resolver
+        .resolve_order(&names)
+        .map_err(|e| anyhow!("Cannot order tables for seeding: {}", e))?;
+    Ok(StarterRules {
+        rules: levels
+            .into_iter()
+            .flatten()
+            .filter_map(|name| rules.remove(&name))
+            .collect(),
+        skipped,
+    })
+}
+
+/// Seed rules for a whole schema, from [`starter_rules`]
+#[derive(Debug)]
+pub struct StarterRules {
+    pub rules: Vec<SeedRule>,
+    /// `table.column` of each NOT NULL self-reference, which no generator can fill
+    pub skipped: Vec<String>,
+}
+
+impl StarterRules {
+    /// Render as a seed config file, noting the columns left out at the top
+    pub fn render(&self, batch_size: usize) -> String {
+        let mut out = String::new();
+        for column in &self.skipped {
+            out.push_str(&format!(
+                "# {} is a NOT NULL reference to its own table and has no rule: \
+                 make it nullable, give it a default, or fill it in after seeding\n",
+                column
+            ));
+        }
+        if !self.skipped.is_empty() {
+            out.push('\n');
+        }
+        out.push_str(&render_config(None, batch_size, &self.rules));
+        out
+    }
+}
+
+/// Render seed rules as a seed config file
+pub fn render_config(global_seed: Option<u64>, batch_size: usize, rules: &[SeedRule]) -> String {
+    let mut out = String::new();
+    if let Some(seed) = global_seed {
+        out.push_str(&format!("global_seed = {}\n", seed));
+    }
+    out.push_str(&format!("batch_size = {}\n", batch_size));
+
+    for rule in rules {
+        out.push_str("\n[[seed_rules.tables]]\n");
+        out.push_str(&format!("name = {}\n", toml::Value::String(rule.name.clone())));
+        out.push_str(&format!("count = {}\n", rule.count));
+
+        for column in &rule.columns {
+            out.push_str("\n[[seed_rules.tables.columns]]\n");
+            out.push_str(&format!("name = {}\n", toml::Value::String(column.name.clone())));
+            out.push_str(&format!(
+                "generator = {}\n",
+                toml::Value::String(column.generator.clone())
+            ));
+
+            let mut options: Vec<_> = column.options.iter().collect();
+            options.sort_by(|a, b| a.0.cmp(b.0));
+            for (key, value) in options {
+                out.push_str(&format!("{} = {}\n", key, value));
+            }
+        }
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::seed::SeedConfig;
+
+    fn column(name: &str, data_type: &str, is_primary_key: bool) -> ColumnMetadata {
+        ColumnMetadata {
+            name: name.to_string(),
+            data_type: data_type.to_string(),
+            is_nullable: !is_primary_key,
+            is_primary_key,
+        }
+    }
+
+    fn users() -> TableMetadata {
+        TableMetadata {
+            name: "users".to_string(),
+            columns: vec![
+                column("id", "integer", true),
+                column("email", "character varying", false),
+                column("full_name", "varchar(255)", false),
+                column("age", "smallint", false),
+                column("balance", "numeric", false),
+                column("active", "boolean", false),
+                column("created_at", "timestamp without time zone", false),
+                column("bio", "text", false),
+            ],
+            primary_key: Some("id".to_string()),
+            row_count_estimate: 0,
+        }
+    }
+
+    #[test]
+    fn test_infer_column_generators() {
+        let rule = infer_rule(&users(), None, &HashMap::new());
+        let generators: Vec<(&str, &str)> = rule
+            .columns
+            .iter()
+            .map(|c| (c.name.as_str(), c.generator.as_str()))
+            .collect();
+
+        assert_eq!(
+            generators,
+            vec![
+                ("id", "sequential"),
+                ("email", "email"),
+                ("full_name", "name"),
+                ("age", "random_int"),
+                ("balance", "random_decimal"),
+                ("active", "boolean"),
+                ("created_at", "timestamp"),
+                ("bio", "template"),
+            ]
+        );
+        assert_eq!(rule.count, DEFAULT_INFERRED_COUNT);
+        assert_eq!(rule.columns[3].options["max"], toml::Value::Integer(100));
+
+        // Binary columns get bytes sized to a binary(n) column
+        let hash = infer_column(&column("sha256", "binary(32)", false));
+        assert_eq!(hash.generator, "bytes");
+        assert_eq!(hash.options["length"], toml::Value::Integer(32));
+        let blob = infer_column(&column("avatar", "bytea", false));
+        assert_eq!(blob.options["length"], toml::Value::Integer(16));
+    }
+
+    #[test]
+    fn test_explicit_rules_override_inference() {
+        let explicit = SeedRule {
+            name: "users".to_string(),
+            count: 50,
+            columns: vec![ColumnRule {
+                name: "bio".to_string(),
+                generator: "enum".to_string(),
+                options: HashMap::from([(
+                    "values".to_string(),
+                    toml::Value::Array(vec![toml::Value::String("hi".to_string())]),
+                )]),
+            }],
+        };
+
+        let rule = infer_rule(&users(), Some(&explicit), &HashMap::new());
+        assert_eq!(rule.count, 50);
+        assert_eq!(rule.columns.len(), 8);
+        let bio = rule.columns.iter().find(|c| c.name == "bio").unwrap();
+        assert_eq!(bio.generator, "enum");
+        let email = rule.columns.iter().find(|c| c.name == "email").unwrap();
+        assert_eq!(email.generator, "email");
+    }
+
+    #[test]
+    fn test_profiles_fit_generators_to_existing_data() {
+        let profile = |min: &str, max: &str, values: &[&str]| ColumnProfile {
+            sampled_rows: 200,
+            non_null: 150,
+            distinct: values.len() as u64,
+            min: Some(min.to_string()).filter(|v| !v.is_empty()),
+            max: Some(max.to_string()).filter(|v| !v.is_empty()),
+            values: values.iter().map(|v| v.to_string()).collect(),
+        };
+        let mut table = users();
+        table.columns.push(column("status", "text", false));
+        let profiles = HashMap::from([
+            ("id".to_string(), profile("1", "500", &[])),
+            ("age".to_string(), profile("18", "90", &[])),
+            ("balance".to_string(), profile("0.5", "99.75", &[])),
+            ("created_at".to_string(), profile("2024-01-05 08:00:00", "2024-03-31 23:59:59", &[])),
+            (
+                "status".to_string(),
+                ColumnProfile { sampled_rows: 150, ..profile("", "", &["active", "banned"]) },
+            ),
+            // Unique values are free text, not categories
+            ("bio".to_string(), ColumnProfile { distinct: 150, ..profile("", "", &[]) }),
+        ]);
+
+        let rule = infer_rule(&table, None, &profiles);
+        let options = |name: &str| &rule.columns.iter().find(|c| c.name == name).unwrap().options;
+
+        assert_eq!(options("id")["start"], toml::Value::Integer(501));
+        assert_eq!(options("age")["min"], toml::Value::Integer(18));
+        assert_eq!(options("age")["max"], toml::Value::Integer(90));
+        assert_eq!(options("age")["null_probability"], toml::Value::Float(0.25));
+        assert_eq!(options("balance")["max"], toml::Value::Float(99.75));
+        assert_eq!(options("created_at")["type"], toml::Value::String("range".to_string()));
+        assert_eq!(options("created_at")["start"], toml::Value::String("2024-01-05".to_string()));
+        assert_eq!(options("created_at")["end"], toml::Value::String("2024-04-01".to_string()));
+        let status = rule.columns.iter().find(|c| c.name == "status").unwrap();
+        assert_eq!(status.generator, "enum");
+        assert!(!options("status").contains_key("null_probability"));
+        let bio = rule.columns.iter().find(|c| c.name == "bio").unwrap();
+        assert_eq!(bio.generator, "template");
+
+        // The fitted rules still load as a seed config
+        let rendered = render_config(None, 100, &[rule]);
+        let config: SeedConfig = toml::from_str(&rendered).unwrap();
+        assert_eq!(config.seed_rules.tables()[0].columns.len(), 9);
+    }
+
+    #[test]
+    fn test_rendered_config_parses_back() {
+        let rules = vec![infer_rule(&users(), None, &HashMap::new())];
+        let rendered = render_config(Some(7), 500, &rules);
+
+        let config: SeedConfig = toml::from_str(&rendered).unwrap();
+        assert_eq!(config.global_seed, Some(7));
+        assert_eq!(config.batch_size, 500);
+        let tables = config.seed_rules.tables();
+        assert_eq!(tables.len(), 1);
+        assert_eq!(tables[0].columns.len(), 8);
+        assert_eq!(tables[0].columns[0].options["start"], toml::Value::Integer(1));
+        assert_eq!(
+            tables[0].columns[7].options["template"],
+            toml::Value::String("bio_{random_int:1:100000}".to_string())
+        );
+    }
+
+    #[test]
+    fn test_starter_rules_follow_foreign_keys() {
+        use crate::seed::ForeignKeyInfo;
+        use crate::workload::ForeignKeyMetadata;
+
+        let table = |name: &str, columns: Vec<ColumnMetadata>, fks: &[(&str, &str, &str)]| {
+            TableSchema {
+                name: name.to_string(),
+                columns,
+                indexes: Vec::new(),
+                foreign_keys: fks
+                    .iter()
+                    .map(|(column, table, referenced)| ForeignKeyMetadata {
+                        column: column.to_string(),
+                        referenced_table: table.to_string(),
+                        referenced_column: referenced.to_string(),
+                    })
+                    .collect(),
+                row_count: None,
+            }
+        };
+        // Listed by name, as introspection returns them
+        let tables = vec![
+            table(
+                "order_items",
+                vec![column("id", "integer", true), column("order_id", "integer", false)],
+                &[("order_id", "orders", "id")],
+            ),
+            table(
+                "orders",
+                vec![
+                    column("id", "integer", true),
+                    column("user_id", "integer", false),
+                    column("parent_id", "integer", false),
+                ],
+                &[("user_id", "users", ""), ("parent_id", "orders", "id")],
+            ),
+            table(
+                "users",
+                vec![column("id", "bigint", true), column("email", "text", false)],
+                &[],
+            ),
+        ];
+
+        let starter = starter_rules(&tables).unwrap();
+        assert!(starter.skipped.is_empty());
+        let rules = starter.rules;
+        let names: Vec<&str> = rules.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(names, vec!["users", "orders", "order_items"]);
+        assert_eq!(rules[0].columns[0].generator, "sequential");
+        assert_eq!(rules[0].columns[1].generator, "email");
+
+        // A reference without a column points at the primary key
+        let user_id = &rules[1].columns[1];
+        assert_eq!(user_id.generator, "foreign_key");
+        let fk = ForeignKeyInfo::from_options(&user_id.options).unwrap();
+        assert_eq!((fk.table.as_str(), fk.column.as_str()), ("users", "id"));
+
+        // Self-references are left NULL
+        let parent_id = &rules[1].columns[2];
+        assert_eq!(parent_id.generator, "random_int");
+        assert_eq!(parent_id.options["null_probability"], toml::Value::Float(1.0));
+
+        // The rendered config loads, references intact
+        let config: SeedConfig = toml::from_str(&render_config(None, 1000, &rules)).unwrap();
+        let order_id = &config.seed_rules.tables()[2].columns[1];
+        assert_eq!(ForeignKeyInfo::from_options(&order_id.options).unwrap().table, "orders");
+    }
+
+    #[test]
+    fn test_starter_rules_skip_required_self_reference() {
+        let mut parent_id = column("parent_id", "integer", false);
+        parent_id.is_nullable = false;
+        let tables = vec![TableSchema {
+            name: "categories".to_string(),
+            columns: vec![column("id", "integer", true), parent_id],
+            indexes: Vec::new(),
+            foreign_keys: vec![crate::workload::ForeignKeyMetadata {
+                column: "parent_id".to_string(),
+                referenced_table: "categories".to_string(),
+                referenced_column: "id".to_string(),
+            }],
+            row_count: None,
+        }];
+
+        let starter = starter_rules(&tables).unwrap();
+        assert_eq!(starter.skipped, vec!["categories.parent_id"]);
+        let columns: Vec<&str> = starter.rules[0].columns.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(columns, vec!["id"]);
+
+        let rendered = starter.render(1000);
+        assert!(rendered.starts_with("# categories.parent_id is a NOT NULL reference"));
+        let config: SeedConfig = toml::from_str(&rendered).unwrap();
+        assert_eq!(config.seed_rules.tables()[0].columns.len(), 1);
+    }
+
+    #[test]
+    fn test_starter_rules_reject_cycles() {
+        let table = |name: &str, references: &str| TableSchema {
+            name: name.to_string(),
+            columns: vec![column("id", "integer", true), column("other_id", "integer", false)],
+            indexes: Vec::new(),
+            foreign_keys: vec![crate::workload::ForeignKeyMetadata {
+                column: "other_id".to_string(),
+                referenced_table: references.to_string(),
+                referenced_column: "id".to_string(),
+            }],
+            row_count: None,
+        };
+
+        let err = starter_rules(&[table("a", "b"), table("b", "a")]).unwrap_err();
+        assert!(err.to_string().contains("Circular dependency"));
+    }
+}
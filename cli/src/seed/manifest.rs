@@ -0,0 +1,197 @@
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::seed::config::SeedRule;
+
+/// Default file name for seed manifests
+pub const DEFAULT_MANIFEST_FILE: &str = "seed-manifest.json";
+
+/// Record of exactly how a seeded dataset was generated
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SeedManifest {
+    /// dbarena version that produced the dataset (generator implementation version)
+    pub generator_version: String,
+    /// BLAKE3 hash of the seed configuration file contents
+    pub config_hash: String,
+    /// Raw seed configuration, embedded so the manifest is self-contained
+    pub config: String,
+    /// Seed value used for the RNG
+    pub global_seed: u64,
+    /// Batch size used for inserts
+    pub batch_size: usize,
+    /// Workers per table; each has its own RNG, so this affects generated values
+    #[serde(default = "default_parallel")]
+    pub parallel: usize,
+    /// Database type of the seeded container
+    pub database_type: String,
+    /// Name of the seeded container
+    pub container: String,
+    /// Per-table row counts and generators (after presets and overrides)
+    pub tables: Vec<TableManifest>,
+    /// Generation timestamp (RFC 3339)
+    pub created_at: String,
+}
+
+fn default_parallel() -> usize {
+    1
+}
+
+/// Per-table entry in a seed manifest
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TableManifest {
+    pub name: String,
+    pub count: usize,
+    /// Column name -> generator type
+    pub generators: Vec<ColumnManifest>,
+}
+
+/// Per-column entry in a seed manifest
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ColumnManifest {
+    pub name: String,
+    pub generator: String,
+}
+
+impl SeedManifest {
+    /// Build a manifest for a seeding run
+    pub fn new(
+        config: &str,
+        global_seed: u64,
+        batch_size: usize,
+        database_type: &str,
+        container: &str,
+        rules: &[SeedRule],
+    ) -> Self {
+        let tables = rules
+            .iter()
+            .map(|rule| TableManifest {
+                name: rule.name.clone(),
+                count: rule.count,
+                generators: rule
+                    .columns
+                    .iter()
+                    .map(|c| ColumnManifest {
+                        name: c.name.clone(),
+                        generator: c.generator.clone(),
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        Self {
+            generator_version: env!("CARGO_PKG_VERSION").to_string(),
+            config_hash: hash_config(config),
+            config: config.to_string(),
+            global_seed,
+            batch_size,
+            parallel: 1,
+            database_type: database_type.to_string(),
+            container: container.to_string(),
+            tables,
+            created_at: chrono::Utc::now().to_rfc3339(),
+        }
+    }
+
+    /// Record the number of workers per table used for the run
+    pub fn with_parallel(mut self, parallel: usize) -> Self {
+        self.parallel = parallel;
+        self
+    }
+
+    /// Load a manifest from a JSON file
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| anyhow!("Failed to read manifest '{}': {}", path.display(), e))?;
+        let manifest: SeedManifest = serde_json::from_str(&content)
+            .map_err(|e| anyhow!("Failed to parse manifest '{}': {}", path.display(), e))?;
+        Ok(manifest)
+    }
+
+    /// Write the manifest as pretty-printed JSON
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let content = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, content)
+            .map_err(|e| anyhow!("Failed to write manifest '{}': {}", path.display(), e))?;
+        Ok(())
+    }
+
+    /// Check that the embedded config matches the recorded hash
+    pub fn verify(&self) -> Result<()> {
+        let actual = hash_config(&self.config);
+        if actual != self.config_hash {
+            return Err(anyhow!(
+                "Manifest config hash mismatch (expected {}, got {})",
+                self.config_hash,
+                actual
+            ));
+        }
+        Ok(())
+    }
+
+    /// Recorded row counts keyed by table name
+    pub fn table_counts(&self) -> HashMap<String, usize> {
+        self.tables
+            .iter()
+            .map(|t| (t.name.clone(), t.count))
+            .collect()
+    }
+}
+
+/// Hash seed configuration contents
+pub fn hash_config(config: &str) -> String {
+    blake3::hash(config.as_bytes()).to_hex().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::seed::config::ColumnRule;
+
+    fn make_rule(name: &str, count: usize) -> SeedRule {
+        SeedRule {
+            name: name.to_string(),
+            count,
+            columns: vec![ColumnRule {
+                name: "id".to_string(),
+                generator: "sequential".to_string(),
+                options: HashMap::new(),
+            }],
+        }
+    }
+
+    #[test]
+    fn test_manifest_records_run() {
+        let rules = vec![make_rule("users", 100), make_rule("orders", 500)];
+        let manifest = SeedManifest::new("global_seed = 42", 42, 1000, "postgres", "mydb", &rules);
+
+        assert_eq!(manifest.global_seed, 42);
+        assert_eq!(manifest.generator_version, env!("CARGO_PKG_VERSION"));
+        assert_eq!(manifest.config_hash, hash_config("global_seed = 42"));
+        assert_eq!(manifest.tables.len(), 2);
+        assert_eq!(manifest.tables[1].generators[0].generator, "sequential");
+        assert_eq!(manifest.table_counts().get("orders"), Some(&500));
+        assert!(manifest.verify().is_ok());
+    }
+
+    #[test]
+    fn test_manifest_detects_tampering() {
+        let mut manifest = SeedManifest::new("batch_size = 10", 7, 10, "mysql", "db", &[]);
+        manifest.config.push_str("\nglobal_seed = 1");
+        assert!(manifest.verify().is_err());
+    }
+
+    #[test]
+    fn test_manifest_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(DEFAULT_MANIFEST_FILE);
+        let manifest = SeedManifest::new("x = 1", 3, 1000, "postgres", "db", &[make_rule("t", 5)]);
+
+        manifest.save(&path).unwrap();
+        let loaded = SeedManifest::load(&path).unwrap();
+
+        assert_eq!(loaded.config_hash, manifest.config_hash);
+        assert_eq!(loaded.tables[0].count, 5);
+    }
+}
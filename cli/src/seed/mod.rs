@@ -3,6 +3,8 @@ pub mod dependency;
 pub mod engine;
 pub mod foreign_key;
 pub mod generator;
+pub mod infer;
+pub mod manifest;
 pub mod models;
 pub mod presets;
 pub mod sql_builder;
@@ -12,5 +14,9 @@ pub use dependency::DependencyResolver;
 pub use engine::SeedingEngine;
 pub use foreign_key::ForeignKeyResolver;
 pub use generator::{DataGenerator, DataType, ForeignKeyInfo};
+pub use manifest::{SeedManifest, DEFAULT_MANIFEST_FILE};
 pub use models::{Row, SeedStats};
 pub use presets::SizePreset;
+pub use sql_builder::{
+    build_batch_insert, build_batch_insert_with_expressions, escape_ident, escape_literal,
+};
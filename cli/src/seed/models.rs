@@ -7,7 +7,10 @@ pub struct SeedStats {
     pub table: String,
     pub rows_inserted: usize,
     pub duration: Duration,
+    /// Combined throughput of all workers that inserted into the table
     pub rows_per_second: f64,
+    /// Concurrent exec sessions used for the table
+    pub workers: usize,
 }
 
 impl SeedStats {
@@ -23,8 +26,14 @@ impl SeedStats {
             rows_inserted,
             duration,
             rows_per_second,
+            workers: 1,
         }
     }
+
+    pub fn with_workers(mut self, workers: usize) -> Self {
+        self.workers = workers;
+        self
+    }
 }
 
 /// Represents a single row of data
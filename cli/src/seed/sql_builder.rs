@@ -1,27 +1,56 @@
 use crate::container::DatabaseType;
 use crate::seed::models::Row;
 use anyhow::Result;
+use std::collections::HashSet;
 
 /// Build batch INSERT statement optimized for the database type
+///
+/// Columns are listed in the order of `columns` and rows in the order given,
+/// so the same rows always produce byte-identical SQL.
 pub fn build_batch_insert(
     db_type: DatabaseType,
     table: &str,
     columns: &[String],
     rows: &[Row],
+) -> Result<String> {
+    build_batch_insert_with_expressions(db_type, table, columns, rows, &HashSet::new())
+}
+
+/// Build batch INSERT statement, emitting values of `expression_columns`
+/// verbatim as SQL expressions instead of escaped literals
+pub fn build_batch_insert_with_expressions(
+    db_type: DatabaseType,
+    table: &str,
+    columns: &[String],
+    rows: &[Row],
+    expression_columns: &HashSet<String>,
 ) -> Result<String> {
     if rows.is_empty() {
         return Ok(String::new());
     }
 
     match db_type {
-        DatabaseType::Postgres => build_postgres_insert(table, columns, rows),
-        DatabaseType::MySQL => build_mysql_insert(table, columns, rows),
-        DatabaseType::SQLServer => build_sqlserver_insert(table, columns, rows),
+        // SQLite takes the same double-quoted identifiers and literals
+        DatabaseType::Postgres | DatabaseType::CockroachDB | DatabaseType::SQLite => {
+            build_postgres_insert(table, columns, rows, expression_columns)
+        }
+        DatabaseType::MySQL => build_mysql_insert(table, columns, rows, expression_columns),
+        DatabaseType::SQLServer => {
+            build_sqlserver_insert(table, columns, rows, expression_columns)
+        }
+        DatabaseType::Redis | DatabaseType::MongoDB => {
+            Err(anyhow::anyhow!("Seeding is not supported for NoSQL databases"))
+        }
     }
 }
 
 /// Build PostgreSQL INSERT statement
-fn build_postgres_insert(table: &str, columns: &[String], rows: &[Row]) -> Result<String> {
+fn build_postgres_insert(
+    table: &str,
+    columns: &[String],
+    rows: &[Row],
+    expression_columns: &HashSet<String>,
+) -> Result<String> {
     let mut sql = format!(
         "INSERT INTO {} ({}) VALUES ",
         escape_identifier(table),
@@ -39,7 +68,13 @@ fn build_postgres_insert(table: &str, columns: &[String], rows: &[Row]) -> Resul
                 .iter()
                 .map(|col| {
                     row.get(col)
-                        .map(|v| escape_value(v))
+                        .map(|v| {
+                            if expression_columns.contains(col) {
+                                v.clone()
+                            } else {
+                                escape_value(v)
+                            }
+                        })
                         .unwrap_or_else(|| "NULL".to_string())
                 })
                 .collect::<Vec<_>>()
@@ -55,7 +90,12 @@ fn build_postgres_insert(table: &str, columns: &[String], rows: &[Row]) -> Resul
 }
 
 /// Build MySQL INSERT statement
-fn build_mysql_insert(table: &str, columns: &[String], rows: &[Row]) -> Result<String> {
+fn build_mysql_insert(
+    table: &str,
+    columns: &[String],
+    rows: &[Row],
+    expression_columns: &HashSet<String>,
+) -> Result<String> {
     // MySQL uses similar syntax to PostgreSQL for multi-row inserts
     let mut sql = format!(
         "INSERT INTO {} ({}) VALUES ",
@@ -74,7 +114,13 @@ fn build_mysql_insert(table: &str, columns: &[String], rows: &[Row]) -> Result<S
                 .iter()
                 .map(|col| {
                     row.get(col)
-                        .map(|v| escape_value(v))
+                        .map(|v| {
+                            if expression_columns.contains(col) {
+                                v.clone()
+                            } else {
+                                escape_value_mysql(v)
+                            }
+                        })
                         .unwrap_or_else(|| "NULL".to_string())
                 })
                 .collect::<Vec<_>>()
@@ -90,7 +136,12 @@ fn build_mysql_insert(table: &str, columns: &[String], rows: &[Row]) -> Result<S
 }
 
 /// Build SQL Server INSERT statement
-fn build_sqlserver_insert(table: &str, columns: &[String], rows: &[Row]) -> Result<String> {
+fn build_sqlserver_insert(
+    table: &str,
+    columns: &[String],
+    rows: &[Row],
+    expression_columns: &HashSet<String>,
+) -> Result<String> {
     let mut sql = format!(
         "INSERT INTO {} ({}) VALUES ",
         escape_identifier_sqlserver(table),
@@ -108,7 +159,13 @@ fn build_sqlserver_insert(table: &str, columns: &[String], rows: &[Row]) -> Resu
                 .iter()
                 .map(|col| {
                     row.get(col)
-                        .map(|v| escape_value(v))
+                        .map(|v| {
+                            if expression_columns.contains(col) {
+                                v.clone()
+                            } else {
+                                escape_value_sqlserver(v)
+                            }
+                        })
                         .unwrap_or_else(|| "NULL".to_string())
                 })
                 .collect::<Vec<_>>()
@@ -123,6 +180,25 @@ fn build_sqlserver_insert(table: &str, columns: &[String], rows: &[Row]) -> Resu
     Ok(sql)
 }
 
+/// Quote `value` as a SQL literal the way the batch INSERTs for `db_type` do
+/// (numbers, booleans and `NULL` stay bare)
+pub fn escape_literal(db_type: DatabaseType, value: &str) -> String {
+    match db_type {
+        DatabaseType::MySQL => escape_value_mysql(value),
+        DatabaseType::SQLServer => escape_value_sqlserver(value),
+        _ => escape_value(value),
+    }
+}
+
+/// Quote `name` as an identifier the way the batch INSERTs for `db_type` do
+pub fn escape_ident(db_type: DatabaseType, name: &str) -> String {
+    match db_type {
+        DatabaseType::MySQL => escape_identifier_mysql(name),
+        DatabaseType::SQLServer => escape_identifier_sqlserver(name),
+        _ => escape_identifier(name),
+    }
+}
+
 /// Escape identifier for PostgreSQL (double quotes)
 fn escape_identifier(name: &str) -> String {
     format!("\"{}\"", name.replace('"', "\"\""))
@@ -138,26 +214,87 @@ fn escape_identifier_sqlserver(name: &str) -> String {
     format!("[{}]", name.replace(']', "]]"))
 }
 
-/// Escape value (for all databases)
-fn escape_value(value: &str) -> String {
-    // Try parsing as number or boolean - no quotes needed
-    if value.parse::<i64>().is_ok()
-        || value.parse::<f64>().is_ok()
+/// Whether `value` goes into SQL unquoted: a number, boolean or NULL
+fn is_bare(value: &str) -> bool {
+    is_numeric_literal(value)
         || value.eq_ignore_ascii_case("true")
         || value.eq_ignore_ascii_case("false")
         || value.eq_ignore_ascii_case("null")
-    {
+}
+
+/// Whether `value` is a number SQL reads back unchanged
+///
+/// Rust also parses `NaN` and `inf`, which SQL would take for column names,
+/// and a text column would drop the `+` or leading zeros of `+1` or `007`.
+fn is_numeric_literal(value: &str) -> bool {
+    let unsigned = value.strip_prefix('-').unwrap_or(value);
+    let leading_zero = unsigned.len() > 1
+        && unsigned.starts_with('0')
+        && unsigned.as_bytes()[1].is_ascii_digit();
+
+    !leading_zero
+        && unsigned.starts_with(|c: char| c.is_ascii_digit() || c == '.')
+        && value
+            .chars()
+            .all(|c| c.is_ascii_digit() || matches!(c, '.' | '-' | '+' | 'e' | 'E'))
+        && value.parse::<f64>().is_ok()
+}
+
+/// Escape value for PostgreSQL, CockroachDB and SQLite
+///
+/// Quotes are doubled and backslashes kept as written (standard strings).
+/// NUL characters are dropped: Postgres text can't store them, and they can't
+/// be passed on a client's command line.
+fn escape_value(value: &str) -> String {
+    if is_bare(value) {
         return value.to_string();
     }
+    format!("'{}'", value.replace('\'', "''").replace('\0', ""))
+}
 
-    // Check if it's a timestamp in RFC3339 format
-    if value.contains('T') && (value.contains('Z') || value.contains('+') || value.contains('-')) {
-        // Likely a timestamp, wrap in quotes
-        return format!("'{}'", value.replace('\'', "''"));
+/// Escape value for MySQL, whose string literals treat backslashes as escapes
+/// (JSON's `\"` would otherwise lose its backslash)
+fn escape_value_mysql(value: &str) -> String {
+    if is_bare(value) {
+        return value.to_string();
     }
+    let escaped = value
+        .replace('\\', "\\\\")
+        .replace('\'', "''")
+        .replace('\0', "\\0");
+    format!("'{}'", escaped)
+}
 
-    // String value - escape single quotes
-    format!("'{}'", value.replace('\'', "''"))
+/// Escape value for SQL Server, prefixing non-ASCII strings with N so
+/// nvarchar columns keep their characters
+///
+/// sqlcmd expands `$(name)` scripting variables even inside quotes, so the
+/// literal is split after the `$`; NUL characters are concatenated in as
+/// `CHAR(0)`.
+fn escape_value_sqlserver(value: &str) -> String {
+    if is_bare(value) {
+        return value.to_string();
+    }
+    let open = if value.is_ascii() { "'" } else { "N'" };
+
+    let mut sql = open.to_string();
+    for c in value.chars() {
+        match c {
+            '\'' => sql.push_str("''"),
+            '\0' => {
+                sql.push_str("' + CHAR(0) + ");
+                sql.push_str(open);
+            }
+            '(' if sql.ends_with('$') => {
+                sql.push_str("' + ");
+                sql.push_str(open);
+                sql.push('(');
+            }
+            c => sql.push(c),
+        }
+    }
+    sql.push('\'');
+    sql
 }
 
 #[cfg(test)]
@@ -176,7 +313,7 @@ mod tests {
         let columns = vec!["id".to_string(), "name".to_string()];
         let rows = vec![make_row(vec![("id", "1"), ("name", "Alice")])];
 
-        let sql = build_postgres_insert("users", &columns, &rows).unwrap();
+        let sql = build_postgres_insert("users", &columns, &rows, &HashSet::new()).unwrap();
         assert_eq!(sql, "INSERT INTO \"users\" (\"id\", \"name\") VALUES (1, 'Alice');");
     }
 
@@ -188,7 +325,7 @@ mod tests {
             make_row(vec![("id", "2"), ("name", "Bob")]),
         ];
 
-        let sql = build_postgres_insert("users", &columns, &rows).unwrap();
+        let sql = build_postgres_insert("users", &columns, &rows, &HashSet::new()).unwrap();
         assert_eq!(
             sql,
             "INSERT INTO \"users\" (\"id\", \"name\") VALUES (1, 'Alice'), (2, 'Bob');"
@@ -200,7 +337,7 @@ mod tests {
         let columns = vec!["id".to_string(), "name".to_string()];
         let rows = vec![make_row(vec![("id", "1"), ("name", "Alice")])];
 
-        let sql = build_mysql_insert("users", &columns, &rows).unwrap();
+        let sql = build_mysql_insert("users", &columns, &rows, &HashSet::new()).unwrap();
         assert_eq!(sql, "INSERT INTO `users` (`id`, `name`) VALUES (1, 'Alice');");
     }
 
@@ -209,10 +346,35 @@ mod tests {
         let columns = vec!["id".to_string(), "name".to_string()];
         let rows = vec![make_row(vec![("id", "1"), ("name", "Alice")])];
 
-        let sql = build_sqlserver_insert("users", &columns, &rows).unwrap();
+        let sql = build_sqlserver_insert("users", &columns, &rows, &HashSet::new()).unwrap();
         assert_eq!(sql, "INSERT INTO [users] ([id], [name]) VALUES (1, 'Alice');");
     }
 
+    #[test]
+    fn test_missing_value_is_unquoted_null() {
+        let columns = vec!["id".to_string(), "name".to_string()];
+        let rows = vec![make_row(vec![("id", "1")])];
+
+        let sql = build_postgres_insert("users", &columns, &rows, &HashSet::new()).unwrap();
+        assert_eq!(sql, "INSERT INTO \"users\" (\"id\", \"name\") VALUES (1, NULL);");
+    }
+
+    #[test]
+    fn test_json_values_per_database() {
+        let json = r#"{"bio":"it's \"quoted\"","city":"Zürich"}"#;
+
+        assert_eq!(escape_value(json), r#"'{"bio":"it''s \"quoted\"","city":"Zürich"}'"#);
+        assert_eq!(
+            escape_value_mysql(json),
+            r#"'{"bio":"it''s \\"quoted\\"","city":"Zürich"}'"#
+        );
+        assert_eq!(
+            escape_value_sqlserver(json),
+            r#"N'{"bio":"it''s \"quoted\"","city":"Zürich"}'"#
+        );
+        assert_eq!(escape_value_sqlserver("Alice"), "'Alice'");
+    }
+
     #[test]
     fn test_escape_value_numbers() {
         assert_eq!(escape_value("42"), "42");
@@ -233,6 +395,51 @@ mod tests {
         assert_eq!(escape_value("hello"), "'hello'");
         assert_eq!(escape_value("it's"), "'it''s'");
         assert_eq!(escape_value("O'Brien"), "'O''Brien'");
+        // UUIDs are quoted so Postgres casts them to uuid columns
+        assert_eq!(
+            escape_value("0b8e6f3a-5c1d-4e2f-9a7b-3c4d5e6f7a8b"),
+            "'0b8e6f3a-5c1d-4e2f-9a7b-3c4d5e6f7a8b'"
+        );
+    }
+
+    #[test]
+    fn test_escape_value_numbers_sql_reads_back() {
+        assert_eq!(escape_value("1e5"), "1e5");
+        assert_eq!(escape_value("0.5"), "0.5");
+        assert_eq!(escape_value("0"), "0");
+        // Names and codes that Rust would parse as numbers
+        assert_eq!(escape_value("Nan"), "'Nan'");
+        assert_eq!(escape_value("inf"), "'inf'");
+        assert_eq!(escape_value("Infinity"), "'Infinity'");
+        assert_eq!(escape_value("007"), "'007'");
+        assert_eq!(escape_value("-0123"), "'-0123'");
+        assert_eq!(escape_value("+15550100"), "'+15550100'");
+    }
+
+    #[test]
+    fn test_adversarial_strings_per_database() {
+        let cases = [
+            ("O'Brien", "'O''Brien'", "'O''Brien'", "'O''Brien'"),
+            ("a\\b", "'a\\b'", "'a\\\\b'", "'a\\b'"),
+            ("it\\'s", "'it\\''s'", "'it\\\\''s'", "'it\\''s'"),
+            ("line1\nline2", "'line1\nline2'", "'line1\nline2'", "'line1\nline2'"),
+            (
+                "''; DROP TABLE t; --",
+                "'''''; DROP TABLE t; --'",
+                "'''''; DROP TABLE t; --'",
+                "'''''; DROP TABLE t; --'",
+            ),
+            ("a\0b", "'ab'", "'a\\0b'", "'a' + CHAR(0) + 'b'"),
+            ("$(PATH)", "'$(PATH)'", "'$(PATH)'", "'$' + '(PATH)'"),
+            ("€$(x)", "'€$(x)'", "'€$(x)'", "N'€$' + N'(x)'"),
+        ];
+
+        for (value, postgres, mysql, sqlserver) in cases {
+            assert_eq!(escape_literal(DatabaseType::Postgres, value), postgres, "{:?}", value);
+            assert_eq!(escape_literal(DatabaseType::SQLite, value), postgres, "{:?}", value);
+            assert_eq!(escape_literal(DatabaseType::MySQL, value), mysql, "{:?}", value);
+            assert_eq!(escape_literal(DatabaseType::SQLServer, value), sqlserver, "{:?}", value);
+        }
     }
 
     #[test]
@@ -270,6 +477,34 @@ mod tests {
         assert!(mssql_sql.contains("[users]"));
     }
 
+    #[test]
+    fn test_column_and_row_order_is_stable() {
+        // More columns than a HashMap keeps in insertion order
+        let columns: Vec<String> = ["z", "y", "x", "w", "v", "u", "t", "s"]
+            .iter()
+            .map(|c| c.to_string())
+            .collect();
+        let rows: Vec<Row> = (0..3)
+            .map(|i| columns.iter().map(|c| (c.clone(), format!("{}{}", c, i))).collect())
+            .collect();
+
+        let sql = build_batch_insert(DatabaseType::Postgres, "t", &columns, &rows).unwrap();
+        assert!(sql.starts_with(
+            "INSERT INTO \"t\" (\"z\", \"y\", \"x\", \"w\", \"v\", \"u\", \"t\", \"s\") VALUES ('z0', 'y0', "
+        ));
+        assert!(sql.ends_with("('z2', 'y2', 'x2', 'w2', 'v2', 'u2', 't2', 's2');"));
+
+        // Rebuilding the rows in a different insertion order changes nothing
+        let reversed: Vec<Row> = rows
+            .iter()
+            .map(|row| columns.iter().rev().map(|c| (c.clone(), row[c].clone())).collect())
+            .collect();
+        assert_eq!(
+            build_batch_insert(DatabaseType::Postgres, "t", &columns, &reversed).unwrap(),
+            sql
+        );
+    }
+
     #[test]
     fn test_empty_rows() {
         let columns = vec!["id".to_string()];
@@ -278,4 +513,27 @@ mod tests {
         let sql = build_batch_insert(DatabaseType::Postgres, "users", &columns, &rows).unwrap();
         assert_eq!(sql, "");
     }
+
+    #[test]
+    fn test_expression_columns_not_escaped() {
+        let columns = vec!["id".to_string(), "location".to_string()];
+        let rows = vec![make_row(vec![
+            ("id", "1"),
+            ("location", "ST_SetSRID(ST_MakePoint(-73.9,40.7),4326)"),
+        ])];
+        let expressions = HashSet::from(["location".to_string()]);
+
+        let sql = build_batch_insert_with_expressions(
+            DatabaseType::Postgres,
+            "places",
+            &columns,
+            &rows,
+            &expressions,
+        )
+        .unwrap();
+        assert_eq!(
+            sql,
+            "INSERT INTO \"places\" (\"id\", \"location\") VALUES (1, ST_SetSRID(ST_MakePoint(-73.9,40.7),4326));"
+        );
+    }
 }
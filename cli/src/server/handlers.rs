@@ -0,0 +1,292 @@
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use axum::Json;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+
+use super::{ApiResult, ApiState};
+use crate::config::load_or_default;
+use crate::container::{Container, DatabaseType};
+use crate::health::{checker_for, wait_for_healthy};
+use crate::init::expand_init_scripts;
+use crate::monitoring::{ContainerMetrics, DockerStatsCollector, MetricsCollector};
+use crate::orchestrator::{create_database, CreateOptions};
+use crate::progress::NoProgress;
+use crate::seed::{SeedConfig, SeedingEngine};
+use crate::workload::{LatencyBucket, WorkloadConfig, WorkloadEngine};
+use crate::DBArenaError;
+
+const DEFAULT_HEALTH_TIMEOUT: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Deserialize)]
+pub struct ListQuery {
+    #[serde(default)]
+    all: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateRequest {
+    database: String,
+    version: Option<String>,
+    name: Option<String>,
+    port: Option<u16>,
+    #[serde(default)]
+    persistent: bool,
+    memory_mb: Option<u64>,
+    cpu_shares: Option<u64>,
+    #[serde(default)]
+    env: HashMap<String, String>,
+    profile: Option<String>,
+    /// Script files or directories on the server's filesystem
+    #[serde(default)]
+    init_scripts: Vec<PathBuf>,
+    #[serde(default)]
+    reuse: bool,
+    #[serde(default)]
+    recreate: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StopRequest {
+    timeout: Option<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DestroyQuery {
+    #[serde(default)]
+    volumes: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SeedRequest {
+    /// Seed configuration as TOML (same format as `dbarena seed --config`)
+    config: String,
+    seed: Option<u64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SeedTableResult {
+    table: String,
+    rows_inserted: usize,
+    duration_secs: f64,
+    rows_per_second: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct WorkloadResult {
+    total: u64,
+    success: u64,
+    failed: u64,
+    success_rate: f64,
+    elapsed_secs: f64,
+    tps: f64,
+    p50_us: Option<u64>,
+    p95_us: Option<u64>,
+    p99_us: Option<u64>,
+    latency_buckets: Vec<LatencyBucket>,
+    operation_counts: HashMap<String, u64>,
+    error_counts: HashMap<String, u64>,
+}
+
+pub async fn health() -> Json<serde_json::Value> {
+    Json(serde_json::json!({ "status": "ok", "version": env!("CARGO_PKG_VERSION") }))
+}
+
+pub async fn list_containers(
+    State(state): State<Arc<ApiState>>,
+    Query(query): Query<ListQuery>,
+) -> ApiResult<Json<Vec<Container>>> {
+    Ok(Json(state.manager.list_containers(query.all).await?))
+}
+
+pub async fn get_container(
+    State(state): State<Arc<ApiState>>,
+    Path(name): Path<String>,
+) -> ApiResult<Json<Container>> {
+    Ok(Json(find(&state, &name).await?))
+}
+
+/// Create and start a container the way `dbarena create` does, returning once
+/// the database is healthy and its init scripts have run
+pub async fn create_container(
+    State(state): State<Arc<ApiState>>,
+    Json(request): Json<CreateRequest>,
+) -> ApiResult<(StatusCode, Json<Container>)> {
+    let mut options = CreateOptions::new(parse_database(&request.database)?);
+    options.version = request.version;
+    options.name = request.name;
+    options.port = request.port;
+    options.persistent = request.persistent;
+    options.memory = request.memory_mb;
+    options.cpu_shares = request.cpu_shares;
+    options.env = request.env;
+    options.profile = request.profile;
+    options.init_scripts = expand_init_scripts(&request.init_scripts)?;
+    options.reuse = request.reuse;
+    options.recreate = request.recreate;
+
+    let file_config = load_or_default(None)?;
+    let container = create_database(&state.manager, &file_config, options, &|_| {}).await?;
+
+    Ok((StatusCode::CREATED, Json(find(&state, &container.id).await?)))
+}
+
+/// Start a container, returning once the database is healthy
+pub async fn start_container(
+    State(state): State<Arc<ApiState>>,
+    Path(name): Path<String>,
+) -> ApiResult<Json<Container>> {
+    let container = find(&state, &name).await?;
+    let id = state.manager.start_container(&container.id).await?;
+
+    let database = parse_database(&container.database_type)?;
+    let checker = checker_for(database, state.docker.docker().clone());
+    wait_for_healthy(&id, checker.as_ref(), DEFAULT_HEALTH_TIMEOUT, &NoProgress).await?;
+
+    Ok(Json(find(&state, &id).await?))
+}
+
+pub async fn stop_container(
+    State(state): State<Arc<ApiState>>,
+    Path(name): Path<String>,
+    body: Option<Json<StopRequest>>,
+) -> ApiResult<Json<Container>> {
+    let container = find(&state, &name).await?;
+    let timeout = body.and_then(|Json(b)| b.timeout);
+    state.manager.stop_container(&container.id, timeout).await?;
+
+    Ok(Json(find(&state, &container.id).await?))
+}
+
+pub async fn destroy_container(
+    State(state): State<Arc<ApiState>>,
+    Path(name): Path<String>,
+    Query(query): Query<DestroyQuery>,
+) -> ApiResult<StatusCode> {
+    let container = find(&state, &name).await?;
+    state
+        .manager
+        .destroy_container(&container.id, query.volumes)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub async fn container_stats(
+    State(state): State<Arc<ApiState>>,
+    Path(name): Path<String>,
+) -> ApiResult<Json<ContainerMetrics>> {
+    let container = find(&state, &name).await?;
+    let collector = DockerStatsCollector::new(Arc::new(state.docker.docker().clone()));
+
+    Ok(Json(collector.collect(&container.id).await?))
+}
+
+pub async fn seed_container(
+    State(state): State<Arc<ApiState>>,
+    Path(name): Path<String>,
+    Json(request): Json<SeedRequest>,
+) -> ApiResult<Json<Vec<SeedTableResult>>> {
+    let container = find(&state, &name).await?;
+    let db_type = parse_sql_database(&container, "Seeding")?;
+
+    let config: SeedConfig = toml::from_str(&request.config)
+        .map_err(|e| DBArenaError::ConfigError(format!("Failed to parse seed config: {}", e)))?;
+
+    let mut engine = SeedingEngine::new(
+        container.id.clone(),
+        db_type,
+        Arc::new(state.docker.docker().clone()),
+        request.seed.or(config.global_seed).unwrap_or(42),
+        config.batch_size,
+    )
+    .with_progress(false);
+
+    let stats = engine
+        .seed_all(config.seed_rules.tables())
+        .await
+        .map_err(|e| DBArenaError::Other(e.to_string()))?;
+
+    Ok(Json(
+        stats
+            .into_iter()
+            .map(|s| SeedTableResult {
+                table: s.table,
+                rows_inserted: s.rows_inserted,
+                duration_secs: s.duration.as_secs_f64(),
+                rows_per_second: s.rows_per_second,
+            })
+            .collect(),
+    ))
+}
+
+/// Run a workload to completion and return its summary
+pub async fn run_workload(
+    State(state): State<Arc<ApiState>>,
+    Path(name): Path<String>,
+    Json(mut config): Json<WorkloadConfig>,
+) -> ApiResult<Json<WorkloadResult>> {
+    let container = find(&state, &name).await?;
+    let db_type = parse_sql_database(&container, "Workloads")?;
+
+    if config.tables.is_empty() {
+        return Err(DBArenaError::InvalidConfig("No tables specified".to_string()).into());
+    }
+    if config.duration_seconds.is_none() && config.transaction_count.is_none() {
+        config.duration_seconds = Some(60);
+    }
+
+    let engine = WorkloadEngine::new(
+        container.id.clone(),
+        db_type,
+        config,
+        Arc::new(state.docker.docker().clone()),
+    );
+    let snapshot = engine
+        .run()
+        .await
+        .map_err(|e| DBArenaError::Other(format!("Workload failed: {}", e)))?
+        .snapshot();
+
+    Ok(Json(WorkloadResult {
+        total: snapshot.total,
+        success: snapshot.success,
+        failed: snapshot.failed,
+        success_rate: snapshot.success_rate,
+        elapsed_secs: snapshot.elapsed.as_secs_f64(),
+        tps: snapshot.tps,
+        p50_us: snapshot.p50,
+        p95_us: snapshot.p95,
+        p99_us: snapshot.p99,
+        latency_buckets: snapshot.latency_buckets,
+        operation_counts: snapshot.operation_counts,
+        error_counts: snapshot.error_counts,
+    }))
+}
+
+async fn find(state: &ApiState, name: &str) -> crate::Result<Container> {
+    state
+        .manager
+        .find_container(name)
+        .await?
+        .ok_or_else(|| DBArenaError::ContainerNotFound(name.to_string()))
+}
+
+fn parse_database(database: &str) -> crate::Result<DatabaseType> {
+    DatabaseType::from_string(database)
+        .ok_or_else(|| DBArenaError::InvalidConfig(format!("Unknown database type: {}", database)))
+}
+
+fn parse_sql_database(container: &Container, operation: &str) -> crate::Result<DatabaseType> {
+    let db_type = parse_database(&container.database_type)?;
+    if !db_type.is_sql() {
+        return Err(DBArenaError::InvalidConfig(format!(
+            "{} is not supported for NoSQL databases ({})",
+            operation, db_type
+        )));
+    }
+    Ok(db_type)
+}
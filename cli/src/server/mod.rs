@@ -0,0 +1,121 @@
+//! HTTP control API
+//!
+//! Exposes container lifecycle, stats, seeding and workloads as a small
+//! JSON API so other processes (IDE plugins, dashboards) can drive dbarena
+//! without shelling out.
+
+mod handlers;
+
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tracing::info;
+
+use crate::container::{ContainerManager, DockerClient};
+use crate::{DBArenaError, Result};
+
+/// Shared state for API handlers
+pub struct ApiState {
+    pub docker: DockerClient,
+    pub manager: ContainerManager,
+}
+
+/// Error returned as `{"error": "..."}` with a status derived from the error kind
+pub struct ApiError(DBArenaError);
+
+impl From<DBArenaError> for ApiError {
+    fn from(err: DBArenaError) -> Self {
+        Self(err)
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let status = match &self.0 {
+            DBArenaError::ContainerNotFound(_) => StatusCode::NOT_FOUND,
+            DBArenaError::InvalidConfig(_)
+            | DBArenaError::ConfigError(_)
+            | DBArenaError::ImageNotFound(_) => StatusCode::BAD_REQUEST,
+            DBArenaError::PortInUse(_)
+            | DBArenaError::PortOccupied(_, _)
+            | DBArenaError::NameInUse { .. } => StatusCode::CONFLICT,
+            DBArenaError::HealthCheckTimeout(_) => StatusCode::GATEWAY_TIMEOUT,
+            DBArenaError::DockerNotAvailable | DBArenaError::DockerConnectionFailed(_, _) => {
+                StatusCode::SERVICE_UNAVAILABLE
+            }
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        };
+
+        (status, Json(serde_json::json!({ "error": self.0.to_string() }))).into_response()
+    }
+}
+
+pub type ApiResult<T> = std::result::Result<T, ApiError>;
+
+/// Build the API router
+pub fn router(state: Arc<ApiState>) -> Router {
+    Router::new()
+        .route("/api/v1/health", get(handlers::health))
+        .route(
+            "/api/v1/containers",
+            get(handlers::list_containers).post(handlers::create_container),
+        )
+        .route(
+            "/api/v1/containers/:name",
+            get(handlers::get_container).delete(handlers::destroy_container),
+        )
+        .route("/api/v1/containers/:name/start", post(handlers::start_container))
+        .route("/api/v1/containers/:name/stop", post(handlers::stop_container))
+        .route("/api/v1/containers/:name/stats", get(handlers::container_stats))
+        .route("/api/v1/containers/:name/seed", post(handlers::seed_container))
+        .route("/api/v1/containers/:name/workload", post(handlers::run_workload))
+        .with_state(state)
+}
+
+/// Serve the API until the process is interrupted
+pub async fn serve(addr: SocketAddr) -> Result<()> {
+    let docker = DockerClient::new()?;
+    docker.verify_connection().await?;
+
+    let state = Arc::new(ApiState {
+        manager: ContainerManager::new(docker.clone()),
+        docker,
+    });
+
+    let listener = tokio::net::TcpListener::bind(addr).await?;
+    info!("API listening on {}", addr);
+
+    axum::serve(listener, router(state))
+        .with_graceful_shutdown(async {
+            let _ = tokio::signal::ctrl_c().await;
+        })
+        .await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_api_error_status() {
+        let status = |err: DBArenaError| ApiError::from(err).into_response().status();
+
+        assert_eq!(
+            status(DBArenaError::ContainerNotFound("db".to_string())),
+            StatusCode::NOT_FOUND
+        );
+        assert_eq!(
+            status(DBArenaError::InvalidConfig("bad".to_string())),
+            StatusCode::BAD_REQUEST
+        );
+        assert_eq!(
+            status(DBArenaError::Other("boom".to_string())),
+            StatusCode::INTERNAL_SERVER_ERROR
+        );
+    }
+}
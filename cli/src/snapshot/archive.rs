@@ -0,0 +1,171 @@
+//! Snapshot archives for moving snapshots between machines
+//!
+//! An archive is a tarball (gzipped when the path ends in `.gz` or `.tgz`) holding the
+//! snapshot's metadata as JSON and its image in `docker save` format.
+
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::path::Path;
+use tar::{Archive, Builder, Header};
+
+use super::metadata::Snapshot;
+use crate::error::{DBArenaError, Result};
+
+/// Archive entry holding the snapshot metadata
+pub const METADATA_ENTRY: &str = "snapshot.json";
+/// Archive entry holding the saved image
+pub const IMAGE_ENTRY: &str = "image.tar";
+
+/// Whether an archive at `path` should be gzipped
+pub fn is_gzip_path(path: &Path) -> bool {
+    let name = path.to_string_lossy().to_lowercase();
+    name.ends_with(".gz") || name.ends_with(".tgz")
+}
+
+/// Write `snapshot`'s metadata and the saved image at `image_path` to an archive
+pub fn write_archive(path: &Path, snapshot: &Snapshot, image_path: &Path) -> Result<()> {
+    let file = File::create(path)?;
+    if is_gzip_path(path) {
+        let mut builder = Builder::new(GzEncoder::new(file, Compression::default()));
+        append_entries(&mut builder, snapshot, image_path)?;
+        builder.into_inner()?.finish()?;
+    } else {
+        let mut builder = Builder::new(file);
+        append_entries(&mut builder, snapshot, image_path)?;
+        builder.into_inner()?.flush()?;
+    }
+    Ok(())
+}
+
+fn append_entries<W: Write>(
+    builder: &mut Builder<W>,
+    snapshot: &Snapshot,
+    image_path: &Path,
+) -> Result<()> {
+    let metadata = serde_json::to_vec_pretty(snapshot)?;
+    let mut header = Header::new_gnu();
+    header.set_size(metadata.len() as u64);
+    header.set_mode(0o644);
+    header.set_mtime(snapshot.created_at.max(0) as u64);
+    header.set_cksum();
+    builder.append_data(&mut header, METADATA_ENTRY, metadata.as_slice())?;
+
+    builder.append_path_with_name(image_path, IMAGE_ENTRY)?;
+    Ok(())
+}
+
+/// Read the metadata from an archive, copying its saved image to `image`; gzip is
+/// detected from the archive's contents
+pub fn read_archive<W: Write>(path: &Path, image: &mut W) -> Result<Snapshot> {
+    let mut reader = BufReader::new(File::open(path)?);
+    let gzipped = reader.fill_buf()?.starts_with(&[0x1f, 0x8b]);
+    if gzipped {
+        read_entries(Archive::new(GzDecoder::new(reader)), image)
+    } else {
+        read_entries(Archive::new(reader), image)
+    }
+}
+
+fn read_entries<R: Read, W: Write>(mut archive: Archive<R>, image: &mut W) -> Result<Snapshot> {
+    let mut snapshot = None;
+    let mut has_image = false;
+
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        let name = entry.path()?.to_string_lossy().to_string();
+        match name.as_str() {
+            METADATA_ENTRY => {
+                let mut json = String::new();
+                entry.read_to_string(&mut json)?;
+                snapshot = Some(serde_json::from_str::<Snapshot>(&json).map_err(|e| {
+                    DBArenaError::SnapshotError(format!("Invalid snapshot metadata: {}", e))
+                })?);
+            }
+            IMAGE_ENTRY => {
+                std::io::copy(&mut entry, image)?;
+                has_image = true;
+            }
+            _ => {}
+        }
+    }
+
+    let missing = |entry: &str| {
+        DBArenaError::SnapshotError(format!("Not a snapshot archive: no {} entry", entry))
+    };
+    let snapshot = snapshot.ok_or_else(|| missing(METADATA_ENTRY))?;
+    if !has_image {
+        return Err(missing(IMAGE_ENTRY));
+    }
+    image.flush()?;
+    Ok(snapshot)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::container::DatabaseType;
+
+    #[test]
+    fn test_archive_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let image_path = dir.path().join("image.tar");
+        std::fs::write(&image_path, b"saved image bytes").unwrap();
+
+        let snapshot = Snapshot::new(
+            "nightly".to_string(),
+            "container-123".to_string(),
+            DatabaseType::Postgres,
+            Some("before migration".to_string()),
+        )
+        .with_parent("base-id".to_string());
+
+        for file in ["nightly.tar", "nightly.tar.gz"] {
+            let path = dir.path().join(file);
+            write_archive(&path, &snapshot, &image_path).unwrap();
+
+            let mut image = Vec::new();
+            let read = read_archive(&path, &mut image).unwrap();
+            assert_eq!(read.id, snapshot.id);
+            assert_eq!(read.name, "nightly");
+            assert_eq!(read.message.as_deref(), Some("before migration"));
+            assert_eq!(read.database_type, DatabaseType::Postgres);
+            assert_eq!(read.created_at, snapshot.created_at);
+            assert_eq!(read.image_tag, snapshot.image_tag);
+            assert_eq!(read.parent.as_deref(), Some("base-id"));
+            assert_eq!(image, b"saved image bytes");
+        }
+
+        assert!(!is_gzip_path(&dir.path().join("nightly.tar")));
+        assert!(is_gzip_path(&dir.path().join("nightly.tgz")));
+    }
+
+    #[test]
+    fn test_read_archive_requires_both_entries() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("metadata-only.tar");
+        let snapshot = Snapshot::new(
+            "nightly".to_string(),
+            "container-123".to_string(),
+            DatabaseType::MySQL,
+            None,
+        );
+        let metadata = serde_json::to_vec(&snapshot).unwrap();
+
+        let mut builder = Builder::new(File::create(&path).unwrap());
+        let mut header = Header::new_gnu();
+        header.set_size(metadata.len() as u64);
+        header.set_cksum();
+        builder
+            .append_data(&mut header, METADATA_ENTRY, metadata.as_slice())
+            .unwrap();
+        builder.finish().unwrap();
+
+        let err = read_archive(&path, &mut Vec::new())
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains(IMAGE_ENTRY), "{}", err);
+    }
+}
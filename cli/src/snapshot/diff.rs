@@ -0,0 +1,191 @@
+use serde::Serialize;
+
+use super::metadata::Snapshot;
+use crate::container::DatabaseType;
+
+/// One snapshot's side of a diff: its metadata plus details read from the image
+#[derive(Debug, Clone, Serialize)]
+pub struct SnapshotSide {
+    pub id: String,
+    pub name: String,
+    pub database_type: DatabaseType,
+    /// Database version, from the `dbarena.version` label the image inherits
+    pub version: Option<String>,
+    pub created_at: i64,
+    /// Image size in bytes
+    pub size: Option<i64>,
+    pub message: Option<String>,
+}
+
+impl SnapshotSide {
+    pub fn new(snapshot: &Snapshot, version: Option<String>, size: Option<i64>) -> Self {
+        Self {
+            id: snapshot.id.clone(),
+            name: snapshot.name.clone(),
+            database_type: snapshot.database_type,
+            version,
+            created_at: snapshot.created_at,
+            size,
+            message: snapshot.message.clone(),
+        }
+    }
+}
+
+/// A compared field, rendered for display
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FieldDiff {
+    pub field: &'static str,
+    pub a: String,
+    pub b: String,
+    pub changed: bool,
+}
+
+/// Metadata comparison between two snapshots
+#[derive(Debug, Clone, Serialize)]
+pub struct SnapshotDiff {
+    pub a: SnapshotSide,
+    pub b: SnapshotSide,
+    /// False when the snapshots come from different database types
+    pub comparable: bool,
+    /// `b.size - a.size`, when both sizes are known
+    pub size_delta: Option<i64>,
+    /// Seconds between the two snapshots (`b - a`)
+    pub created_delta_secs: i64,
+    pub fields: Vec<FieldDiff>,
+}
+
+impl SnapshotDiff {
+    pub fn between(a: SnapshotSide, b: SnapshotSide) -> Self {
+        let field = |field: &'static str, a: String, b: String| FieldDiff {
+            field,
+            changed: a != b,
+            a,
+            b,
+        };
+        let fields = vec![
+            field(
+                "database",
+                a.database_type.to_string(),
+                b.database_type.to_string(),
+            ),
+            field("version", or_dash(&a.version), or_dash(&b.version)),
+            field("created", format_time(a.created_at), format_time(b.created_at)),
+            field(
+                "size",
+                a.size.map(format_size).unwrap_or_else(|| "-".to_string()),
+                b.size.map(format_size).unwrap_or_else(|| "-".to_string()),
+            ),
+            field("message", or_dash(&a.message), or_dash(&b.message)),
+        ];
+
+        Self {
+            comparable: a.database_type == b.database_type,
+            size_delta: a.size.zip(b.size).map(|(a, b)| b - a),
+            created_delta_secs: b.created_at - a.created_at,
+            fields,
+            a,
+            b,
+        }
+    }
+}
+
+fn or_dash(value: &Option<String>) -> String {
+    value.clone().unwrap_or_else(|| "-".to_string())
+}
+
+fn format_time(timestamp: i64) -> String {
+    chrono::DateTime::from_timestamp(timestamp, 0)
+        .map(|dt| dt.format("%Y-%m-%d %H:%M:%S").to_string())
+        .unwrap_or_else(|| "Unknown".to_string())
+}
+
+/// Human-readable byte size, e.g. "412.3 MB"
+pub fn format_size(bytes: i64) -> String {
+    const UNITS: [&str; 4] = ["B", "KB", "MB", "GB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size.abs() >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} B", bytes)
+    } else {
+        format!("{:.1} {}", size, UNITS[unit])
+    }
+}
+
+/// Compact duration for a number of seconds, e.g. "2d 3h" or "45s"
+pub fn format_span(secs: i64) -> String {
+    let secs = secs.unsigned_abs();
+    let (days, hours, minutes) = (secs / 86_400, secs % 86_400 / 3600, secs % 3600 / 60);
+    if days > 0 {
+        format!("{}d {}h", days, hours)
+    } else if hours > 0 {
+        format!("{}h {}m", hours, minutes)
+    } else if minutes > 0 {
+        format!("{}m {}s", minutes, secs % 60)
+    } else {
+        format!("{}s", secs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn side(name: &str, db: DatabaseType, created_at: i64, size: i64) -> SnapshotSide {
+        SnapshotSide {
+            id: format!("{}-id", name),
+            name: name.to_string(),
+            database_type: db,
+            version: Some("16".to_string()),
+            created_at,
+            size: Some(size),
+            message: None,
+        }
+    }
+
+    #[test]
+    fn test_diff_marks_changed_fields() {
+        let mut b = side("after", DatabaseType::Postgres, 1_700_003_600, 3 * 1024 * 1024);
+        b.message = Some("after migration".to_string());
+        let diff = SnapshotDiff::between(
+            side("before", DatabaseType::Postgres, 1_700_000_000, 2 * 1024 * 1024),
+            b,
+        );
+
+        assert!(diff.comparable);
+        assert_eq!(diff.size_delta, Some(1024 * 1024));
+        assert_eq!(diff.created_delta_secs, 3600);
+
+        let changed: Vec<_> = diff
+            .fields
+            .iter()
+            .filter(|f| f.changed)
+            .map(|f| f.field)
+            .collect();
+        assert_eq!(changed, ["created", "size", "message"]);
+        assert_eq!(diff.fields[3].a, "2.0 MB");
+    }
+
+    #[test]
+    fn test_different_database_types_are_not_comparable() {
+        let diff = SnapshotDiff::between(
+            side("pg", DatabaseType::Postgres, 0, 1),
+            side("my", DatabaseType::MySQL, 0, 1),
+        );
+        assert!(!diff.comparable);
+        assert!(diff.fields[0].changed);
+    }
+
+    #[test]
+    fn test_format_size_and_span() {
+        assert_eq!(format_size(512), "512 B");
+        assert_eq!(format_size(1536), "1.5 KB");
+        assert_eq!(format_size(-2 * 1024 * 1024), "-2.0 MB");
+        assert_eq!(format_span(3600), "1h 0m");
+        assert_eq!(format_span(-93_784), "1d 2h");
+        assert_eq!(format_span(45), "45s");
+    }
+}
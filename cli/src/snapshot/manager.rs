@@ -1,7 +1,11 @@
 use bollard::Docker;
+use std::io::Write;
+use std::path::Path;
 use std::sync::Arc;
 
-use super::metadata::Snapshot;
+use super::archive::{read_archive, write_archive};
+use super::diff::{SnapshotDiff, SnapshotSide};
+use super::metadata::{Snapshot, SnapshotState};
 use super::storage::SnapshotStorage;
 use crate::container::DatabaseType;
 use crate::error::{DBArenaError, Result};
@@ -19,12 +23,20 @@ impl SnapshotManager {
     }
 
     /// Create a snapshot from a container
+    ///
+    /// With a `base`, the snapshot is layered on that snapshot's image: the container must
+    /// have been restored from the base (or from a snapshot layered on it), so the committed
+    /// image shares the base's layers and Docker only stores the delta.
+    ///
+    /// A stopped container is committed as-is. A running one keeps serving during the commit
+    /// unless `pause` is set, in which case it is paused so the filesystem is consistent.
     pub async fn create(
         &self,
         container_id: &str,
         name: String,
         message: Option<String>,
-        auto_pause: bool,
+        base: Option<&str>,
+        pause: bool,
     ) -> Result<Snapshot> {
         // Get container info to determine database type
         let inspect = self
@@ -32,6 +44,10 @@ impl SnapshotManager {
             .inspect_container(container_id, None)
             .await
             .map_err(|_e| DBArenaError::ContainerNotFound(container_id.to_string()))?;
+        let container_image = inspect.image.clone().unwrap_or_default();
+        let running = inspect.state.as_ref().and_then(|s| s.running) == Some(true);
+        let paused = inspect.state.as_ref().and_then(|s| s.paused) == Some(true);
+        let (state, pause_during_commit) = commit_state(running, paused, pause);
 
         // Extract database type from labels
         let database_type = inspect
@@ -44,16 +60,41 @@ impl SnapshotManager {
             })?;
 
         // Create snapshot metadata
-        let snapshot = Snapshot::new(name, container_id.to_string(), database_type, message);
+        let mut snapshot = Snapshot::new(name, container_id.to_string(), database_type, message)
+            .with_state(state);
+
+        if let Some(base) = base {
+            let base = self.get(base).await?;
+            if base.database_type != database_type {
+                return Err(DBArenaError::SnapshotError(format!(
+                    "Base snapshot '{}' is a {} snapshot but the container runs {}",
+                    base.name, base.database_type, database_type
+                )));
+            }
+            let base_image = self.storage.image_id(&base).await?;
+            if !self
+                .storage
+                .has_ancestor(&container_image, &base_image)
+                .await?
+            {
+                return Err(DBArenaError::SnapshotError(format!(
+                    "Container {} was not restored from snapshot '{}'; restore the base \
+                     and snapshot the restored container to layer on it",
+                    container_id, base.name
+                )));
+            }
+            snapshot = snapshot.with_parent(base.id);
+        }
 
         // Commit the container as an image
         self.storage
-            .commit_container(container_id, &snapshot, auto_pause)
+            .commit_container(container_id, &snapshot, pause_during_commit)
             .await?;
 
         tracing::info!(
-            "Created snapshot {} from container {}",
+            "Created snapshot {} from {} container {}",
             snapshot.id,
+            state,
             container_id
         );
 
@@ -87,8 +128,9 @@ impl SnapshotManager {
         name: Option<String>,
         port: Option<u16>,
     ) -> Result<crate::container::Container> {
-        // Get the snapshot
+        // Get the snapshot and make sure every base it is layered on is still there
         let snapshot = self.get(snapshot_id).await?;
+        base_chain(&snapshot, &self.list().await?)?;
 
         // Create container directly from snapshot image using Docker API
         use bollard::container::{Config, CreateContainerOptions};
@@ -191,16 +233,211 @@ impl SnapshotManager {
         Ok(())
     }
 
+    /// Compare the metadata of two snapshots
+    pub async fn diff(&self, a: &str, b: &str) -> Result<SnapshotDiff> {
+        let mut sides = Vec::with_capacity(2);
+        for id_or_name in [a, b] {
+            let snapshot = self.get(id_or_name).await?;
+            let (size, version) = self.storage.image_details(&snapshot).await?;
+            sides.push(SnapshotSide::new(&snapshot, version, size));
+        }
+        let b = sides.pop().unwrap();
+        let a = sides.pop().unwrap();
+
+        Ok(SnapshotDiff::between(a, b))
+    }
+
+    /// Approximate on-disk size of a snapshot in bytes: the delta over its base for a layered
+    /// snapshot, the full image size otherwise
+    pub async fn delta_size(&self, snapshot: &Snapshot, all: &[Snapshot]) -> Result<Option<i64>> {
+        let (size, _) = self.storage.image_details(snapshot).await?;
+        let Some(size) = size else {
+            return Ok(None);
+        };
+
+        let parent = snapshot
+            .parent
+            .as_ref()
+            .and_then(|parent| all.iter().find(|s| &s.id == parent));
+        match parent {
+            Some(parent) => {
+                let (parent_size, _) = self.storage.image_details(parent).await?;
+                Ok(parent_size.map(|parent_size| (size - parent_size).max(0)))
+            }
+            None => Ok(Some(size)),
+        }
+    }
+
+    /// Export a snapshot's image and metadata to an archive at `path`
+    pub async fn export(&self, snapshot_id: &str, path: &Path) -> Result<Snapshot> {
+        let snapshot = self.get(snapshot_id).await?;
+
+        // Stage the saved image next to the archive so it can be appended with a known size
+        let image_path = path.with_file_name(format!(".{}.image.tar", snapshot.id));
+        let result: Result<()> = async {
+            let mut image = std::io::BufWriter::new(std::fs::File::create(&image_path)?);
+            self.storage.export_image(&snapshot, &mut image).await?;
+            image.flush()?;
+            drop(image);
+            write_archive(path, &snapshot, &image_path)
+        }
+        .await;
+        let _ = std::fs::remove_file(&image_path);
+        result?;
+
+        tracing::info!("Exported snapshot {} to {}", snapshot.id, path.display());
+
+        Ok(snapshot)
+    }
+
+    /// Import a snapshot exported with [`export`](Self::export)
+    pub async fn import(&self, path: &Path) -> Result<Snapshot> {
+        // Stage the saved image on disk rather than trusting the archive's entry size
+        let image_path =
+            std::env::temp_dir().join(format!("dbarena-import-{}.image.tar", std::process::id()));
+        let result: Result<Snapshot> = async {
+            let mut image = std::io::BufWriter::new(std::fs::File::create(&image_path)?);
+            let snapshot = read_archive(path, &mut image)?;
+            drop(image);
+
+            if self.list().await?.iter().any(|s| s.id == snapshot.id) {
+                return Err(DBArenaError::SnapshotError(format!(
+                    "Snapshot {} ({}) already exists",
+                    snapshot.name, snapshot.id
+                )));
+            }
+
+            self.storage.import_image(&image_path).await?;
+            Ok(snapshot)
+        }
+        .await;
+        let _ = std::fs::remove_file(&image_path);
+        let snapshot = result?;
+
+        // The loaded image carries the snapshot's tag and labels, so it lists like the
+        // original; they must describe the same snapshot as the archive's metadata
+        let imported = self
+            .storage
+            .get_snapshot(&snapshot.id)
+            .await?
+            .ok_or_else(|| {
+                DBArenaError::SnapshotError(format!(
+                    "Imported image {} is not tagged as snapshot {}",
+                    snapshot.image_tag, snapshot.id
+                ))
+            })?;
+        if imported.image_tag != snapshot.image_tag
+            || imported.name != snapshot.name
+            || imported.database_type != snapshot.database_type
+            || imported.created_at != snapshot.created_at
+            || imported.parent != snapshot.parent
+        {
+            return Err(DBArenaError::SnapshotError(format!(
+                "Imported image {} does not match the archive's metadata for snapshot {}",
+                imported.image_tag, snapshot.id
+            )));
+        }
+
+        tracing::info!("Imported snapshot {} from {}", imported.id, path.display());
+
+        Ok(imported)
+    }
+
     /// Inspect a snapshot (get detailed information)
     pub async fn inspect(&self, snapshot_id: &str) -> Result<Snapshot> {
         self.get(snapshot_id).await
     }
 }
 
+/// State to record for a commit and whether Docker should pause the container for it.
+/// Containers that are already paused or stopped are committed without touching them.
+fn commit_state(running: bool, paused: bool, pause: bool) -> (SnapshotState, bool) {
+    if paused {
+        (SnapshotState::Paused, false)
+    } else if !running {
+        (SnapshotState::Stopped, false)
+    } else if pause {
+        (SnapshotState::Paused, true)
+    } else {
+        (SnapshotState::Running, false)
+    }
+}
+
+/// Walk a snapshot's bases from nearest to oldest, erroring if one of them has been deleted
+pub fn base_chain<'a>(snapshot: &Snapshot, all: &'a [Snapshot]) -> Result<Vec<&'a Snapshot>> {
+    let mut chain: Vec<&Snapshot> = Vec::new();
+    let mut child = snapshot;
+
+    while let Some(parent_id) = &child.parent {
+        let parent = all.iter().find(|s| &s.id == parent_id).ok_or_else(|| {
+            DBArenaError::SnapshotError(format!(
+                "Snapshot chain is broken: '{}' is layered on base snapshot {}, which no \
+                 longer exists",
+                child.name, parent_id
+            ))
+        })?;
+        if parent.id == snapshot.id || chain.iter().any(|s| s.id == parent.id) {
+            return Err(DBArenaError::SnapshotError(format!(
+                "Snapshot chain of '{}' loops back on itself at {}",
+                snapshot.name, parent.name
+            )));
+        }
+        chain.push(parent);
+        child = parent;
+    }
+
+    Ok(chain)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn snapshot(name: &str) -> Snapshot {
+        Snapshot::new(
+            name.to_string(),
+            "container-123".to_string(),
+            DatabaseType::Postgres,
+            None,
+        )
+    }
+
+    #[test]
+    fn test_commit_state() {
+        assert_eq!(commit_state(false, false, false), (SnapshotState::Stopped, false));
+        assert_eq!(commit_state(false, false, true), (SnapshotState::Stopped, false));
+        assert_eq!(commit_state(true, false, false), (SnapshotState::Running, false));
+        assert_eq!(commit_state(true, false, true), (SnapshotState::Paused, true));
+        // Already paused by the user: commit without pausing again
+        assert_eq!(commit_state(true, true, true), (SnapshotState::Paused, false));
+    }
+
+    #[test]
+    fn test_base_chain_walks_to_root() {
+        let root = snapshot("root");
+        let hourly = snapshot("hourly-1").with_parent(root.id.clone());
+        let latest = snapshot("hourly-2").with_parent(hourly.id.clone());
+        let all = vec![root.clone(), hourly.clone(), latest.clone()];
+
+        let chain = base_chain(&latest, &all).unwrap();
+        let names: Vec<&str> = chain.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["hourly-1", "root"]);
+        assert!(base_chain(&root, &all).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_base_chain_reports_missing_base() {
+        let root = snapshot("root");
+        let hourly = snapshot("hourly-1").with_parent(root.id.clone());
+        let latest = snapshot("hourly-2").with_parent(hourly.id.clone());
+        let all = vec![hourly.clone(), latest.clone()];
+
+        let err = base_chain(&latest, &all).unwrap_err().to_string();
+        assert!(err.contains("chain is broken"), "{}", err);
+        assert!(err.contains("hourly-1"), "{}", err);
+        assert!(err.contains(&root.id), "{}", err);
+    }
+
     #[tokio::test]
     #[ignore] // Requires Docker
     async fn test_snapshot_manager_creation() {
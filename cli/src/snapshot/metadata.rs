@@ -1,6 +1,44 @@
 use serde::{Deserialize, Serialize};
+use std::fmt;
 use crate::container::DatabaseType;
 
+/// State of the source container when the snapshot was committed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SnapshotState {
+    /// Committed while the database kept running (writes may have been in flight)
+    Running,
+    /// Paused for the duration of the commit
+    Paused,
+    /// Committed from a stopped container
+    Stopped,
+}
+
+impl SnapshotState {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SnapshotState::Running => "running",
+            SnapshotState::Paused => "paused",
+            SnapshotState::Stopped => "stopped",
+        }
+    }
+
+    pub fn from_string(s: &str) -> Option<Self> {
+        match s {
+            "running" => Some(SnapshotState::Running),
+            "paused" => Some(SnapshotState::Paused),
+            "stopped" => Some(SnapshotState::Stopped),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for SnapshotState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 /// Snapshot metadata
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Snapshot {
@@ -18,6 +56,12 @@ pub struct Snapshot {
     pub image_tag: String,
     /// Optional message describing the snapshot
     pub message: Option<String>,
+    /// ID of the base snapshot this one is layered on, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<String>,
+    /// Container state at commit time; unknown for snapshots taken before it was recorded
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<SnapshotState>,
 }
 
 impl Snapshot {
@@ -40,9 +84,23 @@ impl Snapshot {
             created_at,
             image_tag,
             message,
+            parent: None,
+            state: None,
         }
     }
 
+    /// Layer this snapshot on top of a base snapshot
+    pub fn with_parent(mut self, parent_id: String) -> Self {
+        self.parent = Some(parent_id);
+        self
+    }
+
+    /// Record the container state the snapshot was committed in
+    pub fn with_state(mut self, state: SnapshotState) -> Self {
+        self.state = Some(state);
+        self
+    }
+
     /// Get Docker labels for this snapshot
     pub fn to_labels(&self) -> std::collections::HashMap<String, String> {
         let mut labels = std::collections::HashMap::new();
@@ -64,6 +122,12 @@ impl Snapshot {
         if let Some(msg) = &self.message {
             labels.insert("dbarena.snapshot.message".to_string(), msg.clone());
         }
+        if let Some(parent) = &self.parent {
+            labels.insert("dbarena.snapshot.parent".to_string(), parent.clone());
+        }
+        if let Some(state) = self.state {
+            labels.insert("dbarena.snapshot.state".to_string(), state.to_string());
+        }
         labels
     }
 
@@ -91,6 +155,10 @@ impl Snapshot {
                 .ok()?,
             image_tag,
             message: labels.get("dbarena.snapshot.message").cloned(),
+            parent: labels.get("dbarena.snapshot.parent").cloned(),
+            state: labels
+                .get("dbarena.snapshot.state")
+                .and_then(|state| SnapshotState::from_string(state)),
         })
     }
 }
@@ -130,4 +198,53 @@ mod tests {
             Some(&"test".to_string())
         );
     }
+
+    #[test]
+    fn test_parent_roundtrip() {
+        let snapshot = Snapshot::new(
+            "hourly".to_string(),
+            "container-123".to_string(),
+            DatabaseType::Postgres,
+            None,
+        )
+        .with_parent("base-id".to_string());
+
+        let labels = snapshot.to_labels();
+        let restored =
+            Snapshot::from_labels(String::new(), snapshot.image_tag.clone(), &labels).unwrap();
+        assert_eq!(restored.parent, Some("base-id".to_string()));
+
+        let plain = Snapshot::new(
+            "plain".to_string(),
+            "container-123".to_string(),
+            DatabaseType::Postgres,
+            None,
+        );
+        let restored =
+            Snapshot::from_labels(String::new(), plain.image_tag.clone(), &plain.to_labels())
+                .unwrap();
+        assert!(restored.parent.is_none());
+    }
+
+    #[test]
+    fn test_state_roundtrip() {
+        for state in [
+            SnapshotState::Running,
+            SnapshotState::Paused,
+            SnapshotState::Stopped,
+        ] {
+            let snapshot = Snapshot::new(
+                "test".to_string(),
+                "container-123".to_string(),
+                DatabaseType::Postgres,
+                None,
+            )
+            .with_state(state);
+
+            let labels = snapshot.to_labels();
+            let restored =
+                Snapshot::from_labels(String::new(), snapshot.image_tag.clone(), &labels).unwrap();
+            assert_eq!(restored.state, Some(state));
+        }
+    }
 }
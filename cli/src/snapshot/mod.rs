@@ -3,10 +3,15 @@
 //! Provides functionality to create, restore, and manage container snapshots.
 //! Snapshots are stored as Docker images with metadata labels.
 
+pub mod archive;
+pub mod diff;
 pub mod metadata;
+pub mod schedule;
 pub mod storage;
 pub mod manager;
 
-pub use metadata::Snapshot;
+pub use diff::SnapshotDiff;
+pub use metadata::{Snapshot, SnapshotState};
+pub use schedule::{ScheduledSnapshot, SnapshotSchedule};
 pub use storage::SnapshotStorage;
 pub use manager::SnapshotManager;
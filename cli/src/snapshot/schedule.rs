@@ -0,0 +1,198 @@
+//! Periodic snapshots of a container while something else runs against it
+//!
+//! Used by `workload run --snapshot-interval` so a long run leaves a trail of
+//! snapshots to bisect when the data went wrong. Commits don't pause the container,
+//! so the workload keeps going.
+
+use serde::Serialize;
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::oneshot;
+use tokio::task::JoinHandle;
+
+use super::manager::SnapshotManager;
+
+/// A snapshot taken on schedule
+#[derive(Debug, Clone, Serialize)]
+pub struct ScheduledSnapshot {
+    pub id: String,
+    pub name: String,
+    /// Seconds into the schedule when the commit finished
+    pub elapsed_secs: f64,
+    /// False once `--max-snapshots` rotated it out
+    pub kept: bool,
+}
+
+/// Snapshot name for a scheduled snapshot of `container` taken at `at`
+pub fn auto_snapshot_name(container: &str, at: chrono::DateTime<chrono::Utc>) -> String {
+    format!("{}-auto-{}", container, at.format("%Y%m%d-%H%M%S"))
+}
+
+/// Oldest-first IDs of the kept snapshots, bounded by `max`
+#[derive(Debug, Default)]
+pub struct SnapshotRing {
+    max: Option<usize>,
+    kept: VecDeque<String>,
+}
+
+impl SnapshotRing {
+    pub fn new(max: Option<usize>) -> Self {
+        Self {
+            max,
+            kept: VecDeque::new(),
+        }
+    }
+
+    /// Record a new snapshot and return the IDs that no longer fit, oldest first
+    pub fn push(&mut self, id: String) -> Vec<String> {
+        self.kept.push_back(id);
+        let max = self.max.unwrap_or(usize::MAX);
+        let excess = self.kept.len().saturating_sub(max);
+        self.kept.drain(..excess).collect()
+    }
+}
+
+/// Snapshot a container every `interval`, keeping at most `max` of them
+pub struct SnapshotSchedule {
+    manager: Arc<SnapshotManager>,
+    container_id: String,
+    container_name: String,
+    interval: Duration,
+    max: Option<usize>,
+}
+
+/// A running schedule; [`stop`](Self::stop) it to get what it took
+///
+/// Dropping the handle without stopping it aborts the schedule, so an early
+/// return can't leave it snapshotting in the background.
+pub struct ScheduleHandle {
+    stop: Option<oneshot::Sender<()>>,
+    task: Option<JoinHandle<Vec<ScheduledSnapshot>>>,
+}
+
+impl ScheduleHandle {
+    /// Stop taking snapshots, letting a commit in progress finish
+    pub async fn stop(mut self) -> Vec<ScheduledSnapshot> {
+        if let Some(stop) = self.stop.take() {
+            let _ = stop.send(());
+        }
+        match self.task.take() {
+            Some(task) => task.await.unwrap_or_default(),
+            None => Vec::new(),
+        }
+    }
+}
+
+impl Drop for ScheduleHandle {
+    fn drop(&mut self) {
+        if let Some(task) = self.task.take() {
+            task.abort();
+        }
+    }
+}
+
+impl SnapshotSchedule {
+    pub fn new(
+        manager: Arc<SnapshotManager>,
+        container_id: String,
+        container_name: String,
+        interval: Duration,
+        max: Option<usize>,
+    ) -> Self {
+        Self {
+            manager,
+            container_id,
+            container_name,
+            interval,
+            max,
+        }
+    }
+
+    /// Take the first snapshot one interval from now, and every interval after
+    pub fn spawn(self) -> ScheduleHandle {
+        let (stop, mut stopped) = oneshot::channel();
+        let task = tokio::spawn(async move {
+            let start = Instant::now();
+            let mut ticks = tokio::time::interval_at(
+                tokio::time::Instant::now() + self.interval,
+                self.interval,
+            );
+            // A slow commit shouldn't be followed by a burst of catch-up snapshots
+            ticks.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+            let mut ring = SnapshotRing::new(self.max);
+            let mut taken: Vec<ScheduledSnapshot> = Vec::new();
+            loop {
+                tokio::select! {
+                    _ = &mut stopped => break,
+                    _ = ticks.tick() => {}
+                }
+
+                let name = auto_snapshot_name(&self.container_name, chrono::Utc::now());
+                let snapshot = match self
+                    .manager
+                    .create(&self.container_id, name.clone(), None, None, false)
+                    .await
+                {
+                    Ok(snapshot) => snapshot,
+                    Err(e) => {
+                        tracing::warn!("Scheduled snapshot {} failed: {}", name, e);
+                        continue;
+                    }
+                };
+                taken.push(ScheduledSnapshot {
+                    id: snapshot.id.clone(),
+                    name: snapshot.name,
+                    elapsed_secs: start.elapsed().as_secs_f64(),
+                    kept: true,
+                });
+
+                for old in ring.push(snapshot.id) {
+                    if let Err(e) = self.manager.delete(&old).await {
+                        tracing::warn!("Failed to rotate out snapshot {}: {}", old, e);
+                        continue;
+                    }
+                    if let Some(entry) = taken.iter_mut().find(|s| s.id == old) {
+                        entry.kept = false;
+                    }
+                }
+            }
+            taken
+        });
+
+        ScheduleHandle {
+            stop: Some(stop),
+            task: Some(task),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_auto_snapshot_name() {
+        let at = chrono::Utc.with_ymd_and_hms(2026, 3, 9, 14, 5, 7).unwrap();
+        assert_eq!(auto_snapshot_name("mydb", at), "mydb-auto-20260309-140507");
+    }
+
+    #[test]
+    fn test_ring_drops_oldest_beyond_max() {
+        let mut ring = SnapshotRing::new(Some(2));
+        assert!(ring.push("a".to_string()).is_empty());
+        assert!(ring.push("b".to_string()).is_empty());
+        assert_eq!(ring.push("c".to_string()), vec!["a".to_string()]);
+        assert_eq!(ring.push("d".to_string()), vec!["b".to_string()]);
+    }
+
+    #[test]
+    fn test_ring_unbounded() {
+        let mut ring = SnapshotRing::new(None);
+        for i in 0..100 {
+            assert!(ring.push(i.to_string()).is_empty());
+        }
+    }
+}
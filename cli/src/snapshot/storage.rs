@@ -1,5 +1,10 @@
-use bollard::image::{CommitContainerOptions, ListImagesOptions, RemoveImageOptions};
+use bollard::image::{
+    CommitContainerOptions, ImportImageOptions, ListImagesOptions, RemoveImageOptions,
+};
 use bollard::Docker;
+use futures::StreamExt;
+use std::io::Write;
+use std::path::Path;
 use std::sync::Arc;
 
 use super::metadata::Snapshot;
@@ -99,6 +104,85 @@ impl SnapshotStorage {
         Ok(snapshots.into_iter().find(|s| s.id == snapshot_id))
     }
 
+    /// Image size in bytes and the database version label of a snapshot's image
+    pub async fn image_details(
+        &self,
+        snapshot: &Snapshot,
+    ) -> Result<(Option<i64>, Option<String>)> {
+        let image = self
+            .docker
+            .inspect_image(&snapshot.image_tag)
+            .await
+            .map_err(|e| DBArenaError::SnapshotError(format!("Failed to inspect image: {}", e)))?;
+
+        let version = image
+            .config
+            .and_then(|config| config.labels)
+            .and_then(|labels| labels.get("dbarena.version").cloned());
+
+        Ok((image.size, version))
+    }
+
+    /// Image ID a snapshot's tag points at
+    pub async fn image_id(&self, snapshot: &Snapshot) -> Result<String> {
+        let image = self
+            .docker
+            .inspect_image(&snapshot.image_tag)
+            .await
+            .map_err(|e| DBArenaError::SnapshotError(format!("Failed to inspect image: {}", e)))?;
+
+        image.id.ok_or_else(|| {
+            DBArenaError::SnapshotError(format!("Image {} has no ID", snapshot.image_tag))
+        })
+    }
+
+    /// Whether `image` is `ancestor_id` or was built on top of it
+    pub async fn has_ancestor(&self, image: &str, ancestor_id: &str) -> Result<bool> {
+        let history = self
+            .docker
+            .image_history(image)
+            .await
+            .map_err(|e| {
+                DBArenaError::SnapshotError(format!("Failed to read image history: {}", e))
+            })?;
+
+        Ok(history.iter().any(|item| item.id == ancestor_id))
+    }
+
+    /// Write a snapshot's image to `writer` in `docker save` format
+    pub async fn export_image(&self, snapshot: &Snapshot, writer: &mut impl Write) -> Result<()> {
+        let mut stream = self.docker.export_image(&snapshot.image_tag);
+        while let Some(chunk) = stream.next().await {
+            let chunk = chunk.map_err(|e| {
+                DBArenaError::SnapshotError(format!("Failed to export image: {}", e))
+            })?;
+            writer.write_all(&chunk)?;
+        }
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Load an image file written by [`export_image`](Self::export_image), tags and labels
+    /// included
+    pub async fn import_image(&self, image_path: &Path) -> Result<()> {
+        let image = tokio::fs::read(image_path).await?;
+        let mut stream = self
+            .docker
+            .import_image(ImportImageOptions { quiet: true }, image.into(), None);
+        while let Some(info) = stream.next().await {
+            let info = info.map_err(|e| {
+                DBArenaError::SnapshotError(format!("Failed to import image: {}", e))
+            })?;
+            if let Some(error) = info.error {
+                return Err(DBArenaError::SnapshotError(format!(
+                    "Failed to import image: {}",
+                    error
+                )));
+            }
+        }
+        Ok(())
+    }
+
     /// Delete a snapshot image
     pub async fn delete_snapshot(&self, snapshot: &Snapshot) -> Result<()> {
         let options = Some(RemoveImageOptions {
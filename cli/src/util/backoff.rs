@@ -0,0 +1,102 @@
+//! Exponential backoff for retry loops
+//!
+//! Health checks, image pulls and Docker connection retries all wait with
+//! [`Backoff`], so their delays grow, cap and jitter the same way.
+
+use rand::Rng;
+use std::time::Duration;
+
+/// Delays that start at `initial` and grow by `multiplier` per attempt up to
+/// `max`, optionally with jitter so parallel retries don't run in lockstep
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Backoff {
+    pub initial: Duration,
+    pub max: Duration,
+    pub multiplier: f64,
+    /// Share of each delay added or removed at random (0.2 is ±20%)
+    pub jitter: f64,
+}
+
+impl Backoff {
+    /// Doubling delays from `initial`, uncapped and without jitter
+    pub fn exponential(initial: Duration) -> Self {
+        Self {
+            initial,
+            max: Duration::MAX,
+            multiplier: 2.0,
+            jitter: 0.0,
+        }
+    }
+
+    pub fn with_max(mut self, max: Duration) -> Self {
+        self.max = max;
+        self
+    }
+
+    pub fn with_multiplier(mut self, multiplier: f64) -> Self {
+        self.multiplier = multiplier;
+        self
+    }
+
+    pub fn with_jitter(mut self, jitter: f64) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Delay before retry number `attempt` (1-based), before jitter
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(i32::MAX as u32) as i32;
+        let factor = self.multiplier.max(1.0).powi(exponent);
+        let seconds = self.initial.as_secs_f64() * factor;
+        if seconds >= self.max.as_secs_f64() {
+            self.max
+        } else {
+            Duration::from_secs_f64(seconds)
+        }
+    }
+
+    /// `delay` with jitter applied, still capped at `max`
+    pub fn jittered_delay(&self, attempt: u32) -> Duration {
+        let delay = self.delay(attempt);
+        if self.jitter <= 0.0 {
+            return delay;
+        }
+        let jitter = rand::thread_rng().gen_range(-self.jitter..=self.jitter);
+        delay.mul_f64(1.0 + jitter).min(self.max)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff_grows_and_caps() {
+        let backoff = Backoff::exponential(Duration::from_millis(100))
+            .with_max(Duration::from_millis(1000));
+        assert_eq!(backoff.delay(1), Duration::from_millis(100));
+        assert_eq!(backoff.delay(2), Duration::from_millis(200));
+        assert_eq!(backoff.delay(4), Duration::from_millis(800));
+        assert_eq!(backoff.delay(5), Duration::from_millis(1000));
+        assert_eq!(backoff.delay(u32::MAX), Duration::from_millis(1000));
+
+        let uncapped = Backoff::exponential(Duration::from_secs(2));
+        assert_eq!(uncapped.delay(3), Duration::from_secs(8));
+        assert_eq!(uncapped.jittered_delay(3), Duration::from_secs(8));
+        assert_eq!(uncapped.delay(u32::MAX), Duration::MAX);
+    }
+
+    #[test]
+    fn test_jitter_stays_in_range() {
+        let backoff = Backoff::exponential(Duration::from_millis(100))
+            .with_max(Duration::from_millis(1000))
+            .with_multiplier(1.5)
+            .with_jitter(0.2);
+        for attempt in 1..10 {
+            let delay = backoff.jittered_delay(attempt);
+            assert!(delay <= backoff.max);
+            assert!(delay >= backoff.delay(attempt).mul_f64(0.8));
+            assert!(delay <= backoff.delay(attempt).mul_f64(1.2));
+        }
+    }
+}
@@ -0,0 +1,117 @@
+//! Bounded concurrency for batch operations
+//!
+//! Every item is run to completion even when others fail, and results come
+//! back in input order so callers can zip them with their inputs.
+
+use crate::{DBArenaError, Result};
+use futures::stream::{self, StreamExt};
+use std::fmt::Display;
+use std::future::Future;
+
+/// Default for `--concurrency` flags
+pub const DEFAULT_CONCURRENCY: usize = 4;
+
+/// Run `f` over `items` with at most `limit` futures in flight (0 is treated as 1)
+pub async fn for_each_concurrent<I, F, Fut>(items: I, limit: usize, mut f: F) -> Vec<Fut::Output>
+where
+    I: IntoIterator,
+    F: FnMut(I::Item) -> Fut,
+    Fut: Future,
+{
+    let mut results: Vec<(usize, Fut::Output)> = stream::iter(items.into_iter().enumerate())
+        .map(|(index, item)| {
+            let fut = f(item);
+            async move { (index, fut.await) }
+        })
+        .buffer_unordered(limit.max(1))
+        .collect()
+        .await;
+
+    results.sort_by_key(|(index, _)| *index);
+    results.into_iter().map(|(_, output)| output).collect()
+}
+
+/// Unwrap every result, or fail with one error listing each failure
+pub fn collect_errors<T, E: Display>(results: Vec<std::result::Result<T, E>>) -> Result<Vec<T>> {
+    let total = results.len();
+    let mut values = Vec::with_capacity(total);
+    let mut errors = Vec::new();
+
+    for result in results {
+        match result {
+            Ok(value) => values.push(value),
+            Err(e) => errors.push(e.to_string()),
+        }
+    }
+
+    match errors.len() {
+        0 => Ok(values),
+        1 => Err(DBArenaError::Other(errors.remove(0))),
+        n => Err(DBArenaError::Other(format!(
+            "{} of {} tasks failed:\n  - {}",
+            n,
+            total,
+            errors.join("\n  - ")
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_limit_respected_and_order_preserved() {
+        let in_flight = AtomicUsize::new(0);
+        let peak = AtomicUsize::new(0);
+
+        let results = for_each_concurrent(0..10u64, 3, |i| {
+            let in_flight = &in_flight;
+            let peak = &peak;
+            async move {
+                let now = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                peak.fetch_max(now, Ordering::SeqCst);
+                // Later items finish first
+                tokio::time::sleep(Duration::from_millis(20 - i)).await;
+                in_flight.fetch_sub(1, Ordering::SeqCst);
+                i * 2
+            }
+        })
+        .await;
+
+        assert_eq!(results, (0..10).map(|i| i * 2).collect::<Vec<_>>());
+        assert_eq!(peak.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_zero_limit_runs_sequentially() {
+        let results = for_each_concurrent(vec!["a", "b"], 0, |s| async move { s.len() }).await;
+        assert_eq!(results, vec![1, 1]);
+    }
+
+    #[tokio::test]
+    async fn test_errors_aggregated() {
+        let results = for_each_concurrent(1..=4, 2, |i| async move {
+            if i % 2 == 0 {
+                Err(format!("item {} failed", i))
+            } else {
+                Ok(i)
+            }
+        })
+        .await;
+        // Failures don't stop the remaining items
+        assert_eq!(results.len(), 4);
+
+        let err = collect_errors(results).unwrap_err().to_string();
+        assert!(err.contains("2 of 4 tasks failed"));
+        assert!(err.contains("item 2 failed"));
+        assert!(err.contains("item 4 failed"));
+
+        assert_eq!(
+            collect_errors(vec![Ok::<_, String>(1), Ok(2)]).unwrap(),
+            vec![1, 2]
+        );
+    }
+}
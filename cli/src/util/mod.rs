@@ -0,0 +1,6 @@
+//! Small internal helpers shared across command handlers
+
+pub mod backoff;
+pub mod bounded;
+pub mod resp;
+pub mod shell;
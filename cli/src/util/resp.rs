@@ -0,0 +1,119 @@
+use crate::{DBArenaError, Result};
+
+/// Split a redis-cli command line into arguments
+///
+/// Follows redis-cli's own rules: arguments are separated by whitespace,
+/// double quotes allow `\"`, `\\`, `\n`, `\r`, `\t` and `\xHH` escapes, and
+/// single quotes only allow `\'`. A closing quote must be followed by
+/// whitespace or the end of the line.
+pub fn split_args(line: &str) -> Result<Vec<String>> {
+    let mut args = Vec::new();
+    let mut chars = line.chars().peekable();
+    let unbalanced = || DBArenaError::Other(format!("Unbalanced quotes in: {}", line));
+
+    loop {
+        while chars.next_if(|c| c.is_whitespace()).is_some() {}
+        let Some(&first) = chars.peek() else {
+            return Ok(args);
+        };
+
+        let mut arg = String::new();
+        if first == '"' || first == '\'' {
+            chars.next();
+            loop {
+                match chars.next() {
+                    None => return Err(unbalanced()),
+                    Some(c) if c == first => break,
+                    Some('\\') if first == '"' => match chars.next() {
+                        Some('n') => arg.push('\n'),
+                        Some('r') => arg.push('\r'),
+                        Some('t') => arg.push('\t'),
+                        Some('b') => arg.push('\u{8}'),
+                        Some('a') => arg.push('\u{7}'),
+                        Some('x') => {
+                            let hex: String = chars.by_ref().take(2).collect();
+                            let byte = u8::from_str_radix(&hex, 16).map_err(|_| {
+                                DBArenaError::Other(format!(
+                                    "Invalid \\x escape '\\x{}' in: {}",
+                                    hex, line
+                                ))
+                            })?;
+                            arg.push(char::from(byte));
+                        }
+                        Some(c) => arg.push(c),
+                        None => return Err(unbalanced()),
+                    },
+                    Some('\\') if chars.peek() == Some(&'\'') => {
+                        arg.push('\'');
+                        chars.next();
+                    }
+                    Some(c) => arg.push(c),
+                }
+            }
+            if chars.peek().is_some_and(|c| !c.is_whitespace()) {
+                return Err(DBArenaError::Other(format!(
+                    "Closing quote must be followed by a space in: {}",
+                    line
+                )));
+            }
+        } else {
+            while let Some(c) = chars.next_if(|c| !c.is_whitespace()) {
+                arg.push(c);
+            }
+        }
+        args.push(arg);
+    }
+}
+
+/// Convert a redis-cli command file into RESP for `redis-cli --pipe`
+///
+/// Blank lines and lines starting with `#` are skipped.
+pub fn encode_script(script: &str) -> Result<String> {
+    let mut resp = String::new();
+    for (index, line) in script.lines().enumerate() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        let args = split_args(trimmed)
+            .map_err(|e| DBArenaError::Other(format!("line {}: {}", index + 1, e)))?;
+        resp.push_str(&format!("*{}\r\n", args.len()));
+        for arg in args {
+            resp.push_str(&format!("${}\r\n{}\r\n", arg.len(), arg));
+        }
+    }
+    Ok(resp)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_args() {
+        assert_eq!(split_args("SET key value").unwrap(), vec!["SET", "key", "value"]);
+        assert_eq!(
+            split_args(r#"SET greeting "hello world""#).unwrap(),
+            vec!["SET", "greeting", "hello world"]
+        );
+        assert_eq!(
+            split_args(r#"SET k "tab\there \"q\" \x41""#).unwrap(),
+            vec!["SET", "k", "tab\there \"q\" A"]
+        );
+        assert_eq!(
+            split_args(r"SET k 'it\'s raw\n'").unwrap(),
+            vec!["SET", "k", r"it's raw\n"]
+        );
+        assert_eq!(split_args("  ").unwrap(), Vec::<String>::new());
+        assert!(split_args(r#"SET k "open"#).is_err());
+        assert!(split_args(r#"SET k "a"b"#).is_err());
+    }
+
+    #[test]
+    fn test_encode_script() {
+        let resp = encode_script("# seed\nSET k \"a b\"\n\nPING\n").unwrap();
+        assert_eq!(resp, "*3\r\n$3\r\nSET\r\n$1\r\nk\r\n$3\r\na b\r\n*1\r\n$4\r\nPING\r\n");
+        let err = encode_script("PING\nSET k \"open\n").unwrap_err();
+        assert!(err.to_string().starts_with("line 2:"));
+    }
+}
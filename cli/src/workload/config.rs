@@ -1,5 +1,7 @@
+use anyhow::{anyhow, Result};
 use serde::Deserialize;
 use std::collections::HashMap;
+use std::time::Duration;
 
 /// Main workload configuration
 #[derive(Debug, Clone, Deserialize)]
@@ -21,6 +23,11 @@ pub struct WorkloadConfig {
     /// Tables to operate on
     pub tables: Vec<String>,
 
+    /// Relative share of operations per table; unlisted tables weigh 1.0,
+    /// and a weight of 0 excludes a table
+    #[serde(default)]
+    pub table_weights: Option<HashMap<String, f64>>,
+
     /// Number of concurrent connections/workers
     #[serde(default = "default_connections")]
     pub connections: usize,
@@ -36,6 +43,164 @@ pub struct WorkloadConfig {
     /// Total transaction count (optional)
     #[serde(default)]
     pub transaction_count: Option<u64>,
+
+    /// Seed for the generated operations; the same seed and config give each
+    /// worker the same sequence of tables, operations and keys. Also accepted as
+    /// `global_seed`, as in seed configs
+    #[serde(default, alias = "global_seed")]
+    pub seed: Option<u64>,
+
+    /// Seconds to run before measuring; warmup transactions are reported
+    /// separately and left out of the run's stats
+    #[serde(default)]
+    pub warmup_seconds: Option<u64>,
+}
+
+impl WorkloadConfig {
+    /// Check settings the TOML shape can't express
+    pub fn validate(&self) -> Result<()> {
+        if let Some(WorkloadPattern::RampUp {
+            start_tps, end_tps, ..
+        }) = self.pattern
+        {
+            if start_tps == 0 || end_tps == 0 {
+                return Err(anyhow!("Ramp start_tps and end_tps must be greater than 0"));
+            }
+        }
+        if let Some(WorkloadPattern::Spike {
+            base_tps,
+            peak_tps,
+            period_seconds,
+            duty_cycle,
+        }) = self.pattern
+        {
+            if base_tps == 0 || peak_tps == 0 {
+                return Err(anyhow!("Spike base_tps and peak_tps must be greater than 0"));
+            }
+            if period_seconds == 0 {
+                return Err(anyhow!("Spike period_seconds must be greater than 0"));
+            }
+            if !(duty_cycle > 0.0 && duty_cycle < 1.0) {
+                return Err(anyhow!(
+                    "Spike duty_cycle must be between 0 and 1 (exclusive), got {}",
+                    duty_cycle
+                ));
+            }
+        }
+        if let Some(queries) = &self.custom_queries {
+            crate::workload::queries::prepare_queries(queries)?;
+        }
+
+        let Some(weights) = &self.table_weights else {
+            return Ok(());
+        };
+
+        for (table, weight) in weights {
+            if !self.tables.contains(table) {
+                return Err(anyhow!(
+                    "table_weights references '{}', which is not in tables",
+                    table
+                ));
+            }
+            if !weight.is_finite() || *weight < 0.0 {
+                return Err(anyhow!(
+                    "Weight for table '{}' must be a non-negative number, got {}",
+                    table,
+                    weight
+                ));
+            }
+        }
+
+        if self.table_weights().is_empty() {
+            return Err(anyhow!("table_weights excludes every table"));
+        }
+
+        Ok(())
+    }
+
+    /// Target TPS at `elapsed` into the run
+    ///
+    /// Fixed at `target_tps` except for a ramp-up, which climbs linearly from
+    /// `start_tps` to `end_tps` over the ramp window and then holds, and a
+    /// spike, which switches between `base_tps` and `peak_tps`.
+    pub fn target_tps_at(&self, elapsed: Duration) -> usize {
+        match self.pattern {
+            Some(WorkloadPattern::RampUp {
+                start_tps,
+                end_tps,
+                ramp_seconds,
+            }) => {
+                let ramp = Duration::from_secs(ramp_seconds);
+                if elapsed >= ramp {
+                    return end_tps;
+                }
+                let progress = elapsed.as_secs_f64() / ramp.as_secs_f64();
+                let tps = start_tps as f64 + (end_tps as f64 - start_tps as f64) * progress;
+                (tps.round() as usize).max(1)
+            }
+            Some(WorkloadPattern::Spike {
+                base_tps, peak_tps, ..
+            }) => match self.phase_at(elapsed) {
+                Some("peak") => peak_tps,
+                _ => base_tps,
+            },
+            _ => self.target_tps,
+        }
+    }
+
+    /// Name of the load-shape phase the run is in at `elapsed`: "ramp" or
+    /// "hold" for a ramp-up, "base" or "peak" for a spike, `None` otherwise
+    ///
+    /// Each spike period starts at the base rate and spends its final
+    /// `duty_cycle` fraction at the peak.
+    pub fn phase_at(&self, elapsed: Duration) -> Option<&'static str> {
+        match self.pattern? {
+            WorkloadPattern::RampUp { ramp_seconds, .. } => {
+                if elapsed >= Duration::from_secs(ramp_seconds) {
+                    Some("hold")
+                } else {
+                    Some("ramp")
+                }
+            }
+            WorkloadPattern::Spike {
+                period_seconds,
+                duty_cycle,
+                ..
+            } => {
+                let period = period_seconds.max(1) as f64;
+                let position = elapsed.as_secs_f64() % period / period;
+                if position >= 1.0 - duty_cycle {
+                    Some("peak")
+                } else {
+                    Some("base")
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Each table's normalized share of operations, without zero-weight tables
+    pub fn table_weights(&self) -> Vec<(String, f64)> {
+        let weights: Vec<(String, f64)> = self
+            .tables
+            .iter()
+            .map(|table| {
+                let weight = self
+                    .table_weights
+                    .as_ref()
+                    .and_then(|w| w.get(table).copied())
+                    .unwrap_or(1.0);
+                (table.clone(), weight)
+            })
+            .filter(|(_, weight)| *weight > 0.0)
+            .collect();
+
+        let total: f64 = weights.iter().map(|(_, weight)| weight).sum();
+        weights
+            .into_iter()
+            .map(|(table, weight)| (table, weight / total))
+            .collect()
+    }
 }
 
 fn default_connections() -> usize {
@@ -46,6 +211,10 @@ fn default_target_tps() -> usize {
     100
 }
 
+fn default_duty_cycle() -> f64 {
+    0.5
+}
+
 /// Built-in workload patterns
 #[derive(Debug, Clone, Copy, Deserialize, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -67,6 +236,25 @@ pub enum WorkloadPattern {
     ReadHeavy,
     WriteHeavy,
     Balanced,
+
+    // Load-shape patterns
+    /// Balanced mix with the target TPS rising linearly from `start_tps` to
+    /// `end_tps` over `ramp_seconds`, then holding at `end_tps`
+    RampUp {
+        start_tps: usize,
+        end_tps: usize,
+        ramp_seconds: u64,
+    },
+    /// Balanced mix with the target TPS on a square wave: each
+    /// `period_seconds` runs at `base_tps`, then spends its last `duty_cycle`
+    /// fraction at `peak_tps`
+    Spike {
+        base_tps: usize,
+        peak_tps: usize,
+        period_seconds: u64,
+        #[serde(default = "default_duty_cycle")]
+        duty_cycle: f64,
+    },
 }
 
 impl WorkloadPattern {
@@ -149,7 +337,7 @@ impl WorkloadPattern {
                 update: 0.30,
                 delete: 0.10,
             },
-            Self::Balanced => OperationWeights {
+            Self::Balanced | Self::RampUp { .. } | Self::Spike { .. } => OperationWeights {
                 select: 0.50,
                 insert: 0.25,
                 update: 0.20,
@@ -170,6 +358,8 @@ impl WorkloadPattern {
             Self::ReadHeavy => "Generic: 80% reads, 20% writes",
             Self::WriteHeavy => "Generic: 80% writes, 20% reads",
             Self::Balanced => "Generic: 50% reads, 50% writes",
+            Self::RampUp { .. } => "Ramp-up: Balanced mix with a linearly increasing target TPS",
+            Self::Spike { .. } => "Spike: Balanced mix alternating between a base and a peak TPS",
         }
     }
 
@@ -185,6 +375,8 @@ impl WorkloadPattern {
             Self::ReadHeavy => "read_heavy",
             Self::WriteHeavy => "write_heavy",
             Self::Balanced => "balanced",
+            Self::RampUp { .. } => "ramp_up",
+            Self::Spike { .. } => "spike",
         }
     }
 }
@@ -267,6 +459,17 @@ pub struct QueryParameter {
     pub options: HashMap<String, toml::Value>,
 }
 
+impl QueryParameter {
+    /// Options for the parameter's generator, given inline or in an
+    /// `options` table
+    pub fn generator_options(&self) -> HashMap<String, toml::Value> {
+        match self.options.get("options") {
+            Some(toml::Value::Table(table)) => table.clone().into_iter().collect(),
+            _ => self.options.clone(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -291,6 +494,153 @@ mod tests {
         assert_eq!(config.duration_seconds, Some(60));
     }
 
+    #[test]
+    fn test_seed_accepts_global_seed() {
+        let toml = r#"
+            name = "Replay"
+            pattern = "oltp"
+            tables = ["orders"]
+            global_seed = 1843207771
+        "#;
+
+        let config: WorkloadConfig = toml::from_str(toml).unwrap();
+        assert_eq!(config.seed, Some(1843207771));
+    }
+
+    #[test]
+    fn test_table_weights() {
+        let toml = r#"
+            name = "Hot table"
+            pattern = "oltp"
+            tables = ["orders", "users", "audit"]
+
+            [table_weights]
+            orders = 8
+            audit = 0
+        "#;
+        let config: WorkloadConfig = toml::from_str(toml).unwrap();
+        config.validate().unwrap();
+
+        let weights = config.table_weights();
+        assert_eq!(weights.len(), 2);
+        assert_eq!(weights[0].0, "orders");
+        assert!((weights[0].1 - 8.0 / 9.0).abs() < 1e-9);
+        assert_eq!(weights[1].0, "users");
+        assert!((weights[1].1 - 1.0 / 9.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_table_weights_must_reference_tables() {
+        let toml = r#"
+            name = "Typo"
+            tables = ["orders"]
+            table_weights = { order = 1.0 }
+        "#;
+        let config: WorkloadConfig = toml::from_str(toml).unwrap();
+        assert!(config.validate().is_err());
+
+        let toml = r#"
+            name = "Nothing left"
+            tables = ["orders"]
+            table_weights = { orders = 0.0 }
+        "#;
+        let config: WorkloadConfig = toml::from_str(toml).unwrap();
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_ramp_up_config() {
+        let toml = r#"
+            name = "Ramp"
+            pattern = { ramp_up = { start_tps = 10, end_tps = 1000, ramp_seconds = 60 } }
+            tables = ["users"]
+            duration_seconds = 120
+        "#;
+        let config: WorkloadConfig = toml::from_str(toml).unwrap();
+        config.validate().unwrap();
+        assert_eq!(
+            config.pattern,
+            Some(WorkloadPattern::RampUp {
+                start_tps: 10,
+                end_tps: 1000,
+                ramp_seconds: 60
+            })
+        );
+
+        assert_eq!(config.target_tps_at(Duration::ZERO), 10);
+        assert_eq!(config.target_tps_at(Duration::from_secs(30)), 505);
+        assert_eq!(config.target_tps_at(Duration::from_secs(60)), 1000);
+        assert_eq!(config.target_tps_at(Duration::from_secs(90)), 1000);
+    }
+
+    #[test]
+    fn test_target_tps_fixed_without_ramp() {
+        let toml = r#"
+            name = "Steady"
+            pattern = "oltp"
+            tables = ["users"]
+            target_tps = 250
+        "#;
+        let config: WorkloadConfig = toml::from_str(toml).unwrap();
+        assert_eq!(config.target_tps_at(Duration::ZERO), 250);
+        assert_eq!(config.target_tps_at(Duration::from_secs(600)), 250);
+    }
+
+    #[test]
+    fn test_ramp_up_rejects_zero_tps() {
+        let toml = r#"
+            name = "Ramp"
+            pattern = { ramp_up = { start_tps = 0, end_tps = 100, ramp_seconds = 10 } }
+            tables = ["users"]
+        "#;
+        let config: WorkloadConfig = toml::from_str(toml).unwrap();
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_spike_config() {
+        let toml = r#"
+            name = "Spike"
+            pattern = { spike = { base_tps = 50, peak_tps = 500, period_seconds = 10 } }
+            tables = ["users"]
+        "#;
+        let config: WorkloadConfig = toml::from_str(toml).unwrap();
+        config.validate().unwrap();
+        assert_eq!(
+            config.pattern,
+            Some(WorkloadPattern::Spike {
+                base_tps: 50,
+                peak_tps: 500,
+                period_seconds: 10,
+                duty_cycle: 0.5
+            })
+        );
+
+        // Base for the first half of every period, peak for the second
+        assert_eq!(config.target_tps_at(Duration::ZERO), 50);
+        assert_eq!(config.phase_at(Duration::from_secs(4)), Some("base"));
+        assert_eq!(config.target_tps_at(Duration::from_secs(5)), 500);
+        assert_eq!(config.phase_at(Duration::from_millis(9_900)), Some("peak"));
+        assert_eq!(config.target_tps_at(Duration::from_secs(12)), 50);
+        assert_eq!(config.target_tps_at(Duration::from_secs(17)), 500);
+    }
+
+    #[test]
+    fn test_spike_rejects_bad_duty_cycle() {
+        for duty_cycle in ["0.0", "1.0", "1.5"] {
+            let toml = format!(
+                r#"
+                name = "Spike"
+                pattern = {{ spike = {{ base_tps = 50, peak_tps = 500, period_seconds = 10, duty_cycle = {} }} }}
+                tables = ["users"]
+                "#,
+                duty_cycle
+            );
+            let config: WorkloadConfig = toml::from_str(&toml).unwrap();
+            assert!(config.validate().is_err(), "accepted duty_cycle {}", duty_cycle);
+        }
+    }
+
     #[test]
     fn test_pattern_from_str() {
         assert_eq!(
@@ -1,37 +1,55 @@
 use console::style;
+use std::sync::Arc;
 use std::time::{Duration, Instant};
 
-use crate::workload::stats::WorkloadStats;
+use crate::progress::Progress;
+use crate::workload::config::WorkloadConfig;
+use crate::workload::stats::{LatencyBucket, WorkloadStats};
 
 /// Live progress display for workload execution
+///
+/// As a [`Progress`] reporter, each update redraws the dashboard from the
+/// shared stats.
 pub struct WorkloadProgressDisplay {
+    stats: Arc<WorkloadStats>,
     start_time: Instant,
     target_tps: usize,
     duration: Option<Duration>,
     transaction_count: Option<u64>,
+    /// Config whose schedule sets the target over time, for ramp-ups and
+    /// spikes
+    schedule: Option<WorkloadConfig>,
 }
 
 impl WorkloadProgressDisplay {
     pub fn new(
+        stats: Arc<WorkloadStats>,
         target_tps: usize,
         duration: Option<Duration>,
         transaction_count: Option<u64>,
     ) -> Self {
         Self {
+            stats,
             start_time: Instant::now(),
             target_tps,
             duration,
             transaction_count,
+            schedule: None,
         }
     }
 
+    /// Show the target from `config`'s schedule instead of a fixed TPS
+    pub fn with_target_schedule(mut self, config: WorkloadConfig) -> Self {
+        self.schedule = Some(config);
+        self
+    }
+
     /// Render the current progress
     pub fn render(&self, stats: &WorkloadStats) {
         let elapsed = self.start_time.elapsed();
         let snapshot = stats.snapshot();
 
-        // Clear previous lines (move up and clear)
-        print!("\x1B[2J\x1B[1;1H"); // Clear screen and move to top
+        self.clear();
 
         println!("{}", style("=".repeat(70)).dim());
         println!("{}", style("Workload Progress").cyan().bold());
@@ -66,15 +84,20 @@ impl WorkloadProgressDisplay {
         println!();
 
         // Throughput
+        let target_tps = self
+            .schedule
+            .as_ref()
+            .map(|config| config.target_tps_at(stats.elapsed()))
+            .unwrap_or(self.target_tps);
         println!(
             "  {} TPS: {} (target: {})",
             style("⚡").yellow(),
             style(format!("{:.1}", snapshot.tps)).green().bold(),
-            style(self.target_tps).dim()
+            style(target_tps).dim()
         );
 
-        let tps_diff = snapshot.tps - self.target_tps as f64;
-        let tps_indicator = if tps_diff.abs() < self.target_tps as f64 * 0.1 {
+        let tps_diff = snapshot.tps - target_tps as f64;
+        let tps_indicator = if tps_diff.abs() < target_tps as f64 * 0.1 {
             style(format!("✓ On target")).green()
         } else if tps_diff > 0.0 {
             style(format!("▲ +{:.1} above target", tps_diff)).yellow()
@@ -83,6 +106,20 @@ impl WorkloadProgressDisplay {
         };
         println!("     {}", tps_indicator);
 
+        let phase = self
+            .schedule
+            .as_ref()
+            .and_then(|config| config.phase_at(stats.elapsed()));
+        if let Some(phase) = phase {
+            let phase = phase.to_uppercase();
+            let phase = if phase == "PEAK" {
+                style(phase).red().bold()
+            } else {
+                style(phase).cyan().bold()
+            };
+            println!("     Phase: {}", phase);
+        }
+
         println!();
 
         // Success rate
@@ -163,6 +200,11 @@ impl WorkloadProgressDisplay {
         println!("  {} Press Ctrl+C to stop", style("ℹ").blue());
     }
 
+    /// Clear the dashboard from the screen
+    fn clear(&self) {
+        print!("\x1B[2J\x1B[1;1H");
+    }
+
     /// Print a simple progress bar
     fn print_progress_bar(&self, percent: usize) {
         let width = 50;
@@ -176,8 +218,20 @@ impl WorkloadProgressDisplay {
     }
 }
 
-/// Print final summary after workload completes
-pub fn print_summary(stats: &WorkloadStats, pattern_name: &str) {
+impl Progress for WorkloadProgressDisplay {
+    fn start(&self, _message: &str, _total: Option<u64>) {}
+
+    fn update(&self, _position: u64, _message: Option<&str>) {
+        self.render(&self.stats);
+    }
+
+    fn finish(&self, _message: &str) {
+        self.clear();
+    }
+}
+
+/// Print final summary after workload completes, optionally with the latency histogram
+pub fn print_summary(stats: &WorkloadStats, pattern_name: &str, histogram: bool) {
     let snapshot = stats.snapshot();
 
     println!();
@@ -196,6 +250,24 @@ pub fn print_summary(stats: &WorkloadStats, pattern_name: &str) {
     println!();
 
     println!("  {} Throughput: {:.1} TPS", style("⚡").yellow(), style(snapshot.tps).green().bold());
+    for phase in &snapshot.phases {
+        println!(
+            "     {} ({:.0}-{:.0}s): {:.1} TPS achieved, {:.1} target",
+            phase.name, phase.start_secs, phase.end_secs, phase.achieved_tps, phase.target_tps
+        );
+    }
+    if let Some(warmup) = &snapshot.warmup {
+        let latency = match (warmup.p50_us, warmup.p99_us) {
+            (Some(p50), Some(p99)) => {
+                format!(", P50 {:.2}ms, P99 {:.2}ms", p50 as f64 / 1000.0, p99 as f64 / 1000.0)
+            }
+            _ => String::new(),
+        };
+        println!(
+            "     Warmup ({:.0}s, not counted): {:.1} TPS, {} transactions, {} failed{}",
+            warmup.duration_secs, warmup.tps, warmup.total, warmup.failed, latency
+        );
+    }
     println!();
 
     println!("  {} Latency:", style("⏲").cyan());
@@ -211,14 +283,44 @@ pub fn print_summary(stats: &WorkloadStats, pattern_name: &str) {
     if let Some(max) = snapshot.max {
         println!("     Max: {:.2}ms", max as f64 / 1000.0);
     }
+    for phase in &snapshot.phase_latencies {
+        println!(
+            "     {}: P50 {:.2}ms, P95 {:.2}ms, P99 {:.2}ms, Max {:.2}ms ({} ops)",
+            phase.name,
+            phase.p50_us as f64 / 1000.0,
+            phase.p95_us as f64 / 1000.0,
+            phase.p99_us as f64 / 1000.0,
+            phase.max_us as f64 / 1000.0,
+            phase.count
+        );
+    }
     println!();
 
+    if histogram {
+        println!("  {} Latency Histogram:", style("📈").cyan());
+        for line in render_histogram(&snapshot.latency_buckets, 40) {
+            println!("     {}", line);
+        }
+        println!();
+    }
+
     if !snapshot.operation_counts.is_empty() {
         println!("  {} Operation Distribution:", style("📝").cyan());
         let total_ops: u64 = snapshot.operation_counts.values().sum();
         for (op, count) in &snapshot.operation_counts {
             let percentage = (*count as f64 / total_ops as f64) * 100.0;
-            println!("     {}: {} ({:.1}%)", op, count, percentage);
+            match snapshot.operation_latencies.iter().find(|l| &l.name == op) {
+                Some(latency) => println!(
+                    "     {}: {} ({:.1}%), P50 {:.2}ms, P95 {:.2}ms, P99 {:.2}ms",
+                    op,
+                    count,
+                    percentage,
+                    latency.p50_us as f64 / 1000.0,
+                    latency.p95_us as f64 / 1000.0,
+                    latency.p99_us as f64 / 1000.0
+                ),
+                None => println!("     {}: {} ({:.1}%)", op, count, percentage),
+            }
         }
         println!();
     }
@@ -234,3 +336,59 @@ pub fn print_summary(stats: &WorkloadStats, pattern_name: &str) {
     println!("{}", style("=".repeat(70)).dim());
     println!();
 }
+
+/// Render latency buckets as ASCII bars scaled so the largest bucket is `width` wide
+///
+/// Empty buckets before the first and after the last populated one are omitted.
+pub fn render_histogram(buckets: &[LatencyBucket], width: usize) -> Vec<String> {
+    let first = buckets.iter().position(|b| b.count > 0);
+    let last = buckets.iter().rposition(|b| b.count > 0);
+    let (Some(first), Some(last)) = (first, last) else {
+        return vec!["(no successful operations)".to_string()];
+    };
+
+    let total: u64 = buckets.iter().map(|b| b.count).sum();
+    let max = buckets.iter().map(|b| b.count).max().unwrap_or(0);
+    let label_width = buckets.iter().map(|b| b.label.len()).max().unwrap_or(0);
+
+    buckets[first..=last]
+        .iter()
+        .map(|b| {
+            let filled = (b.count as f64 / max as f64 * width as f64).round() as usize;
+            // Keep non-empty buckets visible
+            let filled = if b.count > 0 { filled.max(1) } else { 0 };
+            format!(
+                "{:>label_width$} │{:<width$} {} ({:.1}%)",
+                b.label,
+                "█".repeat(filled),
+                b.count,
+                b.count as f64 / total as f64 * 100.0,
+                label_width = label_width,
+                width = width
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::workload::stats::bucket_latencies;
+
+    #[test]
+    fn test_render_histogram() {
+        let buckets = bucket_latencies([(500, 1), (2_000, 4), (60_000, 2)]);
+        let lines = render_histogram(&buckets, 8);
+
+        // <1ms through 50-100ms; trailing empty buckets dropped
+        assert_eq!(lines.len(), 6);
+        assert!(lines[0].starts_with("     <1ms │██ "));
+        assert!(lines[1].contains("│████████ 4 (57.1%)"));
+        assert!(lines[2].contains("│         0 (0.0%)"));
+
+        assert_eq!(
+            render_histogram(&bucket_latencies(std::iter::empty()), 8),
+            vec!["(no successful operations)"]
+        );
+    }
+}
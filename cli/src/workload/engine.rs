@@ -1,22 +1,30 @@
 // This file will replace engine.rs - creating as v2 first to avoid breaking existing code
 use anyhow::{anyhow, Result};
 use bollard::Docker;
-use rand::{Rng, SeedableRng};
+use rand::Rng;
 use rand_chacha::ChaCha8Rng;
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tokio::sync::{mpsc, Mutex as TokioMutex};
-use tokio::time::sleep;
+use tokio::task::JoinHandle;
+use tokio::time::{interval, sleep};
+use tracing::{info, warn};
 
-use crate::container::DatabaseType;
+use crate::container::{ConnParams, DatabaseType, SQLITE_DB_PATH};
 use crate::database_metrics::collector::DockerDatabaseMetricsCollector;
-use crate::workload::config::{OperationWeights, WorkloadConfig};
+use crate::seed::foreign_key::ForeignKeyResolver;
+use crate::workload::config::{OperationWeights, WorkloadConfig, WorkloadPattern};
 use crate::workload::metadata::{MetadataCollector, TableMetadata};
+use crate::workload::metadata_cache::MetadataCache;
 use crate::workload::operations::OperationGenerator;
+use crate::workload::queries::{prepare_queries, PreparedQuery};
 use crate::workload::rate_limiter::RateLimiter;
 use crate::workload::stats::{MetricSample, WorkloadStats};
 
+/// How often a ramp-up or spike retargets the rate limiter
+const RATE_SCHEDULE_INTERVAL: Duration = Duration::from_millis(250);
+
 /// Main workload generation engine with realistic operations
 pub struct WorkloadEngine {
     container_id: String,
@@ -25,7 +33,12 @@ pub struct WorkloadEngine {
     docker_client: Arc<Docker>,
     rate_limiter: Arc<RateLimiter>,
     stats: Arc<WorkloadStats>,
+    /// Samples from the warmup, kept out of `stats`
+    warmup_stats: Arc<WorkloadStats>,
     metadata: Arc<TokioMutex<HashMap<String, TableMetadata>>>,
+    /// Credentials, and for an external database its address
+    conn: Arc<ConnParams>,
+    metadata_cache: Option<MetadataCache>,
 }
 
 impl WorkloadEngine {
@@ -35,7 +48,7 @@ impl WorkloadEngine {
         config: WorkloadConfig,
         docker_client: Arc<Docker>,
     ) -> Self {
-        let rate_limiter = Arc::new(RateLimiter::new(config.target_tps));
+        let rate_limiter = Arc::new(RateLimiter::new(config.target_tps_at(Duration::ZERO)));
         let stats = Arc::new(WorkloadStats::new());
 
         Self {
@@ -45,19 +58,87 @@ impl WorkloadEngine {
             docker_client,
             rate_limiter,
             stats,
+            warmup_stats: Arc::new(WorkloadStats::new()),
             metadata: Arc::new(TokioMutex::new(HashMap::new())),
+            conn: Arc::new(ConnParams::from_env(db_type, HashMap::new())),
+            metadata_cache: None,
         }
     }
 
+    /// Reuse table metadata cached on disk by earlier runs while the schema is unchanged
+    pub fn with_metadata_cache(mut self, cache: Option<MetadataCache>) -> Self {
+        self.metadata_cache = cache;
+        self
+    }
+
+    /// Connect with these parameters instead of the image defaults
+    ///
+    /// Typically the running container's own (see
+    /// [`crate::container::connection_params`]), or an external database's
+    /// from [`ConnParams::from_url`], whose statements then run through the
+    /// native client on this host.
+    pub fn with_connection(mut self, conn: ConnParams) -> Self {
+        self.conn = Arc::new(conn);
+        self
+    }
+
     /// Run the workload
     pub async fn run(&self) -> Result<WorkloadStats> {
-        println!("Starting workload with {} workers", self.config.connections);
-        println!("Target TPS: {}", self.config.target_tps);
+        info!("Starting workload with {} workers", self.config.connections);
+        if let Some(WorkloadPattern::RampUp {
+            start_tps,
+            end_tps,
+            ramp_seconds,
+        }) = self.config.pattern
+        {
+            info!("Ramping from {} to {} TPS over {}s", start_tps, end_tps, ramp_seconds);
+        } else if let Some(WorkloadPattern::Spike {
+            base_tps,
+            peak_tps,
+            period_seconds,
+            duty_cycle,
+        }) = self.config.pattern
+        {
+            info!(
+                "Spiking from {} to {} TPS for {:.0}% of every {}s",
+                base_tps,
+                peak_tps,
+                duty_cycle * 100.0,
+                period_seconds
+            );
+        } else {
+            info!("Target TPS: {}", self.config.target_tps);
+        }
+
+        // Custom queries replace the generated operations, which need table
+        // metadata collected first
+        let queries = match &self.config.custom_queries {
+            Some(queries) => {
+                info!("Running {} custom queries", queries.len());
+                let mut queries = prepare_queries(queries)?;
+                self.load_referenced_values(&mut queries).await?;
+                Arc::new(queries)
+            }
+            None => {
+                info!("Collecting table metadata...");
+                self.collect_metadata().await?;
+                info!("Metadata collected for {} tables", self.config.tables.len());
+                Arc::new(Vec::new())
+            }
+        };
+
+        // Open every connection before timing starts
+        self.open_connections().await?;
 
-        // Collect table metadata first
-        println!("Collecting table metadata...");
-        self.collect_metadata().await?;
-        println!("Metadata collected for {} tables", self.config.tables.len());
+        // Measurement starts once the warmup ends; until then the clock reads
+        // zero, so the rate holds at its starting target
+        let warmup = self
+            .config
+            .warmup_seconds
+            .filter(|secs| *secs > 0)
+            .map(Duration::from_secs);
+        let warmup_until = warmup.map(|warmup| Instant::now() + warmup);
+        self.stats.start_at(warmup_until.unwrap_or_else(Instant::now));
 
         // Create channel for metric samples
         let (tx, mut rx) = mpsc::channel::<MetricSample>(1000);
@@ -65,7 +146,9 @@ impl WorkloadEngine {
         // Spawn worker tasks
         let mut worker_handles = vec![];
         for worker_id in 0..self.config.connections {
-            let handle = self.spawn_worker(worker_id, tx.clone()).await;
+            let handle = self
+                .spawn_worker(worker_id, tx.clone(), queries.clone(), warmup_until)
+                .await;
             worker_handles.push(handle);
         }
 
@@ -74,18 +157,31 @@ impl WorkloadEngine {
 
         // Spawn aggregator task
         let stats = self.stats.clone();
+        let warmup_stats = self.warmup_stats.clone();
         let aggregator = tokio::spawn(async move {
             while let Some(sample) = rx.recv().await {
-                stats.record_sample(sample);
+                if sample.warmup {
+                    warmup_stats.record_sample(sample);
+                } else {
+                    stats.record_sample(sample);
+                }
             }
         });
 
+        let scheduler = self.spawn_rate_scheduler();
+
+        if let Some(warmup) = warmup {
+            info!("Warming up for {}s", warmup.as_secs());
+            sleep(warmup).await;
+            self.stats.set_warmup(self.warmup_stats.warmup_summary(warmup));
+        }
+
         // Wait for duration or transaction count
         if let Some(duration) = self.config.duration_seconds {
-            println!("Running for {} seconds", duration);
+            info!("Running for {} seconds", duration);
             sleep(Duration::from_secs(duration)).await;
         } else if let Some(count) = self.config.transaction_count {
-            println!("Running until {} transactions", count);
+            info!("Running until {} transactions", count);
             while self.stats.total() < count {
                 sleep(Duration::from_millis(100)).await;
             }
@@ -99,6 +195,12 @@ impl WorkloadEngine {
         // Wait for aggregator to finish
         let _ = aggregator.await;
 
+        if let Some(scheduler) = scheduler {
+            scheduler.abort();
+            let _ = scheduler.await;
+            self.end_ramp_phases();
+        }
+
         // Return final stats
         Ok(WorkloadStats {
             total_transactions: std::sync::atomic::AtomicU64::new(self.stats.total()),
@@ -111,18 +213,124 @@ impl WorkloadEngine {
             operation_counts: std::sync::Mutex::new(
                 self.stats.operation_counts.lock().unwrap().clone(),
             ),
-            start_time: self.stats.start_time,
+            phases: std::sync::Mutex::new(self.stats.phases()),
+            phase_histograms: std::sync::Mutex::new(
+                self.stats.phase_histograms.lock().unwrap().clone(),
+            ),
+            operation_histograms: std::sync::Mutex::new(
+                self.stats.operation_histograms.lock().unwrap().clone(),
+            ),
+            warmup: std::sync::Mutex::new(self.stats.warmup()),
+            start_time: std::sync::Mutex::new(self.stats.start_time()),
         })
     }
 
-    /// Collect metadata for all tables
-    async fn collect_metadata(&self) -> Result<()> {
+    /// Retarget the rate limiter as a ramp-up progresses and close the ramp
+    /// phase once it reaches `end_tps`, or switch a spike between its base and
+    /// peak for the whole run; `None` for fixed-rate patterns
+    fn spawn_rate_scheduler(&self) -> Option<JoinHandle<()>> {
+        let config = self.config.clone();
+        let rate_limiter = self.rate_limiter.clone();
+        let stats = self.stats.clone();
+
+        let (start_tps, end_tps, ramp_seconds) = match self.config.pattern {
+            Some(WorkloadPattern::RampUp {
+                start_tps,
+                end_tps,
+                ramp_seconds,
+            }) => (start_tps, end_tps, ramp_seconds),
+            Some(WorkloadPattern::Spike { .. }) => {
+                return Some(tokio::spawn(async move {
+                    let mut ticker = interval(RATE_SCHEDULE_INTERVAL);
+                    loop {
+                        ticker.tick().await;
+                        rate_limiter.set_tps(config.target_tps_at(stats.elapsed()));
+                    }
+                }));
+            }
+            _ => return None,
+        };
+
+        Some(tokio::spawn(async move {
+            let mut ticker = interval(RATE_SCHEDULE_INTERVAL);
+            loop {
+                ticker.tick().await;
+                let elapsed = stats.elapsed();
+                rate_limiter.set_tps(config.target_tps_at(elapsed));
+
+                if elapsed >= Duration::from_secs(ramp_seconds) {
+                    stats.end_phase("ramp", (start_tps + end_tps) as f64 / 2.0);
+                    break;
+                }
+            }
+        }))
+    }
+
+    /// Close the hold phase, or the ramp itself if the run ended mid-ramp
+    fn end_ramp_phases(&self) {
+        let Some(WorkloadPattern::RampUp {
+            start_tps, end_tps, ..
+        }) = self.config.pattern
+        else {
+            return;
+        };
+
+        if self.stats.phases().is_empty() {
+            let reached = self.config.target_tps_at(self.stats.elapsed());
+            self.stats.end_phase("ramp", (start_tps + reached) as f64 / 2.0);
+        } else {
+            self.stats.end_phase("hold", end_tps as f64);
+        }
+    }
+
+    /// Run a trivial query on each of the workload's connections so the first
+    /// measured transactions don't pay for client startup and authentication
+    async fn open_connections(&self) -> Result<()> {
+        info!("Opening {} connections...", self.config.connections);
         let collector = DockerDatabaseMetricsCollector::new(self.docker_client.clone());
-        let mut metadata_collector = MetadataCollector::new(
-            collector,
+        let results = futures::future::join_all((0..self.config.connections).map(|_| {
+            execute_sql(&collector, &self.container_id, self.db_type, &self.conn, "SELECT 1")
+        }))
+        .await;
+
+        results
+            .into_iter()
+            .collect::<Result<Vec<_>>>()
+            .map_err(|e| anyhow!("Failed to open a connection: {}", e))?;
+        Ok(())
+    }
+
+    /// Load the existing values `foreign_key` query parameters draw from
+    async fn load_referenced_values(&self, queries: &mut [PreparedQuery]) -> Result<()> {
+        let resolver = ForeignKeyResolver::new(
+            self.docker_client.clone(),
             self.container_id.clone(),
             self.db_type,
-        );
+        )
+        .with_connection(self.conn.as_ref().clone());
+
+        for query in queries.iter_mut() {
+            for reference in query.references() {
+                let ids = resolver
+                    .ids(&reference.table, &reference.column)
+                    .await
+                    .map_err(|e| anyhow!("Query '{}': {}", query.name, e))?;
+                query.set_referenced_values(&reference.table, &reference.column, &ids);
+            }
+        }
+        Ok(())
+    }
+
+    /// Collect metadata for all tables
+    async fn collect_metadata(&self) -> Result<()> {
+        let collector = DockerDatabaseMetricsCollector::new(self.docker_client.clone());
+        let mut metadata_collector =
+            MetadataCollector::new(collector, self.container_id.clone(), self.db_type)
+                .with_connection(self.conn.as_ref().clone());
+        if let Some(cache) = &self.metadata_cache {
+            metadata_collector = metadata_collector.with_disk_cache(cache.clone());
+            metadata_collector.load_disk_cache().await;
+        }
 
         let mut metadata_map = self.metadata.lock().await;
 
@@ -132,10 +340,11 @@ impl WorkloadEngine {
                     metadata_map.insert(table.clone(), metadata.clone());
                 }
                 Err(e) => {
-                    println!("Warning: Failed to collect metadata for table '{}': {}", table, e);
+                    warn!("Failed to collect metadata for table '{}': {}", table, e);
                 }
             }
         }
+        metadata_collector.save_disk_cache();
 
         if metadata_map.is_empty() {
             return Err(anyhow!("No table metadata could be collected"));
@@ -149,6 +358,8 @@ impl WorkloadEngine {
         &self,
         worker_id: usize,
         tx: mpsc::Sender<MetricSample>,
+        queries: Arc<Vec<PreparedQuery>>,
+        warmup_until: Option<Instant>,
     ) -> tokio::task::JoinHandle<()> {
         let container_id = self.container_id.clone();
         let db_type = self.db_type;
@@ -157,11 +368,15 @@ impl WorkloadEngine {
         let rate_limiter = self.rate_limiter.clone();
         let stats = self.stats.clone();
         let metadata = self.metadata.clone();
+        let conn = self.conn.clone();
 
         tokio::spawn(async move {
             let collector = DockerDatabaseMetricsCollector::new(docker_client);
-            let op_gen = OperationGenerator::new(db_type);
-            let mut rng = ChaCha8Rng::seed_from_u64(worker_id as u64);
+            let op_gen = OperationGenerator::new(db_type)
+                .with_table_weights(config.table_weights())
+                .with_seed(config.seed.unwrap_or(0))
+                .with_custom_queries(queries);
+            let mut rng = op_gen.worker_rng(worker_id);
 
             // Get operation weights
             let weights = if let Some(pattern) = config.pattern {
@@ -193,23 +408,41 @@ impl WorkloadEngine {
 
                 // Wait for rate limiter
                 rate_limiter.acquire().await;
+                let warmup = warmup_until.is_some_and(|until| Instant::now() < until);
+                let phase = if warmup {
+                    None
+                } else {
+                    config.phase_at(stats.elapsed())
+                };
 
-                // Select operation type
-                let op_type = select_operation(&weights, &mut rng);
-
-                // Execute operation
+                // Run a custom query, or select and execute an operation
                 let start = Instant::now();
-                let result = execute_operation_with_metadata(
-                    &collector,
-                    &container_id,
-                    db_type,
-                    &op_gen,
-                    op_type,
-                    &metadata,
-                    &config.tables,
-                    &mut rng,
-                )
-                .await;
+                let (operation_type, result) = match op_gen.pick_query(&mut rng) {
+                    Some(query) => {
+                        let result = match query.bind(db_type, &mut rng) {
+                            Ok(sql) => {
+                                execute_sql(&collector, &container_id, db_type, &conn, &sql).await
+                            }
+                            Err(e) => Err(e),
+                        };
+                        (query.name.clone(), result)
+                    }
+                    None => {
+                        let op_type = select_operation(&weights, &mut rng);
+                        let result = execute_operation_with_metadata(
+                            &collector,
+                            &container_id,
+                            db_type,
+                            &conn,
+                            &op_gen,
+                            op_type,
+                            &metadata,
+                            &mut rng,
+                        )
+                        .await;
+                        (op_type.to_string(), result)
+                    }
+                };
 
                 let latency = start.elapsed();
 
@@ -217,17 +450,21 @@ impl WorkloadEngine {
                 let sample = match result {
                     Ok(_) => MetricSample {
                         worker_id,
-                        operation_type: op_type.to_string(),
+                        operation_type,
                         success: true,
                         latency_us: latency.as_micros() as u64,
                         error: None,
+                        phase,
+                        warmup,
                     },
                     Err(e) => MetricSample {
                         worker_id,
-                        operation_type: op_type.to_string(),
+                        operation_type,
                         success: false,
                         latency_us: latency.as_micros() as u64,
                         error: Some(e.to_string()),
+                        phase,
+                        warmup,
                     },
                 };
 
@@ -259,23 +496,21 @@ fn select_operation(weights: &OperationWeights, rng: &mut ChaCha8Rng) -> &'stati
 }
 
 /// Execute operation with realistic SQL
+#[allow(clippy::too_many_arguments)]
 async fn execute_operation_with_metadata(
     collector: &DockerDatabaseMetricsCollector,
     container_id: &str,
     db_type: DatabaseType,
+    conn: &ConnParams,
     op_gen: &OperationGenerator,
     op_type: &str,
     metadata_map: &Arc<TokioMutex<HashMap<String, TableMetadata>>>,
-    tables: &[String],
     rng: &mut ChaCha8Rng,
 ) -> Result<()> {
-    if tables.is_empty() {
-        return Err(anyhow!("No tables configured"));
-    }
-
-    // Select random table
-    let table_idx = rng.gen_range(0..tables.len());
-    let table = &tables[table_idx];
+    // Select table by weight
+    let table = op_gen
+        .pick_table(rng)
+        .ok_or_else(|| anyhow!("No tables configured"))?;
 
     // Get metadata for table
     let metadata = {
@@ -299,23 +534,32 @@ async fn execute_operation_with_metadata(
         _ => return Err(anyhow!("Unknown operation type: {}", op_type)),
     };
 
-    let sql = operation.sql();
-
-    // Execute SQL
-    let command = match db_type {
-        DatabaseType::Postgres => vec!["psql", "-U", "postgres", "-c", sql],
-        DatabaseType::MySQL => vec!["mysql", "-uroot", "-proot", "-e", sql],
-        DatabaseType::SQLServer => vec![
-            "/opt/mssql-tools18/bin/sqlcmd",
-            "-S", "localhost",
-            "-U", "sa",
-            "-P", "YourStrong@Passw0rd",
-            "-C",
-            "-Q", sql,
-        ],
+    execute_sql(collector, container_id, db_type, conn, operation.sql()).await
+}
+
+/// Run one statement through the database's client
+async fn execute_sql(
+    collector: &DockerDatabaseMetricsCollector,
+    container_id: &str,
+    db_type: DatabaseType,
+    conn: &ConnParams,
+    sql: &str,
+) -> Result<()> {
+    let mut command = conn.client_command()?;
+    let options: &[&str] = match db_type {
+        DatabaseType::Postgres => &["-c"],
+        DatabaseType::CockroachDB | DatabaseType::MySQL => &["-e"],
+        DatabaseType::SQLServer => &["-Q"],
+        // Workers share one file; wait out each other's write locks
+        DatabaseType::SQLite => &["-cmd", ".timeout 30000", SQLITE_DB_PATH],
+        DatabaseType::Redis | DatabaseType::MongoDB => {
+            return Err(anyhow!("Workloads are not supported for NoSQL databases"));
+        }
     };
+    command.extend(options.iter().map(|s| s.to_string()));
+    command.push(sql.to_string());
 
-    let output = collector.exec_query(container_id, command).await?;
+    let output = collector.exec_client(container_id, conn, &command).await?;
 
     // Check for errors
     if output.to_lowercase().contains("error") && !output.contains("0 rows") {
@@ -1,12 +1,16 @@
 use anyhow::{anyhow, Result};
-use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
+use tracing::{debug, warn};
 
-use crate::container::DatabaseType;
+use super::metadata_cache::MetadataCache;
+use crate::container::{ConnParams, DatabaseType, SQLITE_DB_PATH};
 use crate::database_metrics::collector::DockerDatabaseMetricsCollector;
+use crate::seed::escape_ident;
 
 /// Metadata about a database table
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TableMetadata {
     pub name: String,
     pub columns: Vec<ColumnMetadata>,
@@ -15,7 +19,7 @@ pub struct TableMetadata {
 }
 
 /// Metadata about a table column
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ColumnMetadata {
     pub name: String,
     pub data_type: String,
@@ -23,6 +27,24 @@ pub struct ColumnMetadata {
     pub is_primary_key: bool,
 }
 
+/// An index on a table, primary key indexes included
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IndexMetadata {
+    pub name: String,
+    /// Key columns in index order
+    pub columns: Vec<String>,
+    pub is_unique: bool,
+}
+
+/// One column of a foreign key and the column it references
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ForeignKeyMetadata {
+    pub column: String,
+    pub referenced_table: String,
+    /// Empty when SQLite leaves it implicit (the referenced table's primary key)
+    pub referenced_column: String,
+}
+
 impl TableMetadata {
     /// Get non-primary-key columns that can be updated
     pub fn updatable_columns(&self) -> Vec<&ColumnMetadata> {
@@ -38,12 +60,60 @@ impl TableMetadata {
     }
 }
 
+/// Rows [`MetadataCollector::profile_column`] reads, at most (roughly, where it
+/// samples with `TABLESAMPLE`)
+pub const PROFILE_SAMPLE_ROWS: usize = 10_000;
+
+/// Distinct values a profile lists, at most; columns with more have none listed
+pub const PROFILE_MAX_VALUES: usize = 20;
+
+/// What to measure when profiling a column
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProfileKind {
+    /// Min and max as numbers
+    Numeric,
+    /// Min and max as ISO timestamps
+    Temporal,
+    /// The distinct values, if there are few
+    Values,
+}
+
+/// Distribution of a column's values in a sample of its table
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct ColumnProfile {
+    pub sampled_rows: u64,
+    pub non_null: u64,
+    /// Distinct non-null values in the sample
+    pub distinct: u64,
+    pub min: Option<String>,
+    pub max: Option<String>,
+    /// Every distinct value, for a `Values` profile with at most
+    /// [`PROFILE_MAX_VALUES`] of them
+    pub values: Vec<String>,
+}
+
+impl ColumnProfile {
+    /// Fraction of sampled rows that are NULL
+    pub fn null_fraction(&self) -> f64 {
+        if self.sampled_rows == 0 {
+            return 0.0;
+        }
+        (self.sampled_rows - self.non_null) as f64 / self.sampled_rows as f64
+    }
+}
+
 /// Collects and caches table metadata
 pub struct MetadataCollector {
     collector: DockerDatabaseMetricsCollector,
     container_id: String,
     db_type: DatabaseType,
+    conn: ConnParams,
     cache: HashMap<String, TableMetadata>,
+    disk_cache: Option<MetadataCache>,
+    /// Fingerprint of the schema `disk_cache` entries are checked against
+    schema_hash: Option<String>,
+    /// Tables loaded from `disk_cache` whose row counts haven't been re-read yet
+    stale_row_counts: HashSet<String>,
 }
 
 impl MetadataCollector {
@@ -56,15 +126,161 @@ impl MetadataCollector {
             collector,
             container_id,
             db_type,
+            conn: ConnParams::from_env(db_type, HashMap::new()),
             cache: HashMap::new(),
+            disk_cache: None,
+            schema_hash: None,
+            stale_row_counts: HashSet::new(),
         }
     }
 
+    /// Connect with these parameters instead of the image defaults
+    pub fn with_connection(mut self, conn: ConnParams) -> Self {
+        self.conn = conn;
+        self
+    }
+
+    /// Reuse metadata from an on-disk cache while the schema is unchanged
+    pub fn with_disk_cache(mut self, cache: MetadataCache) -> Self {
+        self.disk_cache = Some(cache);
+        self
+    }
+
+    /// Fill the in-memory cache from the disk cache, if its entry matches the current
+    /// schema. Failing to fingerprint the schema only disables the disk cache.
+    ///
+    /// Row counts change without the schema changing, so each cached table's
+    /// estimate is re-read the first time it is asked for.
+    pub async fn load_disk_cache(&mut self) {
+        let Some(disk_cache) = &self.disk_cache else {
+            return;
+        };
+        let schema_hash = match self.schema_hash().await {
+            Ok(hash) => hash,
+            Err(e) => {
+                debug!("Not using the metadata cache: {}", e);
+                return;
+            }
+        };
+
+        if let Some(tables) = disk_cache.load(&schema_hash) {
+            debug!("Loaded metadata for {} table(s) from cache", tables.len());
+            for (name, metadata) in tables {
+                if !self.cache.contains_key(&name) {
+                    self.stale_row_counts.insert(name.clone());
+                    self.cache.insert(name, metadata);
+                }
+            }
+        }
+        self.schema_hash = Some(schema_hash);
+    }
+
+    /// Write everything collected so far to the disk cache
+    pub fn save_disk_cache(&self) {
+        if let (Some(disk_cache), Some(schema_hash)) = (&self.disk_cache, &self.schema_hash) {
+            if let Err(e) = disk_cache.store(schema_hash, &self.cache) {
+                warn!("Failed to write the metadata cache: {}", e);
+            }
+        }
+    }
+
+    /// Fingerprint of every column's table, name, type and nullability, and of the
+    /// primary and foreign keys, from one query
+    pub async fn schema_hash(&self) -> Result<String> {
+        let query = match self.db_type {
+            // Columns, then key columns with the table and column a foreign key references
+            DatabaseType::Postgres | DatabaseType::CockroachDB => {
+                "SELECT table_schema::text, table_name::text, \
+                   lpad(ordinal_position::text, 5, '0'), column_name::text, data_type::text, is_nullable::text \
+                 FROM information_schema.columns \
+                 WHERE table_schema NOT IN \
+                   ('pg_catalog', 'information_schema', 'crdb_internal', 'pg_extension') \
+                 UNION ALL \
+                 SELECT k.table_schema::text, k.table_name::text, c.constraint_type::text, \
+                   k.column_name::text, COALESCE(r.table_name::text, ''), \
+                   COALESCE(r.column_name::text, '') \
+                 FROM information_schema.table_constraints c \
+                 JOIN information_schema.key_column_usage k \
+                   ON k.constraint_schema = c.constraint_schema \
+                   AND k.constraint_name = c.constraint_name AND k.table_name = c.table_name \
+                 LEFT JOIN information_schema.referential_constraints f \
+                   ON f.constraint_schema = c.constraint_schema \
+                   AND f.constraint_name = c.constraint_name \
+                 LEFT JOIN information_schema.key_column_usage r \
+                   ON r.constraint_schema = f.unique_constraint_schema \
+                   AND r.constraint_name = f.unique_constraint_name \
+                   AND r.ordinal_position = k.ordinal_position \
+                 WHERE c.constraint_type IN ('PRIMARY KEY', 'FOREIGN KEY') \
+                   AND k.table_schema NOT IN \
+                   ('pg_catalog', 'information_schema', 'crdb_internal', 'pg_extension') \
+                 ORDER BY 1, 2, 3, 4, 5, 6"
+            }
+            // COLUMN_KEY covers primary keys; foreign keys name their target
+            DatabaseType::MySQL => {
+                "SELECT TABLE_NAME, LPAD(ORDINAL_POSITION, 5, '0'), COLUMN_NAME, COLUMN_TYPE, \
+                   IS_NULLABLE, COLUMN_KEY \
+                 FROM information_schema.COLUMNS WHERE TABLE_SCHEMA = DATABASE() \
+                 UNION ALL \
+                 SELECT TABLE_NAME, 'FOREIGN KEY', COLUMN_NAME, CONSTRAINT_NAME, \
+                   REFERENCED_TABLE_NAME, REFERENCED_COLUMN_NAME \
+                 FROM information_schema.KEY_COLUMN_USAGE \
+                 WHERE TABLE_SCHEMA = DATABASE() AND REFERENCED_TABLE_NAME IS NOT NULL \
+                 ORDER BY 1, 2, 3, 4, 5, 6"
+            }
+            DatabaseType::SQLServer => {
+                "SELECT TABLE_SCHEMA, TABLE_NAME, \
+                   RIGHT('00000' + CAST(ORDINAL_POSITION AS NVARCHAR(10)), 5), \
+                   COLUMN_NAME, DATA_TYPE, IS_NULLABLE \
+                 FROM INFORMATION_SCHEMA.COLUMNS \
+                 UNION ALL \
+                 SELECT k.TABLE_SCHEMA, k.TABLE_NAME, c.CONSTRAINT_TYPE, k.COLUMN_NAME, \
+                   COALESCE(r.TABLE_NAME, ''), COALESCE(r.COLUMN_NAME, '') \
+                 FROM INFORMATION_SCHEMA.TABLE_CONSTRAINTS c \
+                 JOIN INFORMATION_SCHEMA.KEY_COLUMN_USAGE k \
+                   ON k.CONSTRAINT_SCHEMA = c.CONSTRAINT_SCHEMA \
+                   AND k.CONSTRAINT_NAME = c.CONSTRAINT_NAME \
+                 LEFT JOIN INFORMATION_SCHEMA.REFERENTIAL_CONSTRAINTS f \
+                   ON f.CONSTRAINT_SCHEMA = c.CONSTRAINT_SCHEMA \
+                   AND f.CONSTRAINT_NAME = c.CONSTRAINT_NAME \
+                 LEFT JOIN INFORMATION_SCHEMA.KEY_COLUMN_USAGE r \
+                   ON r.CONSTRAINT_SCHEMA = f.UNIQUE_CONSTRAINT_SCHEMA \
+                   AND r.CONSTRAINT_NAME = f.UNIQUE_CONSTRAINT_NAME \
+                   AND r.ORDINAL_POSITION = k.ORDINAL_POSITION \
+                 WHERE c.CONSTRAINT_TYPE IN ('PRIMARY KEY', 'FOREIGN KEY') \
+                 ORDER BY 1, 2, 3, 4, 5, 6"
+            }
+            DatabaseType::SQLite => "SELECT name, sql FROM sqlite_master ORDER BY name",
+            DatabaseType::Redis | DatabaseType::MongoDB => {
+                return Err(anyhow!("Workloads are not supported for NoSQL databases"));
+            }
+        };
+
+        let options: &[&str] = match self.db_type {
+            DatabaseType::Postgres => &["-t", "-A", "-F", "|", "-c"],
+            DatabaseType::CockroachDB => &["--format=tsv", "-e"],
+            DatabaseType::MySQL => &["-N", "-B", "-e"],
+            DatabaseType::SQLServer => &["-h", "-1", "-s", "|", "-W", "-Q"],
+            _ => &[],
+        };
+
+        let output = self.run_query(query.to_string(), options).await?;
+        Ok(blake3::hash(output.as_bytes()).to_hex().to_string())
+    }
+
     /// Get metadata for a table (cached)
     pub async fn get_metadata(&mut self, table: &str) -> Result<&TableMetadata> {
         if !self.cache.contains_key(table) {
             let metadata = self.collect_table_metadata(table).await?;
             self.cache.insert(table.to_string(), metadata);
+        } else if self.stale_row_counts.remove(table) {
+            match self.get_row_count(table).await {
+                Ok(row_count) => {
+                    if let Some(metadata) = self.cache.get_mut(table) {
+                        metadata.row_count_estimate = row_count;
+                    }
+                }
+                Err(e) => debug!("Keeping the cached row count of {}: {}", table, e),
+            }
         }
 
         self.cache
@@ -106,6 +322,19 @@ impl MetadataCollector {
                  ORDER BY ordinal_position",
                 table, table
             ),
+            DatabaseType::CockroachDB => format!(
+                "SELECT c.column_name || '|' || c.data_type || '|' || c.is_nullable || '|' || \
+                 CASE WHEN k.column_name IS NULL THEN 'NO' ELSE 'YES' END \
+                 FROM information_schema.columns c \
+                 LEFT JOIN information_schema.table_constraints t \
+                   ON t.table_name = c.table_name AND t.constraint_type = 'PRIMARY KEY' \
+                 LEFT JOIN information_schema.key_column_usage k \
+                   ON k.constraint_name = t.constraint_name AND k.table_name = c.table_name \
+                   AND k.column_name = c.column_name \
+                 WHERE c.table_name = '{}' AND c.is_hidden = 'NO' \
+                 ORDER BY c.ordinal_position",
+                table
+            ),
             DatabaseType::MySQL => format!(
                 "SELECT COLUMN_NAME as column_name, DATA_TYPE as data_type, \
                  IS_NULLABLE as is_nullable, COLUMN_KEY as is_primary \
@@ -125,25 +354,28 @@ impl MetadataCollector {
                  ORDER BY c.column_id",
                 table
             ),
+            DatabaseType::SQLite => format!(
+                "SELECT name || '|' || lower(type) || '|' || \
+                 CASE WHEN \"notnull\" THEN 'NO' ELSE 'YES' END || '|' || \
+                 CASE WHEN pk > 0 THEN 'YES' ELSE 'NO' END \
+                 FROM pragma_table_info('{}') ORDER BY cid",
+                table
+            ),
+            DatabaseType::Redis | DatabaseType::MongoDB => {
+                return Err(anyhow!("Workloads are not supported for NoSQL databases"));
+            }
         };
 
-        let command = match self.db_type {
-            DatabaseType::Postgres => vec!["psql", "-U", "postgres", "-t", "-A", "-F", "|", "-c", &query],
-            DatabaseType::MySQL => vec!["mysql", "-uroot", "-proot", "-N", "-B", "-e", &query],
-            DatabaseType::SQLServer => vec![
-                "/opt/mssql-tools18/bin/sqlcmd",
-                "-S", "localhost",
-                "-U", "sa",
-                "-P", "YourStrong@Passw0rd",
-                "-C",
-                "-h", "-1",
-                "-s", "|",
-                "-W",
-                "-Q", &query,
-            ],
+        let options: &[&str] = match self.db_type {
+            DatabaseType::Postgres => &["-t", "-A", "-F", "|", "-c"],
+            // The row is concatenated in SQL, so the tsv header is the only line without '|'
+            DatabaseType::CockroachDB => &["--format=tsv", "-e"],
+            DatabaseType::MySQL => &["-N", "-B", "-e"],
+            DatabaseType::SQLServer => &["-h", "-1", "-s", "|", "-W", "-Q"],
+            _ => &[],
         };
 
-        let output = self.collector.exec_query(&self.container_id, command).await?;
+        let output = self.run_query(query, options).await?;
 
         let mut columns = Vec::new();
         for line in output.lines() {
@@ -177,6 +409,11 @@ impl MetadataCollector {
                 "SELECT reltuples::bigint FROM pg_class WHERE relname = '{}'",
                 table
             ),
+            DatabaseType::CockroachDB => format!(
+                "SELECT estimated_row_count FROM crdb_internal.table_row_statistics \
+                 WHERE table_name = '{}'",
+                table
+            ),
             DatabaseType::MySQL => format!(
                 "SELECT TABLE_ROWS FROM information_schema.TABLES \
                  WHERE TABLE_NAME = '{}' AND TABLE_SCHEMA = DATABASE()",
@@ -188,30 +425,348 @@ impl MetadataCollector {
                  WHERE t.name = '{}' AND p.index_id IN (0, 1)",
                 table
             ),
+            // No statistics without ANALYZE; a full count is cheap at SQLite's scale
+            DatabaseType::SQLite => format!("SELECT count(*) FROM \"{}\"", table),
+            DatabaseType::Redis | DatabaseType::MongoDB => {
+                return Err(anyhow!("Workloads are not supported for NoSQL databases"));
+            }
         };
 
-        let command = match self.db_type {
-            DatabaseType::Postgres => vec!["psql", "-U", "postgres", "-t", "-A", "-c", &query],
-            DatabaseType::MySQL => vec!["mysql", "-uroot", "-proot", "-N", "-B", "-e", &query],
-            DatabaseType::SQLServer => vec![
-                "/opt/mssql-tools18/bin/sqlcmd",
-                "-S", "localhost",
-                "-U", "sa",
-                "-P", "YourStrong@Passw0rd",
-                "-C",
-                "-h", "-1",
-                "-W",
-                "-Q", &query,
-            ],
+        let options: &[&str] = match self.db_type {
+            DatabaseType::Postgres => &["-t", "-A", "-c"],
+            DatabaseType::CockroachDB => &["--format=tsv", "-e"],
+            DatabaseType::MySQL => &["-N", "-B", "-e"],
+            DatabaseType::SQLServer => &["-h", "-1", "-W", "-Q"],
+            _ => &[],
         };
 
-        let output = self.collector.exec_query(&self.container_id, command).await?;
+        let output = self.run_query(query, options).await?;
 
-        let count_str = output.lines().next().unwrap_or("0").trim();
+        let header_lines = usize::from(self.db_type == DatabaseType::CockroachDB);
+        let count_str = output.lines().nth(header_lines).unwrap_or("0").trim();
         let count = count_str.parse::<usize>().unwrap_or(1000);
 
         Ok(count.max(1)) // At least 1 to avoid division by zero
     }
+
+    /// Names of the tables in the connection's database (or default schema), sorted
+    pub async fn list_tables(&self) -> Result<Vec<String>> {
+        let query = match self.db_type {
+            DatabaseType::Postgres | DatabaseType::CockroachDB => {
+                "SELECT table_name FROM information_schema.tables \
+                 WHERE table_schema = current_schema() AND table_type = 'BASE TABLE' \
+                 ORDER BY table_name"
+            }
+            DatabaseType::MySQL => {
+                "SELECT TABLE_NAME FROM information_schema.TABLES \
+                 WHERE TABLE_SCHEMA = DATABASE() AND TABLE_TYPE = 'BASE TABLE' \
+                 ORDER BY TABLE_NAME"
+            }
+            DatabaseType::SQLServer => {
+                "SET NOCOUNT ON; SELECT name FROM sys.tables WHERE is_ms_shipped = 0 ORDER BY name"
+            }
+            DatabaseType::SQLite => {
+                "SELECT name FROM sqlite_master \
+                 WHERE type = 'table' AND name NOT LIKE 'sqlite_%' ORDER BY name"
+            }
+            DatabaseType::Redis | DatabaseType::MongoDB => {
+                return Err(anyhow!("Schema introspection is not supported for NoSQL databases"));
+            }
+        };
+
+        let rows = self.query_rows(query.to_string(), 1).await?;
+        Ok(rows.into_iter().map(|mut row| row.remove(0)).collect())
+    }
+
+    /// Indexes on a table, keyed by their columns in index order
+    pub async fn get_indexes(&self, table: &str) -> Result<Vec<IndexMetadata>> {
+        let query = match self.db_type {
+            DatabaseType::Postgres => format!(
+                "SELECT i.relname || '|' || CASE WHEN ix.indisunique THEN 'YES' ELSE 'NO' END \
+                 || '|' || string_agg(a.attname, ',' \
+                   ORDER BY array_position(ix.indkey::int2[], a.attnum)) \
+                 FROM pg_index ix \
+                 JOIN pg_class i ON i.oid = ix.indexrelid \
+                 JOIN pg_attribute a ON a.attrelid = ix.indrelid AND a.attnum = ANY(ix.indkey) \
+                 WHERE ix.indrelid = '{}'::regclass \
+                 GROUP BY i.relname, ix.indisunique ORDER BY i.relname",
+                table
+            ),
+            DatabaseType::CockroachDB => format!(
+                "SELECT index_name || '|' || CASE WHEN non_unique = 'NO' THEN 'YES' ELSE 'NO' END \
+                 || '|' || string_agg(column_name, ',' ORDER BY seq_in_index) AS info \
+                 FROM information_schema.statistics \
+                 WHERE table_name = '{}' AND storing = 'NO' AND implicit = 'NO' \
+                 GROUP BY index_name, non_unique ORDER BY index_name",
+                table
+            ),
+            DatabaseType::MySQL => format!(
+                "SELECT CONCAT_WS('|', INDEX_NAME, IF(NON_UNIQUE = 0, 'YES', 'NO'), \
+                   GROUP_CONCAT(COLUMN_NAME ORDER BY SEQ_IN_INDEX)) \
+                 FROM information_schema.STATISTICS \
+                 WHERE TABLE_SCHEMA = DATABASE() AND TABLE_NAME = '{}' \
+                 GROUP BY INDEX_NAME, NON_UNIQUE ORDER BY INDEX_NAME",
+                table
+            ),
+            DatabaseType::SQLServer => format!(
+                "SET NOCOUNT ON; \
+                 SELECT i.name + '|' + CASE WHEN i.is_unique = 1 THEN 'YES' ELSE 'NO' END + '|' \
+                   + STRING_AGG(c.name, ',') WITHIN GROUP (ORDER BY ic.key_ordinal) \
+                 FROM sys.indexes i \
+                 JOIN sys.index_columns ic ON ic.object_id = i.object_id AND ic.index_id = i.index_id \
+                 JOIN sys.columns c ON c.object_id = ic.object_id AND c.column_id = ic.column_id \
+                 WHERE i.object_id = OBJECT_ID('{}') AND i.name IS NOT NULL \
+                   AND ic.is_included_column = 0 \
+                 GROUP BY i.name, i.is_unique ORDER BY i.name",
+                table
+            ),
+            DatabaseType::SQLite => format!(
+                "SELECT il.name || '|' || CASE WHEN il.\"unique\" THEN 'YES' ELSE 'NO' END || '|' || \
+                 (SELECT group_concat(name, ',') FROM \
+                   (SELECT name FROM pragma_index_info(il.name) ORDER BY seqno)) \
+                 FROM pragma_index_list('{}') il ORDER BY il.name",
+                table
+            ),
+            DatabaseType::Redis | DatabaseType::MongoDB => {
+                return Err(anyhow!("Schema introspection is not supported for NoSQL databases"));
+            }
+        };
+
+        let rows = self.query_rows(query, 3).await?;
+        Ok(rows
+            .into_iter()
+            .map(|row| IndexMetadata {
+                name: row[0].clone(),
+                columns: row[2].split(',').map(|c| c.trim().to_string()).collect(),
+                is_unique: row[1].eq_ignore_ascii_case("yes"),
+            })
+            .collect())
+    }
+
+    /// Foreign key columns of a table and what they reference
+    pub async fn get_foreign_keys(&self, table: &str) -> Result<Vec<ForeignKeyMetadata>> {
+        let query = match self.db_type {
+            DatabaseType::Postgres | DatabaseType::CockroachDB => format!(
+                "SELECT k.column_name || '|' || u.table_name || '|' || u.column_name AS info \
+                 FROM information_schema.referential_constraints r \
+                 JOIN information_schema.key_column_usage k \
+                   ON k.constraint_name = r.constraint_name \
+                   AND k.constraint_schema = r.constraint_schema \
+                 JOIN information_schema.key_column_usage u \
+                   ON u.constraint_name = r.unique_constraint_name \
+                   AND u.constraint_schema = r.unique_constraint_schema \
+                   AND u.ordinal_position = k.position_in_unique_constraint \
+                 WHERE k.table_name = '{}' AND k.table_schema = current_schema() \
+                 ORDER BY k.constraint_name, k.ordinal_position",
+                table
+            ),
+            DatabaseType::MySQL => format!(
+                "SELECT CONCAT_WS('|', COLUMN_NAME, REFERENCED_TABLE_NAME, REFERENCED_COLUMN_NAME) \
+                 FROM information_schema.KEY_COLUMN_USAGE \
+                 WHERE TABLE_SCHEMA = DATABASE() AND TABLE_NAME = '{}' \
+                   AND REFERENCED_TABLE_NAME IS NOT NULL \
+                 ORDER BY CONSTRAINT_NAME, ORDINAL_POSITION",
+                table
+            ),
+            DatabaseType::SQLServer => format!(
+                "SET NOCOUNT ON; \
+                 SELECT COL_NAME(parent_object_id, parent_column_id) + '|' \
+                   + OBJECT_NAME(referenced_object_id) + '|' \
+                   + COL_NAME(referenced_object_id, referenced_column_id) \
+                 FROM sys.foreign_key_columns \
+                 WHERE parent_object_id = OBJECT_ID('{}') \
+                 ORDER BY constraint_object_id, constraint_column_id",
+                table
+            ),
+            DatabaseType::SQLite => format!(
+                "SELECT \"from\" || '|' || \"table\" || '|' || coalesce(\"to\", '') \
+                 FROM pragma_foreign_key_list('{}') ORDER BY id, seq",
+                table
+            ),
+            DatabaseType::Redis | DatabaseType::MongoDB => {
+                return Err(anyhow!("Schema introspection is not supported for NoSQL databases"));
+            }
+        };
+
+        let rows = self.query_rows(query, 3).await?;
+        Ok(rows
+            .into_iter()
+            .map(|row| ForeignKeyMetadata {
+                column: row[0].clone(),
+                referenced_table: row[1].clone(),
+                referenced_column: row[2].clone(),
+            })
+            .collect())
+    }
+
+    /// Sample a column's values: row and null counts, distinct values, and the range
+    /// or the values themselves depending on `kind`
+    ///
+    /// Tables estimated above [`PROFILE_SAMPLE_ROWS`] are sampled with `TABLESAMPLE` on
+    /// Postgres and SQL Server, and by their first rows elsewhere.
+    pub async fn profile_column(
+        &self,
+        table: &TableMetadata,
+        column: &str,
+        kind: ProfileKind,
+    ) -> Result<ColumnProfile> {
+        let db_type = self.db_type;
+        let col = escape_ident(db_type, column);
+        let source = self.sample_source(table, &col);
+
+        let text = |expr: &str| match (db_type, kind) {
+            (DatabaseType::Postgres | DatabaseType::CockroachDB, _) => format!("({})::text", expr),
+            (DatabaseType::SQLServer, ProfileKind::Temporal) => {
+                format!("CONVERT(varchar(40), {}, 126)", expr)
+            }
+            (DatabaseType::SQLServer, _) => format!("CAST({} AS varchar(40))", expr),
+            _ => expr.to_string(),
+        };
+        let mut fields = vec![
+            text("count(*)"),
+            text(&format!("count({})", col)),
+            text(&format!("count(DISTINCT {})", col)),
+        ];
+        if kind == ProfileKind::Values {
+            fields.extend(["''".to_string(), "''".to_string()]);
+        } else {
+            fields.push(format!("coalesce({}, '')", text(&format!("min({})", col))));
+            fields.push(format!("coalesce({}, '')", text(&format!("max({})", col))));
+        }
+        let row = match db_type {
+            DatabaseType::MySQL => format!("CONCAT_WS('|', {})", fields.join(", ")),
+            DatabaseType::SQLServer => fields.join(" + '|' + "),
+            _ => fields.join(" || '|' || "),
+        };
+        let prefix = if db_type == DatabaseType::SQLServer {
+            "SET NOCOUNT ON; "
+        } else {
+            ""
+        };
+
+        let query = format!("{}SELECT {} AS info FROM {}", prefix, row, source);
+        let rows = self.query_rows(query, 5).await?;
+        let row = rows
+            .first()
+            .ok_or_else(|| anyhow!("Could not profile {}.{}", table.name, column))?;
+        let count = |i: usize| row[i].parse::<u64>().unwrap_or(0);
+        let optional = |i: usize| Some(row[i].clone()).filter(|v| !v.is_empty());
+        let mut profile = ColumnProfile {
+            sampled_rows: count(0),
+            non_null: count(1),
+            distinct: count(2),
+            min: optional(3),
+            max: optional(4),
+            values: Vec::new(),
+        };
+
+        if kind == ProfileKind::Values
+            && profile.distinct > 0
+            && profile.distinct as usize <= PROFILE_MAX_VALUES
+        {
+            let query = format!(
+                "{}SELECT DISTINCT {} FROM {} WHERE {} IS NOT NULL ORDER BY 1",
+                prefix,
+                text(&col),
+                source,
+                col
+            );
+            profile.values = self
+                .query_rows(query, 1)
+                .await?
+                .into_iter()
+                .map(|mut row| row.remove(0))
+                .collect();
+        }
+
+        Ok(profile)
+    }
+
+    /// `FROM` clause reading at most about [`PROFILE_SAMPLE_ROWS`] rows of `col`
+    fn sample_source(&self, table: &TableMetadata, col: &str) -> String {
+        let name = escape_ident(self.db_type, &table.name);
+        let estimate = table.row_count_estimate;
+        let percent = PROFILE_SAMPLE_ROWS as f64 * 100.0 / estimate.max(1) as f64;
+        match self.db_type {
+            DatabaseType::Postgres if estimate > PROFILE_SAMPLE_ROWS => {
+                format!("{} TABLESAMPLE SYSTEM ({:.4})", name, percent)
+            }
+            DatabaseType::SQLServer if estimate > PROFILE_SAMPLE_ROWS => {
+                format!("{} TABLESAMPLE ({:.4} PERCENT)", name, percent)
+            }
+            DatabaseType::Postgres | DatabaseType::SQLServer => name,
+            _ => format!(
+                "(SELECT {} FROM {} LIMIT {}) AS sample",
+                col, name, PROFILE_SAMPLE_ROWS
+            ),
+        }
+    }
+
+    /// Exact row count of a table, unlike the estimate in [`TableMetadata`]
+    pub async fn count_rows(&self, table: &str) -> Result<u64> {
+        let prefix = if self.db_type == DatabaseType::SQLServer {
+            "SET NOCOUNT ON; "
+        } else {
+            ""
+        };
+        let query = format!(
+            "{}SELECT count(*) FROM {}",
+            prefix,
+            escape_ident(self.db_type, table)
+        );
+        let rows = self.query_rows(query, 1).await?;
+        rows.first()
+            .and_then(|row| row[0].parse().ok())
+            .ok_or_else(|| anyhow!("Could not count rows of {}", table))
+    }
+
+    /// Run a query whose rows are one value, or fields joined with `|`, and return the
+    /// rows with exactly `fields` fields (dropping headers and status lines)
+    async fn query_rows(&self, query: String, fields: usize) -> Result<Vec<Vec<String>>> {
+        let options: &[&str] = match self.db_type {
+            DatabaseType::Postgres => &["-t", "-A", "-c"],
+            DatabaseType::CockroachDB => &["--format=tsv", "-e"],
+            DatabaseType::MySQL => &["-N", "-B", "-e"],
+            DatabaseType::SQLServer => &["-h", "-1", "-W", "-Q"],
+            _ => &[],
+        };
+
+        let output = self.run_query(query, options).await?;
+        Ok(parse_rows(&output, fields, self.db_type == DatabaseType::CockroachDB))
+    }
+
+    /// Run a query through the database's client with output `options`
+    async fn run_query(&self, query: String, options: &[&str]) -> Result<String> {
+        let mut command = self.conn.client_command()?;
+        command.extend(options.iter().map(|s| s.to_string()));
+        if self.db_type == DatabaseType::SQLite {
+            command.push(SQLITE_DB_PATH.to_string());
+        }
+        command.push(query);
+
+        let output = self
+            .collector
+            .exec_client(&self.container_id, &self.conn, &command)
+            .await?;
+        Ok(output)
+    }
+}
+
+/// Split client output into rows of `fields` `|`-separated values, skipping a header line
+///
+/// A single field is the whole line, so it may contain `|` itself.
+fn parse_rows(output: &str, fields: usize, has_header: bool) -> Vec<Vec<String>> {
+    output
+        .lines()
+        .skip(usize::from(has_header))
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| match fields {
+            1 => vec![line.to_string()],
+            _ => line.split('|').map(|v| v.trim().to_string()).collect::<Vec<_>>(),
+        })
+        .filter(|row| row.len() == fields)
+        .collect()
 }
 
 #[cfg(test)]
@@ -251,6 +806,21 @@ mod tests {
         assert_eq!(metadata.primary_key_column().unwrap().name, "id");
     }
 
+    #[test]
+    fn test_parse_rows() {
+        let output = "row\nusers_pkey|YES|id\norders_user_idx|NO|user_id,created_at\n\n";
+        let rows = parse_rows(output, 3, true);
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[1], vec!["orders_user_idx", "NO", "user_id,created_at"]);
+
+        // Status lines have the wrong number of fields
+        let output = "orders|users|id\n(1 rows affected)\n";
+        assert_eq!(parse_rows(output, 3, false), vec![vec!["orders", "users", "id"]]);
+
+        // Single values are taken whole
+        assert_eq!(parse_rows("a|b\n", 1, false), vec![vec!["a|b"]]);
+    }
+
     #[test]
     fn test_updatable_columns() {
         let metadata = TableMetadata {
@@ -0,0 +1,145 @@
+//! On-disk cache of table metadata, so repeated seed and workload runs skip
+//! re-introspecting wide schemas
+//!
+//! Entries are keyed by the target (container ID, or an external database's address)
+//! and checked against a fingerprint of the schema (see
+//! [`MetadataCollector::schema_hash`](super::MetadataCollector::schema_hash)), so a
+//! recreated container or an altered table is introspected again.
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use super::metadata::TableMetadata;
+use crate::container::ConnParams;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CacheEntry {
+    schema_hash: String,
+    tables: HashMap<String, TableMetadata>,
+}
+
+/// A cached metadata file for one target
+#[derive(Debug, Clone)]
+pub struct MetadataCache {
+    path: PathBuf,
+    refresh: bool,
+}
+
+impl MetadataCache {
+    /// Cache for a container (or, with an empty `container_id`, the external database
+    /// `conn` points at), under the user's cache directory
+    pub fn for_target(container_id: &str, conn: &ConnParams) -> Option<Self> {
+        let dir = dirs::cache_dir()?.join("dbarena").join("metadata");
+        let key = Self::key(container_id, conn);
+        let file = format!("{}.json", &blake3::hash(key.as_bytes()).to_hex()[..32]);
+        Some(Self::at(dir.join(file)))
+    }
+
+    /// Cache stored at `path`
+    pub fn at(path: PathBuf) -> Self {
+        Self {
+            path,
+            refresh: false,
+        }
+    }
+
+    /// Ignore cached metadata so it is introspected again (and re-cached)
+    pub fn refreshing(mut self, refresh: bool) -> Self {
+        self.refresh = refresh;
+        self
+    }
+
+    fn key(container_id: &str, conn: &ConnParams) -> String {
+        let target = match &conn.endpoint {
+            Some(endpoint) if container_id.is_empty() => endpoint.to_string(),
+            _ => container_id.to_string(),
+        };
+        format!("{}|{}|{}", conn.db_type, target, conn.database)
+    }
+
+    /// Cached tables, if the entry was stored for `schema_hash`
+    pub fn load(&self, schema_hash: &str) -> Option<HashMap<String, TableMetadata>> {
+        if self.refresh {
+            return None;
+        }
+        let content = std::fs::read_to_string(&self.path).ok()?;
+        let entry: CacheEntry = serde_json::from_str(&content).ok()?;
+        (entry.schema_hash == schema_hash).then_some(entry.tables)
+    }
+
+    /// Replace the entry with `tables`, collected under `schema_hash`
+    pub fn store(&self, schema_hash: &str, tables: &HashMap<String, TableMetadata>) -> Result<()> {
+        if let Some(dir) = self.path.parent() {
+            std::fs::create_dir_all(dir)
+                .with_context(|| format!("Failed to create {}", dir.display()))?;
+        }
+        let entry = CacheEntry {
+            schema_hash: schema_hash.to_string(),
+            tables: tables.clone(),
+        };
+        std::fs::write(&self.path, serde_json::to_string(&entry)?)
+            .with_context(|| format!("Failed to write {}", self.path.display()))?;
+        Ok(())
+    }
+
+    /// Drop the entry, e.g. after seeding made its row count estimates stale
+    pub fn invalidate(&self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::container::DatabaseType;
+    use crate::workload::ColumnMetadata;
+
+    fn tables() -> HashMap<String, TableMetadata> {
+        let users = TableMetadata {
+            name: "users".to_string(),
+            columns: vec![ColumnMetadata {
+                name: "id".to_string(),
+                data_type: "integer".to_string(),
+                is_nullable: false,
+                is_primary_key: true,
+            }],
+            primary_key: Some("id".to_string()),
+            row_count_estimate: 5000,
+        };
+        HashMap::from([("users".to_string(), users)])
+    }
+
+    #[test]
+    fn test_cache_roundtrip_checks_schema_hash() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = MetadataCache::at(dir.path().join("nested").join("cache.json"));
+        assert!(cache.load("abc").is_none());
+
+        cache.store("abc", &tables()).unwrap();
+        let loaded = cache.load("abc").unwrap();
+        assert_eq!(loaded["users"].row_count_estimate, 5000);
+        assert_eq!(loaded["users"].columns[0].data_type, "integer");
+        assert_eq!(loaded["users"].primary_key.as_deref(), Some("id"));
+
+        // Altered schema, or asked to re-introspect
+        assert!(cache.load("def").is_none());
+        assert!(cache.clone().refreshing(true).load("abc").is_none());
+
+        cache.invalidate();
+        assert!(cache.load("abc").is_none());
+    }
+
+    #[test]
+    fn test_cache_key_per_target() {
+        let conn = ConnParams::from_env(DatabaseType::Postgres, HashMap::new());
+        let a = MetadataCache::key("container-a", &conn);
+        let recreated = MetadataCache::key("container-b", &conn);
+        assert_ne!(a, recreated);
+
+        let mut other_db = conn.clone();
+        other_db.database = "analytics".to_string();
+        assert_ne!(a, MetadataCache::key("container-a", &other_db));
+    }
+}
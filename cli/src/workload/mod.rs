@@ -2,14 +2,26 @@ pub mod config;
 pub mod display;
 pub mod engine;
 pub mod metadata;
+pub mod metadata_cache;
 pub mod operations;
+pub mod queries;
 pub mod rate_limiter;
 pub mod stats;
+pub mod sweep;
 
 pub use config::{CustomOperations, CustomQuery, OperationWeights, WorkloadConfig, WorkloadPattern};
 pub use display::{print_summary, WorkloadProgressDisplay};
 pub use engine::WorkloadEngine;
-pub use metadata::{ColumnMetadata, MetadataCollector, TableMetadata};
+pub use metadata::{
+    ColumnMetadata, ColumnProfile, ForeignKeyMetadata, IndexMetadata, MetadataCollector,
+    ProfileKind, TableMetadata,
+};
+pub use metadata_cache::MetadataCache;
 pub use operations::{Operation, OperationGenerator};
+pub use queries::{load_queries_file, PreparedQuery};
 pub use rate_limiter::RateLimiter;
-pub use stats::{MetricSample, WorkloadStats};
+pub use sweep::{print_sweep, SweepFormat, SweepStep};
+pub use stats::{
+    JsonlStatsWriter, LatencyBreakdown, LatencyBucket, LatencyDistribution, LatencyPercentile,
+    MetricSample, PhaseStats, StatsLine, WorkloadReport, WorkloadStats,
+};
@@ -1,9 +1,11 @@
 use anyhow::{anyhow, Result};
 use rand::{Rng, SeedableRng};
 use rand_chacha::ChaCha8Rng;
+use std::sync::Arc;
 
 use crate::container::DatabaseType;
 use crate::workload::metadata::TableMetadata;
+use crate::workload::queries::PreparedQuery;
 
 /// SQL operation type
 #[derive(Debug, Clone)]
@@ -37,11 +39,80 @@ impl Operation {
 /// Generates realistic SQL operations
 pub struct OperationGenerator {
     db_type: DatabaseType,
+    /// Target tables with their normalized share of operations
+    tables: Vec<(String, f64)>,
+    /// Seed the worker RNGs are derived from
+    seed: u64,
+    /// Custom queries that replace the generated operations, if any
+    queries: Arc<Vec<PreparedQuery>>,
 }
 
 impl OperationGenerator {
     pub fn new(db_type: DatabaseType) -> Self {
-        Self { db_type }
+        Self {
+            db_type,
+            tables: Vec::new(),
+            seed: 0,
+            queries: Arc::new(Vec::new()),
+        }
+    }
+
+    /// Run these custom queries instead of generating operations
+    pub fn with_custom_queries(mut self, queries: Arc<Vec<PreparedQuery>>) -> Self {
+        self.queries = queries;
+        self
+    }
+
+    /// Pick a custom query in proportion to its weight; `None` when the
+    /// workload generates its operations
+    pub fn pick_query(&self, rng: &mut ChaCha8Rng) -> Option<&PreparedQuery> {
+        let total: f64 = self.queries.iter().map(|query| query.weight).sum();
+        if total <= 0.0 {
+            return None;
+        }
+        let mut roll = rng.gen::<f64>() * total;
+        for query in self.queries.iter() {
+            if roll < query.weight {
+                return Some(query);
+            }
+            roll -= query.weight;
+        }
+        // Rounding can leave a sliver past the last query
+        self.queries.iter().rev().find(|query| query.weight > 0.0)
+    }
+
+    /// Set the tables to target, weighted as from `WorkloadConfig::table_weights`
+    pub fn with_table_weights(mut self, tables: Vec<(String, f64)>) -> Self {
+        self.tables = tables;
+        self
+    }
+
+    /// Derive worker RNGs from `seed`, so the same seed and config generate
+    /// the same operations
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// RNG for one worker's table, operation and key choices: its own stream
+    /// of the generator's seed, so workers don't repeat each other
+    pub fn worker_rng(&self, worker_id: usize) -> ChaCha8Rng {
+        let mut rng = ChaCha8Rng::seed_from_u64(self.seed);
+        rng.set_stream(worker_id as u64);
+        rng
+    }
+
+    /// Pick a target table in proportion to its weight
+    pub fn pick_table(&self, rng: &mut ChaCha8Rng) -> Option<&str> {
+        let mut roll = rng.gen::<f64>();
+        for (table, weight) in &self.tables {
+            if roll < *weight {
+                return Some(table);
+            }
+            roll -= weight;
+        }
+        // Rounding can leave a sliver past the last table
+        self.tables.last().map(|(table, _)| table.as_str())
     }
 
     /// Generate a SELECT operation
@@ -215,9 +286,12 @@ impl OperationGenerator {
 /// Escape identifier based on database type
 fn escape_identifier(name: &str, db_type: DatabaseType) -> String {
     match db_type {
-        DatabaseType::Postgres => format!("\"{}\"", name.replace('"', "\"\"")),
+        DatabaseType::Postgres | DatabaseType::CockroachDB | DatabaseType::SQLite => {
+            format!("\"{}\"", name.replace('"', "\"\""))
+        }
         DatabaseType::MySQL => format!("`{}`", name.replace('`', "``")),
         DatabaseType::SQLServer => format!("[{}]", name.replace(']', "]]")),
+        DatabaseType::Redis | DatabaseType::MongoDB => name.to_string(),
     }
 }
 
@@ -245,7 +319,9 @@ fn generate_value_for_type(data_type: &str, rng: &mut ChaCha8Rng) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::workload::config::CustomQuery;
     use crate::workload::metadata::ColumnMetadata;
+    use crate::workload::queries::prepare_queries;
 
     fn test_metadata() -> TableMetadata {
         TableMetadata {
@@ -275,6 +351,68 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_pick_table_follows_weights() {
+        let gen = OperationGenerator::new(DatabaseType::Postgres).with_table_weights(vec![
+            ("orders".to_string(), 0.8),
+            ("users".to_string(), 0.2),
+        ]);
+        let mut rng = ChaCha8Rng::seed_from_u64(42);
+
+        let orders = (0..1000)
+            .filter(|_| gen.pick_table(&mut rng) == Some("orders"))
+            .count();
+        assert!(orders > 750 && orders < 850, "orders picked {} times", orders);
+
+        let empty = OperationGenerator::new(DatabaseType::Postgres);
+        assert_eq!(empty.pick_table(&mut rng), None);
+    }
+
+    #[test]
+    fn test_pick_query_follows_weights() {
+        let queries: Vec<CustomQuery> = [("hot", 9.0), ("cold", 1.0), ("off", 0.0)]
+            .into_iter()
+            .map(|(name, weight)| CustomQuery {
+                name: name.to_string(),
+                sql: "SELECT 1".to_string(),
+                weight,
+                parameters: Vec::new(),
+            })
+            .collect();
+        let gen = OperationGenerator::new(DatabaseType::Postgres)
+            .with_custom_queries(Arc::new(prepare_queries(&queries).unwrap()));
+        let mut rng = ChaCha8Rng::seed_from_u64(42);
+
+        let mut counts = std::collections::HashMap::new();
+        for _ in 0..1000 {
+            let name = gen.pick_query(&mut rng).unwrap().name.clone();
+            *counts.entry(name).or_insert(0) += 1;
+        }
+        assert!(counts["hot"] > 850, "hot picked {} times", counts["hot"]);
+        assert!(!counts.contains_key("off"));
+
+        let generated = OperationGenerator::new(DatabaseType::Postgres);
+        assert!(generated.pick_query(&mut rng).is_none());
+    }
+
+    #[test]
+    fn test_worker_rng_replays_with_seed() {
+        let gen = OperationGenerator::new(DatabaseType::Postgres).with_seed(7);
+        let metadata = test_metadata();
+        let stream = |gen: &OperationGenerator, worker_id| {
+            let mut rng = gen.worker_rng(worker_id);
+            (0..20)
+                .map(|_| gen.generate_update(&metadata, &mut rng).unwrap().sql().to_string())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(stream(&gen, 0), stream(&gen, 0));
+        assert_ne!(stream(&gen, 0), stream(&gen, 1));
+
+        let other = OperationGenerator::new(DatabaseType::Postgres).with_seed(8);
+        assert_ne!(stream(&gen, 0), stream(&other, 0));
+    }
+
     #[test]
     fn test_generate_select() {
         let gen = OperationGenerator::new(DatabaseType::Postgres);
@@ -0,0 +1,437 @@
+//! Custom SQL workloads: named queries with weights, their placeholders
+//! filled with values from seed data generators on every execution
+
+use anyhow::{anyhow, Context, Result};
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+use serde::Deserialize;
+use std::path::Path;
+
+use crate::config::loader::ConfigFormat;
+use crate::container::DatabaseType;
+use crate::seed::generator::{create_generator, DataType, ForeignKeyInfo};
+use crate::seed::{escape_literal, DataGenerator};
+use crate::workload::config::{CustomQuery, QueryParameter};
+
+/// A `workload --queries-file`: the queries to run in place of a pattern's
+/// generated operations
+#[derive(Debug, Clone, Deserialize)]
+pub struct QueriesFile {
+    pub queries: Vec<CustomQuery>,
+}
+
+/// Load the queries from a TOML or YAML file (by extension; TOML otherwise)
+pub fn load_queries_file(path: &Path) -> Result<Vec<CustomQuery>> {
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read queries file {}", path.display()))?;
+    let file: QueriesFile = match ConfigFormat::from_path(path) {
+        Some(ConfigFormat::Yaml) => serde_yaml::from_str(&content)
+            .with_context(|| format!("Failed to parse {}", path.display()))?,
+        _ => toml::from_str(&content)
+            .with_context(|| format!("Failed to parse {}", path.display()))?,
+    };
+
+    prepare_queries(&file.queries)?;
+    Ok(file.queries)
+}
+
+/// Prepare every query, checking their weights, placeholders and generators
+pub fn prepare_queries(queries: &[CustomQuery]) -> Result<Vec<PreparedQuery>> {
+    if queries.is_empty() {
+        return Err(anyhow!("No custom queries defined"));
+    }
+    let prepared = queries
+        .iter()
+        .map(PreparedQuery::new)
+        .collect::<Result<Vec<_>>>()?;
+    if prepared.iter().all(|query| query.weight == 0.0) {
+        return Err(anyhow!("Every custom query has a weight of 0"));
+    }
+    Ok(prepared)
+}
+
+/// Piece of a query's SQL: literal text, or the parameter bound in its place
+#[derive(Debug, Clone, PartialEq)]
+enum Segment {
+    Text(String),
+    Param(usize),
+}
+
+/// Where a parameter's values come from
+enum Parameter {
+    Generated(Box<dyn DataGenerator>),
+    /// `foreign_key`: an existing value of the referenced column, loaded
+    /// before the run starts
+    Lookup {
+        reference: ForeignKeyInfo,
+        values: Vec<String>,
+        null_probability: f64,
+    },
+}
+
+impl Parameter {
+    fn new(param: &QueryParameter) -> Result<Self> {
+        let options = param.generator_options();
+        if param.generator != "foreign_key" {
+            return Ok(Parameter::Generated(create_generator(&param.generator, &options)?));
+        }
+
+        let null_probability = match options.get("null_probability") {
+            None => 0.0,
+            Some(value) => value
+                .as_float()
+                .or_else(|| value.as_integer().map(|n| n as f64))
+                .filter(|p| (0.0..=1.0).contains(p))
+                .ok_or_else(|| anyhow!("null_probability must be a number between 0 and 1"))?,
+        };
+        Ok(Parameter::Lookup {
+            reference: ForeignKeyInfo::from_options(&options)?,
+            values: Vec::new(),
+            null_probability,
+        })
+    }
+
+    /// A fresh value as a SQL literal or expression for `db_type`
+    fn value(&self, db_type: DatabaseType, rng: &mut ChaCha8Rng) -> Result<String> {
+        match self {
+            Parameter::Generated(generator) => Ok(match generator.generate_nullable(rng)? {
+                None => "NULL".to_string(),
+                Some(value) if generator.is_sql_expression() => value,
+                // T-SQL has no boolean literals
+                Some(value)
+                    if db_type == DatabaseType::SQLServer
+                        && generator.data_type() == DataType::Boolean =>
+                {
+                    if value == "true" { "1" } else { "0" }.to_string()
+                }
+                Some(value) => escape_literal(db_type, &value),
+            }),
+            Parameter::Lookup {
+                reference,
+                values,
+                null_probability,
+            } => {
+                if values.is_empty() {
+                    return Err(anyhow!(
+                        "Values of {}.{} were not loaded",
+                        reference.table,
+                        reference.column
+                    ));
+                }
+                if *null_probability > 0.0 && rng.gen::<f64>() < *null_probability {
+                    return Ok("NULL".to_string());
+                }
+                Ok(escape_literal(db_type, &values[rng.gen_range(0..values.len())]))
+            }
+        }
+    }
+}
+
+/// A custom query ready to execute, with a generator per parameter
+pub struct PreparedQuery {
+    pub name: String,
+    pub weight: f64,
+    segments: Vec<Segment>,
+    parameters: Vec<Parameter>,
+}
+
+impl PreparedQuery {
+    pub fn new(query: &CustomQuery) -> Result<Self> {
+        if !query.weight.is_finite() || query.weight < 0.0 {
+            return Err(anyhow!(
+                "Weight for query '{}' must be a non-negative number, got {}",
+                query.name,
+                query.weight
+            ));
+        }
+
+        let parameters = query
+            .parameters
+            .iter()
+            .map(|param| {
+                Parameter::new(param).with_context(|| {
+                    format!("Query '{}' parameter '{}'", query.name, param.name)
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let names: Vec<&str> = query.parameters.iter().map(|p| p.name.as_str()).collect();
+        let segments = parse_placeholders(&query.sql, &names)
+            .with_context(|| format!("Query '{}'", query.name))?;
+
+        Ok(Self {
+            name: query.name.clone(),
+            weight: query.weight,
+            segments,
+            parameters,
+        })
+    }
+
+    /// Columns whose existing values `foreign_key` parameters draw from
+    pub fn references(&self) -> Vec<ForeignKeyInfo> {
+        self.parameters
+            .iter()
+            .filter_map(|param| match param {
+                Parameter::Lookup { reference, .. } => Some(reference.clone()),
+                Parameter::Generated(_) => None,
+            })
+            .collect()
+    }
+
+    /// Give the `foreign_key` parameters referencing `table.column` its values
+    pub fn set_referenced_values(&mut self, table: &str, column: &str, ids: &[String]) {
+        for param in &mut self.parameters {
+            if let Parameter::Lookup {
+                reference, values, ..
+            } = param
+            {
+                if reference.table == table && reference.column == column {
+                    *values = ids.to_vec();
+                }
+            }
+        }
+    }
+
+    /// A statement that runs the query with freshly generated parameter values
+    ///
+    /// Values are written into the SQL as escaped literals for `db_type`;
+    /// every execution runs in its own client session, so there is no
+    /// connection to keep a server-side prepared statement on. Each parameter
+    /// is generated once per execution, so a placeholder used twice gets the
+    /// same value both times.
+    pub fn bind(&self, db_type: DatabaseType, rng: &mut ChaCha8Rng) -> Result<String> {
+        let values = self
+            .parameters
+            .iter()
+            .map(|param| param.value(db_type, rng))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(self.render(|index| values[index].clone()))
+    }
+
+    /// The SQL with each placeholder written by `placeholder`
+    fn render(&self, placeholder: impl Fn(usize) -> String) -> String {
+        self.segments
+            .iter()
+            .map(|segment| match segment {
+                Segment::Text(text) => text.clone(),
+                Segment::Param(index) => placeholder(*index),
+            })
+            .collect()
+    }
+}
+
+/// Split `sql` at its placeholders: `$1` (the first parameter), `?` (the
+/// next parameter in order) or `:name`
+///
+/// Quoted strings and identifiers are left alone, as are Postgres `::` casts.
+fn parse_placeholders(sql: &str, names: &[&str]) -> Result<Vec<Segment>> {
+    let chars: Vec<char> = sql.chars().collect();
+    let mut segments = Vec::new();
+    let mut text = String::new();
+    let mut quote: Option<char> = None;
+    let mut next_positional = 0;
+    let mut i = 0;
+
+    let is_ident = |c: char| c.is_ascii_alphanumeric() || c == '_';
+
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+
+        if let Some(q) = quote {
+            text.push(c);
+            if c == q {
+                quote = None;
+            }
+            i += 1;
+            continue;
+        }
+
+        let (param, len) = match c {
+            '\'' | '"' | '`' => {
+                quote = Some(c);
+                (None, 1)
+            }
+            '$' if next.is_some_and(|n| n.is_ascii_digit()) => {
+                let digits: String =
+                    chars[i + 1..].iter().take_while(|c| c.is_ascii_digit()).collect();
+                let n: usize = digits.parse()?;
+                if n == 0 || n > names.len() {
+                    return Err(anyhow!("${} has no matching parameter", n));
+                }
+                (Some(n - 1), digits.len() + 1)
+            }
+            '?' => {
+                if next_positional >= names.len() {
+                    return Err(anyhow!("More ? placeholders than parameters"));
+                }
+                next_positional += 1;
+                (Some(next_positional - 1), 1)
+            }
+            ':' if i.checked_sub(1).map(|p| chars[p]) != Some(':')
+                && next.is_some_and(|n| n.is_ascii_alphabetic() || n == '_') =>
+            {
+                let name: String = chars[i + 1..].iter().take_while(|c| is_ident(**c)).collect();
+                let index = names
+                    .iter()
+                    .position(|n| *n == name)
+                    .ok_or_else(|| anyhow!(":{} has no matching parameter", name))?;
+                (Some(index), name.len() + 1)
+            }
+            _ => (None, 1),
+        };
+
+        match param {
+            Some(index) => {
+                if !text.is_empty() {
+                    segments.push(Segment::Text(std::mem::take(&mut text)));
+                }
+                segments.push(Segment::Param(index));
+            }
+            None => text.extend(&chars[i..i + len]),
+        }
+        i += len;
+    }
+
+    if !text.is_empty() {
+        segments.push(Segment::Text(text));
+    }
+    Ok(segments)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+
+    fn query(toml: &str) -> CustomQuery {
+        toml::from_str(toml).unwrap()
+    }
+
+    #[test]
+    fn test_bind_placeholders() {
+        let q = PreparedQuery::new(&query(
+            r#"
+            name = "by_status"
+            sql = "SELECT * FROM orders WHERE status = $1 AND id > $2 AND note <> '$1?' AND total::int > $2"
+            weight = 1.0
+            parameters = [
+                { name = "status", generator = "enum", values = ["shipped"] },
+                { name = "min_id", generator = "random_int", min = 7, max = 7 },
+            ]
+            "#,
+        ))
+        .unwrap();
+
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+        assert_eq!(
+            q.bind(DatabaseType::Postgres, &mut rng).unwrap(),
+            "SELECT * FROM orders WHERE status = 'shipped' AND id > 7 AND note <> '$1?' AND total::int > 7"
+        );
+    }
+
+    #[test]
+    fn test_bind_sql_server_literals() {
+        let q = PreparedQuery::new(&query(
+            r#"
+            name = "ship"
+            sql = "UPDATE orders SET status = :status, shipped = :shipped WHERE id = :id;"
+            weight = 1.0
+            parameters = [
+                { name = "id", generator = "random_int", min = 5, max = 5 },
+                { name = "status", generator = "enum", values = ["it's"] },
+                { name = "shipped", generator = "boolean", true_probability = 1.0 },
+            ]
+            "#,
+        ))
+        .unwrap();
+
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+        assert_eq!(
+            q.bind(DatabaseType::SQLServer, &mut rng).unwrap(),
+            "UPDATE orders SET status = 'it''s', shipped = 1 WHERE id = 5;"
+        );
+    }
+
+    #[test]
+    fn test_foreign_key_parameter() {
+        let mut q = PreparedQuery::new(&query(
+            r#"
+            name = "orders_by_user"
+            sql = "SELECT * FROM orders WHERE user_id = :user"
+            weight = 1.0
+            parameters = [
+                { name = "user", generator = "foreign_key", references = { table = "users", column = "id" } },
+            ]
+            "#,
+        ))
+        .unwrap();
+
+        let references = q.references();
+        assert_eq!(references.len(), 1);
+        assert_eq!((references[0].table.as_str(), references[0].column.as_str()), ("users", "id"));
+
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+        assert!(q.bind(DatabaseType::Postgres, &mut rng).is_err());
+
+        q.set_referenced_values("users", "id", &["12".to_string()]);
+        assert_eq!(
+            q.bind(DatabaseType::Postgres, &mut rng).unwrap(),
+            "SELECT * FROM orders WHERE user_id = 12"
+        );
+    }
+
+    #[test]
+    fn test_question_mark_and_named_placeholders() {
+        let names = ["a", "b"];
+        assert_eq!(
+            parse_placeholders("VALUES (?, ?)", &names).unwrap(),
+            vec![
+                Segment::Text("VALUES (".to_string()),
+                Segment::Param(0),
+                Segment::Text(", ".to_string()),
+                Segment::Param(1),
+                Segment::Text(")".to_string()),
+            ]
+        );
+        assert_eq!(
+            parse_placeholders("WHERE x = :b", &names).unwrap(),
+            vec![Segment::Text("WHERE x = ".to_string()), Segment::Param(1)]
+        );
+
+        assert!(parse_placeholders("VALUES (?, ?, ?)", &names).is_err());
+        assert!(parse_placeholders("WHERE x = $3", &names).is_err());
+        assert!(parse_placeholders("WHERE x = :c", &names).is_err());
+    }
+
+    #[test]
+    fn test_nested_options_table() {
+        let q = PreparedQuery::new(&query(
+            r#"
+            name = "by_id"
+            sql = "SELECT * FROM users WHERE id = ?"
+            weight = 1.0
+
+            [[parameters]]
+            name = "id"
+            generator = "random_int"
+            [parameters.options]
+            min = 42
+            max = 42
+            "#,
+        ))
+        .unwrap();
+
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+        assert_eq!(
+            q.bind(DatabaseType::MySQL, &mut rng).unwrap(),
+            "SELECT * FROM users WHERE id = 42"
+        );
+    }
+
+    #[test]
+    fn test_prepare_queries_rejects_zero_weights() {
+        let queries = [query("name = \"x\"\nsql = \"SELECT 1\"\nweight = 0.0")];
+        assert!(prepare_queries(&queries).is_err());
+        assert!(prepare_queries(&[]).is_err());
+    }
+}
@@ -4,32 +4,84 @@ use governor::{
     Quota, RateLimiter as GovRateLimiter,
 };
 use std::num::NonZeroU32;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, RwLock};
+use tokio::sync::Notify;
+
+type DirectLimiter = GovRateLimiter<NotKeyed, InMemoryState, DefaultClock>;
 
 /// Rate limiter wrapper for controlling transaction throughput
 pub struct RateLimiter {
-    limiter: GovRateLimiter<NotKeyed, InMemoryState, DefaultClock>,
+    limiter: RwLock<Arc<DirectLimiter>>,
+    tps: AtomicUsize,
+    /// Wakes waiting workers when the rate changes
+    retargeted: Notify,
 }
 
 impl RateLimiter {
     /// Create a new rate limiter with target TPS
     pub fn new(tps: usize) -> Self {
-        let quota = Quota::per_second(NonZeroU32::new(tps as u32).unwrap());
         Self {
-            limiter: GovRateLimiter::direct(quota),
+            limiter: RwLock::new(Arc::new(direct_limiter(tps))),
+            tps: AtomicUsize::new(tps),
+            retargeted: Notify::new(),
+        }
+    }
+
+    /// Current target TPS
+    pub fn tps(&self) -> usize {
+        self.tps.load(Ordering::SeqCst)
+    }
+
+    /// Change the target TPS
+    ///
+    /// Workers already waiting switch to the new rate, so a worker parked on a
+    /// slow rate isn't held back once it rises. Setting the current rate is a
+    /// no-op.
+    pub fn set_tps(&self, tps: usize) {
+        if self.tps.swap(tps, Ordering::SeqCst) == tps {
+            return;
+        }
+        if let Ok(mut limiter) = self.limiter.write() {
+            *limiter = Arc::new(direct_limiter(tps));
         }
+        self.retargeted.notify_waiters();
     }
 
     /// Wait until we can proceed (blocks until a permit is available)
     pub async fn acquire(&self) {
-        self.limiter.until_ready().await;
+        loop {
+            // Register for retargets before reading the limiter so none is missed
+            let retargeted = self.retargeted.notified();
+            tokio::pin!(retargeted);
+            retargeted.as_mut().enable();
+
+            let limiter = self.current();
+            tokio::select! {
+                _ = limiter.until_ready() => return,
+                _ = &mut retargeted => {}
+            }
+        }
     }
 
     /// Try to acquire a permit without waiting
     pub fn try_acquire(&self) -> bool {
-        self.limiter.check().is_ok()
+        self.current().check().is_ok()
+    }
+
+    fn current(&self) -> Arc<DirectLimiter> {
+        match self.limiter.read() {
+            Ok(limiter) => limiter.clone(),
+            Err(poisoned) => poisoned.into_inner().clone(),
+        }
     }
 }
 
+fn direct_limiter(tps: usize) -> DirectLimiter {
+    let tps = NonZeroU32::new(tps.clamp(1, u32::MAX as usize) as u32).unwrap();
+    GovRateLimiter::direct(Quota::per_second(tps))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -89,6 +141,43 @@ mod tests {
         assert!(limiter.try_acquire());
     }
 
+    #[tokio::test]
+    async fn test_set_tps() {
+        let limiter = RateLimiter::new(10);
+        for _ in 0..10 {
+            limiter.try_acquire();
+        }
+        assert!(!limiter.try_acquire());
+
+        // A new rate starts with a fresh burst allowance
+        limiter.set_tps(100);
+        assert_eq!(limiter.tps(), 100);
+        for _ in 0..50 {
+            assert!(limiter.try_acquire());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_set_tps_wakes_waiters() {
+        let limiter = Arc::new(RateLimiter::new(1));
+        limiter.acquire().await;
+
+        // The next permit at 1 TPS is a second away
+        let waiter = {
+            let limiter = limiter.clone();
+            tokio::spawn(async move {
+                let start = Instant::now();
+                limiter.acquire().await;
+                start.elapsed()
+            })
+        };
+        sleep(Duration::from_millis(50)).await;
+        limiter.set_tps(1000);
+
+        let waited = waiter.await.unwrap();
+        assert!(waited < Duration::from_millis(500), "waited {:?}", waited);
+    }
+
     #[tokio::test]
     async fn test_concurrent_workers() {
         use std::sync::Arc;
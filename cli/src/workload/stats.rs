@@ -1,9 +1,15 @@
 use hdrhistogram::Histogram;
+use serde::Serialize;
 use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 
+use crate::snapshot::ScheduledSnapshot;
+
 /// Metric sample from a worker
 #[derive(Debug, Clone)]
 pub struct MetricSample {
@@ -12,6 +18,109 @@ pub struct MetricSample {
     pub success: bool,
     pub latency_us: u64,
     pub error: Option<String>,
+    /// Load-shape phase the operation was issued in (see
+    /// [`crate::workload::WorkloadConfig::phase_at`])
+    pub phase: Option<&'static str>,
+    /// Issued during the warmup, so kept out of the run's stats
+    pub warmup: bool,
+}
+
+/// Upper bounds (exclusive, in microseconds) of the latency histogram buckets;
+/// the final bucket is unbounded
+const LATENCY_BUCKET_BOUNDS_US: &[u64] = &[
+    1_000, 5_000, 10_000, 25_000, 50_000, 100_000, 250_000, 500_000, 1_000_000,
+];
+
+/// Bounds of the finer buckets in exported histograms (1-2-5 steps from 10us);
+/// fixed so exports from different runs line up bucket for bucket
+const EXPORT_BUCKET_BOUNDS_US: &[u64] = &[
+    10, 20, 50, 100, 200, 500, 1_000, 2_000, 5_000, 10_000, 20_000, 50_000, 100_000, 200_000,
+    500_000, 1_000_000, 2_000_000, 5_000_000, 10_000_000, 20_000_000,
+];
+
+/// Percentiles reported alongside an exported histogram
+const EXPORT_PERCENTILES: &[(&str, f64)] = &[
+    ("p50", 0.50),
+    ("p90", 0.90),
+    ("p95", 0.95),
+    ("p99", 0.99),
+    ("p99.9", 0.999),
+];
+
+/// Number of successful operations whose latency fell in `[lower_us, upper_us)`
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct LatencyBucket {
+    pub label: String,
+    pub lower_us: u64,
+    /// `None` for the final, unbounded bucket
+    pub upper_us: Option<u64>,
+    pub count: u64,
+}
+
+/// Group `(latency_us, count)` pairs into the fixed latency buckets
+pub fn bucket_latencies(values: impl IntoIterator<Item = (u64, u64)>) -> Vec<LatencyBucket> {
+    bucket_latencies_with(LATENCY_BUCKET_BOUNDS_US, values)
+}
+
+fn bucket_latencies_with(
+    bounds: &[u64],
+    values: impl IntoIterator<Item = (u64, u64)>,
+) -> Vec<LatencyBucket> {
+    let mut buckets: Vec<LatencyBucket> = (0..=bounds.len())
+        .map(|i| {
+            let lower_us = if i == 0 { 0 } else { bounds[i - 1] };
+            let upper_us = bounds.get(i).copied();
+            let label = match upper_us {
+                Some(upper) if i == 0 => format!("<{}", format_bound(upper)),
+                Some(upper) => format_range(lower_us, upper),
+                None => format!(">={}", format_bound(lower_us)),
+            };
+            LatencyBucket {
+                label,
+                lower_us,
+                upper_us,
+                count: 0,
+            }
+        })
+        .collect();
+
+    for (value, count) in values {
+        let index = bounds
+            .iter()
+            .position(|&upper| value < upper)
+            .unwrap_or(bounds.len());
+        buckets[index].count += count;
+    }
+
+    buckets
+}
+
+/// Split a bucket bound into a value in us below a millisecond, ms, or s from
+/// one second up, and its unit
+fn split_bound(us: u64) -> (u64, &'static str) {
+    if us >= 1_000_000 {
+        (us / 1_000_000, "s")
+    } else if us >= 1_000 {
+        (us / 1_000, "ms")
+    } else {
+        (us, "us")
+    }
+}
+
+fn format_bound(us: u64) -> String {
+    let (value, unit) = split_bound(us);
+    format!("{}{}", value, unit)
+}
+
+/// `1-5ms`, or `500ms-1s` when the bounds are in different units
+fn format_range(lower_us: u64, upper_us: u64) -> String {
+    let (lower, lower_unit) = split_bound(lower_us);
+    let (upper, upper_unit) = split_bound(upper_us);
+    if lower_unit == upper_unit {
+        format!("{}-{}{}", lower, upper, upper_unit)
+    } else {
+        format!("{}{}-{}{}", lower, lower_unit, upper, upper_unit)
+    }
 }
 
 /// Workload statistics with histogram-based latency tracking
@@ -22,7 +131,16 @@ pub struct WorkloadStats {
     pub latency_histogram: Mutex<Histogram<u64>>,
     pub errors: Mutex<HashMap<String, u64>>,
     pub operation_counts: Mutex<HashMap<String, u64>>,
-    pub start_time: Instant,
+    pub phases: Mutex<Vec<PhaseStats>>,
+    /// Latency of successful operations per load-shape phase, in the order
+    /// the phases were first seen
+    pub phase_histograms: Mutex<Vec<(String, Histogram<u64>)>>,
+    /// Latency of successful operations per operation type or custom query
+    pub operation_histograms: Mutex<HashMap<String, Histogram<u64>>>,
+    /// Cold-start throughput of the warmup, if the run had one
+    pub warmup: Mutex<Option<WarmupStats>>,
+    /// When measurement started (or will, at the end of a warmup)
+    pub start_time: Mutex<Instant>,
 }
 
 impl WorkloadStats {
@@ -36,7 +154,11 @@ impl WorkloadStats {
             ),
             errors: Mutex::new(HashMap::new()),
             operation_counts: Mutex::new(HashMap::new()),
-            start_time: Instant::now(),
+            phases: Mutex::new(Vec::new()),
+            phase_histograms: Mutex::new(Vec::new()),
+            operation_histograms: Mutex::new(HashMap::new()),
+            warmup: Mutex::new(None),
+            start_time: Mutex::new(Instant::now()),
         }
     }
 
@@ -53,6 +175,13 @@ impl WorkloadStats {
         if let Ok(mut counts) = self.operation_counts.lock() {
             *counts.entry(operation_type.to_string()).or_insert(0) += 1;
         }
+
+        if let Ok(mut histograms) = self.operation_histograms.lock() {
+            let hist = histograms
+                .entry(operation_type.to_string())
+                .or_insert_with(|| Histogram::<u64>::new_with_bounds(1, 60_000_000, 3).unwrap());
+            let _ = hist.record(latency_us);
+        }
     }
 
     /// Record a failed transaction
@@ -69,10 +198,30 @@ impl WorkloadStats {
         }
     }
 
+    /// Record a successful transaction's latency against the phase it ran in
+    pub fn record_phase_latency(&self, phase: &str, latency: Duration) {
+        let Ok(mut histograms) = self.phase_histograms.lock() else {
+            return;
+        };
+        let index = match histograms.iter().position(|(name, _)| name == phase) {
+            Some(index) => index,
+            None => {
+                let hist = Histogram::<u64>::new_with_bounds(1, 60_000_000, 3).unwrap();
+                histograms.push((phase.to_string(), hist));
+                histograms.len() - 1
+            }
+        };
+        let _ = histograms[index].1.record(latency.as_micros() as u64);
+    }
+
     /// Record a metric sample from a worker
     pub fn record_sample(&self, sample: MetricSample) {
         if sample.success {
-            self.record_success(&sample.operation_type, Duration::from_micros(sample.latency_us));
+            let latency = Duration::from_micros(sample.latency_us);
+            self.record_success(&sample.operation_type, latency);
+            if let Some(phase) = sample.phase {
+                self.record_phase_latency(phase, latency);
+            }
         } else if let Some(error) = &sample.error {
             self.record_failure(&sample.operation_type, error);
         }
@@ -102,9 +251,51 @@ impl WorkloadStats {
         (self.success_count() as f64 / total as f64) * 100.0
     }
 
-    /// Get elapsed time since start
+    /// Get elapsed time since start; zero until a future start arrives
     pub fn elapsed(&self) -> Duration {
-        self.start_time.elapsed()
+        Instant::now().saturating_duration_since(self.start_time())
+    }
+
+    /// When measurement started
+    pub fn start_time(&self) -> Instant {
+        match self.start_time.lock() {
+            Ok(start) => *start,
+            Err(poisoned) => *poisoned.into_inner(),
+        }
+    }
+
+    /// Restart the clock at `start`, e.g. the end of a warmup that is about to
+    /// begin
+    pub fn start_at(&self, start: Instant) {
+        if let Ok(mut start_time) = self.start_time.lock() {
+            *start_time = start;
+        }
+    }
+
+    /// Record the summary of the warmup that preceded this run
+    pub fn set_warmup(&self, warmup: WarmupStats) {
+        if let Ok(mut slot) = self.warmup.lock() {
+            *slot = Some(warmup);
+        }
+    }
+
+    /// Cold-start throughput of the run's warmup, if it had one
+    pub fn warmup(&self) -> Option<WarmupStats> {
+        self.warmup.lock().ok().and_then(|warmup| warmup.clone())
+    }
+
+    /// Summarize these stats as a warmup that lasted `duration`
+    pub fn warmup_summary(&self, duration: Duration) -> WarmupStats {
+        let secs = duration.as_secs_f64();
+        let any_success = self.success_count() > 0;
+        WarmupStats {
+            duration_secs: secs,
+            total: self.total(),
+            failed: self.failure_count(),
+            tps: if secs > 0.0 { self.total() as f64 / secs } else { 0.0 },
+            p50_us: self.latency_percentile(0.50).filter(|_| any_success),
+            p99_us: self.latency_percentile(0.99).filter(|_| any_success),
+        }
     }
 
     /// Get transactions per second
@@ -126,6 +317,14 @@ impl WorkloadStats {
         }
     }
 
+    /// A copy of the run's latency histogram
+    fn latency_snapshot(&self) -> Histogram<u64> {
+        match self.latency_histogram.lock() {
+            Ok(hist) => hist.clone(),
+            Err(poisoned) => poisoned.into_inner().clone(),
+        }
+    }
+
     /// Get mean latency in microseconds
     pub fn mean_latency(&self) -> Option<f64> {
         if let Ok(hist) = self.latency_histogram.lock() {
@@ -153,6 +352,104 @@ impl WorkloadStats {
         }
     }
 
+    /// Get successful operation latencies grouped into fixed buckets
+    pub fn latency_buckets(&self) -> Vec<LatencyBucket> {
+        match self.latency_histogram.lock() {
+            Ok(hist) => bucket_latencies(
+                hist.iter_recorded().map(|v| {
+                    (hist.lowest_equivalent(v.value_iterated_to()), v.count_at_value())
+                }),
+            ),
+            Err(_) => bucket_latencies(std::iter::empty()),
+        }
+    }
+
+    /// Full latency distribution of successful operations for export
+    ///
+    /// Built from the HDR histogram samples are recorded into, so memory stays
+    /// constant however long the run.
+    pub fn latency_distribution(&self) -> LatencyDistribution {
+        let Ok(hist) = self.latency_histogram.lock() else {
+            return LatencyDistribution::from_values(0, Vec::new(), None, Vec::new());
+        };
+
+        let count = hist.len();
+        let percentiles = EXPORT_PERCENTILES
+            .iter()
+            .filter(|_| count > 0)
+            .map(|&(name, quantile)| LatencyPercentile {
+                name: name.to_string(),
+                value_us: hist.value_at_quantile(quantile),
+            })
+            .collect();
+        let values: Vec<(u64, u64)> = hist
+            .iter_recorded()
+            .map(|v| (hist.lowest_equivalent(v.value_iterated_to()), v.count_at_value()))
+            .collect();
+        let range = (count > 0).then(|| (hist.min(), hist.max(), hist.mean()));
+
+        LatencyDistribution::from_values(count, percentiles, range, values)
+    }
+
+    /// Close the current phase, covering everything since the previous phase
+    /// ended (or the start of the run), against an average `target_tps`
+    pub fn end_phase(&self, name: &str, target_tps: f64) {
+        let end_secs = self.elapsed().as_secs_f64();
+        let total = self.total();
+        let Ok(mut phases) = self.phases.lock() else {
+            return;
+        };
+
+        let start_secs = phases.last().map(|p| p.end_secs).unwrap_or(0.0);
+        let phase_total = total - phases.iter().map(|p| p.total).sum::<u64>();
+        let secs = end_secs - start_secs;
+        phases.push(PhaseStats {
+            name: name.to_string(),
+            start_secs,
+            end_secs,
+            total: phase_total,
+            target_tps,
+            achieved_tps: if secs > 0.0 { phase_total as f64 / secs } else { 0.0 },
+        });
+    }
+
+    /// Phases closed so far, in order
+    pub fn phases(&self) -> Vec<PhaseStats> {
+        if let Ok(phases) = self.phases.lock() {
+            phases.clone()
+        } else {
+            Vec::new()
+        }
+    }
+
+    /// Latency percentiles of each load-shape phase, e.g. the base and peak of
+    /// a spike workload
+    pub fn phase_latencies(&self) -> Vec<LatencyBreakdown> {
+        let Ok(histograms) = self.phase_histograms.lock() else {
+            return Vec::new();
+        };
+        histograms
+            .iter()
+            .filter(|(_, hist)| !hist.is_empty())
+            .map(|(name, hist)| LatencyBreakdown::from_histogram(name, hist))
+            .collect()
+    }
+
+    /// Latency percentiles of each operation type, or of each custom query,
+    /// sorted by name
+    pub fn operation_latencies(&self) -> Vec<LatencyBreakdown> {
+        let Ok(histograms) = self.operation_histograms.lock() else {
+            return Vec::new();
+        };
+        let mut latencies: Vec<_> = histograms
+            .iter()
+            .filter(|(_, hist)| !hist.is_empty())
+            .map(|(name, hist)| LatencyBreakdown::from_histogram(name, hist))
+            .collect();
+        latencies.sort_by(|a, b| a.name.cmp(&b.name));
+        latencies
+    }
+
     /// Get operation counts
     pub fn operation_counts(&self) -> HashMap<String, u64> {
         if let Ok(counts) = self.operation_counts.lock() {
@@ -186,10 +483,25 @@ impl WorkloadStats {
             mean: self.mean_latency(),
             min: self.min_latency(),
             max: self.max_latency(),
+            latency_buckets: self.latency_buckets(),
             operation_counts: self.operation_counts(),
             error_counts: self.error_counts(),
+            phases: self.phases(),
+            phase_latencies: self.phase_latencies(),
+            operation_latencies: self.operation_latencies(),
+            warmup: self.warmup(),
         }
     }
+
+    /// Open a JSON Lines file for streaming per-interval samples of these stats
+    pub fn write_jsonl(&self, path: &Path) -> std::io::Result<JsonlStatsWriter> {
+        Ok(JsonlStatsWriter {
+            writer: BufWriter::new(File::create(path)?),
+            last_total: self.total(),
+            last_sample: Instant::now(),
+            last_latencies: self.latency_snapshot(),
+        })
+    }
 }
 
 impl Default for WorkloadStats {
@@ -213,8 +525,307 @@ pub struct StatsSnapshot {
     pub mean: Option<f64>,
     pub min: Option<u64>,
     pub max: Option<u64>,
+    pub latency_buckets: Vec<LatencyBucket>,
+    pub operation_counts: HashMap<String, u64>,
+    pub error_counts: HashMap<String, u64>,
+    pub phases: Vec<PhaseStats>,
+    pub phase_latencies: Vec<LatencyBreakdown>,
+    pub operation_latencies: Vec<LatencyBreakdown>,
+    pub warmup: Option<WarmupStats>,
+}
+
+/// Achieved vs target throughput over one phase of a run, e.g. the ramp and
+/// hold of a ramp-up workload
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PhaseStats {
+    pub name: String,
+    pub start_secs: f64,
+    pub end_secs: f64,
+    /// Transactions completed during the phase
+    pub total: u64,
+    /// Average target over the phase
+    pub target_tps: f64,
+    pub achieved_tps: f64,
+}
+
+/// Throughput and latency of the warmup before a run, reported apart from
+/// its steady-state numbers
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct WarmupStats {
+    pub duration_secs: f64,
+    pub total: u64,
+    pub failed: u64,
+    pub tps: f64,
+    pub p50_us: Option<u64>,
+    pub p99_us: Option<u64>,
+}
+
+/// Latency of a share of a run's successful operations: those issued in one
+/// load-shape phase (e.g. all the peaks of a spike), or of one operation type
+/// or custom query
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct LatencyBreakdown {
+    pub name: String,
+    pub count: u64,
+    pub p50_us: u64,
+    pub p95_us: u64,
+    pub p99_us: u64,
+    pub max_us: u64,
+}
+
+impl LatencyBreakdown {
+    fn from_histogram(name: &str, hist: &Histogram<u64>) -> Self {
+        Self {
+            name: name.to_string(),
+            count: hist.len(),
+            p50_us: hist.value_at_quantile(0.50),
+            p95_us: hist.value_at_quantile(0.95),
+            p99_us: hist.value_at_quantile(0.99),
+            max_us: hist.max(),
+        }
+    }
+}
+
+/// Final workload summary for `--json` output
+#[derive(Debug, Clone, Serialize)]
+pub struct WorkloadReport {
+    pub pattern: String,
+    pub total: u64,
+    pub success: u64,
+    pub failed: u64,
+    pub success_rate: f64,
+    pub elapsed_secs: f64,
+    pub tps: f64,
+    pub p50_us: Option<u64>,
+    pub p95_us: Option<u64>,
+    pub p99_us: Option<u64>,
+    pub mean_us: Option<f64>,
+    pub max_us: Option<u64>,
+    pub latency_buckets: Vec<LatencyBucket>,
     pub operation_counts: HashMap<String, u64>,
     pub error_counts: HashMap<String, u64>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub phases: Vec<PhaseStats>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub phase_latencies: Vec<LatencyBreakdown>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub operation_latencies: Vec<LatencyBreakdown>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub warmup: Option<WarmupStats>,
+    /// Seed the run's operations were generated from, to replay it with `--seed`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u64>,
+    /// Snapshots taken by `--snapshot-interval` during the run
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub snapshots: Vec<ScheduledSnapshot>,
+}
+
+impl WorkloadReport {
+    pub fn from_snapshot(pattern: &str, snapshot: StatsSnapshot) -> Self {
+        Self {
+            pattern: pattern.to_string(),
+            total: snapshot.total,
+            success: snapshot.success,
+            failed: snapshot.failed,
+            success_rate: snapshot.success_rate,
+            elapsed_secs: snapshot.elapsed.as_secs_f64(),
+            tps: snapshot.tps,
+            p50_us: snapshot.p50,
+            p95_us: snapshot.p95,
+            p99_us: snapshot.p99,
+            mean_us: snapshot.mean,
+            max_us: snapshot.max,
+            latency_buckets: snapshot.latency_buckets,
+            operation_counts: snapshot.operation_counts,
+            error_counts: snapshot.error_counts,
+            phases: snapshot.phases,
+            phase_latencies: snapshot.phase_latencies,
+            operation_latencies: snapshot.operation_latencies,
+            warmup: snapshot.warmup,
+            seed: None,
+            snapshots: Vec::new(),
+        }
+    }
+
+    /// Record the seed the run used
+    pub fn with_seed(mut self, seed: Option<u64>) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Record the snapshots taken during the run
+    pub fn with_snapshots(mut self, snapshots: Vec<ScheduledSnapshot>) -> Self {
+        self.snapshots = snapshots;
+        self
+    }
+}
+
+/// A named latency percentile, e.g. `p99`
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct LatencyPercentile {
+    pub name: String,
+    pub value_us: u64,
+}
+
+/// Latency histogram of a run, as written by `workload --histogram-out`
+///
+/// Buckets use the same fixed bounds in every export (empty ones included), so
+/// two files can be diffed line by line.
+#[derive(Debug, Clone, Serialize)]
+pub struct LatencyDistribution {
+    /// Successful operations recorded
+    pub count: u64,
+    pub min_us: Option<u64>,
+    pub max_us: Option<u64>,
+    pub mean_us: Option<f64>,
+    pub percentiles: Vec<LatencyPercentile>,
+    pub buckets: Vec<LatencyBucket>,
+}
+
+impl LatencyDistribution {
+    fn from_values(
+        count: u64,
+        percentiles: Vec<LatencyPercentile>,
+        range: Option<(u64, u64, f64)>,
+        values: Vec<(u64, u64)>,
+    ) -> Self {
+        Self {
+            count,
+            min_us: range.map(|(min, _, _)| min),
+            max_us: range.map(|(_, max, _)| max),
+            mean_us: range.map(|(_, _, mean)| mean),
+            percentiles,
+            buckets: bucket_latencies_with(EXPORT_BUCKET_BOUNDS_US, values),
+        }
+    }
+
+    /// Write as CSV when `path` ends in `.csv`, JSON otherwise
+    pub fn write(&self, path: &Path) -> std::io::Result<()> {
+        let is_csv = path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("csv"));
+        let contents = if is_csv {
+            self.to_csv()
+        } else {
+            serde_json::to_string_pretty(self)? + "\n"
+        };
+        std::fs::write(path, contents)
+    }
+
+    /// One row per summary value, percentile and bucket:
+    /// `kind,name,lower_us,upper_us,value`
+    pub fn to_csv(&self) -> String {
+        let opt = |value: Option<u64>| value.map(|v| v.to_string()).unwrap_or_default();
+
+        let mut out = String::from("kind,name,lower_us,upper_us,value\n");
+        out.push_str(&format!("summary,count,,,{}\n", self.count));
+        out.push_str(&format!("summary,min_us,,,{}\n", opt(self.min_us)));
+        out.push_str(&format!("summary,max_us,,,{}\n", opt(self.max_us)));
+        out.push_str(&format!(
+            "summary,mean_us,,,{}\n",
+            self.mean_us.map(|m| format!("{:.1}", m)).unwrap_or_default()
+        ));
+        for percentile in &self.percentiles {
+            out.push_str(&format!("percentile,{},,,{}\n", percentile.name, percentile.value_us));
+        }
+        for bucket in &self.buckets {
+            out.push_str(&format!(
+                "bucket,{},{},{},{}\n",
+                bucket.label,
+                bucket.lower_us,
+                opt(bucket.upper_us),
+                bucket.count
+            ));
+        }
+        out
+    }
+}
+
+/// One line of JSON Lines stats output
+#[derive(Debug, Clone, Serialize)]
+pub struct StatsLine {
+    pub timestamp: String,
+    pub elapsed_secs: f64,
+    pub tps: f64,
+    pub total: u64,
+    pub success: u64,
+    pub failed: u64,
+    pub p50_us: Option<u64>,
+    pub p95_us: Option<u64>,
+    pub p99_us: Option<u64>,
+    #[serde(rename = "final", skip_serializing_if = "std::ops::Not::not")]
+    pub is_final: bool,
+}
+
+/// Streams workload stats to a file, one JSON object per line
+pub struct JsonlStatsWriter {
+    writer: BufWriter<File>,
+    last_total: u64,
+    last_sample: Instant,
+    /// The run's latency histogram as of the previous sample
+    last_latencies: Histogram<u64>,
+}
+
+impl JsonlStatsWriter {
+    /// Write a sample with the throughput and latency percentiles since the
+    /// previous sample and flush, so the file can be followed while the
+    /// workload runs
+    pub fn sample(&mut self, stats: &WorkloadStats) -> std::io::Result<()> {
+        let total = stats.total();
+        let interval = self.last_sample.elapsed().as_secs_f64();
+        let tps = if interval > 0.0 {
+            total.saturating_sub(self.last_total) as f64 / interval
+        } else {
+            0.0
+        };
+        self.last_total = total;
+        self.last_sample = Instant::now();
+
+        // The run's histogram only grows, so the difference is this interval's
+        let latencies = stats.latency_snapshot();
+        let mut interval_latencies = latencies.clone();
+        if interval_latencies.subtract(&self.last_latencies).is_err() {
+            interval_latencies.reset();
+        }
+        self.last_latencies = latencies;
+
+        self.write_line(stats, tps, &interval_latencies, false)
+    }
+
+    /// Write the final summary line (overall TPS and latencies, `"final": true`)
+    /// and close the file
+    pub fn finish(mut self, stats: &WorkloadStats) -> std::io::Result<()> {
+        let latencies = stats.latency_snapshot();
+        self.write_line(stats, stats.tps(), &latencies, true)
+    }
+
+    fn write_line(
+        &mut self,
+        stats: &WorkloadStats,
+        tps: f64,
+        latencies: &Histogram<u64>,
+        is_final: bool,
+    ) -> std::io::Result<()> {
+        // An interval without successful operations has no latencies to report
+        let percentile =
+            |quantile: f64| (!latencies.is_empty()).then(|| latencies.value_at_quantile(quantile));
+        let line = StatsLine {
+            timestamp: chrono::Utc::now().to_rfc3339(),
+            elapsed_secs: stats.elapsed().as_secs_f64(),
+            tps,
+            total: stats.total(),
+            success: stats.success_count(),
+            failed: stats.failure_count(),
+            p50_us: percentile(0.50),
+            p95_us: percentile(0.95),
+            p99_us: percentile(0.99),
+            is_final,
+        };
+
+        serde_json::to_writer(&mut self.writer, &line)?;
+        self.writer.write_all(b"\n")?;
+        self.writer.flush()
+    }
 }
 
 #[cfg(test)]
@@ -324,6 +935,8 @@ mod tests {
             success: true,
             latency_us: 5000,
             error: None,
+            phase: None,
+            warmup: false,
         };
 
         stats.record_sample(sample);
@@ -332,6 +945,113 @@ mod tests {
         assert_eq!(stats.total(), 1);
     }
 
+    #[test]
+    fn test_end_phase() {
+        let stats = WorkloadStats::new();
+        stats.record_success("select", Duration::from_millis(1));
+        stats.record_success("select", Duration::from_millis(1));
+        stats.end_phase("ramp", 55.0);
+        stats.record_success("insert", Duration::from_millis(1));
+        stats.end_phase("hold", 100.0);
+
+        let phases = stats.snapshot().phases;
+        assert_eq!(phases.len(), 2);
+        assert_eq!(phases[0].name, "ramp");
+        assert_eq!(phases[0].total, 2);
+        assert_eq!(phases[0].target_tps, 55.0);
+        assert_eq!(phases[1].total, 1);
+        assert_eq!(phases[1].start_secs, phases[0].end_secs);
+
+        let report = WorkloadReport::from_snapshot("ramp_up", stats.snapshot());
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["phases"][1]["name"], "hold");
+    }
+
+    #[test]
+    fn test_phase_latencies() {
+        let stats = WorkloadStats::new();
+        let sample = |latency_us, phase| MetricSample {
+            worker_id: 0,
+            operation_type: "select".to_string(),
+            success: true,
+            latency_us,
+            error: None,
+            phase: Some(phase),
+            warmup: false,
+        };
+        for _ in 0..10 {
+            stats.record_sample(sample(1_000, "base"));
+            stats.record_sample(sample(50_000, "peak"));
+        }
+
+        let phases = stats.snapshot().phase_latencies;
+        assert_eq!(phases.len(), 2);
+        assert_eq!(phases[0].name, "base");
+        assert_eq!(phases[0].count, 10);
+        assert!(phases[0].p99_us < 2_000);
+        assert_eq!(phases[1].name, "peak");
+        assert!(phases[1].p99_us >= 49_000);
+        // The overall histogram still sees every sample
+        assert_eq!(stats.success_count(), 20);
+
+        let report = WorkloadReport::from_snapshot("spike", stats.snapshot());
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["phase_latencies"][1]["name"], "peak");
+    }
+
+    #[test]
+    fn test_warmup_summary() {
+        let warmup = WorkloadStats::new();
+        for _ in 0..20 {
+            warmup.record_success("select", Duration::from_millis(30));
+        }
+        warmup.record_failure("select", "connection refused");
+
+        let summary = warmup.warmup_summary(Duration::from_secs(10));
+        assert_eq!(summary.total, 21);
+        assert_eq!(summary.failed, 1);
+        assert!((summary.tps - 2.1).abs() < 0.001);
+        assert!(summary.p50_us.unwrap() >= 29_000);
+
+        // The run's clock starts once the warmup ends
+        let stats = WorkloadStats::new();
+        stats.start_at(Instant::now() + Duration::from_secs(60));
+        assert_eq!(stats.elapsed(), Duration::ZERO);
+        stats.set_warmup(summary);
+
+        let report = WorkloadReport::from_snapshot("oltp", stats.snapshot());
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["warmup"]["total"], 21);
+        assert!(WorkloadStats::new().warmup_summary(Duration::ZERO).p50_us.is_none());
+    }
+
+    #[test]
+    fn test_operation_latencies() {
+        let stats = WorkloadStats::new();
+        for _ in 0..10 {
+            stats.record_success("recent_orders", Duration::from_millis(1));
+            stats.record_success("order_by_id", Duration::from_millis(40));
+        }
+        stats.record_failure("order_by_id", "timeout");
+
+        let latencies = stats.snapshot().operation_latencies;
+        assert_eq!(latencies.len(), 2);
+        assert_eq!(latencies[0].name, "order_by_id");
+        assert_eq!(latencies[0].count, 10);
+        assert!(latencies[0].p50_us >= 39_000);
+        assert_eq!(latencies[1].name, "recent_orders");
+        assert!(latencies[1].p99_us < 2_000);
+
+        let report = WorkloadReport::from_snapshot("custom", stats.snapshot());
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["operation_latencies"][1]["name"], "recent_orders");
+        assert!(json.get("seed").is_none());
+
+        let report = WorkloadReport::from_snapshot("custom", stats.snapshot()).with_seed(Some(7));
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["seed"], 7);
+    }
+
     #[test]
     fn test_mean_latency() {
         let stats = WorkloadStats::new();
@@ -344,4 +1064,118 @@ mod tests {
         // Mean should be around 20ms (20,000 microseconds)
         assert!(mean > 15_000.0 && mean < 25_000.0);
     }
+
+    #[test]
+    fn test_write_jsonl() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("stats.jsonl");
+        let stats = WorkloadStats::new();
+
+        let mut writer = stats.write_jsonl(&path).unwrap();
+        stats.record_success("select", Duration::from_millis(5));
+        writer.sample(&stats).unwrap();
+        stats.record_success("select", Duration::from_millis(50));
+        writer.sample(&stats).unwrap();
+        writer.sample(&stats).unwrap();
+        stats.record_failure("insert", "deadlock");
+        writer.finish(&stats).unwrap();
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        let lines: Vec<serde_json::Value> = content
+            .lines()
+            .map(|l| serde_json::from_str(l).unwrap())
+            .collect();
+
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[0]["total"], 1);
+        assert!(lines[0].get("final").is_none());
+
+        // Percentiles cover only the operations since the previous line
+        let p99 = |line: &serde_json::Value| line["p99_us"].as_u64();
+        assert!(p99(&lines[0]).unwrap() < 10_000);
+        assert!(p99(&lines[1]).unwrap() > 40_000);
+        assert!(lines[1]["p50_us"].as_u64().unwrap() > 40_000);
+        assert!(lines[2]["p50_us"].is_null());
+
+        // The final line covers the whole run
+        assert_eq!(lines[3]["failed"], 1);
+        assert_eq!(lines[3]["final"], true);
+        assert!(lines[3]["p50_us"].as_u64().unwrap() < 10_000);
+    }
+
+    #[test]
+    fn test_latency_buckets() {
+        let buckets = bucket_latencies([(500, 3), (1_000, 1), (7_500, 2), (2_000_000, 1)]);
+
+        let labels: Vec<_> = buckets.iter().map(|b| b.label.as_str()).collect();
+        assert_eq!(labels[..3], ["<1ms", "1-5ms", "5-10ms"]);
+        assert_eq!(labels.last(), Some(&">=1s"));
+
+        // Lower bounds are inclusive
+        assert_eq!(buckets[0].count, 3);
+        assert_eq!(buckets[1].count, 1);
+        assert_eq!(buckets[2].count, 2);
+        assert_eq!(buckets.last().unwrap().count, 1);
+        assert_eq!(buckets.last().unwrap().upper_us, None);
+    }
+
+    #[test]
+    fn test_latency_distribution() {
+        let stats = WorkloadStats::new();
+        for _ in 0..99 {
+            stats.record_success("select", Duration::from_micros(150));
+        }
+        stats.record_success("select", Duration::from_millis(40));
+        stats.record_failure("insert", "deadlock");
+
+        let dist = stats.latency_distribution();
+        assert_eq!(dist.count, 100);
+        assert_eq!(dist.buckets.len(), EXPORT_BUCKET_BOUNDS_US.len() + 1);
+        assert_eq!(dist.buckets.iter().map(|b| b.count).sum::<u64>(), 100);
+        assert_eq!(dist.buckets.iter().find(|b| b.label == "100-200us").unwrap().count, 99);
+        assert_eq!(dist.buckets.iter().find(|b| b.label == "20-50ms").unwrap().count, 1);
+
+        let p99_9 = dist.percentiles.iter().find(|p| p.name == "p99.9").unwrap();
+        assert!(p99_9.value_us >= 40_000);
+    }
+
+    #[test]
+    fn test_empty_latency_distribution_has_every_bucket() {
+        let dist = WorkloadStats::new().latency_distribution();
+        assert_eq!(dist.count, 0);
+        assert!(dist.percentiles.is_empty());
+        assert_eq!(dist.max_us, None);
+        assert_eq!(dist.buckets.len(), EXPORT_BUCKET_BOUNDS_US.len() + 1);
+    }
+
+    #[test]
+    fn test_latency_distribution_csv() {
+        let stats = WorkloadStats::new();
+        stats.record_success("select", Duration::from_micros(15));
+
+        let csv = stats.latency_distribution().to_csv();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines[0], "kind,name,lower_us,upper_us,value");
+        assert_eq!(lines[1], "summary,count,,,1");
+        assert!(lines.contains(&"percentile,p50,,,15"));
+        assert!(lines.contains(&"bucket,<10us,0,10,0"));
+        assert!(lines.contains(&"bucket,10-20us,10,20,1"));
+        assert_eq!(lines.last(), Some(&"bucket,>=20s,20000000,,0"));
+    }
+
+    #[test]
+    fn test_stats_latency_buckets() {
+        let stats = WorkloadStats::new();
+        stats.record_success("select", Duration::from_micros(200));
+        stats.record_success("select", Duration::from_millis(30));
+        stats.record_failure("insert", "deadlock");
+
+        let buckets = stats.latency_buckets();
+        assert_eq!(buckets.iter().map(|b| b.count).sum::<u64>(), 2);
+        assert_eq!(buckets[0].count, 1);
+        assert_eq!(
+            buckets.iter().find(|b| b.label == "25-50ms").unwrap().count,
+            1
+        );
+    }
 }
@@ -0,0 +1,243 @@
+use anyhow::{anyhow, Result};
+use console::style;
+use serde::Serialize;
+
+use crate::workload::stats::StatsSnapshot;
+
+/// p99 growth over the first step that marks the latency knee
+const KNEE_P99_FACTOR: f64 = 2.0;
+
+/// Achieved/target TPS ratio below which a step counts as saturated
+const SATURATION_RATIO: f64 = 0.9;
+
+/// Result of one phase of a TPS sweep
+#[derive(Debug, Clone, Serialize)]
+pub struct SweepStep {
+    pub target_tps: usize,
+    pub achieved_tps: f64,
+    pub total: u64,
+    pub failed: u64,
+    pub success_rate: f64,
+    pub p50_us: Option<u64>,
+    pub p95_us: Option<u64>,
+    pub p99_us: Option<u64>,
+}
+
+impl SweepStep {
+    pub fn from_snapshot(target_tps: usize, snapshot: &StatsSnapshot) -> Self {
+        Self {
+            target_tps,
+            achieved_tps: snapshot.tps,
+            total: snapshot.total,
+            failed: snapshot.failed,
+            success_rate: snapshot.success_rate,
+            p50_us: snapshot.p50,
+            p95_us: snapshot.p95,
+            p99_us: snapshot.p99,
+        }
+    }
+
+    /// Whether the database fell noticeably short of the target rate
+    pub fn is_saturated(&self) -> bool {
+        self.achieved_tps < self.target_tps as f64 * SATURATION_RATIO
+    }
+}
+
+/// Output format for sweep results
+#[derive(Debug, Clone, Copy, PartialEq, clap::ValueEnum)]
+pub enum SweepFormat {
+    Table,
+    Csv,
+    Json,
+}
+
+/// Target TPS values for a sweep from `from` to `to` (inclusive) in `step` increments
+pub fn sweep_targets(from: usize, to: usize, step: usize) -> Result<Vec<usize>> {
+    if from == 0 {
+        return Err(anyhow!("--from must be greater than 0"));
+    }
+    if step == 0 {
+        return Err(anyhow!("--step must be greater than 0"));
+    }
+    if from > to {
+        return Err(anyhow!("--from ({}) cannot exceed --to ({})", from, to));
+    }
+
+    Ok((from..=to).step_by(step).collect())
+}
+
+/// Parse a phase duration such as "30", "30s", "2m" or "1h" into seconds
+pub fn parse_duration_secs(value: &str) -> Result<u64> {
+    let value = value.trim();
+    let (number, multiplier) = match value.chars().last() {
+        Some('s') => (&value[..value.len() - 1], 1),
+        Some('m') => (&value[..value.len() - 1], 60),
+        Some('h') => (&value[..value.len() - 1], 3600),
+        _ => (value, 1),
+    };
+
+    let secs = number
+        .parse::<u64>()
+        .map_err(|_| anyhow!("Invalid duration '{}' (use e.g. 30, 30s, 2m)", value))?
+        * multiplier;
+
+    if secs == 0 {
+        return Err(anyhow!("Duration must be greater than 0"));
+    }
+
+    Ok(secs)
+}
+
+/// Index of the first step where latency knees upward: p99 at least double the
+/// first step's, or achieved TPS falling short of the target
+pub fn find_knee(steps: &[SweepStep]) -> Option<usize> {
+    let baseline = steps.first()?.p99_us;
+
+    steps.iter().position(|step| {
+        let latency_knee = match (baseline, step.p99_us) {
+            (Some(base), Some(p99)) if base > 0 => p99 as f64 >= base as f64 * KNEE_P99_FACTOR,
+            _ => false,
+        };
+        latency_knee || step.is_saturated()
+    })
+}
+
+/// Print sweep results in the requested format
+pub fn print_sweep(steps: &[SweepStep], format: SweepFormat) -> Result<()> {
+    let knee = find_knee(steps);
+
+    match format {
+        SweepFormat::Csv => {
+            println!("target_tps,achieved_tps,total,failed,success_rate,p50_us,p95_us,p99_us,knee");
+            for (i, step) in steps.iter().enumerate() {
+                println!(
+                    "{},{:.1},{},{},{:.2},{},{},{},{}",
+                    step.target_tps,
+                    step.achieved_tps,
+                    step.total,
+                    step.failed,
+                    step.success_rate,
+                    csv_opt(step.p50_us),
+                    csv_opt(step.p95_us),
+                    csv_opt(step.p99_us),
+                    knee == Some(i)
+                );
+            }
+        }
+        SweepFormat::Json => {
+            let output = serde_json::json!({
+                "steps": steps,
+                "knee_target_tps": knee.map(|i| steps[i].target_tps),
+            });
+            println!("{}", serde_json::to_string_pretty(&output)?);
+        }
+        SweepFormat::Table => {
+            println!();
+            println!("{}", style("=".repeat(70)).dim());
+            println!("{}", style("Workload Sweep Results").green().bold());
+            println!("{}", style("=".repeat(70)).dim());
+            println!(
+                "  {:>10} {:>12} {:>10} {:>10} {:>10} {:>9}",
+                "TARGET", "ACHIEVED", "P50 (ms)", "P99 (ms)", "SUCCESS", ""
+            );
+
+            for (i, step) in steps.iter().enumerate() {
+                let marker = if knee == Some(i) {
+                    style("◀ knee").red().bold().to_string()
+                } else if step.is_saturated() {
+                    style("saturated").yellow().to_string()
+                } else {
+                    String::new()
+                };
+
+                println!(
+                    "  {:>10} {:>12.1} {:>10} {:>10} {:>9.1}% {}",
+                    step.target_tps,
+                    step.achieved_tps,
+                    ms_opt(step.p50_us),
+                    ms_opt(step.p99_us),
+                    step.success_rate,
+                    marker
+                );
+            }
+
+            println!();
+            match knee {
+                Some(i) => println!(
+                    "  Latency knee at {} TPS target (achieved {:.1} TPS)",
+                    style(steps[i].target_tps).yellow().bold(),
+                    steps[i].achieved_tps
+                ),
+                None => println!("  {}", style("No latency knee detected in this range").green()),
+            }
+            println!("{}", style("=".repeat(70)).dim());
+        }
+    }
+
+    Ok(())
+}
+
+fn csv_opt(value: Option<u64>) -> String {
+    value.map(|v| v.to_string()).unwrap_or_default()
+}
+
+fn ms_opt(value: Option<u64>) -> String {
+    value
+        .map(|v| format!("{:.2}", v as f64 / 1000.0))
+        .unwrap_or_else(|| "-".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn step(target_tps: usize, achieved_tps: f64, p99_us: u64) -> SweepStep {
+        SweepStep {
+            target_tps,
+            achieved_tps,
+            total: 0,
+            failed: 0,
+            success_rate: 100.0,
+            p50_us: None,
+            p95_us: None,
+            p99_us: Some(p99_us),
+        }
+    }
+
+    #[test]
+    fn test_sweep_targets() {
+        assert_eq!(sweep_targets(100, 500, 100).unwrap(), vec![100, 200, 300, 400, 500]);
+        assert_eq!(sweep_targets(100, 250, 100).unwrap(), vec![100, 200]);
+        assert!(sweep_targets(0, 100, 10).is_err());
+        assert!(sweep_targets(100, 50, 10).is_err());
+        assert!(sweep_targets(100, 200, 0).is_err());
+    }
+
+    #[test]
+    fn test_parse_duration_secs() {
+        assert_eq!(parse_duration_secs("30").unwrap(), 30);
+        assert_eq!(parse_duration_secs("30s").unwrap(), 30);
+        assert_eq!(parse_duration_secs("2m").unwrap(), 120);
+        assert_eq!(parse_duration_secs("1h").unwrap(), 3600);
+        assert!(parse_duration_secs("abc").is_err());
+        assert!(parse_duration_secs("0s").is_err());
+    }
+
+    #[test]
+    fn test_find_knee() {
+        let steps = vec![
+            step(100, 100.0, 2_000),
+            step(200, 199.0, 2_500),
+            step(300, 298.0, 4_500),
+            step(400, 310.0, 20_000),
+        ];
+        assert_eq!(find_knee(&steps), Some(2));
+
+        // Falling short of the target also counts
+        let steps = vec![step(100, 100.0, 2_000), step(200, 150.0, 2_100)];
+        assert_eq!(find_knee(&steps), Some(1));
+
+        let steps = vec![step(100, 100.0, 2_000), step(200, 200.0, 2_100)];
+        assert_eq!(find_knee(&steps), None);
+    }
+}
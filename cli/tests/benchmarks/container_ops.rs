@@ -1,5 +1,6 @@
 use dbarena::container::{ContainerConfig, ContainerManager, DatabaseType, DockerClient};
 use dbarena::health::{wait_for_healthy, PostgresHealthChecker};
+use dbarena::progress::NoProgress;
 use std::time::{Duration, Instant};
 
 #[tokio::test]
@@ -37,7 +38,7 @@ async fn bench_postgres_warm_start() {
         .expect("Failed to start container");
 
     let checker = PostgresHealthChecker::new(DockerClient::new().unwrap().docker().clone());
-    wait_for_healthy(&container.id, &checker, Duration::from_secs(60))
+    wait_for_healthy(&container.id, &checker, Duration::from_secs(60), &NoProgress)
         .await
         .expect("Health check failed");
 
@@ -135,7 +136,7 @@ async fn bench_health_check_detection() {
     // Measure health check time
     let start = Instant::now();
     let checker = PostgresHealthChecker::new(DockerClient::new().unwrap().docker().clone());
-    wait_for_healthy(&container.id, &checker, Duration::from_secs(60))
+    wait_for_healthy(&container.id, &checker, Duration::from_secs(60), &NoProgress)
         .await
         .expect("Health check failed");
     let elapsed = start.elapsed();
@@ -1,6 +1,10 @@
 /// Common test utilities shared across all test modules
 use dbarena::container::{ContainerConfig, ContainerManager, DatabaseType, DockerClient};
-use dbarena::health::{wait_for_healthy, HealthChecker, MySQLHealthChecker, PostgresHealthChecker, SQLServerHealthChecker};
+use dbarena::health::{
+    wait_for_healthy, HealthChecker, MySQLHealthChecker, PostgresHealthChecker,
+    SQLServerHealthChecker, SQLiteHealthChecker,
+};
+use dbarena::progress::NoProgress;
 use std::net::TcpListener;
 use std::time::Duration;
 use tempfile::TempDir;
@@ -70,6 +74,10 @@ pub async fn create_and_start_container(
         DatabaseType::MySQL => std::cmp::max(timeout, Duration::from_secs(180)),
         DatabaseType::Postgres => std::cmp::max(timeout, Duration::from_secs(120)),
         DatabaseType::SQLServer => std::cmp::max(timeout, Duration::from_secs(180)),
+        DatabaseType::Redis | DatabaseType::MongoDB | DatabaseType::CockroachDB => {
+            std::cmp::max(timeout, Duration::from_secs(120))
+        }
+        DatabaseType::SQLite => std::cmp::max(timeout, Duration::from_secs(60)),
     };
 
     wait_for_healthy_container(&test_container, effective_timeout).await?;
@@ -96,10 +104,11 @@ pub async fn wait_for_healthy_container(
         "postgres" => Box::new(PostgresHealthChecker::new(docker)),
         "mysql" => Box::new(MySQLHealthChecker::new(docker)),
         "sqlserver" => Box::new(SQLServerHealthChecker::new(docker)),
+        "sqlite" => Box::new(SQLiteHealthChecker::new(docker)),
         _ => return Err(anyhow::anyhow!("Unknown database type")),
     };
 
-    wait_for_healthy(&test_container.id, checker.as_ref(), timeout).await?;
+    wait_for_healthy(&test_container.id, checker.as_ref(), timeout, &NoProgress).await?;
 
     Ok(())
 }
@@ -200,6 +209,33 @@ pub async fn execute_query(
             attach_stderr: Some(true),
             ..Default::default()
         },
+        DatabaseType::CockroachDB => bollard::exec::CreateExecOptions {
+            cmd: Some(vec![
+                "cockroach".to_string(),
+                "sql".to_string(),
+                "--insecure".to_string(),
+                "--format=tsv".to_string(),
+                "-e".to_string(),
+                query.to_string(),
+            ]),
+            attach_stdout: Some(true),
+            attach_stderr: Some(true),
+            ..Default::default()
+        },
+        DatabaseType::SQLite => bollard::exec::CreateExecOptions {
+            cmd: Some(vec![
+                "sqlite3".to_string(),
+                "-bail".to_string(),
+                dbarena::container::SQLITE_DB_PATH.to_string(),
+                query.to_string(),
+            ]),
+            attach_stdout: Some(true),
+            attach_stderr: Some(true),
+            ..Default::default()
+        },
+        DatabaseType::Redis | DatabaseType::MongoDB => {
+            anyhow::bail!("{} has no SQL to execute", database_type)
+        }
     };
 
     let exec = docker.create_exec(container_id, exec_config).await?;
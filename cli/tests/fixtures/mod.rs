@@ -0,0 +1,21 @@
+//! Captured client output for testing parsers without Docker
+//!
+//! Files live under `tests/fixtures/outputs/<client>/`, exactly as `psql`,
+//! `mysql` or `sqlcmd` printed them (stdout and stderr together, as
+//! `docker exec` returns them).
+
+use std::path::PathBuf;
+
+/// Path of a fixture, e.g. `output_path("psql/syntax_error.txt")`
+pub fn output_path(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures/outputs")
+        .join(name)
+}
+
+/// Contents of a captured output fixture
+pub fn output(name: &str) -> String {
+    let path = output_path(name);
+    std::fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("Failed to read fixture {}: {}", path.display(), e))
+}
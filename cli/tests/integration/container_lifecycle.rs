@@ -1,5 +1,14 @@
-use dbarena::container::{ContainerConfig, ContainerManager, DatabaseType, DockerClient};
-use crate::common::{docker_available, unique_container_name};
+use dbarena::container::{
+    connection_uri, ConnParams, ContainerConfig, ContainerManager, DatabaseType, DockerClient,
+};
+use dbarena::database_metrics::{DatabaseMetricsCollector, DockerDatabaseMetricsCollector};
+use dbarena::DBArenaError;
+use crate::common::{
+    create_and_start_container, docker_available, execute_query, unique_container_name,
+};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
 
 #[tokio::test]
 #[ignore] // Requires Docker
@@ -96,6 +105,111 @@ async fn test_mysql_container_creation() {
         .expect("Failed to destroy container");
 }
 
+#[tokio::test]
+#[ignore] // Requires Docker
+async fn test_sqlite_container_lifecycle() {
+    if !docker_available().await {
+        eprintln!("Skipping test: Docker not available");
+        return;
+    }
+
+    let name = unique_container_name("test-sqlite-lifecycle");
+    let config = ContainerConfig::new(DatabaseType::SQLite).with_name(name);
+    let container = create_and_start_container(config, Duration::from_secs(60))
+        .await
+        .expect("SQLite container should become healthy");
+
+    // No server, so nothing is published
+    let found = container
+        .manager
+        .find_container(&container.name)
+        .await
+        .unwrap()
+        .expect("container should be listed");
+    assert_eq!(found.database_type, "sqlite");
+    assert_eq!(found.host_port, None);
+
+    execute_query(&container.id, "CREATE TABLE t (id INTEGER PRIMARY KEY)", DatabaseType::SQLite)
+        .await
+        .expect("create table");
+    execute_query(&container.id, "INSERT INTO t VALUES (1), (2)", DatabaseType::SQLite)
+        .await
+        .expect("insert");
+    let count = execute_query(&container.id, "SELECT count(*) FROM t", DatabaseType::SQLite)
+        .await
+        .expect("count");
+    assert_eq!(count.trim(), "2");
+
+    container
+        .manager
+        .destroy_container(&container.id, true)
+        .await
+        .expect("Failed to destroy container");
+}
+
+#[tokio::test]
+#[ignore] // Requires Docker
+async fn test_redis_container_lifecycle() {
+    if !docker_available().await {
+        eprintln!("Skipping test: Docker not available");
+        return;
+    }
+
+    // Healthy once `redis-cli ping` answers PONG
+    let name = unique_container_name("test-redis-lifecycle");
+    let config = ContainerConfig::new(DatabaseType::Redis).with_name(name);
+    let container = create_and_start_container(config, Duration::from_secs(60))
+        .await
+        .expect("Redis container should become healthy");
+
+    let found = container
+        .manager
+        .find_container(&container.name)
+        .await
+        .unwrap()
+        .expect("container should be listed");
+    assert_eq!(found.database_type, "redis");
+    let port = found.host_port.expect("Redis port should be published");
+    assert_eq!(
+        connection_uri(DatabaseType::Redis, &HashMap::new(), "localhost", port, false).unwrap(),
+        format!("redis://localhost:{}", port)
+    );
+
+    // Commands go through redis-cli
+    let docker = Arc::new(
+        bollard::Docker::connect_with_local_defaults().expect("Failed to connect to Docker"),
+    );
+    let collector = DockerDatabaseMetricsCollector::new(docker);
+    let params = ConnParams::from_env(DatabaseType::Redis, HashMap::new());
+    let redis_cli = |args: &[&str]| {
+        let mut command = vec!["redis-cli".to_string()];
+        command.extend(args.iter().map(|arg| arg.to_string()));
+        command
+    };
+    collector
+        .exec_client(&container.id, &params, &redis_cli(&["SET", "greeting", "hello"]))
+        .await
+        .expect("SET");
+    let value = collector
+        .exec_client(&container.id, &params, &redis_cli(&["GET", "greeting"]))
+        .await
+        .expect("GET");
+    assert_eq!(value.trim(), "hello");
+
+    let metrics = collector
+        .collect(&container.id, DatabaseType::Redis)
+        .await
+        .expect("Redis metrics from INFO");
+    assert_eq!(metrics.database_type, DatabaseType::Redis);
+    assert!(metrics.active_connections >= 1);
+
+    container
+        .manager
+        .destroy_container(&container.id, true)
+        .await
+        .expect("Failed to destroy container");
+}
+
 #[tokio::test]
 #[ignore] // Requires Docker
 async fn test_find_container_by_name() {
@@ -146,6 +260,38 @@ async fn test_find_container_by_name() {
         .expect("Failed to destroy container");
 }
 
+#[tokio::test]
+#[ignore] // Requires Docker
+async fn test_duplicate_name_is_rejected() {
+    if !docker_available().await {
+        eprintln!("Skipping test: Docker not available");
+        return;
+    }
+    let client = DockerClient::new().expect("Failed to create Docker client");
+    let manager = ContainerManager::new(client);
+
+    let name = unique_container_name("test-duplicate-name");
+    let config = ContainerConfig::new(DatabaseType::Postgres).with_name(name.clone());
+
+    let container = manager
+        .create_container(config.clone())
+        .await
+        .expect("Failed to create container");
+
+    let err = manager
+        .create_container(config)
+        .await
+        .expect_err("Second container with the same name should be rejected");
+    assert!(matches!(&err, DBArenaError::NameInUse { name: n } if *n == name));
+    assert!(err.to_string().contains("--recreate"));
+
+    // Cleanup
+    manager
+        .destroy_container(&container.id, false)
+        .await
+        .expect("Failed to destroy container");
+}
+
 #[tokio::test]
 async fn test_docker_client_connection() {
     let client = match DockerClient::new() {
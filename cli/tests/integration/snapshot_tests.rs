@@ -1,5 +1,5 @@
-/// Integration tests for snapshot lifecycle
-/// Run with: cargo test --test integration -- --ignored
+//! Integration tests for snapshot lifecycle
+//! Run with: cargo test --test integration -- --ignored
 
 use std::sync::Arc;
 use std::time::Duration;
@@ -10,7 +10,10 @@ use dbarena::snapshot::SnapshotManager;
 
 #[path = "../common/mod.rs"]
 mod common;
-use common::{create_and_start_container, cleanup_container, docker_available, unique_container_name};
+use common::{
+    create_and_start_container, cleanup_container, docker_available, execute_query, tempdir,
+    unique_container_name,
+};
 
 #[tokio::test]
 #[ignore]
@@ -35,6 +38,7 @@ async fn test_snapshot_lifecycle() {
             &test_container.id,
             "test-snapshot".to_string(),
             Some("integration test".to_string()),
+            None,
             true,
         )
         .await
@@ -74,3 +78,89 @@ async fn test_snapshot_lifecycle() {
         .await
         .expect("Failed to cleanup container");
 }
+
+#[tokio::test]
+#[ignore]
+async fn test_snapshot_export_import_restores() {
+    if !docker_available().await {
+        eprintln!("Skipping test: Docker not available");
+        return;
+    }
+
+    let config = ContainerConfig::new(DatabaseType::Postgres)
+        .with_name(unique_container_name("test-snapshot-export"));
+
+    let test_container = create_and_start_container(config, Duration::from_secs(60))
+        .await
+        .expect("Failed to create container");
+
+    let docker = Docker::connect_with_local_defaults().expect("Docker not available");
+    let manager = SnapshotManager::new(Arc::new(docker));
+
+    let snapshot = manager
+        .create(
+            &test_container.id,
+            "export-test".to_string(),
+            Some("exported".to_string()),
+            None,
+            true,
+        )
+        .await
+        .expect("Failed to create snapshot");
+
+    let dir = tempdir().expect("Failed to create temp dir");
+    let path = dir.path().join("export-test.tar.gz");
+    manager
+        .export(&snapshot.id, &path)
+        .await
+        .expect("Failed to export snapshot");
+
+    // Remove the local copy so the import has to recreate it
+    manager
+        .delete(&snapshot.id)
+        .await
+        .expect("Failed to delete snapshot");
+    assert!(manager.get(&snapshot.id).await.is_err());
+
+    let imported = manager.import(&path).await.expect("Failed to import snapshot");
+    assert_eq!(imported.id, snapshot.id);
+    assert_eq!(imported.name, snapshot.name);
+    assert_eq!(imported.message, snapshot.message);
+    assert_eq!(imported.database_type, snapshot.database_type);
+    assert_eq!(imported.created_at, snapshot.created_at);
+    assert!(manager.list().await.unwrap().iter().any(|s| s.id == snapshot.id));
+
+    let restored = manager
+        .restore(&imported.id, Some(unique_container_name("restored-import")), None)
+        .await
+        .expect("Failed to restore imported snapshot");
+
+    // The restored database takes a moment to accept connections
+    let mut output = String::new();
+    for _ in 0..30 {
+        if let Ok(result) =
+            execute_query(&restored.id, "SELECT 41 + 1;", DatabaseType::Postgres).await
+        {
+            output = result;
+            break;
+        }
+        tokio::time::sleep(Duration::from_secs(1)).await;
+    }
+    assert!(output.contains("42"), "Restored container should answer queries: {}", output);
+
+    let client = DockerClient::new().expect("Failed to create docker client");
+    let container_manager = ContainerManager::new(client);
+    container_manager
+        .destroy_container(&restored.id, false)
+        .await
+        .expect("Failed to destroy restored container");
+
+    manager
+        .delete(&imported.id)
+        .await
+        .expect("Failed to delete snapshot");
+
+    cleanup_container(&test_container.id)
+        .await
+        .expect("Failed to cleanup container");
+}
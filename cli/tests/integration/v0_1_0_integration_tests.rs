@@ -5,6 +5,7 @@
 
 use dbarena::container::{ContainerConfig, ContainerManager, DatabaseType, DockerClient};
 use dbarena::health::{wait_for_healthy, MySQLHealthChecker, PostgresHealthChecker, SQLServerHealthChecker};
+use dbarena::progress::NoProgress;
 use std::collections::HashMap;
 use std::time::Duration;
 
@@ -42,7 +43,7 @@ async fn test_mysql_health_checker() {
     // Wait for MySQL to be healthy
     let client = DockerClient::new().expect("Failed to create Docker client");
     let checker = MySQLHealthChecker::new(client.docker().clone());
-    let result = wait_for_healthy(&test_container.id, &checker, Duration::from_secs(60)).await;
+    let result = wait_for_healthy(&test_container.id, &checker, Duration::from_secs(60), &NoProgress).await;
 
     assert!(result.is_ok(), "MySQL should become healthy");
 }
@@ -71,7 +72,7 @@ async fn test_postgres_health_checker() {
     // Wait for PostgreSQL to be healthy
     let client = DockerClient::new().expect("Failed to create Docker client");
     let checker = PostgresHealthChecker::new(client.docker().clone());
-    let result = wait_for_healthy(&test_container.id, &checker, Duration::from_secs(60)).await;
+    let result = wait_for_healthy(&test_container.id, &checker, Duration::from_secs(60), &NoProgress).await;
 
     assert!(result.is_ok(), "PostgreSQL should become healthy");
 }
@@ -94,7 +95,7 @@ async fn test_health_check_timeout() {
     // Don't start the container - health check should timeout
     let client = DockerClient::new().expect("Failed to create Docker client");
     let checker = PostgresHealthChecker::new(client.docker().clone());
-    let result = wait_for_healthy(&test_container.id, &checker, Duration::from_secs(2)).await;
+    let result = wait_for_healthy(&test_container.id, &checker, Duration::from_secs(2), &NoProgress).await;
 
     assert!(result.is_err(), "Health check should timeout for stopped container");
 }
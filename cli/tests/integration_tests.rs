@@ -18,4 +18,5 @@ mod integration {
     mod qps_tps_accuracy_tests;
     mod tui_rendering_tests;
     mod log_streaming_tests;
+    mod snapshot_tests;
 }
@@ -200,10 +200,13 @@ fn test_workload_tps_accuracy() {
             custom_operations: None,
             custom_queries: None,
             tables: vec!["test_products".to_string()],
+            table_weights: None,
             connections: 10,
             target_tps: 100,
             duration_seconds: Some(30),
             transaction_count: None,
+            seed: None,
+            warmup_seconds: None,
         };
 
         // Run workload
@@ -290,10 +293,13 @@ fn test_workload_latency_p99() {
             custom_operations: None,
             custom_queries: None,
             tables: vec!["test_data".to_string()],
+            table_weights: None,
             connections: 20,
             target_tps: 200,
             duration_seconds: Some(30),
             transaction_count: None,
+            seed: None,
+            warmup_seconds: None,
         };
 
         let docker_client = DockerClient::new().unwrap();
@@ -456,10 +462,13 @@ fn test_workload_long_duration() {
             custom_operations: None,
             custom_queries: None,
             tables: vec!["stability_test".to_string()],
+            table_weights: None,
             connections: 50,
             target_tps: 500,
             duration_seconds: Some(300), // 5 minutes
             transaction_count: None,
+            seed: None,
+            warmup_seconds: None,
         };
 
         let docker_client = DockerClient::new().unwrap();
@@ -0,0 +1,122 @@
+/// Parsing tests against captured client output in tests/fixtures/outputs
+use dbarena::container::DatabaseType;
+use dbarena::database_metrics::{
+    parse_connection_states, parse_database_stats, parse_performance_counters,
+    parse_status_rows, PgDatabaseStats,
+};
+use dbarena::init::{count_statements, parse_error};
+use dbarena::seed::{build_batch_insert, Row};
+use std::path::Path;
+
+use crate::fixtures::output;
+
+#[test]
+fn test_psql_syntax_error() {
+    let error = parse_error(
+        &output("psql/syntax_error.txt"),
+        Path::new("01_schema.sql"),
+        DatabaseType::Postgres,
+    );
+    // The psql prefix's file line wins over LINE 1 of the statement
+    assert_eq!(error.line_number, Some(12));
+    assert_eq!(error.suggestion, Some("Did you mean 'INSERT'?".to_string()));
+}
+
+#[test]
+fn test_mysql_syntax_error() {
+    // The password warning mysql prints first must not confuse the parser
+    let error = parse_error(
+        &output("mysql/syntax_error.txt"),
+        Path::new("init.sql"),
+        DatabaseType::MySQL,
+    );
+    assert_eq!(error.line_number, Some(3));
+    assert_eq!(error.database_error_code, Some("1064".to_string()));
+}
+
+#[test]
+fn test_sqlcmd_syntax_error() {
+    let error = parse_error(
+        &output("sqlcmd/syntax_error.txt"),
+        Path::new("init.sql"),
+        DatabaseType::SQLServer,
+    );
+    assert_eq!(error.line_number, Some(7));
+    assert_eq!(error.database_error_code, Some("102".to_string()));
+}
+
+#[test]
+fn test_count_statements_in_psql_output() {
+    assert_eq!(count_statements(&output("psql/init_success.txt")), 5);
+}
+
+#[test]
+fn test_postgres_metrics_output() {
+    let states = parse_connection_states(&output("psql/connection_states.txt"));
+    assert_eq!(states.len(), 4);
+    assert_eq!(states.get("idle in transaction"), Some(&2));
+
+    let stats = parse_database_stats(&output("psql/database_stats.txt")).unwrap();
+    assert_eq!(
+        stats,
+        PgDatabaseStats {
+            commits: 1542,
+            rollbacks: 7,
+            rows_returned: 982311,
+            rows_fetched: 40211,
+            rows_inserted: 1200,
+            rows_updated: 340,
+            rows_deleted: 12,
+        }
+    );
+    assert!(parse_database_stats("").is_none());
+}
+
+#[test]
+fn test_mysql_status_output() {
+    let status = parse_status_rows(&output("mysql/global_status.txt"));
+    assert_eq!(status.len(), 6);
+    assert_eq!(status.get("Com_select"), Some(&5120));
+    assert_eq!(status.get("Com_rollback"), Some(&2));
+}
+
+#[test]
+fn test_sqlcmd_performance_counters_output() {
+    let counters = parse_performance_counters(&output("sqlcmd/performance_counters.txt"));
+    assert_eq!(counters.len(), 2);
+    assert_eq!(counters.get("Batch Requests/sec"), Some(&48211));
+    assert_eq!(counters.get("Transactions/sec"), Some(&9120));
+}
+
+#[test]
+fn test_batch_insert_per_database() {
+    let columns = vec!["id".to_string(), "name".to_string(), "bio".to_string()];
+    let rows: Vec<Row> = vec![
+        [("id", "1"), ("name", "O'Brien"), ("bio", r"C:\path")]
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect(),
+        [("id", "2"), ("name", "Zoë")]
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect(),
+    ];
+
+    let sql = build_batch_insert(DatabaseType::Postgres, "users", &columns, &rows).unwrap();
+    assert_eq!(
+        sql,
+        r#"INSERT INTO "users" ("id", "name", "bio") VALUES (1, 'O''Brien', 'C:\path'), (2, 'Zoë', NULL);"#
+    );
+
+    let sql = build_batch_insert(DatabaseType::MySQL, "users", &columns, &rows).unwrap();
+    assert_eq!(
+        sql,
+        r#"INSERT INTO `users` (`id`, `name`, `bio`) VALUES (1, 'O''Brien', 'C:\\path'), (2, 'Zoë', NULL);"#
+    );
+
+    let sql = build_batch_insert(DatabaseType::SQLServer, "users", &columns, &rows).unwrap();
+    assert_eq!(
+        sql,
+        r#"INSERT INTO [users] ([id], [name], [bio]) VALUES (1, 'O''Brien', 'C:\path'), (2, N'Zoë', NULL);"#
+    );
+}
@@ -337,6 +337,17 @@ fn parse_error(error_msg: &str, script_path: &Path, db_type: DatabaseType) -> Sc
                 }
             }
         }
+        DatabaseType::SQLite => {
+            if let Some(line_pos) = error_msg.find("near line ") {
+                if let Some(num_str) = error_msg[line_pos + 10..].split(':').next() {
+                    if let Ok(line_num) = num_str.trim().parse::<usize>() {
+                        script_error.line_number = Some(line_num);
+                    }
+                }
+            }
+        }
+        // redis-cli, mongosh and cockroach sql errors carry no position to parse
+        DatabaseType::Redis | DatabaseType::MongoDB | DatabaseType::CockroachDB => {}
     }
 
     script_error
@@ -406,6 +417,27 @@ fn build_exec_command(db_type: DatabaseType, script_path: &str, config: &Contain
                 script_path.to_string(),
             ]
         }
+        DatabaseType::Redis => vec![
+            "sh".to_string(),
+            "-c".to_string(),
+            format!("redis-cli --pipe < {}", script_path),
+        ],
+        DatabaseType::MongoDB => vec![
+            "mongosh".to_string(),
+            "--quiet".to_string(),
+            "--file".to_string(),
+            script_path.to_string(),
+        ],
+        DatabaseType::CockroachDB => vec![
+            "sh".to_string(),
+            "-c".to_string(),
+            format!("cockroach sql --insecure < {}", script_path),
+        ],
+        DatabaseType::SQLite => vec![
+            "sh".to_string(),
+            "-c".to_string(),
+            format!("sqlite3 -bail /data/db.sqlite < {}", script_path),
+        ],
     }
 }
 
@@ -1,6 +1,8 @@
 /// Unit tests for v0.1.0 features
 /// Tests core functionality: container config builder, database types, connection strings, etc.
-use dbarena::container::{ContainerConfig, DatabaseType};
+use dbarena::container::{
+    is_known_capability, parse_tmpfs, ContainerConfig, DatabaseType, Ulimit,
+};
 use std::collections::HashMap;
 use std::path::PathBuf;
 
@@ -56,6 +58,42 @@ fn test_database_type_from_string() {
         Some(DatabaseType::SQLServer)
     );
 
+    // Redis
+    assert_eq!(
+        DatabaseType::from_string("redis"),
+        Some(DatabaseType::Redis)
+    );
+
+    // MongoDB
+    assert_eq!(
+        DatabaseType::from_string("mongodb"),
+        Some(DatabaseType::MongoDB)
+    );
+    assert_eq!(
+        DatabaseType::from_string("mongo"),
+        Some(DatabaseType::MongoDB)
+    );
+
+    // CockroachDB
+    assert_eq!(
+        DatabaseType::from_string("cockroachdb"),
+        Some(DatabaseType::CockroachDB)
+    );
+    assert_eq!(
+        DatabaseType::from_string("crdb"),
+        Some(DatabaseType::CockroachDB)
+    );
+
+    // SQLite
+    assert_eq!(
+        DatabaseType::from_string("sqlite"),
+        Some(DatabaseType::SQLite)
+    );
+    assert_eq!(
+        DatabaseType::from_string("sqlite3"),
+        Some(DatabaseType::SQLite)
+    );
+
     // Invalid
     assert_eq!(DatabaseType::from_string("oracle"), None);
     assert_eq!(DatabaseType::from_string(""), None);
@@ -66,6 +104,10 @@ fn test_database_type_default_versions() {
     assert_eq!(DatabaseType::Postgres.default_version(), "16");
     assert_eq!(DatabaseType::MySQL.default_version(), "8.0");
     assert_eq!(DatabaseType::SQLServer.default_version(), "2022-latest");
+    assert_eq!(DatabaseType::Redis.default_version(), "7");
+    assert_eq!(DatabaseType::MongoDB.default_version(), "7");
+    assert_eq!(DatabaseType::CockroachDB.default_version(), "latest-v24.1");
+    assert_eq!(DatabaseType::SQLite.default_version(), "latest");
 }
 
 #[test]
@@ -82,6 +124,129 @@ fn test_database_type_docker_images() {
         DatabaseType::SQLServer.docker_image("2022-latest"),
         "mcr.microsoft.com/mssql/server:2022-latest"
     );
+    assert_eq!(
+        DatabaseType::Redis.docker_image("7"),
+        "redis:7"
+    );
+    assert_eq!(
+        DatabaseType::MongoDB.docker_image("7"),
+        "mongo:7"
+    );
+    assert_eq!(
+        DatabaseType::CockroachDB.docker_image("latest-v24.1"),
+        "cockroachdb/cockroach:latest-v24.1"
+    );
+    assert_eq!(
+        DatabaseType::SQLite.docker_image("latest"),
+        "keinos/sqlite3:latest"
+    );
+}
+
+#[test]
+fn test_container_config_custom_image() {
+    let config = ContainerConfig::new(DatabaseType::Postgres).with_version("16".to_string());
+    assert_eq!(config.image_ref(), "postgres:16");
+
+    let mirrored = config.clone().with_image("registry.internal:5000/postgres".to_string());
+    assert_eq!(mirrored.image_ref(), "registry.internal:5000/postgres:16");
+
+    let pinned = config.clone().with_image("registry.internal/postgres:16-alpine".to_string());
+    assert_eq!(pinned.image_ref(), "registry.internal/postgres:16-alpine");
+
+    let digest = config.with_image("registry.internal/postgres@sha256:abc".to_string());
+    assert_eq!(digest.image_ref(), "registry.internal/postgres@sha256:abc");
+}
+
+#[test]
+fn test_container_config_user_and_workdir() {
+    let config = ContainerConfig::new(DatabaseType::Postgres);
+    assert!(config.user.is_none());
+    assert!(config.workdir.is_none());
+
+    let config = config
+        .with_user("999:999".to_string())
+        .with_workdir("/docker-entrypoint-initdb.d".to_string());
+    assert_eq!(config.user.as_deref(), Some("999:999"));
+    assert_eq!(config.workdir.as_deref(), Some("/docker-entrypoint-initdb.d"));
+}
+
+#[test]
+fn test_container_config_capabilities() {
+    let config = ContainerConfig::new(DatabaseType::Postgres)
+        .with_cap_add(vec!["NET_ADMIN".to_string()])
+        .with_security_opt(vec!["seccomp=unconfined".to_string()]);
+    assert_eq!(config.cap_add, ["NET_ADMIN"]);
+    assert!(config.cap_drop.is_empty());
+    assert_eq!(config.security_opt, ["seccomp=unconfined"]);
+
+    assert!(is_known_capability("NET_ADMIN"));
+    assert!(is_known_capability("cap_sys_nice"));
+    assert!(is_known_capability("ALL"));
+    assert!(!is_known_capability("NET_ADMN"));
+}
+
+#[test]
+fn test_ulimit_parse() {
+    assert_eq!(
+        Ulimit::parse("nofile=1024:4096").unwrap(),
+        Ulimit::new("nofile", 1024, 4096)
+    );
+    assert_eq!(Ulimit::parse("nproc=2048").unwrap(), Ulimit::new("nproc", 2048, 2048));
+    assert_eq!(
+        Ulimit::parse("memlock=unlimited").unwrap(),
+        Ulimit::new("memlock", -1, -1)
+    );
+    assert_eq!(Ulimit::new("nofile", 1, 2).to_string(), "nofile=1:2");
+
+    assert!(Ulimit::parse("nofile").is_err());
+    assert!(Ulimit::parse("openfiles=10").is_err());
+    assert!(Ulimit::parse("nofile=4096:1024").is_err());
+    assert!(Ulimit::parse("nofile=lots").is_err());
+}
+
+#[test]
+fn test_effective_ulimits_override_defaults() {
+    let config = ContainerConfig::new(DatabaseType::Postgres);
+    assert_eq!(config.effective_ulimits(), [Ulimit::new("nofile", 65536, 65536)]);
+
+    let config = config.with_ulimits(vec![
+        Ulimit::new("nofile", 1024, 1024),
+        Ulimit::new("memlock", -1, -1),
+    ]);
+    assert_eq!(
+        config.effective_ulimits(),
+        [Ulimit::new("nofile", 1024, 1024), Ulimit::new("memlock", -1, -1)]
+    );
+}
+
+#[test]
+fn test_command_args_builder() {
+    let args = vec!["-c".to_string(), "shared_buffers=512MB".to_string()];
+    let config = ContainerConfig::new(DatabaseType::Postgres).with_command_args(args.clone());
+    assert_eq!(config.command_args, args);
+    assert!(config.command.is_none());
+
+    let config = config.with_command(vec!["postgres".to_string()]);
+    assert_eq!(config.command, Some(vec!["postgres".to_string()]));
+}
+
+#[test]
+fn test_parse_tmpfs() {
+    assert_eq!(
+        parse_tmpfs("/var/lib/postgresql/data:size=1g").unwrap(),
+        ("/var/lib/postgresql/data".to_string(), "size=1g".to_string())
+    );
+    assert_eq!(
+        parse_tmpfs("/scratch").unwrap(),
+        ("/scratch".to_string(), "rw".to_string())
+    );
+    assert!(parse_tmpfs("relative/path").is_err());
+
+    let config = ContainerConfig::new(DatabaseType::MySQL).with_tmpfs(
+        DatabaseType::MySQL.data_dir().to_string(),
+        "rw".to_string(),
+    );
+    assert_eq!(config.tmpfs["/var/lib/mysql"], "rw");
 }
 
 #[test]
@@ -89,6 +254,9 @@ fn test_database_type_default_ports() {
     assert_eq!(DatabaseType::Postgres.default_port(), 5432);
     assert_eq!(DatabaseType::MySQL.default_port(), 3306);
     assert_eq!(DatabaseType::SQLServer.default_port(), 1433);
+    assert_eq!(DatabaseType::Redis.default_port(), 6379);
+    assert_eq!(DatabaseType::MongoDB.default_port(), 27017);
+    assert_eq!(DatabaseType::CockroachDB.default_port(), 26257);
 }
 
 #[test]
@@ -96,6 +264,35 @@ fn test_database_type_as_str() {
     assert_eq!(DatabaseType::Postgres.as_str(), "postgres");
     assert_eq!(DatabaseType::MySQL.as_str(), "mysql");
     assert_eq!(DatabaseType::SQLServer.as_str(), "sqlserver");
+    assert_eq!(DatabaseType::Redis.as_str(), "redis");
+    assert_eq!(DatabaseType::MongoDB.as_str(), "mongodb");
+    assert_eq!(DatabaseType::CockroachDB.as_str(), "cockroachdb");
+    assert_eq!(DatabaseType::SQLite.as_str(), "sqlite");
+}
+
+#[test]
+fn test_database_type_default_command() {
+    assert_eq!(
+        DatabaseType::CockroachDB.default_command(),
+        Some(vec!["start-single-node".to_string(), "--insecure".to_string()])
+    );
+    assert_eq!(DatabaseType::Postgres.default_command(), None);
+    assert!(DatabaseType::CockroachDB.is_sql());
+}
+
+#[test]
+fn test_sqlite_is_embedded() {
+    let sqlite = DatabaseType::SQLite;
+    assert!(sqlite.is_sql());
+    assert!(sqlite.is_embedded());
+    assert!(!DatabaseType::Postgres.is_embedded());
+    assert_eq!(sqlite.default_port(), 0);
+    assert_eq!(sqlite.default_user(), Some("root"));
+
+    // The container creates the database file, then idles
+    let command = sqlite.default_command().unwrap();
+    assert_eq!(command[..2], ["sh".to_string(), "-c".to_string()]);
+    assert!(command[2].contains("sqlite3 /data/db.sqlite"));
 }
 
 #[test]
@@ -1,10 +1,16 @@
 // Unit test runner for tests in tests/unit/ directory
+
+#[path = "fixtures/mod.rs"]
+mod fixtures;
+
 mod unit {
     mod v0_1_0_tests;
     mod config_tests;
     mod init_tests;
+    mod cli_parsing_tests;
     mod monitoring_tests;
     mod tui_tests;
     mod database_metrics_tests;
     mod logs_tests;
+    mod fixture_tests;
 }
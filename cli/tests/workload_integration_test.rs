@@ -39,10 +39,13 @@ async fn test_workload_engine_end_to_end() {
         custom_operations: None,
         custom_queries: None,
         tables: vec!["pg_database".to_string()], // Use system table that always exists
+        table_weights: None,
         connections: 5,
         target_tps: 50,
         duration_seconds: Some(2), // Short duration for testing
         transaction_count: None,
+        seed: None,
+        warmup_seconds: None,
     };
 
     // Create workload engine
@@ -149,10 +152,13 @@ async fn test_workload_patterns() {
             custom_operations: None,
             custom_queries: None,
             tables: vec!["pg_database".to_string()],
+            table_weights: None,
             connections: 3,
             target_tps: 30,
             duration_seconds: Some(1),
             transaction_count: None,
+            seed: None,
+            warmup_seconds: None,
         };
 
         let engine = WorkloadEngine::new(
@@ -213,10 +219,13 @@ async fn test_rate_limiting_accuracy() {
         custom_operations: None,
         custom_queries: None,
         tables: vec!["pg_database".to_string()],
+        table_weights: None,
         connections: 10,
         target_tps,
         duration_seconds: Some(duration_secs),
         transaction_count: None,
+        seed: None,
+        warmup_seconds: None,
     };
 
     let engine = WorkloadEngine::new(
@@ -290,10 +299,13 @@ async fn test_concurrent_workers() {
             custom_operations: None,
             custom_queries: None,
             tables: vec!["pg_database".to_string()],
+            table_weights: None,
             connections: workers,
             target_tps: 50,
             duration_seconds: Some(2),
             transaction_count: None,
+            seed: None,
+            warmup_seconds: None,
         };
 
         let engine = WorkloadEngine::new(